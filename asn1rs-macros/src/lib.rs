@@ -13,7 +13,7 @@ use syn::LitStr;
 #[proc_macro]
 pub fn asn_to_rust(item: TokenStream) -> TokenStream {
     let input = parse_macro_input!(item as LitStr).value();
-    let tokens = Tokenizer::default().parse(&input);
+    let tokens = Tokenizer::default().parse(&input).unwrap();
     let model = Model::try_from(tokens).unwrap();
 
     let mut generator = RustGenerator::default();
@@ -1,10 +1,15 @@
 use super::range::ident_or_literal_or_punct;
-use super::range::IntegerRange;
+use super::range::InnerTypeConstraint;
+use super::range::IntegerConstraint;
 use super::tag::AttrTag;
 use crate::ast::constants::ConstLit;
+use crate::model::constraint::ElementSet;
 use crate::model::{
-    Charset, Choice, ChoiceVariant, Enumerated, EnumeratedVariant, Range, Size, Tag, Type,
+    Charset, Choice, ChoiceVariant, Container, Enumerated, EnumeratedVariant, Integer, LitOrRef,
+    LiteralValue, PermittedAlphabet, Size, Tag, Tagging, TimeType, Type,
 };
+use crate::parser::Tokenizer;
+use std::convert::TryFrom;
 use std::fmt::Display;
 use std::marker::PhantomData;
 use std::ops::Deref;
@@ -18,8 +23,14 @@ use syn::Token;
 pub(crate) struct AsnAttribute<C: Context> {
     pub(crate) primary: C::Primary,
     pub(crate) tag: Option<Tag>,
+    pub(crate) tagging: Option<Tagging>,
     pub(crate) consts: Vec<ConstLit>,
     pub(crate) extensible_after: Option<String>,
+    pub(crate) identifier: Option<String>,
+    pub(crate) unknown_extensions: Option<String>,
+    pub(crate) default: Option<LiteralValue>,
+    pub(crate) key: Option<String>,
+    pub(crate) boxed: bool,
     _c: PhantomData<C>,
 }
 
@@ -28,8 +39,14 @@ impl<C: Context> AsnAttribute<C> {
         Self {
             primary,
             tag: None,
+            tagging: None,
             consts: Vec::default(),
             extensible_after: None,
+            identifier: None,
+            unknown_extensions: None,
+            default: None,
+            key: None,
+            boxed: false,
             _c: Default::default(),
         }
     }
@@ -37,8 +54,11 @@ impl<C: Context> AsnAttribute<C> {
 
 impl<C: Context> Parse for AsnAttribute<C> {
     fn parse<'a>(input: &'a ParseBuffer<'a>) -> syn::Result<Self> {
+        let primary_omitted = C::Primary::OPTIONAL && input.peek(syn::Ident);
         let mut asn = Self::new(C::Primary::parse(input)?);
-        eof_or_comma(&input, "Primary attribute must be separated by comma")?;
+        if !primary_omitted {
+            eof_or_comma(&input, "Primary attribute must be separated by comma")?;
+        }
 
         while !input.cursor().eof() {
             let lowercase_ident = input
@@ -53,6 +73,15 @@ impl<C: Context> Parse for AsnAttribute<C> {
                     let tag = AttrTag::parse(input)?;
                     asn.tag = Some(tag.0);
                 }
+                "explicit" if C::TAGGABLE && asn.tagging.is_none() => {
+                    asn.tagging = Some(Tagging::Explicit);
+                }
+                "implicit" if C::TAGGABLE && asn.tagging.is_none() => {
+                    asn.tagging = Some(Tagging::Implicit);
+                }
+                "boxed" if C::BOXABLE && !asn.boxed => {
+                    asn.boxed = true;
+                }
                 "extensible_after" if C::EXTENSIBLE_AFTER => {
                     let content;
                     parenthesized!(content in input);
@@ -60,6 +89,45 @@ impl<C: Context> Parse for AsnAttribute<C> {
                         .step(|s| s.ident().ok_or_else(|| content.error("Not a valid ident")))?;
                     asn.extensible_after = Some(ident.to_string());
                 }
+                "unknown_extensions" if C::UNKNOWN_EXTENSIONS => {
+                    let content;
+                    parenthesized!(content in input);
+                    let ident = content
+                        .step(|s| s.ident().ok_or_else(|| content.error("Not a valid ident")))?;
+                    asn.unknown_extensions = Some(ident.to_string());
+                }
+                "default" if C::DEFAULT => {
+                    let content;
+                    parenthesized!(content in input);
+                    let value = content.parse::<syn::LitStr>()?;
+                    let tokens = Tokenizer
+                        .parse(&value.value())
+                        .map_err(|e| syn::Error::new(value.span(), e))?;
+                    asn.default = match &tokens[..] {
+                        [token] => LiteralValue::try_from(token)
+                            .map(Some)
+                            .map_err(|e| syn::Error::new(value.span(), e))?,
+                        _ => return Err(syn::Error::new(value.span(), "Expected a single value")),
+                    };
+                }
+                "key" if C::KEY => {
+                    let content;
+                    parenthesized!(content in input);
+                    let key = content.step(|c| {
+                        ident_or_literal_or_punct(*c).ok_or_else(|| c.error("Expected key"))
+                    })?;
+                    asn.key = Some(if key == "-" {
+                        let value = content.parse::<syn::LitInt>()?;
+                        format!("-{}", value)
+                    } else {
+                        key.to_string()
+                    });
+                }
+                "identifier" if C::IDENTIFIER => {
+                    let content;
+                    parenthesized!(content in input);
+                    asn.identifier = Some(content.parse::<syn::LitStr>()?.value());
+                }
                 "const" if C::CONSTS => {
                     let content;
                     parenthesized!(content in input);
@@ -99,14 +167,17 @@ fn parse_type_pre_stepped<'a>(
     match lowercase_ident {
         // "utf8string" => parse_opt_size_or_any(input).map(|size| Type::String(size, Charset::Utf8)),
         // "ia5string" => parse_opt_size_or_any(input).map(|size| Type::String(size, Charset::Ia5)),
-        "octet_string" => parse_opt_size_or_any(input).map(Type::OctetString),
+        "octet_string" => {
+            parse_opt_size_or_any(input).map(|size| Type::OctetString(size.map(LitOrRef::Lit)))
+        }
         "bit_string" => parse_opt_size_or_any(input).map(Type::bit_vec_with_size),
         string if string.ends_with("string") => {
             let len = string.chars().count();
             let charset = &string[..len - "string".chars().count()];
             let charset = Charset::from_str(&charset)
                 .map_err(|_| input.error(format!("Unexpected charset '{}'", charset)))?;
-            parse_opt_size_or_any(input).map(|size| Type::String(size, charset))
+            parse_opt_size_and_alphabet(input)
+                .map(|(size, alphabet)| Type::String(size.map(LitOrRef::Lit), charset, alphabet))
         }
         "integer" => {
             if input.is_empty() {
@@ -114,18 +185,21 @@ fn parse_type_pre_stepped<'a>(
             } else {
                 let content;
                 parenthesized!(content in input);
-                if content.is_empty() {
-                    Ok(Type::unconstrained_integer())
-                } else {
-                    let int_range = IntegerRange::parse(&content)?;
-                    Ok(Type::integer_with_range_opt(
-                        int_range
-                            .0
-                            .map(|(min, max)| Range::inclusive(Some(min), Some(max)))
-                            .unwrap_or_else(Range::none)
-                            .with_extensible(int_range.1),
-                    ))
+                let big =
+                    matches!(content.fork().parse::<syn::Ident>(), Ok(ident) if ident == "big");
+                if big {
+                    let _ = content.parse::<syn::Ident>()?;
+                    if !content.is_empty() {
+                        let _ = content.parse::<Token![,]>()?;
+                    }
                 }
+                let integer = if content.is_empty() {
+                    Integer::with_constraint(ElementSet::Range(None, None), false)
+                } else {
+                    let IntegerConstraint(set, extensible) = IntegerConstraint::parse(&content)?;
+                    Integer::with_constraint(set.map(&|value| LitOrRef::Lit(*value)), extensible)
+                };
+                Ok(Type::Integer(Integer { big, ..integer }))
             }
         }
         "complex" => {
@@ -140,6 +214,33 @@ fn parse_type_pre_stepped<'a>(
             let tag = AttrTag::parse(&content)?;
             Ok(Type::TypeReference(ident.to_string(), Some(tag.0)))
         }
+        "open_type" => {
+            let content;
+            parenthesized!(content in input);
+            let ident: syn::Ident = content.parse()?;
+            Ok(Type::TypeReference(ident.to_string(), None))
+        }
+        "with_components" => {
+            let content;
+            parenthesized!(content in input);
+            let inner = parse_type(&content)?;
+            let _ = content.parse::<Token![,]>()?;
+            let InnerTypeConstraint(constraints) = content.parse()?;
+            Ok(Type::WithComponents(
+                Box::new(inner),
+                constraints.map(&|value| LitOrRef::Lit(*value)),
+            ))
+        }
+        "containing" => {
+            let content;
+            parenthesized!(content in input);
+            let container = content.parse::<syn::Ident>()?.to_string();
+            let container = Container::from_str(&container)
+                .map_err(|_| content.error(format!("Unexpected container '{}'", container)))?;
+            let _ = content.parse::<Token![,]>()?;
+            let inner = parse_type(&content)?;
+            Ok(Type::Containing(Box::new(inner), container))
+        }
         "option" | "optional" => {
             let content;
             parenthesized!(content in input);
@@ -147,6 +248,10 @@ fn parse_type_pre_stepped<'a>(
             Ok(Type::Optional(Box::new(inner)))
         }
         "boolean" => Ok(Type::Boolean),
+        "null" => Ok(Type::Null),
+        "real" => Ok(Type::Real),
+        "object_identifier" => Ok(Type::ObjectIdentifier),
+        "relative_oid" => Ok(Type::RelativeOid),
         "sequence_of" | "set_of" => {
             let content;
             parenthesized!(content in input);
@@ -160,6 +265,7 @@ fn parse_type_pre_stepped<'a>(
                 Size::Any
             };
             let inner = parse_type(&content)?;
+            let size = size.map(LitOrRef::Lit);
             if lowercase_ident == "sequence_of" {
                 Ok(Type::SequenceOf(Box::new(inner), size))
             } else {
@@ -167,7 +273,9 @@ fn parse_type_pre_stepped<'a>(
                 Ok(Type::SetOf(Box::new(inner), size))
             }
         }
-        r#type => Err(input.error(format!("Unexpected attribute: `{}`", r#type))),
+        r#type => TimeType::from_str(r#type)
+            .map(Type::Time)
+            .map_err(|_| input.error(format!("Unexpected attribute: `{}`", r#type))),
     }
 }
 
@@ -185,6 +293,64 @@ fn parse_opt_size_or_any(input: ParseStream) -> syn::Result<Size> {
     }
 }
 
+/// Parses the optional size and permitted alphabet of a string type in the form of
+/// `(1..8, from("0".."9" | "A"))`
+fn parse_opt_size_and_alphabet(
+    input: ParseStream,
+) -> syn::Result<(Size, Option<PermittedAlphabet>)> {
+    if input.is_empty() || !input.peek(token::Paren) {
+        return Ok((Size::Any, None));
+    }
+    let content;
+    parenthesized!(content in input);
+    let from_follows = |content: &ParseBuffer| matches!(content.fork().parse::<syn::Ident>(), Ok(ident) if ident == "from");
+    let size = if content.is_empty() || from_follows(&content) {
+        Size::Any
+    } else {
+        let size = Size::parse(&content)?;
+        if !content.is_empty() {
+            let _ = content.parse::<Token![,]>()?;
+        }
+        size
+    };
+    if content.is_empty() {
+        return Ok((size, None));
+    }
+
+    let ident = content.parse::<syn::Ident>()?;
+    if ident != "from" {
+        return Err(syn::Error::new(ident.span(), "Expected 'from'"));
+    }
+    let ranges;
+    parenthesized!(ranges in content);
+    let single_char = |string: syn::LitStr| {
+        let mut chars = string.value().chars().collect::<Vec<_>>();
+        match (chars.pop(), chars.is_empty()) {
+            (Some(c), true) => Ok(u32::from(c)),
+            _ => Err(syn::Error::new(
+                string.span(),
+                "Expected a single character",
+            )),
+        }
+    };
+    let mut alphabet = Vec::new();
+    loop {
+        let start = ranges.parse::<syn::LitStr>()?;
+        if ranges.peek(Token![..]) {
+            let _ = ranges.parse::<Token![..]>()?;
+            let end = ranges.parse::<syn::LitStr>()?;
+            alphabet.push((single_char(start)?, single_char(end)?));
+        } else {
+            alphabet.extend(start.value().chars().map(|c| (u32::from(c), u32::from(c))));
+        }
+        if ranges.is_empty() {
+            break;
+        }
+        let _ = ranges.parse::<Token![|]>()?;
+    }
+    Ok((size, Some(PermittedAlphabet::new(alphabet))))
+}
+
 fn eof_or_comma<T: Display>(input: &ParseBuffer, msg: T) -> syn::Result<()> {
     if !input.cursor().eof() && !input.peek(syn::token::Comma) {
         Err(input.error(msg))
@@ -200,6 +366,9 @@ fn eof_or_comma<T: Display>(input: &ParseBuffer, msg: T) -> syn::Result<()> {
 }
 
 pub trait PrimaryContext: Sized {
+    /// Whether the primary attribute can be omitted in favour of further attributes
+    const OPTIONAL: bool = false;
+
     fn parse(input: &ParseBuffer<'_>) -> syn::Result<Self>;
 }
 
@@ -217,7 +386,12 @@ impl PrimaryContext for Type {
 }
 
 impl PrimaryContext for Option<usize> {
+    const OPTIONAL: bool = true;
+
     fn parse(input: &ParseBuffer<'_>) -> syn::Result<Self> {
+        if input.peek(syn::Ident) {
+            return Ok(None);
+        }
         input
             .step(|c| {
                 ident_or_literal_or_punct(*c)
@@ -242,6 +416,11 @@ pub trait Context {
     const EXTENSIBLE_AFTER: bool;
     const TAGGABLE: bool;
     const CONSTS: bool;
+    const IDENTIFIER: bool;
+    const UNKNOWN_EXTENSIONS: bool;
+    const DEFAULT: bool;
+    const KEY: bool;
+    const BOXABLE: bool;
 }
 
 impl Context for Choice {
@@ -249,6 +428,11 @@ impl Context for Choice {
     const EXTENSIBLE_AFTER: bool = true;
     const TAGGABLE: bool = true;
     const CONSTS: bool = false;
+    const IDENTIFIER: bool = false;
+    const UNKNOWN_EXTENSIONS: bool = false;
+    const DEFAULT: bool = false;
+    const KEY: bool = false;
+    const BOXABLE: bool = false;
 }
 
 impl Context for ChoiceVariant {
@@ -256,6 +440,11 @@ impl Context for ChoiceVariant {
     const EXTENSIBLE_AFTER: bool = false;
     const TAGGABLE: bool = true;
     const CONSTS: bool = false;
+    const IDENTIFIER: bool = true;
+    const UNKNOWN_EXTENSIONS: bool = false;
+    const DEFAULT: bool = false;
+    const KEY: bool = true;
+    const BOXABLE: bool = true;
 }
 
 impl Context for Enumerated {
@@ -263,6 +452,11 @@ impl Context for Enumerated {
     const EXTENSIBLE_AFTER: bool = true;
     const TAGGABLE: bool = true;
     const CONSTS: bool = false;
+    const IDENTIFIER: bool = false;
+    const UNKNOWN_EXTENSIONS: bool = false;
+    const DEFAULT: bool = false;
+    const KEY: bool = false;
+    const BOXABLE: bool = false;
 }
 
 impl Context for EnumeratedVariant {
@@ -270,6 +464,11 @@ impl Context for EnumeratedVariant {
     const EXTENSIBLE_AFTER: bool = false;
     const TAGGABLE: bool = false;
     const CONSTS: bool = false;
+    const IDENTIFIER: bool = true;
+    const UNKNOWN_EXTENSIONS: bool = false;
+    const DEFAULT: bool = false;
+    const KEY: bool = false;
+    const BOXABLE: bool = false;
 }
 
 pub struct Transparent;
@@ -278,6 +477,11 @@ impl Context for Transparent {
     const EXTENSIBLE_AFTER: bool = false;
     const TAGGABLE: bool = true;
     const CONSTS: bool = true;
+    const IDENTIFIER: bool = true;
+    const UNKNOWN_EXTENSIONS: bool = false;
+    const DEFAULT: bool = true;
+    const KEY: bool = true;
+    const BOXABLE: bool = true;
 }
 
 pub struct DefinitionHeader(String);
@@ -286,6 +490,11 @@ impl Context for DefinitionHeader {
     const EXTENSIBLE_AFTER: bool = true;
     const TAGGABLE: bool = true;
     const CONSTS: bool = false;
+    const IDENTIFIER: bool = false;
+    const UNKNOWN_EXTENSIONS: bool = true;
+    const DEFAULT: bool = false;
+    const KEY: bool = false;
+    const BOXABLE: bool = false;
 }
 
 impl Deref for DefinitionHeader {
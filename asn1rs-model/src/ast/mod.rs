@@ -6,10 +6,11 @@ mod tag;
 
 use crate::ast::attribute::{Context, DefinitionHeader, Transparent};
 use crate::ast::constants::ConstLit;
+use crate::model::information_object::{OpenType, OpenTypeVariant};
 use crate::model::{
-    Asn as AsnModelType, ComponentTypeList, EnumeratedVariant, TagProperty, TagResolver,
+    Asn as AsnModelType, ComponentTypeList, EnumeratedVariant, TagDefault, TagProperty, TagResolver,
 };
-use crate::model::{Choice, ChoiceVariant, Definition, Enumerated, Field, Model, Type};
+use crate::model::{Choice, ChoiceVariant, Definition, Enumerated, Field, Model, Tagging, Type};
 use attribute::AsnAttribute;
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -65,8 +66,15 @@ pub fn expand(definition: Option<Definition<AsnModelType>>) -> Vec<TokenStream>
     let mut model: Model<AsnModelType> = Model {
         name: "__proc_macro".to_string(),
         oid: None,
+        // the fields of the definition are tagged automatically, unless any of them is tagged
+        tag_default: TagDefault::Automatic,
+        extensibility_implied: false,
         imports: vec![],
         definitions: vec![],
+        value_references: vec![],
+        classes: Vec::default(),
+        objects: Vec::default(),
+        object_sets: Vec::default(),
     };
 
     if let Some(definition) = definition {
@@ -123,6 +131,9 @@ pub fn parse_asn_definition(
         Item::Enum(enm) if asn.primary.eq_ignore_ascii_case("choice") => {
             parse_choice(enm, &asn, attr_span)
         }
+        Item::Enum(enm) if asn.primary.eq_ignore_ascii_case("open_type") => {
+            parse_open_type(enm, &asn, attr_span)
+        }
         item => Ok((None, item)),
     }
 }
@@ -133,9 +144,17 @@ fn parse_sequence_or_set<F: Fn(ComponentTypeList) -> Type>(
     asn_span: proc_macro2::Span,
     mapper: F,
 ) -> Result<(Option<Definition<AsnModelType>>, Item), TokenStream> {
+    let unknown_extensions = find_unknown_extensions(
+        asn,
+        asn_span,
+        strct.fields.iter().flat_map(|field| field.ident.as_ref()),
+    )?;
     let fields = strct
         .fields
         .iter_mut()
+        .filter(|field| !is_unknown_extensions(&unknown_extensions, field.ident.as_ref()))
+        .collect::<Vec<_>>()
+        .into_iter()
         .map(|field| {
             if field.ident.is_none() {
                 compile_err_ts(
@@ -144,15 +163,13 @@ fn parse_sequence_or_set<F: Fn(ComponentTypeList) -> Type>(
                 )?;
             }
 
-            parse_and_remove_first_asn_attribute_type::<Transparent>(
-                field.span(),
-                &field.ty,
-                &mut field.attrs,
-            )
-            .map(|asn| Field {
-                name: field.ident.as_ref().unwrap().to_string(),
-                role: asn,
-            })
+            let ident = field.ident.as_ref().unwrap().to_string();
+            parse_and_remove_first_asn_attribute::<Transparent>(field.span(), &mut field.attrs)
+                .and_then(|asn| {
+                    let name = asn.identifier.clone().unwrap_or_else(|| ident.clone());
+                    into_asn_or_err(field.span(), &field.ty, asn)
+                        .map(|role| (ident, Field { name, role }))
+                })
         })
         .vec_result()?;
 
@@ -163,11 +180,14 @@ fn parse_sequence_or_set<F: Fn(ComponentTypeList) -> Type>(
                 extension_after: find_extensible_index(
                     asn,
                     asn_span,
-                    fields.iter().map(|v| &v.name),
+                    fields.iter().map(|(ident, _)| ident),
                 )?,
-                fields,
+                fields: fields.into_iter().map(|(_, field)| field).collect(),
+                unknown_extensions,
+                components_of: Vec::default(),
             })
-            .opt_tagged(asn.tag),
+            .opt_tagged(asn.tag)
+            .with_tagging(asn.tagging),
         )),
         Item::Struct(strct),
     ))
@@ -207,8 +227,12 @@ fn parse_enumerated(
     asn: &AsnAttribute<DefinitionHeader>,
     asn_span: proc_macro2::Span,
 ) -> Result<(Option<Definition<AsnModelType>>, Item), TokenStream> {
+    let unknown_extensions =
+        find_unknown_extensions(asn, asn_span, enm.variants.iter().map(|v| &v.ident))?;
+
     enm.variants
         .iter()
+        .filter(|v| !is_unknown_extensions(&unknown_extensions, Some(&v.ident)))
         .find(|v| !v.fields.is_empty())
         .map(|v| {
             compile_err_ts(
@@ -221,8 +245,11 @@ fn parse_enumerated(
     let variants = enm
         .variants
         .iter_mut()
+        .filter(|v| !is_unknown_extensions(&unknown_extensions, Some(&v.ident)))
+        .collect::<Vec<_>>()
+        .into_iter()
         .map(|v| {
-            let variant = EnumeratedVariant::from_name(v.ident.to_string());
+            let ident = v.ident.to_string();
             let attributes = index_of_first_asn_attribute(&v.attrs).map(|_index| {
                 parse_and_remove_first_asn_attribute::<EnumeratedVariant>(v.span(), &mut v.attrs)
             });
@@ -232,21 +259,30 @@ fn parse_enumerated(
                         compile_err_ts(v.span(), "ENUMERATED Variants must not have a Tag")?;
                     }
 
-                    Ok(variant.with_number_opt(attr.primary))
+                    let name = attr.identifier.unwrap_or_else(|| ident.clone());
+                    let variant = EnumeratedVariant::from_name(name);
+                    Ok((ident, variant.with_number_opt(attr.primary)))
                 })
             } else {
-                Ok(variant)
+                let variant = EnumeratedVariant::from_name(&ident);
+                Ok((ident, variant))
             }
         })
         .vec_result()?;
 
-    let extension_after = find_extensible_index(asn, asn_span, variants.iter().map(|v| v.name()))?;
-    let enumerated = Enumerated::from_variants(variants).with_extension_after(extension_after);
+    let extension_after =
+        find_extensible_index(asn, asn_span, variants.iter().map(|(ident, _)| ident))?;
+    let enumerated =
+        Enumerated::from_variants(variants.into_iter().map(|(_, v)| v).collect::<Vec<_>>())
+            .with_extension_after(extension_after)
+            .with_unknown_extensions(unknown_extensions);
 
     Ok((
         Some(Definition(
             enm.ident.to_string(),
-            Type::Enumerated(enumerated).opt_tagged(asn.tag),
+            Type::Enumerated(enumerated)
+                .opt_tagged(asn.tag)
+                .with_tagging(asn.tagging),
         )),
         Item::Enum(enm),
     ))
@@ -257,8 +293,12 @@ fn parse_choice(
     asn: &AsnAttribute<DefinitionHeader>,
     asn_span: proc_macro2::Span,
 ) -> Result<(Option<Definition<AsnModelType>>, Item), TokenStream> {
+    let unknown_extensions =
+        find_unknown_extensions(asn, asn_span, enm.variants.iter().map(|v| &v.ident))?;
+
     enm.variants
         .iter()
+        .filter(|v| !is_unknown_extensions(&unknown_extensions, Some(&v.ident)))
         .find(|v| v.fields.is_empty())
         .map(|v| {
             compile_err_ts(
@@ -268,45 +308,125 @@ fn parse_choice(
         })
         .transpose()?;
 
+    let variants =
+        enm.variants
+            .iter_mut()
+            .filter(|v| !is_unknown_extensions(&unknown_extensions, Some(&v.ident)))
+            .collect::<Vec<_>>()
+            .into_iter()
+            .map(|v| {
+                if v.fields.len() != 1 || v.fields.iter().next().unwrap().ident.is_some() {
+                    compile_err_ts(
+                        v.span(),
+                        "Variants of CHOICE have to have exactly one unnamed field",
+                    )?;
+                }
+
+                let ident = v.ident.to_string();
+                let ty = &v.fields.iter().next().unwrap().ty;
+                parse_and_remove_first_asn_attribute::<ChoiceVariant>(v.span(), &mut v.attrs)
+                    .and_then(|asn| {
+                        let name = asn.identifier.clone().unwrap_or_else(|| ident.clone());
+                        into_asn_or_err(v.span(), ty, asn).map(|asn| {
+                            // TODO extensible
+                            // TODO tags
+                            let variant = ChoiceVariant {
+                                name,
+                                tag: asn.tag,
+                                tagging: asn.tagging,
+                                r#type: asn.r#type,
+                                boxed: asn.boxed,
+                            };
+                            (ident, variant)
+                        })
+                    })
+            })
+            .vec_result()?;
+
+    let extensible_after =
+        find_extensible_index(&asn, asn_span, variants.iter().map(|(ident, _)| ident))?;
+
+    let choice = Type::Choice(
+        Choice::from_variants(variants.into_iter().map(|(_, variant)| variant))
+            .with_extension_after(extensible_after)
+            .with_unknown_extensions(unknown_extensions),
+    );
+
+    let tag = asn.tag.or_else(|| TagResolver::resolve_default(&choice));
+    // the tag of the attribute is only the explicit tag of the type assignment if marked as such
+    let tagging = asn.tagging.or(Some(Tagging::Implicit));
+
+    Ok((
+        Some(Definition(
+            enm.ident.to_string(),
+            choice.opt_tagged(tag).with_tagging(tagging),
+        )),
+        Item::Enum(enm),
+    ))
+}
+
+fn parse_open_type(
+    mut enm: syn::ItemEnum,
+    asn: &AsnAttribute<DefinitionHeader>,
+    asn_span: proc_macro2::Span,
+) -> Result<(Option<Definition<AsnModelType>>, Item), TokenStream> {
+    // any key can be unknown to an object set, regardless of its extensibility
+    let unknown_extensions = match &asn.unknown_extensions {
+        Some(name) if !enm.variants.iter().any(|v| v.ident.eq(name)) => {
+            compile_err_ts(
+                asn_span,
+                "Cannot find variant for unknown_extensions attribute",
+            )?;
+            None
+        }
+        unknown_extensions => unknown_extensions.clone(),
+    };
+
     let variants = enm
         .variants
         .iter_mut()
+        .filter(|v| !is_unknown_extensions(&unknown_extensions, Some(&v.ident)))
+        .collect::<Vec<_>>()
+        .into_iter()
         .map(|v| {
             if v.fields.len() != 1 || v.fields.iter().next().unwrap().ident.is_some() {
                 compile_err_ts(
                     v.span(),
-                    "Variants of CHOICE have to have exactly one unnamed field",
+                    "Variants of an open type have to have exactly one unnamed field",
                 )?;
             }
 
-            parse_and_remove_first_asn_attribute_type::<ChoiceVariant>(
-                v.span(),
-                &v.fields.iter().next().unwrap().ty,
-                &mut v.attrs,
+            let span = v.span();
+            let name = v.ident.to_string();
+            let ty = &v.fields.iter().next().unwrap().ty;
+            parse_and_remove_first_asn_attribute::<ChoiceVariant>(span, &mut v.attrs).and_then(
+                |asn| {
+                    let key = asn
+                        .key
+                        .as_deref()
+                        .map(i64::from_str)
+                        .transpose()
+                        .map_err(|e| compile_error_ts(span, format!("Invalid key: {}", e)))?
+                        .ok_or_else(|| compile_error_ts(span, "Missing key of variant"))?;
+                    into_asn_or_err(span, ty, asn).map(|asn| OpenTypeVariant {
+                        key,
+                        name,
+                        r#type: asn.r#type,
+                    })
+                },
             )
-            .map(|asn| {
-                // TODO extensible
-                // TODO tags
-                ChoiceVariant {
-                    name: v.ident.to_string(),
-                    tag: asn.tag,
-                    r#type: asn.r#type,
-                }
-            })
         })
         .vec_result()?;
 
-    let extensible_after =
-        find_extensible_index(&asn, asn_span, variants.iter().map(|v| v.name()))?;
-
-    let choice = Type::Choice(
-        Choice::from_variants(variants.into_iter()).with_extension_after(extensible_after),
-    );
-
-    let tag = asn.tag.or_else(|| TagResolver::resolve_default(&choice));
-
     Ok((
-        Some(Definition(enm.ident.to_string(), choice.opt_tagged(tag))),
+        Some(Definition(
+            enm.ident.to_string(),
+            Type::OpenType(OpenType {
+                variants,
+                unknown_extensions,
+            })
+            .untagged(),
+        )),
         Item::Enum(enm),
     ))
 }
@@ -335,6 +455,33 @@ fn find_extensible_index(
         .transpose()
 }
 
+/// The field or variant which preserves extensions unknown to the definition is not part of
+/// the ASN.1 definition itself
+fn find_unknown_extensions(
+    asn: &AsnAttribute<DefinitionHeader>,
+    asn_span: proc_macro2::Span,
+    mut idents: impl Iterator<Item = impl ToString>,
+) -> Result<Option<String>, TokenStream> {
+    match &asn.unknown_extensions {
+        Some(_) if asn.extensible_after.is_none() => Err(compile_error_ts(
+            asn_span,
+            "Only extensible definitions can preserve unknown extensions",
+        )),
+        Some(name) if !idents.any(|ident| ident.to_string().eq(name)) => Err(compile_error_ts(
+            asn_span,
+            "Cannot find field or variant for unknown_extensions attribute",
+        )),
+        unknown_extensions => Ok(unknown_extensions.clone()),
+    }
+}
+
+fn is_unknown_extensions(unknown_extensions: &Option<String>, ident: Option<&syn::Ident>) -> bool {
+    unknown_extensions
+        .as_ref()
+        .zip(ident)
+        .is_some_and(|(name, ident)| ident.eq(name))
+}
+
 fn parse_and_remove_first_asn_attribute_type<C: Context<Primary = Type>>(
     span: proc_macro2::Span,
     ty: &syn::Type,
@@ -367,10 +514,22 @@ fn into_asn<C: Context<Primary = Type>>(
     ty: &syn::Type,
     mut asn: AsnAttribute<C>,
 ) -> Option<AsnModelType> {
+    // the tag of an open type is indeterminate, a tag belongs to the keyed field only
+    let type_tag = if asn.key.is_some() { None } else { asn.tag };
     Some(AsnModelType {
         tag: asn.tag,
-        r#type: if let Type::TypeReference(_, empty_tag) = asn.primary {
-            Type::TypeReference(quote! { #ty }.to_string(), empty_tag.or(asn.tag))
+        tagging: asn.tagging,
+        default: asn.default,
+        key: asn.key,
+        boxed: asn.boxed,
+        r#type: if let Type::TypeReference(name, empty_tag) = asn.primary {
+            // the type of a boxed value is not the referenced type itself
+            let name = if asn.boxed {
+                name
+            } else {
+                quote! { #ty }.to_string()
+            };
+            Type::TypeReference(name, empty_tag.or(type_tag))
         } else {
             if let Type::Integer(int) = asn.primary.no_optional_mut() {
                 asn.consts
@@ -1,4 +1,8 @@
+use crate::model::constraint::{
+    ComponentConstraint, ElementSet, InnerTypeConstraints, Presence, ValueConstraint,
+};
 use syn::buffer::Cursor;
+use syn::ext::IdentExt;
 use syn::parse::{Parse, ParseStream};
 use syn::Ident;
 use syn::Lit;
@@ -7,17 +11,28 @@ use syn::Token;
 #[derive(Debug)]
 enum MMV {
     MinMax,
-    Value(i64),
+    Value(i128),
 }
 
 impl MMV {
+    fn value(self) -> Option<i128> {
+        match self {
+            MMV::MinMax => None,
+            MMV::Value(value) => Some(value),
+        }
+    }
+
     pub fn try_parse(input: ParseStream) -> syn::Result<Option<Self>> {
+        let negative = input.peek(Token![-]) && input.parse::<Token![-]>().is_ok();
         if let Ok(Lit::Int(int)) = input.parse::<Lit>() {
-            Ok(Some(MMV::Value(
-                int.base10_digits()
-                    .parse::<i64>()
-                    .map_err(|_| input.error("Expected int literal for from value of range"))?,
-            )))
+            let digits = if negative {
+                format!("-{}", int.base10_digits())
+            } else {
+                int.base10_digits().to_string()
+            };
+            Ok(Some(MMV::Value(digits.parse::<i128>().map_err(|_| {
+                input.error("Expected int literal for from value of range")
+            })?)))
         } else if let Ok(ident) = input.parse::<Ident>() {
             let lc = ident.to_string().to_lowercase();
             if lc == "min" || lc == "max" {
@@ -31,15 +46,13 @@ impl MMV {
     }
 }
 
+/// The full constraint of an integer (`1 | 3 | 5..10`) and whether it is extensible
 #[derive(Debug)]
-pub struct IntegerRange(pub Option<(i64, i64)>, pub bool);
+pub struct IntegerConstraint(pub ElementSet<i128>, pub bool);
 
-impl Parse for IntegerRange {
+impl Parse for IntegerConstraint {
     fn parse<'a>(input: ParseStream) -> syn::Result<Self> {
-        let min = MMV::try_parse(input)?.ok_or_else(|| input.error("invalid min"))?;
-        let _ = input.parse::<Token![.]>()?;
-        let _ = input.parse::<Token![.]>()?;
-        let max = MMV::try_parse(input)?.ok_or_else(|| input.error("invalid max"))?;
+        let set = parse_element_set(input)?;
         let extensible = if input.peek(Token![,]) {
             let _ = input.parse::<Token![,]>()?;
             let _ = input.parse::<Token![.]>()?;
@@ -49,27 +62,171 @@ impl Parse for IntegerRange {
         } else {
             false
         };
+        Ok(IntegerConstraint(set, extensible))
+    }
+}
 
-        match (min, max) {
-            (MMV::MinMax, MMV::MinMax) | (MMV::Value(0), MMV::MinMax) => {
-                Ok(IntegerRange(None, extensible))
-            }
-            (MMV::Value(min), MMV::Value(max)) => Ok(IntegerRange(Some((min, max)), extensible)),
-            (MMV::MinMax, MMV::Value(max)) => Ok(IntegerRange(
-                Some((
-                    if max.is_positive() {
-                        0
-                    } else {
-                        i64::max_value().wrapping_add(1)
-                    },
-                    max,
-                )),
-                extensible,
-            )),
-            (MMV::Value(min), MMV::MinMax) => {
-                Ok(IntegerRange(Some((min, i64::max_value())), extensible))
+/// The inner subtype constraint `{..., a present, b (1 | 3) absent, c ({d optional})}` or
+/// `{(1 | 3)}` for the elements of a `SEQUENCE OF` or `SET OF`
+#[derive(Debug)]
+pub struct InnerTypeConstraint(pub InnerTypeConstraints<i128>);
+
+impl Parse for InnerTypeConstraint {
+    fn parse<'a>(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        syn::braced!(content in input);
+        parse_inner_type_constraints(&content).map(InnerTypeConstraint)
+    }
+}
+
+fn parse_inner_type_constraints(input: ParseStream) -> syn::Result<InnerTypeConstraints<i128>> {
+    if input.peek(syn::token::Paren) {
+        let content;
+        syn::parenthesized!(content in input);
+        return Ok(InnerTypeConstraints::for_elements(parse_value_constraint(
+            &content,
+        )?));
+    }
+    let partial = input.peek(Token![.]);
+    if partial {
+        let _ = input.parse::<Token![.]>()?;
+        let _ = input.parse::<Token![.]>()?;
+        let _ = input.parse::<Token![.]>()?;
+    }
+    let mut components = Vec::new();
+    while !input.is_empty() {
+        if partial || !components.is_empty() {
+            let _ = input.parse::<Token![,]>()?;
+        }
+        components.push(parse_component_constraint(input)?);
+    }
+    Ok(InnerTypeConstraints {
+        partial,
+        components,
+        element: None,
+    })
+}
+
+fn parse_value_constraint(input: ParseStream) -> syn::Result<ValueConstraint<i128>> {
+    if input.peek(syn::token::Brace) {
+        let inner;
+        syn::braced!(inner in input);
+        Ok(ValueConstraint::Components(parse_inner_type_constraints(
+            &inner,
+        )?))
+    } else {
+        Ok(ValueConstraint::Integer(parse_element_set(input)?))
+    }
+}
+
+fn parse_component_constraint(input: ParseStream) -> syn::Result<ComponentConstraint<i128>> {
+    // identifiers of ASN.1 can contain hyphens
+    let mut name = Ident::parse_any(input)?.to_string();
+    while input.peek(Token![-]) {
+        let _ = input.parse::<Token![-]>()?;
+        name.push('-');
+        name.push_str(&Ident::parse_any(input)?.to_string());
+    }
+    let value = if input.peek(syn::token::Paren) {
+        let content;
+        syn::parenthesized!(content in input);
+        Some(parse_value_constraint(&content)?)
+    } else {
+        None
+    };
+    let presence = [
+        ("present", Presence::Present),
+        ("absent", Presence::Absent),
+        ("optional", Presence::Optional),
+    ]
+    .iter()
+    .find(|(keyword, _)| peek_keyword(input, keyword))
+    .map(|(_, presence)| *presence);
+    if presence.is_some() {
+        let _ = input.parse::<Ident>()?;
+    }
+    Ok(ComponentConstraint {
+        name,
+        value,
+        presence,
+    })
+}
+
+fn peek_keyword(input: ParseStream, keyword: &str) -> bool {
+    matches!(
+        input.fork().parse::<Ident>(),
+        Ok(ident) if ident.to_string().eq_ignore_ascii_case(keyword)
+    )
+}
+
+fn parse_element_set(input: ParseStream) -> syn::Result<ElementSet<i128>> {
+    if peek_keyword(input, "all") {
+        let _ = input.parse::<Ident>()?;
+        if !peek_keyword(input, "except") {
+            return Err(input.error("Expected `except` after `all`"));
+        }
+        let _ = input.parse::<Ident>()?;
+        let except = parse_elements(input)?;
+        return Ok(ElementSet::Except(
+            Box::new(ElementSet::All),
+            Box::new(except),
+        ));
+    }
+
+    let mut unions = Vec::new();
+    loop {
+        let mut intersections = Vec::new();
+        loop {
+            let elements = parse_elements(input)?;
+            intersections.push(if peek_keyword(input, "except") {
+                let _ = input.parse::<Ident>()?;
+                ElementSet::Except(Box::new(elements), Box::new(parse_elements(input)?))
+            } else {
+                elements
+            });
+            if input.peek(Token![^]) {
+                let _ = input.parse::<Token![^]>()?;
+            } else {
+                break;
             }
         }
+        unions.push(single_or(intersections, ElementSet::Intersection));
+        if input.peek(Token![|]) {
+            let _ = input.parse::<Token![|]>()?;
+        } else {
+            break;
+        }
+    }
+    Ok(single_or(unions, ElementSet::Union))
+}
+
+fn parse_elements(input: ParseStream) -> syn::Result<ElementSet<i128>> {
+    if input.peek(syn::token::Paren) {
+        let content;
+        syn::parenthesized!(content in input);
+        return parse_element_set(&content);
+    }
+    let min = MMV::try_parse(input)?.ok_or_else(|| input.error("invalid min"))?;
+    if input.peek(Token![.]) {
+        let _ = input.parse::<Token![.]>()?;
+        let _ = input.parse::<Token![.]>()?;
+        let max = MMV::try_parse(input)?.ok_or_else(|| input.error("invalid max"))?;
+        Ok(ElementSet::Range(min.value(), max.value()))
+    } else {
+        min.value()
+            .map(ElementSet::Value)
+            .ok_or_else(|| input.error("Expected a value"))
+    }
+}
+
+fn single_or(
+    mut sets: Vec<ElementSet<i128>>,
+    f: impl FnOnce(Vec<ElementSet<i128>>) -> ElementSet<i128>,
+) -> ElementSet<i128> {
+    if sets.len() == 1 {
+        sets.remove(0)
+    } else {
+        f(sets)
     }
 }
 
@@ -7,33 +7,27 @@ use syn::Token;
 impl Parse for Size {
     fn parse<'a>(input: ParseStream) -> syn::Result<Self> {
         let min = value(input)?.ok_or_else(|| input.error("invalid min"))?;
-        if input.peek(syn::token::Paren) {
-            Ok(Size::Fix(min, false))
-        } else if input.peek(Token![,]) {
-            let _ = input.parse::<Token![,]>()?;
-            let _ = input.parse::<Token![.]>()?;
+        let max = if input.peek(Token![.]) {
             let _ = input.parse::<Token![.]>()?;
             let _ = input.parse::<Token![.]>()?;
-            Ok(Size::Fix(min, true))
+            value(input)?.ok_or_else(|| input.error("invalid max"))?
         } else {
+            min
+        };
+        let extensible = if input.peek(Token![,]) && input.peek2(Token![.]) {
+            let _ = input.parse::<Token![,]>()?;
             let _ = input.parse::<Token![.]>()?;
             let _ = input.parse::<Token![.]>()?;
-            let max = value(input)?.ok_or_else(|| input.error("invalid max"))?;
-            let extensible = if input.peek(Token![,]) && input.peek2(Token![.]) {
-                let _ = input.parse::<Token![,]>()?;
-                let _ = input.parse::<Token![.]>()?;
-                let _ = input.parse::<Token![.]>()?;
-                let _ = input.parse::<Token![.]>()?;
-                true
-            } else {
-                false
-            };
+            let _ = input.parse::<Token![.]>()?;
+            true
+        } else {
+            false
+        };
 
-            if min == max {
-                Ok(Size::Fix(min, extensible))
-            } else {
-                Ok(Size::Range(min, max, extensible))
-            }
+        if min == max {
+            Ok(Size::Fix(min, extensible))
+        } else {
+            Ok(Size::Range(min, max, extensible))
         }
     }
 }
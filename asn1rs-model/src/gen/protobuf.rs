@@ -70,6 +70,13 @@ impl ProtobufDefGenerator {
         for import in &model.imports {
             writeln!(target, "import '{}';", Self::model_file_name(&import.from))?;
         }
+        if model
+            .definitions
+            .iter()
+            .any(|Definition(_, protobuf)| protobuf.uses_empty())
+        {
+            writeln!(target, "import 'google/protobuf/empty.proto';")?;
+        }
         writeln!(target)?;
         Ok(())
     }
@@ -155,6 +162,7 @@ impl ProtobufDefGenerator {
             ProtobufType::Repeated(inner) => {
                 format!("repeated {}", Self::role_to_full_type(inner, model))
             }
+            ProtobufType::Empty => "google.protobuf.Empty".to_string(),
             r => r.to_string(),
         }
     }
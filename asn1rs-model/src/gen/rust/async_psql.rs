@@ -18,9 +18,19 @@ impl GeneratorSupplement<Rust> for AsyncPsqlInserter {
         scope.import("asn1rs::io", &format!("async_psql as {}", MODULE_NAME));
     }
 
-    fn impl_supplement(&self, _scope: &mut Scope, _definition: &Definition<Rust>) {}
+    fn impl_supplement(&self, scope: &mut Scope, definition: &Definition<Rust>) {
+        // the implementations are extended by the functions below, which skip the definitions
+        // that are rejected here
+        reject_unsupported(scope, definition);
+    }
 
     fn extend_impl_of_struct<'a>(&self, name: &str, impl_scope: &mut Impl, fields: &[Field]) {
+        if fields
+            .iter()
+            .any(|field| find_unsupported_type(field.r#type()).is_some())
+        {
+            return;
+        }
         AsyncPsqlInserter::append_retrieve_many_for_container_type(name, impl_scope);
         AsyncPsqlInserter::append_retrieve_for_container_type(name, impl_scope);
         AsyncPsqlInserter::append_load_struct(name, impl_scope, fields);
@@ -50,6 +60,12 @@ impl GeneratorSupplement<Rust> for AsyncPsqlInserter {
     }
 
     fn extend_impl_of_data_enum(&self, name: &str, impl_scope: &mut Impl, enumeration: &DataEnum) {
+        if enumeration
+            .variants()
+            .any(|variant| find_unsupported_type(variant.r#type()).is_some())
+        {
+            return;
+        }
         Self::append_retrieve_many_for_container_type(name, impl_scope);
         Self::append_retrieve_for_container_type(name, impl_scope);
 
@@ -92,6 +108,9 @@ impl GeneratorSupplement<Rust> for AsyncPsqlInserter {
     }
 
     fn extend_impl_of_tuple(&self, name: &str, impl_scope: &mut Impl, definition: &RustType) {
+        if find_unsupported_type(definition).is_some() {
+            return;
+        }
         let fields = [("0".to_string(), definition.clone())];
 
         // append_default_retrieve_many_fn(impl_scope);
@@ -317,7 +336,13 @@ fn insert_field(
             field_name_as_variable,
         )
     } else {
-        insert_complex_field(on_self, container, field_name, field_name_as_variable)
+        insert_complex_field(
+            on_self,
+            container,
+            field_name,
+            field_name_as_variable,
+            r_type.is_boxed(),
+        )
     }
 }
 
@@ -403,11 +428,7 @@ fn insert_optional_field_maybe_async(
         if Model::<Sql>::is_primitive(inner) {
             if inner.is_primitive() && !inner.as_no_option().to_sql().to_rust().similar(inner) {
                 let conversion = inner.as_no_option().to_sql().to_rust();
-                block_some_inner.line(&format!(
-                    "{} as {}",
-                    variable_name,
-                    conversion.to_inner_type_string()
-                ));
+                block_some_inner.line(&cast(&variable_name, &conversion));
             } else {
                 block_some_inner.line(variable_name);
             }
@@ -462,7 +483,8 @@ fn insert_vec_field(
         ));
     } else {
         many_insert.line(&format!(
-            "let inserted = {}::try_join_all({}{}.iter().map(|v| v.{}(context)));",
+            // the elements might be of a recursive type, whose futures need to be on the heap
+            "let inserted = {}::try_join_all({}{}.iter().map(|v| Box::pin(v.{}(context))));",
             MODULE_NAME,
             if on_self { "self." } else { "" },
             field_name,
@@ -490,7 +512,7 @@ fn insert_vec_field(
     many_insert.line(&format!(
         "{}::try_join_all(inserted.iter().map(|i| async move {{ context.query(prepared, &[&id, {}]).await }} )).await",
         MODULE_NAME,
-        if conversion { format!("&(*i as {})", r_type.to_sql().to_rust().to_inner_type_string()) } else { "&i".to_string() }
+        if conversion { format!("&({})", cast("*i", &r_type.to_sql().to_rust())) } else { "&i".to_string() }
     ));
     many_insert.after(".await?;");
     container.push_block(many_insert);
@@ -511,17 +533,19 @@ fn insert_sql_primitive_field(
         None
     };
     let variable = field_name_as_variable.unwrap_or(field_name).to_string();
-    container.line(&format!(
-        "let {} = {}{}{}{}{};",
-        variable,
+    let value = format!(
+        "{}{}{}",
         if r_type.is_primitive() { "" } else { "&" },
         if on_self { "self." } else { "" },
         field_name,
-        conversion.as_ref().map(|_| " as ").unwrap_or_default(),
-        conversion
-            .as_ref()
-            .map(|r| r.to_string())
-            .unwrap_or_default(),
+    );
+    container.line(&format!(
+        "let {} = {};",
+        variable,
+        match &conversion {
+            Some(conversion) => cast(&value, conversion),
+            None => value,
+        }
     ));
     FieldInsert::Primitive(variable, conversion)
 }
@@ -531,14 +555,24 @@ fn insert_complex_field(
     container: &mut impl Container,
     field_name: &str,
     field_name_as_variable: Option<&str>,
+    boxed: bool,
 ) -> FieldInsert {
     let variable_name = field_name_as_variable.unwrap_or(field_name).to_string();
-    container.line(&format!(
-        "let {} = {}{}.{}(context);",
-        variable_name,
+    let insert = format!(
+        "{}{}.{}(context)",
         if on_self { "self." } else { "" },
         field_name,
         insert_fn_name()
+    );
+    container.line(&format!(
+        "let {} = {};",
+        variable_name,
+        if boxed {
+            // the future of a recursive call needs to be on the heap as well
+            format!("Box::pin({})", insert)
+        } else {
+            insert
+        }
     ));
     FieldInsert::AsyncComplex(variable_name)
 }
@@ -702,15 +736,18 @@ impl AsyncPsqlInserter {
         f_type: &RustType,
         sql: &SqlType,
     ) {
-        container.line(format!(
-            "let {} = row.try_get::<usize, {}>({})?{};",
-            RustCodeGenerator::rust_field_name(field, true),
+        let value = format!(
+            "row.try_get::<usize, {}>({})?",
             sql.to_rust().to_inner_type_string(),
             index + 1,
+        );
+        container.line(format!(
+            "let {} = {};",
+            RustCodeGenerator::rust_field_name(field, true),
             if !sql.to_rust().similar(f_type) {
-                format!(" as {}", f_type.to_inner_type_string())
+                cast(&value, f_type)
             } else {
-                String::default()
+                value
             }
         ));
     }
@@ -740,14 +777,17 @@ impl AsyncPsqlInserter {
                 "let mut {} = Vec::with_capacity(rows.len());",
                 RustCodeGenerator::rust_field_name(field, true)
             ));
+            let value = format!(
+                "row.try_get::<usize, {}>(0)?",
+                inner.to_sql().to_rust().to_inner_type_string(),
+            );
             container.line(format!(
-                "for row in rows {{ {}.push(row.try_get::<usize, {}>(0)?{}); }}",
+                "for row in rows {{ {}.push({}); }}",
                 RustCodeGenerator::rust_field_name(field, true),
-                inner.to_sql().to_rust().to_inner_type_string(),
                 if !sql.to_rust().similar(f_type) {
-                    format!(" as {}", f_type.to_inner_type_string())
+                    cast(&value, f_type)
                 } else {
-                    String::default()
+                    value
                 }
             ));
         } else {
@@ -771,8 +811,9 @@ impl AsyncPsqlInserter {
                 RustCodeGenerator::rust_field_name(field, true)
             ));
 
+            // the elements might be of a recursive type, whose futures need to be on the heap
             container.line(format!(
-                "for row in rows {{ {}.push({}::{}(context, &row).await?); }}",
+                "for row in rows {{ {}.push(Box::pin({}::{}(context, &row)).await?); }}",
                 RustCodeGenerator::rust_field_name(field, true),
                 inner.to_inner_type_string(),
                 load_fn_name(),
@@ -800,15 +841,18 @@ impl AsyncPsqlInserter {
                 RustCodeGenerator::rust_field_name(field, true),
             ))
         } else if Model::<Sql>::is_primitive(inner) {
-            container.line(format!(
-                "let {} = row.try_get::<usize, Option<{}>>({})?{};",
-                RustCodeGenerator::rust_field_name(field, true),
+            let value = format!(
+                "row.try_get::<usize, Option<{}>>({})?",
                 sql.to_rust().as_no_option().to_inner_type_string(),
                 index + 1,
+            );
+            container.line(format!(
+                "let {} = {};",
+                RustCodeGenerator::rust_field_name(field, true),
                 if !sql.to_rust().similar(f_type) {
-                    format!(".map(|v| v as {})", inner.to_inner_type_string())
+                    cast_option(&value, inner)
                 } else {
-                    String::default()
+                    value
                 }
             ));
         } else {
@@ -829,12 +873,21 @@ impl AsyncPsqlInserter {
     }
 
     fn append_load_complex_field(container: &mut impl Container, field: &str, f_type: &RustType) {
-        container.line(format!(
-            "let {} = {}::{}(context, {}).await?;",
-            RustCodeGenerator::rust_field_name(field, true),
-            f_type.to_inner_type_string(),
+        let load = format!(
+            "{}::{}(context, {})",
+            f_type.clone().into_inner_type().to_string(),
             retrieve_fn_name(),
             RustCodeGenerator::rust_field_name(field, true),
+        );
+        container.line(format!(
+            "let {} = {};",
+            RustCodeGenerator::rust_field_name(field, true),
+            if f_type.is_boxed() {
+                // the future of a recursive call needs to be on the heap as well
+                format!("Box::new(Box::pin({}).await?)", load)
+            } else {
+                format!("{}.await?", load)
+            }
         ));
     }
 }
@@ -15,13 +15,15 @@ pub mod async_psql;
 pub(crate) mod shared_psql;
 
 use crate::gen::Generator;
+use crate::model::constraint::ElementSet;
 use crate::model::rust::{DataEnum, Field};
 use crate::model::rust::{EncodingOrdering, PlainEnum};
+use crate::model::LiteralValue;
 use crate::model::Model;
 use crate::model::Rust;
 use crate::model::RustType;
 use crate::model::TagProperty;
-use crate::model::{Definition, Tag, Type as AsnType, Type};
+use crate::model::{Definition, Tag, Type as AsnType, Type, ValueReference};
 use codegen::Block;
 use codegen::Enum;
 use codegen::Function;
@@ -29,6 +31,7 @@ use codegen::Impl;
 use codegen::Scope;
 use codegen::Struct;
 use std::borrow::Cow;
+use std::fmt::Display;
 
 #[cfg(feature = "psql")]
 use self::psql::PsqlInserter;
@@ -43,6 +46,14 @@ const KEYWORDS: [&str; 9] = [
     "use", "mod", "const", "type", "pub", "enum", "struct", "impl", "trait",
 ];
 
+/// The name of the hidden field of extensible structs, see
+/// [`RustCodeGenerator::set_preserve_unknown_extensions`]
+pub const UNKNOWN_EXTENSIONS_FIELD: &str = "unknown_extensions";
+
+/// The name of the additional variant of extensible enums, see
+/// [`RustCodeGenerator::set_preserve_unknown_extensions`]
+pub const UNKNOWN_EXTENSIONS_VARIANT: &str = "Unknown";
+
 pub trait GeneratorSupplement<T> {
     fn add_imports(&self, scope: &mut Scope);
     fn impl_supplement(&self, scope: &mut Scope, definition: &Definition<T>);
@@ -65,6 +76,8 @@ pub struct RustCodeGenerator {
     global_derives: Vec<String>,
     direct_field_access: bool,
     getter_and_setter: bool,
+    preserve_unknown_extensions: bool,
+    big_integers: bool,
 }
 
 impl Default for RustCodeGenerator {
@@ -74,6 +87,8 @@ impl Default for RustCodeGenerator {
             global_derives: Default::default(),
             direct_field_access: true,
             getter_and_setter: false,
+            preserve_unknown_extensions: false,
+            big_integers: false,
         }
     }
 }
@@ -136,6 +151,29 @@ impl RustCodeGenerator {
         self.getter_and_setter = allow;
     }
 
+    pub const fn preserves_unknown_extensions(&self) -> bool {
+        self.preserve_unknown_extensions
+    }
+
+    /// Whether extensible structs shall have a hidden field for the extended fields unknown to
+    /// their definition and extensible enums an additional variant for unknown extensions. This
+    /// allows to re-encode values of a newer version of a definition without losing data. Only
+    /// the `UperReader` and `UperWriter` make use of them and the protobuf and psql supplements
+    /// do not support them.
+    pub fn set_preserve_unknown_extensions(&mut self, preserve: bool) {
+        self.preserve_unknown_extensions = preserve;
+    }
+
+    pub const fn uses_big_integers(&self) -> bool {
+        self.big_integers
+    }
+
+    /// Whether unconstrained `INTEGER`s shall be of arbitrary precision, which requires the
+    /// feature `bigint` of `asn1rs`. The protobuf and psql supplements do not support them.
+    pub fn set_big_integers(&mut self, big: bool) {
+        self.big_integers = big;
+    }
+
     pub fn model_to_file(
         &self,
         model: &Model<Rust>,
@@ -158,9 +196,33 @@ impl RustCodeGenerator {
             }
         }
 
+        for value_reference in &model.value_references {
+            Self::add_value_reference(&mut scope, value_reference);
+        }
+
         for definition in &model.definitions {
+            let definition = &if self.big_integers {
+                Cow::Owned(definition.with_big_integers())
+            } else {
+                Cow::Borrowed(definition)
+            };
+            let definition = &if self.preserve_unknown_extensions {
+                Cow::Owned(Self::with_unknown_extensions(definition))
+            } else {
+                Cow::Borrowed(&**definition)
+            };
             self.add_definition(&mut scope, definition);
-            Self::impl_definition(&mut scope, definition, generators, self.getter_and_setter);
+            Self::impl_definition(
+                &mut scope,
+                // the impl extensions treat the arcs of object identifiers as a list of integers
+                // and are unaware of tags and contained values
+                &definition
+                    .with_object_identifiers_as_vec()
+                    .without_containing()
+                    .without_explicit_tags(),
+                generators,
+                self.getter_and_setter,
+            );
 
             generators
                 .iter()
@@ -170,56 +232,173 @@ impl RustCodeGenerator {
         (file, scope.to_string())
     }
 
+    /// Names the hidden field or variant that preserves unknown extensions on the given
+    /// definition, if it is extensible or an open type
+    pub fn with_unknown_extensions(Definition(name, rust): &Definition<Rust>) -> Definition<Rust> {
+        let rust = match rust.clone() {
+            Rust::Struct {
+                ordering,
+                fields,
+                tag,
+                extension_after: Some(extension_after),
+                unknown_extensions: _,
+                explicit,
+            } => Rust::Struct {
+                ordering,
+                fields,
+                tag,
+                extension_after: Some(extension_after),
+                unknown_extensions: Some(UNKNOWN_EXTENSIONS_FIELD.to_string()),
+                explicit,
+            },
+            Rust::Enum(plain) if plain.is_extensible() => Rust::Enum(
+                plain.with_unknown_extensions(Some(UNKNOWN_EXTENSIONS_VARIANT.to_string())),
+            ),
+            Rust::DataEnum(data) if data.is_extensible() => Rust::DataEnum(
+                data.with_unknown_extensions(Some(UNKNOWN_EXTENSIONS_VARIANT.to_string())),
+            ),
+            Rust::OpenType(data) => Rust::OpenType(
+                data.with_unknown_extensions(Some(UNKNOWN_EXTENSIONS_VARIANT.to_string())),
+            ),
+            rust => rust,
+        };
+        Definition(name.clone(), rust)
+    }
+
+    pub fn add_value_reference(
+        scope: &mut Scope,
+        ValueReference { name, role, value }: &ValueReference<RustType>,
+    ) {
+        let (r#type, value) = Self::constant_type_and_expression(role, value);
+        scope.raw(&format!("pub const {}: {} = {};", name, r#type, value));
+    }
+
+    /// The type and the expression of a constant with the given value. Strings and octet strings
+    /// are represented by their borrowed counterpart.
+    pub fn constant_type_and_expression(
+        r#type: &RustType,
+        value: &LiteralValue,
+    ) -> (String, String) {
+        match (r#type, value) {
+            (RustType::Constrained(inner, _), value) => {
+                Self::constant_type_and_expression(inner, value)
+            }
+            (RustType::String(..), LiteralValue::String(value)) => {
+                ("&str".to_string(), format!("{:?}", value))
+            }
+            (RustType::VecU8(_), LiteralValue::Bits(bytes, _)) => {
+                ("&[u8]".to_string(), format!("&{:?}", bytes))
+            }
+            (RustType::BigInt(_), LiteralValue::Integer(value)) => {
+                ("i64".to_string(), value.to_string())
+            }
+            (RustType::BitVec(_), _)
+            | (RustType::Complex(..), LiteralValue::String(_))
+            | (RustType::Complex(..), LiteralValue::Bits(..)) => panic!(
+                "{} cannot be represented as constant of {}",
+                value,
+                r#type.to_string()
+            ),
+            (r#type, value) => (
+                r#type.to_string(),
+                Self::default_value_expression(r#type, value),
+            ),
+        }
+    }
+
     pub fn add_definition(&self, scope: &mut Scope, Definition(name, rust): &Definition<Rust>) {
+        let hashable = self.is_hashable(rust, &mut Vec::default());
         match rust {
             Rust::Struct {
                 fields,
                 tag,
                 extension_after,
                 ordering,
+                unknown_extensions,
+                explicit,
             } => {
                 scope.raw(&Self::asn_attribute(
-                    match ordering {
-                        EncodingOrdering::Keep => "sequence",
-                        EncodingOrdering::Sort => "set",
-                    },
+                    Self::asn_attribute_definition_type(
+                        match ordering {
+                            EncodingOrdering::Keep => "sequence",
+                            EncodingOrdering::Sort => "set",
+                        },
+                        *explicit,
+                    ),
                     *tag,
                     extension_after.map(|index| fields[index].name().to_string()),
+                    unknown_extensions.as_deref(),
                     &[],
+                    None,
+                    None,
                 ));
                 Self::add_struct(
-                    self.new_struct(scope, name),
+                    self.new_struct(
+                        scope,
+                        name,
+                        fields.iter().all(|f| f.default().is_none()),
+                        hashable,
+                    ),
                     name,
                     fields,
+                    unknown_extensions.as_deref(),
                     self.direct_field_access,
                 )
             }
             Rust::Enum(plain) => {
                 scope.raw(&Self::asn_attribute(
-                    "enumerated",
+                    Self::asn_attribute_definition_type("enumerated", plain.is_explicit()),
                     plain.tag(),
-                    plain.extension_after_variant().cloned(),
+                    plain
+                        .extension_after_variant()
+                        .map(|v| v.name().to_string()),
+                    plain.unknown_extensions(),
                     &[],
+                    None,
+                    None,
                 ));
-                Self::add_enum(self.new_enum(scope, name, true), name, plain)
+                Self::add_enum(self.new_enum(scope, name, true, hashable), name, plain)
             }
             Rust::DataEnum(data) => {
                 scope.raw(&Self::asn_attribute(
-                    "choice",
+                    Self::asn_attribute_definition_type("choice", data.is_explicit()),
                     data.tag(),
                     data.extension_after_variant().map(|v| v.name().to_string()),
+                    data.unknown_extensions(),
+                    &[],
+                    None,
+                    None,
+                ));
+                Self::add_data_enum(self.new_enum(scope, name, false, hashable), name, data)
+            }
+            Rust::OpenType(data) => {
+                scope.raw(&Self::asn_attribute(
+                    "open_type",
+                    None,
+                    None,
+                    data.unknown_extensions(),
                     &[],
+                    None,
+                    None,
                 ));
-                Self::add_data_enum(self.new_enum(scope, name, false), name, data)
+                Self::add_open_type(self.new_enum(scope, name, false, hashable), name, data)
             }
             Rust::TupleStruct {
                 r#type,
                 tag,
                 constants,
             } => {
-                scope.raw(&Self::asn_attribute("transparent", *tag, None, &[]));
+                scope.raw(&Self::asn_attribute(
+                    "transparent",
+                    *tag,
+                    None,
+                    None,
+                    &[],
+                    None,
+                    None,
+                ));
                 Self::add_tuple_struct(
-                    self.new_struct(scope, name),
+                    self.new_struct(scope, name, true, hashable),
                     name,
                     r#type,
                     self.direct_field_access,
@@ -230,45 +409,139 @@ impl RustCodeGenerator {
         }
     }
 
-    fn add_struct(str_ct: &mut Struct, _name: &str, fields: &[Field], pub_access: bool) {
+    fn add_struct(
+        str_ct: &mut Struct,
+        _name: &str,
+        fields: &[Field],
+        unknown_extensions: Option<&str>,
+        pub_access: bool,
+    ) {
         for field in fields {
+            let field_name = Self::rust_field_name(field.name(), true);
+            let r#type = match field.key() {
+                Some(key) => format!(
+                    "open_type({}), {}{}",
+                    field.r#type().to_string(),
+                    Self::asn_attribute_key(key),
+                    if field.r#type().is_explicit() {
+                        ", explicit"
+                    } else {
+                        ""
+                    }
+                ),
+                None => Self::asn_attribute_rust_type(field.r#type()),
+            };
             str_ct.field(
                 &format!(
                     "{} {}{}",
                     Self::asn_attribute(
-                        &Self::asn_attribute_type(&field.r#type().clone().into_asn()),
+                        &r#type,
                         field.tag(),
                         None,
+                        None,
                         field.constants(),
+                        Some(field.asn_name()).filter(|name| *name != field_name),
+                        field.default(),
                     ),
                     if pub_access { "pub " } else { "" },
-                    Self::rust_field_name(field.name(), true),
+                    field_name,
                 ),
                 field.r#type().to_string(),
             );
         }
+        if let Some(unknown_extensions) = unknown_extensions {
+            str_ct.field(
+                &format!(
+                    "#[doc(hidden)] {}{}",
+                    if pub_access { "pub " } else { "" },
+                    unknown_extensions
+                ),
+                "Vec<Option<Vec<u8>>>",
+            );
+        }
     }
 
     fn add_enum(en_m: &mut Enum, _name: &str, rust_enum: &PlainEnum) {
         for variant in rust_enum.variants() {
-            en_m.new_variant(&Self::rust_variant_name(variant));
+            let variant_name = Self::rust_variant_name(variant.name());
+            let attributes = variant
+                .number()
+                .map(|number| number.to_string())
+                .into_iter()
+                .chain(
+                    Some(variant.asn_name())
+                        .filter(|name| *name != variant_name)
+                        .map(Self::asn_attribute_identifier),
+                )
+                .collect::<Vec<_>>();
+            if attributes.is_empty() {
+                en_m.new_variant(&variant_name);
+            } else {
+                en_m.new_variant(&format!(
+                    "#[asn({})] {}",
+                    attributes.join(", "),
+                    variant_name
+                ));
+            }
+        }
+        if let Some(unknown_extensions) = rust_enum.unknown_extensions() {
+            en_m.new_variant(&format!("{} {{ index: u64 }}", unknown_extensions));
         }
     }
 
     fn add_data_enum(en_m: &mut Enum, _name: &str, enumeration: &DataEnum) {
+        for variant in enumeration.variants() {
+            let variant_name = Self::rust_variant_name(variant.name());
+            en_m.new_variant(&format!(
+                "{} {}({})",
+                Self::asn_attribute(
+                    Self::asn_attribute_rust_type(variant.r#type()),
+                    variant.tag(),
+                    None,
+                    None,
+                    &[],
+                    Some(variant.asn_name()).filter(|name| *name != variant_name),
+                    None
+                ),
+                variant_name,
+                variant.r#type().to_string(),
+            ));
+        }
+        if let Some(unknown_extensions) = enumeration.unknown_extensions() {
+            en_m.new_variant(&format!(
+                "{} {{ index: u64, bytes: Vec<u8> }}",
+                unknown_extensions
+            ));
+        }
+    }
+
+    fn add_open_type(en_m: &mut Enum, _name: &str, enumeration: &DataEnum) {
         for variant in enumeration.variants() {
             en_m.new_variant(&format!(
                 "{} {}({})",
                 Self::asn_attribute(
-                    Self::asn_attribute_type(&variant.r#type().clone().into_asn()),
+                    format!(
+                        "{}, {}",
+                        Self::asn_attribute_type(&variant.r#type().clone().into_asn()),
+                        Self::asn_attribute_key(variant.key().unwrap_or_default())
+                    ),
                     variant.tag(),
                     None,
+                    None,
                     &[],
+                    None,
+                    None
                 ),
                 Self::rust_variant_name(variant.name()),
                 variant.r#type().to_string(),
             ));
         }
+        if let Some(unknown_extensions) = enumeration.unknown_extensions() {
+            en_m.new_variant(&format!(
+                "{} {{ key: i64, bytes: Vec<u8> }}",
+                unknown_extensions
+            ));
+        }
     }
 
     fn add_tuple_struct(
@@ -282,10 +555,13 @@ impl RustCodeGenerator {
         str_ct.tuple_field(format!(
             "{} {}{}",
             Self::asn_attribute(
-                Self::asn_attribute_type(&inner.clone().into_asn()),
+                Self::asn_attribute_rust_type(inner),
                 tag,
                 None,
+                None,
                 constants,
+                None,
+                None
             ),
             if pub_access { "pub " } else { "" },
             inner.to_string(),
@@ -296,7 +572,10 @@ impl RustCodeGenerator {
         r#type: T,
         tag: Option<Tag>,
         extensible_after: Option<String>,
+        unknown_extensions: Option<&str>,
         constants: &[(String, String)],
+        identifier: Option<&str>,
+        default: Option<&LiteralValue>,
     ) -> String {
         format!(
             "#[asn({})]",
@@ -304,6 +583,7 @@ impl RustCodeGenerator {
                 Some(r#type.to_string()),
                 tag.map(Self::asn_attribute_tag),
                 extensible_after.map(Self::asn_attribute_extensible_after),
+                unknown_extensions.map(Self::asn_attribute_unknown_extensions),
                 if constants.is_empty() {
                     None
                 } else {
@@ -315,7 +595,9 @@ impl RustCodeGenerator {
                             .collect::<Vec<_>>()
                             .join(", ")
                     ))
-                }
+                },
+                identifier.map(Self::asn_attribute_identifier),
+                default.map(Self::asn_attribute_default),
             ]
             .into_iter()
             .flatten()
@@ -324,39 +606,67 @@ impl RustCodeGenerator {
         )
     }
 
+    /// The type of the attribute of a definition, followed by `explicit` if its tag is the
+    /// explicit tag of the type assignment
+    fn asn_attribute_definition_type(r#type: &str, explicit: bool) -> String {
+        if explicit {
+            format!("{}, explicit", r#type)
+        } else {
+            r#type.to_string()
+        }
+    }
+
+    /// The type of the attribute, followed by `explicit` if its tag is explicit and by `boxed` if
+    /// the value is boxed
+    fn asn_attribute_rust_type(r#type: &RustType) -> String {
+        let mut attribute = Self::asn_attribute_type(&r#type.clone().into_asn());
+        if r#type.is_explicit() {
+            attribute.push_str(", explicit");
+        }
+        if r#type.is_boxed() {
+            attribute.push_str(", boxed");
+        }
+        attribute
+    }
+
     fn asn_attribute_type(r#type: &AsnType) -> String {
         let (name, parameters) = match r#type {
             Type::Boolean => (Cow::Borrowed("boolean"), Vec::default()),
             Type::Integer(integer) => (
                 Cow::Borrowed("integer"),
-                vec![format!(
-                    "{}..{}{}",
-                    integer
-                        .range
-                        .min()
-                        .as_ref()
-                        .map(ToString::to_string)
-                        .unwrap_or_else(|| "min".to_string()),
-                    integer
-                        .range
-                        .max()
-                        .as_ref()
-                        .map(ToString::to_string)
-                        .unwrap_or_else(|| "max".to_string()),
-                    if integer.range.extensible() {
-                        ",..."
-                    } else {
-                        ""
-                    }
-                )],
-            ),
-            Type::String(size, charset) => (
-                Cow::Owned(format!("{:?}string", charset).to_lowercase()),
-                vec![size.to_constraint_string()]
+                Some("big".to_string())
+                    .filter(|_| integer.big)
                     .into_iter()
-                    .flatten()
+                    .chain(Some(format!(
+                        "{}{}",
+                        match &integer.constraint {
+                            Some(constraint) => constraint.to_string(),
+                            None => ElementSet::Range(
+                                integer.range.min().clone(),
+                                integer.range.max().clone(),
+                            )
+                            .to_string(),
+                        },
+                        if integer.range.extensible() {
+                            ",..."
+                        } else {
+                            ""
+                        }
+                    )))
                     .collect(),
             ),
+            Type::String(size, charset, alphabet) => (
+                Cow::Owned(format!("{:?}string", charset).to_lowercase()),
+                vec![
+                    size.to_constraint_string(),
+                    alphabet
+                        .as_ref()
+                        .map(|alphabet| format!("from({})", alphabet.to_constraint_string())),
+                ]
+                .into_iter()
+                .flatten()
+                .collect(),
+            ),
             Type::OctetString(size) => (
                 Cow::Borrowed("octet_string"),
                 vec![size.to_constraint_string()]
@@ -371,6 +681,14 @@ impl RustCodeGenerator {
                     .flatten()
                     .collect()],
             ),
+            Type::Null => (Cow::Borrowed("null"), Vec::default()),
+            Type::Real => (Cow::Borrowed("real"), Vec::default()),
+            Type::ObjectIdentifier => (Cow::Borrowed("object_identifier"), Vec::default()),
+            Type::RelativeOid => (Cow::Borrowed("relative_oid"), Vec::default()),
+            Type::Time(time) => (
+                Cow::Owned(format!("{:?}", time).to_lowercase()),
+                Vec::default(),
+            ),
             Type::Optional(inner) => (
                 Cow::Borrowed("optional"),
                 vec![Self::asn_attribute_type(&*inner)],
@@ -410,6 +728,18 @@ impl RustCodeGenerator {
                 .flatten()
                 .collect(),
             ),
+            Type::WithComponents(inner, constraints) => (
+                Cow::Borrowed("with_components"),
+                vec![Self::asn_attribute_type(inner), constraints.to_string()],
+            ),
+            Type::Containing(inner, container) => (
+                Cow::Borrowed("containing"),
+                vec![container.to_string(), Self::asn_attribute_type(inner)],
+            ),
+            Type::OpenType(_) => (Cow::Borrowed("open_type"), Vec::default()),
+            Type::ObjectClassField(_) => {
+                unreachable!("Fields of classes are resolved by the conversion to rust")
+            }
         };
         if parameters.is_empty() {
             name.into_owned()
@@ -431,6 +761,22 @@ impl RustCodeGenerator {
         format!("extensible_after({})", variant)
     }
 
+    fn asn_attribute_unknown_extensions(name: &str) -> String {
+        format!("unknown_extensions({})", name)
+    }
+
+    fn asn_attribute_key(key: impl Display) -> String {
+        format!("key({})", key)
+    }
+
+    fn asn_attribute_identifier(identifier: &str) -> String {
+        format!("identifier({:?})", identifier)
+    }
+
+    fn asn_attribute_default(default: &LiteralValue) -> String {
+        format!("default({:?})", default.to_string())
+    }
+
     fn impl_definition(
         scope: &mut Scope,
         Definition(name, rust): &Definition<Rust>,
@@ -442,12 +788,17 @@ impl RustCodeGenerator {
                 fields,
                 tag: _,
                 extension_after: _,
+                unknown_extensions,
                 ordering: _,
+                explicit: _,
             } => {
                 let implementation = Self::impl_struct(scope, name, fields, getter_and_setter);
                 for g in generators {
                     g.extend_impl_of_struct(name, implementation, fields);
                 }
+                if fields.iter().any(|f| f.default().is_some()) {
+                    Self::impl_struct_default(scope, name, fields, unknown_extensions.as_deref());
+                }
             }
             Rust::Enum(r_enum) => {
                 let implementation = Self::impl_enum(scope, name, r_enum);
@@ -456,8 +807,13 @@ impl RustCodeGenerator {
                 }
                 Self::impl_enum_default(scope, name, r_enum);
             }
-            Rust::DataEnum(enumeration) => {
-                let implementation = Self::impl_data_enum(scope, name, enumeration);
+            Rust::DataEnum(enumeration) | Rust::OpenType(enumeration) => {
+                let implementation = Self::impl_data_enum(
+                    scope,
+                    name,
+                    enumeration,
+                    matches!(rust, Rust::OpenType(_)),
+                );
                 for g in generators {
                     g.extend_impl_of_data_enum(name, implementation, enumeration);
                 }
@@ -562,6 +918,34 @@ impl RustCodeGenerator {
             ));
     }
 
+    fn impl_struct_default(
+        scope: &mut Scope,
+        name: &str,
+        fields: &[Field],
+        unknown_extensions: Option<&str>,
+    ) {
+        let mut block = Block::new(name);
+        for field in fields {
+            block.line(format!(
+                "{}: {},",
+                Self::rust_field_name(field.name(), true),
+                field.default().map_or_else(
+                    || "Default::default()".to_string(),
+                    |default| Self::default_value_expression(field.r#type(), default)
+                )
+            ));
+        }
+        if let Some(unknown_extensions) = unknown_extensions {
+            block.line(format!("{}: Default::default(),", unknown_extensions));
+        }
+        scope
+            .new_impl(name)
+            .impl_trait("Default")
+            .new_fn("default")
+            .ret(name as &str)
+            .push_block(block);
+    }
+
     fn impl_enum_default(scope: &mut Scope, name: &str, r_enum: &PlainEnum) {
         scope
             .new_impl(name)
@@ -571,7 +955,7 @@ impl RustCodeGenerator {
             .line(format!(
                 "{}::{}",
                 name,
-                Self::rust_variant_name(r_enum.variants().next().unwrap())
+                Self::rust_variant_name(r_enum.variants().next().unwrap().name())
             ));
     }
 
@@ -598,7 +982,7 @@ impl RustCodeGenerator {
                 "{} => Some({}::{}),",
                 index,
                 name,
-                Self::rust_variant_name(variant)
+                Self::rust_variant_name(variant.name())
             ));
         }
         block_match.line("_ => None,");
@@ -613,7 +997,11 @@ impl RustCodeGenerator {
             .line("[");
 
         for variant in r_enum.variants() {
-            values_fn.line(format!("{}::{},", name, Self::rust_variant_name(variant)));
+            values_fn.line(format!(
+                "{}::{},",
+                name,
+                Self::rust_variant_name(variant.name())
+            ));
         }
         values_fn.line("]");
     }
@@ -633,10 +1021,16 @@ impl RustCodeGenerator {
                 block.line(format!(
                     "{}::{} => {},",
                     name,
-                    Self::rust_variant_name(variant),
+                    Self::rust_variant_name(variant.name()),
                     ordinal
                 ));
             });
+        if let Some(unknown_extensions) = r_enum.unknown_extensions() {
+            block.line(format!(
+                "{}::{} {{ index }} => index as usize,",
+                name, unknown_extensions
+            ));
+        }
 
         ordinal_fn.push_block(block);
     }
@@ -645,11 +1039,12 @@ impl RustCodeGenerator {
         scope: &'a mut Scope,
         name: &str,
         enumeration: &DataEnum,
+        open_type: bool,
     ) -> &'a mut Impl {
         let implementation = scope.new_impl(name);
 
         Self::impl_data_enum_values_fn(implementation, name, enumeration);
-        Self::impl_data_enum_value_index_fn(implementation, name, enumeration);
+        Self::impl_data_enum_value_index_fn(implementation, name, enumeration, open_type);
 
         for variant in enumeration.variants() {
             let field_name = Self::rust_module_name(variant.name());
@@ -680,6 +1075,7 @@ impl RustCodeGenerator {
         implementation: &mut Impl,
         name: &str,
         enumeration: &DataEnum,
+        open_type: bool,
     ) {
         let ordinal_fn = implementation
             .new_fn("value_index")
@@ -699,6 +1095,22 @@ impl RustCodeGenerator {
                     ordinal
                 ));
             });
+        if let Some(unknown_extensions) = enumeration.unknown_extensions() {
+            if open_type {
+                // the value of a key that is unknown to the object set has no known variant
+                block.line(format!(
+                    "{}::{} {{ .. }} => {},",
+                    name,
+                    unknown_extensions,
+                    enumeration.len()
+                ));
+            } else {
+                block.line(format!(
+                    "{}::{} {{ index, .. }} => *index as usize,",
+                    name, unknown_extensions
+                ));
+            }
+        }
 
         ordinal_fn.push_block(block);
     }
@@ -784,6 +1196,50 @@ impl RustCodeGenerator {
         out
     }
 
+    /// The rust expression for the given default value of a field of the given type
+    pub fn default_value_expression(r#type: &RustType, value: &LiteralValue) -> String {
+        match (r#type, value) {
+            (RustType::Bool, LiteralValue::Boolean(value)) => value.to_string(),
+            (RustType::String(..), LiteralValue::String(value)) => {
+                format!("{:?}.to_string()", value)
+            }
+            (RustType::VecU8(_), LiteralValue::Bits(bytes, _)) => format!("vec!{:?}", bytes),
+            (RustType::BitVec(_), LiteralValue::Bits(bytes, bits)) => format!(
+                "{}BitVec::from_bytes(vec!{:?}, {})",
+                walker::CRATE_SYN_PREFIX,
+                bytes,
+                bits
+            ),
+            (RustType::Complex(name, _), LiteralValue::Enumerated(variant)) => {
+                format!("{}::{}", name, Self::rust_variant_name(variant))
+            }
+            (RustType::Complex(name, _), LiteralValue::String(value)) => {
+                format!("{}({:?}.to_string())", name, value)
+            }
+            (RustType::Complex(name, _), LiteralValue::Bits(bytes, _)) => {
+                format!("{}(vec!{:?})", name, bytes)
+            }
+            (RustType::Complex(name, _), LiteralValue::Boolean(value)) => {
+                format!("{}({})", name, value)
+            }
+            (RustType::Complex(name, _), LiteralValue::Integer(value)) => {
+                format!("{}({})", name, value)
+            }
+            (RustType::Constrained(inner, _), value) => {
+                Self::default_value_expression(inner, value)
+            }
+            (RustType::BigInt(_), LiteralValue::Integer(value)) => format!(
+                "{}numbers::BigInt::from({}_i64)",
+                walker::CRATE_SYN_PREFIX,
+                value
+            ),
+            (_, LiteralValue::Integer(value)) if r#type.integer_range_str().is_some() => {
+                value.to_string()
+            }
+            (r#type, value) => panic!("{} is not a valid value for {}", value, r#type.to_string()),
+        }
+    }
+
     pub fn rust_module_name(name: &str) -> String {
         let mut out = String::new();
         let mut prev_lowered = false;
@@ -812,29 +1268,77 @@ impl RustCodeGenerator {
         out
     }
 
-    fn new_struct<'a>(&self, scope: &'a mut Scope, name: &str) -> &'a mut Struct {
-        let str_ct = scope
-            .new_struct(name)
-            .vis("pub")
-            .derive("Default")
-            .derive("Debug")
-            .derive("Clone")
-            .derive("PartialEq")
-            .derive("Hash");
+    /// Whether `Hash` can be derived for the given definition, which is not the case if it -
+    /// directly or through the definitions it references - contains a `f64`
+    fn is_hashable(&self, rust: &Rust, visited: &mut Vec<String>) -> bool {
+        match rust {
+            Rust::Struct { fields, .. } => fields
+                .iter()
+                .all(|field| self.is_hashable_type(field.r#type(), visited)),
+            Rust::Enum(_) => true,
+            Rust::DataEnum(data) | Rust::OpenType(data) => data
+                .variants()
+                .all(|variant| self.is_hashable_type(variant.r#type(), visited)),
+            Rust::TupleStruct { r#type, .. } => self.is_hashable_type(r#type, visited),
+        }
+    }
+
+    fn is_hashable_type(&self, r#type: &RustType, visited: &mut Vec<String>) -> bool {
+        match r#type.as_inner_type() {
+            RustType::F64 => false,
+            RustType::Complex(name, _) if !visited.contains(name) => {
+                visited.push(name.clone());
+                let definition = self
+                    .models
+                    .iter()
+                    .flat_map(|model| model.definitions.iter())
+                    .find(|Definition(other, _)| other.eq(name));
+                match definition {
+                    Some(Definition(_, rust)) => self.is_hashable(rust, visited),
+                    None => true,
+                }
+            }
+            _ => true,
+        }
+    }
+
+    fn new_struct<'a>(
+        &self,
+        scope: &'a mut Scope,
+        name: &str,
+        derive_default: bool,
+        derive_hash: bool,
+    ) -> &'a mut Struct {
+        let str_ct = scope.new_struct(name).vis("pub");
+        if derive_default {
+            str_ct.derive("Default");
+        }
+        str_ct.derive("Debug").derive("Clone").derive("PartialEq");
+        if derive_hash {
+            str_ct.derive("Hash");
+        }
         self.global_derives.iter().for_each(|derive| {
             str_ct.derive(derive);
         });
         str_ct
     }
 
-    fn new_enum<'a>(&self, scope: &'a mut Scope, name: &str, c_enum: bool) -> &'a mut Enum {
+    fn new_enum<'a>(
+        &self,
+        scope: &'a mut Scope,
+        name: &str,
+        c_enum: bool,
+        derive_hash: bool,
+    ) -> &'a mut Enum {
         let en_m = scope
             .new_enum(name)
             .vis("pub")
             .derive("Debug")
             .derive("Clone")
-            .derive("PartialEq")
-            .derive("Hash");
+            .derive("PartialEq");
+        if derive_hash {
+            en_m.derive("Hash");
+        }
         if c_enum {
             en_m.derive("Copy").derive("PartialOrd").derive("Eq");
         }
@@ -41,6 +41,20 @@ impl GeneratorSupplement<Rust> for ProtobufSerializer {
     }
 
     fn impl_supplement(&self, scope: &mut Scope, definition: &Definition<Rust>) {
+        if let Some(unsupported) = Self::find_unsupported_type(&definition.1) {
+            scope.raw(&format!(
+                "compile_error!({:?});",
+                format!(
+                    "{}: {:?} is not supported by the protobuf serializer",
+                    definition.0, unsupported
+                )
+            ));
+            return;
+        }
+        let definition = &definition
+            .with_object_identifiers_as_vec()
+            .without_containing()
+            .without_explicit_tags();
         Self::impl_eq_fn(
             Self::new_eq_fn(Self::new_eq_impl(scope, &definition.0)),
             definition,
@@ -59,6 +73,52 @@ impl GeneratorSupplement<Rust> for ProtobufSerializer {
 impl ProtobufSerializer {
     const CODEC: &'static str = "Protobuf";
 
+    /// The 128-bit and arbitrarily large `INTEGER`s are mapped to `string`s, which can only be
+    /// converted to the Rust types by a fallible parse
+    fn find_unsupported_type(rust: &Rust) -> Option<&RustType> {
+        match rust {
+            Rust::TupleStruct { r#type, .. } => Self::find_unsupported_inner_type(r#type),
+            Rust::Struct { fields, .. } => fields
+                .iter()
+                .find_map(|field| Self::find_unsupported_inner_type(field.r#type())),
+            Rust::Enum(_) => None,
+            Rust::DataEnum(enumeration) | Rust::OpenType(enumeration) => enumeration
+                .variants()
+                .find_map(|variant| Self::find_unsupported_inner_type(variant.r#type())),
+        }
+    }
+
+    fn find_unsupported_inner_type(rust: &RustType) -> Option<&RustType> {
+        match rust {
+            RustType::Constrained(inner, _)
+            | RustType::WithComponents(inner, _)
+            | RustType::Explicit(inner)
+            | RustType::Boxed(inner)
+            | RustType::Vec(inner, _, _)
+            | RustType::Option(inner) => Self::find_unsupported_inner_type(inner),
+            RustType::I128(_) | RustType::U128(_) | RustType::BigInt(_) => Some(rust),
+            RustType::Bool
+            | RustType::U8(_)
+            | RustType::I8(_)
+            | RustType::U16(_)
+            | RustType::I16(_)
+            | RustType::U32(_)
+            | RustType::I32(_)
+            | RustType::I64(_)
+            | RustType::U64(_)
+            | RustType::F64
+            | RustType::String(..)
+            | RustType::VecU8(_)
+            | RustType::BitVec(_)
+            | RustType::Null
+            | RustType::ObjectIdentifier
+            | RustType::RelativeOid
+            | RustType::Time(_)
+            | RustType::Containing(..)
+            | RustType::Complex(..) => None,
+        }
+    }
+
     fn new_protobuf_serializable_impl<'a>(scope: &'a mut Scope, impl_for: &str) -> &'a mut Impl {
         RustCodeGenerator::new_serializable_impl(scope, impl_for, Self::CODEC)
     }
@@ -76,14 +136,16 @@ impl ProtobufSerializer {
                 fields,
                 tag: _,
                 extension_after: _,
+                unknown_extensions: _,
                 ordering: _,
+                explicit: _,
             } => {
                 Self::impl_read_fn_for_struct(function, name, &fields[..]);
             }
             Rust::Enum(r_enum) => {
                 Self::impl_read_fn_for_enum(function, name, r_enum);
             }
-            Rust::DataEnum(enumeration) => {
+            Rust::DataEnum(enumeration) | Rust::OpenType(enumeration) => {
                 Self::impl_read_fn_for_data_enum(function, name, enumeration);
             }
         };
@@ -109,6 +171,11 @@ impl ProtobufSerializer {
             RustType::Complex(custom, _asn_tag) => {
                 if aliased.as_no_option().is_vec() {
                     block_reader.line(format!("me.0.push({}::read_protobuf(reader)?);", custom))
+                } else if aliased.is_boxed() {
+                    block_reader.line(format!(
+                        "me.0 = Box::new({}::read_protobuf(reader)?);",
+                        custom
+                    ))
                 } else {
                     block_reader.line(format!("me.0 = {}::read_protobuf(reader)?;", custom))
                 }
@@ -210,7 +277,9 @@ impl ProtobufSerializer {
                 "{}: read_{}{}{},",
                 RustCodeGenerator::rust_field_name(field.name(), true),
                 RustCodeGenerator::rust_field_name(field.name(), false),
-                if as_rust_statement.is_empty() {
+                if field.r#type().is_boxed() {
+                    ".map(Box::new)".into()
+                } else if as_rust_statement.is_empty() {
                     "".into()
                 } else if let RustType::Vec(..) = field.r#type().clone().no_option() {
                     format!(
@@ -239,7 +308,7 @@ impl ProtobufSerializer {
                 "{} => Ok({}::{}),",
                 field,
                 name,
-                RustCodeGenerator::rust_variant_name(variant),
+                RustCodeGenerator::rust_variant_name(variant.name()),
             ));
         }
         block_match.line(format!(
@@ -280,14 +349,20 @@ impl ProtobufSerializer {
             } else {
                 // primitive
                 block_case.line(format!(
-                    "let value = reader.read_{}()?;",
-                    variant.r#type().to_protobuf().to_string()
+                    "let value = reader.read_{}()?{};",
+                    variant.r#type().to_protobuf().to_string(),
+                    Self::get_as_rust_type_statement(&variant.r#type().clone().into_inner_type())
                 ));
             }
             block_case.line(format!(
-                "Ok({}::{}(value))",
+                "Ok({}::{}({}))",
                 name,
-                RustCodeGenerator::rust_variant_name(variant.name())
+                RustCodeGenerator::rust_variant_name(variant.name()),
+                if variant.r#type().is_boxed() {
+                    "Box::new(value)"
+                } else {
+                    "value"
+                }
             ));
             block_match.push_block(block_case);
         }
@@ -313,14 +388,16 @@ impl ProtobufSerializer {
                 fields,
                 tag: _,
                 extension_after: _,
+                unknown_extensions: _,
                 ordering: _,
+                explicit: _,
             } => {
                 Self::impl_write_fn_for_struct(function, &fields[..]);
             }
             Rust::Enum(r_enum) => {
                 Self::impl_write_fn_for_enum(function, name, r_enum);
             }
-            Rust::DataEnum(enumeration) => {
+            Rust::DataEnum(enumeration) | Rust::OpenType(enumeration) => {
                 Self::impl_write_fn_for_data_enum(function, name, enumeration);
             }
         };
@@ -412,7 +489,7 @@ impl ProtobufSerializer {
             RustType::Vec(..) => {
                 Self::impl_write_for_vec_attribute(&mut block, field_type, &field_name, tag);
             }
-            RustType::Complex(_, _) => {
+            RustType::Complex(..) | RustType::WithComponents(..) | RustType::Boxed(_) => {
                 let format_line = format!(
                     "{}{}.{}_format()",
                     if deny_self || field_type.is_option() {
@@ -493,7 +570,7 @@ impl ProtobufSerializer {
             outer_block.line(format!(
                 "{}::{} => writer.write_varint({})?,",
                 name,
-                RustCodeGenerator::rust_variant_name(variant),
+                RustCodeGenerator::rust_variant_name(variant.name()),
                 field,
             ));
         }
@@ -511,6 +588,16 @@ impl ProtobufSerializer {
             Self::impl_write_field(field + 1, variant.r#type(), "value", &mut block_case, true);
             block_match.push_block(block_case);
         }
+        if let Some(unknown_extensions) = enumeration.unknown_extensions() {
+            // unknown extensions have no representation in protobuf
+            block_match.line(format!(
+                "{}::{} {{ .. }} => return Err({}Error::invalid_variant({})),",
+                name,
+                unknown_extensions,
+                Self::CODEC,
+                enumeration.len() + 1
+            ));
+        }
         function.push_block(block_match);
     }
 
@@ -527,7 +614,7 @@ impl ProtobufSerializer {
             Rust::TupleStruct { .. } => Some("LengthDelimited"),
             Rust::Struct { .. } => Some("LengthDelimited"),
             Rust::Enum(_) => Some("VarInt"),
-            Rust::DataEnum(enumeration) => {
+            Rust::DataEnum(enumeration) | Rust::OpenType(enumeration) => {
                 let mut block_match = Block::new("match self");
                 for variant in enumeration.variants() {
                     block_match.line(format!(
@@ -537,6 +624,14 @@ impl ProtobufSerializer {
                         Self::role_to_format(variant.r#type(), "value"),
                     ));
                 }
+                if let Some(unknown_extensions) = enumeration.unknown_extensions() {
+                    block_match.line(format!(
+                        "{}::{} {{ .. }} => {}Format::LengthDelimited,",
+                        name,
+                        unknown_extensions,
+                        Self::CODEC
+                    ));
+                }
                 function.push_block(block_match);
                 None
             }
@@ -572,7 +667,9 @@ impl ProtobufSerializer {
                 fields,
                 tag: _,
                 extension_after: _,
+                unknown_extensions: _,
                 ordering: _,
+                explicit: _,
             } => {
                 for (num, field) in fields.iter().enumerate() {
                     if num > 0 {
@@ -590,7 +687,7 @@ impl ProtobufSerializer {
             Rust::Enum(_) => {
                 function.line("self == other");
             }
-            Rust::DataEnum(enumeration) => {
+            Rust::DataEnum(enumeration) | Rust::OpenType(enumeration) => {
                 let mut block_match = Block::new("match self");
                 for variant in enumeration.variants() {
                     let mut block_case = Block::new(&format!(
@@ -613,6 +710,12 @@ impl ProtobufSerializer {
                     block_case.push_block(block_else);
                     block_match.push_block(block_case);
                 }
+                if let Some(unknown_extensions) = enumeration.unknown_extensions() {
+                    block_match.line(format!(
+                        "{}::{} {{ .. }} => self == other,",
+                        name, unknown_extensions
+                    ));
+                }
                 function.push_block(block_match);
             }
         }
@@ -628,10 +731,12 @@ impl ProtobufSerializer {
             ProtobufType::UInt64 => format!("{}Format::VarInt", Self::CODEC),
             ProtobufType::SInt32 => format!("{}Format::VarInt", Self::CODEC),
             ProtobufType::SInt64 => format!("{}Format::VarInt", Self::CODEC),
+            ProtobufType::Double => format!("{}Format::Fixed64", Self::CODEC),
             ProtobufType::String => format!("{}Format::LengthDelimited", Self::CODEC),
             ProtobufType::Bytes | ProtobufType::BitsReprByBytesAndBitsLen => {
                 format!("{}Format::LengthDelimited", Self::CODEC)
             }
+            ProtobufType::Empty => format!("{}Format::LengthDelimited", Self::CODEC),
             ProtobufType::OneOf(_) => format!("{}Format::LengthDelimited", Self::CODEC),
             ProtobufType::Repeated(_) => format!("{}Format::LengthDelimited", Self::CODEC),
             ProtobufType::Complex(_complex_type) => {
@@ -48,6 +48,13 @@ impl GeneratorSupplement<Rust> for PsqlInserter {
     }
 
     fn impl_supplement(&self, scope: &mut Scope, definition: &Definition<Rust>) {
+        if reject_unsupported(scope, definition) {
+            return;
+        }
+        let definition = &definition
+            .with_object_identifiers_as_vec()
+            .without_containing()
+            .without_explicit_tags();
         Self::impl_representable(scope, definition);
         Self::impl_insertable(scope, definition);
         Self::impl_queryable(scope, definition);
@@ -79,7 +86,9 @@ impl PsqlInserter {
                 fields,
                 tag: _,
                 extension_after: _,
+                unknown_extensions: _,
                 ordering: _,
+                explicit: _,
             } => {
                 Self::impl_struct_insert_statement(
                     Self::new_insert_statement_fn(implementation),
@@ -92,7 +101,7 @@ impl PsqlInserter {
                     fields.iter().map(Field::fallback_representation),
                 );
             }
-            Rust::DataEnum(enumeration) => {
+            Rust::DataEnum(enumeration) | Rust::OpenType(enumeration) => {
                 Self::impl_data_enum_insert_statement(
                     Self::new_insert_statement_fn(implementation),
                     name,
@@ -276,12 +285,12 @@ impl PsqlInserter {
                     if use_from_instead_of_as {
                         format!("{}.map({}::from)", name, as_target)
                     } else {
-                        format!("{}.map(|v| v as {})", name, as_target)
+                        cast_option(name, &rust_from_sql)
                     }
                 } else if use_from_instead_of_as {
                     format!("{}::from({})", as_target, name)
                 } else {
-                    format!("{} as {}", name, as_target)
+                    cast(name, &rust_from_sql)
                 }
             })
         }
@@ -293,13 +302,10 @@ impl PsqlInserter {
         if inner_sql.to_rust().into_inner_type().similar(&inner_rust) {
             None
         } else {
-            Some({
-                let as_target = inner_rust.to_string();
-                if let RustType::Option(_) = rust {
-                    format!("{}.map(|v| v as {})", name, as_target)
-                } else {
-                    format!("{} as {}", name, as_target)
-                }
+            Some(if let RustType::Option(_) = rust {
+                cast_option(name, &inner_rust)
+            } else {
+                cast(name, &inner_rust)
             })
         }
     }
@@ -391,7 +397,7 @@ impl PsqlInserter {
                     if use_from_instead_of_as {
                         format!("{}::from(*value)", as_target)
                     } else {
-                        format!("(*value) as {}", as_target)
+                        cast("(*value)", &rust_from_sql)
                     },
                 ));
             }
@@ -412,7 +418,9 @@ impl PsqlInserter {
                 fields,
                 tag: _,
                 extension_after: _,
+                unknown_extensions: _,
                 ordering: _,
+                explicit: _,
             } => {
                 Self::impl_query_statement(Self::new_query_statement_fn(implementation), name);
                 Self::impl_struct_query_fn(Self::new_query_fn(implementation, true), name);
@@ -427,7 +435,7 @@ impl PsqlInserter {
                     fields.iter().map(Field::fallback_representation),
                 );
             }
-            Rust::DataEnum(enumeration) => {
+            Rust::DataEnum(enumeration) | Rust::OpenType(enumeration) => {
                 Self::impl_query_statement(Self::new_query_statement_fn(implementation), name);
                 Self::impl_data_enum_query_fn(Self::new_query_fn(implementation, true), name);
                 Self::impl_data_enum_load_fn(
@@ -531,8 +539,8 @@ impl PsqlInserter {
                         ERROR_TYPE,
                         sql.to_rust().to_string(),
                     ));
-                    if rust < sql.to_rust() {
-                        rows_foreach.line(&format!("let value = value as {};", rust.to_string()));
+                    if !rust.similar(&sql.to_rust()) {
+                        rows_foreach.line(&format!("let value = {};", cast("value", &rust)));
                     }
                     rows_foreach.line("vec.push(value);");
                 }
@@ -562,18 +570,28 @@ impl PsqlInserter {
                 let load = if let RustType::Option(_) = rust {
                     format!(
                         "{}::value_at_column::<Option<i32>>(&row, {})?\
-                        .map(|id| {}::query_with(transaction, id)).transpose()?",
+                        .map(|id| {}::query_with(transaction, id)).transpose()?{}",
                         ERROR_TYPE,
                         index + 1,
                         inner.to_string(),
+                        if rust.is_boxed() {
+                            ".map(Box::new)"
+                        } else {
+                            ""
+                        },
                     )
                 } else {
-                    format!(
+                    let load = format!(
                         "{}::query_with(transaction, {}::value_at_column::<i32>(&row, {})?)?",
                         inner.to_string(),
                         ERROR_TYPE,
                         index + 1,
-                    )
+                    );
+                    if rust.is_boxed() {
+                        format!("Box::new({})", load)
+                    } else {
+                        load
+                    }
                 };
                 block.line(&format!(
                     "{}: {},",
@@ -616,40 +634,32 @@ impl PsqlInserter {
             ));
 
             if Model::<Sql>::is_primitive(variant.r#type().as_inner_type()) {
-                if let Some(wrap) =
-                    Self::wrap_for_query_in_as_or_from_if_required("", variant.r#type())
-                {
-                    block_case.line(format!(
-                        "{}::value_at_column::<{}>(&row, {})?{}",
-                        ERROR_TYPE,
-                        variant
-                            .r#type()
-                            .as_inner_type()
-                            .to_sql()
-                            .to_rust()
-                            .to_string(),
-                        index + 1,
-                        wrap
-                    ));
-                } else {
-                    block_case.line(format!(
-                        "{}::value_at_column::<{}>(&row, {})?",
-                        ERROR_TYPE,
-                        variant
-                            .r#type()
-                            .as_inner_type()
-                            .to_sql()
-                            .to_rust()
-                            .to_string(),
-                        index + 1
-                    ));
-                }
+                let load = format!(
+                    "{}::value_at_column::<{}>(&row, {})?",
+                    ERROR_TYPE,
+                    variant
+                        .r#type()
+                        .as_inner_type()
+                        .to_sql()
+                        .to_rust()
+                        .to_string(),
+                    index + 1
+                );
+                block_case.line(
+                    Self::wrap_for_query_in_as_or_from_if_required(&load, variant.r#type())
+                        .unwrap_or(load),
+                );
             } else {
-                block_case.line(&format!(
+                let load = format!(
                     "{}::query_with(transaction, row.get({}))?",
                     variant.r#type().clone().into_inner_type().to_string(),
                     index + 1
-                ));
+                );
+                if variant.r#type().is_boxed() {
+                    block_case.line(&format!("Box::new({})", load));
+                } else {
+                    block_case.line(&load);
+                }
             }
 
             block_case.after(")),");
@@ -719,6 +729,9 @@ impl PsqlInserter {
                 "let values = {}::load_from(transaction, &row)?;",
                 inner.to_string()
             ));
+            if rust.is_boxed() {
+                func.line("let values = Box::new(values);");
+            }
         }
         func.line(&format!("Ok({}(values))", name));
     }
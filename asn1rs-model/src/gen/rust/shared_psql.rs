@@ -1,7 +1,89 @@
 use crate::gen::RustCodeGenerator;
 use crate::model::rust::{DataEnum, Field};
 use crate::model::sql::Sql;
-use crate::model::{Model, RustType};
+use crate::model::{Definition, Model, Rust, RustType};
+use codegen::Scope;
+
+/// The 128-bit and arbitrarily large `INTEGER`s are stored in `TEXT` columns, whose values can
+/// only be converted to the Rust types by a fallible parse, which is why the psql supplements
+/// refuse to generate code for them and report a `compile_error!` instead. Returns whether the
+/// definition was rejected.
+pub(crate) fn reject_unsupported(
+    scope: &mut Scope,
+    Definition(name, rust): &Definition<Rust>,
+) -> bool {
+    let unsupported = match rust {
+        Rust::TupleStruct { r#type, .. } => find_unsupported_type(r#type),
+        Rust::Struct { fields, .. } => fields
+            .iter()
+            .find_map(|field| find_unsupported_type(field.r#type())),
+        Rust::Enum(_) => None,
+        Rust::DataEnum(enumeration) | Rust::OpenType(enumeration) => enumeration
+            .variants()
+            .find_map(|variant| find_unsupported_type(variant.r#type())),
+    };
+    if let Some(unsupported) = unsupported {
+        scope.raw(&format!(
+            "compile_error!({:?});",
+            format!(
+                "{}: {:?} is not supported by the psql supplements",
+                name, unsupported
+            )
+        ));
+    }
+    unsupported.is_some()
+}
+
+pub(crate) fn find_unsupported_type(rust: &RustType) -> Option<&RustType> {
+    match rust {
+        RustType::Constrained(inner, _)
+        | RustType::WithComponents(inner, _)
+        | RustType::Explicit(inner)
+        | RustType::Boxed(inner)
+        | RustType::Vec(inner, _, _)
+        | RustType::Option(inner) => find_unsupported_type(inner),
+        RustType::I128(_) | RustType::U128(_) | RustType::BigInt(_) => Some(rust),
+        RustType::Bool
+        | RustType::U8(_)
+        | RustType::I8(_)
+        | RustType::U16(_)
+        | RustType::I16(_)
+        | RustType::U32(_)
+        | RustType::I32(_)
+        | RustType::I64(_)
+        | RustType::U64(_)
+        | RustType::F64
+        | RustType::String(..)
+        | RustType::VecU8(_)
+        | RustType::BitVec(_)
+        | RustType::Null
+        | RustType::ObjectIdentifier
+        | RustType::RelativeOid
+        | RustType::Time(_)
+        | RustType::Containing(..)
+        | RustType::Complex(..) => None,
+    }
+}
+
+/// Converts the `value` of a primitive type to the `target` type of its SQL or Rust
+/// representation. `NULL` is stored in a `BOOLEAN` column that is always `true`, because `()`
+/// cannot be casted from or to `bool`.
+pub(crate) fn cast(value: &str, target: &RustType) -> String {
+    match target.as_inner_type() {
+        RustType::Bool => "true".to_string(),
+        RustType::Null => format!("{{ let _ = {}; }}", value),
+        target => format!("{} as {}", value, target.to_string()),
+    }
+}
+
+/// Like [`cast`], but converts the content of the `Option` in `value`.
+pub(crate) fn cast_option(value: &str, target: &RustType) -> String {
+    match target.as_inner_type() {
+        RustType::Bool => format!("{}.map(|_| true)", value),
+        RustType::Null => format!("{}.map(|_| ())", value),
+        target => format!("{}.map(|v| v as {})", value, target.to_string()),
+    }
+}
 
 pub(crate) fn select_statement_single(name: &str) -> String {
     format!("SELECT * FROM {} WHERE id = $1", name)
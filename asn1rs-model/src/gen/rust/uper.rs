@@ -31,6 +31,17 @@ impl GeneratorSupplement<Rust> for UperSerializer {
     }
 
     fn impl_supplement(&self, scope: &mut Scope, definition: &Definition<Rust>) {
+        if let Some(unsupported) = Self::find_unsupported_type(&definition.1) {
+            scope.raw(&format!(
+                "compile_error!({:?});",
+                format!(
+                    "{}: {:?} is not supported by the legacy UPER codegen",
+                    definition.0, unsupported
+                )
+            ));
+            return;
+        }
+        let definition = &definition.without_explicit_tags();
         let serializable_implementation = Self::new_uper_serializable_impl(scope, &definition.0);
         Self::impl_read_fn(Self::new_read_fn(serializable_implementation), definition);
         Self::impl_write_fn(Self::new_write_fn(serializable_implementation), definition);
@@ -40,6 +51,52 @@ impl GeneratorSupplement<Rust> for UperSerializer {
 impl UperSerializer {
     const CODEC: &'static str = "Uper";
 
+    fn find_unsupported_type(rust: &Rust) -> Option<&RustType> {
+        match rust {
+            Rust::TupleStruct { r#type, .. } => Self::find_unsupported_inner_type(r#type),
+            Rust::Struct { fields, .. } => fields
+                .iter()
+                .find_map(|field| Self::find_unsupported_inner_type(field.r#type())),
+            Rust::Enum(_) => None,
+            Rust::DataEnum(enumeration) | Rust::OpenType(enumeration) => enumeration
+                .variants()
+                .find_map(|variant| Self::find_unsupported_inner_type(variant.r#type())),
+        }
+    }
+
+    fn find_unsupported_inner_type(rust: &RustType) -> Option<&RustType> {
+        match rust {
+            RustType::Constrained(inner, _)
+            | RustType::WithComponents(inner, _)
+            | RustType::Explicit(inner)
+            | RustType::Boxed(inner)
+            | RustType::Vec(inner, _, _)
+            | RustType::Option(inner) => Self::find_unsupported_inner_type(inner),
+            RustType::I128(_)
+            | RustType::U128(_)
+            | RustType::BigInt(_)
+            | RustType::F64
+            | RustType::ObjectIdentifier
+            | RustType::RelativeOid
+            | RustType::Time(_)
+            | RustType::Containing(..) => Some(rust),
+            RustType::Bool
+            | RustType::U8(_)
+            | RustType::I8(_)
+            | RustType::U16(_)
+            | RustType::I16(_)
+            | RustType::U32(_)
+            | RustType::I32(_)
+            | RustType::I64(_)
+            | RustType::U64(_)
+            | RustType::String(..)
+            | RustType::VecU8(_)
+            | RustType::BitVec(_)
+            | RustType::Null
+            | RustType::Complex(..) => None,
+        }
+    }
+
     fn new_uper_serializable_impl<'a>(scope: &'a mut Scope, impl_for: &str) -> &'a mut Impl {
         RustCodeGenerator::new_serializable_impl(scope, impl_for, Self::CODEC)
     }
@@ -59,7 +116,9 @@ impl UperSerializer {
                 fields,
                 tag: _,
                 extension_after: _,
+                unknown_extensions: _,
                 ordering: _,
+                explicit: _,
             } => {
                 for field in fields.iter() {
                     Self::impl_read_fn_header_for_type(function, field.name(), field.r#type());
@@ -69,7 +128,7 @@ impl UperSerializer {
             Rust::Enum(r_enum) => {
                 Self::impl_read_fn_for_enum(function, name, r_enum);
             }
-            Rust::DataEnum(enumeration) => {
+            Rust::DataEnum(enumeration) | Rust::OpenType(enumeration) => {
                 Self::impl_read_fn_for_data_enum(function, name, enumeration);
             }
         };
@@ -81,7 +140,6 @@ impl UperSerializer {
             let mut block = Block::new(&format!("Ok({}(", name));
             Self::impl_read_fn_for_type(
                 &mut block,
-                &aliased.to_inner_type_string(),
                 Some(Member::Instance("0".into(), !aliased.is_primitive(), false)),
                 aliased,
             );
@@ -99,13 +157,18 @@ impl UperSerializer {
         }
     }
 
-    fn impl_read_fn_for_type(
-        block: &mut Block,
-        type_name: &str,
-        field_name: Option<Member>,
-        rust: &RustType,
-    ) {
+    fn impl_read_fn_for_type(block: &mut Block, field_name: Option<Member>, rust: &RustType) {
         match rust {
+            // tags are not encoded in PER
+            RustType::Constrained(inner, _)
+            | RustType::WithComponents(inner, _)
+            | RustType::Explicit(inner) => Self::impl_read_fn_for_type(block, field_name, inner),
+            RustType::Boxed(inner) => {
+                let mut boxed_block = Block::new("Box::new(");
+                Self::impl_read_fn_for_type(&mut boxed_block, field_name, inner);
+                boxed_block.after(")");
+                block.push_block(boxed_block);
+            }
             RustType::Bool => {
                 block.line("reader.read_bit()?");
             }
@@ -149,6 +212,19 @@ impl UperSerializer {
             RustType::BitVec(_) => {
                 block.line("reader.read_bitstring()?");
             }
+            RustType::Null => {
+                block.line("()");
+            }
+            RustType::I128(_)
+            | RustType::U128(_)
+            | RustType::BigInt(_)
+            | RustType::F64
+            | RustType::ObjectIdentifier
+            | RustType::RelativeOid
+            | RustType::Time(_)
+            | RustType::Containing(..) => {
+                unreachable!("see UperSerializer::find_unsupported_type")
+            }
             RustType::Vec(inner, _size, _ordering) => {
                 block.line("let len = reader.read_length_determinant()?;");
                 block.line("let mut values = Vec::with_capacity(len);");
@@ -157,7 +233,6 @@ impl UperSerializer {
                     let mut inner_block = Block::new("values.push(");
                     Self::impl_read_fn_for_type(
                         &mut inner_block,
-                        &inner.to_inner_type_string(),
                         Some(Member::Local(
                             field_name.map_or_else(|| "value".into(), |f| f.name().to_string()),
                             false,
@@ -176,15 +251,10 @@ impl UperSerializer {
                     "if {}",
                     field_name
                         .clone()
-                        .map_or_else(|| "value".into(), |f| f.name().to_string())
+                        .map_or_else(|| "value".into(), |f| f.ident())
                 ));
                 let mut if_true_block = Block::new("Some(");
-                Self::impl_read_fn_for_type(
-                    &mut if_true_block,
-                    &inner.to_inner_type_string(),
-                    field_name,
-                    inner,
-                );
+                Self::impl_read_fn_for_type(&mut if_true_block, field_name, inner);
                 if_true_block.after(")");
                 if_block.push_block(if_true_block);
                 let mut else_block = Block::new("else");
@@ -192,8 +262,8 @@ impl UperSerializer {
                 block.push_block(if_block);
                 block.push_block(else_block);
             }
-            RustType::Complex(_inner, _tag) => {
-                block.line(format!("{}::read_uper(reader)?", type_name));
+            RustType::Complex(inner, _tag) => {
+                block.line(format!("{}::read_uper(reader)?", inner));
             }
         };
     }
@@ -219,7 +289,6 @@ impl UperSerializer {
             ));
             Self::impl_read_fn_for_type(
                 &mut block,
-                &field.r#type().to_inner_type_string(),
                 Some(Member::Instance(field.name().to_string(), false, false)),
                 field.r#type(),
             );
@@ -284,7 +353,6 @@ impl UperSerializer {
             }
             Self::impl_read_fn_for_type(
                 &mut block_case,
-                &variant.r#type().to_inner_type_string(),
                 Some(Member::Local(
                     var_name,
                     false,
@@ -325,7 +393,9 @@ impl UperSerializer {
                 fields,
                 tag: _,
                 extension_after: _,
+                unknown_extensions: _,
                 ordering: _,
+                explicit: _,
             } => {
                 for field in fields.iter() {
                     Self::impl_write_fn_header_for_type(function, field.name(), field.r#type());
@@ -335,7 +405,7 @@ impl UperSerializer {
             Rust::Enum(r_enum) => {
                 Self::impl_write_fn_for_enum(function, name, r_enum);
             }
-            Rust::DataEnum(enumeration) => {
+            Rust::DataEnum(enumeration) | Rust::OpenType(enumeration) => {
                 Self::impl_write_fn_for_data_enum(function, name, enumeration);
             }
         }
@@ -366,6 +436,11 @@ impl UperSerializer {
 
     fn impl_write_fn_for_type(block: &mut Block, field_name: Option<Member>, rust: &RustType) {
         match rust {
+            // tags are not encoded in PER
+            RustType::Constrained(inner, _)
+            | RustType::WithComponents(inner, _)
+            | RustType::Explicit(inner) => Self::impl_write_fn_for_type(block, field_name, inner),
+            RustType::Boxed(inner) => Self::impl_write_fn_for_type(block, field_name, inner),
             RustType::Bool => {
                 block.line(format!(
                     "writer.write_bit({})?;",
@@ -430,6 +505,17 @@ impl UperSerializer {
                     field_name.map_or_else(|| "value".into(), |f| f.with_ref().to_string()),
                 ));
             }
+            RustType::Null => {}
+            RustType::I128(_)
+            | RustType::U128(_)
+            | RustType::BigInt(_)
+            | RustType::F64
+            | RustType::ObjectIdentifier
+            | RustType::RelativeOid
+            | RustType::Time(_)
+            | RustType::Containing(..) => {
+                unreachable!("see UperSerializer::find_unsupported_type")
+            }
             RustType::Vec(inner, _size, _ordering) => {
                 block.line(format!(
                     "writer.write_length_determinant({}.len())?;",
@@ -439,7 +525,7 @@ impl UperSerializer {
                 ));
                 let local_name = field_name
                     .as_ref()
-                    .map(|f| f.name().to_string())
+                    .map(Member::ident)
                     .filter(|name| name.ne("0"))
                     .unwrap_or_else(|| "value".into());
                 let mut for_block = Block::new(&format!(
@@ -468,7 +554,7 @@ impl UperSerializer {
                     if inner.is_primitive() { "" } else { "ref " },
                     field_name
                         .clone()
-                        .map_or_else(|| "value".into(), |f| f.name().to_string()),
+                        .map_or_else(|| "value".into(), |f| f.ident()),
                     field_name
                         .clone()
                         .map_or_else(|| "value".into(), |f| f.to_string()),
@@ -476,7 +562,7 @@ impl UperSerializer {
                 Self::impl_write_fn_for_type(
                     &mut if_block,
                     Some(Member::Local(
-                        field_name.map_or_else(|| "value".into(), |f| f.name().to_string()),
+                        field_name.map_or_else(|| "value".into(), |f| f.ident()),
                         false,
                         false,
                     )),
@@ -594,6 +680,14 @@ impl UperSerializer {
             }
             block.push_block(block_case);
         }
+        if let Some(unknown_extensions) = enumeration.unknown_extensions() {
+            // unknown extensions are only preserved by the UperWriter of the syn module
+            block.line(format!(
+                "{}::{} {{ .. }} => return Err(UperError::UnsupportedOperation(\
+                 \"Writing an unknown extension of {}\".to_string())),",
+                name, unknown_extensions, name
+            ));
+        }
         function.push_block(block);
         function.line("Ok(())");
     }
@@ -624,6 +718,11 @@ impl Member {
         }
     }
 
+    /// The name as a valid rust identifier, see [`RustCodeGenerator::rust_field_name`]
+    pub fn ident(&self) -> String {
+        RustCodeGenerator::rust_field_name(self.name(), true)
+    }
+
     pub fn name_mut(&mut self) -> &mut String {
         #[allow(clippy::match_same_arms)] // to have the same order as the original enum
         match self {
@@ -699,11 +798,11 @@ impl ToString for Member {
                 if *prefix_deref { "*" } else { "" },
                 name.clone()
             ),
-            Member::Instance(name, prefix_ref, prefix_deref) => format!(
+            Member::Instance(_, prefix_ref, prefix_deref) => format!(
                 "{}{}self.{}",
                 if *prefix_ref { "&" } else { "" },
                 if *prefix_deref { "*" } else { "" },
-                name.clone()
+                self.ident()
             ),
         }
     }
@@ -1,6 +1,12 @@
 use crate::gen::RustCodeGenerator;
-use crate::model::rust::{rust_module_name, DataEnum, EncodingOrdering, Field, PlainEnum};
-use crate::model::{Charset, Definition, Model, Range, Rust, RustType, Size, Tag, TagProperty};
+use crate::model::constraint::{ElementSet, InnerTypeConstraints, ValueConstraint};
+use crate::model::rust::{
+    rust_module_name, DataEnum, DataVariant, EncodingOrdering, Field, PlainEnum,
+};
+use crate::model::{
+    Charset, Definition, LiteralValue, Model, PermittedAlphabet, Range, Rust, RustType, Size, Tag,
+    TagProperty,
+};
 use codegen::{Block, Impl, Scope};
 use std::fmt::Display;
 
@@ -9,6 +15,18 @@ pub const CRATE_MODEL_PREFIX: &str = "::asn1rs::model::";
 
 pub struct AsnDefWriter;
 
+/// The properties of a `SEQUENCE` or `SET` that determine its generated constraint. A tuple struct
+/// is written as a transparent `SEQUENCE` with a single field.
+struct SequenceOrSet<'a> {
+    tag: Option<Tag>,
+    fields: &'a [Field],
+    extension_after: Option<usize>,
+    unknown_extensions: Option<&'a str>,
+    ordering: EncodingOrdering,
+    transparent: bool,
+    explicit: bool,
+}
+
 impl AsnDefWriter {
     fn write_type_definitions(
         &self,
@@ -20,7 +38,9 @@ impl AsnDefWriter {
                 fields,
                 tag: _,
                 extension_after: _,
+                unknown_extensions: _,
                 ordering,
+                explicit: _,
             } => {
                 scope.raw(&format!(
                     "type AsnDef{} = {}{}<{}>;",
@@ -33,7 +53,18 @@ impl AsnDefWriter {
                     name
                 ));
                 for field in fields {
-                    self.write_type_declaration(scope, &name, field.name(), field.r#type());
+                    if field.key().is_some() {
+                        self.write_open_type_declaration(scope, name, field.name(), field.r#type());
+                    } else if field.default().is_some() {
+                        self.write_default_type_declaration(
+                            scope,
+                            name,
+                            field.name(),
+                            field.r#type(),
+                        );
+                    } else {
+                        self.write_type_declaration(scope, name, field.name(), field.r#type());
+                    }
                 }
             }
             Rust::Enum(_enm) => {
@@ -51,6 +82,15 @@ impl AsnDefWriter {
                     self.write_type_declaration(scope, &name, variant.name(), variant.r#type());
                 }
             }
+            Rust::OpenType(enm) => {
+                scope.raw(&format!(
+                    "type AsnDef{} = {}OpenType<{}>;",
+                    name, CRATE_SYN_PREFIX, name
+                ));
+                for variant in enm.variants() {
+                    self.write_type_declaration(scope, name, variant.name(), variant.r#type());
+                }
+            }
             Rust::TupleStruct {
                 r#type: field,
                 tag: _,
@@ -68,7 +108,7 @@ impl AsnDefWriter {
     #[must_use]
     pub fn type_declaration(r#type: &RustType, name: &str) -> String {
         match r#type {
-            RustType::Bool => format!("{}Boolean", CRATE_SYN_PREFIX),
+            RustType::Bool => format!("{}Boolean<{}Constraint>", CRATE_SYN_PREFIX, name),
             RustType::I8(_) => format!("{}Integer<i8, {}Constraint>", CRATE_SYN_PREFIX, name),
             RustType::U8(_) => format!("{}Integer<u8, {}Constraint>", CRATE_SYN_PREFIX, name),
             RustType::I16(_) => format!("{}Integer<i16, {}Constraint>", CRATE_SYN_PREFIX, name),
@@ -77,12 +117,29 @@ impl AsnDefWriter {
             RustType::U32(_) => format!("{}Integer<u32, {}Constraint>", CRATE_SYN_PREFIX, name),
             RustType::I64(_) => format!("{}Integer<i64, {}Constraint>", CRATE_SYN_PREFIX, name),
             RustType::U64(_) => format!("{}Integer<u64, {}Constraint>", CRATE_SYN_PREFIX, name),
-            RustType::String(_, charset) => format!(
+            RustType::I128(_) => {
+                format!("{}WideInteger<i128, {}Constraint>", CRATE_SYN_PREFIX, name)
+            }
+            RustType::U128(_) => {
+                format!("{}WideInteger<u128, {}Constraint>", CRATE_SYN_PREFIX, name)
+            }
+            RustType::BigInt(_) => format!(
+                "{}WideInteger<{}numbers::BigInt, {}Constraint>",
+                CRATE_SYN_PREFIX, CRATE_SYN_PREFIX, name
+            ),
+            RustType::String(_, charset, _) => format!(
                 "{}{:?}String<{}Constraint>",
                 CRATE_SYN_PREFIX, charset, name
             ),
             RustType::VecU8(_) => format!("{}OctetString<{}Constraint>", CRATE_SYN_PREFIX, name),
             RustType::BitVec(_) => format!("{}BitString<{}Constraint>", CRATE_SYN_PREFIX, name),
+            RustType::Null => format!("{}Null<{}Constraint>", CRATE_SYN_PREFIX, name),
+            RustType::F64 => format!("{}Real<{}Constraint>", CRATE_SYN_PREFIX, name),
+            RustType::ObjectIdentifier => {
+                format!("{}ObjectIdentifier<{}Constraint>", CRATE_SYN_PREFIX, name)
+            }
+            RustType::RelativeOid => format!("{}RelativeOid<{}Constraint>", CRATE_SYN_PREFIX, name),
+            RustType::Time(time) => format!("{}{:?}<{}Constraint>", CRATE_SYN_PREFIX, time, name),
             RustType::Vec(inner, _, ordering) => {
                 let virtual_field = Self::vec_virtual_field_name(name);
                 format!(
@@ -97,9 +154,39 @@ impl AsnDefWriter {
                 )
             }
             RustType::Option(inner) => format!("Option<{}>", Self::type_declaration(&*inner, name)),
+            RustType::Boxed(inner) => format!(
+                "{}Boxed<{}>",
+                CRATE_SYN_PREFIX,
+                Self::type_declaration(inner, name)
+            ),
+            RustType::Constrained(inner, _) => Self::type_declaration(inner, name),
             RustType::Complex(inner, _tag) => {
                 format!("{}Complex<{}, {}Constraint>", CRATE_SYN_PREFIX, inner, name)
             }
+            RustType::WithComponents(inner, _) => format!(
+                "{}WithComponents<{}, {}Constraint>",
+                CRATE_SYN_PREFIX,
+                Self::type_declaration(inner, name),
+                name
+            ),
+            RustType::Containing(inner, _) => {
+                let virtual_field = Self::contained_virtual_field_name(name);
+                format!(
+                    "{}Containing<{}, {}Constraint>",
+                    CRATE_SYN_PREFIX,
+                    Self::type_declaration(inner, &virtual_field),
+                    name
+                )
+            }
+            RustType::Explicit(inner) => {
+                let virtual_field = Self::explicit_virtual_field_name(name);
+                format!(
+                    "{}Explicit<{}, {}Constraint>",
+                    CRATE_SYN_PREFIX,
+                    Self::type_declaration(inner, &virtual_field),
+                    name
+                )
+            }
         }
     }
 
@@ -109,6 +196,42 @@ impl AsnDefWriter {
         scope.raw(&format!("type AsnDef{} = {};", combined, type_dec));
     }
 
+    /// The open type of the field is read with the value of its key field and therefore has no
+    /// constraint of its own
+    fn write_open_type_declaration(
+        &self,
+        scope: &mut Scope,
+        base: &str,
+        name: &str,
+        r#type: &RustType,
+    ) {
+        let combined = Self::combined_field_type_name(base, name);
+        scope.raw(&format!(
+            "type AsnDef{} = {}OpenType<{}>;",
+            combined,
+            CRATE_SYN_PREFIX,
+            r#type.to_string()
+        ));
+    }
+
+    /// Wraps the declaration of [`AsnDefWriter::write_type_declaration`] so that the default
+    /// value is omitted in the encoding
+    fn write_default_type_declaration(
+        &self,
+        scope: &mut Scope,
+        base: &str,
+        name: &str,
+        r#type: &RustType,
+    ) {
+        let combined = Self::combined_field_type_name(base, name);
+        let constraint = Self::constraint_impl_name(&combined);
+        let type_dec = Self::type_declaration(r#type, &constraint);
+        scope.raw(&format!(
+            "type AsnDef{} = {}DefaultValue<{}, {}Constraint>;",
+            combined, CRATE_SYN_PREFIX, type_dec, constraint
+        ));
+    }
+
     fn constraint_impl_name(combined: &str) -> String {
         format!("___asn1rs_{}", combined)
     }
@@ -128,7 +251,9 @@ impl AsnDefWriter {
                 fields,
                 tag: _,
                 extension_after: _,
+                unknown_extensions: _,
                 ordering: _,
+                explicit: _,
             } => {
                 let constants = fields
                     .iter()
@@ -150,6 +275,7 @@ impl AsnDefWriter {
             }
             Rust::Enum(_) => {}
             Rust::DataEnum(_) => {}
+            Rust::OpenType(_) => {}
             Rust::TupleStruct {
                 r#type,
                 tag: _,
@@ -195,6 +321,8 @@ impl AsnDefWriter {
                 tag,
                 extension_after,
                 ordering,
+                unknown_extensions,
+                explicit,
             } => {
                 // ITU-T X.680 | ISO/IEC 8824-1, G.2.12.3 (SEQUENCE and SET)
                 let fields = Self::assign_implicit_tags(&fields);
@@ -202,10 +330,15 @@ impl AsnDefWriter {
                 self.write_sequence_or_set_constraint(
                     scope,
                     &name,
-                    *tag,
-                    &fields,
-                    *extension_after,
-                    *ordering,
+                    SequenceOrSet {
+                        tag: *tag,
+                        fields: &fields,
+                        extension_after: *extension_after,
+                        unknown_extensions: unknown_extensions.as_deref(),
+                        ordering: *ordering,
+                        transparent: false,
+                        explicit: *explicit,
+                    },
                 );
             }
             Rust::Enum(plain) => {
@@ -218,6 +351,9 @@ impl AsnDefWriter {
                         name_type: (variant.name().to_string(), variant.r#type().clone()),
                         tag: variant.tag(),
                         constants: Vec::default(),
+                        asn_name: None,
+                        default: None,
+                        key: None,
                     })
                     .collect::<Vec<_>>();
 
@@ -227,6 +363,23 @@ impl AsnDefWriter {
                 self.write_field_constraints(scope, &name, &fields);
                 self.write_choice_constraint(scope, &name, data)
             }
+            Rust::OpenType(data) => {
+                // the variants keep their own tags, they are not components of the open type
+                let fields = data
+                    .variants()
+                    .map(|variant| Field {
+                        name_type: (variant.name().to_string(), variant.r#type().clone()),
+                        tag: variant.tag(),
+                        constants: Vec::default(),
+                        asn_name: None,
+                        default: None,
+                        key: None,
+                    })
+                    .collect::<Vec<_>>();
+
+                self.write_field_constraints(scope, name, &fields);
+                self.write_open_type_constraint(scope, name, data)
+            }
             Rust::TupleStruct {
                 r#type,
                 tag,
@@ -236,27 +389,57 @@ impl AsnDefWriter {
                     name_type: ("0".to_string(), r#type.clone()),
                     tag: *tag,
                     constants: constants.to_vec(),
+                    asn_name: None,
+                    default: None,
+                    key: None,
                 }];
                 self.write_field_constraints(scope, &name, &fields[..]);
                 self.write_sequence_or_set_constraint(
                     scope,
                     &name,
-                    *tag,
-                    &fields[..],
-                    None,
-                    EncodingOrdering::Keep,
+                    SequenceOrSet {
+                        tag: *tag,
+                        fields: &fields[..],
+                        extension_after: None,
+                        unknown_extensions: None,
+                        ordering: EncodingOrdering::Keep,
+                        transparent: true,
+                        explicit: false,
+                    },
                 );
             }
         }
     }
 
     fn write_field_constraints(&self, scope: &mut Scope, name: &str, fields: &[Field]) {
-        for field in fields {
+        for field in fields.iter().filter(|field| field.key().is_none()) {
             let constraint_name = Self::constraint_type_name(name, field.name());
             Self::write_constraint_type_decl(scope, &constraint_name);
-            self.write_field_constraint(scope, name, field, &constraint_name)
+            self.write_field_constraint(scope, name, field, &constraint_name);
+            if let Some(default) = field.default() {
+                Self::write_default_constraint(scope, field.r#type(), default, &constraint_name);
+            }
         }
     }
+
+    fn write_default_constraint(
+        scope: &mut Scope,
+        r#type: &RustType,
+        default: &LiteralValue,
+        constraint_type_name: &str,
+    ) {
+        scope
+            .new_impl(constraint_type_name)
+            .impl_trait(format!(
+                "{}default::Constraint<{}>",
+                CRATE_SYN_PREFIX,
+                r#type.to_string()
+            ))
+            .new_fn("default_value")
+            .attr("inline")
+            .ret(r#type.to_string())
+            .line(RustCodeGenerator::default_value_expression(r#type, default));
+    }
     fn write_field_constraint(
         &self,
         scope: &mut Scope,
@@ -264,13 +447,21 @@ impl AsnDefWriter {
         field: &Field,
         constraint_type_name: &str,
     ) {
-        match field.r#type() {
+        let (r#type, values) = match field.r#type() {
+            RustType::Constrained(inner, values) => (&**inner, Some(values)),
+            r#type => (r#type, None),
+        };
+        match r#type {
             RustType::Bool => {
                 Self::write_common_constraint_type(
                     scope,
                     constraint_type_name,
                     field.tag.unwrap_or(Tag::DEFAULT_BOOLEAN),
                 );
+                scope.raw(&format!(
+                    "impl {}boolean::Constraint for {} {{}}",
+                    CRATE_SYN_PREFIX, constraint_type_name
+                ));
             }
             RustType::I8(range) => {
                 Self::write_common_constraint_type(
@@ -283,6 +474,7 @@ impl AsnDefWriter {
                     constraint_type_name,
                     &field.r#type().to_string(),
                     &range.wrap_opt(),
+                    values,
                 )
             }
             RustType::U8(range) => {
@@ -296,6 +488,7 @@ impl AsnDefWriter {
                     constraint_type_name,
                     &field.r#type().to_string(),
                     &range.wrap_opt(),
+                    values,
                 )
             }
             RustType::I16(range) => {
@@ -309,6 +502,7 @@ impl AsnDefWriter {
                     constraint_type_name,
                     &field.r#type().to_string(),
                     &range.wrap_opt(),
+                    values,
                 )
             }
             RustType::U16(range) => {
@@ -322,6 +516,7 @@ impl AsnDefWriter {
                     constraint_type_name,
                     &field.r#type().to_string(),
                     &range.wrap_opt(),
+                    values,
                 )
             }
             RustType::I32(range) => {
@@ -335,6 +530,7 @@ impl AsnDefWriter {
                     constraint_type_name,
                     &field.r#type().to_string(),
                     &range.wrap_opt(),
+                    values,
                 )
             }
             RustType::U32(range) => {
@@ -348,6 +544,7 @@ impl AsnDefWriter {
                     constraint_type_name,
                     &field.r#type().to_string(),
                     &range.wrap_opt(),
+                    values,
                 )
             }
             RustType::I64(range) => {
@@ -360,7 +557,8 @@ impl AsnDefWriter {
                     scope,
                     constraint_type_name,
                     &field.r#type().to_string(),
-                    &range.wrap_opt(),
+                    range,
+                    values,
                 )
             }
             RustType::U64(range) => {
@@ -374,25 +572,50 @@ impl AsnDefWriter {
                     constraint_type_name,
                     &field.r#type().to_string(),
                     range,
+                    values,
                 )
             }
-            RustType::String(size, charset) => {
+            RustType::I128(range) => {
                 Self::write_common_constraint_type(
                     scope,
                     constraint_type_name,
-                    field.tag.unwrap_or(match charset {
-                        Charset::Ia5 => Tag::DEFAULT_IA5_STRING,
-                        Charset::Utf8 => Tag::DEFAULT_UTF8_STRING,
-                    }),
+                    field.tag.unwrap_or(Tag::DEFAULT_INTEGER),
                 );
-                Self::write_size_constraint(
-                    match charset {
-                        Charset::Utf8 => "utf8string",
-                        Charset::Ia5 => "ia5string",
-                    },
+                Self::write_wide_integer_constraint_type(scope, constraint_type_name, range, values)
+            }
+            RustType::U128(range) => {
+                Self::write_common_constraint_type(
+                    scope,
+                    constraint_type_name,
+                    field.tag.unwrap_or(Tag::DEFAULT_INTEGER),
+                );
+                Self::write_wide_integer_constraint_type(scope, constraint_type_name, range, values)
+            }
+            RustType::BigInt(extensible) => {
+                Self::write_common_constraint_type(
+                    scope,
+                    constraint_type_name,
+                    field.tag.unwrap_or(Tag::DEFAULT_INTEGER),
+                );
+                Self::write_wide_integer_constraint_type::<i128>(
+                    scope,
+                    constraint_type_name,
+                    &Range::none().with_extensible(*extensible),
+                    values,
+                )
+            }
+            RustType::String(size, charset, alphabet) => {
+                Self::write_common_constraint_type(
+                    scope,
+                    constraint_type_name,
+                    field.tag.unwrap_or_else(|| charset.default_tag()),
+                );
+                Self::write_string_constraint(
+                    *charset,
                     scope,
                     constraint_type_name,
                     size,
+                    alphabet.as_ref(),
                 )
             }
             RustType::VecU8(size) => {
@@ -411,11 +634,71 @@ impl AsnDefWriter {
                 );
                 Self::write_size_constraint("bitstring", scope, constraint_type_name, size)
             }
+            RustType::Null => {
+                Self::write_common_constraint_type(
+                    scope,
+                    constraint_type_name,
+                    field.tag.unwrap_or(Tag::DEFAULT_NULL),
+                );
+                scope.raw(&format!(
+                    "impl {}null::Constraint for {} {{}}",
+                    CRATE_SYN_PREFIX, constraint_type_name
+                ));
+            }
+            RustType::F64 => {
+                Self::write_common_constraint_type(
+                    scope,
+                    constraint_type_name,
+                    field.tag.unwrap_or(Tag::DEFAULT_REAL),
+                );
+                scope.raw(&format!(
+                    "impl {}real::Constraint for {} {{}}",
+                    CRATE_SYN_PREFIX, constraint_type_name
+                ));
+            }
+            RustType::ObjectIdentifier => {
+                Self::write_common_constraint_type(
+                    scope,
+                    constraint_type_name,
+                    field.tag.unwrap_or(Tag::DEFAULT_OBJECT_IDENTIFIER),
+                );
+                scope.raw(&format!(
+                    "impl {}objectidentifier::Constraint for {} {{}}",
+                    CRATE_SYN_PREFIX, constraint_type_name
+                ));
+            }
+            RustType::RelativeOid => {
+                Self::write_common_constraint_type(
+                    scope,
+                    constraint_type_name,
+                    field.tag.unwrap_or(Tag::DEFAULT_RELATIVE_OID),
+                );
+                scope.raw(&format!(
+                    "impl {}relativeoid::Constraint for {} {{}}",
+                    CRATE_SYN_PREFIX, constraint_type_name
+                ));
+            }
+            RustType::Time(time) => {
+                Self::write_common_constraint_type(
+                    scope,
+                    constraint_type_name,
+                    field.tag.unwrap_or_else(|| time.default_tag()),
+                );
+                scope.raw(&format!(
+                    "impl {}{}::Constraint for {} {{}}",
+                    CRATE_SYN_PREFIX,
+                    format!("{:?}", time).to_lowercase(),
+                    constraint_type_name
+                ));
+            }
             RustType::Vec(inner, size, ordering) => {
                 Self::write_common_constraint_type(
                     scope,
                     constraint_type_name,
-                    field.tag.unwrap_or(Tag::DEFAULT_SEQUENCE_OF),
+                    field.tag.unwrap_or(match ordering {
+                        EncodingOrdering::Keep => Tag::DEFAULT_SEQUENCE_OF,
+                        EncodingOrdering::Sort => Tag::DEFAULT_SET_OF,
+                    }),
                 );
                 Self::write_size_constraint(
                     match ordering {
@@ -438,20 +721,27 @@ impl AsnDefWriter {
                         name_type: (virtual_field_name, *inner.clone()),
                         tag: None,
                         constants: field.constants().to_vec(),
+                        asn_name: None,
+                        default: None,
+                        key: None,
                     },
                     &constraint_type_name,
                 )
             }
-            RustType::Option(inner) => self.write_field_constraint(
+            RustType::Option(inner) | RustType::Boxed(inner) => self.write_field_constraint(
                 scope,
                 name,
                 &Field {
                     name_type: (field.name().to_string(), *inner.clone()),
                     tag: field.tag(),
                     constants: field.constants().to_vec(),
+                    asn_name: None,
+                    default: None,
+                    key: None,
                 },
                 constraint_type_name,
             ),
+            RustType::Constrained(..) => unreachable!("The full constraint is not nested"),
             RustType::Complex(_, tag) => {
                 self.write_complex_constraint(
                     scope,
@@ -466,6 +756,155 @@ impl AsnDefWriter {
                     }),
                 );
             }
+            RustType::WithComponents(inner, constraints) => {
+                self.write_field_constraint(
+                    scope,
+                    name,
+                    &Field {
+                        name_type: (field.name().to_string(), *inner.clone()),
+                        tag: field.tag(),
+                        constants: field.constants().to_vec(),
+                        asn_name: None,
+                        default: None,
+                        key: None,
+                    },
+                    constraint_type_name,
+                );
+                scope.raw(&format!(
+                    "impl {}components::Constraint for {} {{",
+                    CRATE_SYN_PREFIX, constraint_type_name
+                ));
+                scope.raw(&format!(
+                    "const COMPONENTS: {}components::InnerType = {};",
+                    CRATE_SYN_PREFIX,
+                    Self::inner_type_str(constraints)
+                ));
+                scope.raw("}");
+            }
+            RustType::Containing(inner, container) => {
+                Self::write_common_constraint_type(
+                    scope,
+                    constraint_type_name,
+                    field.tag.unwrap_or_else(|| container.default_tag()),
+                );
+                scope.raw(&format!(
+                    "impl {}octetstring::Constraint for {} {{}}",
+                    CRATE_SYN_PREFIX, constraint_type_name
+                ));
+                scope.raw(&format!(
+                    "impl {}bitstring::Constraint for {} {{}}",
+                    CRATE_SYN_PREFIX, constraint_type_name
+                ));
+                scope.raw(&format!(
+                    "impl {}containing::Constraint for {} {{",
+                    CRATE_SYN_PREFIX, constraint_type_name
+                ));
+                scope.raw(&format!(
+                    "const CONTAINER: {}Container = {}Container::{:?};",
+                    CRATE_MODEL_PREFIX, CRATE_MODEL_PREFIX, container
+                ));
+                scope.raw("}");
+
+                let virtual_field_name = Self::contained_virtual_field_name(field.name());
+                let constraint_type_name = Self::constraint_type_name(name, &virtual_field_name);
+                Self::write_constraint_type_decl(scope, &constraint_type_name);
+
+                self.write_field_constraint(
+                    scope,
+                    name,
+                    &Field {
+                        name_type: (virtual_field_name, *inner.clone()),
+                        tag: None,
+                        constants: field.constants().to_vec(),
+                        asn_name: None,
+                        default: None,
+                        key: None,
+                    },
+                    &constraint_type_name,
+                )
+            }
+            RustType::Explicit(inner) => {
+                Self::write_common_constraint_type(
+                    scope,
+                    constraint_type_name,
+                    field.tag.unwrap_or_else(|| {
+                        panic!("The explicitly tagged field {} has no tag", field.name())
+                    }),
+                );
+                scope.raw(&format!(
+                    "impl {}explicit::Constraint for {} {{}}",
+                    CRATE_SYN_PREFIX, constraint_type_name
+                ));
+
+                // the value is encoded with its own tag within the explicit tag
+                let virtual_field_name = Self::explicit_virtual_field_name(field.name());
+                let constraint_type_name = Self::constraint_type_name(name, &virtual_field_name);
+                Self::write_constraint_type_decl(scope, &constraint_type_name);
+
+                self.write_field_constraint(
+                    scope,
+                    name,
+                    &Field {
+                        name_type: (virtual_field_name, *inner.clone()),
+                        tag: None,
+                        constants: field.constants().to_vec(),
+                        asn_name: None,
+                        default: None,
+                        key: None,
+                    },
+                    &constraint_type_name,
+                )
+            }
+        }
+    }
+
+    fn inner_type_str(constraints: &InnerTypeConstraints<i128>) -> String {
+        let components = constraints
+            .components
+            .iter()
+            .map(|component| {
+                format!(
+                    "{}components::ComponentConstraint {{ name: \"{}\", value: {}, presence: {} }}",
+                    CRATE_SYN_PREFIX,
+                    component.name,
+                    match &component.value {
+                        None => "None".to_string(),
+                        Some(value) => format!("Some({})", Self::value_constraint_str(value)),
+                    },
+                    match component.presence {
+                        None => "None".to_string(),
+                        Some(presence) => format!(
+                            "Some({}components::Presence::{:?})",
+                            CRATE_SYN_PREFIX, presence
+                        ),
+                    }
+                )
+            })
+            .collect::<Vec<_>>();
+        format!(
+            "{}components::InnerType {{ partial: {}, components: &[{}], element: {} }}",
+            CRATE_SYN_PREFIX,
+            constraints.partial,
+            components.join(", "),
+            match &constraints.element {
+                None => "None".to_string(),
+                Some(element) => format!("Some(&{})", Self::value_constraint_str(element)),
+            }
+        )
+    }
+
+    fn value_constraint_str(value: &ValueConstraint<i128>) -> String {
+        match value {
+            ValueConstraint::Integer(values) => format!(
+                "{}components::ValueConstraint::Integer(&[{}])",
+                CRATE_SYN_PREFIX,
+                Self::permitted_values_str(values, |value| value)
+            ),
+            ValueConstraint::Components(inner) => format!(
+                "{}components::ValueConstraint::Components({})",
+                CRATE_SYN_PREFIX,
+                Self::inner_type_str(inner)
+            ),
         }
     }
 
@@ -480,16 +919,36 @@ impl AsnDefWriter {
         field_name.to_string() + "Values"
     }
 
+    fn contained_virtual_field_name(field_name: &str) -> String {
+        field_name.to_string() + "Contained"
+    }
+
+    fn explicit_virtual_field_name(field_name: &str) -> String {
+        field_name.to_string() + "Explicit"
+    }
+
     fn write_sequence_or_set_constraint(
         &self,
         scope: &mut Scope,
         name: &str,
-        tag: Option<Tag>,
-        fields: &[Field],
-        extension_after_field: Option<usize>,
-        ordering: EncodingOrdering,
+        SequenceOrSet {
+            tag,
+            fields,
+            extension_after: extension_after_field,
+            unknown_extensions,
+            ordering,
+            transparent,
+            explicit,
+        }: SequenceOrSet,
     ) {
-        Self::write_common_constraint_type(scope, name, tag.unwrap_or(Tag::DEFAULT_SEQUENCE));
+        Self::write_common_constraint_type(
+            scope,
+            name,
+            tag.unwrap_or(match ordering {
+                EncodingOrdering::Keep => Tag::DEFAULT_SEQUENCE,
+                EncodingOrdering::Sort => Tag::DEFAULT_SET,
+            }),
+        );
 
         let sorted;
         let (fields, module) = match ordering {
@@ -503,14 +962,16 @@ impl AsnDefWriter {
         let mut imp = Impl::new(name);
         imp.impl_trait(format!("{}{}::Constraint", CRATE_SYN_PREFIX, module));
 
-        self.write_sequence_or_set_constraint_read_fn(&mut imp, name, fields);
-        self.write_sequence_or_set_constraint_write_fn(&mut imp, name, fields);
+        self.write_sequence_or_set_constraint_read_fn(&mut imp, name, fields, unknown_extensions);
+        self.write_sequence_or_set_constraint_write_fn(&mut imp, name, fields, unknown_extensions);
 
         Self::write_sequence_constraint_insert_consts(
             scope,
             name,
             fields,
             extension_after_field,
+            transparent,
+            explicit,
             imp,
         );
     }
@@ -542,6 +1003,220 @@ impl AsnDefWriter {
             .line(format!("AsnDef{}::write_value(writer, self)", name));
     }
 
+    /// Every type provides access to its components, so that referencing types can check their
+    /// inner subtype constraints (`WITH COMPONENTS`)
+    fn impl_components(&self, scope: &mut Scope, Definition(name, r#type): &Definition<Rust>) {
+        let imp = scope
+            .new_impl(name)
+            .impl_trait(format!("{}components::Components", CRATE_SYN_PREFIX));
+
+        match r#type {
+            Rust::Struct { fields, .. } if !fields.is_empty() => {
+                Self::impl_optional_components(
+                    imp,
+                    fields
+                        .iter()
+                        .filter(|field| field.r#type().is_option())
+                        .map(Field::asn_name),
+                );
+                let mut match_block = Block::new("match name");
+                for field in fields {
+                    let field_name = RustCodeGenerator::rust_field_name(field.name(), true);
+                    match_block.line(format!(
+                        "\"{}\" => {},",
+                        field.asn_name(),
+                        Self::component_option_str(field.r#type(), &format!("self.{}", field_name))
+                    ));
+                }
+                match_block.line("_ => None,");
+                Self::impl_component(imp, match_block);
+            }
+            Rust::DataEnum(choice) => {
+                Self::impl_optional_components(imp, choice.variants().map(DataVariant::asn_name));
+                let mut match_block = Block::new("match (self, name)");
+                for variant in choice.variants() {
+                    match_block.line(format!(
+                        "(Self::{}(value), \"{}\") => {},",
+                        RustCodeGenerator::rust_variant_name(variant.name()),
+                        variant.asn_name(),
+                        Self::component_option_str(variant.r#type(), "*value")
+                    ));
+                }
+                match_block.line("_ => None,");
+                Self::impl_component(imp, match_block);
+            }
+            Rust::TupleStruct { r#type, .. } => match r#type {
+                r#type if Self::has_components(r#type) => {
+                    Self::impl_delegated_components(imp);
+                }
+                RustType::Vec(inner, ..) => {
+                    imp.new_fn("elements")
+                        .attr("inline")
+                        .arg_ref_self()
+                        .ret(format!(
+                            "Vec<{}components::Component<'_>>",
+                            CRATE_SYN_PREFIX
+                        ))
+                        .line(Self::elements_str(inner, "self.0"));
+                }
+                r#type => {
+                    if let Some(integer) = Self::integer_str(r#type, "self.0") {
+                        imp.new_fn("integer")
+                            .attr("inline")
+                            .arg_ref_self()
+                            .ret("Option<i128>")
+                            .line(format!("Some({})", integer));
+                    }
+                }
+            },
+            Rust::Struct { .. } | Rust::Enum(_) | Rust::OpenType(_) => {}
+        }
+    }
+
+    /// The components of the wrapped type, see [`RustType::Complex`]
+    fn impl_delegated_components(imp: &mut Impl) {
+        imp.new_fn("optional_components")
+            .attr("inline")
+            .arg_ref_self()
+            .ret("&'static [&'static str]")
+            .line("self.0.optional_components()");
+        imp.new_fn("component")
+            .attr("inline")
+            .arg_ref_self()
+            .arg("name", "&str")
+            .ret(format!(
+                "Option<{}components::Component<'_>>",
+                CRATE_SYN_PREFIX
+            ))
+            .line("self.0.component(name)");
+        imp.new_fn("integer")
+            .attr("inline")
+            .arg_ref_self()
+            .ret("Option<i128>")
+            .line("self.0.integer()");
+        imp.new_fn("elements")
+            .attr("inline")
+            .arg_ref_self()
+            .ret(format!(
+                "Vec<{}components::Component<'_>>",
+                CRATE_SYN_PREFIX
+            ))
+            .line("self.0.elements()");
+    }
+
+    fn impl_optional_components<'a>(imp: &mut Impl, names: impl Iterator<Item = &'a str>) {
+        imp.new_fn("optional_components")
+            .attr("inline")
+            .arg_ref_self()
+            .ret("&'static [&'static str]")
+            .line(format!(
+                "&[{}]",
+                names
+                    .map(|name| format!("\"{}\"", name))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+    }
+
+    fn impl_component(imp: &mut Impl, match_block: Block) {
+        imp.new_fn("component")
+            .attr("inline")
+            .arg_ref_self()
+            .arg("name", "&str")
+            .ret(format!(
+                "Option<{}components::Component<'_>>",
+                CRATE_SYN_PREFIX
+            ))
+            .push_block(match_block);
+    }
+
+    /// Whether the value of the given type has components of its own
+    fn has_components(r#type: &RustType) -> bool {
+        match r#type {
+            RustType::Complex(..) | RustType::WithComponents(..) => true,
+            RustType::Explicit(inner) | RustType::Boxed(inner) => Self::has_components(inner),
+            _ => false,
+        }
+    }
+
+    /// The optional component for the given place expression of the given type
+    fn component_option_str(r#type: &RustType, place: &str) -> String {
+        match r#type {
+            RustType::Option(inner) => format!(
+                "{}.as_ref().map(|value| {})",
+                place,
+                Self::component_str(inner, "*value")
+            ),
+            r#type => format!("Some({})", Self::component_str(r#type, place)),
+        }
+    }
+
+    fn component_str(r#type: &RustType, place: &str) -> String {
+        match r#type {
+            RustType::Constrained(inner, _)
+            | RustType::Containing(inner, _)
+            | RustType::Explicit(inner) => Self::component_str(inner, place),
+            RustType::Boxed(inner) => Self::component_str(inner, &format!("*{}", place)),
+            RustType::Complex(..) | RustType::WithComponents(..) => format!(
+                "{}components::Component::Components(&{})",
+                CRATE_SYN_PREFIX, place
+            ),
+            RustType::Vec(inner, ..) => format!(
+                "{}components::Component::Elements({})",
+                CRATE_SYN_PREFIX,
+                Self::elements_str(inner, place)
+            ),
+            r#type => match Self::integer_str(r#type, place) {
+                Some(integer) => format!(
+                    "{}components::Component::Integer({})",
+                    CRATE_SYN_PREFIX, integer
+                ),
+                None => format!("{}components::Component::Other", CRATE_SYN_PREFIX),
+            },
+        }
+    }
+
+    /// The elements of the given place expression of a `Vec` with the given element type
+    fn elements_str(inner: &RustType, place: &str) -> String {
+        format!(
+            "{}.iter().map(|value| {}).collect()",
+            Self::receiver_str(place),
+            Self::component_str(inner, "*value")
+        )
+    }
+
+    /// The given place expression as receiver of a method call
+    fn receiver_str(place: &str) -> String {
+        if place.starts_with('*') {
+            format!("({})", place)
+        } else {
+            place.to_string()
+        }
+    }
+
+    /// The value of the given place expression as `i128`, if it is an integer of up to 128 bits
+    fn integer_str(r#type: &RustType, place: &str) -> Option<String> {
+        match r#type {
+            RustType::I8(_)
+            | RustType::U8(_)
+            | RustType::I16(_)
+            | RustType::U16(_)
+            | RustType::I32(_)
+            | RustType::U32(_)
+            | RustType::I64(_)
+            | RustType::U64(_) => Some(format!("i128::from({})", place)),
+            RustType::I128(_) => Some(place.to_string()),
+            RustType::U128(_) => Some(format!(
+                "{}.min(i128::MAX as u128) as i128",
+                Self::receiver_str(place)
+            )),
+            RustType::Constrained(inner, _) | RustType::Explicit(inner) => {
+                Self::integer_str(inner, place)
+            }
+            _ => None,
+        }
+    }
+
     fn write_enumerated_constraint(&self, scope: &mut Scope, name: &str, enumerated: &PlainEnum) {
         Self::write_common_constraint_type(
             scope,
@@ -558,7 +1233,17 @@ impl AsnDefWriter {
             .push_block({
                 let mut match_block = Block::new("match self");
                 for (index, variant) in enumerated.variants().enumerate() {
-                    match_block.line(format!("Self::{} => {},", variant, index));
+                    match_block.line(format!(
+                        "Self::{} => {},",
+                        RustCodeGenerator::rust_variant_name(variant.name()),
+                        index
+                    ));
+                }
+                if let Some(unknown_extensions) = enumerated.unknown_extensions() {
+                    match_block.line(format!(
+                        "Self::{} {{ index }} => *index,",
+                        unknown_extensions
+                    ));
                 }
                 match_block
             });
@@ -570,27 +1255,52 @@ impl AsnDefWriter {
             .push_block({
                 let mut match_block = Block::new("match index");
                 for (index, variant) in enumerated.variants().enumerate() {
-                    match_block.line(format!("{} => Some(Self::{}),", index, variant));
+                    match_block.line(format!(
+                        "{} => Some(Self::{}),",
+                        index,
+                        RustCodeGenerator::rust_variant_name(variant.name())
+                    ));
+                }
+                if let Some(unknown_extensions) = enumerated.unknown_extensions() {
+                    match_block.line(format!(
+                        "index => Some(Self::{} {{ index }}),",
+                        unknown_extensions
+                    ));
+                } else {
+                    match_block.line("_ => None,");
                 }
-                match_block.line("_ => None,");
                 match_block
             });
 
         Self::insert_consts(
             scope,
             imp,
-            &[
-                format!("const NAME: &'static str = \"{}\";", name),
-                format!("const VARIANT_COUNT: u64 = {};", enumerated.len()),
-                format!(
-                    "const STD_VARIANT_COUNT: u64 = {};",
-                    enumerated
-                        .extension_after_index()
-                        .map(|v| v + 1)
-                        .unwrap_or_else(|| enumerated.len())
-                ),
-                format!("const EXTENSIBLE: bool = {};", enumerated.is_extensible()),
-            ],
+            enumerated
+                .is_explicit()
+                .then(|| "const EXPLICIT: bool = true;".to_string())
+                .into_iter()
+                .chain([
+                    format!("const NAME: &'static str = \"{}\";", name),
+                    Self::names_const("VARIANT_NAMES", enumerated.variants().map(|v| v.asn_name())),
+                    format!(
+                        "const VARIANT_VALUES: &'static [i64] = &[{}];",
+                        enumerated
+                            .variant_values()
+                            .iter()
+                            .map(ToString::to_string)
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ),
+                    format!("const VARIANT_COUNT: u64 = {};", enumerated.len()),
+                    format!(
+                        "const STD_VARIANT_COUNT: u64 = {};",
+                        enumerated
+                            .extension_after_index()
+                            .map(|v| v + 1)
+                            .unwrap_or_else(|| enumerated.len())
+                    ),
+                    format!("const EXTENSIBLE: bool = {};", enumerated.is_extensible()),
+                ]),
         );
     }
 
@@ -612,7 +1322,17 @@ impl AsnDefWriter {
             .push_block({
                 let mut match_block = Block::new("match self");
                 for (index, variant) in choice.variants().enumerate() {
-                    match_block.line(format!("Self::{}(_) => {},", variant.name(), index));
+                    match_block.line(format!(
+                        "Self::{}(_) => {},",
+                        RustCodeGenerator::rust_variant_name(variant.name()),
+                        index
+                    ));
+                }
+                if let Some(unknown_extensions) = choice.unknown_extensions() {
+                    match_block.line(format!(
+                        "Self::{} {{ index, .. }} => *index,",
+                        unknown_extensions
+                    ));
                 }
                 match_block
             });
@@ -629,10 +1349,14 @@ impl AsnDefWriter {
                     let combined = Self::combined_field_type_name(name, variant.name());
                     match_block.line(format!(
                         "Self::{}(c) => AsnDef{}::write_value(writer, c),",
-                        variant.name(),
+                        RustCodeGenerator::rust_variant_name(variant.name()),
                         combined
                     ));
                 }
+                if let Some(unknown_extensions) = choice.unknown_extensions() {
+                    // the content is written by the writer itself, see `unknown_extension`
+                    match_block.line(format!("Self::{} {{ .. }} => Ok(()),", unknown_extensions));
+                }
                 match_block
             });
 
@@ -649,7 +1373,7 @@ impl AsnDefWriter {
                     match_block.line(format!(
                         "{} => Ok(Some(Self::{}(AsnDef{}::read_value(reader)?))),",
                         index,
-                        variant.name(),
+                        RustCodeGenerator::rust_variant_name(variant.name()),
                         combined
                     ));
                 }
@@ -657,21 +1381,163 @@ impl AsnDefWriter {
                 match_block
             });
 
+        if let Some(unknown_extensions) = choice.unknown_extensions() {
+            imp.new_fn("from_unknown_extension")
+                .attr("inline")
+                .arg("index", "u64")
+                .arg("bytes", "Vec<u8>")
+                .ret("Option<Self>")
+                .line(format!(
+                    "Some(Self::{} {{ index, bytes }})",
+                    unknown_extensions
+                ));
+
+            imp.new_fn("unknown_extension")
+                .attr("inline")
+                .arg_ref_self()
+                .ret("Option<&[u8]>")
+                .push_block({
+                    let mut match_block = Block::new("match self");
+                    match_block.line(format!(
+                        "Self::{} {{ bytes, .. }} => Some(&bytes[..]),",
+                        unknown_extensions
+                    ));
+                    match_block.line("_ => None,");
+                    match_block
+                });
+        }
+
         Self::insert_consts(
             scope,
             imp,
-            &[
-                format!("const NAME: &'static str = \"{}\";", name),
-                format!("const VARIANT_COUNT: u64 = {};", choice.len()),
-                format!(
-                    "const STD_VARIANT_COUNT: u64 = {};",
-                    choice
-                        .extension_after_index()
-                        .map(|v| v + 1)
-                        .unwrap_or_else(|| choice.len())
-                ),
-                format!("const EXTENSIBLE: bool = {};", choice.is_extensible()),
-            ],
+            choice
+                .is_explicit()
+                .then(|| "const EXPLICIT: bool = true;".to_string())
+                .into_iter()
+                .chain([
+                    format!("const NAME: &'static str = \"{}\";", name),
+                    Self::names_const("VARIANT_NAMES", choice.variants().map(|v| v.asn_name())),
+                    format!("const VARIANT_COUNT: u64 = {};", choice.len()),
+                    format!(
+                        "const STD_VARIANT_COUNT: u64 = {};",
+                        choice
+                            .extension_after_index()
+                            .map(|v| v + 1)
+                            .unwrap_or_else(|| choice.len())
+                    ),
+                    format!("const EXTENSIBLE: bool = {};", choice.is_extensible()),
+                ]),
+        );
+    }
+
+    fn write_open_type_constraint(&self, scope: &mut Scope, name: &str, open_type: &DataEnum) {
+        let mut imp = Impl::new(name);
+        imp.impl_trait(format!("{}opentype::Constraint", CRATE_SYN_PREFIX));
+
+        imp.new_fn("key")
+            .attr("inline")
+            .arg_ref_self()
+            .ret("i64")
+            .push_block({
+                let mut match_block = Block::new("match self");
+                for variant in open_type.variants() {
+                    match_block.line(format!(
+                        "Self::{}(_) => {},",
+                        RustCodeGenerator::rust_variant_name(variant.name()),
+                        variant.key().unwrap_or_else(|| panic!(
+                            "The variant {}::{} of the open type has no key",
+                            name,
+                            variant.name()
+                        )),
+                    ));
+                }
+                if let Some(unknown_extensions) = open_type.unknown_extensions() {
+                    match_block.line(format!(
+                        "Self::{} {{ key, .. }} => *key,",
+                        unknown_extensions
+                    ));
+                }
+                match_block
+            });
+
+        imp.new_fn("write_content")
+            .attr("inline")
+            .generic(&format!("W: {}Writer", CRATE_SYN_PREFIX))
+            .arg_ref_self()
+            .arg("writer", "&mut W")
+            .ret("Result<(), W::Error>")
+            .push_block({
+                let mut match_block = Block::new("match self");
+                for variant in open_type.variants() {
+                    let combined = Self::combined_field_type_name(name, variant.name());
+                    match_block.line(format!(
+                        "Self::{}(c) => AsnDef{}::write_value(writer, c),",
+                        RustCodeGenerator::rust_variant_name(variant.name()),
+                        combined
+                    ));
+                }
+                if let Some(unknown_extensions) = open_type.unknown_extensions() {
+                    // the content is written by the writer itself, see `unknown`
+                    match_block.line(format!("Self::{} {{ .. }} => Ok(()),", unknown_extensions));
+                }
+                match_block
+            });
+
+        imp.new_fn("read_content")
+            .attr("inline")
+            .generic(&format!("R: {}Reader", CRATE_SYN_PREFIX))
+            .arg("key", "i64")
+            .arg("reader", "&mut R")
+            .ret("Result<Option<Self>, R::Error>")
+            .push_block({
+                let mut match_block = Block::new("match key");
+                for variant in open_type.variants() {
+                    let combined = Self::combined_field_type_name(name, variant.name());
+                    match_block.line(format!(
+                        "{} => Ok(Some(Self::{}(AsnDef{}::read_value(reader)?))),",
+                        variant.key().unwrap_or_else(|| panic!(
+                            "The variant {}::{} of the open type has no key",
+                            name,
+                            variant.name()
+                        )),
+                        RustCodeGenerator::rust_variant_name(variant.name()),
+                        combined
+                    ));
+                }
+                match_block.line("_ => Ok(None),");
+                match_block
+            });
+
+        if let Some(unknown_extensions) = open_type.unknown_extensions() {
+            imp.new_fn("from_unknown")
+                .attr("inline")
+                .arg("key", "i64")
+                .arg("bytes", "Vec<u8>")
+                .ret("Option<Self>")
+                .line(format!(
+                    "Some(Self::{} {{ key, bytes }})",
+                    unknown_extensions
+                ));
+
+            imp.new_fn("unknown")
+                .attr("inline")
+                .arg_ref_self()
+                .ret("Option<&[u8]>")
+                .push_block({
+                    let mut match_block = Block::new("match self");
+                    match_block.line(format!(
+                        "Self::{} {{ bytes, .. }} => Some(&bytes[..]),",
+                        unknown_extensions
+                    ));
+                    match_block.line("_ => None,");
+                    match_block
+                });
+        }
+
+        Self::insert_consts(
+            scope,
+            imp,
+            &[format!("const NAME: &'static str = \"{}\";", name)],
         );
     }
 
@@ -692,6 +1558,7 @@ impl AsnDefWriter {
         constraint_type_name: &str,
         r#type: &str,
         range: &Range<Option<T>>,
+        values: Option<&ElementSet<i128>>,
     ) {
         scope.raw(&format!(
             "impl {}numbers::Constraint<{}> for {} {{",
@@ -710,9 +1577,51 @@ impl AsnDefWriter {
             scope.raw(&format!("const MAX_T: Option<{}> = Some({});", r#type, max));
         }
         scope.raw(&format!("const EXTENSIBLE: bool = {};", range.extensible()));
+        if let Some(values) = values {
+            let clamp = |value: i128| value.clamp(i128::from(i64::MIN), i128::from(i64::MAX));
+            scope.raw(&format!(
+                "const PERMITTED: Option<&'static [(i64, i64)]> = Some(&[{}]);",
+                Self::permitted_values_str(values, clamp)
+            ));
+        }
+        scope.raw("}");
+    }
+
+    fn write_wide_integer_constraint_type<T: Display>(
+        scope: &mut Scope,
+        constraint_type_name: &str,
+        range: &Range<Option<T>>,
+        values: Option<&ElementSet<i128>>,
+    ) {
+        scope.raw(&format!(
+            "impl {}numbers::WideConstraint for {} {{",
+            CRATE_SYN_PREFIX, constraint_type_name
+        ));
+        if let Some(min) = range.min() {
+            scope.raw(&format!("const MIN: Option<i128> = Some({});", min));
+        }
+        if let Some(max) = range.max() {
+            scope.raw(&format!("const MAX: Option<i128> = Some({});", max));
+        }
+        scope.raw(&format!("const EXTENSIBLE: bool = {};", range.extensible()));
+        if let Some(values) = values {
+            scope.raw(&format!(
+                "const PERMITTED: Option<&'static [(i128, i128)]> = Some(&[{}]);",
+                Self::permitted_values_str(values, |value| value)
+            ));
+        }
         scope.raw("}");
     }
 
+    fn permitted_values_str(values: &ElementSet<i128>, map: impl Fn(i128) -> i128) -> String {
+        values
+            .permitted_values()
+            .into_iter()
+            .map(|(min, max)| format!("({}, {})", map(min), map(max)))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
     fn constraint_type_name(name: &str, field: &str) -> String {
         let combined = Self::combined_field_type_name(name, field) + "Constraint";
         Self::constraint_impl_name(&combined)
@@ -732,6 +1641,11 @@ impl AsnDefWriter {
             "impl {}{}::Constraint for {} {{",
             CRATE_SYN_PREFIX, module, constraint_type_name
         ));
+        Self::write_size_consts(scope, size);
+        scope.raw("}");
+    }
+
+    fn write_size_consts(scope: &mut Scope, size: &Size) {
         if let Some(min) = size.min() {
             scope.raw(&format!("const MIN: Option<u64> = Some({});", min));
         }
@@ -739,6 +1653,35 @@ impl AsnDefWriter {
             scope.raw(&format!("const MAX: Option<u64> = Some({});", max));
         }
         scope.raw(&format!("const EXTENSIBLE: bool = {};", size.extensible()));
+    }
+
+    /// The permitted alphabet is only relevant for the known-multiplier character string types,
+    /// `UTF8String` has no `ALPHABET` const
+    fn write_string_constraint(
+        charset: Charset,
+        scope: &mut Scope,
+        constraint_type_name: &str,
+        size: &Size,
+        alphabet: Option<&PermittedAlphabet>,
+    ) {
+        scope.raw(&format!(
+            "impl {}{}::Constraint for {} {{",
+            CRATE_SYN_PREFIX,
+            format!("{:?}string", charset).to_lowercase(),
+            constraint_type_name
+        ));
+        Self::write_size_consts(scope, size);
+        if let Some(alphabet) = alphabet.filter(|_| charset != Charset::Utf8) {
+            scope.raw(&format!(
+                "const ALPHABET: &'static [(u32, u32)] = &[{}];",
+                alphabet
+                    .ranges()
+                    .iter()
+                    .map(|(min, max)| format!("({}, {})", min, max))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+        }
         scope.raw("}");
     }
 
@@ -747,33 +1690,52 @@ impl AsnDefWriter {
         name: &str,
         fields: &[Field],
         extension_after_field: Option<usize>,
+        transparent: bool,
+        explicit: bool,
         imp: Impl,
     ) {
         Self::insert_consts(
             scope,
             imp,
-            &[
-                format!(
-                    "const EXTENDED_AFTER_FIELD: Option<u64> = {:?};",
-                    extension_after_field
-                ),
-                format!("const FIELD_COUNT: u64 = {};", fields.len()),
-                format!(
-                    "const STD_OPTIONAL_FIELDS: u64 = {};",
-                    fields
-                        .iter()
-                        .enumerate()
-                        .take_while(
-                            |(index, _f)| *index <= extension_after_field.unwrap_or(usize::MAX)
-                        )
-                        .filter(|(_index, f)| f.r#type().is_option())
-                        .count()
-                ),
-                format!("const NAME: &'static str = \"{}\";", name),
-            ],
+            transparent
+                .then(|| "const TRANSPARENT: bool = true;".to_string())
+                .into_iter()
+                .chain(explicit.then(|| "const EXPLICIT: bool = true;".to_string()))
+                .chain([
+                    format!(
+                        "const EXTENDED_AFTER_FIELD: Option<u64> = {:?};",
+                        extension_after_field
+                    ),
+                    format!("const FIELD_COUNT: u64 = {};", fields.len()),
+                    format!(
+                        "const STD_OPTIONAL_FIELDS: u64 = {};",
+                        fields
+                            .iter()
+                            .enumerate()
+                            .take_while(
+                                |(index, _f)| *index <= extension_after_field.unwrap_or(usize::MAX)
+                            )
+                            .filter(|(_index, f)| f.r#type().is_option() || f.default().is_some())
+                            .count()
+                    ),
+                    Self::names_const("FIELD_NAMES", fields.iter().map(Field::asn_name)),
+                    format!("const NAME: &'static str = \"{}\";", name),
+                ]),
         );
     }
 
+    /// The identifiers as in the ASN.1 definition, for encodings such as JER that rely on them
+    fn names_const<'a>(const_name: &str, names: impl Iterator<Item = &'a str>) -> String {
+        format!(
+            "const {}: &'static [&'static str] = &[{}];",
+            const_name,
+            names
+                .map(|name| format!("{:?}", name))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+
     fn insert_consts<S: ToString, I: IntoIterator<Item = S>>(
         scope: &mut Scope,
         imp: Impl,
@@ -794,27 +1756,87 @@ impl AsnDefWriter {
         imp: &mut Impl,
         name: &str,
         fields: &[Field],
+        unknown_extensions: Option<&str>,
     ) {
-        imp.new_fn("read_seq")
+        let function = imp
+            .new_fn("read_seq")
             .attr("inline")
             .generic(&format!("R: {}Reader", CRATE_SYN_PREFIX))
             .arg("reader", "&mut R")
             .ret("Result<Self, R::Error>")
-            .bound("Self", "Sized")
-            .push_block({
+            .bound("Self", "Sized");
+
+        if fields.iter().any(|field| field.key().is_some()) {
+            // the values of the keys must be known before their open types are read
+            for field in fields {
+                let combined = Self::combined_field_type_name(name, field.name());
+                let field_name = RustCodeGenerator::rust_field_name(field.name(), true);
+                if let Some(key) = field.key() {
+                    let key_field = fields
+                        .iter()
+                        .find(|f| RustCodeGenerator::rust_field_name(f.name(), true).eq(key))
+                        .unwrap_or_else(|| {
+                            panic!("Cannot find the key {} of the field {}", key, field.name())
+                        });
+                    function.line(format!(
+                        "let {} = AsnDef{}::read_with_key(reader, {}{} as i64)?;",
+                        field_name,
+                        combined,
+                        if matches!(key_field.r#type(), RustType::Complex(..)) {
+                            "*"
+                        } else {
+                            ""
+                        },
+                        key
+                    ));
+                } else {
+                    function.line(format!(
+                        "let {} = AsnDef{}::read_value(reader)?;",
+                        field_name, combined
+                    ));
+                }
+            }
+            function.push_block({
+                let mut block = Block::new("Ok(Self");
+                for field in fields {
+                    block.line(format!(
+                        "{},",
+                        RustCodeGenerator::rust_field_name(field.name(), true)
+                    ));
+                }
+                if let Some(unknown_extensions) = unknown_extensions {
+                    block.line(format!(
+                        "{}: reader.read_unknown_extensions()?,",
+                        unknown_extensions
+                    ));
+                }
+                block.after(")");
+                block
+            });
+        } else {
+            function.push_block({
                 let mut block = Block::new("Ok(Self");
 
                 for field in fields {
                     block.line(format!(
                         "{}: AsnDef{}::read_value(reader)?,",
-                        field.name(),
+                        RustCodeGenerator::rust_field_name(field.name(), true),
                         Self::combined_field_type_name(name, field.name())
                     ));
                 }
 
+                if let Some(unknown_extensions) = unknown_extensions {
+                    // after all known fields, so the unknown ones are next in line
+                    block.line(format!(
+                        "{}: reader.read_unknown_extensions()?,",
+                        unknown_extensions
+                    ));
+                }
+
                 block.after(")");
                 block
             });
+        }
     }
 
     fn write_sequence_or_set_constraint_write_fn(
@@ -822,6 +1844,7 @@ impl AsnDefWriter {
         imp: &mut Impl,
         name: &str,
         fields: &[Field],
+        unknown_extensions: Option<&str>,
     ) {
         let body = imp
             .new_fn("write_seq")
@@ -831,14 +1854,42 @@ impl AsnDefWriter {
             .arg("writer", "&mut W")
             .ret("Result<(), W::Error>");
 
-        for field in fields {
+        if let Some(unknown_extensions) = unknown_extensions {
+            // before all known fields, so their number is known in advance
             body.line(format!(
-                "AsnDef{}::write_value(writer, &self.{})?;",
-                Self::combined_field_type_name(name, field.name()),
-                field.name(),
+                "writer.write_unknown_extensions(&self.{})?;",
+                unknown_extensions
             ));
         }
 
+        for field in fields {
+            if let Some(key) = field.key() {
+                let key_field = fields
+                    .iter()
+                    .find(|f| RustCodeGenerator::rust_field_name(f.name(), true).eq(key))
+                    .unwrap_or_else(|| {
+                        panic!("Cannot find the key {} of the field {}", key, field.name())
+                    });
+                body.line(format!(
+                    "AsnDef{}::write_with_key(writer, &self.{}, {}self.{} as i64)?;",
+                    Self::combined_field_type_name(name, field.name()),
+                    RustCodeGenerator::rust_field_name(field.name(), true),
+                    if matches!(key_field.r#type(), RustType::Complex(..)) {
+                        "*"
+                    } else {
+                        ""
+                    },
+                    key
+                ));
+            } else {
+                body.line(format!(
+                    "AsnDef{}::write_value(writer, &self.{})?;",
+                    Self::combined_field_type_name(name, field.name()),
+                    RustCodeGenerator::rust_field_name(field.name(), true),
+                ));
+            }
+        }
+
         body.line("Ok(())");
     }
 
@@ -850,8 +1901,12 @@ impl AsnDefWriter {
             myself.write_type_definitions(&mut scope, definition);
             myself.write_impl(&mut scope, definition);
             myself.write_constraints(&mut scope, definition);
-            myself.impl_readable(&mut scope, &definition.0);
-            myself.impl_writable(&mut scope, &definition.0);
+            if !matches!(definition.1, Rust::OpenType(_)) {
+                // open types can only be read and written with the value of their key
+                myself.impl_readable(&mut scope, &definition.0);
+                myself.impl_writable(&mut scope, &definition.0);
+            }
+            myself.impl_components(&mut scope, definition);
         }
 
         scope.to_string()
@@ -911,14 +1966,14 @@ pub mod tests {
         Definition(
             String::from("Whatever"),
             Rust::struct_from_fields(vec![
-                Field::from_name_type("name", RustType::String(Size::Any, Charset::Utf8)),
+                Field::from_name_type("name", RustType::String(Size::Any, Charset::Utf8, None)),
                 Field::from_name_type(
                     "opt",
-                    RustType::Option(Box::new(RustType::String(Size::Any, Charset::Utf8))),
+                    RustType::Option(Box::new(RustType::String(Size::Any, Charset::Utf8, None))),
                 ),
                 Field::from_name_type(
                     "some",
-                    RustType::Option(Box::new(RustType::String(Size::Any, Charset::Utf8))),
+                    RustType::Option(Box::new(RustType::String(Size::Any, Charset::Utf8, None))),
                 ),
             ]),
         )
@@ -930,18 +1985,28 @@ pub mod tests {
             Rust::Struct {
                 ordering: EncodingOrdering::Keep,
                 fields: vec![
-                    Field::from_name_type("name", RustType::String(Size::Any, Charset::Utf8)),
+                    Field::from_name_type("name", RustType::String(Size::Any, Charset::Utf8, None)),
                     Field::from_name_type(
                         "opt",
-                        RustType::Option(Box::new(RustType::String(Size::Any, Charset::Utf8))),
+                        RustType::Option(Box::new(RustType::String(
+                            Size::Any,
+                            Charset::Utf8,
+                            None,
+                        ))),
                     ),
                     Field::from_name_type(
                         "some",
-                        RustType::Option(Box::new(RustType::String(Size::Any, Charset::Utf8))),
+                        RustType::Option(Box::new(RustType::String(
+                            Size::Any,
+                            Charset::Utf8,
+                            None,
+                        ))),
                     ),
                 ],
                 tag: None,
                 extension_after: Some(1),
+                unknown_extensions: None,
+                explicit: false,
             },
         )
     }
@@ -1031,6 +2096,7 @@ pub mod tests {
 
             impl ::asn1rs::syn::sequence::Constraint for Whatever {
                 const NAME: &'static str = "Whatever";
+                const FIELD_NAMES: &'static [&'static str] = &["name", "opt", "some"];
                 const STD_OPTIONAL_FIELDS: u64 = 2;
                 const FIELD_COUNT: u64 = 3;
                 const EXTENDED_AFTER_FIELD: Option<u64> = None;
@@ -1115,6 +2181,7 @@ pub mod tests {
             }
             impl ::asn1rs::syn::sequence::Constraint for Potato {
                 const NAME: &'static str = "Potato";
+                const FIELD_NAMES: &'static [&'static str] = &["name", "opt", "some"];
                 const STD_OPTIONAL_FIELDS: u64 = 1;
                 const FIELD_COUNT: u64 = 3;
                 const EXTENDED_AFTER_FIELD: Option<u64> = Some(1);
@@ -1146,8 +2213,10 @@ pub mod tests {
 
     #[test]
     pub fn test_integer_struct_constants() {
-        let model = Model::try_from(Tokenizer::default().parse(
-            r#"BasicInteger DEFINITIONS AUTOMATIC TAGS ::=
+        let model = Model::try_from(
+            Tokenizer::default()
+                .parse(
+                    r#"BasicInteger DEFINITIONS AUTOMATIC TAGS ::=
             BEGIN
     
             MyStruct ::= SEQUENCE {
@@ -1156,7 +2225,9 @@ pub mod tests {
             
             END
         "#,
-        ))
+                )
+                .unwrap(),
+        )
         .unwrap()
         .to_rust();
 
@@ -1179,15 +2250,19 @@ pub mod tests {
 
     #[test]
     pub fn test_integer_tuple_constants() {
-        let model = Model::try_from(Tokenizer::default().parse(
-            r#"BasicInteger DEFINITIONS AUTOMATIC TAGS ::=
+        let model = Model::try_from(
+            Tokenizer::default()
+                .parse(
+                    r#"BasicInteger DEFINITIONS AUTOMATIC TAGS ::=
             BEGIN
             
             MyTuple ::= INTEGER { abc(8), bernd(9) } (0..255)
             
             END
         "#,
-        ))
+                )
+                .unwrap(),
+        )
         .unwrap()
         .to_rust();
 
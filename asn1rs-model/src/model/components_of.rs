@@ -0,0 +1,222 @@
+//! The inclusion of components as described in ITU-T X.680 | ISO/IEC 8824-1:2015, chapter 25
+//!
+//! The root components of the type referenced by `COMPONENTS OF Type` are spliced into the
+//! including `SEQUENCE` or `SET` before the model is converted to rust. Because the referenced
+//! type might be assigned in an imported module, this requires the scope of the conversion. The
+//! types that are referenced by the included components are then imported from the modules they
+//! are assigned in.
+
+use crate::model::{Asn, ComponentTypeList, Definition, Field, Import, Model, TagResolver, Type};
+use std::borrow::Cow;
+
+/// Inclusions that are nested deeper than this are considered to be infinitely recursive
+const MAX_INCLUSION_DEPTH: usize = 32;
+
+impl Model<Asn> {
+    /// This model with the root components of all types referenced by `COMPONENTS OF` included
+    ///
+    /// # Panics
+    ///
+    /// If an included type is neither a `SEQUENCE` nor a `SET` or if it is neither assigned in
+    /// the module it is referenced in nor imported from a module in the scope
+    pub(crate) fn with_included_components<'m>(&'m self, scope: &[&Self]) -> Cow<'m, Self> {
+        if !self
+            .definitions
+            .iter()
+            .any(|Definition(_, asn)| Self::includes_components(&asn.r#type))
+        {
+            return Cow::Borrowed(self);
+        }
+        let mut model = self.clone();
+        for Definition(_, asn) in &mut model.definitions {
+            Self::include_components(self, self, scope, &mut asn.r#type, &mut model.imports, 0);
+        }
+        Cow::Owned(model)
+    }
+
+    fn includes_components(r#type: &Type) -> bool {
+        match r#type {
+            Type::Sequence(list) | Type::Set(list) if !list.components_of.is_empty() => true,
+            r#type => Self::nested_types(r#type)
+                .into_iter()
+                .any(Self::includes_components),
+        }
+    }
+
+    /// Includes the components in the given type, which is assigned in the given module
+    fn include_components(
+        including: &Self,
+        model: &Self,
+        scope: &[&Self],
+        r#type: &mut Type,
+        imports: &mut Vec<Import>,
+        depth: usize,
+    ) {
+        match r#type {
+            Type::Sequence(list) | Type::Set(list) => {
+                Self::include_list_components(including, model, scope, list, imports, depth)
+            }
+            Type::Choice(choice) => {
+                for variant in &mut choice.variants {
+                    Self::include_components(
+                        including,
+                        model,
+                        scope,
+                        &mut variant.r#type,
+                        imports,
+                        depth,
+                    );
+                }
+            }
+            Type::Optional(inner)
+            | Type::SequenceOf(inner, _)
+            | Type::SetOf(inner, _)
+            | Type::WithComponents(inner, _)
+            | Type::Containing(inner, _) => {
+                Self::include_components(including, model, scope, inner, imports, depth)
+            }
+            _ => {}
+        }
+    }
+
+    fn include_list_components(
+        including: &Self,
+        model: &Self,
+        scope: &[&Self],
+        list: &mut ComponentTypeList,
+        imports: &mut Vec<Import>,
+        depth: usize,
+    ) {
+        for field in &mut list.fields {
+            Self::include_components(
+                including,
+                model,
+                scope,
+                &mut field.role.r#type,
+                imports,
+                depth,
+            );
+        }
+        // in reverse, so that the indices of the remaining inclusions stay valid
+        for (index, included) in core::mem::take(&mut list.components_of).into_iter().rev() {
+            let fields =
+                Self::root_components(including, model, scope, &included, imports, depth + 1);
+            // only the root can include components
+            list.extension_after = list.extension_after.map(|after| after + fields.len());
+            list.fields.splice(index..index, fields);
+        }
+    }
+
+    /// ITU-T X.680 | ISO/IEC 8824-1:2015, chapter 25: the components of the included type
+    /// without its extension additions
+    fn root_components(
+        including: &Self,
+        model: &Self,
+        scope: &[&Self],
+        included: &Type,
+        imports: &mut Vec<Import>,
+        depth: usize,
+    ) -> Vec<Field<Asn>> {
+        if depth > MAX_INCLUSION_DEPTH {
+            panic!(
+                "The inclusion of the components of {:?} is infinitely recursive",
+                included
+            );
+        }
+        match included {
+            Type::TypeReference(name, _) => {
+                let (defining, asn) = TagResolver { model, scope }
+                    .resolve_model(name)
+                    .and_then(|defining| {
+                        defining
+                            .definitions
+                            .iter()
+                            .find(|definition| definition.0.eq(name))
+                            .map(|Definition(_, asn)| (defining, asn))
+                    })
+                    .unwrap_or_else(|| {
+                        panic!(
+                            "The type {} of COMPONENTS OF is neither assigned in {} nor imported from a module in the scope",
+                            name, model.name
+                        )
+                    });
+                Self::root_components(including, defining, scope, &asn.r#type, imports, depth + 1)
+            }
+            Type::Sequence(list) | Type::Set(list) => {
+                let mut list = list.clone();
+                Self::include_list_components(including, model, scope, &mut list, imports, depth);
+                let mut fields = list.fields;
+                if let Some(after) = list.extension_after {
+                    fields.truncate(after + 1);
+                }
+                if !model.name.eq(&including.name) {
+                    for field in &fields {
+                        Self::import_type_references(
+                            including,
+                            model,
+                            scope,
+                            &field.role.r#type,
+                            imports,
+                        );
+                    }
+                }
+                fields
+            }
+            r#type => panic!(
+                "COMPONENTS OF requires a SEQUENCE or SET type, but got {:?}",
+                r#type
+            ),
+        }
+    }
+
+    /// Imports the types that are referenced by the given type, which is assigned in the given
+    /// module, from the modules they are assigned in
+    fn import_type_references(
+        including: &Self,
+        model: &Self,
+        scope: &[&Self],
+        r#type: &Type,
+        imports: &mut Vec<Import>,
+    ) {
+        if let Type::TypeReference(name, _) = r#type {
+            let defining = TagResolver { model, scope }.resolve_model(name);
+            if let Some(defining) = defining.filter(|defining| !defining.name.eq(&including.name)) {
+                if !including.definitions.iter().any(|d| d.0.eq(name)) {
+                    match imports
+                        .iter_mut()
+                        .find(|import| import.from.eq(&defining.name))
+                    {
+                        Some(import) if import.what.contains(name) => {}
+                        Some(import) => import.what.push(name.clone()),
+                        None => imports.push(Import {
+                            what: vec![name.clone()],
+                            from: defining.name.clone(),
+                            from_oid: defining.oid.clone(),
+                        }),
+                    }
+                }
+            }
+        }
+        for nested in Self::nested_types(r#type) {
+            Self::import_type_references(including, model, scope, nested, imports);
+        }
+    }
+
+    fn nested_types(r#type: &Type) -> Vec<&Type> {
+        match r#type {
+            Type::Sequence(list) | Type::Set(list) => list
+                .fields
+                .iter()
+                .map(|field| &field.role.r#type)
+                .chain(list.components_of.iter().map(|(_, included)| included))
+                .collect(),
+            Type::Choice(choice) => choice.variants.iter().map(|v| &v.r#type).collect(),
+            Type::Optional(inner)
+            | Type::SequenceOf(inner, _)
+            | Type::SetOf(inner, _)
+            | Type::WithComponents(inner, _)
+            | Type::Containing(inner, _) => vec![&**inner],
+            _ => Vec::new(),
+        }
+    }
+}
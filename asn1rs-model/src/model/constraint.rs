@@ -0,0 +1,742 @@
+//! Subtype constraints and their set arithmetic as described in ITU-T X.680 | ISO/IEC
+//! 8824-1:2015, chapter 46 to 51.
+//!
+//! The elements of a constraint (`1 | 3 | 5..10`) are kept as [`ElementSet`], so the values can
+//! be validated against the full constraint. The encoding rules only consider the PER-visible
+//! parts of a constraint, which are summarized by the effective constraint, see
+//! [`ElementSet::effective_range`].
+
+use crate::model::{Asn, Error, LitOrRef, Model, Type};
+use crate::parser::Token;
+use std::fmt::{Display, Formatter};
+use std::iter::Peekable;
+use std::str::FromStr;
+use std::vec::IntoIter;
+
+/// ITU-T X.680 | ISO/IEC 8824-1:2015, chapter 46
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub enum ElementSet<T> {
+    /// A single value (`5`)
+    Value(T),
+    /// A value range (`1..10`), of which `None` stands for `MIN` or `MAX`
+    Range(Option<T>, Option<T>),
+    /// All values, as in `ALL EXCEPT 0`
+    All,
+    /// `1 | 3` or `1 UNION 3`
+    Union(Vec<ElementSet<T>>),
+    /// `1..10 ^ 5..20` or `1..10 INTERSECTION 5..20`
+    Intersection(Vec<ElementSet<T>>),
+    /// `1..10 EXCEPT 5`
+    Except(Box<ElementSet<T>>, Box<ElementSet<T>>),
+}
+
+impl<T> ElementSet<T> {
+    /// Whether this is a single value or value range, which is already fully described by its
+    /// effective constraint
+    pub fn is_simple(&self) -> bool {
+        matches!(self, ElementSet::Value(_) | ElementSet::Range(..))
+    }
+
+    pub fn map<R>(&self, f: &impl Fn(&T) -> R) -> ElementSet<R> {
+        self.try_map(&|value| Ok::<_, ()>(f(value)))
+            .unwrap_or_else(|_| unreachable!())
+    }
+
+    pub fn try_map<R, E>(&self, f: &impl Fn(&T) -> Result<R, E>) -> Result<ElementSet<R>, E> {
+        let map_all = |sets: &[ElementSet<T>]| {
+            sets.iter()
+                .map(|set| set.try_map(f))
+                .collect::<Result<Vec<_>, E>>()
+        };
+        Ok(match self {
+            ElementSet::Value(value) => ElementSet::Value(f(value)?),
+            ElementSet::Range(min, max) => ElementSet::Range(
+                min.as_ref().map(f).transpose()?,
+                max.as_ref().map(f).transpose()?,
+            ),
+            ElementSet::All => ElementSet::All,
+            ElementSet::Union(sets) => ElementSet::Union(map_all(sets)?),
+            ElementSet::Intersection(sets) => ElementSet::Intersection(map_all(sets)?),
+            ElementSet::Except(set, except) => {
+                ElementSet::Except(Box::new(set.try_map(f)?), Box::new(except.try_map(f)?))
+            }
+        })
+    }
+}
+
+impl<T: Ord + Copy> ElementSet<T> {
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 9.3: the smallest range which contains all
+    /// values of the PER-visible parts of the constraint, where `None` stands for `MIN` or
+    /// `MAX`. The values that follow an `EXCEPT` are ignored.
+    pub fn effective_range(&self) -> (Option<T>, Option<T>) {
+        match self {
+            ElementSet::Value(value) => (Some(*value), Some(*value)),
+            ElementSet::Range(min, max) => (*min, *max),
+            ElementSet::All => (None, None),
+            ElementSet::Union(sets) => {
+                let ranges = sets.iter().map(ElementSet::effective_range);
+                ranges
+                    .reduce(|(min_a, max_a), (min_b, max_b)| {
+                        (
+                            min_a.zip(min_b).map(|(a, b)| a.min(b)),
+                            max_a.zip(max_b).map(|(a, b)| a.max(b)),
+                        )
+                    })
+                    .unwrap_or((None, None))
+            }
+            ElementSet::Intersection(sets) => {
+                let ranges = sets.iter().map(ElementSet::effective_range);
+                ranges
+                    .reduce(|(min_a, max_a), (min_b, max_b)| {
+                        (min_a.max(min_b), Self::min_of_upper(max_a, max_b))
+                    })
+                    .unwrap_or((None, None))
+            }
+            ElementSet::Except(set, _) => set.effective_range(),
+        }
+    }
+
+    /// The smaller of two upper bounds, of which `None` is unbounded
+    fn min_of_upper(a: Option<T>, b: Option<T>) -> Option<T> {
+        match (a, b) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, None) => a,
+            (None, b) => b,
+        }
+    }
+}
+
+impl ElementSet<i128> {
+    /// The values of the full constraint as sorted, disjoint and inclusive ranges, where
+    /// `i128::MIN` and `i128::MAX` stand for `MIN` and `MAX`
+    pub fn permitted_values(&self) -> Vec<(i128, i128)> {
+        match self {
+            ElementSet::Value(value) => vec![(*value, *value)],
+            ElementSet::Range(min, max) => {
+                let min = min.unwrap_or(i128::MIN);
+                let max = max.unwrap_or(i128::MAX);
+                if min <= max {
+                    vec![(min, max)]
+                } else {
+                    Vec::new()
+                }
+            }
+            ElementSet::All => vec![(i128::MIN, i128::MAX)],
+            ElementSet::Union(sets) => {
+                normalized(sets.iter().flat_map(ElementSet::permitted_values).collect())
+            }
+            ElementSet::Intersection(sets) => sets
+                .iter()
+                .map(ElementSet::permitted_values)
+                .reduce(|a, b| intersection(&a, &b))
+                .unwrap_or_default(),
+            ElementSet::Except(set, except) => intersection(
+                &set.permitted_values(),
+                &complement(&except.permitted_values()),
+            ),
+        }
+    }
+}
+
+/// Sorts the ranges and merges the overlapping and adjacent ones
+fn normalized(mut ranges: Vec<(i128, i128)>) -> Vec<(i128, i128)> {
+    ranges.sort_unstable();
+    let mut result: Vec<(i128, i128)> = Vec::with_capacity(ranges.len());
+    for (min, max) in ranges {
+        match result.last_mut() {
+            Some((_, last_max)) if min <= last_max.saturating_add(1) => {
+                *last_max = (*last_max).max(max);
+            }
+            _ => result.push((min, max)),
+        }
+    }
+    result
+}
+
+fn intersection(a: &[(i128, i128)], b: &[(i128, i128)]) -> Vec<(i128, i128)> {
+    let mut result = Vec::new();
+    for (min_a, max_a) in a {
+        for (min_b, max_b) in b {
+            let (min, max) = ((*min_a).max(*min_b), (*max_a).min(*max_b));
+            if min <= max {
+                result.push((min, max));
+            }
+        }
+    }
+    normalized(result)
+}
+
+fn complement(ranges: &[(i128, i128)]) -> Vec<(i128, i128)> {
+    let mut result = Vec::new();
+    let mut next = Some(i128::MIN);
+    for (min, max) in ranges {
+        if let Some(next) = next.filter(|next| next < min) {
+            result.push((next, min - 1));
+        }
+        next = max.checked_add(1);
+    }
+    if let Some(next) = next {
+        result.push((next, i128::MAX));
+    }
+    result
+}
+
+/// The notation of `#[asn(..)]`, in which `MIN`, `MAX`, `ALL` and `EXCEPT` are lowercase
+impl<T: Display> Display for ElementSet<T> {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        let write_all = |f: &mut Formatter, sets: &[ElementSet<T>], separator: &str| {
+            sets.iter().enumerate().try_for_each(|(index, set)| {
+                if index > 0 {
+                    f.write_str(separator)?;
+                }
+                set.fmt_nested(f)
+            })
+        };
+        match self {
+            ElementSet::Value(value) => write!(f, "{}", value),
+            ElementSet::Range(min, max) => {
+                match min {
+                    Some(min) => write!(f, "{}", min)?,
+                    None => f.write_str("min")?,
+                }
+                f.write_str("..")?;
+                match max {
+                    Some(max) => write!(f, "{}", max),
+                    None => f.write_str("max"),
+                }
+            }
+            ElementSet::All => f.write_str("all"),
+            ElementSet::Union(sets) => write_all(f, sets, " | "),
+            ElementSet::Intersection(sets) => write_all(f, sets, " ^ "),
+            ElementSet::Except(set, except) => {
+                set.fmt_nested(f)?;
+                f.write_str(" except ")?;
+                except.fmt_nested(f)
+            }
+        }
+    }
+}
+
+impl<T: Display> ElementSet<T> {
+    fn fmt_nested(&self, f: &mut Formatter) -> std::fmt::Result {
+        if self.is_simple() || matches!(self, ElementSet::All) {
+            Display::fmt(self, f)
+        } else {
+            write!(f, "({})", self)
+        }
+    }
+}
+
+/// ITU-T X.680 | ISO/IEC 8824-1:2015, chapter 51.8: the inner subtype constraint
+/// `WITH COMPONENTS {..., a PRESENT, b (1..5)}` of a `SEQUENCE`, `SET` or `CHOICE` or
+/// `WITH COMPONENT (1..5)` of a `SEQUENCE OF` or `SET OF`, which is not PER-visible
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub struct InnerTypeConstraints<T> {
+    /// Whether the components that are not listed are unconstrained (`...,`), otherwise they are
+    /// absent unless they are mandatory
+    pub partial: bool,
+    pub components: Vec<ComponentConstraint<T>>,
+    /// The constraint of every element of a `SEQUENCE OF` or `SET OF` (`WITH COMPONENT`), which
+    /// has no named components
+    pub element: Option<Box<ValueConstraint<T>>>,
+}
+
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub struct ComponentConstraint<T> {
+    /// The identifier of the component as in the ASN.1 definition
+    pub name: String,
+    pub value: Option<ValueConstraint<T>>,
+    pub presence: Option<Presence>,
+}
+
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub enum ValueConstraint<T> {
+    /// The values of an `INTEGER` component
+    Integer(ElementSet<T>),
+    /// The inner subtype constraint of a component that is a `SEQUENCE`, `SET`, `CHOICE`,
+    /// `SEQUENCE OF` or `SET OF`
+    Components(InnerTypeConstraints<T>),
+}
+
+#[derive(Debug, Clone, Copy, PartialOrd, PartialEq, Eq)]
+pub enum Presence {
+    Present,
+    Absent,
+    Optional,
+}
+
+impl<T> InnerTypeConstraints<T> {
+    /// `WITH COMPONENT`, which constrains every element of a `SEQUENCE OF` or `SET OF`
+    pub fn for_elements(element: ValueConstraint<T>) -> Self {
+        InnerTypeConstraints {
+            partial: true,
+            components: Vec::default(),
+            element: Some(Box::new(element)),
+        }
+    }
+
+    pub fn map<R>(&self, f: &impl Fn(&T) -> R) -> InnerTypeConstraints<R> {
+        self.try_map(&|value| Ok::<_, ()>(f(value)))
+            .unwrap_or_else(|_| unreachable!())
+    }
+
+    pub fn try_map<R, E>(
+        &self,
+        f: &impl Fn(&T) -> Result<R, E>,
+    ) -> Result<InnerTypeConstraints<R>, E> {
+        Ok(InnerTypeConstraints {
+            partial: self.partial,
+            components: self
+                .components
+                .iter()
+                .map(|component| {
+                    Ok(ComponentConstraint {
+                        name: component.name.clone(),
+                        value: match &component.value {
+                            None => None,
+                            Some(value) => Some(value.try_map(f)?),
+                        },
+                        presence: component.presence,
+                    })
+                })
+                .collect::<Result<Vec<_>, E>>()?,
+            element: match &self.element {
+                None => None,
+                Some(element) => Some(Box::new(element.try_map(f)?)),
+            },
+        })
+    }
+}
+
+impl<T> ValueConstraint<T> {
+    pub fn try_map<R, E>(&self, f: &impl Fn(&T) -> Result<R, E>) -> Result<ValueConstraint<R>, E> {
+        Ok(match self {
+            ValueConstraint::Integer(set) => ValueConstraint::Integer(set.try_map(f)?),
+            ValueConstraint::Components(inner) => ValueConstraint::Components(inner.try_map(f)?),
+        })
+    }
+}
+
+/// The notation of `#[asn(..)]`: `{..., a present, b (1 | 3) absent, c ({d optional})}` and
+/// `{(1 | 3)}` for the elements of a `SEQUENCE OF` or `SET OF`
+impl<T: Display> Display for InnerTypeConstraints<T> {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        if let Some(element) = &self.element {
+            return write!(f, "{{({})}}", element);
+        }
+        f.write_str("{")?;
+        if self.partial {
+            f.write_str("...")?;
+        }
+        for (index, component) in self.components.iter().enumerate() {
+            if self.partial || index > 0 {
+                f.write_str(", ")?;
+            }
+            f.write_str(&component.name)?;
+            if let Some(value) = &component.value {
+                write!(f, " ({})", value)?;
+            }
+            if let Some(presence) = component.presence {
+                write!(f, " {}", presence)?;
+            }
+        }
+        f.write_str("}")
+    }
+}
+
+impl<T: Display> Display for ValueConstraint<T> {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            ValueConstraint::Integer(set) => write!(f, "{}", set),
+            ValueConstraint::Components(inner) => write!(f, "{}", inner),
+        }
+    }
+}
+
+impl Display for Presence {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        f.write_str(match self {
+            Presence::Present => "present",
+            Presence::Absent => "absent",
+            Presence::Optional => "optional",
+        })
+    }
+}
+
+/// The value constraint of an `INTEGER` and whether it is extensible
+type ValueConstraints = (ElementSet<LitOrRef<i128>>, bool);
+
+impl Model<Asn> {
+    /// Reads the serially applied constraints (`(1..10)(2 | 4)`) of an `INTEGER` as intersection
+    /// and whether the last of them is extensible
+    pub(crate) fn maybe_read_value_constraints(
+        iter: &mut Peekable<IntoIter<Token>>,
+    ) -> Result<Option<ValueConstraints>, Error> {
+        let mut constraints = Vec::new();
+        let mut extensible = false;
+        while Self::peek(iter)?.eq_separator('(') {
+            Self::next_separator_ignore_case(iter, '(')?;
+            let (set, ext) = Self::read_element_set_spec(iter, &mut Self::read_value_element)?;
+            Self::next_separator_ignore_case(iter, ')')?;
+            constraints.push(set);
+            extensible = ext;
+        }
+        Ok(match constraints.len() {
+            0 => None,
+            1 => constraints.pop().map(|set| (set, extensible)),
+            _ => Some((ElementSet::Intersection(constraints), extensible)),
+        })
+    }
+
+    /// ITU-T X.680 | ISO/IEC 8824-1:2015, chapter 46.1: reads the root of an element set
+    /// (`1 | 3 | 5..10`) and whether it is extensible, while the additional elements that
+    /// follow the extension marker are skipped. The elements that are neither nested nor
+    /// `ALL` are read by the given function.
+    pub(crate) fn read_element_set_spec<T>(
+        iter: &mut Peekable<IntoIter<Token>>,
+        element: &mut impl FnMut(&mut Peekable<IntoIter<Token>>) -> Result<ElementSet<T>, Error>,
+    ) -> Result<(ElementSet<T>, bool), Error> {
+        let root = Self::read_element_set(iter, element)?;
+        let extensible = if Self::peek(iter)?.eq_separator(',') {
+            Self::next_separator_ignore_case(iter, ',')?;
+            Self::next_symbol(iter, "...")?;
+            if Self::peek(iter)?.eq_separator(',') {
+                Self::next_separator_ignore_case(iter, ',')?;
+                let _additional = Self::read_element_set(iter, element)?;
+            }
+            true
+        } else {
+            false
+        };
+        Ok((root, extensible))
+    }
+
+    fn read_element_set<T>(
+        iter: &mut Peekable<IntoIter<Token>>,
+        element: &mut impl FnMut(&mut Peekable<IntoIter<Token>>) -> Result<ElementSet<T>, Error>,
+    ) -> Result<ElementSet<T>, Error> {
+        if Self::peek(iter)?.eq_text_ignore_ascii_case("ALL") {
+            let _ = Self::next(iter)?;
+            Self::next_text_ignore_ascii_case(iter, "EXCEPT")?;
+            let except = Self::read_elements(iter, element)?;
+            Ok(ElementSet::Except(
+                Box::new(ElementSet::All),
+                Box::new(except),
+            ))
+        } else {
+            Self::read_unions(iter, element)
+        }
+    }
+
+    fn read_unions<T>(
+        iter: &mut Peekable<IntoIter<Token>>,
+        element: &mut impl FnMut(&mut Peekable<IntoIter<Token>>) -> Result<ElementSet<T>, Error>,
+    ) -> Result<ElementSet<T>, Error> {
+        let mut sets = vec![Self::read_intersections(iter, element)?];
+        while Self::peek(iter)?.eq_separator('|')
+            || Self::peek(iter)?.eq_text_ignore_ascii_case("UNION")
+        {
+            let _ = Self::next(iter)?;
+            sets.push(Self::read_intersections(iter, element)?);
+        }
+        Ok(Self::single_or(sets, ElementSet::Union))
+    }
+
+    fn read_intersections<T>(
+        iter: &mut Peekable<IntoIter<Token>>,
+        element: &mut impl FnMut(&mut Peekable<IntoIter<Token>>) -> Result<ElementSet<T>, Error>,
+    ) -> Result<ElementSet<T>, Error> {
+        let mut sets = vec![Self::read_intersection_elements(iter, element)?];
+        while Self::peek(iter)?.eq_separator('^')
+            || Self::peek(iter)?.eq_text_ignore_ascii_case("INTERSECTION")
+        {
+            let _ = Self::next(iter)?;
+            sets.push(Self::read_intersection_elements(iter, element)?);
+        }
+        Ok(Self::single_or(sets, ElementSet::Intersection))
+    }
+
+    fn read_intersection_elements<T>(
+        iter: &mut Peekable<IntoIter<Token>>,
+        element: &mut impl FnMut(&mut Peekable<IntoIter<Token>>) -> Result<ElementSet<T>, Error>,
+    ) -> Result<ElementSet<T>, Error> {
+        let elements = Self::read_elements(iter, element)?;
+        if Self::peek(iter)?.eq_text_ignore_ascii_case("EXCEPT") {
+            let _ = Self::next(iter)?;
+            let except = Self::read_elements(iter, element)?;
+            Ok(ElementSet::Except(Box::new(elements), Box::new(except)))
+        } else {
+            Ok(elements)
+        }
+    }
+
+    fn read_elements<T>(
+        iter: &mut Peekable<IntoIter<Token>>,
+        element: &mut impl FnMut(&mut Peekable<IntoIter<Token>>) -> Result<ElementSet<T>, Error>,
+    ) -> Result<ElementSet<T>, Error> {
+        if Self::peek(iter)?.eq_separator('(') {
+            Self::next_separator_ignore_case(iter, '(')?;
+            let set = Self::read_element_set(iter, element)?;
+            Self::next_separator_ignore_case(iter, ')')?;
+            Ok(set)
+        } else {
+            element(iter)
+        }
+    }
+
+    /// Reads the inner subtype constraint (`(WITH COMPONENTS {..., a PRESENT})`) that may follow a
+    /// type reference
+    pub(crate) fn maybe_read_inner_type_constraints(
+        iter: &mut Peekable<IntoIter<Token>>,
+    ) -> Result<Option<InnerTypeConstraints<LitOrRef<i128>>>, Error> {
+        let with = matches!(
+            iter.clone().nth(1),
+            Some(token) if token.eq_text_ignore_ascii_case("WITH")
+        );
+        if Self::peek(iter)?.eq_separator('(') && with {
+            Self::next_separator_ignore_case(iter, '(')?;
+            let constraints = Self::read_inner_type_constraints(iter)?;
+            Self::next_separator_ignore_case(iter, ')')?;
+            Ok(Some(constraints))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// ITU-T X.680 | ISO/IEC 8824-1:2015, chapter 51.10, reads the contents constraint
+    /// (`(CONTAINING Type)`) that may follow `OCTET STRING` or `BIT STRING`. The contained type
+    /// is only returned without `ENCODED BY`, because the contents are otherwise encoded by rules
+    /// that might differ from the rules of the enclosing value.
+    pub(crate) fn maybe_read_contents_constraint(
+        iter: &mut Peekable<IntoIter<Token>>,
+    ) -> Result<Option<Type>, Error> {
+        let contents = matches!(
+            iter.clone().nth(1),
+            Some(token) if token.eq_text_ignore_ascii_case("CONTAINING")
+                || token.eq_text_ignore_ascii_case("ENCODED")
+        );
+        if !Self::peek(iter)?.eq_separator('(') || !contents {
+            return Ok(None);
+        }
+        Self::next_separator_ignore_case(iter, '(')?;
+        let mut contained = None;
+        if Self::peek(iter)?.eq_text_ignore_ascii_case("CONTAINING") {
+            let _ = Self::next(iter)?;
+            contained = Some(Self::read_role(iter)?);
+        }
+        if Self::peek(iter)?.eq_text_ignore_ascii_case("ENCODED") {
+            let _ = Self::next(iter)?;
+            let token = Self::next(iter)?;
+            if !token.eq_text_ignore_ascii_case("BY") {
+                return Err(Error::unexpected_token(token));
+            }
+            // the encoding rules are either given as object identifier or as reference to one
+            if Self::maybe_read_oid(iter)?.is_none() {
+                let _ = Self::next_text(iter)?;
+            }
+            contained = None;
+        }
+        Self::next_separator_ignore_case(iter, ')')?;
+        Ok(contained)
+    }
+
+    /// ITU-T X.680 | ISO/IEC 8824-1:2015, chapter 51.8, reads `WITH COMPONENTS {...}` or
+    /// `WITH COMPONENT (...)`
+    fn read_inner_type_constraints(
+        iter: &mut Peekable<IntoIter<Token>>,
+    ) -> Result<InnerTypeConstraints<LitOrRef<i128>>, Error> {
+        Self::next_text_ignore_ascii_case(iter, "WITH")?;
+        if Self::peek(iter)?.eq_text_ignore_ascii_case("COMPONENT") {
+            let _ = Self::next(iter)?;
+            return Ok(InnerTypeConstraints::for_elements(
+                Self::read_value_constraint(iter)?,
+            ));
+        }
+        Self::next_text_ignore_ascii_case(iter, "COMPONENTS")?;
+        Self::next_separator_ignore_case(iter, '{')?;
+        let partial = Self::peek(iter)?.eq_symbol("...");
+        if partial {
+            Self::next_symbol(iter, "...")?;
+        }
+        let mut components = Vec::new();
+        while !Self::peek(iter)?.eq_separator('}') {
+            if partial || !components.is_empty() {
+                Self::next_separator_ignore_case(iter, ',')?;
+            }
+            let name = Self::next_text(iter)?;
+            components.push(Self::read_component_constraint(iter, name)?);
+        }
+        Self::next_separator_ignore_case(iter, '}')?;
+        Ok(InnerTypeConstraints {
+            partial,
+            components,
+            element: None,
+        })
+    }
+
+    fn read_component_constraint(
+        iter: &mut Peekable<IntoIter<Token>>,
+        name: String,
+    ) -> Result<ComponentConstraint<LitOrRef<i128>>, Error> {
+        let value = if Self::peek(iter)?.eq_separator('(') {
+            Some(Self::read_value_constraint(iter)?)
+        } else {
+            None
+        };
+        let presence = match Self::peek(iter)?.text() {
+            Some(text) if text.eq_ignore_ascii_case("PRESENT") => Some(Presence::Present),
+            Some(text) if text.eq_ignore_ascii_case("ABSENT") => Some(Presence::Absent),
+            Some(text) if text.eq_ignore_ascii_case("OPTIONAL") => Some(Presence::Optional),
+            _ => None,
+        };
+        if presence.is_some() {
+            let _ = Self::next(iter)?;
+        }
+        Ok(ComponentConstraint {
+            name,
+            value,
+            presence,
+        })
+    }
+
+    /// Reads the constraint of a component (`(1 | 3)` or `(WITH COMPONENTS {...})`)
+    fn read_value_constraint(
+        iter: &mut Peekable<IntoIter<Token>>,
+    ) -> Result<ValueConstraint<LitOrRef<i128>>, Error> {
+        Self::next_separator_ignore_case(iter, '(')?;
+        let value = if Self::peek(iter)?.eq_text_ignore_ascii_case("WITH") {
+            ValueConstraint::Components(Self::read_inner_type_constraints(iter)?)
+        } else {
+            // the extensibility of a constraint that is not PER-visible is irrelevant
+            let (set, _extensible) =
+                Self::read_element_set_spec(iter, &mut Self::read_value_element)?;
+            ValueConstraint::Integer(set)
+        };
+        Self::next_separator_ignore_case(iter, ')')?;
+        Ok(value)
+    }
+
+    /// Reads a single value (`5`) or a value range (`1..10`)
+    pub(crate) fn read_value_element<T: FromStr>(
+        iter: &mut Peekable<IntoIter<Token>>,
+    ) -> Result<ElementSet<LitOrRef<T>>, Error> {
+        let start = Self::next(iter)?;
+        if Self::peek(iter)?.eq_symbol("..") {
+            Self::next_symbol(iter, "..")?;
+            let end = Self::next(iter)?;
+            Ok(ElementSet::Range(
+                Self::read_bound(start, "MIN")?,
+                Self::read_bound(end, "MAX")?,
+            ))
+        } else {
+            match Self::read_bound(start.clone(), "MIN")? {
+                Some(value) => Ok(ElementSet::Value(value)),
+                None => Err(Error::invalid_range_value(start)),
+            }
+        }
+    }
+
+    fn single_or<T>(
+        mut sets: Vec<ElementSet<T>>,
+        f: impl FnOnce(Vec<ElementSet<T>>) -> ElementSet<T>,
+    ) -> ElementSet<T> {
+        if sets.len() == 1 {
+            sets.remove(0)
+        } else {
+            f(sets)
+        }
+    }
+
+    fn next_text_ignore_ascii_case(
+        iter: &mut Peekable<IntoIter<Token>>,
+        text: &str,
+    ) -> Result<(), Error> {
+        let token = Self::next(iter)?;
+        if token.eq_text_ignore_ascii_case(text) {
+            Ok(())
+        } else {
+            Err(Error::unexpected_token(token))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_effective_range() {
+        let set = ElementSet::Union(vec![
+            ElementSet::Value(1),
+            ElementSet::Value(3),
+            ElementSet::Range(Some(5), Some(10)),
+        ]);
+        assert_eq!((Some(1), Some(10)), set.effective_range());
+
+        let set = ElementSet::Intersection(vec![
+            ElementSet::Range(Some(0), None),
+            ElementSet::Range(None, Some(7)),
+        ]);
+        assert_eq!((Some(0), Some(7)), set.effective_range());
+
+        let set = ElementSet::Union(vec![ElementSet::Value(1), ElementSet::Range(Some(5), None)]);
+        assert_eq!((Some(1), None), set.effective_range());
+
+        // the values that follow EXCEPT are not PER-visible
+        let set = ElementSet::Except(
+            Box::new(ElementSet::Range(Some(1), Some(10))),
+            Box::new(ElementSet::Value(10)),
+        );
+        assert_eq!((Some(1), Some(10)), set.effective_range());
+
+        let set = ElementSet::Except(Box::new(ElementSet::All), Box::new(ElementSet::Value(0)));
+        assert_eq!((None, None), set.effective_range());
+    }
+
+    #[test]
+    fn test_permitted_values() {
+        let set = ElementSet::Union(vec![
+            ElementSet::Range(Some(5), Some(10)),
+            ElementSet::Value(1),
+            ElementSet::Value(3),
+            ElementSet::Value(4),
+        ]);
+        assert_eq!(vec![(1, 1), (3, 10)], set.permitted_values());
+
+        let set = ElementSet::Except(
+            Box::new(ElementSet::Range(Some(1), Some(10))),
+            Box::new(ElementSet::Union(vec![
+                ElementSet::Value(1),
+                ElementSet::Range(Some(4), Some(6)),
+            ])),
+        );
+        assert_eq!(vec![(2, 3), (7, 10)], set.permitted_values());
+
+        let set = ElementSet::Except(Box::new(ElementSet::All), Box::new(ElementSet::Value(0)));
+        assert_eq!(
+            vec![(i128::MIN, -1), (1, i128::MAX)],
+            set.permitted_values()
+        );
+
+        let set = ElementSet::Intersection(vec![
+            ElementSet::Range(Some(0), Some(10)),
+            ElementSet::Range(Some(20), None),
+        ]);
+        assert!(set.permitted_values().is_empty());
+    }
+
+    #[test]
+    fn test_display_is_nested() {
+        let set = ElementSet::Union(vec![
+            ElementSet::Value(1),
+            ElementSet::Except(
+                Box::new(ElementSet::Range(Some(5), None)),
+                Box::new(ElementSet::Intersection(vec![
+                    ElementSet::Range(None, Some(10)),
+                    ElementSet::Value(7),
+                ])),
+            ),
+        ]);
+        assert_eq!("1 | (5..max except (min..10 ^ 7))", set.to_string());
+    }
+}
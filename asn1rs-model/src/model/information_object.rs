@@ -0,0 +1,1058 @@
+//! Information object classes, objects and object sets as described in ITU-T X.681 | ISO/IEC
+//! 8824-2:2015 and the table constraint of ITU-T X.682 | ISO/IEC 8824-3:2015, chapter 10.
+//!
+//! The syntax of an object depends on its class (`WITH SYNTAX`), so objects and object sets are
+//! parsed after all classes of the module are known. Objects in the default syntax
+//! (`{ &id 1, &Type INTEGER }`) can be of an imported class, objects in a defined syntax
+//! (`{ ID 1 TYPE INTEGER }`) require their class to be defined in the same module.
+//!
+//! A component of a `SEQUENCE` in the form of `CLASS.&Type ({ObjectSet}{@id})` is an open type,
+//! whose actual type is given by the object of the object set that is identified by the value
+//! of the component `id`, see [`OpenType`].
+
+use crate::gen::rust::UNKNOWN_EXTENSIONS_VARIANT;
+use crate::model::{Asn, Field, Size};
+use crate::model::{Context, Definition, Error, LiteralValue, Model, Type};
+use crate::parser::{Location, Token};
+use std::convert::TryFrom;
+use std::iter::Peekable;
+use std::vec::IntoIter;
+
+/// ITU-T X.681 | ISO/IEC 8824-2:2015, chapter 9: `CLASS { &id INTEGER UNIQUE, &Type }`
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub struct ObjectClass {
+    pub fields: Vec<FieldSpec>,
+    /// The syntax of the objects of the class, as defined by `WITH SYNTAX { ... }`
+    pub syntax: Option<Vec<SyntaxToken>>,
+}
+
+impl ObjectClass {
+    pub fn field(&self, name: &str) -> Option<&FieldSpec> {
+        self.fields.iter().find(|field| field.name == name)
+    }
+}
+
+/// ITU-T X.681 | ISO/IEC 8824-2:2015, chapter 9.2. Only type fields and fixed-type value fields
+/// are supported.
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub struct FieldSpec {
+    /// The name of the field, including the leading `&`
+    pub name: String,
+    pub kind: FieldSpecKind,
+    pub unique: bool,
+    /// Whether the field is declared with `OPTIONAL` or `DEFAULT`
+    pub optional: bool,
+}
+
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub enum FieldSpecKind {
+    /// `&Type`
+    Type,
+    /// `&id INTEGER`
+    Value(Type),
+}
+
+/// ITU-T X.681 | ISO/IEC 8824-2:2015, chapter 10
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub enum SyntaxToken {
+    /// A word or a comma that has to be present literally
+    Literal(String),
+    /// The setting for the field of the given name
+    Field(String),
+    /// A group in brackets that can be omitted
+    Optional(Vec<SyntaxToken>),
+}
+
+/// ITU-T X.681 | ISO/IEC 8824-2:2015, chapter 11
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub struct Object {
+    pub class: String,
+    pub settings: Vec<(String, Setting)>,
+}
+
+impl Object {
+    pub fn setting(&self, field: &str) -> Option<&Setting> {
+        self.settings
+            .iter()
+            .find(|(name, _)| name == field)
+            .map(|(_, setting)| setting)
+    }
+}
+
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub enum Setting {
+    Type(Type),
+    /// A literal value or - for an identifier - possibly a value reference
+    Value(LiteralValue),
+}
+
+/// ITU-T X.681 | ISO/IEC 8824-2:2015, chapter 12: `{ object1 | { &id 2, &Type BOOLEAN }, ... }`
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub struct ObjectSet {
+    pub class: String,
+    pub elements: Vec<ObjectSetElement>,
+    pub extensible: bool,
+}
+
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub enum ObjectSetElement {
+    Object(Object),
+    /// The name of an object or of another object set
+    Reference(String),
+}
+
+/// ITU-T X.681 | ISO/IEC 8824-2:2015, chapter 14: `CLASS.&field`, optionally with a table
+/// constraint
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub struct ObjectClassField {
+    pub class: String,
+    /// The name of the field, including the leading `&`
+    pub field: String,
+    pub table_constraint: Option<TableConstraint>,
+}
+
+/// ITU-T X.682 | ISO/IEC 8824-3:2015, chapter 10: `({ObjectSet})` or `({ObjectSet}{@id})`
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub struct TableConstraint {
+    pub object_set: String,
+    /// The component that is referenced by the component relation constraint (`@id`)
+    pub component: Option<String>,
+}
+
+/// The open type of a component with a component relation constraint. Each variant is the type
+/// of an object of the object set and is identified by the value of the referenced component
+/// for that object.
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub struct OpenType {
+    pub variants: Vec<OpenTypeVariant>,
+    /// The name of the Rust variant that preserves values of keys unknown to the object set
+    pub unknown_extensions: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub struct OpenTypeVariant {
+    pub key: i64,
+    pub name: String,
+    pub r#type: Type,
+}
+
+impl Model<Asn> {
+    /// Reads the class after `CLASS` of `NAME ::= CLASS { ... } WITH SYNTAX { ... }`
+    pub(crate) fn read_object_class(
+        iter: &mut Peekable<IntoIter<Token>>,
+    ) -> Result<ObjectClass, Error> {
+        Self::next_separator_ignore_case(iter, '{')?;
+        let mut fields = Vec::new();
+        loop {
+            fields.push(Self::read_field_spec(iter)?);
+            let token = Self::next(iter)?;
+            if token.eq_separator('}') {
+                break;
+            } else if !token.eq_separator(',') {
+                return Err(Error::unexpected_token(token));
+            }
+        }
+
+        let syntax = if Self::peek(iter)?.eq_text("WITH") {
+            let _ = Self::next(iter)?;
+            let token = Self::next(iter)?;
+            if !token.eq_text("SYNTAX") {
+                return Err(Error::expected_text("SYNTAX".to_string(), token));
+            }
+            // nested optional groups might end with `]]`, which is not a version bracket here
+            let tokens = Self::read_braced_tokens(iter)?
+                .into_iter()
+                .flat_map(Self::split_version_bracket)
+                .collect::<Vec<_>>();
+            let mut tokens = tokens.into_iter().peekable();
+            Self::next_separator_ignore_case(&mut tokens, '{')?;
+            Some(Self::read_syntax(&mut tokens, '}')?)
+        } else {
+            None
+        };
+
+        Ok(ObjectClass { fields, syntax })
+    }
+
+    fn read_field_spec(iter: &mut Peekable<IntoIter<Token>>) -> Result<FieldSpec, Error> {
+        let token = Self::next(iter)?;
+        let name = match token.text() {
+            Some(name) if name.starts_with('&') && name.len() > 1 => name.to_string(),
+            _ => return Err(Error::unexpected_token(token)),
+        };
+
+        let mut field = if name[1..].starts_with(|c: char| c.is_ascii_uppercase()) {
+            FieldSpec {
+                name,
+                kind: FieldSpecKind::Type,
+                unique: false,
+                optional: false,
+            }
+        } else {
+            if Self::peek(iter)?.test_text(|t| t.starts_with('&')) {
+                // variable-type value fields are not supported
+                return Err(Error::unexpected_token(Self::next(iter)?));
+            }
+            FieldSpec {
+                name,
+                kind: FieldSpecKind::Value(Self::read_role(iter)?),
+                unique: false,
+                optional: false,
+            }
+        };
+
+        if Self::peek(iter)?.eq_text("UNIQUE") {
+            let _ = Self::next(iter)?;
+            field.unique = true;
+        }
+
+        if Self::peek(iter)?.eq_text("OPTIONAL") {
+            let _ = Self::next(iter)?;
+            field.optional = true;
+        } else if Self::peek(iter)?.eq_text("DEFAULT") {
+            let _ = Self::next(iter)?;
+            field.optional = true;
+            match &field.kind {
+                FieldSpecKind::Type => {
+                    let _ = Self::read_role(iter)?;
+                }
+                FieldSpecKind::Value(_) => {
+                    let _ = Self::next(iter)?;
+                }
+            }
+        }
+
+        Ok(field)
+    }
+
+    /// Splits `[[` and `]]` into two separators each
+    fn split_version_bracket(token: Token) -> Vec<Token> {
+        let location = token.location();
+        let next = Location::at(location.line(), location.column() + 1);
+        match token.symbol() {
+            Some("[[") => vec![Token::Separator(location, '['), Token::Separator(next, '[')],
+            Some("]]") => vec![Token::Separator(location, ']'), Token::Separator(next, ']')],
+            _ => vec![token],
+        }
+    }
+
+    /// Reads the tokens of a defined syntax until the given closing separator
+    fn read_syntax(
+        iter: &mut Peekable<IntoIter<Token>>,
+        close: char,
+    ) -> Result<Vec<SyntaxToken>, Error> {
+        let mut syntax = Vec::new();
+        loop {
+            let token = Self::next(iter)?;
+            if token.eq_separator(close) {
+                return Ok(syntax);
+            } else if token.eq_separator('[') {
+                syntax.push(SyntaxToken::Optional(Self::read_syntax(iter, ']')?));
+            } else if token.eq_separator(',') {
+                syntax.push(SyntaxToken::Literal(",".to_string()));
+            } else {
+                let text = token.into_text_or_else(Error::unexpected_token)?;
+                if text.starts_with('&') {
+                    syntax.push(SyntaxToken::Field(text));
+                } else {
+                    syntax.push(SyntaxToken::Literal(text));
+                }
+            }
+        }
+    }
+
+    /// Reads the tokens of the value of an object or object set assignment, so they can be
+    /// parsed once all classes of the module are known
+    pub(crate) fn read_braced_tokens(
+        iter: &mut Peekable<IntoIter<Token>>,
+    ) -> Result<Vec<Token>, Error> {
+        let mut tokens = Vec::new();
+        let mut depth = 0_usize;
+        loop {
+            let token = Self::next(iter)?;
+            if token.eq_separator('{') {
+                depth += 1;
+            } else if token.eq_separator('}') {
+                depth = depth.saturating_sub(1);
+            } else if depth == 0 {
+                return Err(Error::expected_separator('{', token));
+            }
+            tokens.push(token);
+            if depth == 0 {
+                return Ok(tokens);
+            }
+        }
+    }
+
+    fn read_object(
+        tokens: Vec<Token>,
+        class: &str,
+        classes: &[Definition<ObjectClass>],
+    ) -> Result<Object, Error> {
+        Self::read_object_from_iter(&mut tokens.into_iter().peekable(), class, classes)
+    }
+
+    fn read_object_from_iter(
+        iter: &mut Peekable<IntoIter<Token>>,
+        class: &str,
+        classes: &[Definition<ObjectClass>],
+    ) -> Result<Object, Error> {
+        let open = Self::next(iter)?;
+        if !open.eq_separator('{') {
+            return Err(Error::expected_separator('{', open));
+        }
+
+        let class_definition = classes
+            .iter()
+            .find(|c| c.name() == class)
+            .map(|c| c.value());
+        let is_default_syntax = Self::peek(iter)?.test_text(|t| t.starts_with('&'))
+            || Self::peek(iter)?.eq_separator('}');
+
+        let mut object = Object {
+            class: class.to_string(),
+            settings: Vec::new(),
+        };
+
+        if is_default_syntax {
+            // ITU-T X.681 | ISO/IEC 8824-2:2015, chapter 11.5
+            loop {
+                let token = Self::next(iter)?;
+                if token.eq_separator('}') {
+                    break;
+                } else if token.eq_separator(',') {
+                    continue;
+                }
+                let field = token.into_text_or_else(Error::unexpected_token)?;
+                let is_type = field[1..].starts_with(|c: char| c.is_ascii_uppercase());
+                let setting = Self::read_setting(iter, is_type)?;
+                object.settings.push((field, setting));
+            }
+        } else {
+            let class =
+                class_definition.ok_or_else(|| Error::unknown_object_class(open.clone()))?;
+            let syntax = class
+                .syntax
+                .as_ref()
+                .ok_or_else(|| Error::invalid_object_syntax(open.clone()))?;
+            Self::read_settings_with_syntax(iter, class, syntax, &mut object, false)?;
+            let token = Self::next(iter)?;
+            if !token.eq_separator('}') {
+                return Err(Error::invalid_object_syntax(token));
+            }
+        }
+
+        Ok(object)
+    }
+
+    /// Reads the settings of an object in the given defined syntax and returns whether the
+    /// settings were present, which can only be false for an optional group
+    fn read_settings_with_syntax(
+        iter: &mut Peekable<IntoIter<Token>>,
+        class: &ObjectClass,
+        syntax: &[SyntaxToken],
+        object: &mut Object,
+        optional: bool,
+    ) -> Result<bool, Error> {
+        for (index, token) in syntax.iter().enumerate() {
+            match token {
+                SyntaxToken::Literal(literal) => {
+                    let present = Self::peek(iter)?.eq_text(literal)
+                        || matches!(
+                            Self::peek(iter)?.separator(),
+                            Some(s) if literal.eq(&s.to_string())
+                        );
+                    if present {
+                        let _ = Self::next(iter)?;
+                    } else if optional && index == 0 {
+                        return Ok(false);
+                    } else {
+                        return Err(Error::invalid_object_syntax(Self::next(iter)?));
+                    }
+                }
+                SyntaxToken::Field(name) => {
+                    let field = match class.field(name) {
+                        Some(field) => field,
+                        None => return Err(Error::invalid_object_syntax(Self::next(iter)?)),
+                    };
+                    let setting =
+                        Self::read_setting(iter, matches!(field.kind, FieldSpecKind::Type))?;
+                    object.settings.push((name.clone(), setting));
+                }
+                SyntaxToken::Optional(group) => {
+                    let _ = Self::read_settings_with_syntax(iter, class, group, object, true)?;
+                }
+            }
+        }
+        Ok(true)
+    }
+
+    fn read_setting(iter: &mut Peekable<IntoIter<Token>>, is_type: bool) -> Result<Setting, Error> {
+        if is_type {
+            Ok(Setting::Type(Self::read_role(iter)?))
+        } else {
+            Ok(Setting::Value(LiteralValue::try_from(&Self::next(iter)?)?))
+        }
+    }
+
+    fn read_object_set(
+        tokens: Vec<Token>,
+        class: &str,
+        classes: &[Definition<ObjectClass>],
+    ) -> Result<ObjectSet, Error> {
+        let mut iter = tokens.into_iter().peekable();
+        Self::next_separator_ignore_case(&mut iter, '{')?;
+        let mut set = ObjectSet {
+            class: class.to_string(),
+            elements: Vec::new(),
+            extensible: false,
+        };
+
+        loop {
+            let token = Self::peek(&mut iter)?;
+            if token.eq_separator('}') {
+                let _ = Self::next(&mut iter)?;
+                return Ok(set);
+            } else if token.eq_separator(',') || token.eq_separator('|') {
+                let _ = Self::next(&mut iter)?;
+            } else if token.eq_symbol("...") {
+                let _ = Self::next(&mut iter)?;
+                set.extensible = true;
+            } else if token.eq_separator('{') {
+                set.elements
+                    .push(ObjectSetElement::Object(Self::read_object_from_iter(
+                        &mut iter, class, classes,
+                    )?));
+            } else {
+                set.elements
+                    .push(ObjectSetElement::Reference(Self::next_text(&mut iter)?));
+            }
+        }
+    }
+
+    /// Reads the assignments of objects (`object CLASS ::= { ... }`) and object sets
+    /// (`ObjectSet CLASS ::= { ... }`), which are distinguished by the case of their name
+    pub(crate) fn read_information_objects(
+        &mut self,
+        assignments: Vec<(String, String, Vec<Token>)>,
+    ) -> Result<(), Error> {
+        for (name, class, tokens) in assignments {
+            if name.starts_with(|c: char| c.is_ascii_lowercase()) {
+                let object = Self::read_object(tokens, &class, &self.classes)?;
+                self.objects.push(Definition(name, object));
+            } else {
+                let object_set = Self::read_object_set(tokens, &class, &self.classes)?;
+                self.object_sets.push(Definition(name, object_set));
+            }
+        }
+        Ok(())
+    }
+
+    /// Reads the field after `CLASS` of `CLASS.&field` and its optional table constraint
+    pub(crate) fn read_object_class_field(
+        iter: &mut Peekable<IntoIter<Token>>,
+        class: String,
+    ) -> Result<ObjectClassField, Error> {
+        Self::next_separator_ignore_case(iter, '.')?;
+        let token = Self::next(iter)?;
+        let field = match token.text() {
+            Some(field) if field.starts_with('&') => field.to_string(),
+            _ => return Err(Error::unexpected_token(token)),
+        };
+
+        let table_constraint = if matches!(iter.peek(), Some(t) if t.eq_separator('(')) {
+            Self::next_separator_ignore_case(iter, '(')?;
+            let object_set = Self::read_braced_name(iter)?;
+            let component = if Self::peek(iter)?.eq_separator('{') {
+                Some(Self::read_braced_name(iter)?)
+            } else {
+                None
+            };
+            Self::next_separator_ignore_case(iter, ')')?;
+            Some(TableConstraint {
+                object_set,
+                component,
+            })
+        } else {
+            None
+        };
+
+        Ok(ObjectClassField {
+            class,
+            field,
+            table_constraint,
+        })
+    }
+
+    /// Reads `{Name}`, `{@name}` or `{@.name}`. Redundant brackets - as result of substituting a
+    /// parameter of a parameterized type with an object set (`{{ObjectSet}}`) - are ignored.
+    fn read_braced_name(iter: &mut Peekable<IntoIter<Token>>) -> Result<String, Error> {
+        let mut depth = 0;
+        while Self::peek(iter)?.eq_separator('{') {
+            let _ = Self::next(iter)?;
+            depth += 1;
+        }
+        if Self::peek(iter)?.eq_separator('@') {
+            let _ = Self::next(iter)?;
+            if Self::peek(iter)?.eq_separator('.') {
+                let _ = Self::next(iter)?;
+            }
+        }
+        let name = Self::next_text(iter)?;
+        for _ in 0..depth {
+            Self::next_separator_ignore_case(iter, '}')?;
+        }
+        Ok(name)
+    }
+}
+
+impl Context<'_> {
+    /// Resolves the type of the given field of a class. An open type without a component
+    /// relation constraint cannot be decoded into a specific type and is therefore represented
+    /// as its encoding (`OCTET STRING`).
+    ///
+    /// # Errors
+    ///
+    /// If the class or its field cannot be resolved or if the field is an open type with a
+    /// component relation constraint, which requires the enclosing `SEQUENCE`
+    pub fn resolve_object_class_field(&self, field: &ObjectClassField) -> Result<Type, Error> {
+        match &self.resolve_field_spec(field)?.kind {
+            FieldSpecKind::Value(r#type) => Ok(r#type.clone()),
+            FieldSpecKind::Type => {
+                if let Some(TableConstraint {
+                    component: Some(component),
+                    ..
+                }) = &field.table_constraint
+                {
+                    return Err(Error::unsupported_component_relation(
+                        format!("{}.{}", field.class, field.field),
+                        component.clone(),
+                    ));
+                }
+                Ok(Type::OctetString(Size::Any))
+            }
+        }
+    }
+
+    /// Resolves the open type and the name of the component that identifies its actual type,
+    /// if the given type of a component is an open type with a component relation constraint
+    /// (`CLASS.&Type ({ObjectSet}{@id})`). The referenced component has to be one of the given
+    /// preceding components.
+    ///
+    /// # Errors
+    ///
+    /// If the referenced component is not one of the preceding components or if a class, object,
+    /// object set or key cannot be resolved
+    pub fn resolve_component_relation<'c>(
+        &self,
+        r#type: &Type,
+        preceding: &'c [Field<Asn>],
+    ) -> Result<Option<(OpenType, &'c str)>, Error> {
+        let field = match r#type {
+            Type::ObjectClassField(field) => field,
+            _ => return Ok(None),
+        };
+        let component = match field
+            .table_constraint
+            .as_ref()
+            .and_then(|constraint| constraint.component.as_ref())
+        {
+            Some(component) => component,
+            None => return Ok(None),
+        };
+        if !matches!(self.resolve_field_spec(field)?.kind, FieldSpecKind::Type) {
+            // the value of a value field is not affected by the actual type
+            return Ok(None);
+        }
+
+        match preceding.iter().find(|c| c.name.eq(component)) {
+            Some(Field {
+                name,
+                role:
+                    Asn {
+                        r#type: Type::ObjectClassField(key),
+                        ..
+                    },
+            }) => Ok(Some((self.resolve_open_type(field, key)?, name))),
+            _ => Err(Error::invalid_component_relation(
+                format!("{}.{}", field.class, field.field),
+                component.clone(),
+            )),
+        }
+    }
+
+    fn resolve_field_spec(&self, field: &ObjectClassField) -> Result<&FieldSpec, Error> {
+        self.resolve_class(&field.class)
+            .ok_or_else(|| Error::unresolved_reference(field.class.clone()))?
+            .field(&field.field)
+            .ok_or_else(|| Error::unknown_class_field(field.class.clone(), field.field.clone()))
+    }
+
+    /// Resolves the open type of `field` whose component relation constraint references a
+    /// component of the type `key`, by collecting the types of all objects of the object set
+    ///
+    /// # Errors
+    ///
+    /// If a class, object, object set or key cannot be resolved
+    pub fn resolve_open_type(
+        &self,
+        field: &ObjectClassField,
+        key: &ObjectClassField,
+    ) -> Result<OpenType, Error> {
+        let object_set = field
+            .table_constraint
+            .as_ref()
+            .map(|constraint| constraint.object_set.as_str())
+            .ok_or_else(|| {
+                Error::missing_table_constraint(format!("{}.{}", field.class, field.field))
+            })?;
+
+        let mut objects = Vec::new();
+        self.collect_objects(object_set, &mut objects)?;
+
+        let mut variants = Vec::with_capacity(objects.len());
+        for object in objects {
+            let r#type = match object.setting(&field.field) {
+                Some(Setting::Type(r#type)) => r#type.clone(),
+                Some(Setting::Value(_)) => {
+                    return Err(Error::invalid_object_setting(
+                        object_set.to_string(),
+                        field.field.clone(),
+                    ))
+                }
+                None => continue,
+            };
+            let key = match object.setting(&key.field) {
+                Some(Setting::Value(value)) => self.resolve_key(value)?,
+                _ => {
+                    return Err(Error::invalid_object_setting(
+                        object_set.to_string(),
+                        key.field.clone(),
+                    ))
+                }
+            };
+            let name = match &r#type {
+                Type::TypeReference(name, _) => name.clone(),
+                _ => "Value".to_string(),
+            };
+            variants.push(OpenTypeVariant { key, name, r#type });
+        }
+
+        // the same type might be used for multiple keys
+        let names = variants.iter().map(|v| v.name.clone()).collect::<Vec<_>>();
+        for variant in &mut variants {
+            if names.iter().filter(|name| **name == variant.name).count() > 1 {
+                variant.name = format!("{}{}", variant.name, variant.key);
+            }
+        }
+
+        // values of keys that are unknown to an extensible object set are kept as their encoding
+        let extensible = self
+            .resolve_object_set(object_set)
+            .map(|set| set.extensible)
+            .unwrap_or(false);
+
+        Ok(OpenType {
+            variants,
+            unknown_extensions: if extensible {
+                Some(UNKNOWN_EXTENSIONS_VARIANT.to_string())
+            } else {
+                None
+            },
+        })
+    }
+
+    fn collect_objects<'a>(
+        &'a self,
+        object_set: &str,
+        objects: &mut Vec<&'a Object>,
+    ) -> Result<(), Error> {
+        let set = self
+            .resolve_object_set(object_set)
+            .ok_or_else(|| Error::unresolved_reference(object_set.to_string()))?;
+        for element in &set.elements {
+            match element {
+                ObjectSetElement::Object(object) => objects.push(object),
+                ObjectSetElement::Reference(name) => {
+                    if let Some(object) = self.resolve_object(name) {
+                        objects.push(object);
+                    } else {
+                        self.collect_objects(name, objects)?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn resolve_key(&self, value: &LiteralValue) -> Result<i64, Error> {
+        match value {
+            LiteralValue::Integer(key) => Ok(*key),
+            LiteralValue::Enumerated(name) => match self.resolve_value(name) {
+                Some(LiteralValue::Integer(key)) => Ok(*key),
+                Some(value) => Err(Error::invalid_key(format!("{} of {}", value, name))),
+                None => Err(Error::unresolved_reference(name.clone())),
+            },
+            value => Err(Error::invalid_key(value.to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::rust::{DataEnum, DataVariant, Field as RustField};
+    use crate::model::*;
+    use crate::parser::{Location, Tokenizer};
+
+    const PROTOCOL_IES: &str = r"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+        PROTOCOL-IES ::= CLASS {
+            &id INTEGER (0..65535) UNIQUE,
+            &Value
+        } WITH SYNTAX {
+            ID &id
+            TYPE &Value
+            [PRESENCE]
+        }
+
+        id-Name INTEGER ::= 7
+
+        name PROTOCOL-IES ::= { ID id-Name TYPE Name }
+
+        SetupIEs PROTOCOL-IES ::= {
+            name |
+            { ID 8 TYPE INTEGER (0..255) PRESENCE },
+            ...
+        }
+
+        Name ::= UTF8String
+
+        SetupIE ::= SEQUENCE {
+            id PROTOCOL-IES.&id ({SetupIEs}),
+            value PROTOCOL-IES.&Value ({SetupIEs}{@id})
+        }
+
+        END";
+
+    #[test]
+    fn test_class_objects_and_object_sets_are_parsed() {
+        let model = Model::try_from(Tokenizer.parse(PROTOCOL_IES).unwrap()).unwrap();
+
+        assert_eq!(
+            vec![Definition(
+                "PROTOCOL-IES".to_string(),
+                ObjectClass {
+                    fields: vec![
+                        FieldSpec {
+                            name: "&id".to_string(),
+                            kind: FieldSpecKind::Value(Type::integer_with_range(Range::inclusive(
+                                Some(0),
+                                Some(65535)
+                            ))),
+                            unique: true,
+                            optional: false,
+                        },
+                        FieldSpec {
+                            name: "&Value".to_string(),
+                            kind: FieldSpecKind::Type,
+                            unique: false,
+                            optional: false,
+                        },
+                    ],
+                    syntax: Some(vec![
+                        SyntaxToken::Literal("ID".to_string()),
+                        SyntaxToken::Field("&id".to_string()),
+                        SyntaxToken::Literal("TYPE".to_string()),
+                        SyntaxToken::Field("&Value".to_string()),
+                        SyntaxToken::Optional(vec![SyntaxToken::Literal("PRESENCE".to_string())]),
+                    ]),
+                }
+            )],
+            model.classes
+        );
+        assert_eq!(
+            vec![Definition(
+                "name".to_string(),
+                Object {
+                    class: "PROTOCOL-IES".to_string(),
+                    settings: vec![
+                        (
+                            "&id".to_string(),
+                            Setting::Value(LiteralValue::Enumerated("id-Name".to_string()))
+                        ),
+                        (
+                            "&Value".to_string(),
+                            Setting::Type(Type::TypeReference("Name".to_string(), None))
+                        ),
+                    ],
+                }
+            )],
+            model.objects
+        );
+        assert_eq!(
+            vec![Definition(
+                "SetupIEs".to_string(),
+                ObjectSet {
+                    class: "PROTOCOL-IES".to_string(),
+                    elements: vec![
+                        ObjectSetElement::Reference("name".to_string()),
+                        ObjectSetElement::Object(Object {
+                            class: "PROTOCOL-IES".to_string(),
+                            settings: vec![
+                                ("&id".to_string(), Setting::Value(LiteralValue::Integer(8))),
+                                (
+                                    "&Value".to_string(),
+                                    Setting::Type(Type::integer_with_range(Range::inclusive(
+                                        Some(0),
+                                        Some(255)
+                                    )))
+                                ),
+                            ],
+                        }),
+                    ],
+                    extensible: true,
+                }
+            )],
+            model.object_sets
+        );
+        assert_eq!(
+            Some(&Definition(
+                "SetupIE".to_string(),
+                Type::sequence_from_fields(vec![
+                    Field {
+                        name: "id".to_string(),
+                        role: Type::ObjectClassField(ObjectClassField {
+                            class: "PROTOCOL-IES".to_string(),
+                            field: "&id".to_string(),
+                            table_constraint: Some(TableConstraint {
+                                object_set: "SetupIEs".to_string(),
+                                component: None,
+                            }),
+                        })
+                        .untagged(),
+                    },
+                    Field {
+                        name: "value".to_string(),
+                        role: Type::ObjectClassField(ObjectClassField {
+                            class: "PROTOCOL-IES".to_string(),
+                            field: "&Value".to_string(),
+                            table_constraint: Some(TableConstraint {
+                                object_set: "SetupIEs".to_string(),
+                                component: Some("id".to_string()),
+                            }),
+                        })
+                        .untagged(),
+                    },
+                ])
+                .untagged(),
+            )),
+            model.definitions.last()
+        );
+    }
+
+    #[test]
+    fn test_open_type_is_converted_to_rust() {
+        let model = Model::try_from(Tokenizer.parse(PROTOCOL_IES).unwrap())
+            .unwrap()
+            .to_rust();
+
+        assert_eq!(
+            vec![
+                Definition(
+                    "Name".to_string(),
+                    Rust::tuple_struct_from_type(RustType::String(Size::Any, Charset::Utf8, None)),
+                ),
+                Definition(
+                    "SetupIEValue".to_string(),
+                    Rust::OpenType(
+                        DataEnum::from(vec![
+                            DataVariant::from_name_type(
+                                "Name",
+                                RustType::Complex(
+                                    "Name".to_string(),
+                                    Some(Tag::DEFAULT_UTF8_STRING)
+                                ),
+                            )
+                            .with_key(7),
+                            DataVariant::from_name_type(
+                                "Value",
+                                RustType::U8(Range::inclusive(0, 255))
+                            )
+                            .with_key(8),
+                        ])
+                        .with_unknown_extensions(Some(UNKNOWN_EXTENSIONS_VARIANT.to_string()))
+                    ),
+                ),
+                Definition(
+                    "SetupIE".to_string(),
+                    Rust::struct_from_fields(vec![
+                        RustField::from_name_type("id", RustType::U16(Range::inclusive(0, 65535))),
+                        RustField::from_name_type(
+                            "value",
+                            RustType::Complex("SetupIEValue".to_string(), None),
+                        )
+                        .with_key(Some("id".to_string())),
+                    ]),
+                ),
+            ],
+            model.definitions
+        );
+    }
+
+    #[test]
+    fn test_hyphenated_type_of_class_field_is_converted_to_rust() {
+        let model = Model::try_from(
+            Tokenizer
+                .parse(
+                    r"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+            ProtocolIE-ID ::= INTEGER (0..65535)
+
+            Plain-Name ::= UTF8String
+
+            PROTOCOL-IES ::= CLASS { &id ProtocolIE-ID UNIQUE, &Value }
+
+            SetupIEs PROTOCOL-IES ::= { { &id 7, &Value Plain-Name } }
+
+            SetupIE ::= SEQUENCE {
+                id PROTOCOL-IES.&id ({SetupIEs}),
+                value PROTOCOL-IES.&Value ({SetupIEs}{@id})
+            }
+
+            END",
+                )
+                .unwrap(),
+        )
+        .unwrap()
+        .to_rust();
+
+        assert_eq!(
+            &[
+                Definition(
+                    "SetupIEValue".to_string(),
+                    Rust::OpenType(DataEnum::from(vec![DataVariant::from_name_type(
+                        "PlainName",
+                        RustType::Complex("PlainName".to_string(), Some(Tag::DEFAULT_UTF8_STRING)),
+                    )
+                    .with_key(7)])),
+                ),
+                Definition(
+                    "SetupIE".to_string(),
+                    Rust::struct_from_fields(vec![
+                        RustField::from_name_type(
+                            "id",
+                            RustType::Complex(
+                                "ProtocolIEID".to_string(),
+                                Some(Tag::DEFAULT_INTEGER)
+                            ),
+                        ),
+                        RustField::from_name_type(
+                            "value",
+                            RustType::Complex("SetupIEValue".to_string(), None),
+                        )
+                        .with_key(Some("id".to_string())),
+                    ]),
+                ),
+            ][..],
+            &model.definitions[2..]
+        );
+    }
+
+    #[test]
+    fn test_unknown_object_set_is_rejected() {
+        assert_eq!(
+            Error::unresolved_reference("UnknownIEs".to_string()),
+            Model::try_from(
+                Tokenizer
+                    .parse(
+                        r"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+                PROTOCOL-IES ::= CLASS { &id INTEGER, &Value }
+
+                SetupIE ::= SEQUENCE {
+                    id PROTOCOL-IES.&id ({UnknownIEs}),
+                    value PROTOCOL-IES.&Value ({UnknownIEs}{@id})
+                }
+
+                END",
+                    )
+                    .unwrap()
+            )
+            .unwrap()
+            .try_to_rust()
+            .expect_err("Converted open type of unknown object set")
+        );
+    }
+
+    #[test]
+    fn test_component_relation_to_subsequent_component_is_rejected() {
+        assert_eq!(
+            Error::invalid_component_relation("PROTOCOL-IES.&Value".to_string(), "id".to_string()),
+            Model::try_from(
+                Tokenizer
+                    .parse(
+                        r"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+                PROTOCOL-IES ::= CLASS { &id INTEGER, &Value }
+
+                SetupIEs PROTOCOL-IES ::= { { &id 1, &Value BOOLEAN } }
+
+                SetupIE ::= SEQUENCE {
+                    value PROTOCOL-IES.&Value ({SetupIEs}{@id}),
+                    id PROTOCOL-IES.&id ({SetupIEs})
+                }
+
+                END",
+                    )
+                    .unwrap()
+            )
+            .unwrap()
+            .try_to_rust()
+            .expect_err("Converted component relation to a subsequent component")
+        );
+    }
+
+    #[test]
+    fn test_object_of_unknown_class_is_rejected() {
+        assert_eq!(
+            Error::unknown_object_class(Token::Separator(Location::at(3, 39), '{')),
+            Model::try_from(
+                Tokenizer
+                    .parse(
+                        r"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+                name PROTOCOL-IES ::= { ID 1 TYPE BOOLEAN }
+
+                END",
+                    )
+                    .unwrap()
+            )
+            .expect_err("Parsed object of unknown class")
+        );
+    }
+
+    #[test]
+    fn test_object_with_invalid_syntax_is_rejected() {
+        assert_eq!(
+            Error::invalid_object_syntax(Token::Identifier(
+                Location::at(5, 41),
+                "KIND".to_string()
+            )),
+            Model::try_from(
+                Tokenizer
+                    .parse(
+                        r"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+                PROTOCOL-IES ::= CLASS { &id INTEGER, &Value } WITH SYNTAX { ID &id TYPE &Value }
+
+                name PROTOCOL-IES ::= { KIND 1 TYPE BOOLEAN }
+
+                END",
+                    )
+                    .unwrap()
+            )
+            .expect_err("Parsed object with invalid syntax")
+        );
+    }
+}
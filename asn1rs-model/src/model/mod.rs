@@ -1,4 +1,9 @@
+mod components_of;
+pub mod constraint;
+pub mod information_object;
+mod parameterized;
 pub mod protobuf;
+mod recursion;
 pub mod rust;
 pub mod sql;
 
@@ -8,12 +13,15 @@ pub use self::rust::RustType;
 pub use self::protobuf::Protobuf;
 pub use self::protobuf::ProtobufType;
 
-use crate::parser::Token;
+use self::constraint::{ElementSet, InnerTypeConstraints};
+use self::information_object::{Object, ObjectClass, ObjectClassField, ObjectSet, OpenType};
+use crate::parser::{Location, Token};
 use backtrace::Backtrace;
 use std::convert::TryFrom;
 use std::error::Error as StdError;
 use std::fmt::{Debug, Display, Formatter};
 use std::iter::Peekable;
+use std::str::FromStr;
 use std::vec::IntoIter;
 
 macro_rules! loop_ctrl_separator {
@@ -35,6 +43,7 @@ pub enum ErrorKind {
     ExpectedTextGot(String, Token),
     ExpectedSeparator(Token),
     ExpectedSeparatorGot(char, Token),
+    ExpectedSymbolGot(&'static str, Token),
     UnexpectedToken(Token),
     MissingModuleName,
     UnexpectedEndOfStream,
@@ -44,6 +53,22 @@ pub enum ErrorKind {
     InvalidTag(Token),
     InvalidPositionForExtensionMarker(Token),
     InvalidIntText(Token),
+    InvalidLiteralValue(Token),
+    InvalidNumberOfParameters(Token),
+    RecursiveParameterizedType(Token),
+    UnknownObjectClass(Token),
+    InvalidObjectSyntax(Token),
+    InvalidPermittedAlphabet(Token),
+    UnterminatedString(Location),
+    UnterminatedComment(Location),
+    InvalidDigit(Location, char),
+    UnresolvedReference(String),
+    UnknownClassField(String, String),
+    MissingTableConstraint(String),
+    UnsupportedComponentRelation(String, String),
+    InvalidComponentRelation(String, String),
+    InvalidObjectSetting(String, String),
+    InvalidKey(String),
 }
 
 pub struct Error {
@@ -71,6 +96,30 @@ impl Error {
         ErrorKind::InvalidIntText(token).into()
     }
 
+    pub fn invalid_literal_value(token: Token) -> Self {
+        ErrorKind::InvalidLiteralValue(token).into()
+    }
+
+    pub fn invalid_number_of_parameters(token: Token) -> Self {
+        ErrorKind::InvalidNumberOfParameters(token).into()
+    }
+
+    pub fn recursive_parameterized_type(token: Token) -> Self {
+        ErrorKind::RecursiveParameterizedType(token).into()
+    }
+
+    pub fn unknown_object_class(token: Token) -> Self {
+        ErrorKind::UnknownObjectClass(token).into()
+    }
+
+    pub fn invalid_object_syntax(token: Token) -> Self {
+        ErrorKind::InvalidObjectSyntax(token).into()
+    }
+
+    pub fn invalid_permitted_alphabet(token: Token) -> Self {
+        ErrorKind::InvalidPermittedAlphabet(token).into()
+    }
+
     pub fn invalid_position_for_extension_marker(token: Token) -> Self {
         ErrorKind::InvalidPositionForExtensionMarker(token).into()
     }
@@ -107,6 +156,10 @@ impl Error {
         ErrorKind::ExpectedSeparatorGot(separator, token).into()
     }
 
+    pub fn expected_symbol(symbol: &'static str, token: Token) -> Self {
+        ErrorKind::ExpectedSymbolGot(symbol, token).into()
+    }
+
     pub fn missing_module_name() -> Self {
         ErrorKind::MissingModuleName.into()
     }
@@ -119,6 +172,46 @@ impl Error {
         ErrorKind::UnexpectedEndOfStream.into()
     }
 
+    pub fn unterminated_string(location: Location) -> Self {
+        ErrorKind::UnterminatedString(location).into()
+    }
+
+    pub fn unterminated_comment(location: Location) -> Self {
+        ErrorKind::UnterminatedComment(location).into()
+    }
+
+    pub fn invalid_digit(location: Location, digit: char) -> Self {
+        ErrorKind::InvalidDigit(location, digit).into()
+    }
+
+    pub fn unresolved_reference(name: String) -> Self {
+        ErrorKind::UnresolvedReference(name).into()
+    }
+
+    pub fn unknown_class_field(class: String, field: String) -> Self {
+        ErrorKind::UnknownClassField(class, field).into()
+    }
+
+    pub fn missing_table_constraint(field: String) -> Self {
+        ErrorKind::MissingTableConstraint(field).into()
+    }
+
+    pub fn unsupported_component_relation(field: String, component: String) -> Self {
+        ErrorKind::UnsupportedComponentRelation(field, component).into()
+    }
+
+    pub fn invalid_component_relation(field: String, component: String) -> Self {
+        ErrorKind::InvalidComponentRelation(field, component).into()
+    }
+
+    pub fn invalid_object_setting(object_set: String, field: String) -> Self {
+        ErrorKind::InvalidObjectSetting(object_set, field).into()
+    }
+
+    pub fn invalid_key(key: String) -> Self {
+        ErrorKind::InvalidKey(key).into()
+    }
+
     fn backtrace(&self) -> &Backtrace {
         &self.backtrace
     }
@@ -129,6 +222,7 @@ impl Error {
             ErrorKind::ExpectedTextGot(_, t) => Some(t),
             ErrorKind::ExpectedSeparator(t) => Some(t),
             ErrorKind::ExpectedSeparatorGot(_, t) => Some(t),
+            ErrorKind::ExpectedSymbolGot(_, t) => Some(t),
             ErrorKind::UnexpectedToken(t) => Some(t),
             ErrorKind::MissingModuleName => None,
             ErrorKind::UnexpectedEndOfStream => None,
@@ -138,6 +232,22 @@ impl Error {
             ErrorKind::InvalidTag(t) => Some(t),
             ErrorKind::InvalidPositionForExtensionMarker(t) => Some(t),
             ErrorKind::InvalidIntText(t) => Some(t),
+            ErrorKind::InvalidLiteralValue(t) => Some(t),
+            ErrorKind::InvalidNumberOfParameters(t) => Some(t),
+            ErrorKind::RecursiveParameterizedType(t) => Some(t),
+            ErrorKind::UnknownObjectClass(t) => Some(t),
+            ErrorKind::InvalidObjectSyntax(t) => Some(t),
+            ErrorKind::InvalidPermittedAlphabet(t) => Some(t),
+            ErrorKind::UnterminatedString(_) => None,
+            ErrorKind::UnterminatedComment(_) => None,
+            ErrorKind::InvalidDigit(..) => None,
+            ErrorKind::UnresolvedReference(_) => None,
+            ErrorKind::UnknownClassField(..) => None,
+            ErrorKind::MissingTableConstraint(_) => None,
+            ErrorKind::UnsupportedComponentRelation(..) => None,
+            ErrorKind::InvalidComponentRelation(..) => None,
+            ErrorKind::InvalidObjectSetting(..) => None,
+            ErrorKind::InvalidKey(_) => None,
         }
     }
 }
@@ -185,6 +295,14 @@ impl Display for Error {
                 separator,
                 token,
             ),
+            ErrorKind::ExpectedSymbolGot(symbol, token) => write!(
+                f,
+                "At line {}, column {} expected a symbol like '{}', but instead got: {}",
+                token.location().line(),
+                token.location().column(),
+                symbol,
+                token,
+            ),
             ErrorKind::UnexpectedToken(token) => write!(
                 f,
                 "At line {}, column {} an unexpected token was encountered: {}",
@@ -236,7 +354,201 @@ impl Display for Error {
                 token.location().line(),
                 token.location().column(),
                 token
-            )
+            ),
+            ErrorKind::InvalidLiteralValue(token) => write!(
+                f,
+                "At line {}, column {} a value for the type was expected but instead got: {}",
+                token.location().line(),
+                token.location().column(),
+                token
+            ),
+            ErrorKind::InvalidNumberOfParameters(token) => write!(
+                f,
+                "At line {}, column {} the number of actual parameters does not match the parameterized type: {}",
+                token.location().line(),
+                token.location().column(),
+                token
+            ),
+            ErrorKind::RecursiveParameterizedType(token) => write!(
+                f,
+                "At line {}, column {} the instantiation of the parameterized type is infinitely recursive: {}",
+                token.location().line(),
+                token.location().column(),
+                token
+            ),
+            ErrorKind::UnknownObjectClass(token) => write!(
+                f,
+                "At line {}, column {} the object is in a defined syntax, but its class is not defined in this module: {}",
+                token.location().line(),
+                token.location().column(),
+                token
+            ),
+            ErrorKind::InvalidObjectSyntax(token) => write!(
+                f,
+                "At line {}, column {} the object does not match the syntax of its class: {}",
+                token.location().line(),
+                token.location().column(),
+                token
+            ),
+            ErrorKind::InvalidPermittedAlphabet(token) => write!(
+                f,
+                "At line {}, column {} expected a string or a range of single characters for the permitted alphabet: {}",
+                token.location().line(),
+                token.location().column(),
+                token
+            ),
+            ErrorKind::UnterminatedString(location) => write!(
+                f,
+                "At line {}, column {} the character string is not terminated",
+                location.line(),
+                location.column(),
+            ),
+            ErrorKind::UnterminatedComment(location) => write!(
+                f,
+                "At line {}, column {} the block comment is not terminated",
+                location.line(),
+                location.column(),
+            ),
+            ErrorKind::InvalidDigit(location, digit) => write!(
+                f,
+                "At line {}, column {} the binary or hexadecimal string has an invalid digit: {}",
+                location.line(),
+                location.column(),
+                digit
+            ),
+            ErrorKind::UnresolvedReference(name) => write!(
+                f,
+                "Unable to resolve the reference {}, it is neither assigned in this module nor imported from a module in the scope",
+                name
+            ),
+            ErrorKind::UnknownClassField(class, field) => {
+                write!(f, "The class {} has no field named {}", class, field)
+            }
+            ErrorKind::MissingTableConstraint(field) => {
+                write!(f, "{} has no table constraint", field)
+            }
+            ErrorKind::UnsupportedComponentRelation(field, component) => write!(
+                f,
+                "The component relation constraint {} ({{@{}}}) is only supported on mandatory components of a SEQUENCE",
+                field, component
+            ),
+            ErrorKind::InvalidComponentRelation(field, component) => write!(
+                f,
+                "The component {} referenced by {} has to be a preceding component, whose type is a field of a class",
+                component, field
+            ),
+            ErrorKind::InvalidObjectSetting(object_set, field) => write!(
+                f,
+                "An object of {} has no setting for {} or its setting is not of the kind of the field",
+                object_set, field
+            ),
+            ErrorKind::InvalidKey(key) => write!(f, "The key {} is not an integer", key),
+        }
+    }
+}
+
+/// The notation of a value as described in ITU-T X.680 | ISO/IEC 8824-1:2015, chapter 17.
+/// Only the values of the simple types, that can be written in a single token, are supported.
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub enum LiteralValue {
+    Boolean(bool),
+    Integer(i64),
+    String(String),
+    /// A `bstring` or `hstring` with the given number of bits, see chapter 12.10 and 12.12
+    Bits(Vec<u8>, u64),
+    /// The identifier of an `ENUMERATED` variant
+    Enumerated(String),
+}
+
+impl LiteralValue {
+    pub fn is_compatible_with(&self, r#type: &Type) -> bool {
+        match (self, r#type) {
+            (_, Type::TypeReference(..)) => true,
+            (_, Type::WithComponents(..)) => true,
+            (_, Type::Containing(inner, _)) => self.is_compatible_with(inner),
+            (_, Type::ObjectClassField(..)) => true,
+            (LiteralValue::Boolean(_), Type::Boolean) => true,
+            (LiteralValue::Integer(_), Type::Integer(_)) => true,
+            (LiteralValue::String(_), Type::String(..)) => true,
+            (LiteralValue::Bits(..), Type::BitString(_)) => true,
+            (LiteralValue::Bits(_, bits), Type::OctetString(_)) => bits % 8 == 0,
+            (LiteralValue::Enumerated(name), Type::Enumerated(enumerated)) => {
+                enumerated.variants().any(|v| v.name() == name)
+            }
+            _ => false,
+        }
+    }
+
+    fn parse_bits(digits: &str, bits_per_digit: u32) -> Option<Vec<u8>> {
+        let mut bytes = Vec::with_capacity(digits.len() * bits_per_digit as usize / 8 + 1);
+        let mut bit = 0;
+        for digit in digits.chars() {
+            let value = digit.to_digit(1 << bits_per_digit)?;
+            for i in (0..bits_per_digit).rev() {
+                if bit % 8 == 0 {
+                    bytes.push(0);
+                }
+                if value & (1 << i) != 0 {
+                    *bytes.last_mut()? |= 0x80 >> (bit % 8);
+                }
+                bit += 1;
+            }
+        }
+        Some(bytes)
+    }
+}
+
+impl TryFrom<&Token> for LiteralValue {
+    type Error = Error;
+
+    fn try_from(token: &Token) -> Result<Self, Self::Error> {
+        match token {
+            Token::Keyword(_, text) if text == "TRUE" || text == "FALSE" => {
+                Ok(LiteralValue::Boolean(text == "TRUE"))
+            }
+            Token::Number(_, text) => text
+                .parse::<i64>()
+                .map(LiteralValue::Integer)
+                .map_err(|_| Error::invalid_literal_value(token.clone())),
+            Token::CString(_, text) => Ok(LiteralValue::String(text.clone())),
+            Token::BString(_, digits) | Token::HString(_, digits) => {
+                let bits_per_digit = if matches!(token, Token::BString(..)) {
+                    1
+                } else {
+                    4
+                };
+                let bytes = Self::parse_bits(digits, bits_per_digit)
+                    .ok_or_else(|| Error::invalid_literal_value(token.clone()))?;
+                let bits = digits.chars().count() as u64 * u64::from(bits_per_digit);
+                Ok(LiteralValue::Bits(bytes, bits))
+            }
+            Token::Identifier(_, text)
+                if text.starts_with(|c: char| c.is_ascii_lowercase())
+                    && text.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') =>
+            {
+                Ok(LiteralValue::Enumerated(text.to_string()))
+            }
+            _ => Err(Error::invalid_literal_value(token.clone())),
+        }
+    }
+}
+
+impl Display for LiteralValue {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            LiteralValue::Boolean(true) => write!(f, "TRUE"),
+            LiteralValue::Boolean(false) => write!(f, "FALSE"),
+            LiteralValue::Integer(value) => write!(f, "{}", value),
+            LiteralValue::String(value) => write!(f, "\"{}\"", value.replace('"', "\"\"")),
+            LiteralValue::Bits(bytes, bits) => {
+                write!(f, "'")?;
+                for bit in 0..*bits {
+                    let set = bytes[(bit / 8) as usize] & (0x80 >> (bit % 8)) != 0;
+                    write!(f, "{}", if set { '1' } else { '0' })?;
+                }
+                write!(f, "'B")
+            }
+            LiteralValue::Enumerated(name) => write!(f, "{}", name),
         }
     }
 }
@@ -263,47 +575,164 @@ pub enum ObjectIdentifierComponent {
     NameAndNumberForm(String, u64),
 }
 
+/// The notation a [`Model`] is represented in
+pub trait Target {
+    /// The type of the value of a [`ValueReference`] in this notation
+    type ValueReferenceType;
+}
+
+/// The tagging of a module, see ITU-T X.680 | ISO/IEC 8824-1:2015, 13.1 (`TagDefault`)
+#[derive(Debug, Clone, Copy, PartialOrd, PartialEq, Eq)]
+pub enum TagDefault {
+    /// Also applies if the module does not specify its tagging
+    Explicit,
+    Implicit,
+    Automatic,
+}
+
+/// The keyword that follows the tag of a tagged type, see ITU-T X.680 | ISO/IEC 8824-1:2015,
+/// 31.1. Without it, the tagging of the module applies.
+#[derive(Debug, Clone, Copy, PartialOrd, PartialEq, Eq)]
+pub enum Tagging {
+    Explicit,
+    Implicit,
+}
+
 #[derive(Debug, Clone)]
-pub struct Model<T> {
+pub struct Model<T: Target> {
     pub name: String,
     pub oid: Option<ObjectIdentifier>,
+    pub tag_default: TagDefault,
+    /// Whether the module is declared with `EXTENSIBILITY IMPLIED`, which makes all `SEQUENCE`,
+    /// `SET`, `CHOICE` and `ENUMERATED` types of the module extensible
+    pub extensibility_implied: bool,
     pub imports: Vec<Import>,
     pub definitions: Vec<Definition<T>>,
+    pub value_references: Vec<ValueReference<T::ValueReferenceType>>,
+    pub classes: Vec<Definition<ObjectClass>>,
+    pub objects: Vec<Definition<Object>>,
+    pub object_sets: Vec<Definition<ObjectSet>>,
 }
 
-impl<T> Default for Model<T> {
+impl<T: Target> Default for Model<T> {
     fn default() -> Self {
         Model {
             name: Default::default(),
             oid: None,
+            // models that are not parsed from a module have always been tagged automatically
+            tag_default: TagDefault::Automatic,
+            extensibility_implied: false,
             imports: Default::default(),
             definitions: Default::default(),
+            value_references: Default::default(),
+            classes: Default::default(),
+            objects: Default::default(),
+            object_sets: Default::default(),
+        }
+    }
+}
+
+/// A value assignment as described in ITU-T X.680 | ISO/IEC 8824-1:2015, chapter 16.2, such as
+/// `maxNumberOfItems INTEGER ::= 23`
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub struct ValueReference<T> {
+    pub name: String,
+    pub role: T,
+    pub value: LiteralValue,
+}
+
+/// A literal value or a reference to a [`ValueReference`] of the module itself or of one of its
+/// imports, which is resolved when converting the model to rust
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub enum LitOrRef<T> {
+    Lit(T),
+    Ref(String),
+}
+
+impl<T> LitOrRef<T> {
+    pub fn lit(&self) -> Option<&T> {
+        match self {
+            LitOrRef::Lit(value) => Some(value),
+            LitOrRef::Ref(_) => None,
+        }
+    }
+}
+
+impl<T: Display> Display for LitOrRef<T> {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            LitOrRef::Lit(value) => Display::fmt(value, f),
+            LitOrRef::Ref(name) => f.write_str(name),
         }
     }
 }
 
+impl<T> From<T> for LitOrRef<T> {
+    fn from(value: T) -> Self {
+        LitOrRef::Lit(value)
+    }
+}
+
 impl Model<Asn> {
     pub fn try_from(value: Vec<Token>) -> Result<Self, Error> {
         let mut model = Model::default();
-        let mut iter = value.into_iter().peekable();
+        let mut iter = Self::expand_parameterized_types(value)?
+            .into_iter()
+            .peekable();
 
         model.name = Self::read_name(&mut iter)?;
         model.oid = Self::maybe_read_oid(&mut iter)?;
-        Self::skip_until_after_text_ignore_ascii_case(&mut iter, "BEGIN")?;
+        model.read_module_header(&mut iter)?;
+
+        // objects and object sets are read once all classes are known
+        let mut information_objects = Vec::new();
 
         while let Some(token) = iter.next() {
             if token.eq_text_ignore_ascii_case("END") {
+                model.read_information_objects(information_objects)?;
+                if model.extensibility_implied {
+                    model.imply_extensibility();
+                }
                 model.make_names_nice();
                 return Ok(model);
             } else if token.eq_text_ignore_ascii_case("IMPORTS") {
                 Self::read_imports(&mut iter)?
                     .into_iter()
                     .for_each(|i| model.imports.push(i));
+            } else if Self::peek(&mut iter)?.eq_symbol("::=") {
+                let name = token.into_text_or_else(Error::unexpected_token)?;
+                Self::read_assignment_operator(&mut iter)?;
+                if Self::peek(&mut iter)?.eq_text("CLASS") {
+                    let _ = Self::next(&mut iter)?;
+                    model
+                        .classes
+                        .push(Definition(name, Self::read_object_class(&mut iter)?));
+                } else {
+                    model
+                        .definitions
+                        .push(Self::read_type_assignment(&mut iter, name)?);
+                }
             } else {
-                model.definitions.push(Self::read_definition(
-                    &mut iter,
-                    token.into_text_or_else(Error::unexpected_token)?,
-                )?);
+                let name = token.into_text_or_else(Error::unexpected_token)?;
+                let role = Self::read_role(&mut iter)?;
+                Self::read_assignment_operator(&mut iter)?;
+                match role {
+                    // an object or an object set of the given class
+                    Type::TypeReference(class, None)
+                        if Self::peek(&mut iter)?.eq_separator('{') =>
+                    {
+                        information_objects.push((
+                            name,
+                            class,
+                            Self::read_braced_tokens(&mut iter)?,
+                        ));
+                    }
+                    role => {
+                        model
+                            .value_references
+                            .push(Self::read_value_reference(&mut iter, name, role)?);
+                    }
+                }
             }
         }
         Err(Error::unexpected_end_of_stream())
@@ -359,14 +788,72 @@ impl Model<Asn> {
         Ok(ObjectIdentifier(vec))
     }
 
-    fn skip_until_after_text_ignore_ascii_case(
-        iter: &mut Peekable<IntoIter<Token>>,
-        text: &str,
-    ) -> Result<(), Error> {
-        for t in iter {
-            if t.eq_text_ignore_ascii_case(text) {
+    /// ITU-T X.680 | ISO/IEC 8824-1:2015, chapter 13: `EXTENSIBILITY IMPLIED` is equivalent to
+    /// an extension marker at the end of each type that is not extensible already
+    fn imply_extensibility(&mut self) {
+        for Definition(_, asn) in &mut self.definitions {
+            Self::imply_type_extensibility(&mut asn.r#type);
+        }
+    }
+
+    fn imply_type_extensibility(r#type: &mut Type) {
+        match r#type {
+            Type::Sequence(list) | Type::Set(list) => {
+                if list.extension_after.is_none() {
+                    list.extension_after = list.fields.len().checked_sub(1);
+                }
+                for field in &mut list.fields {
+                    Self::imply_type_extensibility(&mut field.role.r#type);
+                }
+            }
+            Type::Choice(choice) => {
+                if choice.extension_after.is_none() {
+                    choice.extension_after = choice.variants.len().checked_sub(1);
+                }
+                for variant in &mut choice.variants {
+                    Self::imply_type_extensibility(&mut variant.r#type);
+                }
+            }
+            Type::Enumerated(enumerated) if enumerated.extension_after.is_none() => {
+                enumerated.extension_after = enumerated.variants.len().checked_sub(1);
+            }
+            Type::Optional(inner)
+            | Type::SequenceOf(inner, _)
+            | Type::SetOf(inner, _)
+            | Type::WithComponents(inner, _)
+            | Type::Containing(inner, _) => Self::imply_type_extensibility(inner),
+            _ => {}
+        }
+    }
+
+    /// ITU-T X.680 | ISO/IEC 8824-1:2015, 13.1, reads the header of the module up to and
+    /// including `BEGIN`. Other than the tagging and the extensibility, the header is skipped.
+    fn read_module_header(&mut self, iter: &mut Peekable<IntoIter<Token>>) -> Result<(), Error> {
+        self.tag_default = TagDefault::Explicit;
+        while let Some(token) = iter.next() {
+            let tag_default = if token.eq_text_ignore_ascii_case("BEGIN") {
                 return Ok(());
+            } else if token.eq_text_ignore_ascii_case("EXPLICIT") {
+                TagDefault::Explicit
+            } else if token.eq_text_ignore_ascii_case("IMPLICIT") {
+                TagDefault::Implicit
+            } else if token.eq_text_ignore_ascii_case("AUTOMATIC") {
+                TagDefault::Automatic
+            } else if token.eq_text_ignore_ascii_case("EXTENSIBILITY") {
+                let token = Self::next(iter)?;
+                if !token.eq_text_ignore_ascii_case("IMPLIED") {
+                    return Err(Error::expected_text("IMPLIED".to_string(), token));
+                }
+                self.extensibility_implied = true;
+                continue;
+            } else {
+                continue;
+            };
+            let token = Self::next(iter)?;
+            if !token.eq_text_ignore_ascii_case("TAGS") {
+                return Err(Error::expected_text("TAGS".to_string(), token));
             }
+            self.tag_default = tag_default;
         }
         Err(Error::unexpected_end_of_stream())
     }
@@ -380,7 +867,12 @@ impl Model<Asn> {
             } else {
                 let text = token.into_text_or_else(Error::unexpected_token)?;
                 import.what.push(text);
-                let token = Self::next(iter)?;
+                let mut token = Self::next(iter)?;
+                if token.eq_separator('{') {
+                    // parameterized types are imported as `Name{}`
+                    Self::next_separator_ignore_case(iter, '}')?;
+                    token = Self::next(iter)?;
+                }
                 if token.eq_separator(',') {
                     // ignore separator
                 } else if token.eq_text_ignore_ascii_case("FROM") {
@@ -397,53 +889,73 @@ impl Model<Asn> {
         iter: &mut Peekable<IntoIter<Token>>,
         name: String,
     ) -> Result<Definition<Asn>, Error> {
-        Self::next_separator_ignore_case(iter, ':')?;
-        Self::next_separator_ignore_case(iter, ':')?;
-        Self::next_separator_ignore_case(iter, '=')?;
+        Self::read_assignment_operator(iter)?;
+        Self::read_type_assignment(iter, name)
+    }
+
+    fn read_assignment_operator(iter: &mut Peekable<IntoIter<Token>>) -> Result<(), Error> {
+        Self::next_symbol(iter, "::=")
+    }
 
-        let (token, tag) = Self::next_with_opt_tag(iter)?;
+    /// Reads the type of a type assignment, after its `::=`
+    fn read_type_assignment(
+        iter: &mut Peekable<IntoIter<Token>>,
+        name: String,
+    ) -> Result<Definition<Asn>, Error> {
+        let (token, tag, tagging) = Self::next_with_opt_tag(iter)?;
 
-        if token.eq_text_ignore_ascii_case("SEQUENCE") {
-            Ok(Definition(
-                name,
-                Self::read_sequence_or_sequence_of(iter)?.opt_tagged(tag),
-            ))
+        let r#type = if token.eq_text_ignore_ascii_case("SEQUENCE") {
+            Self::read_sequence_or_sequence_of(iter)?
         } else if token.eq_text_ignore_ascii_case("SET") {
-            Ok(Definition(
-                name,
-                Self::read_set_or_set_of(iter)?.opt_tagged(tag),
-            ))
+            Self::read_set_or_set_of(iter)?
         } else if token.eq_text_ignore_ascii_case("ENUMERATED") {
-            Ok(Definition(
-                name,
-                Type::Enumerated(Enumerated::try_from(iter)?).opt_tagged(tag),
-            ))
+            Type::Enumerated(Enumerated::try_from(iter)?)
         } else if token.eq_text_ignore_ascii_case("CHOICE") {
-            Ok(Definition(
-                name,
-                Type::Choice(Choice::try_from(iter)?).opt_tagged(tag),
-            ))
+            Type::Choice(Choice::try_from(iter)?)
         } else if let Some(text) = token.text() {
-            Ok(Definition(
-                name,
-                Self::read_role_given_text(iter, text.to_string())?.opt_tagged(tag),
-            ))
+            Self::read_role_given_text(iter, text.to_string())?
         } else {
-            Err(Error::unexpected_token(token))
+            return Err(Error::unexpected_token(token));
+        };
+        Ok(Definition(
+            name,
+            r#type.opt_tagged(tag).with_tagging(tagging),
+        ))
+    }
+
+    /// Reads the value of a value assignment, after its `::=`
+    fn read_value_reference(
+        iter: &mut Peekable<IntoIter<Token>>,
+        name: String,
+        role: Type,
+    ) -> Result<ValueReference<Type>, Error> {
+        let token = Self::next(iter)?;
+        let value = LiteralValue::try_from(&token)?;
+        if value.is_compatible_with(&role) {
+            Ok(ValueReference { name, role, value })
+        } else {
+            Err(Error::invalid_literal_value(token))
         }
     }
 
+    /// Reads the next token, which is preceded by the optional tag and its tagging
     fn next_with_opt_tag(
         iter: &mut Peekable<IntoIter<Token>>,
-    ) -> Result<(Token, Option<Tag>), Error> {
+    ) -> Result<(Token, Option<Tag>, Option<Tagging>), Error> {
         let token = Self::next(iter)?;
         if token.eq_separator('[') {
             let tag = Tag::try_from(&mut *iter)?;
             Self::next_separator_ignore_case(iter, ']')?;
             let token = Self::next(iter)?;
-            Ok((token, Some(tag)))
+            if token.eq_text("EXPLICIT") {
+                Ok((Self::next(iter)?, Some(tag), Some(Tagging::Explicit)))
+            } else if token.eq_text("IMPLICIT") {
+                Ok((Self::next(iter)?, Some(tag), Some(Tagging::Implicit)))
+            } else {
+                Ok((token, Some(tag), None))
+            }
         } else {
-            Ok((token, None))
+            Ok((token, None, None))
         }
     }
 
@@ -460,27 +972,69 @@ impl Model<Asn> {
             Ok(Type::Integer(Integer::try_from(iter)?))
         } else if text.eq_ignore_ascii_case("BOOLEAN") {
             Ok(Type::Boolean)
+        } else if text.eq_ignore_ascii_case("NULL") {
+            Ok(Type::Null)
+        } else if text.eq_ignore_ascii_case("REAL") {
+            Ok(Type::Real)
+        } else if text.eq_ignore_ascii_case("OBJECT") {
+            let token = Self::next(iter)?;
+            if token.eq_text("IDENTIFIER") {
+                Ok(Type::ObjectIdentifier)
+            } else {
+                Err(Error::unexpected_token(token))
+            }
+        } else if text.eq_ignore_ascii_case("RELATIVE-OID") {
+            Ok(Type::RelativeOid)
+        } else if text.eq_ignore_ascii_case("UTCTime") {
+            Ok(Type::Time(TimeType::UtcTime))
+        } else if text.eq_ignore_ascii_case("GeneralizedTime") {
+            Ok(Type::Time(TimeType::GeneralizedTime))
+        } else if text.eq_ignore_ascii_case("DATE") {
+            Ok(Type::Time(TimeType::Date))
+        } else if text.eq_ignore_ascii_case("TIME-OF-DAY") {
+            Ok(Type::Time(TimeType::TimeOfDay))
+        } else if text.eq_ignore_ascii_case("DATE-TIME") {
+            Ok(Type::Time(TimeType::DateTime))
+        } else if text.eq_ignore_ascii_case("DURATION") {
+            Ok(Type::Time(TimeType::Duration))
         } else if text.eq_ignore_ascii_case("UTF8String") {
-            Ok(Type::String(
-                Model::<Asn>::maybe_read_size(iter)?,
-                Charset::Utf8,
-            ))
+            Model::<Asn>::read_string(iter, Charset::Utf8)
         } else if text.eq_ignore_ascii_case("IA5STring") {
-            Ok(Type::String(
-                Model::<Asn>::maybe_read_size(iter)?,
-                Charset::Ia5,
-            ))
+            Model::<Asn>::read_string(iter, Charset::Ia5)
+        } else if text.eq_ignore_ascii_case("NumericString") {
+            Model::<Asn>::read_string(iter, Charset::Numeric)
+        } else if text.eq_ignore_ascii_case("PrintableString") {
+            Model::<Asn>::read_string(iter, Charset::Printable)
+        } else if text.eq_ignore_ascii_case("VisibleString")
+            || text.eq_ignore_ascii_case("ISO646String")
+        {
+            Model::<Asn>::read_string(iter, Charset::Visible)
+        } else if text.eq_ignore_ascii_case("BMPString") {
+            Model::<Asn>::read_string(iter, Charset::Bmp)
+        } else if text.eq_ignore_ascii_case("UniversalString") {
+            Model::<Asn>::read_string(iter, Charset::Universal)
         } else if text.eq_ignore_ascii_case("OCTET") {
             let token = Self::next(iter)?;
             if token.text().map_or(false, |t| t.eq("STRING")) {
-                Ok(Type::OctetString(Model::<Asn>::maybe_read_size(iter)?))
+                match Self::maybe_read_contents_constraint(iter)? {
+                    Some(contained) => Ok(Type::Containing(
+                        Box::new(contained),
+                        Container::OctetString,
+                    )),
+                    None => Ok(Type::OctetString(Model::<Asn>::maybe_read_size(iter)?)),
+                }
             } else {
                 Err(Error::unexpected_token(token))
             }
         } else if text.eq_ignore_ascii_case("BIT") {
             let token = Self::next(iter)?;
             if token.text().map_or(false, |t| t.eq("STRING")) {
-                Ok(Type::BitString(BitString::try_from(iter)?))
+                match Self::maybe_read_contents_constraint(iter)? {
+                    Some(contained) => {
+                        Ok(Type::Containing(Box::new(contained), Container::BitString))
+                    }
+                    None => Ok(Type::BitString(BitString::try_from(iter)?)),
+                }
             } else {
                 Err(Error::unexpected_token(token))
             }
@@ -490,59 +1044,46 @@ impl Model<Asn> {
             Ok(Type::Enumerated(Enumerated::try_from(iter)?))
         } else if text.eq_ignore_ascii_case("SEQUENCE") {
             Ok(Self::read_sequence_or_sequence_of(iter)?)
+        } else if matches!(iter.peek(), Some(t) if t.eq_separator('.')) {
+            Ok(Type::ObjectClassField(Self::read_object_class_field(
+                iter, text,
+            )?))
         } else {
-            Ok(Type::TypeReference(text, None))
+            let reference = Type::TypeReference(text, None);
+            Ok(match Self::maybe_read_inner_type_constraints(iter)? {
+                Some(constraints) => Type::WithComponents(Box::new(reference), constraints),
+                None => reference,
+            })
         }
     }
 
-    fn read_number_range(
-        iter: &mut Peekable<IntoIter<Token>>,
-    ) -> Result<Range<Option<i64>>, Error> {
-        if Self::peek(iter)?.eq_separator('(') {
-            Self::next_separator_ignore_case(iter, '(')?;
-            let start = Self::next(iter)?;
-            Self::next_separator_ignore_case(iter, '.')?;
-            Self::next_separator_ignore_case(iter, '.')?;
-            let end = Self::next(iter)?;
-            let extensible = if Self::peek(iter)?.eq_separator(',') {
-                let _ = Self::next_separator_ignore_case(iter, ',')?;
-                Self::next_separator_ignore_case(iter, '.')?;
-                Self::next_separator_ignore_case(iter, '.')?;
-                Self::next_separator_ignore_case(iter, '.')?;
-                true
-            } else {
-                false
-            };
-            Self::next_separator_ignore_case(iter, ')')?;
-            let start = start
-                .text()
-                .filter(|txt| !txt.eq_ignore_ascii_case("MIN"))
-                .map(|t| t.parse::<i64>())
-                .transpose()
-                .map_err(|_| Error::invalid_range_value(start))?;
-
-            let end = end
-                .text()
-                .filter(|txt| !txt.eq_ignore_ascii_case("MAX"))
-                .map(|t| t.parse::<i64>())
-                .transpose()
-                .map_err(|_| Error::invalid_range_value(end))?;
-
-            match (start, end) {
-                (Some(0), None) => Ok(Range(None, None, extensible)),
-                (start, end) => Ok(Range(start, end, extensible)),
+    /// Reads the bound of a range, which is either the given keyword (`MIN` or `MAX`), a number
+    /// or a reference to a value
+    fn read_bound<T: FromStr>(token: Token, keyword: &str) -> Result<Option<LitOrRef<T>>, Error> {
+        match token.text() {
+            Some(text) if text.eq_ignore_ascii_case(keyword) => Ok(None),
+            Some(text) if text.starts_with(|c: char| c.is_ascii_lowercase()) => {
+                Ok(Some(LitOrRef::Ref(text.to_string())))
             }
-        } else {
-            Ok(Range(None, None, false))
+            Some(text) => match text.parse::<T>() {
+                Ok(value) => Ok(Some(LitOrRef::Lit(value))),
+                Err(_) => Err(Error::invalid_range_value(token)),
+            },
+            None => Err(Error::invalid_range_value(token)),
         }
     }
 
-    fn maybe_read_size(iter: &mut Peekable<IntoIter<Token>>) -> Result<Size, Error> {
+    fn maybe_read_size(
+        iter: &mut Peekable<IntoIter<Token>>,
+    ) -> Result<Size<LitOrRef<usize>>, Error> {
         if Self::peek(iter)?.eq_separator('(') {
-            Self::next_separator_ignore_case(iter, '(')?;
-            let result = Self::read_size(iter)?;
+            let token = Self::next(iter)?;
+            let mut extensible = false;
+            let (set, ext) = Self::read_element_set_spec(iter, &mut |iter| {
+                Self::read_size_element(iter, &mut extensible)
+            })?;
             Self::next_separator_ignore_case(iter, ')')?;
-            Ok(result)
+            Self::size_from_element_set(set, extensible || ext, token)
         } else if Self::peek(iter)?.eq_text_ignore_ascii_case("SIZE") {
             Self::read_size(iter)
         } else {
@@ -550,95 +1091,179 @@ impl Model<Asn> {
         }
     }
 
-    fn read_size(iter: &mut Peekable<IntoIter<Token>>) -> Result<Size, Error> {
-        let size_token = Self::next(iter)?;
-        if size_token.eq_text_ignore_ascii_case("SIZE") {
-            Self::next_separator_ignore_case(iter, '(')?;
-            let start = Self::next(iter)?;
-            let start = start
-                .text()
-                .filter(|txt| !txt.eq_ignore_ascii_case("MIN"))
-                .map(|t| t.parse::<usize>())
-                .transpose()
-                .map_err(|_| Error::invalid_range_value(start))?;
-
-            if !Self::peek(iter)?.eq_separator('.') {
-                match Self::next(iter)? {
-                    t if t.eq_separator(')') => Ok(Size::Fix(start.unwrap_or_default(), false)),
-                    t if t.eq_separator(',') => {
-                        Self::next_separator_ignore_case(iter, '.')?;
-                        Self::next_separator_ignore_case(iter, '.')?;
-                        Self::next_separator_ignore_case(iter, '.')?;
-                        Ok(Size::Fix(start.unwrap_or_default(), true))
-                    }
-                    t => Err(Error::unexpected_token(t)),
-                }
-            } else {
-                Self::next_separator_ignore_case(iter, '.')?;
-                Self::next_separator_ignore_case(iter, '.')?;
-                let end = Self::next(iter)?;
-                let end = end
-                    .text()
-                    .filter(|txt| !txt.eq_ignore_ascii_case("MAX"))
-                    .map(|t| t.parse::<usize>())
-                    .transpose()
-                    .map_err(|_| Error::invalid_range_value(end))?;
-
-                const MAX: usize = i64::MAX as usize;
-                let any = matches!(
-                    (start, end),
-                    (None, None) | (Some(0), None) | (None, Some(MAX))
-                );
-
-                if any {
-                    Self::next_separator_ignore_case(iter, ')')?;
-                    Ok(Size::Any)
-                } else {
-                    let start = start.unwrap_or_default();
-                    let end = end.unwrap_or_else(|| i64::MAX as usize);
-                    let extensible = if Self::peek(iter)?.eq_separator(',') {
-                        let _ = Self::next_separator_ignore_case(iter, ',')?;
-                        Self::next_separator_ignore_case(iter, '.')?;
-                        Self::next_separator_ignore_case(iter, '.')?;
-                        Self::next_separator_ignore_case(iter, '.')?;
-                        true
-                    } else {
-                        false
-                    };
-                    Self::next_separator_ignore_case(iter, ')')?;
-                    if start == end {
-                        Ok(Size::Fix(start, extensible))
+    /// Reads the optional constraints of a restricted character string type, which are a
+    /// `SIZE` and - through `FROM` - a permitted alphabet constraint, either one after another
+    /// `(SIZE(1..8)) (FROM("0".."9"))` or as intersection `(SIZE(1..8) ^ FROM("0".."9"))`
+    fn read_string(iter: &mut Peekable<IntoIter<Token>>, charset: Charset) -> Result<Type, Error> {
+        let mut size = Size::Any;
+        let mut alphabet = None;
+        loop {
+            if Self::peek(iter)?.eq_text_ignore_ascii_case("SIZE") {
+                size = Self::read_size(iter)?;
+            } else if Self::peek(iter)?.eq_separator('(') {
+                let token = Self::next(iter)?;
+                let mut extensible = false;
+                let mut sized = false;
+                let (set, ext) = Self::read_element_set_spec(iter, &mut |iter: &mut Peekable<
+                    IntoIter<Token>,
+                >| {
+                    if Self::peek(iter)?.eq_text_ignore_ascii_case("FROM") {
+                        let _ = Self::next(iter)?;
+                        alphabet = Self::read_permitted_alphabet(iter)?
+                            .map(|alphabet| alphabet.intersect(charset));
+                        // a permitted alphabet does not constrain the size
+                        Ok(ElementSet::All)
                     } else {
-                        Ok(Size::Range(start, end, extensible))
+                        sized = true;
+                        Self::read_size_element(iter, &mut extensible)
                     }
+                })?;
+                Self::next_separator_ignore_case(iter, ')')?;
+                if sized {
+                    size = Self::size_from_element_set(set, extensible || ext, token)?;
                 }
+            } else {
+                return Ok(Type::String(size, charset, alphabet));
             }
-        } else {
-            Err(Error::unexpected_token(size_token))
         }
     }
 
-    fn constant_i64_parser(token: Token) -> Result<i64, Error> {
-        let parsed = token.text().and_then(|s| s.parse().ok());
-        parsed.ok_or_else(|| Error::invalid_value_for_constant(token))
-    }
-
-    fn constant_u64_parser(token: Token) -> Result<u64, Error> {
-        let parsed = token.text().and_then(|s| s.parse().ok());
-        parsed.ok_or_else(|| Error::invalid_value_for_constant(token))
-    }
-
-    fn maybe_read_constants<T, F: Fn(Token) -> Result<T, Error>>(
+    /// ITU-T X.680 | ISO/IEC 8824-1:2015, chapter 51.7, reads the strings (`"ABC"`) and ranges of
+    /// single characters (`"0".."9"`) of a `FROM` constraint. Extensible permitted alphabets are
+    /// not PER-visible and therefore ignored.
+    fn read_permitted_alphabet(
         iter: &mut Peekable<IntoIter<Token>>,
-        parser: F,
-    ) -> Result<Vec<(String, T)>, Error> {
-        let mut constants = Vec::default();
-        if Self::peek(iter)?.eq_separator('{') {
-            Self::next_separator_ignore_case(iter, '{')?;
-            loop {
-                constants.push(Self::read_constant(iter, |token| parser(token))?);
-                if !Self::peek(iter)?.eq_separator(',') {
-                    break;
+    ) -> Result<Option<PermittedAlphabet>, Error> {
+        let read_string = |iter: &mut Peekable<IntoIter<Token>>| {
+            let token = Self::next(iter)?;
+            match LiteralValue::try_from(&token)? {
+                LiteralValue::String(string) => Ok((token, string)),
+                _ => Err(Error::invalid_permitted_alphabet(token)),
+            }
+        };
+        let single_char = |(token, string): (Token, String)| {
+            let mut chars = string.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => Ok(u32::from(c)),
+                _ => Err(Error::invalid_permitted_alphabet(token)),
+            }
+        };
+
+        Self::next_separator_ignore_case(iter, '(')?;
+        let mut ranges = Vec::new();
+        loop {
+            let start = read_string(iter)?;
+            if Self::peek(iter)?.eq_symbol("..") {
+                Self::next_symbol(iter, "..")?;
+                let end = read_string(iter)?;
+                ranges.push((single_char(start)?, single_char(end)?));
+            } else {
+                ranges.extend(start.1.chars().map(|c| (u32::from(c), u32::from(c))));
+            }
+            let token = Self::peek(iter)?;
+            if token.eq_separator('|') || token.eq_text_ignore_ascii_case("UNION") {
+                let _ = Self::next(iter)?;
+            } else {
+                break;
+            }
+        }
+        let extensible = if Self::peek(iter)?.eq_separator(',') {
+            let _ = Self::next_separator_ignore_case(iter, ',')?;
+            Self::next_symbol(iter, "...")?;
+            true
+        } else {
+            false
+        };
+        Self::next_separator_ignore_case(iter, ')')?;
+        Ok(Some(PermittedAlphabet::new(ranges)).filter(|_| !extensible))
+    }
+
+    fn read_size(iter: &mut Peekable<IntoIter<Token>>) -> Result<Size<LitOrRef<usize>>, Error> {
+        let token = Self::peek(iter)?.clone();
+        let mut extensible = false;
+        let set = Self::read_size_element(iter, &mut extensible)?;
+        Self::size_from_element_set(set, extensible, token)
+    }
+
+    /// Reads the sizes of a `SIZE(4 | 8)` constraint and whether it is extensible
+    fn read_size_element(
+        iter: &mut Peekable<IntoIter<Token>>,
+        extensible: &mut bool,
+    ) -> Result<ElementSet<LitOrRef<usize>>, Error> {
+        let size_token = Self::next(iter)?;
+        if size_token.eq_text_ignore_ascii_case("SIZE") {
+            Self::next_separator_ignore_case(iter, '(')?;
+            let (set, ext) = Self::read_element_set_spec(iter, &mut Self::read_value_element)?;
+            Self::next_separator_ignore_case(iter, ')')?;
+            *extensible |= ext;
+            Ok(set)
+        } else {
+            Err(Error::unexpected_token(size_token))
+        }
+    }
+
+    /// The effective size constraint of the given sizes, which must not refer to values unless
+    /// they are a single value or value range
+    fn size_from_element_set(
+        set: ElementSet<LitOrRef<usize>>,
+        extensible: bool,
+        token: Token,
+    ) -> Result<Size<LitOrRef<usize>>, Error> {
+        let (start, end) = match set {
+            ElementSet::Value(size) => return Ok(Size::Fix(size, extensible)),
+            ElementSet::Range(start, end) => (start, end),
+            set => {
+                let (start, end) = set
+                    .try_map(&|size| size.lit().copied().ok_or(()))
+                    .map_err(|_| Error::invalid_range_value(token))?
+                    .effective_range();
+                (start.map(LitOrRef::Lit), end.map(LitOrRef::Lit))
+            }
+        };
+
+        const MAX: usize = i64::MAX as usize;
+        let any = matches!(
+            (
+                start.as_ref().map(LitOrRef::lit),
+                end.as_ref().map(LitOrRef::lit)
+            ),
+            (None, None) | (Some(Some(0)), None) | (None, Some(Some(&MAX)))
+        );
+
+        if any {
+            Ok(Size::Any)
+        } else {
+            let start = start.unwrap_or(LitOrRef::Lit(0));
+            let end = end.unwrap_or(LitOrRef::Lit(MAX));
+            if start == end {
+                Ok(Size::Fix(start, extensible))
+            } else {
+                Ok(Size::Range(start, end, extensible))
+            }
+        }
+    }
+
+    fn constant_i64_parser(token: Token) -> Result<i64, Error> {
+        let parsed = token.text().and_then(|s| s.parse().ok());
+        parsed.ok_or_else(|| Error::invalid_value_for_constant(token))
+    }
+
+    fn constant_u64_parser(token: Token) -> Result<u64, Error> {
+        let parsed = token.text().and_then(|s| s.parse().ok());
+        parsed.ok_or_else(|| Error::invalid_value_for_constant(token))
+    }
+
+    fn maybe_read_constants<T, F: Fn(Token) -> Result<T, Error>>(
+        iter: &mut Peekable<IntoIter<Token>>,
+        parser: F,
+    ) -> Result<Vec<(String, T)>, Error> {
+        let mut constants = Vec::default();
+        if Self::peek(iter)?.eq_separator('{') {
+            Self::next_separator_ignore_case(iter, '{')?;
+            loop {
+                constants.push(Self::read_constant(iter, |token| parser(token))?);
+                if !Self::peek(iter)?.eq_separator(',') {
+                    break;
                 } else {
                     let _ = Self::next(iter)?;
                 }
@@ -688,19 +1313,7 @@ impl Model<Asn> {
     }
 
     fn read_field(iter: &mut Peekable<IntoIter<Token>>) -> Result<(Field<Asn>, bool), Error> {
-        let name = Self::next_text(iter)?;
-        let (token, tag) = Self::next_with_opt_tag(iter)?;
-        let mut field = Field {
-            name,
-            role: Self::read_role_given_text(iter, token.into_text_or_else(Error::no_text)?)?
-                .opt_tagged(tag),
-        };
-        let mut token = Self::next(iter)?;
-        if let Some(_optional_flag) = token.text().map(|s| s.eq_ignore_ascii_case("OPTIONAL")) {
-            field.role.optional();
-            token = Self::next(iter)?;
-        }
-
+        let (field, token) = Self::read_field_until_separator(iter)?;
         let (continues, ends) = token
             .separator()
             .map_or((false, false), |s| (s == ',', s == '}'));
@@ -712,6 +1325,40 @@ impl Model<Asn> {
         }
     }
 
+    /// Reads a field and returns it together with the token that follows it
+    fn read_field_until_separator(
+        iter: &mut Peekable<IntoIter<Token>>,
+    ) -> Result<(Field<Asn>, Token), Error> {
+        let name = Self::next_text(iter)?;
+        let (token, tag, tagging) = Self::next_with_opt_tag(iter)?;
+        let mut field = Field {
+            name,
+            role: Self::read_role_given_text(iter, token.into_text_or_else(Error::no_text)?)?
+                .opt_tagged(tag)
+                .with_tagging(tagging),
+        };
+        let mut token = Self::next(iter)?;
+        if token.eq_text_ignore_ascii_case("OPTIONAL") {
+            field.role.optional();
+            token = Self::next(iter)?;
+        } else if token.eq_text_ignore_ascii_case("DEFAULT") {
+            let value_token = Self::next(iter)?;
+            let value = LiteralValue::try_from(&value_token)?;
+            let representable = match (&value, &field.role.r#type) {
+                (LiteralValue::Integer(value), Type::Integer(integer)) => {
+                    integer.can_represent(*value)
+                }
+                _ => true,
+            };
+            if !value.is_compatible_with(&field.role.r#type) || !representable {
+                return Err(Error::invalid_literal_value(value_token));
+            }
+            field.role.default = Some(value);
+            token = Self::next(iter)?;
+        }
+        Ok((field, token))
+    }
+
     fn next(iter: &mut Peekable<IntoIter<Token>>) -> Result<Token, Error> {
         iter.next().ok_or_else(Error::unexpected_end_of_stream)
     }
@@ -737,6 +1384,18 @@ impl Model<Asn> {
         Err(Error::expected_separator(separator, token))
     }
 
+    fn next_symbol(
+        iter: &mut Peekable<IntoIter<Token>>,
+        symbol: &'static str,
+    ) -> Result<(), Error> {
+        let token = Self::next(iter)?;
+        if token.eq_symbol(symbol) {
+            Ok(())
+        } else {
+            Err(Error::expected_symbol(symbol, token))
+        }
+    }
+
     pub fn make_names_nice(&mut self) {
         Self::make_name_nice(&mut self.name);
         for import in &mut self.imports {
@@ -754,12 +1413,33 @@ impl Model<Asn> {
         }
     }
 
+    /// Converts this model to rust, resolving references to values in constraints.
+    ///
+    /// # Panics
+    ///
+    /// If a referenced value or a type referenced by `COMPONENTS OF` is neither assigned in this
+    /// module nor imported from a module in the scope, or if [`Model::try_to_rust`] fails
     pub fn to_rust(&self) -> Model<rust::Rust> {
         let scope: &[&Self] = &[];
-        Model::convert_asn_to_rust(self, scope)
+        self.to_rust_with_scope(scope)
     }
 
+    /// See [`Model::to_rust`]
     pub fn to_rust_with_scope(&self, scope: &[&Self]) -> Model<rust::Rust> {
+        self.try_to_rust_with_scope(scope)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Converts this model to rust like [`Model::to_rust`], but returns an error if a class,
+    /// object set or component relation constraint of an information object class field cannot
+    /// be resolved
+    pub fn try_to_rust(&self) -> Result<Model<rust::Rust>, Error> {
+        let scope: &[&Self] = &[];
+        Model::convert_asn_to_rust(self, scope)
+    }
+
+    /// See [`Model::try_to_rust`]
+    pub fn try_to_rust_with_scope(&self, scope: &[&Self]) -> Result<Model<rust::Rust>, Error> {
         Model::convert_asn_to_rust(self, scope)
     }
 }
@@ -776,7 +1456,7 @@ pub struct TagResolver<'a> {
     scope: &'a [&'a Model<Asn>],
 }
 
-impl TagResolver<'_> {
+impl<'a> TagResolver<'a> {
     pub fn resolve_default(ty: &Type) -> Option<Tag> {
         let model = Model::<Asn>::default();
         TagResolver {
@@ -789,6 +1469,16 @@ impl TagResolver<'_> {
     /// ITU-T X.680 | ISO/IEC 8824-1, 8.6
     /// ITU-T X.680 | ISO/IEC 8824-1, 41, table 8
     pub fn resolve_tag(&self, ty: &str) -> Option<Tag> {
+        self.resolve_tag_within(ty, &[])
+    }
+
+    /// Resolves the tag of the given type, while the tags of the given types are being resolved
+    /// already. This only happens for a recursive type, whose tag cannot depend on itself.
+    fn resolve_tag_within(&self, ty: &str, within: &[&str]) -> Option<Tag> {
+        if within.contains(&ty) {
+            return None;
+        }
+        let within = within.iter().copied().chain(Some(ty)).collect::<Vec<_>>();
         self.model
             .imports
             .iter()
@@ -800,11 +1490,14 @@ impl TagResolver<'_> {
                     model,
                     scope: self.scope,
                 }
-                .resolve_tag(ty)
+                .resolve_tag_within(ty, &within[..within.len() - 1])
             })
             .or_else(|| {
                 self.model.definitions.iter().find(|d| d.0.eq(ty)).and_then(
-                    |Definition(_name, asn)| asn.tag.or_else(|| self.resolve_type_tag(&asn.r#type)),
+                    |Definition(_name, asn)| {
+                        asn.tag
+                            .or_else(|| self.resolve_type_tag_within(&asn.r#type, &within))
+                    },
                 )
             })
     }
@@ -820,15 +1513,27 @@ impl TagResolver<'_> {
     /// ITU-T X.680 | ISO/IEC 8824-1, 8.6
     /// ITU-T X.680 | ISO/IEC 8824-1, 41, table 8
     pub fn resolve_type_tag(&self, ty: &Type) -> Option<Tag> {
+        self.resolve_type_tag_within(ty, &[])
+    }
+
+    /// See [`TagResolver::resolve_tag_within`]
+    fn resolve_type_tag_within(&self, ty: &Type, within: &[&str]) -> Option<Tag> {
         match ty {
             Type::Boolean => Some(Tag::DEFAULT_BOOLEAN),
             Type::Integer(_) => Some(Tag::DEFAULT_INTEGER),
             Type::BitString(_) => Some(Tag::DEFAULT_BIT_STRING),
             Type::OctetString(_) => Some(Tag::DEFAULT_OCTET_STRING),
+            Type::Null => Some(Tag::DEFAULT_NULL),
+            Type::Real => Some(Tag::DEFAULT_REAL),
+            Type::ObjectIdentifier => Some(Tag::DEFAULT_OBJECT_IDENTIFIER),
+            Type::RelativeOid => Some(Tag::DEFAULT_RELATIVE_OID),
             Type::Enumerated(_) => Some(Tag::DEFAULT_ENUMERATED),
-            Type::String(_, Charset::Utf8) => Some(Tag::DEFAULT_UTF8_STRING),
-            Type::String(_, Charset::Ia5) => Some(Tag::DEFAULT_IA5_STRING),
-            Type::Optional(inner) => self.resolve_type_tag(&**inner),
+            Type::String(_, charset, _) => Some(charset.default_tag()),
+            Type::Time(time) => Some(time.default_tag()),
+            Type::Containing(_, container) => Some(container.default_tag()),
+            Type::Optional(inner) | Type::WithComponents(inner, _) => {
+                self.resolve_type_tag_within(&**inner, within)
+            }
             Type::Sequence(_) => Some(Tag::DEFAULT_SEQUENCE),
             Type::SequenceOf(_, _) => Some(Tag::DEFAULT_SEQUENCE_OF),
             Type::Set(_) => Some(Tag::DEFAULT_SET),
@@ -842,7 +1547,19 @@ impl TagResolver<'_> {
                             .map(|extension_after| extension_after + 1)
                             .unwrap_or(choice.variants.len()),
                     )
-                    .map(|v| v.tag().or_else(|| self.resolve_type_tag(v.r#type())))
+                    // an alternative that refers back to a CHOICE whose tag is being resolved
+                    // adds no tags of its own
+                    .filter(|v| {
+                        v.tag().is_some()
+                            || !matches!(
+                                v.r#type(),
+                                Type::TypeReference(name, None) if within.contains(&name.as_str())
+                            )
+                    })
+                    .map(|v| {
+                        v.tag()
+                            .or_else(|| self.resolve_type_tag_within(v.r#type(), within))
+                    })
                     .collect::<Option<Vec<Tag>>>()?;
                 tags.sort();
                 if cfg!(feature = "debug-proc-macro") {
@@ -851,13 +1568,90 @@ impl TagResolver<'_> {
                 tags.into_iter().next()
             }
             Type::TypeReference(inner, tag) => {
-                let tag = tag.clone().or_else(|| self.resolve_tag(inner.as_str()));
+                let tag = tag
+                    .clone()
+                    .or_else(|| self.resolve_tag_within(inner.as_str(), within));
                 if cfg!(feature = "debug-proc-macro") {
                     println!("resolved :: {}::Tag = {:?}", inner, tag);
                 }
                 tag
             }
+            Type::ObjectClassField(field) => self
+                .resolve(&field.class, |model| {
+                    model.classes.iter().find(|c| c.0.eq(&field.class))
+                })
+                .and_then(|class| class.1.field(&field.field))
+                .and_then(|spec| match &spec.kind {
+                    information_object::FieldSpecKind::Value(r#type) => {
+                        self.resolve_type_tag(r#type)
+                    }
+                    // ITU-T X.680 | ISO/IEC 8824-1, 8.6: the tag of an open type is indeterminate
+                    information_object::FieldSpecKind::Type => None,
+                }),
+            Type::OpenType(_) => None,
+        }
+    }
+
+    /// ITU-T X.680 | ISO/IEC 8824-1, 25.3 and 29.3, resolves the tags of the components of a
+    /// `SEQUENCE`, `SET` or `CHOICE`. In a module with `AUTOMATIC TAGS`, the components are
+    /// tagged by their position, unless any of them is tagged already. Otherwise, untagged
+    /// components have the tag of their type.
+    pub fn resolve_component_tags(&self, components: &[(Option<Tag>, &Type)]) -> Vec<Option<Tag>> {
+        if self.is_tagged_automatically(components) {
+            (0..components.len())
+                .map(|index| Some(Tag::ContextSpecific(index)))
+                .collect()
+        } else {
+            components
+                .iter()
+                .map(|(tag, r#type)| tag.or_else(|| self.resolve_type_tag(r#type)))
+                .collect()
+        }
+    }
+
+    /// ITU-T X.680 | ISO/IEC 8824-1, 25.3 and 29.3, whether the given components of a
+    /// `SEQUENCE`, `SET` or `CHOICE` are tagged by their position
+    pub fn is_tagged_automatically(&self, components: &[(Option<Tag>, &Type)]) -> bool {
+        self.model.tag_default == TagDefault::Automatic
+            && components.iter().all(|(tag, _)| tag.is_none())
+    }
+
+    /// ITU-T X.680 | ISO/IEC 8824-1, 31.2.7, whether the given tag is explicit. Unless the tag is
+    /// followed by `EXPLICIT` or `IMPLICIT`, this depends on the tagging of the module. A
+    /// tagged `CHOICE` is explicitly tagged by the encodings regardless.
+    pub fn is_explicit(&self, tag: Option<Tag>, tagging: Option<Tagging>) -> bool {
+        tag.is_some()
+            && match tagging {
+                Some(tagging) => tagging == Tagging::Explicit,
+                None => self.model.tag_default == TagDefault::Explicit,
+            }
+    }
+
+    /// Looks up an item with the given name in the module or - if imported - in the module it
+    /// is imported from
+    fn resolve<T>(
+        &self,
+        name: &str,
+        find: impl Fn(&'a Model<Asn>) -> Option<&'a T>,
+    ) -> Option<&'a T> {
+        self.resolve_model(name).and_then(find)
+    }
+
+    /// The module that the item with the given name is assigned in, if it is not imported from
+    /// a module outside of the scope
+    fn resolve_model(&self, name: &str) -> Option<&'a Model<Asn>> {
+        let mut model = self.model;
+        while let Some(import) = model
+            .imports
+            .iter()
+            .find(|import| import.what.iter().any(|what| what.eq(name)))
+        {
+            model = self
+                .scope
+                .iter()
+                .find(|model| model.name.eq(&import.from))?;
         }
+        Some(model)
     }
 }
 
@@ -874,13 +1668,73 @@ impl Context<'_> {
     pub fn resolver(&self) -> &TagResolver<'_> {
         &self.resolver
     }
+
+    /// The tags of the given components of a `SEQUENCE`, `SET` or `CHOICE` in the rust model.
+    /// Automatic tags are assigned when the code is generated instead, because the tag of a
+    /// `CHOICE` is resolved from the tags of its alternatives.
+    pub fn component_tags(&self, components: &[(Option<Tag>, &Type)]) -> Vec<Option<Tag>> {
+        if self.resolver.is_tagged_automatically(components) {
+            vec![None; components.len()]
+        } else {
+            self.resolver.resolve_component_tags(components)
+        }
+    }
+
+    /// ITU-T X.680 | ISO/IEC 8824-1, 16.2
+    ///
+    /// Looks up the value of the given value reference in the module or - if imported - in the
+    /// module it is imported from
+    pub fn resolve_value(&self, name: &str) -> Option<&LiteralValue> {
+        self.resolver.resolve(name, |model| {
+            model
+                .value_references
+                .iter()
+                .find(|value| value.name.eq(name))
+                .map(|value| &value.value)
+        })
+    }
+
+    /// ITU-T X.681 | ISO/IEC 8824-2, 9.1
+    pub fn resolve_class(&self, name: &str) -> Option<&ObjectClass> {
+        self.resolver
+            .resolve(name, |model| Self::find(&model.classes, name))
+    }
+
+    /// ITU-T X.681 | ISO/IEC 8824-2, 11.1
+    pub fn resolve_object(&self, name: &str) -> Option<&Object> {
+        self.resolver
+            .resolve(name, |model| Self::find(&model.objects, name))
+    }
+
+    /// ITU-T X.681 | ISO/IEC 8824-2, 12.1
+    pub fn resolve_object_set(&self, name: &str) -> Option<&ObjectSet> {
+        self.resolver
+            .resolve(name, |model| Self::find(&model.object_sets, name))
+    }
+
+    fn find<'a, T>(definitions: &'a [Definition<T>], name: &str) -> Option<&'a T> {
+        definitions
+            .iter()
+            .find(|definition| definition.0.eq(name))
+            .map(|definition| &definition.1)
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialOrd, PartialEq)]
-pub enum Size {
+pub enum Size<T = usize> {
     Any,
-    Fix(usize, bool),
-    Range(usize, usize, bool),
+    Fix(T, bool),
+    Range(T, T, bool),
+}
+
+impl<T> Size<T> {
+    pub fn map<U>(self, f: impl Fn(T) -> U) -> Size<U> {
+        match self {
+            Size::Any => Size::Any,
+            Size::Fix(size, extensible) => Size::Fix(f(size), extensible),
+            Size::Range(min, max, extensible) => Size::Range(f(min), f(max), extensible),
+        }
+    }
 }
 
 impl Size {
@@ -899,7 +1753,9 @@ impl Size {
             Size::Range(_, max, _) => Some(*max),
         }
     }
+}
 
+impl<T: Display> Size<T> {
     pub fn extensible(&self) -> bool {
         match self {
             Size::Any => false,
@@ -909,16 +1765,17 @@ impl Size {
     }
 
     pub fn to_constraint_string(&self) -> Option<String> {
-        if Size::Any != *self {
-            Some(format!(
-                "{}..{}{}",
-                self.min().unwrap_or_default(),
-                self.max().unwrap_or_else(|| i64::max_value() as usize),
-                if self.extensible() { ",..." } else { "" }
-            ))
-        } else {
-            None
-        }
+        let (min, max) = match self {
+            Size::Any => return None,
+            Size::Fix(size, _) => (size, size),
+            Size::Range(min, max, _) => (min, max),
+        };
+        Some(format!(
+            "{}..{}{}",
+            min,
+            max,
+            if self.extensible() { ",..." } else { "" }
+        ))
     }
 }
 
@@ -927,6 +1784,175 @@ impl Size {
 pub enum Charset {
     Utf8,
     Ia5,
+    Numeric,
+    Printable,
+    Visible,
+    Bmp,
+    Universal,
+}
+
+impl Charset {
+    /// ITU-T X.680 | ISO/IEC 8824-1, 41, table 8
+    pub const fn default_tag(self) -> Tag {
+        match self {
+            Charset::Utf8 => Tag::DEFAULT_UTF8_STRING,
+            Charset::Ia5 => Tag::DEFAULT_IA5_STRING,
+            Charset::Numeric => Tag::DEFAULT_NUMERIC_STRING,
+            Charset::Printable => Tag::DEFAULT_PRINTABLE_STRING,
+            Charset::Visible => Tag::DEFAULT_VISIBLE_STRING,
+            Charset::Bmp => Tag::DEFAULT_BMP_STRING,
+            Charset::Universal => Tag::DEFAULT_UNIVERSAL_STRING,
+        }
+    }
+
+    /// The values of the characters of the character set as sorted and inclusive ranges, see
+    /// ITU-T X.680 | ISO/IEC 8824-1, 41.2 to 41.4 (tables 9 and 10)
+    pub const fn alphabet(self) -> &'static [(u32, u32)] {
+        match self {
+            Charset::Utf8 => &[(0x00, 0x10_FF_FF)],
+            Charset::Ia5 => &[(0x00, 0x7F)],
+            Charset::Numeric => &[(0x20, 0x20), (0x30, 0x39)],
+            // space ' ( ) + , - . / 0-9 : = ? A-Z a-z
+            Charset::Printable => &[
+                (0x20, 0x20),
+                (0x27, 0x29),
+                (0x2B, 0x3A),
+                (0x3D, 0x3D),
+                (0x3F, 0x3F),
+                (0x41, 0x5A),
+                (0x61, 0x7A),
+            ],
+            Charset::Visible => &[(0x20, 0x7E)],
+            Charset::Bmp => &[(0x00, 0xFF_FF)],
+            Charset::Universal => &[(0x00, 0xFF_FF_FF_FF)],
+        }
+    }
+
+    /// Whether the character is part of the character set
+    pub fn contains(self, character: char) -> bool {
+        let value = u32::from(character);
+        self.alphabet()
+            .iter()
+            .any(|(min, max)| (*min..=*max).contains(&value))
+    }
+}
+
+/// The useful time types `UTCTime` and `GeneralizedTime` (ITU-T X.680 | ISO/IEC 8824-1, 46 and
+/// 47) and the subtypes of `TIME` that are defined as types of their own (ITU-T X.680 | ISO/IEC
+/// 8824-1, 38.4.1)
+#[derive(Debug, Clone, Copy, PartialOrd, PartialEq, EnumString)]
+#[strum(serialize_all = "lowercase")]
+pub enum TimeType {
+    UtcTime,
+    GeneralizedTime,
+    Date,
+    TimeOfDay,
+    DateTime,
+    Duration,
+}
+
+impl TimeType {
+    /// ITU-T X.680 | ISO/IEC 8824-1, 41, table 8
+    pub const fn default_tag(self) -> Tag {
+        match self {
+            TimeType::UtcTime => Tag::DEFAULT_UTC_TIME,
+            TimeType::GeneralizedTime => Tag::DEFAULT_GENERALIZED_TIME,
+            TimeType::Date => Tag::DEFAULT_DATE,
+            TimeType::TimeOfDay => Tag::DEFAULT_TIME_OF_DAY,
+            TimeType::DateTime => Tag::DEFAULT_DATE_TIME,
+            TimeType::Duration => Tag::DEFAULT_DURATION,
+        }
+    }
+
+    /// The name of the type in ASN.1 notation
+    pub const fn name(self) -> &'static str {
+        match self {
+            TimeType::UtcTime => "UTCTime",
+            TimeType::GeneralizedTime => "GeneralizedTime",
+            TimeType::Date => "DATE",
+            TimeType::TimeOfDay => "TIME-OF-DAY",
+            TimeType::DateTime => "DATE-TIME",
+            TimeType::Duration => "DURATION",
+        }
+    }
+}
+
+/// The string type that holds the encoding of a contained value, see ITU-T X.680 | ISO/IEC
+/// 8824-1:2015, chapter 51.10
+#[derive(Debug, Clone, Copy, PartialOrd, PartialEq, EnumString, Display)]
+#[strum(serialize_all = "snake_case")]
+pub enum Container {
+    OctetString,
+    BitString,
+}
+
+impl Container {
+    /// ITU-T X.680 | ISO/IEC 8824-1, 41, table 8
+    pub const fn default_tag(self) -> Tag {
+        match self {
+            Container::OctetString => Tag::DEFAULT_OCTET_STRING,
+            Container::BitString => Tag::DEFAULT_BIT_STRING,
+        }
+    }
+}
+
+/// ITU-T X.680 | ISO/IEC 8824-1, 51.7, the characters a restricted character string is limited to
+/// through a `FROM` constraint, as sorted and non-overlapping inclusive ranges of character values
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub struct PermittedAlphabet(Vec<(u32, u32)>);
+
+impl PermittedAlphabet {
+    pub fn new<I: IntoIterator<Item = (u32, u32)>>(ranges: I) -> Self {
+        let mut ranges = ranges
+            .into_iter()
+            .map(|(a, b)| (a.min(b), a.max(b)))
+            .collect::<Vec<_>>();
+        ranges.sort_unstable();
+        let mut merged: Vec<(u32, u32)> = Vec::with_capacity(ranges.len());
+        for (min, max) in ranges {
+            match merged.last_mut() {
+                Some((_, last)) if min <= last.saturating_add(1) => *last = (*last).max(max),
+                _ => merged.push((min, max)),
+            }
+        }
+        Self(merged)
+    }
+
+    pub fn ranges(&self) -> &[(u32, u32)] {
+        &self.0[..]
+    }
+
+    /// Removes all characters that are not part of the given charset
+    pub fn intersect(&self, charset: Charset) -> Self {
+        Self::new(self.0.iter().flat_map(|(min, max)| {
+            charset
+                .alphabet()
+                .iter()
+                .filter(move |(c_min, c_max)| c_min <= max && min <= c_max)
+                .map(move |(c_min, c_max)| (*min.max(c_min), *max.min(c_max)))
+        }))
+    }
+
+    /// The ranges as string literals in the form of `"0".."9" | "A"`
+    pub fn to_constraint_string(&self) -> String {
+        let literal = |value: u32| {
+            format!(
+                "{:?}",
+                char::from_u32(value).unwrap_or_default().to_string()
+            )
+        };
+        self.0
+            .iter()
+            .map(|(min, max)| {
+                if min == max {
+                    literal(*min)
+                } else {
+                    format!("{}..{}", literal(*min), literal(*max))
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" | ")
+    }
 }
 
 #[derive(Debug, Default, Clone, Copy, PartialOrd, PartialEq)]
@@ -960,11 +1986,13 @@ impl<T> Range<T> {
     }
 }
 
-impl<T: Copy> Range<Option<T>> {
+impl<T> Range<Option<T>> {
     pub fn none() -> Self {
         Range(None, None, false)
     }
+}
 
+impl<T: Copy> Range<Option<T>> {
     pub fn min_max(&self, min_fn: impl Fn() -> T, max_fn: impl Fn() -> T) -> Option<(T, T)> {
         match (self.0, self.1) {
             (Some(min), Some(max)) => Some((min, max)),
@@ -1081,13 +2109,28 @@ impl Tag {
     pub const DEFAULT_INTEGER: Tag = Tag::Universal(2);
     pub const DEFAULT_BIT_STRING: Tag = Tag::Universal(3);
     pub const DEFAULT_OCTET_STRING: Tag = Tag::Universal(4);
+    pub const DEFAULT_NULL: Tag = Tag::Universal(5);
+    pub const DEFAULT_OBJECT_IDENTIFIER: Tag = Tag::Universal(6);
+    pub const DEFAULT_REAL: Tag = Tag::Universal(9);
     pub const DEFAULT_ENUMERATED: Tag = Tag::Universal(10);
     pub const DEFAULT_UTF8_STRING: Tag = Tag::Universal(12);
+    pub const DEFAULT_RELATIVE_OID: Tag = Tag::Universal(13);
     pub const DEFAULT_SEQUENCE: Tag = Tag::Universal(16);
     pub const DEFAULT_SEQUENCE_OF: Tag = Tag::Universal(16);
     pub const DEFAULT_SET: Tag = Tag::Universal(17);
     pub const DEFAULT_SET_OF: Tag = Tag::Universal(17);
+    pub const DEFAULT_NUMERIC_STRING: Tag = Tag::Universal(18);
+    pub const DEFAULT_PRINTABLE_STRING: Tag = Tag::Universal(19);
     pub const DEFAULT_IA5_STRING: Tag = Tag::Universal(22);
+    pub const DEFAULT_UTC_TIME: Tag = Tag::Universal(23);
+    pub const DEFAULT_GENERALIZED_TIME: Tag = Tag::Universal(24);
+    pub const DEFAULT_VISIBLE_STRING: Tag = Tag::Universal(26);
+    pub const DEFAULT_UNIVERSAL_STRING: Tag = Tag::Universal(28);
+    pub const DEFAULT_BMP_STRING: Tag = Tag::Universal(30);
+    pub const DEFAULT_DATE: Tag = Tag::Universal(31);
+    pub const DEFAULT_TIME_OF_DAY: Tag = Tag::Universal(32);
+    pub const DEFAULT_DATE_TIME: Tag = Tag::Universal(33);
+    pub const DEFAULT_DURATION: Tag = Tag::Universal(34);
 }
 
 impl TryFrom<&mut Peekable<IntoIter<Token>>> for Tag {
@@ -1159,7 +2202,19 @@ pub trait TagProperty {
 #[derive(Debug, Clone, PartialOrd, PartialEq)]
 pub struct Asn {
     pub tag: Option<Tag>,
+    pub tagging: Option<Tagging>,
     pub r#type: Type,
+    /// The value of a `SEQUENCE` or `SET` component that is declared with `DEFAULT`
+    pub default: Option<LiteralValue>,
+    /// The name of the component whose value identifies the actual type of this open type
+    pub key: Option<String>,
+    /// Whether the value is boxed in rust, which breaks the cycle of a recursive type. A parsed
+    /// module never boxes a value explicitly, this is determined by [`Model::to_rust`] instead.
+    pub boxed: bool,
+}
+
+impl Target for Asn {
+    type ValueReferenceType = Type;
 }
 
 impl Asn {
@@ -1169,7 +2224,24 @@ impl Asn {
     }
 
     pub const fn opt_tagged(tag: Option<Tag>, r#type: Type) -> Self {
-        Self { tag, r#type }
+        Self {
+            tag,
+            tagging: None,
+            r#type,
+            default: None,
+            key: None,
+            boxed: false,
+        }
+    }
+
+    pub fn with_tagging(mut self, tagging: Option<Tagging>) -> Self {
+        self.tagging = tagging;
+        self
+    }
+
+    pub fn with_default(mut self, default: Option<LiteralValue>) -> Self {
+        self.default = default;
+        self
     }
 
     pub const fn untagged(r#type: Type) -> Self {
@@ -1205,24 +2277,38 @@ impl TagProperty for Asn {
 pub enum Type {
     Boolean,
     Integer(Integer),
-    String(Size, Charset),
-    OctetString(Size),
+    String(Size<LitOrRef<usize>>, Charset, Option<PermittedAlphabet>),
+    OctetString(Size<LitOrRef<usize>>),
     BitString(BitString),
+    Null,
+    Real,
+    ObjectIdentifier,
+    RelativeOid,
+    Time(TimeType),
 
     Optional(Box<Type>),
 
     Sequence(ComponentTypeList),
-    SequenceOf(Box<Type>, Size),
+    SequenceOf(Box<Type>, Size<LitOrRef<usize>>),
     Set(ComponentTypeList),
-    SetOf(Box<Type>, Size),
+    SetOf(Box<Type>, Size<LitOrRef<usize>>),
     Enumerated(Enumerated),
     Choice(Choice),
     TypeReference(String, Option<Tag>),
+    /// A type reference with an inner subtype constraint (`WITH COMPONENTS`)
+    WithComponents(Box<Type>, InnerTypeConstraints<LitOrRef<i128>>),
+    /// An `OCTET STRING` or `BIT STRING` that contains the encoding of a value of the given type
+    /// (`CONTAINING`)
+    Containing(Box<Type>, Container),
+    /// `CLASS.&field`, which is resolved when converting the model to rust
+    ObjectClassField(ObjectClassField),
+    /// A resolved open type, see [`information_object`]
+    OpenType(OpenType),
 }
 
 impl Type {
     pub const fn unconstrained_utf8string() -> Self {
-        Self::String(Size::Any, Charset::Utf8)
+        Self::String(Size::Any, Charset::Utf8, None)
     }
 
     pub const fn unconstrained_octetstring() -> Self {
@@ -1233,23 +2319,27 @@ impl Type {
         Self::integer_with_range_opt(Range::none())
     }
 
-    pub const fn integer_with_range(range: Range<Option<i64>>) -> Self {
-        Self::Integer(Integer {
-            range,
-            constants: Vec::new(),
-        })
+    pub fn integer_with_range(range: Range<Option<i128>>) -> Self {
+        let Range(min, max, extensible) = range;
+        Self::integer_with_range_opt(Range(
+            min.map(LitOrRef::Lit),
+            max.map(LitOrRef::Lit),
+            extensible,
+        ))
     }
 
-    pub const fn integer_with_range_opt(range: Range<Option<i64>>) -> Self {
+    pub const fn integer_with_range_opt(range: Range<Option<LitOrRef<i128>>>) -> Self {
         Self::Integer(Integer {
             range,
             constants: Vec::new(),
+            big: false,
+            constraint: None,
         })
     }
 
-    pub const fn bit_vec_with_size(size: Size) -> Self {
+    pub fn bit_vec_with_size(size: Size) -> Self {
         Self::BitString(BitString {
-            size,
+            size: size.map(LitOrRef::Lit),
             constants: Vec::new(),
         })
     }
@@ -1258,6 +2348,8 @@ impl Type {
         Self::Sequence(ComponentTypeList {
             fields,
             extension_after: None,
+            unknown_extensions: None,
+            components_of: Vec::new(),
         })
     }
 
@@ -1292,8 +2384,58 @@ impl Type {
 
 #[derive(Debug, Clone, PartialOrd, PartialEq)]
 pub struct Integer {
-    pub range: Range<Option<i64>>,
+    /// The effective constraint, see [`ElementSet::effective_range`]
+    pub range: Range<Option<LitOrRef<i128>>>,
     pub constants: Vec<(String, i64)>,
+    /// Whether an unconstrained `INTEGER` is represented with arbitrary precision instead of `u64`
+    pub big: bool,
+    /// The full constraint, unless it is a single value or value range. The effective constraint
+    /// is only known after resolving the value references of the full constraint.
+    pub constraint: Option<ElementSet<LitOrRef<i128>>>,
+}
+
+impl Integer {
+    pub fn with_constraint(set: ElementSet<LitOrRef<i128>>, extensible: bool) -> Self {
+        let effective = |set: &ElementSet<LitOrRef<i128>>| {
+            set.try_map(&|value| value.lit().copied().ok_or(()))
+                .map(|set| set.effective_range())
+                .unwrap_or_default()
+        };
+        let (range, constraint) = match set {
+            ElementSet::Value(value) => (Range(Some(value.clone()), Some(value), extensible), None),
+            ElementSet::Range(Some(LitOrRef::Lit(0)), None) => {
+                (Range(None, None, extensible), None)
+            }
+            ElementSet::Range(min, max) => (Range(min, max, extensible), None),
+            set => {
+                let (min, max) = effective(&set);
+                (
+                    Range(min.map(LitOrRef::Lit), max.map(LitOrRef::Lit), extensible),
+                    Some(set),
+                )
+            }
+        };
+        Self {
+            range,
+            constants: Vec::new(),
+            big: false,
+            constraint,
+        }
+    }
+
+    /// Whether the value can be held by the Rust type of this `INTEGER`, which is unsigned unless
+    /// the lower bound of its range is negative
+    pub fn can_represent(&self, value: i64) -> bool {
+        if value >= 0 || self.constraint.is_some() {
+            return true;
+        }
+        match (&self.range.0, &self.range.1) {
+            (Some(LitOrRef::Lit(min)), _) => *min <= i128::from(value),
+            (None, Some(LitOrRef::Lit(max))) => *max < 0,
+            (None, None) => self.big,
+            (Some(LitOrRef::Ref(_)), _) | (None, Some(LitOrRef::Ref(_))) => true,
+        }
+    }
 }
 
 impl TryFrom<&mut Peekable<IntoIter<Token>>> for Integer {
@@ -1302,14 +2444,20 @@ impl TryFrom<&mut Peekable<IntoIter<Token>>> for Integer {
     fn try_from(iter: &mut Peekable<IntoIter<Token>>) -> Result<Self, Self::Error> {
         let constants =
             Model::<Asn>::maybe_read_constants(iter, Model::<Asn>::constant_i64_parser)?;
-        let range = Model::<Asn>::read_number_range(iter)?;
-        Ok(Self { range, constants })
+        let integer = match Model::<Asn>::maybe_read_value_constraints(iter)? {
+            Some((set, extensible)) => Integer::with_constraint(set, extensible),
+            None => Integer::with_constraint(ElementSet::Range(None, None), false),
+        };
+        Ok(Self {
+            constants,
+            ..integer
+        })
     }
 }
 
 #[derive(Debug, Clone, PartialOrd, PartialEq)]
 pub struct BitString {
-    pub size: Size,
+    pub size: Size<LitOrRef<usize>>,
     pub constants: Vec<(String, u64)>,
 }
 
@@ -1325,10 +2473,20 @@ impl TryFrom<&mut Peekable<IntoIter<Token>>> for BitString {
 }
 
 /// ITU-T X.680 | ISO/IEC 8824-1:2015, Annex L
+///
+/// An extension addition group (`[[ 2: a INTEGER, b BOOLEAN ]]`) is represented as a field of an
+/// inline `SEQUENCE` containing the components of the group, see
+/// [`ComponentTypeList::extension_addition_group_name`]. This field is `OPTIONAL` if all
+/// components of the group are `OPTIONAL`.
 #[derive(Debug, Clone, PartialOrd, PartialEq)]
 pub struct ComponentTypeList {
     pub fields: Vec<Field<Asn>>,
     pub extension_after: Option<usize>,
+    /// The name of the Rust field that preserves extended fields unknown to this definition
+    pub unknown_extensions: Option<String>,
+    /// The types whose root components are included (`COMPONENTS OF Type`), each with the index
+    /// of the field before which they are included. They are resolved by the conversion to rust.
+    pub components_of: Vec<(usize, Type)>,
 }
 
 impl TryFrom<&mut Peekable<IntoIter<Token>>> for ComponentTypeList {
@@ -1339,13 +2497,14 @@ impl TryFrom<&mut Peekable<IntoIter<Token>>> for ComponentTypeList {
         let mut sequence = Self {
             fields: Vec::default(),
             extension_after: None,
+            unknown_extensions: None,
+            components_of: Vec::default(),
         };
+        let mut groups = 0;
 
         loop {
-            let continues = if Model::<Asn>::peek(iter)?.eq_separator('.') {
-                let _ = Model::<Asn>::next_separator_ignore_case(iter, '.')?;
-                let _ = Model::<Asn>::next_separator_ignore_case(iter, '.')?;
-                let _ = Model::<Asn>::next_separator_ignore_case(iter, '.')?;
+            let continues = if Model::<Asn>::peek(iter)?.eq_symbol("...") {
+                Model::<Asn>::next_symbol(iter, "...")?;
                 let field_len = sequence.fields.len();
                 sequence.extension_after = Some(field_len.saturating_sub(1));
                 let token = Model::<Asn>::next(iter)?;
@@ -1356,9 +2515,47 @@ impl TryFrom<&mut Peekable<IntoIter<Token>>> for ComponentTypeList {
                 } else {
                     return Err(Error::unexpected_token(token));
                 }
-            } else {
-                let (field, continues) = Model::<Asn>::read_field(iter)?;
-                sequence.fields.push(field);
+            } else if Model::<Asn>::peek(iter)?.eq_symbol("[[") {
+                let token = Model::<Asn>::next(iter)?;
+                if sequence.extension_after.is_none() {
+                    return Err(Error::invalid_position_for_extension_marker(token));
+                }
+                groups += 1;
+                let field = Self::read_extension_addition_group(iter, groups)?;
+                sequence.fields.push(field);
+                let token = Model::<Asn>::next(iter)?;
+                if token.eq_separator(',') {
+                    true
+                } else if token.eq_separator('}') {
+                    false
+                } else {
+                    return Err(Error::unexpected_token(token));
+                }
+            } else if Model::<Asn>::peek(iter)?.eq_text("COMPONENTS") {
+                // ITU-T X.680 | ISO/IEC 8824-1:2015, chapter 25, only within the root
+                let token = Model::<Asn>::next(iter)?;
+                if sequence.extension_after.is_some() {
+                    return Err(Error::unexpected_token(token));
+                }
+                let token = Model::<Asn>::next(iter)?;
+                if !token.eq_text("OF") {
+                    return Err(Error::unexpected_token(token));
+                }
+                let included = Model::<Asn>::read_role(iter)?;
+                sequence
+                    .components_of
+                    .push((sequence.fields.len(), included));
+                let token = Model::<Asn>::next(iter)?;
+                if token.eq_separator(',') {
+                    true
+                } else if token.eq_separator('}') {
+                    false
+                } else {
+                    return Err(Error::unexpected_token(token));
+                }
+            } else {
+                let (field, continues) = Model::<Asn>::read_field(iter)?;
+                sequence.fields.push(field);
                 continues
             };
 
@@ -1371,10 +2568,71 @@ impl TryFrom<&mut Peekable<IntoIter<Token>>> for ComponentTypeList {
     }
 }
 
+impl ComponentTypeList {
+    /// The name of the field representing an extension addition group, which is derived from
+    /// the version number of the group (`ext-group-v2`) or - if there is none - from the position
+    /// of the group within the extension additions (`ext-group-1`)
+    pub fn extension_addition_group_name(version: Option<u64>, position: usize) -> String {
+        match version {
+            Some(version) => format!("ext-group-v{}", version),
+            None => format!("ext-group-{}", position),
+        }
+    }
+
+    /// ITU-T X.680 | ISO/IEC 8824-1:2015, chapter 25.1 with the leading `[[` already consumed
+    fn read_extension_addition_group(
+        iter: &mut Peekable<IntoIter<Token>>,
+        position: usize,
+    ) -> Result<Field<Asn>, Error> {
+        let version = if matches!(
+            Model::<Asn>::peek(iter)?.text(),
+            Some(t) if t.starts_with(|c: char| c.is_ascii_digit())
+        ) {
+            let token = Model::<Asn>::next(iter)?;
+            let version = match token.text().and_then(|t| t.parse::<u64>().ok()) {
+                Some(version) => version,
+                None => return Err(Error::invalid_int_value(token)),
+            };
+            Model::<Asn>::next_separator_ignore_case(iter, ':')?;
+            Some(version)
+        } else {
+            None
+        };
+
+        let mut group = Self {
+            fields: Vec::default(),
+            extension_after: None,
+            unknown_extensions: None,
+            components_of: Vec::default(),
+        };
+
+        loop {
+            let (field, token) = Model::<Asn>::read_field_until_separator(iter)?;
+            group.fields.push(field);
+            if token.eq_symbol("]]") {
+                break;
+            } else if !token.eq_separator(',') {
+                return Err(Error::unexpected_token(token));
+            }
+        }
+
+        let all_optional = group
+            .fields
+            .iter()
+            .all(|f| matches!(f.role.r#type, Type::Optional(_)));
+        let role = Type::Sequence(group);
+        Ok(Field {
+            name: Self::extension_addition_group_name(version, position),
+            role: if all_optional { role.optional() } else { role }.untagged(),
+        })
+    }
+}
+
 #[derive(Debug, Clone, PartialOrd, PartialEq)]
 pub struct Choice {
     variants: Vec<ChoiceVariant>,
     extension_after: Option<usize>,
+    unknown_extensions: Option<String>,
 }
 
 impl From<Vec<ChoiceVariant>> for Choice {
@@ -1382,6 +2640,7 @@ impl From<Vec<ChoiceVariant>> for Choice {
         Self {
             variants,
             extension_after: None,
+            unknown_extensions: None,
         }
     }
 }
@@ -1391,6 +2650,7 @@ impl Choice {
         Self {
             variants: variants.collect(),
             extension_after: None,
+            unknown_extensions: None,
         }
     }
 
@@ -1399,6 +2659,11 @@ impl Choice {
         self
     }
 
+    pub fn with_unknown_extensions(mut self, unknown_extensions: Option<String>) -> Self {
+        self.unknown_extensions = unknown_extensions;
+        self
+    }
+
     pub fn len(&self) -> usize {
         self.variants.len()
     }
@@ -1418,6 +2683,11 @@ impl Choice {
     pub fn extension_after_index(&self) -> Option<usize> {
         self.extension_after
     }
+
+    /// The name of the Rust variant that preserves extensions unknown to this definition
+    pub fn unknown_extensions(&self) -> Option<&str> {
+        self.unknown_extensions.as_deref()
+    }
 }
 
 impl TryFrom<&mut Peekable<IntoIter<Token>>> for Choice {
@@ -1428,29 +2698,33 @@ impl TryFrom<&mut Peekable<IntoIter<Token>>> for Choice {
         let mut choice = Choice {
             variants: Vec::new(),
             extension_after: None,
+            unknown_extensions: None,
         };
 
         loop {
             let name_or_extension_marker = Model::<Asn>::next(iter)?;
-            if name_or_extension_marker.eq_separator('.') {
-                Model::<Asn>::next_separator_ignore_case(iter, '.')?;
-                Model::<Asn>::next_separator_ignore_case(iter, '.')?;
-
+            if name_or_extension_marker.eq_symbol("...") {
                 if choice.variants.is_empty() || choice.extension_after.is_some() {
                     return Err(Error::invalid_position_for_extension_marker(
                         name_or_extension_marker,
                     ));
                 } else {
-                    choice.extension_after = Some(choice.variants.len() - 1);
+                    choice.extension_after = choice.variants.len().checked_sub(1);
                 }
             } else {
                 let name = name_or_extension_marker.into_text_or_else(Error::no_text)?;
-                let (token, tag) = Model::<Asn>::next_with_opt_tag(iter)?;
+                let (token, tag, tagging) = Model::<Asn>::next_with_opt_tag(iter)?;
                 let r#type = Model::<Asn>::read_role_given_text(
                     iter,
                     token.into_text_or_else(Error::no_text)?,
                 )?;
-                choice.variants.push(ChoiceVariant { name, tag, r#type });
+                choice.variants.push(ChoiceVariant {
+                    name,
+                    tag,
+                    tagging,
+                    r#type,
+                    boxed: false,
+                });
             }
 
             let end_or_continuation_marker = Model::<Asn>::next(iter)?;
@@ -1472,7 +2746,10 @@ impl TryFrom<&mut Peekable<IntoIter<Token>>> for Choice {
 pub struct ChoiceVariant {
     pub name: String,
     pub tag: Option<Tag>,
+    pub tagging: Option<Tagging>,
     pub r#type: Type,
+    /// Whether the value is boxed in rust, see [`Asn::boxed`]
+    pub boxed: bool,
 }
 
 impl ChoiceVariant {
@@ -1481,7 +2758,9 @@ impl ChoiceVariant {
         ChoiceVariant {
             name: name.to_string(),
             tag: None,
+            tagging: None,
             r#type,
+            boxed: false,
         }
     }
 
@@ -1512,6 +2791,7 @@ impl TagProperty for ChoiceVariant {
 pub struct Enumerated {
     variants: Vec<EnumeratedVariant>,
     extension_after: Option<usize>,
+    unknown_extensions: Option<String>,
 }
 
 impl From<Vec<EnumeratedVariant>> for Enumerated {
@@ -1519,6 +2799,7 @@ impl From<Vec<EnumeratedVariant>> for Enumerated {
         Self {
             variants,
             extension_after: None,
+            unknown_extensions: None,
         }
     }
 }
@@ -1528,6 +2809,7 @@ impl Enumerated {
         Self {
             variants: variants.into(),
             extension_after: None,
+            unknown_extensions: None,
         }
     }
 
@@ -1535,6 +2817,7 @@ impl Enumerated {
         Self {
             variants: variants.map(EnumeratedVariant::from_name).collect(),
             extension_after: None,
+            unknown_extensions: None,
         }
     }
 
@@ -1543,6 +2826,11 @@ impl Enumerated {
         self
     }
 
+    pub fn with_unknown_extensions(mut self, unknown_extensions: Option<String>) -> Self {
+        self.unknown_extensions = unknown_extensions;
+        self
+    }
+
     pub fn len(&self) -> usize {
         self.variants.len()
     }
@@ -1562,6 +2850,11 @@ impl Enumerated {
     pub fn extension_after_index(&self) -> Option<usize> {
         self.extension_after
     }
+
+    /// The name of the Rust variant that preserves extensions unknown to this definition
+    pub fn unknown_extensions(&self) -> Option<&str> {
+        self.unknown_extensions.as_deref()
+    }
 }
 
 impl TryFrom<&mut Peekable<IntoIter<Token>>> for Enumerated {
@@ -1572,18 +2865,17 @@ impl TryFrom<&mut Peekable<IntoIter<Token>>> for Enumerated {
         let mut enumerated = Self {
             variants: Vec::new(),
             extension_after: None,
+            unknown_extensions: None,
         };
 
         loop {
             let token = Model::<Asn>::next(iter)?;
 
-            if token.eq_separator('.') {
-                Model::<Asn>::next_separator_ignore_case(iter, '.')?;
-                Model::<Asn>::next_separator_ignore_case(iter, '.')?;
+            if token.eq_symbol("...") {
                 if enumerated.variants.is_empty() || enumerated.extension_after.is_some() {
                     return Err(Error::invalid_position_for_extension_marker(token));
                 } else {
-                    enumerated.extension_after = Some(enumerated.variants.len() - 1);
+                    enumerated.extension_after = enumerated.variants.len().checked_sub(1);
                     loop_ctrl_separator!(Model::<Asn>::next(iter)?);
                 }
             } else {
@@ -1682,7 +2974,12 @@ pub(crate) mod tests {
 
     #[test]
     fn test_simple_asn_sequence_represented_correctly_as_asn_model() {
-        let model = Model::try_from(Tokenizer::default().parse(SIMPLE_INTEGER_STRUCT_ASN)).unwrap();
+        let model = Model::try_from(
+            Tokenizer::default()
+                .parse(SIMPLE_INTEGER_STRUCT_ASN)
+                .unwrap(),
+        )
+        .unwrap();
 
         assert_eq!("SimpleSchema", model.name);
         assert_eq!(true, model.imports.is_empty());
@@ -1735,7 +3032,8 @@ pub(crate) mod tests {
 
     #[test]
     fn test_inline_asn_enumerated_represented_correctly_as_asn_model() {
-        let model = Model::try_from(Tokenizer::default().parse(INLINE_ASN_WITH_ENUM)).unwrap();
+        let model =
+            Model::try_from(Tokenizer::default().parse(INLINE_ASN_WITH_ENUM).unwrap()).unwrap();
 
         assert_eq!("SimpleSchema", model.name);
         assert_eq!(true, model.imports.is_empty());
@@ -1775,8 +3073,12 @@ pub(crate) mod tests {
 
     #[test]
     fn test_inline_asn_sequence_of_represented_correctly_as_asn_model() {
-        let model =
-            Model::try_from(Tokenizer::default().parse(INLINE_ASN_WITH_SEQUENCE_OF)).unwrap();
+        let model = Model::try_from(
+            Tokenizer::default()
+                .parse(INLINE_ASN_WITH_SEQUENCE_OF)
+                .unwrap(),
+        )
+        .unwrap();
 
         assert_eq!("SimpleSchema", model.name);
         assert_eq!(true, model.imports.is_empty());
@@ -1876,7 +3178,8 @@ pub(crate) mod tests {
 
     #[test]
     fn test_inline_asn_choice_represented_correctly_as_asn_model() {
-        let model = Model::try_from(Tokenizer::default().parse(INLINE_ASN_WITH_CHOICE)).unwrap();
+        let model =
+            Model::try_from(Tokenizer::default().parse(INLINE_ASN_WITH_CHOICE).unwrap()).unwrap();
 
         assert_eq!("SimpleSchema", model.name);
         assert_eq!(true, model.imports.is_empty());
@@ -1950,7 +3253,12 @@ pub(crate) mod tests {
 
     #[test]
     fn test_inline_asn_sequence_represented_correctly_as_asn_model() {
-        let model = Model::try_from(Tokenizer::default().parse(INLINE_ASN_WITH_SEQUENCE)).unwrap();
+        let model = Model::try_from(
+            Tokenizer::default()
+                .parse(INLINE_ASN_WITH_SEQUENCE)
+                .unwrap(),
+        )
+        .unwrap();
 
         assert_eq!("SimpleSchema", model.name);
         assert_eq!(true, model.imports.is_empty());
@@ -1999,6 +3307,287 @@ pub(crate) mod tests {
         );
     }
 
+    #[test]
+    fn test_extension_addition_groups() {
+        let model = Model::try_from(
+            Tokenizer
+                .parse(
+                    r"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+            Grouped ::= SEQUENCE {
+                abc INTEGER,
+                ...,
+                [[ 2: def INTEGER, ghi BOOLEAN OPTIONAL ]],
+                [[ jkl BOOLEAN OPTIONAL ]],
+                mno BOOLEAN
+            }
+
+            END",
+                )
+                .unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            Definition(
+                "Grouped".into(),
+                Type::Sequence(ComponentTypeList {
+                    fields: vec![
+                        Field {
+                            name: "abc".into(),
+                            role: Type::unconstrained_integer().untagged(),
+                        },
+                        Field {
+                            name: "ext-group-v2".into(),
+                            role: Type::sequence_from_fields(vec![
+                                Field {
+                                    name: "def".into(),
+                                    role: Type::unconstrained_integer().untagged(),
+                                },
+                                Field {
+                                    name: "ghi".into(),
+                                    role: Type::Boolean.optional().untagged(),
+                                },
+                            ])
+                            .untagged(),
+                        },
+                        Field {
+                            name: "ext-group-2".into(),
+                            role: Type::sequence_from_fields(vec![Field {
+                                name: "jkl".into(),
+                                role: Type::Boolean.optional().untagged(),
+                            }])
+                            .optional()
+                            .untagged(),
+                        },
+                        Field {
+                            name: "mno".into(),
+                            role: Type::Boolean.untagged(),
+                        },
+                    ],
+                    extension_after: Some(0),
+                    unknown_extensions: None,
+                    components_of: Vec::new(),
+                })
+                .untagged(),
+            ),
+            model.definitions[0]
+        );
+    }
+
+    #[test]
+    pub fn test_extension_addition_group_outside_of_extension_additions() {
+        assert_eq!(
+            Error::invalid_position_for_extension_marker(Token::Symbol(Location::at(4, 17), "[[",)),
+            Model::try_from(
+                Tokenizer
+                    .parse(
+                        r"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+                Invalid ::= SEQUENCE {
+                [[ abc INTEGER ]]
+                }
+
+                END",
+                    )
+                    .unwrap()
+            )
+            .expect_err("Parsed invalid definition")
+        );
+    }
+
+    #[test]
+    fn test_default_values() {
+        let model = Model::try_from(
+            Tokenizer
+                .parse(
+                    r#"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+            Defaults ::= SEQUENCE {
+                abc INTEGER (-10..10) DEFAULT -5,
+                def BOOLEAN DEFAULT TRUE,
+                ghi UTF8String DEFAULT "x ""y""",
+                jkl BIT STRING DEFAULT '101'B,
+                mno OCTET STRING DEFAULT 'A0FF'H,
+                pqr ENUMERATED { a, b-c } DEFAULT b-c,
+                stu Other DEFAULT 7
+            }
+
+            END"#,
+                )
+                .unwrap(),
+        )
+        .unwrap();
+
+        let defaults = match &model.definitions[0].1.r#type {
+            Type::Sequence(ComponentTypeList { fields, .. }) => fields
+                .iter()
+                .map(|f| f.role.default.clone())
+                .collect::<Vec<_>>(),
+            _ => panic!("Expected a SEQUENCE"),
+        };
+
+        assert_eq!(
+            vec![
+                Some(LiteralValue::Integer(-5)),
+                Some(LiteralValue::Boolean(true)),
+                Some(LiteralValue::String("x \"y\"".to_string())),
+                Some(LiteralValue::Bits(vec![0xA0], 3)),
+                Some(LiteralValue::Bits(vec![0xA0, 0xFF], 16)),
+                Some(LiteralValue::Enumerated("b-c".to_string())),
+                Some(LiteralValue::Integer(7)),
+            ],
+            defaults
+        );
+    }
+
+    #[test]
+    fn test_default_values_with_separators_and_comments() {
+        let model = Model::try_from(
+            Tokenizer
+                .parse(
+                    r#"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+            /* the defaults /* of */ the -- defaults */
+            Defaults ::= SEQUENCE {
+                abc UTF8String DEFAULT "a, b -- c } /* d */", --- comment ---
+                def BIT STRING DEFAULT '1010 1'B,
+                ghi OCTET STRING DEFAULT 'A0 FF'H -- comment
+            }
+
+            END"#,
+                )
+                .unwrap(),
+        )
+        .unwrap();
+
+        let defaults = match &model.definitions[0].1.r#type {
+            Type::Sequence(ComponentTypeList { fields, .. }) => fields
+                .iter()
+                .map(|f| f.role.default.clone())
+                .collect::<Vec<_>>(),
+            _ => panic!("Expected a SEQUENCE"),
+        };
+
+        assert_eq!(
+            vec![
+                Some(LiteralValue::String("a, b -- c } /* d */".to_string())),
+                Some(LiteralValue::Bits(vec![0xA8], 5)),
+                Some(LiteralValue::Bits(vec![0xA0, 0xFF], 16)),
+            ],
+            defaults
+        );
+    }
+
+    #[test]
+    fn test_default_value_display_is_asn_notation() {
+        assert_eq!("-5", LiteralValue::Integer(-5).to_string());
+        assert_eq!("TRUE", LiteralValue::Boolean(true).to_string());
+        assert_eq!(
+            "\"x \"\"y\"\"\"",
+            LiteralValue::String("x \"y\"".to_string()).to_string()
+        );
+        assert_eq!("'101'B", LiteralValue::Bits(vec![0xA0], 3).to_string());
+    }
+
+    #[test]
+    fn test_default_value_of_wrong_type() {
+        assert_eq!(
+            Error::invalid_literal_value(Token::CString(Location::at(4, 41), "abc".to_string())),
+            Model::try_from(
+                Tokenizer
+                    .parse(
+                        r#"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+                Invalid ::= SEQUENCE {
+                    abc INTEGER DEFAULT "abc"
+                }
+
+                END"#,
+                    )
+                    .unwrap()
+            )
+            .expect_err("Parsed invalid definition")
+        );
+    }
+
+    #[test]
+    fn test_value_references() {
+        let model = Model::try_from(
+            Tokenizer
+                .parse(
+                    r#"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+            maxItems INTEGER ::= 23
+            Items ::= SEQUENCE (SIZE(1..maxItems)) OF INTEGER (minValue..255)
+            minValue INTEGER ::= -5
+            greeting UTF8String ::= "hi"
+
+            END"#,
+                )
+                .unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            vec![
+                ValueReference {
+                    name: "maxItems".to_string(),
+                    role: Type::unconstrained_integer(),
+                    value: LiteralValue::Integer(23),
+                },
+                ValueReference {
+                    name: "minValue".to_string(),
+                    role: Type::unconstrained_integer(),
+                    value: LiteralValue::Integer(-5),
+                },
+                ValueReference {
+                    name: "greeting".to_string(),
+                    role: Type::unconstrained_utf8string(),
+                    value: LiteralValue::String("hi".to_string()),
+                },
+            ],
+            model.value_references
+        );
+        assert_eq!(
+            vec![Definition(
+                "Items".to_string(),
+                Type::SequenceOf(
+                    Box::new(Type::integer_with_range_opt(Range::inclusive(
+                        Some(LitOrRef::Ref("minValue".to_string())),
+                        Some(LitOrRef::Lit(255)),
+                    ))),
+                    Size::Range(
+                        LitOrRef::Lit(1),
+                        LitOrRef::Ref("maxItems".to_string()),
+                        false
+                    ),
+                )
+                .untagged(),
+            )],
+            model.definitions
+        );
+    }
+
+    #[test]
+    fn test_value_reference_of_wrong_type() {
+        assert_eq!(
+            Error::invalid_literal_value(Token::Keyword(Location::at(3, 38), "TRUE".to_string())),
+            Model::try_from(
+                Tokenizer
+                    .parse(
+                        r#"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+                maxItems INTEGER ::= TRUE
+
+                END"#,
+                    )
+                    .unwrap()
+            )
+            .expect_err("Parsed invalid definition")
+        );
+    }
+
     #[test]
     fn test_nice_names() {
         let mut model = Model::default();
@@ -2022,8 +3611,10 @@ pub(crate) mod tests {
 
     #[test]
     pub fn test_integer_type_with_range() {
-        let model = Model::try_from(Tokenizer::default().parse(
-            r"
+        let model = Model::try_from(
+            Tokenizer::default()
+                .parse(
+                    r"
             SimpleSchema DEFINITIONS AUTOMATIC TAGS ::=
             BEGIN
     
@@ -2031,7 +3622,9 @@ pub(crate) mod tests {
             
             END
         ",
-        ))
+                )
+                .unwrap(),
+        )
         .expect("Failed to parse");
 
         assert_eq!("SimpleSchema", &model.name);
@@ -2046,8 +3639,10 @@ pub(crate) mod tests {
 
     #[test]
     pub fn test_string_type() {
-        let model = Model::try_from(Tokenizer::default().parse(
-            r"
+        let model = Model::try_from(
+            Tokenizer::default()
+                .parse(
+                    r"
             SimpleSchema DEFINITIONS AUTOMATIC TAGS ::=
             BEGIN
     
@@ -2055,7 +3650,9 @@ pub(crate) mod tests {
             
             END
         ",
-        ))
+                )
+                .unwrap(),
+        )
         .expect("Failed to parse");
 
         assert_eq!("SimpleSchema", &model.name);
@@ -2070,8 +3667,10 @@ pub(crate) mod tests {
 
     #[test]
     pub fn test_enumerated_advanced() {
-        let model = Model::try_from(Tokenizer::default().parse(
-            r"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::=
+        let model = Model::try_from(
+            Tokenizer::default()
+                .parse(
+                    r"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::=
             BEGIN
     
             Basic ::= ENUMERATED {
@@ -2099,7 +3698,9 @@ pub(crate) mod tests {
             
             END
         ",
-        ))
+                )
+                .unwrap(),
+        )
         .expect("Failed to parse");
 
         assert_eq!("SimpleSchema", &model.name);
@@ -2117,6 +3718,7 @@ pub(crate) mod tests {
                             EnumeratedVariant::from_name_number("def", 9)
                         ],
                         extension_after: None,
+                        unknown_extensions: None,
                     })
                     .untagged(),
                 ),
@@ -2128,6 +3730,7 @@ pub(crate) mod tests {
                             EnumeratedVariant::from_name_number("def", 7),
                         ],
                         extension_after: Some(1),
+                        unknown_extensions: None,
                     })
                     .untagged(),
                 ),
@@ -2140,6 +3743,7 @@ pub(crate) mod tests {
                             EnumeratedVariant::from_name_number("v2", 11)
                         ],
                         extension_after: Some(1),
+                        unknown_extensions: None,
                     })
                     .untagged(),
                 )
@@ -2150,8 +3754,10 @@ pub(crate) mod tests {
 
     #[test]
     pub fn test_enumerated_tags() {
-        let model = Model::try_from(Tokenizer::default().parse(
-            r"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::=
+        let model = Model::try_from(
+            Tokenizer::default()
+                .parse(
+                    r"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::=
             BEGIN
     
             Universal ::= [UNIVERSAL 2] ENUMERATED {
@@ -2176,7 +3782,9 @@ pub(crate) mod tests {
             
             END
         ",
-        ))
+                )
+                .unwrap(),
+        )
         .expect("Failed to parse");
 
         assert_eq!("SimpleSchema", &model.name);
@@ -2209,8 +3817,10 @@ pub(crate) mod tests {
 
     #[test]
     pub fn test_parsing_tags_in_front_of_definitions_does_not_fail() {
-        let model = Model::try_from(Tokenizer::default().parse(
-            r"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::=
+        let model = Model::try_from(
+            Tokenizer::default()
+                .parse(
+                    r"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::=
             BEGIN
     
             Universal ::= [UNIVERSAL 2] SEQUENCE {
@@ -2229,7 +3839,9 @@ pub(crate) mod tests {
             
             END
         ",
-        ))
+                )
+                .unwrap(),
+        )
         .expect("Failed to parse");
 
         assert_eq!("SimpleSchema", &model.name);
@@ -2271,8 +3883,10 @@ pub(crate) mod tests {
 
     #[test]
     pub fn test_parsing_of_extensible_choices() {
-        let model = Model::try_from(Tokenizer::default().parse(
-            r"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::=
+        let model = Model::try_from(
+            Tokenizer::default()
+                .parse(
+                    r"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::=
             BEGIN
     
             WithoutMarker ::= CHOICE {
@@ -2295,7 +3909,9 @@ pub(crate) mod tests {
             
             END
         ",
-        ))
+                )
+                .unwrap(),
+        )
         .expect("Failed to parse");
 
         assert_eq!("SimpleSchema", model.name.as_str());
@@ -2309,6 +3925,7 @@ pub(crate) mod tests {
                             ChoiceVariant::name_type("def", Type::unconstrained_utf8string()),
                         ],
                         extension_after: None,
+                        unknown_extensions: None,
                     })
                     .untagged(),
                 ),
@@ -2320,6 +3937,7 @@ pub(crate) mod tests {
                             ChoiceVariant::name_type("def", Type::unconstrained_utf8string()),
                         ],
                         extension_after: Some(1),
+                        unknown_extensions: None,
                     })
                     .untagged(),
                 ),
@@ -2332,6 +3950,7 @@ pub(crate) mod tests {
                             ChoiceVariant::name_type("ghi", Type::unconstrained_utf8string()),
                         ],
                         extension_after: Some(1),
+                        unknown_extensions: None,
                     })
                     .untagged(),
                 )
@@ -2343,29 +3962,33 @@ pub(crate) mod tests {
     #[test]
     pub fn test_parsing_of_extensible_with_markers_at_invalid_locations() {
         assert_eq!(
-            Error::invalid_position_for_extension_marker(Token::Separator(
-                Location::at(4, 21),
-                '.',
-            )),
-            Model::try_from(Tokenizer::default().parse(
-                r"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+            Error::invalid_position_for_extension_marker(
+                Token::Symbol(Location::at(4, 21), "...",)
+            ),
+            Model::try_from(
+                Tokenizer::default()
+                    .parse(
+                        r"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
 
                 Invalid ::= CHOICE {
                     ...
                 }
                 
                 END",
-            ))
+                    )
+                    .unwrap()
+            )
             .expect_err("Parsed invalid definition")
         );
 
         assert_eq!(
-            Error::invalid_position_for_extension_marker(Token::Separator(
-                Location::at(4, 21),
-                '.',
-            )),
-            Model::try_from(Tokenizer::default().parse(
-                r"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+            Error::invalid_position_for_extension_marker(
+                Token::Symbol(Location::at(4, 21), "...",)
+            ),
+            Model::try_from(
+                Tokenizer::default()
+                    .parse(
+                        r"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
     
                 Invalid ::= CHOICE {
                     ...,
@@ -2373,34 +3996,40 @@ pub(crate) mod tests {
                 }
                 
                 END",
-            ))
+                    )
+                    .unwrap()
+            )
             .expect_err("Parsed invalid definition")
         );
 
         assert_eq!(
-            Error::invalid_position_for_extension_marker(Token::Separator(
-                Location::at(4, 21),
-                '.',
-            )),
-            Model::try_from(Tokenizer::default().parse(
-                r"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+            Error::invalid_position_for_extension_marker(
+                Token::Symbol(Location::at(4, 21), "...",)
+            ),
+            Model::try_from(
+                Tokenizer::default()
+                    .parse(
+                        r"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
     
                 Invalid ::= ENUMERATED {
                     ...
                 }
                 
                 END",
-            ))
+                    )
+                    .unwrap()
+            )
             .expect_err("Parsed invalid definition")
         );
 
         assert_eq!(
-            Error::invalid_position_for_extension_marker(Token::Separator(
-                Location::at(4, 21),
-                '.',
-            )),
-            Model::try_from(Tokenizer::default().parse(
-                r"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+            Error::invalid_position_for_extension_marker(
+                Token::Symbol(Location::at(4, 21), "...",)
+            ),
+            Model::try_from(
+                Tokenizer::default()
+                    .parse(
+                        r"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
 
                 Invalid ::= ENUMERATED {
                     ...,
@@ -2408,16 +4037,72 @@ pub(crate) mod tests {
                 }
                 
                 END",
-            ))
+                    )
+                    .unwrap()
+            )
+            .expect_err("Parsed invalid definition")
+        );
+    }
+
+    #[test]
+    pub fn test_negative_default_of_unsigned_integer() {
+        assert_eq!(
+            Error::invalid_literal_value(Token::Number(Location::at(4, 39), "-3".to_string())),
+            Model::try_from(
+                Tokenizer
+                    .parse(
+                        r"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+                Invalid ::= SEQUENCE {
+                    a INTEGER DEFAULT -3
+                }
+
+                END",
+                    )
+                    .unwrap()
+            )
+            .expect_err("Parsed invalid definition")
+        );
+
+        assert_eq!(
+            Error::invalid_literal_value(Token::Number(Location::at(4, 47), "-3".to_string())),
+            Model::try_from(
+                Tokenizer
+                    .parse(
+                        r"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+                Invalid ::= SEQUENCE {
+                    a INTEGER (0..10) DEFAULT -3
+                }
+
+                END",
+                    )
+                    .unwrap()
+            )
             .expect_err("Parsed invalid definition")
         );
+
+        Model::try_from(
+            Tokenizer
+                .parse(
+                    r"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+            Valid ::= SEQUENCE {
+                a INTEGER (-5..10) DEFAULT -3
+            }
+
+            END",
+                )
+                .unwrap(),
+        )
+        .expect("Failed to load model");
     }
 
     #[test]
     pub fn test_parsing_module_definition_oid() {
         let model = Model::try_from(Tokenizer::default().parse(
             "SomeName { very(1) clever oid(4) 1337 } DEFINITIONS AUTOMATIC TAGS ::= BEGIN END",
-        ))
+        ).unwrap())
         .expect("Failed to load model");
         assert_eq!(
             ObjectIdentifier(vec![
@@ -2432,13 +4117,17 @@ pub(crate) mod tests {
 
     #[test]
     pub fn test_parsing_module_definition_oid_in_import_from() {
-        let model = Model::try_from(Tokenizer::default().parse(
-            r"SomeName DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+        let model = Model::try_from(
+            Tokenizer::default()
+                .parse(
+                    r"SomeName DEFINITIONS AUTOMATIC TAGS ::= BEGIN
                 IMPORTS
                     SomeData, OtherDef, Wowz
                 FROM TheOtherModule { very(1) official(2) oid 42 };
                 END",
-        ))
+                )
+                .unwrap(),
+        )
         .expect("Failed to load model");
         assert_eq!(
             &ObjectIdentifier(vec![
@@ -2454,10 +4143,82 @@ pub(crate) mod tests {
         )
     }
 
+    #[test]
+    pub fn test_parsing_module_tagging() {
+        let model = Model::try_from(
+            Tokenizer
+                .parse(
+                    r"SomeName DEFINITIONS IMPLICIT TAGS EXTENSIBILITY IMPLIED ::= BEGIN
+
+            Simple ::= SEQUENCE {
+                abc [0] EXPLICIT INTEGER,
+                def [1] INTEGER
+            }
+
+            Decision ::= CHOICE {
+                abc [0] IMPLICIT INTEGER,
+                def BOOLEAN,
+                ...
+            }
+
+            Tagged ::= [APPLICATION 3] EXPLICIT INTEGER
+
+            END",
+                )
+                .unwrap(),
+        )
+        .expect("Failed to load model");
+        assert_eq!(TagDefault::Implicit, model.tag_default);
+        assert!(model.extensibility_implied);
+
+        match &model.definitions[0].1.r#type {
+            Type::Sequence(list) => {
+                assert_eq!(Some(1), list.extension_after);
+                assert_eq!(Some(Tagging::Explicit), list.fields[0].role.tagging);
+                assert_eq!(None, list.fields[1].role.tagging);
+            }
+            r#type => panic!("Expected SEQUENCE but got {:?}", r#type),
+        }
+        match &model.definitions[1].1.r#type {
+            Type::Choice(choice) => {
+                assert_eq!(Some(1), choice.extension_after);
+                assert_eq!(Some(Tagging::Implicit), choice.variants[0].tagging);
+                assert_eq!(None, choice.variants[1].tagging);
+            }
+            r#type => panic!("Expected CHOICE but got {:?}", r#type),
+        }
+        assert_eq!(Some(Tag::Application(3)), model.definitions[2].1.tag);
+        assert_eq!(Some(Tagging::Explicit), model.definitions[2].1.tagging);
+
+        let model = Model::try_from(
+            Tokenizer
+                .parse("SomeName DEFINITIONS ::= BEGIN END")
+                .unwrap(),
+        )
+        .expect("Failed to load model");
+        assert_eq!(TagDefault::Explicit, model.tag_default);
+        assert!(!model.extensibility_implied);
+
+        assert_eq!(
+            Error::expected_text(
+                "TAGS".to_string(),
+                Token::Symbol(Location::at(1, 32), "::=")
+            ),
+            Model::try_from(
+                Tokenizer
+                    .parse("SomeName DEFINITIONS AUTOMATIC ::= BEGIN END")
+                    .unwrap(),
+            )
+            .expect_err("Parsed invalid module header")
+        );
+    }
+
     #[test]
     pub fn test_parsing_module_definition_with_integer_constant() {
-        let model = Model::try_from(Tokenizer::default().parse(
-            r"SomeName DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+        let model = Model::try_from(
+            Tokenizer::default()
+                .parse(
+                    r"SomeName DEFINITIONS AUTOMATIC TAGS ::= BEGIN
                 TheGreatStruct ::= SEQUENCE {
                     inline     INTEGER { ab(1), cd(2), ef(3) },
                     eff-u8     INTEGER { gh(1), ij(4), kl(9) } (0..255),
@@ -2468,7 +4229,9 @@ pub(crate) mod tests {
                 
                 OhAlias ::= [APPLICATION 9] INTEGER { oh(1), lul(2) } (0..255)
                 END",
-        ))
+                )
+                .unwrap(),
+        )
         .expect("Failed to load model");
         assert_eq!(
             vec![
@@ -2484,30 +4247,42 @@ pub(crate) mod tests {
                                     ("cd".to_string(), 2),
                                     ("ef".to_string(), 3)
                                 ],
+                                big: false,
+                                constraint: None,
                             })
                             .untagged(),
                         },
                         Field {
                             name: "eff-u8".to_string(),
                             role: Type::Integer(Integer {
-                                range: Range::inclusive(Some(0), Some(255)),
+                                range: Range::inclusive(
+                                    Some(LitOrRef::Lit(0)),
+                                    Some(LitOrRef::Lit(255))
+                                ),
                                 constants: vec![
                                     ("gh".to_string(), 1),
                                     ("ij".to_string(), 4),
                                     ("kl".to_string(), 9)
                                 ],
+                                big: false,
+                                constraint: None,
                             })
                             .untagged(),
                         },
                         Field {
                             name: "tagged".to_string(),
                             role: Type::Integer(Integer {
-                                range: Range::inclusive(Some(0), Some(255)),
+                                range: Range::inclusive(
+                                    Some(LitOrRef::Lit(0)),
+                                    Some(LitOrRef::Lit(255))
+                                ),
                                 constants: vec![
                                     ("mn".to_string(), 5),
                                     ("op".to_string(), 4),
                                     ("qr".to_string(), 9)
                                 ],
+                                big: false,
+                                constraint: None,
                             })
                             .tagged(Tag::ContextSpecific(7)),
                         },
@@ -2523,14 +4298,18 @@ pub(crate) mod tests {
                             ("much".to_string(), 2),
                             ("great".to_string(), 3),
                         ],
+                        big: false,
+                        constraint: None,
                     })
                     .untagged(),
                 ),
                 Definition(
                     "OhAlias".to_string(),
                     Type::Integer(Integer {
-                        range: Range::inclusive(Some(0), Some(255)),
+                        range: Range::inclusive(Some(LitOrRef::Lit(0)), Some(LitOrRef::Lit(255))),
                         constants: vec![("oh".to_string(), 1), ("lul".to_string(), 2),],
+                        big: false,
+                        constraint: None,
                     })
                     .tagged(Tag::Application(9)),
                 )
@@ -2541,14 +4320,18 @@ pub(crate) mod tests {
 
     #[test]
     pub fn test_parsing_module_definition_with_extensible_integer() {
-        let model = Model::try_from(Tokenizer::default().parse(
-            r"SomeName DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+        let model = Model::try_from(
+            Tokenizer::default()
+                .parse(
+                    r"SomeName DEFINITIONS AUTOMATIC TAGS ::= BEGIN
                 RangedOptional ::= SEQUENCE {
                     value     INTEGER { gh(1), ij(4), kl(9) } (0..255,...) OPTIONAL
                 }
                 
                 END",
-        ))
+                )
+                .unwrap(),
+        )
         .expect("Failed to load model");
         assert_eq!(
             vec![Definition(
@@ -2556,12 +4339,15 @@ pub(crate) mod tests {
                 Type::sequence_from_fields(vec![Field {
                     name: "value".to_string(),
                     role: Type::Integer(Integer {
-                        range: Range::inclusive(Some(0), Some(255)).with_extensible(true),
+                        range: Range::inclusive(Some(LitOrRef::Lit(0)), Some(LitOrRef::Lit(255)))
+                            .with_extensible(true),
                         constants: vec![
                             ("gh".to_string(), 1),
                             ("ij".to_string(), 4),
                             ("kl".to_string(), 9)
                         ],
+                        big: false,
+                        constraint: None,
                     })
                     .optional()
                     .untagged(),
@@ -2572,17 +4358,474 @@ pub(crate) mod tests {
         )
     }
 
+    #[test]
+    pub fn test_parsing_integer_and_size_set_arithmetic() {
+        let model = Model::try_from(
+            Tokenizer
+                .parse(
+                    r"SomeName DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                Sparse ::= INTEGER (1 | 3 | 5..10)
+                Serial ::= INTEGER (0..100) (50..MAX, ...)
+                NonZero ::= INTEGER (ALL EXCEPT 0)
+                Digest ::= OCTET STRING (SIZE(4) | SIZE(8))
+                END",
+                )
+                .unwrap(),
+        )
+        .expect("Failed to load model");
+        assert_eq!(
+            vec![
+                Definition(
+                    "Sparse".to_string(),
+                    Type::Integer(Integer {
+                        range: Range::inclusive(Some(LitOrRef::Lit(1)), Some(LitOrRef::Lit(10))),
+                        constants: Vec::new(),
+                        big: false,
+                        constraint: Some(ElementSet::Union(vec![
+                            ElementSet::Value(LitOrRef::Lit(1)),
+                            ElementSet::Value(LitOrRef::Lit(3)),
+                            ElementSet::Range(Some(LitOrRef::Lit(5)), Some(LitOrRef::Lit(10))),
+                        ])),
+                    })
+                    .untagged(),
+                ),
+                Definition(
+                    "Serial".to_string(),
+                    Type::Integer(Integer {
+                        range: Range::inclusive(Some(LitOrRef::Lit(50)), Some(LitOrRef::Lit(100)))
+                            .with_extensible(true),
+                        constants: Vec::new(),
+                        big: false,
+                        constraint: Some(ElementSet::Intersection(vec![
+                            ElementSet::Range(Some(LitOrRef::Lit(0)), Some(LitOrRef::Lit(100))),
+                            ElementSet::Range(Some(LitOrRef::Lit(50)), None),
+                        ])),
+                    })
+                    .untagged(),
+                ),
+                Definition(
+                    "NonZero".to_string(),
+                    Type::Integer(Integer {
+                        range: Range::inclusive(None, None),
+                        constants: Vec::new(),
+                        big: false,
+                        constraint: Some(ElementSet::Except(
+                            Box::new(ElementSet::All),
+                            Box::new(ElementSet::Value(LitOrRef::Lit(0))),
+                        )),
+                    })
+                    .untagged(),
+                ),
+                Definition(
+                    "Digest".to_string(),
+                    Type::OctetString(Size::Range(LitOrRef::Lit(4), LitOrRef::Lit(8), false))
+                        .untagged(),
+                ),
+            ],
+            model.definitions
+        )
+    }
+
+    #[test]
+    pub fn test_parsing_inner_type_constraints() {
+        use crate::model::constraint::{ComponentConstraint, Presence, ValueConstraint};
+        let model = Model::try_from(
+            Tokenizer
+                .parse(
+                    r"SomeName DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                Located ::= Report (WITH COMPONENTS { ..., position PRESENT, speed ABSENT })
+                Ground ::= Report (WITH COMPONENTS {
+                    id (1 | 3),
+                    position (WITH COMPONENTS { ..., altitude ABSENT }) OPTIONAL
+                })
+                Small ::= Readings (WITH COMPONENT (0..100))
+                END",
+                )
+                .unwrap(),
+        )
+        .expect("Failed to load model");
+        assert_eq!(
+            vec![
+                Definition(
+                    "Located".to_string(),
+                    Type::WithComponents(
+                        Box::new(Type::TypeReference("Report".to_string(), None)),
+                        InnerTypeConstraints {
+                            partial: true,
+                            components: vec![
+                                ComponentConstraint {
+                                    name: "position".to_string(),
+                                    value: None,
+                                    presence: Some(Presence::Present),
+                                },
+                                ComponentConstraint {
+                                    name: "speed".to_string(),
+                                    value: None,
+                                    presence: Some(Presence::Absent),
+                                },
+                            ],
+                            element: None,
+                        }
+                    )
+                    .untagged(),
+                ),
+                Definition(
+                    "Ground".to_string(),
+                    Type::WithComponents(
+                        Box::new(Type::TypeReference("Report".to_string(), None)),
+                        InnerTypeConstraints {
+                            partial: false,
+                            components: vec![
+                                ComponentConstraint {
+                                    name: "id".to_string(),
+                                    value: Some(ValueConstraint::Integer(ElementSet::Union(vec![
+                                        ElementSet::Value(LitOrRef::Lit(1)),
+                                        ElementSet::Value(LitOrRef::Lit(3)),
+                                    ]))),
+                                    presence: None,
+                                },
+                                ComponentConstraint {
+                                    name: "position".to_string(),
+                                    value: Some(ValueConstraint::Components(
+                                        InnerTypeConstraints {
+                                            partial: true,
+                                            components: vec![ComponentConstraint {
+                                                name: "altitude".to_string(),
+                                                value: None,
+                                                presence: Some(Presence::Absent),
+                                            }],
+                                            element: None,
+                                        }
+                                    )),
+                                    presence: Some(Presence::Optional),
+                                },
+                            ],
+                            element: None,
+                        }
+                    )
+                    .untagged(),
+                ),
+                Definition(
+                    "Small".to_string(),
+                    Type::WithComponents(
+                        Box::new(Type::TypeReference("Readings".to_string(), None)),
+                        InnerTypeConstraints::for_elements(ValueConstraint::Integer(
+                            ElementSet::Range(Some(LitOrRef::Lit(0)), Some(LitOrRef::Lit(100)))
+                        ))
+                    )
+                    .untagged(),
+                ),
+            ],
+            model.definitions
+        );
+    }
+
+    #[test]
+    pub fn test_parsing_components_of() {
+        let model = Model::try_from(
+            Tokenizer
+                .parse(
+                    r"SomeName DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                Message ::= SEQUENCE {
+                    COMPONENTS OF Header,
+                    payload INTEGER,
+                    COMPONENTS OF SEQUENCE { checksum INTEGER },
+                    ...
+                }
+                END",
+                )
+                .unwrap(),
+        )
+        .expect("Failed to load model");
+        assert_eq!(
+            vec![Definition(
+                "Message".to_string(),
+                Type::Sequence(ComponentTypeList {
+                    fields: vec![Field {
+                        name: "payload".into(),
+                        role: Type::unconstrained_integer().untagged(),
+                    }],
+                    extension_after: Some(0),
+                    unknown_extensions: None,
+                    components_of: vec![
+                        (0, Type::TypeReference("Header".to_string(), None)),
+                        (
+                            1,
+                            Type::sequence_from_fields(vec![Field {
+                                name: "checksum".into(),
+                                role: Type::unconstrained_integer().untagged(),
+                            }])
+                        ),
+                    ],
+                })
+                .untagged(),
+            )],
+            model.definitions
+        );
+
+        // components can only be included in the root
+        let error = Model::try_from(
+            Tokenizer
+                .parse(
+                    r"SomeName DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                Message ::= SEQUENCE {
+                    payload INTEGER,
+                    ...,
+                    COMPONENTS OF Header
+                }
+                END",
+                )
+                .unwrap(),
+        )
+        .expect_err("Included components in the extension additions");
+        assert_eq!(Some("COMPONENTS"), error.token().and_then(Token::text));
+    }
+
+    #[test]
+    pub fn test_parsing_contents_constraints() {
+        let model = Model::try_from(Tokenizer.parse(
+            r"SomeName DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                Wrapped ::= OCTET STRING (CONTAINING Report)
+                Signed ::= BIT STRING (CONTAINING INTEGER (0..255))
+                Foreign ::= OCTET STRING (CONTAINING Report ENCODED BY { joint-iso-itu-t asn1(1) ber(1) })
+                END",
+        ).unwrap())
+        .expect("Failed to load model");
+        assert_eq!(
+            vec![
+                Definition(
+                    "Wrapped".to_string(),
+                    Type::Containing(
+                        Box::new(Type::TypeReference("Report".to_string(), None)),
+                        Container::OctetString
+                    )
+                    .untagged(),
+                ),
+                Definition(
+                    "Signed".to_string(),
+                    Type::Containing(
+                        Box::new(Type::integer_with_range(Range::inclusive(
+                            Some(0),
+                            Some(255)
+                        ))),
+                        Container::BitString
+                    )
+                    .untagged(),
+                ),
+                // the contents are encoded with other encoding rules and are therefore opaque
+                Definition(
+                    "Foreign".to_string(),
+                    Type::OctetString(Size::Any).untagged(),
+                ),
+            ],
+            model.definitions
+        );
+    }
+
+    #[test]
+    pub fn test_parsing_module_definition_with_permitted_alphabet() {
+        let model = Model::try_from(
+            Tokenizer
+                .parse(
+                    r#"SomeName DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                Hex ::= IA5String (FROM("0".."9" | "a".."f" | "A".."F"))
+                Pin ::= NumericString (SIZE(4) ^ FROM("0".."9"))
+                Code ::= PrintableString (SIZE(1..3)) (FROM("CAB" UNION "*"))
+                Loose ::= VisibleString (FROM("a".."z", ...))
+                END"#,
+                )
+                .unwrap(),
+        )
+        .expect("Failed to load model");
+        assert_eq!(
+            vec![
+                Definition(
+                    "Hex".to_string(),
+                    Type::String(
+                        Size::Any,
+                        Charset::Ia5,
+                        Some(PermittedAlphabet::new(vec![
+                            (0x30, 0x39),
+                            (0x41, 0x46),
+                            (0x61, 0x66)
+                        ]))
+                    )
+                    .untagged(),
+                ),
+                Definition(
+                    "Pin".to_string(),
+                    Type::String(
+                        Size::Fix(LitOrRef::Lit(4), false),
+                        Charset::Numeric,
+                        Some(PermittedAlphabet::new(vec![(0x30, 0x39)]))
+                    )
+                    .untagged(),
+                ),
+                // '*' is not a printable character
+                Definition(
+                    "Code".to_string(),
+                    Type::String(
+                        Size::Range(LitOrRef::Lit(1), LitOrRef::Lit(3), false),
+                        Charset::Printable,
+                        Some(PermittedAlphabet::new(vec![(0x41, 0x43)]))
+                    )
+                    .untagged(),
+                ),
+                Definition(
+                    "Loose".to_string(),
+                    Type::String(Size::Any, Charset::Visible, None).untagged(),
+                ),
+            ],
+            model.definitions
+        )
+    }
+
+    #[test]
+    pub fn test_parsing_permitted_alphabet_with_invalid_range() {
+        assert_eq!(
+            Error::invalid_permitted_alphabet(Token::CString(
+                Location::at(2, 46),
+                "99".to_string()
+            )),
+            Model::try_from(
+                Tokenizer
+                    .parse(
+                        r#"SomeName DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                Hex ::= IA5String (FROM("0".."99"))
+                END"#,
+                    )
+                    .unwrap()
+            )
+            .expect_err("Parsed invalid range")
+        );
+    }
+
+    #[test]
+    pub fn test_parsing_null_real_and_object_identifiers() {
+        let model = Model::try_from(
+            Tokenizer
+                .parse(
+                    r#"SomeName DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                Nothing ::= NULL
+                Number ::= REAL
+                Oid ::= OBJECT IDENTIFIER
+                Relative ::= RELATIVE-OID
+                END"#,
+                )
+                .unwrap(),
+        )
+        .expect("Failed to load model");
+        assert_eq!(
+            vec![
+                Definition("Nothing".to_string(), Type::Null.untagged()),
+                Definition("Number".to_string(), Type::Real.untagged()),
+                Definition("Oid".to_string(), Type::ObjectIdentifier.untagged()),
+                Definition("Relative".to_string(), Type::RelativeOid.untagged()),
+            ],
+            model.definitions
+        );
+        assert_eq!(
+            vec![
+                Tag::DEFAULT_NULL,
+                Tag::DEFAULT_REAL,
+                Tag::DEFAULT_OBJECT_IDENTIFIER,
+                Tag::DEFAULT_RELATIVE_OID,
+            ],
+            model
+                .definitions
+                .iter()
+                .map(|d| TagResolver::resolve_default(&d.1.r#type).unwrap())
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    pub fn test_parsing_object_without_identifier() {
+        assert_eq!(
+            Error::unexpected_token(Token::Identifier(Location::at(2, 32), "NAME".to_string())),
+            Model::try_from(
+                Tokenizer
+                    .parse(
+                        r#"SomeName DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                Oid ::= OBJECT NAME
+                END"#,
+                    )
+                    .unwrap()
+            )
+            .expect_err("Parsed OBJECT without IDENTIFIER")
+        );
+    }
+
+    #[test]
+    pub fn test_parsing_time_types() {
+        let model = Model::try_from(
+            Tokenizer
+                .parse(
+                    r#"SomeName DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                Utc ::= UTCTime
+                Generalized ::= GeneralizedTime
+                Day ::= DATE
+                Clock ::= TIME-OF-DAY
+                Moment ::= DATE-TIME
+                Period ::= DURATION
+                END"#,
+                )
+                .unwrap(),
+        )
+        .expect("Failed to load model");
+        let times = [
+            TimeType::UtcTime,
+            TimeType::GeneralizedTime,
+            TimeType::Date,
+            TimeType::TimeOfDay,
+            TimeType::DateTime,
+            TimeType::Duration,
+        ];
+        assert_eq!(
+            times
+                .iter()
+                .map(|time| Type::Time(*time).untagged())
+                .collect::<Vec<_>>(),
+            model
+                .definitions
+                .iter()
+                .map(|d| d.1.clone())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![
+                Tag::Universal(23),
+                Tag::Universal(24),
+                Tag::Universal(31),
+                Tag::Universal(32),
+                Tag::Universal(33),
+                Tag::Universal(34),
+            ],
+            model
+                .definitions
+                .iter()
+                .map(|d| TagResolver::resolve_default(&d.1.r#type).unwrap())
+                .collect::<Vec<_>>()
+        );
+    }
+
     #[test]
     pub fn test_resolve_tag() {
-        let external = Model::try_from(Tokenizer::default().parse(
-            r"ExternalModule DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+        let external = Model::try_from(
+            Tokenizer::default()
+                .parse(
+                    r"ExternalModule DEFINITIONS AUTOMATIC TAGS ::= BEGIN
             External ::= [APPLICATION 1] INTEGER
             END
             ",
-        ))
+                )
+                .unwrap(),
+        )
         .expect("Failed to parse module");
-        let model = Model::try_from(Tokenizer::default().parse(
-            r"InternalModul DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+        let model = Model::try_from(
+            Tokenizer::default()
+                .parse(
+                    r"InternalModul DEFINITIONS AUTOMATIC TAGS ::= BEGIN
                 IMPORTS
                     External
                 FROM ExternalModule;
@@ -2608,7 +4851,9 @@ pub(crate) mod tests {
                 }
                 
                 END",
-        ))
+                )
+                .unwrap(),
+        )
         .expect("Failed to load model");
         let rust = model.to_rust_with_scope(&[&external]);
 
@@ -2617,11 +4862,15 @@ pub(crate) mod tests {
             fields,
             tag,
             extension_after: _,
+            unknown_extensions: _,
+            explicit: _,
         } = rust.definitions[0].value()
         {
             assert_eq!("Implicit", rust.definitions[0].0.as_str());
             assert_eq!(None, *tag); // None because default
-            assert_eq!(None, fields[0].tag()); // None because default
+                                    // automatic tagging is not applied, because the other field is tagged already (ITU-T
+                                    // X.680 | ISO/IEC 8824-1, 25.3), so the untagged field keeps the tag of INTEGER
+            assert_eq!(Some(Tag::DEFAULT_INTEGER), fields[0].tag());
             assert_eq!(Some(Tag::ContextSpecific(4)), fields[1].tag()); // explicitly set
         } else {
             panic!("Expected Rust::Struct for ASN.1 SEQUENCE");
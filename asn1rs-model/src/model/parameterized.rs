@@ -0,0 +1,373 @@
+//! Parameterized types as described in ITU-T X.683 | ISO/IEC 8824-4:2015
+//!
+//! Parameterized type assignments (`Container{ItemType} ::= SEQUENCE OF ItemType`) are removed
+//! from the token stream and each instantiation (`Container{Item}`) is replaced by the type of
+//! the assignment, in which the dummy references are substituted by the actual parameters. This
+//! happens before the tokens are parsed into a [`Model`], so the expanded types are parsed like
+//! any other type.
+
+use crate::model::{Asn, Error, Model};
+use crate::parser::Token;
+use std::collections::HashMap;
+
+/// Instantiations that are nested deeper than this are considered to be infinitely recursive
+const MAX_INSTANTIATION_DEPTH: usize = 32;
+
+struct ParameterizedType {
+    parameters: Vec<String>,
+    r#type: Vec<Token>,
+}
+
+/// The location of a parameterized type assignment in the token stream
+struct Assignment {
+    /// The index of the name of the type
+    start: usize,
+    /// The index of the `::=`
+    assignment: usize,
+    parameters: Vec<String>,
+}
+
+impl Model<Asn> {
+    pub(crate) fn expand_parameterized_types(tokens: Vec<Token>) -> Result<Vec<Token>, Error> {
+        let assignments = Self::find_parameterized_assignments(&tokens);
+        if assignments.is_empty() {
+            return Ok(tokens);
+        }
+
+        let names = assignments
+            .iter()
+            .filter_map(|a| tokens[a.start].text())
+            .collect::<Vec<_>>();
+        let (skeleton, origin) = Self::without_actual_parameters(&tokens, &names, &assignments);
+
+        let mut types = HashMap::with_capacity(assignments.len());
+        let mut ranges = Vec::with_capacity(assignments.len());
+        for assignment in assignments {
+            let end =
+                Self::end_of_parameterized_assignment(&tokens, &skeleton, &origin, &assignment)?;
+            types.insert(
+                tokens[assignment.start]
+                    .text()
+                    .unwrap_or_default()
+                    .to_string(),
+                ParameterizedType {
+                    parameters: assignment.parameters,
+                    r#type: tokens[assignment.assignment + 1..end].to_vec(),
+                },
+            );
+            ranges.push(assignment.start..end);
+        }
+
+        let remaining = tokens
+            .into_iter()
+            .enumerate()
+            .filter(|(index, _)| !ranges.iter().any(|range| range.contains(index)))
+            .map(|(_, token)| token)
+            .collect::<Vec<_>>();
+
+        Self::instantiate(remaining, &types, 0)
+    }
+
+    /// ITU-T X.683 | ISO/IEC 8824-4:2015, 8.2: `Name{DummyReference, ...} ::= Type`
+    fn find_parameterized_assignments(tokens: &[Token]) -> Vec<Assignment> {
+        let mut assignments = Vec::new();
+        for start in 0..tokens.len().saturating_sub(1) {
+            if tokens[start].is_text() && tokens[start + 1].eq_separator('{') {
+                let close = Self::index_of_closing_bracket(tokens, start + 1);
+                let assignment = close + 1;
+                let is_assignment =
+                    matches!(tokens.get(assignment), Some(token) if token.eq_symbol("::="));
+
+                if is_assignment {
+                    let parameters = Self::split_at_commas(&tokens[start + 2..close])
+                        .into_iter()
+                        // a parameter with a governor (`INTEGER : size`) is named after the colon
+                        .filter_map(|parameter| {
+                            parameter
+                                .last()
+                                .and_then(Token::text)
+                                .map(ToString::to_string)
+                        })
+                        .collect();
+                    assignments.push(Assignment {
+                        start,
+                        assignment,
+                        parameters,
+                    });
+                }
+            }
+        }
+        assignments
+    }
+
+    /// Returns the tokens without the actual parameters of instantiations of the given types and
+    /// for each remaining token its index in the given tokens
+    fn without_actual_parameters(
+        tokens: &[Token],
+        names: &[&str],
+        assignments: &[Assignment],
+    ) -> (Vec<Token>, Vec<usize>) {
+        let mut skeleton = Vec::with_capacity(tokens.len());
+        let mut origin = Vec::with_capacity(tokens.len());
+        let mut index = 0;
+        while index < tokens.len() {
+            skeleton.push(tokens[index].clone());
+            origin.push(index);
+
+            let is_instantiation = tokens[index].test_text(|t| names.contains(&t))
+                && matches!(tokens.get(index + 1), Some(t) if t.eq_separator('{'))
+                && !assignments.iter().any(|a| a.start == index);
+
+            if is_instantiation {
+                index = Self::index_of_closing_bracket(tokens, index + 1) + 1;
+            } else {
+                index += 1;
+            }
+        }
+        (skeleton, origin)
+    }
+
+    /// Parses the type of the given assignment to find the index of the first token after it
+    fn end_of_parameterized_assignment(
+        tokens: &[Token],
+        skeleton: &[Token],
+        origin: &[usize],
+        assignment: &Assignment,
+    ) -> Result<usize, Error> {
+        let offset = origin
+            .iter()
+            .position(|index| *index == assignment.assignment)
+            .unwrap_or_default();
+        let mut iter = Vec::from(&skeleton[offset..]).into_iter().peekable();
+        let _ = Self::read_definition(&mut iter, String::default())?;
+        let end = skeleton.len() - iter.len();
+        Ok(origin.get(end).copied().unwrap_or(tokens.len()))
+    }
+
+    fn instantiate(
+        tokens: Vec<Token>,
+        types: &HashMap<String, ParameterizedType>,
+        depth: usize,
+    ) -> Result<Vec<Token>, Error> {
+        let mut result = Vec::with_capacity(tokens.len());
+        let mut index = 0;
+        while index < tokens.len() {
+            let parameterized = tokens[index]
+                .text()
+                .and_then(|name| types.get(name))
+                .filter(|_| matches!(tokens.get(index + 1), Some(t) if t.eq_separator('{')));
+
+            if let Some(parameterized) = parameterized {
+                if depth >= MAX_INSTANTIATION_DEPTH {
+                    return Err(Error::recursive_parameterized_type(tokens[index].clone()));
+                }
+
+                let close = Self::index_of_closing_bracket(&tokens, index + 1);
+                let actual = Self::split_at_commas(&tokens[index + 2..close]);
+                if actual.len() != parameterized.parameters.len() {
+                    return Err(Error::invalid_number_of_parameters(tokens[index].clone()));
+                }
+
+                let mut r#type = Vec::with_capacity(parameterized.r#type.len());
+                for token in &parameterized.r#type {
+                    match parameterized
+                        .parameters
+                        .iter()
+                        .position(|dummy| token.eq_text(dummy))
+                    {
+                        Some(position) => r#type.extend(actual[position].iter().cloned()),
+                        None => r#type.push(token.clone()),
+                    }
+                }
+
+                result.extend(Self::instantiate(r#type, types, depth + 1)?);
+                index = close + 1;
+            } else {
+                result.push(tokens[index].clone());
+                index += 1;
+            }
+        }
+        Ok(result)
+    }
+
+    /// Returns the index of the bracket closing the one at the given index or the length of the
+    /// tokens if it is not closed
+    fn index_of_closing_bracket(tokens: &[Token], open: usize) -> usize {
+        let mut depth = 0_usize;
+        for (index, token) in tokens.iter().enumerate().skip(open) {
+            match token.separator() {
+                Some('{') | Some('(') | Some('[') => depth += 1,
+                Some('}') | Some(')') | Some(']') => {
+                    depth = depth.saturating_sub(1);
+                    if depth == 0 {
+                        return index;
+                    }
+                }
+                _ => {}
+            }
+        }
+        tokens.len()
+    }
+
+    /// Splits the given tokens at the commas that are not enclosed by brackets
+    fn split_at_commas(tokens: &[Token]) -> Vec<Vec<Token>> {
+        let mut parts = Vec::new();
+        let mut part = Vec::new();
+        let mut depth = 0_usize;
+        for token in tokens {
+            match token.separator() {
+                Some(',') if depth == 0 => {
+                    parts.push(std::mem::take(&mut part));
+                    continue;
+                }
+                Some('{') | Some('(') | Some('[') => depth += 1,
+                Some('}') | Some(')') | Some(']') => depth = depth.saturating_sub(1),
+                _ => {}
+            }
+            part.push(token.clone());
+        }
+        if !part.is_empty() || !parts.is_empty() {
+            parts.push(part);
+        }
+        parts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::tests::*;
+    use crate::model::*;
+    use crate::parser::{Location, Tokenizer};
+
+    #[test]
+    fn test_instantiations_are_expanded() {
+        let model = Model::try_from(
+            Tokenizer
+                .parse(
+                    r"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+            Numbers ::= Container{INTEGER (0..255), 4}
+
+            Container{ItemType, INTEGER : maxItems} ::= SEQUENCE (SIZE(1..maxItems)) OF ItemType
+
+            Pair{First, Second} ::= SEQUENCE {
+                first First,
+                second Second
+            }
+
+            Message ::= SEQUENCE {
+                names Container{UTF8String, 2},
+                pair Pair{BOOLEAN, Container{Other, 3}}
+            }
+
+            END",
+                )
+                .unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            vec![
+                Definition(
+                    "Numbers".to_string(),
+                    Type::SequenceOf(
+                        Box::new(Type::integer_with_range(Range::inclusive(
+                            Some(0),
+                            Some(255)
+                        ))),
+                        Size::Range(LitOrRef::Lit(1), LitOrRef::Lit(4), false),
+                    )
+                    .untagged(),
+                ),
+                Definition(
+                    "Message".to_string(),
+                    Type::sequence_from_fields(vec![
+                        Field {
+                            name: "names".to_string(),
+                            role: Type::SequenceOf(
+                                Box::new(Type::unconstrained_utf8string()),
+                                Size::Range(LitOrRef::Lit(1), LitOrRef::Lit(2), false),
+                            )
+                            .untagged(),
+                        },
+                        Field {
+                            name: "pair".to_string(),
+                            role: Type::sequence_from_fields(vec![
+                                Field {
+                                    name: "first".to_string(),
+                                    role: Type::Boolean.untagged(),
+                                },
+                                Field {
+                                    name: "second".to_string(),
+                                    role: Type::SequenceOf(
+                                        Box::new(Type::TypeReference("Other".to_string(), None)),
+                                        Size::Range(LitOrRef::Lit(1), LitOrRef::Lit(3), false),
+                                    )
+                                    .untagged(),
+                                },
+                            ])
+                            .untagged(),
+                        },
+                    ])
+                    .untagged(),
+                ),
+            ],
+            model.definitions
+        );
+    }
+
+    #[test]
+    fn test_invalid_number_of_parameters() {
+        assert_eq!(
+            Error::invalid_number_of_parameters(Token::Identifier(
+                Location::at(4, 29),
+                "Container".to_string()
+            )),
+            Model::try_from(
+                Tokenizer
+                    .parse(
+                        r"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+                Container{ItemType} ::= SEQUENCE OF ItemType
+                Numbers ::= Container{INTEGER, BOOLEAN}
+
+                END",
+                    )
+                    .unwrap()
+            )
+            .expect_err("Parsed invalid definition")
+        );
+    }
+
+    #[test]
+    fn test_recursive_parameterized_type() {
+        assert_eq!(
+            Error::recursive_parameterized_type(Token::Identifier(
+                Location::at(3, 63),
+                "Tree".to_string()
+            )),
+            Model::try_from(
+                Tokenizer
+                    .parse(
+                        r"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+                Tree{Leaf} ::= SEQUENCE { leaf Leaf, children Tree{Leaf} }
+                Numbers ::= Tree{INTEGER}
+
+                END",
+                    )
+                    .unwrap()
+            )
+            .expect_err("Parsed invalid definition")
+        );
+    }
+
+    #[test]
+    fn test_without_parameterized_types_nothing_changes() {
+        let tokens = Tokenizer.parse(SIMPLE_INTEGER_STRUCT_ASN).unwrap();
+        assert_eq!(
+            tokens.clone(),
+            Model::expand_parameterized_types(tokens).unwrap()
+        );
+    }
+}
@@ -16,9 +16,12 @@ pub enum ProtobufType {
     UInt64,
     SInt32,
     SInt64,
+    Double,
     String,
     Bytes,
     BitsReprByBytesAndBitsLen,
+    /// `google.protobuf.Empty`
+    Empty,
     Repeated(Box<ProtobufType>),
     OneOf(Vec<(String, ProtobufType)>),
     /// Indicates a complex, custom type that is
@@ -36,14 +39,20 @@ impl ProtobufType {
         match self {
             ProtobufType::Bool => RustType::Bool,
             ProtobufType::SFixed32 => RustType::I32(Range::inclusive(0, i32::max_value())),
-            ProtobufType::SFixed64 => RustType::I64(Range::inclusive(0, i64::max_value())),
+            ProtobufType::SFixed64 => {
+                RustType::I64(Range::inclusive(Some(0), Some(i64::max_value())))
+            }
             ProtobufType::UInt32 => RustType::U32(Range::inclusive(0, u32::max_value())),
             ProtobufType::UInt64 => RustType::U64(Range::none()),
             ProtobufType::SInt32 => RustType::I32(Range::inclusive(0, i32::max_value())),
-            ProtobufType::SInt64 => RustType::I64(Range::inclusive(0, i64::max_value())),
-            ProtobufType::String => RustType::String(Size::Any, Charset::Utf8),
+            ProtobufType::SInt64 => {
+                RustType::I64(Range::inclusive(Some(0), Some(i64::max_value())))
+            }
+            ProtobufType::Double => RustType::F64,
+            ProtobufType::String => RustType::String(Size::Any, Charset::Utf8, None),
             ProtobufType::Bytes => RustType::VecU8(Size::Any),
             ProtobufType::BitsReprByBytesAndBitsLen => RustType::BitVec(Size::Any),
+            ProtobufType::Empty => RustType::Null,
             ProtobufType::Repeated(inner) => {
                 RustType::Vec(Box::new(inner.to_rust()), Size::Any, EncodingOrdering::Keep)
             }
@@ -62,13 +71,25 @@ impl ProtobufType {
             ProtobufType::UInt64 => true,
             ProtobufType::SInt32 => true,
             ProtobufType::SInt64 => true,
+            ProtobufType::Double => true,
             ProtobufType::String => true,
             ProtobufType::Bytes | ProtobufType::BitsReprByBytesAndBitsLen => true,
+            ProtobufType::Empty => true,
             ProtobufType::OneOf(_) => false,
             ProtobufType::Complex(_) => false,
             ProtobufType::Repeated(_) => false,
         }
     }
+
+    /// Whether this type refers to `google.protobuf.Empty`, which needs to be imported
+    pub fn uses_empty(&self) -> bool {
+        match self {
+            ProtobufType::Empty => true,
+            ProtobufType::Repeated(inner) => inner.uses_empty(),
+            ProtobufType::OneOf(variants) => variants.iter().any(|(_, r#type)| r#type.uses_empty()),
+            _ => false,
+        }
+    }
 }
 
 impl ToString for ProtobufType {
@@ -81,9 +102,11 @@ impl ToString for ProtobufType {
             ProtobufType::UInt64 => "uint64",
             ProtobufType::SInt32 => "sint32",
             ProtobufType::SInt64 => "sint64",
+            ProtobufType::Double => "double",
             ProtobufType::String => "string",
             ProtobufType::Bytes => "bytes",
             ProtobufType::BitsReprByBytesAndBitsLen => "bit_vec",
+            ProtobufType::Empty => "empty",
             ProtobufType::OneOf(_) => "oneof",
             ProtobufType::Complex(name) => return name.clone(),
             ProtobufType::Repeated(name) => return format!("repeated {}", name.to_string()),
@@ -108,13 +131,33 @@ pub enum Protobuf {
     Enum(Vec<String>),
 }
 
+impl Protobuf {
+    /// Whether any field refers to `google.protobuf.Empty`, which needs to be imported
+    pub fn uses_empty(&self) -> bool {
+        match self {
+            Protobuf::Message(fields) => fields.iter().any(|(_, r#type)| r#type.uses_empty()),
+            Protobuf::Enum(_) => false,
+        }
+    }
+}
+
+impl Target for Protobuf {
+    type ValueReferenceType = ProtobufType;
+}
+
 impl Model<Protobuf> {
     pub fn convert_rust_to_protobuf(rust_model: &Model<Rust>) -> Model<Protobuf> {
         let mut model = Model {
             name: rust_model.name.clone(),
             oid: rust_model.oid.clone(),
+            tag_default: rust_model.tag_default,
+            extensibility_implied: rust_model.extensibility_implied,
             imports: rust_model.imports.clone(),
             definitions: Vec::with_capacity(rust_model.definitions.len()),
+            value_references: Vec::default(), // not supported in protobuf
+            classes: Vec::default(),
+            objects: Vec::default(),
+            object_sets: Vec::default(),
         };
         for Definition(name, rust) in &rust_model.definitions {
             let proto = Self::definition_to_protobuf(rust);
@@ -131,7 +174,9 @@ impl Model<Protobuf> {
                 fields,
                 tag: _,
                 extension_after: _,
+                unknown_extensions: _,
                 ordering: _,
+                explicit: _,
             } => {
                 let mut proto_fields = Vec::with_capacity(fields.len());
                 for field in fields.iter() {
@@ -143,10 +188,13 @@ impl Model<Protobuf> {
 
                 Protobuf::Message(proto_fields)
             }
-            Rust::Enum(r_enum) => {
-                Protobuf::Enum(r_enum.variants().map(|v| proto_variant_name(v)).collect())
-            }
-            Rust::DataEnum(enumeration) => {
+            Rust::Enum(r_enum) => Protobuf::Enum(
+                r_enum
+                    .variants()
+                    .map(|v| proto_variant_name(v.name()))
+                    .collect(),
+            ),
+            Rust::DataEnum(enumeration) | Rust::OpenType(enumeration) => {
                 let mut proto_enum = Vec::with_capacity(enumeration.len());
                 for variant in enumeration.variants() {
                     proto_enum.push((
@@ -178,9 +226,23 @@ impl Model<Protobuf> {
             RustType::I32(_) => ProtobufType::SInt32,
             RustType::U64(_) => ProtobufType::UInt64,
             RustType::I64(_) => ProtobufType::SInt64,
-            RustType::String(..) => ProtobufType::String,
+            // in their decimal notation
+            RustType::I128(_) | RustType::U128(_) | RustType::BigInt(_) => ProtobufType::String,
+            RustType::Constrained(inner, _)
+            | RustType::WithComponents(inner, _)
+            | RustType::Containing(inner, _)
+            | RustType::Explicit(inner)
+            | RustType::Boxed(inner) => Self::definition_type_to_protobuf_type(inner),
+            RustType::String(..) | RustType::Time(_) => ProtobufType::String,
             RustType::VecU8(_) => ProtobufType::Bytes,
             RustType::BitVec(_) => ProtobufType::BitsReprByBytesAndBitsLen,
+            RustType::Null => ProtobufType::Empty,
+            RustType::F64 => ProtobufType::Double,
+            RustType::ObjectIdentifier | RustType::RelativeOid => {
+                Self::definition_type_to_protobuf_type(
+                    &rust_type.clone().with_object_identifiers_as_vec(),
+                )
+            }
 
             RustType::Complex(complex, _) => ProtobufType::Complex(complex.clone()),
 
@@ -292,7 +354,7 @@ mod tests {
                 "SuchStruct".into(),
                 Rust::struct_from_fields(vec![Field::from_name_type(
                     "very_optional",
-                    RustType::Option(Box::new(RustType::String(Size::Any, Charset::Utf8))),
+                    RustType::Option(Box::new(RustType::String(Size::Any, Charset::Utf8, None))),
                 )]),
             )],
             &[Definition(
@@ -310,7 +372,7 @@ mod tests {
                 Rust::DataEnum(
                     vec![DataVariant::from_name_type(
                         "MuchVariant",
-                        RustType::String(Size::Any, Charset::Utf8),
+                        RustType::String(Size::Any, Charset::Utf8, None),
                     )]
                     .into(),
                 ),
@@ -325,6 +387,31 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_null_real_and_object_identifier_to_protobuf() {
+        test_model_definition_conversion(
+            &[Definition(
+                "Measurement".into(),
+                Rust::struct_from_fields(vec![
+                    rust::Field::from_name_type("marker", RustType::Null),
+                    rust::Field::from_name_type("value", RustType::F64),
+                    rust::Field::from_name_type("unit", RustType::ObjectIdentifier),
+                ]),
+            )],
+            &[Definition(
+                "Measurement".into(),
+                Protobuf::Message(vec![
+                    ("marker".into(), ProtobufType::Empty),
+                    ("value".into(), ProtobufType::Double),
+                    (
+                        "unit".into(),
+                        ProtobufType::Repeated(Box::new(ProtobufType::UInt64)),
+                    ),
+                ]),
+            )],
+        );
+    }
+
     #[test]
     fn test_multiple_rust_defs_to_protobuf() {
         test_model_definition_conversion(
@@ -0,0 +1,99 @@
+//! The boxing of recursive types
+//!
+//! A type that contains itself - directly or through other types - has an infinite size in rust.
+//! After the conversion to rust, the references between the definitions of a model are therefore
+//! searched for cycles and each cycle is broken by boxing the component that closes it. The
+//! elements of a `Vec` are stored on the heap already and never close a cycle. The values of open
+//! types are not boxed.
+
+use crate::model::rust::{Rust, RustType};
+use crate::model::{Definition, Model};
+use std::collections::HashMap;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Visit {
+    Pending,
+    Active,
+    Done,
+}
+
+impl Model<Rust> {
+    /// Boxes the components that close a cycle of references between the definitions
+    pub(crate) fn box_recursive_components(&mut self) {
+        let indices = self
+            .definitions
+            .iter()
+            .enumerate()
+            .map(|(index, Definition(name, _))| (name.clone(), index))
+            .collect::<HashMap<_, _>>();
+        // the definition that is referenced by each boxable component of each definition
+        let references = self
+            .definitions
+            .iter_mut()
+            .map(|Definition(_, rust)| {
+                rust.boxable_types_mut()
+                    .into_iter()
+                    .map(|r#type| {
+                        referenced_definition(r#type).and_then(|name| indices.get(name).copied())
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let mut visits = vec![Visit::Pending; references.len()];
+        let mut closing = Vec::new();
+        for index in 0..references.len() {
+            find_closing_components(index, &references, &mut visits, &mut closing);
+        }
+
+        for (index, component) in closing {
+            if let Some(r#type) = self.definitions[index]
+                .1
+                .boxable_types_mut()
+                .into_iter()
+                .nth(component)
+            {
+                *r#type = r#type.clone().into_boxed();
+            }
+        }
+    }
+}
+
+/// Searches the definitions depth-first for the components that reference a definition which is
+/// still being visited
+fn find_closing_components(
+    index: usize,
+    references: &[Vec<Option<usize>>],
+    visits: &mut [Visit],
+    closing: &mut Vec<(usize, usize)>,
+) {
+    if visits[index] != Visit::Pending {
+        return;
+    }
+    visits[index] = Visit::Active;
+    for (component, target) in references[index].iter().enumerate() {
+        if let Some(target) = *target {
+            match visits[target] {
+                Visit::Pending => find_closing_components(target, references, visits, closing),
+                Visit::Active => closing.push((index, component)),
+                Visit::Done => {}
+            }
+        }
+    }
+    visits[index] = Visit::Done;
+}
+
+/// The name of the definition whose value is stored inline in a value of the given type
+fn referenced_definition(r#type: &RustType) -> Option<&str> {
+    match r#type {
+        RustType::Complex(name, _) => Some(name),
+        RustType::Option(inner)
+        | RustType::Constrained(inner, _)
+        | RustType::WithComponents(inner, _)
+        | RustType::Containing(inner, _)
+        | RustType::Explicit(inner) => referenced_definition(inner),
+        // the values of all other types - including the elements of a `Vec` and boxed values -
+        // are either primitive or stored on the heap
+        _ => None,
+    }
+}
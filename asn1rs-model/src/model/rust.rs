@@ -1,12 +1,17 @@
+use crate::model::constraint::{ElementSet, InnerTypeConstraints};
+use crate::model::information_object::OpenTypeVariant;
 use crate::model::rust::Field as RustField;
 use crate::model::ComponentTypeList;
 use crate::model::Context;
 use crate::model::{Asn, ChoiceVariant};
-use crate::model::{Charset, Range};
-use crate::model::{Definition, Type};
-use crate::model::{Import, Tag, TagProperty};
-use crate::model::{Model, Size};
+use crate::model::{
+    Charset, Container, LitOrRef, LiteralValue, PermittedAlphabet, Range, TimeType,
+};
+use crate::model::{Definition, Error, Type};
+use crate::model::{Import, Integer, Tag, TagProperty, Tagging};
+use crate::model::{Model, Size, Target, ValueReference};
 use crate::model::{TagResolver, Type as AsnType};
+use std::convert::TryFrom;
 
 const I8_MAX: i64 = i8::max_value() as i64;
 const I16_MAX: i64 = i16::max_value() as i64;
@@ -18,7 +23,6 @@ const U16_MAX: u64 = u16::max_value() as u64;
 const U32_MAX: u64 = u32::max_value() as u64;
 //const U64_MAX: u64 = u64::max_value() as u64;
 
-pub type PlainVariant = String;
 pub type PlainEnum = Enumeration<PlainVariant>;
 pub type DataEnum = Enumeration<DataVariant>;
 
@@ -36,11 +40,29 @@ pub enum RustType {
     U16(Range<u16>),
     I32(Range<i32>),
     U32(Range<u32>),
-    I64(Range<i64>),
+    I64(Range<Option<i64>>),
     U64(Range<Option<u64>>),
-    String(Size, Charset),
+    /// An `INTEGER` whose bounds exceed the 64 bit types
+    I128(Range<Option<i128>>),
+    /// A non-negative `INTEGER` whose bounds exceed the 64 bit types
+    U128(Range<Option<u128>>),
+    /// An unconstrained `INTEGER` of arbitrary precision, the flag tells whether it is extensible
+    BigInt(bool),
+    /// An `INTEGER` whose full constraint is not a single value range, of which the inner type
+    /// represents the effective constraint, see [`ElementSet::effective_range`]
+    Constrained(Box<RustType>, ElementSet<i128>),
+    String(Size, Charset, Option<PermittedAlphabet>),
     VecU8(Size),
     BitVec(Size),
+    /// `NULL`, represented by the unit type
+    Null,
+    F64,
+    /// The arcs of an `OBJECT IDENTIFIER`
+    ObjectIdentifier,
+    /// The arcs of a `RELATIVE-OID`
+    RelativeOid,
+    /// The value notation of a time type as `String`
+    Time(TimeType),
     Vec(Box<RustType>, Size, EncodingOrdering),
     Option(Box<RustType>),
 
@@ -49,6 +71,17 @@ pub enum RustType {
     /// thought of as a "ReferenceType"; declaring usage,
     /// but not being declared here
     Complex(String, Option<Tag>),
+    /// A [`RustType::Complex`] with an inner subtype constraint (`WITH COMPONENTS`), which is
+    /// validated when written and read
+    WithComponents(Box<RustType>, InnerTypeConstraints<i128>),
+    /// A value that is encoded into the contents of an `OCTET STRING` or `BIT STRING`
+    /// (`CONTAINING`)
+    Containing(Box<RustType>, Container),
+    /// A value whose tag - the tag of the component or definition - is explicit, which means that
+    /// the tag of the value itself is encoded as well
+    Explicit(Box<RustType>),
+    /// A value on the heap, which breaks the cycle of a recursive type
+    Boxed(Box<RustType>),
 }
 
 impl RustType {
@@ -56,7 +89,11 @@ impl RustType {
         if self.is_primitive() {
             return self;
         }
-        if let RustType::Vec(inner, ..) | RustType::Option(inner) = self {
+        if let RustType::Vec(inner, ..)
+        | RustType::Option(inner)
+        | RustType::Boxed(inner)
+        | RustType::WithComponents(inner, _) = self
+        {
             inner.as_inner_type()
         } else {
             self
@@ -67,7 +104,11 @@ impl RustType {
         if self.is_primitive() {
             return self;
         }
-        if let RustType::Vec(inner, ..) | RustType::Option(inner) = self {
+        if let RustType::Vec(inner, ..)
+        | RustType::Option(inner)
+        | RustType::Boxed(inner)
+        | RustType::WithComponents(inner, _) = self
+        {
             inner.into_inner_type()
         } else {
             self
@@ -78,7 +119,11 @@ impl RustType {
         if self.is_primitive() {
             return Some(self.to_string());
         }
-        if let RustType::Vec(inner, ..) | RustType::Option(inner) = self {
+        if let RustType::Vec(inner, ..)
+        | RustType::Option(inner)
+        | RustType::Boxed(inner)
+        | RustType::WithComponents(inner, _) = self
+        {
             inner.to_inner()
         } else {
             None
@@ -113,7 +158,25 @@ impl RustType {
     }
 
     pub fn is_primitive(&self) -> bool {
-        matches!(self, RustType::Bool | RustType::U8(_) | RustType::I8(_) | RustType::U16(_) | RustType::I16(_) | RustType::U32(_) | RustType::I32(_) | RustType::U64(_) | RustType::I64(_))
+        if let RustType::Constrained(inner, _) = self {
+            return inner.is_primitive();
+        }
+        matches!(
+            self,
+            RustType::Bool
+                | RustType::Null
+                | RustType::F64
+                | RustType::U8(_)
+                | RustType::I8(_)
+                | RustType::U16(_)
+                | RustType::I16(_)
+                | RustType::U32(_)
+                | RustType::I32(_)
+                | RustType::U64(_)
+                | RustType::I64(_)
+                | RustType::I128(_)
+                | RustType::U128(_)
+        )
     }
 
     pub fn integer_range_str(&self) -> Option<Range<String>> {
@@ -143,15 +206,37 @@ impl RustType {
                 max.unwrap_or_else(|| i64::max_value() as u64).to_string(),
                 *extensible,
             )),
-            RustType::I64(Range(min, max, extensible)) => {
-                Some(Range(min.to_string(), max.to_string(), *extensible))
-            }
+            RustType::I64(Range(min, max, extensible)) => Some(Range(
+                min.unwrap_or(i64::MIN).to_string(),
+                max.unwrap_or(i64::MAX).to_string(),
+                *extensible,
+            )),
+            RustType::I128(Range(min, max, extensible)) => Some(Range(
+                min.unwrap_or(i128::MIN).to_string(),
+                max.unwrap_or(i128::MAX).to_string(),
+                *extensible,
+            )),
+            RustType::U128(Range(min, max, extensible)) => Some(Range(
+                min.unwrap_or_default().to_string(),
+                max.unwrap_or(i128::MAX as u128).to_string(),
+                *extensible,
+            )),
+            RustType::BigInt(_) => None,
+            RustType::Constrained(inner, _) => inner.integer_range_str(),
             RustType::String(..) => None,
             RustType::VecU8(_) => None,
             RustType::BitVec(_) => None,
+            RustType::Null => None,
+            RustType::F64 => None,
+            RustType::ObjectIdentifier => None,
+            RustType::RelativeOid => None,
+            RustType::Time(_) => None,
             RustType::Vec(inner, _size, _ordering) => inner.integer_range_str(),
             RustType::Option(inner) => inner.integer_range_str(),
-            RustType::Complex(_, _) => None,
+            RustType::Complex(_, _) | RustType::WithComponents(..) => None,
+            RustType::Containing(..) => None,
+            RustType::Explicit(inner) => inner.integer_range_str(),
+            RustType::Boxed(inner) => inner.integer_range_str(),
         }
     }
 
@@ -159,54 +244,92 @@ impl RustType {
         match self {
             RustType::Bool => AsnType::Boolean,
             RustType::I8(Range(min, max, extensible)) => AsnType::integer_with_range(Range(
-                Some(i64::from(min)),
-                Some(i64::from(max)),
+                Some(i128::from(min)),
+                Some(i128::from(max)),
                 extensible,
             )),
             RustType::U8(Range(min, max, extensible)) => AsnType::integer_with_range(Range(
-                Some(i64::from(min)),
-                Some(i64::from(max)),
+                Some(i128::from(min)),
+                Some(i128::from(max)),
                 extensible,
             )),
             RustType::I16(Range(min, max, extensible)) => AsnType::integer_with_range(Range(
-                Some(i64::from(min)),
-                Some(i64::from(max)),
+                Some(i128::from(min)),
+                Some(i128::from(max)),
                 extensible,
             )),
             RustType::U16(Range(min, max, extensible)) => AsnType::integer_with_range(Range(
-                Some(i64::from(min)),
-                Some(i64::from(max)),
+                Some(i128::from(min)),
+                Some(i128::from(max)),
                 extensible,
             )),
             RustType::I32(Range(min, max, extensible)) => AsnType::integer_with_range(Range(
-                Some(i64::from(min)),
-                Some(i64::from(max)),
+                Some(i128::from(min)),
+                Some(i128::from(max)),
                 extensible,
             )),
             RustType::U32(Range(min, max, extensible)) => AsnType::integer_with_range(Range(
-                Some(i64::from(min)),
-                Some(i64::from(max)),
+                Some(i128::from(min)),
+                Some(i128::from(max)),
                 extensible,
             )),
-            RustType::I64(Range(min, max, extensible)) => {
-                AsnType::integer_with_range(Range(Some(min), Some(max), extensible))
-            }
+            RustType::I64(range) => AsnType::integer_with_range(Range(
+                range.min().map(i128::from),
+                range.max().map(i128::from),
+                range.extensible(),
+            )),
             RustType::U64(range) => AsnType::integer_with_range(Range(
-                range.min().map(|v| v as i64),
-                range.max().map(|v| v as i64),
+                range.min().map(i128::from),
+                range.max().map(i128::from),
                 range.extensible(),
             )),
-            RustType::String(size, charset) => AsnType::String(size, charset),
-            RustType::VecU8(size) => AsnType::OctetString(size),
+            RustType::I128(range) => AsnType::integer_with_range(range),
+            RustType::U128(range) => AsnType::integer_with_range(Range(
+                range.min().map(|v| v as i128),
+                range.max().map(|v| v as i128),
+                range.extensible(),
+            )),
+            RustType::BigInt(extensible) => AsnType::Integer(Integer {
+                range: Range(None, None, extensible),
+                constants: Vec::new(),
+                big: true,
+                constraint: None,
+            }),
+            RustType::Constrained(inner, set) => match inner.into_asn() {
+                AsnType::Integer(integer) => AsnType::Integer(Integer {
+                    constraint: Some(set.map(&|value| LitOrRef::Lit(*value))),
+                    ..integer
+                }),
+                asn => asn,
+            },
+            RustType::String(size, charset, alphabet) => {
+                AsnType::String(size.map(LitOrRef::Lit), charset, alphabet)
+            }
+            RustType::VecU8(size) => AsnType::OctetString(size.map(LitOrRef::Lit)),
             RustType::BitVec(size) => AsnType::bit_vec_with_size(size),
+            RustType::Null => AsnType::Null,
+            RustType::F64 => AsnType::Real,
+            RustType::ObjectIdentifier => AsnType::ObjectIdentifier,
+            RustType::RelativeOid => AsnType::RelativeOid,
+            RustType::Time(time) => AsnType::Time(time),
             RustType::Vec(inner, size, EncodingOrdering::Keep) => {
-                AsnType::SequenceOf(Box::new(inner.into_asn()), size)
+                AsnType::SequenceOf(Box::new(inner.into_asn()), size.map(LitOrRef::Lit))
             }
             RustType::Vec(inner, size, EncodingOrdering::Sort) => {
-                AsnType::SetOf(Box::new(inner.into_asn()), size)
+                AsnType::SetOf(Box::new(inner.into_asn()), size.map(LitOrRef::Lit))
             }
             RustType::Option(value) => AsnType::Optional(Box::new(value.into_asn())),
             RustType::Complex(name, tag) => AsnType::TypeReference(name, tag),
+            RustType::WithComponents(inner, constraints) => AsnType::WithComponents(
+                Box::new(inner.into_asn()),
+                constraints.map(&|value| LitOrRef::Lit(*value)),
+            ),
+            RustType::Containing(inner, container) => {
+                AsnType::Containing(Box::new(inner.into_asn()), container)
+            }
+            // the tagging and boxing are properties of the component or definition and not of the
+            // type
+            RustType::Explicit(inner) | RustType::Boxed(inner) => inner.into_asn(),
         }
     }
 
@@ -221,9 +344,22 @@ impl RustType {
             RustType::I32(_) => matches!(other, RustType::I32(_)),
             RustType::U64(_) => matches!(other, RustType::U64(_)),
             RustType::I64(_) => matches!(other, RustType::I64(_)),
-            RustType::String(..) => matches!(other, RustType::String(..)),
+            RustType::I128(_) => matches!(other, RustType::I128(_)),
+            RustType::U128(_) => matches!(other, RustType::U128(_)),
+            RustType::BigInt(_) => matches!(other, RustType::BigInt(_)),
+            RustType::Constrained(inner_a, _) => {
+                matches!(other, RustType::Constrained(inner_b, _) if inner_a.similar(inner_b))
+            }
+            // the time types are represented by their `String` value
+            RustType::String(..) | RustType::Time(_) => {
+                matches!(other, RustType::String(..) | RustType::Time(_))
+            }
             RustType::VecU8(_) => matches!(other, RustType::VecU8(_)),
             RustType::BitVec(_) => matches!(other, RustType::BitVec(_)),
+            RustType::Null => RustType::Null == *other,
+            RustType::F64 => RustType::F64 == *other,
+            RustType::ObjectIdentifier => RustType::ObjectIdentifier == *other,
+            RustType::RelativeOid => RustType::RelativeOid == *other,
             RustType::Vec(inner_a, _size, _ordering) => {
                 if let RustType::Vec(inner_b, _other_size, _ordering) = other {
                     inner_a.similar(inner_b)
@@ -245,6 +381,124 @@ impl RustType {
                     false
                 }
             }
+            RustType::WithComponents(inner_a, _) => {
+                matches!(other, RustType::WithComponents(inner_b, _) if inner_a.similar(inner_b))
+            }
+            RustType::Containing(inner_a, container_a) => matches!(
+                other,
+                RustType::Containing(inner_b, container_b)
+                    if container_a == container_b && inner_a.similar(inner_b)
+            ),
+            RustType::Explicit(inner_a) => {
+                matches!(other, RustType::Explicit(inner_b) if inner_a.similar(inner_b))
+            }
+            RustType::Boxed(inner_a) => {
+                matches!(other, RustType::Boxed(inner_b) if inner_a.similar(inner_b))
+            }
+        }
+    }
+
+    /// The protobuf and SQL mappings are unaware of (relative) object identifiers and represent
+    /// their arcs as a list of integers instead, which shares the rust type `Vec<u64>`
+    pub fn with_object_identifiers_as_vec(self) -> RustType {
+        match self {
+            RustType::ObjectIdentifier | RustType::RelativeOid => RustType::Vec(
+                Box::new(RustType::U64(Range::none())),
+                Size::Any,
+                EncodingOrdering::Keep,
+            ),
+            RustType::Vec(inner, size, ordering) => RustType::Vec(
+                Box::new(inner.with_object_identifiers_as_vec()),
+                size,
+                ordering,
+            ),
+            RustType::Option(inner) => {
+                RustType::Option(Box::new(inner.with_object_identifiers_as_vec()))
+            }
+            RustType::Explicit(inner) => {
+                RustType::Explicit(Box::new(inner.with_object_identifiers_as_vec()))
+            }
+            RustType::Boxed(inner) => {
+                RustType::Boxed(Box::new(inner.with_object_identifiers_as_vec()))
+            }
+            other => other,
+        }
+    }
+
+    /// The legacy UPER, protobuf and SQL mappings are unaware of tags and therefore represent an
+    /// explicitly tagged value as the value itself
+    pub fn without_explicit_tags(self) -> RustType {
+        match self {
+            RustType::Explicit(inner) => inner.without_explicit_tags(),
+            RustType::Vec(inner, size, ordering) => {
+                RustType::Vec(Box::new(inner.without_explicit_tags()), size, ordering)
+            }
+            RustType::Option(inner) => RustType::Option(Box::new(inner.without_explicit_tags())),
+            RustType::Boxed(inner) => RustType::Boxed(Box::new(inner.without_explicit_tags())),
+            other => other,
+        }
+    }
+
+    /// The protobuf and SQL mappings store the contained value of a `CONTAINING` constraint
+    /// like any other value of the contained type and not as its encoded octets or bits
+    pub fn without_containing(self) -> RustType {
+        match self {
+            RustType::Containing(inner, _container) => inner.without_containing(),
+            RustType::Vec(inner, size, ordering) => {
+                RustType::Vec(Box::new(inner.without_containing()), size, ordering)
+            }
+            RustType::Option(inner) => RustType::Option(Box::new(inner.without_containing())),
+            RustType::Explicit(inner) => RustType::Explicit(Box::new(inner.without_containing())),
+            RustType::Boxed(inner) => RustType::Boxed(Box::new(inner.without_containing())),
+            other => other,
+        }
+    }
+
+    /// Unconstrained `INTEGER`s are represented as `u64` unless they shall be of arbitrary
+    /// precision, see [`RustType::BigInt`]
+    pub fn with_big_integers(self) -> RustType {
+        match self {
+            RustType::U64(Range(None, None, extensible)) => RustType::BigInt(extensible),
+            RustType::Vec(inner, size, ordering) => {
+                RustType::Vec(Box::new(inner.with_big_integers()), size, ordering)
+            }
+            RustType::Option(inner) => RustType::Option(Box::new(inner.with_big_integers())),
+            RustType::Explicit(inner) => RustType::Explicit(Box::new(inner.with_big_integers())),
+            RustType::Boxed(inner) => RustType::Boxed(Box::new(inner.with_big_integers())),
+            other => other,
+        }
+    }
+
+    /// This type with an explicit tag, which is the tag of the value of an `Option`
+    pub fn into_explicit(self) -> RustType {
+        match self {
+            RustType::Option(inner) => RustType::Option(Box::new(inner.into_explicit())),
+            other => RustType::Explicit(Box::new(other)),
+        }
+    }
+
+    /// Whether the tag of this type - or of the value of an `Option` - is explicit
+    pub fn is_explicit(&self) -> bool {
+        match self {
+            RustType::Option(inner) | RustType::Boxed(inner) => inner.is_explicit(),
+            other => matches!(other, RustType::Explicit(_)),
+        }
+    }
+
+    /// This type on the heap, which is the value of an `Option`
+    pub fn into_boxed(self) -> RustType {
+        match self {
+            RustType::Option(inner) => RustType::Option(Box::new(inner.into_boxed())),
+            boxed @ RustType::Boxed(_) => boxed,
+            other => RustType::Boxed(Box::new(other)),
+        }
+    }
+
+    /// Whether this type - or the value of an `Option` - is on the heap
+    pub fn is_boxed(&self) -> bool {
+        match self {
+            RustType::Option(inner) => inner.is_boxed(),
+            other => matches!(other, RustType::Boxed(_)),
         }
     }
 
@@ -259,16 +513,26 @@ impl RustType {
             | RustType::I32(_)
             | RustType::U32(_)
             | RustType::I64(_)
-            | RustType::U64(_) => Tag::DEFAULT_INTEGER,
+            | RustType::U64(_)
+            | RustType::I128(_)
+            | RustType::U128(_)
+            | RustType::BigInt(_) => Tag::DEFAULT_INTEGER,
             RustType::BitVec(_) => Tag::DEFAULT_BIT_STRING,
             RustType::VecU8(_) => Tag::DEFAULT_OCTET_STRING,
-            RustType::String(_, charset) => match charset {
-                Charset::Utf8 => Tag::DEFAULT_UTF8_STRING,
-                Charset::Ia5 => Tag::DEFAULT_IA5_STRING,
-            },
+            RustType::Null => Tag::DEFAULT_NULL,
+            RustType::F64 => Tag::DEFAULT_REAL,
+            RustType::ObjectIdentifier => Tag::DEFAULT_OBJECT_IDENTIFIER,
+            RustType::RelativeOid => Tag::DEFAULT_RELATIVE_OID,
+            RustType::Time(time) => time.default_tag(),
+            RustType::Containing(_, container) => container.default_tag(),
+            RustType::String(_, charset, _) => charset.default_tag(),
             RustType::Vec(_, _, EncodingOrdering::Keep) => Tag::DEFAULT_SEQUENCE_OF,
             RustType::Vec(_, _, EncodingOrdering::Sort) => Tag::DEFAULT_SET_OF,
-            RustType::Option(inner) => return inner.tag(),
+            RustType::Option(inner)
+            | RustType::Constrained(inner, _)
+            | RustType::WithComponents(inner, _)
+            | RustType::Explicit(inner)
+            | RustType::Boxed(inner) => return inner.tag(),
             // TODO this is wrong. This should resolve the tag from the referenced type instead, but atm the infrastructure is missing to do such a thing, see github#13
             RustType::Complex(_, tag) => return *tag,
         })
@@ -290,9 +554,16 @@ pub enum Rust {
         fields: Vec<Field>,
         tag: Option<Tag>,
         extension_after: Option<usize>,
+        /// The name of the field that preserves extended fields unknown to this definition
+        unknown_extensions: Option<String>,
+        /// Whether the tag is the explicit tag of the type assignment, which wraps the
+        /// `SEQUENCE` or `SET` with its universal tag
+        explicit: bool,
     },
     Enum(PlainEnum),
     DataEnum(DataEnum),
+    /// An open type, whose variants are identified by their key, see [`DataVariant::key`]
+    OpenType(DataEnum),
 
     /// Used to represent a single, unnamed inner type
     TupleStruct {
@@ -302,6 +573,82 @@ pub enum Rust {
     },
 }
 
+impl Target for Rust {
+    type ValueReferenceType = RustType;
+}
+
+impl Definition<Rust> {
+    /// See [`RustType::with_object_identifiers_as_vec`]
+    pub fn with_object_identifiers_as_vec(&self) -> Self {
+        self.with_types_mapped(RustType::with_object_identifiers_as_vec)
+    }
+
+    /// See [`RustType::without_explicit_tags`]
+    pub fn without_explicit_tags(&self) -> Self {
+        self.with_types_mapped(RustType::without_explicit_tags)
+    }
+
+    /// See [`RustType::without_containing`]
+    pub fn without_containing(&self) -> Self {
+        self.with_types_mapped(RustType::without_containing)
+    }
+
+    /// See [`RustType::with_big_integers`]
+    pub fn with_big_integers(&self) -> Self {
+        self.with_types_mapped(RustType::with_big_integers)
+    }
+
+    fn with_types_mapped(&self, map: fn(RustType) -> RustType) -> Self {
+        let Definition(name, rust) = self;
+        let rust = match rust.clone() {
+            Rust::Struct {
+                ordering,
+                fields,
+                tag,
+                extension_after,
+                unknown_extensions,
+                explicit,
+            } => Rust::Struct {
+                ordering,
+                fields: fields
+                    .into_iter()
+                    .map(|mut field| {
+                        field.name_type.1 = map(field.name_type.1);
+                        field
+                    })
+                    .collect(),
+                tag,
+                extension_after,
+                unknown_extensions,
+                explicit,
+            },
+            Rust::DataEnum(mut data) => {
+                data.variants
+                    .iter_mut()
+                    .for_each(|variant| variant.name_type.1 = map(variant.name_type.1.clone()));
+                Rust::DataEnum(data)
+            }
+            Rust::OpenType(mut data) => {
+                data.variants
+                    .iter_mut()
+                    .for_each(|variant| variant.name_type.1 = map(variant.name_type.1.clone()));
+                Rust::OpenType(data)
+            }
+            Rust::TupleStruct {
+                r#type,
+                tag,
+                constants,
+            } => Rust::TupleStruct {
+                r#type: map(r#type),
+                tag,
+                constants,
+            },
+            rust @ Rust::Enum(_) => rust,
+        };
+        Definition(name.clone(), rust)
+    }
+}
+
 impl Rust {
     #[cfg(test)]
     pub fn struct_from_fields(fields: Vec<Field>) -> Self {
@@ -310,6 +657,8 @@ impl Rust {
             fields,
             tag: None,
             extension_after: None,
+            unknown_extensions: None,
+            explicit: false,
         }
     }
 
@@ -320,6 +669,25 @@ impl Rust {
             constants: Vec::default(),
         }
     }
+
+    /// The types of the fields of a struct - except for open types -, of the variants of a
+    /// `CHOICE` and of a tuple struct, which are the types that can be boxed
+    pub(crate) fn boxable_types_mut(&mut self) -> Vec<&mut RustType> {
+        match self {
+            Rust::Struct { fields, .. } => fields
+                .iter_mut()
+                .filter(|field| field.key.is_none())
+                .map(|field| &mut field.name_type.1)
+                .collect(),
+            Rust::DataEnum(data) => data
+                .variants
+                .iter_mut()
+                .map(|variant| &mut variant.name_type.1)
+                .collect(),
+            Rust::TupleStruct { r#type, .. } => vec![r#type],
+            Rust::Enum(_) | Rust::OpenType(_) => Vec::default(),
+        }
+    }
 }
 
 impl TagProperty for Rust {
@@ -327,7 +695,7 @@ impl TagProperty for Rust {
         match self {
             Rust::Struct { tag, .. } => *tag,
             Rust::Enum(e) => e.tag(),
-            Rust::DataEnum(c) => c.tag(),
+            Rust::DataEnum(c) | Rust::OpenType(c) => c.tag(),
             Rust::TupleStruct { tag, .. } => *tag,
         }
     }
@@ -336,7 +704,7 @@ impl TagProperty for Rust {
         match self {
             Rust::Struct { tag, .. } => *tag = Some(new_tag),
             Rust::Enum(e) => e.set_tag(new_tag),
-            Rust::DataEnum(c) => c.set_tag(new_tag),
+            Rust::DataEnum(c) | Rust::OpenType(c) => c.set_tag(new_tag),
             Rust::TupleStruct { tag, .. } => *tag = Some(new_tag),
         }
     }
@@ -345,7 +713,7 @@ impl TagProperty for Rust {
         match self {
             Rust::Struct { tag, .. } => *tag = None,
             Rust::Enum(e) => e.reset_tag(),
-            Rust::DataEnum(c) => c.reset_tag(),
+            Rust::DataEnum(c) | Rust::OpenType(c) => c.reset_tag(),
             Rust::TupleStruct { tag, .. } => *tag = None,
         }
     }
@@ -363,11 +731,22 @@ impl ToString for RustType {
             RustType::I32(_) => "i32",
             RustType::U64(_) => "u64",
             RustType::I64(_) => "i64",
-            RustType::String(..) => "String",
+            RustType::I128(_) => "i128",
+            RustType::U128(_) => "u128",
+            RustType::BigInt(_) => "BigInt",
+            RustType::String(..) | RustType::Time(_) => "String",
             RustType::VecU8(_) => "Vec<u8>",
             RustType::BitVec(_) => "BitVec",
+            RustType::Null => "()",
+            RustType::F64 => "f64",
+            RustType::ObjectIdentifier | RustType::RelativeOid => "Vec<u64>",
             RustType::Vec(inner, _size, _ordering) => return format!("Vec<{}>", inner.to_string()),
             RustType::Option(inner) => return format!("Option<{}>", inner.to_string()),
+            RustType::Boxed(inner) => return format!("Box<{}>", inner.to_string()),
+            RustType::Constrained(inner, _)
+            | RustType::WithComponents(inner, _)
+            | RustType::Containing(inner, _)
+            | RustType::Explicit(inner) => return inner.to_string(),
             RustType::Complex(name, _) => return name.clone(),
         }
         .into()
@@ -379,6 +758,9 @@ pub struct Field {
     pub(crate) name_type: (String, RustType),
     pub(crate) tag: Option<Tag>,
     pub(crate) constants: Vec<(String, String)>,
+    pub(crate) asn_name: Option<String>,
+    pub(crate) default: Option<LiteralValue>,
+    pub(crate) key: Option<String>,
 }
 
 impl Field {
@@ -387,6 +769,9 @@ impl Field {
             name_type: (name.to_string(), r#type),
             tag: None,
             constants: Vec::default(),
+            asn_name: None,
+            default: None,
+            key: None,
         }
     }
 
@@ -410,6 +795,38 @@ impl Field {
         self.constants = constants;
         self
     }
+
+    /// The identifier of the field in the ASN.1 definition, see [`Field::with_asn_name`]
+    pub fn asn_name(&self) -> &str {
+        self.asn_name.as_deref().unwrap_or_else(|| self.name())
+    }
+
+    /// The identifier is only stored if it differs from the rust name of the field
+    pub fn with_asn_name<T: ToString>(mut self, asn_name: T) -> Self {
+        let asn_name = asn_name.to_string();
+        self.asn_name = Some(asn_name).filter(|asn_name| asn_name != self.name());
+        self
+    }
+
+    /// The value of the field if it is not present in the encoding
+    pub fn default(&self) -> Option<&LiteralValue> {
+        self.default.as_ref()
+    }
+
+    pub fn with_default(mut self, default: Option<LiteralValue>) -> Self {
+        self.default = default;
+        self
+    }
+
+    /// The name of the field whose value identifies the actual type of this open type field
+    pub fn key(&self) -> Option<&str> {
+        self.key.as_deref()
+    }
+
+    pub fn with_key(mut self, key: Option<String>) -> Self {
+        self.key = key;
+        self
+    }
 }
 
 impl TagProperty for Field {
@@ -431,6 +848,8 @@ pub struct Enumeration<T> {
     variants: Vec<T>,
     tag: Option<Tag>,
     extended_after_index: Option<usize>,
+    unknown_extensions: Option<String>,
+    explicit: bool,
 }
 
 impl<T> From<Vec<T>> for Enumeration<T> {
@@ -439,6 +858,8 @@ impl<T> From<Vec<T>> for Enumeration<T> {
             variants,
             tag: None,
             extended_after_index: None,
+            unknown_extensions: None,
+            explicit: false,
         }
     }
 }
@@ -449,6 +870,11 @@ impl<T> Enumeration<T> {
         self
     }
 
+    pub fn with_unknown_extensions(mut self, unknown_extensions: Option<String>) -> Self {
+        self.unknown_extensions = unknown_extensions;
+        self
+    }
+
     pub fn len(&self) -> usize {
         self.variants.len()
     }
@@ -473,6 +899,17 @@ impl<T> Enumeration<T> {
     pub fn is_extensible(&self) -> bool {
         self.extended_after_index.is_some()
     }
+
+    /// The name of the variant that preserves extensions unknown to this definition
+    pub fn unknown_extensions(&self) -> Option<&str> {
+        self.unknown_extensions.as_deref()
+    }
+
+    /// Whether the tag is the explicit tag of the type assignment, which wraps the
+    /// `ENUMERATED` with its universal tag or the `CHOICE` with the tag of its variant
+    pub fn is_explicit(&self) -> bool {
+        self.explicit
+    }
 }
 
 impl<T> TagProperty for Enumeration<T> {
@@ -491,7 +928,98 @@ impl<T> TagProperty for Enumeration<T> {
 
 impl PlainEnum {
     pub fn from_names(names: impl Iterator<Item = impl ToString>) -> Self {
-        Self::from(names.map(|n| n.to_string()).collect::<Vec<_>>())
+        Self::from(names.map(PlainVariant::from_name).collect::<Vec<_>>())
+    }
+
+    /// The enumeration values of the variants in their order, which are either explicitly given
+    /// or allocated according to ITU-T X.680 | ISO/IEC 8824-1, 20.3 and 20.5
+    pub fn variant_values(&self) -> Vec<usize> {
+        let root_len = self
+            .extension_after_index()
+            .map_or(self.len(), |index| index + 1);
+        let mut used = self
+            .variants()
+            .take(root_len)
+            .filter_map(PlainVariant::number)
+            .collect::<Vec<_>>();
+        let mut values = Vec::with_capacity(self.len());
+        let mut next = 0;
+        for (index, variant) in self.variants().enumerate() {
+            let value = variant.number().unwrap_or_else(|| {
+                while used.contains(&next) {
+                    next += 1;
+                }
+                next
+            });
+            used.push(value);
+            if index >= root_len {
+                // an extension addition must be greater than all previous additions
+                next = next.max(value + 1);
+            }
+            values.push(value);
+        }
+        values
+    }
+}
+
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub struct PlainVariant {
+    name: String,
+    asn_name: Option<String>,
+    number: Option<usize>,
+}
+
+impl PlainVariant {
+    pub fn from_name<T: ToString>(name: T) -> Self {
+        Self {
+            name: name.to_string(),
+            asn_name: None,
+            number: None,
+        }
+    }
+
+    /// The explicitly given enumeration value, see [`PlainEnum::variant_values`]
+    pub fn number(&self) -> Option<usize> {
+        self.number
+    }
+
+    pub fn with_number(mut self, number: Option<usize>) -> Self {
+        self.number = number;
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The identifier of the variant in the ASN.1 definition, see [`PlainVariant::with_asn_name`]
+    pub fn asn_name(&self) -> &str {
+        self.asn_name.as_deref().unwrap_or(&self.name)
+    }
+
+    /// The identifier is only stored if it differs from the rust name of the variant
+    pub fn with_asn_name<T: ToString>(mut self, asn_name: T) -> Self {
+        let asn_name = asn_name.to_string();
+        self.asn_name = Some(asn_name).filter(|asn_name| asn_name != &self.name);
+        self
+    }
+}
+
+impl From<&str> for PlainVariant {
+    fn from(name: &str) -> Self {
+        Self::from_name(name)
+    }
+}
+
+impl From<String> for PlainVariant {
+    fn from(name: String) -> Self {
+        Self::from_name(name)
+    }
+}
+
+impl std::fmt::Display for PlainVariant {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.name)
     }
 }
 
@@ -499,6 +1027,8 @@ impl PlainEnum {
 pub struct DataVariant {
     name_type: (String, RustType),
     tag: Option<Tag>,
+    asn_name: Option<String>,
+    key: Option<i64>,
 }
 
 impl DataVariant {
@@ -506,9 +1036,33 @@ impl DataVariant {
         Self {
             name_type: (name.to_string(), r#type),
             tag: None,
+            asn_name: None,
+            key: None,
         }
     }
 
+    /// The value that identifies this variant of an open type
+    pub fn key(&self) -> Option<i64> {
+        self.key
+    }
+
+    pub fn with_key(mut self, key: i64) -> Self {
+        self.key = Some(key);
+        self
+    }
+
+    /// The identifier of the variant in the ASN.1 definition, see [`DataVariant::with_asn_name`]
+    pub fn asn_name(&self) -> &str {
+        self.asn_name.as_deref().unwrap_or_else(|| self.name())
+    }
+
+    /// The identifier is only stored if it differs from the rust name of the variant
+    pub fn with_asn_name<T: ToString>(mut self, asn_name: T) -> Self {
+        let asn_name = asn_name.to_string();
+        self.asn_name = Some(asn_name).filter(|asn_name| asn_name != self.name());
+        self
+    }
+
     pub fn fallback_representation(&self) -> &(String, RustType) {
         &self.name_type
     }
@@ -537,20 +1091,41 @@ impl TagProperty for DataVariant {
 }
 
 impl Model<Rust> {
-    pub fn convert_asn_to_rust(asn_model: &Model<Asn>, scope: &[&Model<Asn>]) -> Model<Rust> {
+    pub fn convert_asn_to_rust(
+        asn_model: &Model<Asn>,
+        scope: &[&Model<Asn>],
+    ) -> Result<Model<Rust>, Error> {
+        let asn_model = &*asn_model.with_included_components(scope);
         let mut model = Model {
             name: rust_module_name(&asn_model.name),
             oid: asn_model.oid.clone(),
+            tag_default: asn_model.tag_default,
+            extensibility_implied: asn_model.extensibility_implied,
             imports: asn_model
                 .imports
                 .iter()
                 .map(|i| Import {
-                    what: i.what.iter().map(|w| rust_struct_or_enum_name(w)).collect(),
+                    what: i
+                        .what
+                        .iter()
+                        .map(|w| {
+                            if w.starts_with(|c: char| c.is_ascii_lowercase()) {
+                                rust_constant_name(w)
+                            } else {
+                                rust_struct_or_enum_name(w)
+                            }
+                        })
+                        .collect(),
                     from: rust_module_name(&i.from),
                     from_oid: i.from_oid.clone(),
                 })
                 .collect(),
             definitions: Vec::with_capacity(asn_model.definitions.len()),
+            value_references: Vec::with_capacity(asn_model.value_references.len()),
+            // information objects are resolved into the definitions
+            classes: Vec::default(),
+            objects: Vec::default(),
+            object_sets: Vec::default(),
         };
         for Definition(name, asn) in &asn_model.definitions {
             let rust_name = rust_struct_or_enum_name(name);
@@ -561,9 +1136,95 @@ impl Model<Rust> {
                 },
                 target: &mut model.definitions,
             };
-            Self::definition_to_rust(&rust_name, &asn.r#type, asn.tag, &mut ctxt);
+            let explicit = ctxt.resolver().is_explicit(asn.tag, asn.tagging);
+            Self::definition_to_rust(&rust_name, &asn.r#type, asn.tag, &mut ctxt)?;
+            match model.definitions.last_mut().filter(|d| d.0 == rust_name) {
+                Some(Definition(_, Rust::TupleStruct { r#type, tag, .. }))
+                    if explicit && tag.is_some() =>
+                {
+                    *r#type = r#type.clone().into_explicit();
+                }
+                Some(Definition(_, Rust::Struct { explicit: e, .. })) => *e = explicit,
+                Some(Definition(_, Rust::Enum(enumeration))) => enumeration.explicit = explicit,
+                Some(Definition(_, Rust::DataEnum(enumeration))) => {
+                    // ITU-T X.680 | ISO/IEC 8824-1, 31.2.7: a tagged CHOICE is always
+                    // explicitly tagged, even in a module with IMPLICIT TAGS
+                    enumeration.explicit =
+                        asn.tag.is_some() && asn.tagging != Some(Tagging::Implicit);
+                }
+                _ => {}
+            }
+            if asn.boxed {
+                if let Some(Definition(_, Rust::TupleStruct { r#type, .. })) =
+                    model.definitions.last_mut().filter(|d| d.0 == rust_name)
+                {
+                    *r#type = r#type.clone().into_boxed();
+                }
+            }
+        }
+        for ValueReference { name, role, value } in &asn_model.value_references {
+            let mut ctxt = Context {
+                resolver: TagResolver {
+                    model: asn_model,
+                    scope,
+                },
+                target: &mut model.definitions,
+            };
+            let rust_role = match Self::definition_type_to_rust_type(
+                &rust_struct_or_enum_name(name),
+                role,
+                None,
+                &mut ctxt,
+            )? {
+                // an unconstrained INTEGER is unsigned, unless its value is negative
+                RustType::U64(range)
+                    if range.min().is_none()
+                        && matches!(value, LiteralValue::Integer(value) if *value < 0) =>
+                {
+                    RustType::I64(Range::inclusive(Some(i64::MIN), Some(i64::MAX)))
+                }
+                rust_role => rust_role,
+            };
+            model.value_references.push(ValueReference {
+                name: rust_constant_name(name),
+                role: rust_role,
+                value: value.clone(),
+            });
+        }
+        model.box_recursive_components();
+        Ok(model)
+    }
+
+    fn resolve_size(size: &Size<LitOrRef<usize>>, ctxt: &Context<'_>) -> Size {
+        match size.clone().map(|value| Self::resolve_integer(value, ctxt)) {
+            Size::Range(min, max, extensible) if min == max => Size::Fix(min, extensible),
+            size => size,
+        }
+    }
+
+    fn resolve_range(
+        range: &Range<Option<LitOrRef<i128>>>,
+        ctxt: &Context<'_>,
+    ) -> Range<Option<i128>> {
+        let Range(min, max, extensible) = range.clone();
+        Range(
+            min.map(|value| Self::resolve_integer(value, ctxt)),
+            max.map(|value| Self::resolve_integer(value, ctxt)),
+            extensible,
+        )
+    }
+
+    fn resolve_integer<T: TryFrom<i64>>(value: LitOrRef<T>, ctxt: &Context<'_>) -> T {
+        match value {
+            LitOrRef::Lit(value) => value,
+            LitOrRef::Ref(name) => match ctxt.resolve_value(&name) {
+                Some(LiteralValue::Integer(value)) => T::try_from(*value).unwrap_or_else(|_| {
+                    panic!("The value {} of {} is not allowed here", value, name)
+                }),
+                Some(value) => panic!("The value {} of {} is not an integer", value, name),
+                None => panic!("Unable to resolve the value reference {}", name),
+            },
         }
-        model
     }
 
     /// Converts the given `Asn` value to `Rust`, adding new `Defintion`s as
@@ -573,28 +1234,48 @@ impl Model<Rust> {
     /// and can therefore be used to be inserted in the parent element.
     ///
     /// The name is expected in a valid and rusty way
-    pub fn definition_to_rust(name: &str, asn: &AsnType, tag: Option<Tag>, ctxt: &mut Context<'_>) {
+    pub fn definition_to_rust(
+        name: &str,
+        asn: &AsnType,
+        tag: Option<Tag>,
+        ctxt: &mut Context<'_>,
+    ) -> Result<(), Error> {
         match asn {
             AsnType::Boolean
             | AsnType::String(..)
             | AsnType::OctetString(_)
-            | AsnType::BitString(_) => {
-                let rust_type = Self::definition_type_to_rust_type(name, asn, tag, ctxt);
+            | AsnType::BitString(_)
+            | AsnType::Null
+            | AsnType::Real
+            | AsnType::ObjectIdentifier
+            | AsnType::RelativeOid
+            | AsnType::Time(_)
+            | AsnType::Containing(..) => {
+                let rust_type = Self::definition_type_to_rust_type(name, asn, tag, ctxt)?;
                 ctxt.add_definition(Definition(
                     name.to_string(),
                     Rust::tuple_struct_from_type(rust_type).with_tag_opt(tag),
                 ));
             }
             AsnType::TypeReference(_, tag) => {
-                let rust_type = Self::definition_type_to_rust_type(name, asn, *tag, ctxt);
+                let rust_type = Self::definition_type_to_rust_type(name, asn, *tag, ctxt)?;
                 ctxt.add_definition(Definition(
                     name.to_string(),
                     Rust::tuple_struct_from_type(rust_type).with_tag_opt(*tag),
                 ));
             }
+            AsnType::WithComponents(..) => {
+                let rust_type = Self::definition_type_to_rust_type(name, asn, tag, ctxt)?;
+                // the constraint does not change the tag of the referenced type
+                let tag = tag.or_else(|| rust_type.tag());
+                ctxt.add_definition(Definition(
+                    name.to_string(),
+                    Rust::tuple_struct_from_type(rust_type).with_tag_opt(tag),
+                ));
+            }
 
             me @ AsnType::Integer(_) => {
-                let rust_type = Self::definition_type_to_rust_type(name, asn, tag, ctxt);
+                let rust_type = Self::definition_type_to_rust_type(name, asn, tag, ctxt)?;
                 let constants = Self::asn_constants_to_rust_constants(me);
                 ctxt.add_definition(Definition(
                     name.into(),
@@ -609,7 +1290,7 @@ impl Model<Rust> {
             AsnType::Optional(inner) => {
                 let inner = RustType::Option(Box::new(Self::definition_type_to_rust_type(
                     name, inner, tag, ctxt,
-                )));
+                )?));
                 ctxt.add_definition(Definition(
                     name.into(),
                     Rust::tuple_struct_from_type(inner).with_tag_opt(tag),
@@ -619,8 +1300,10 @@ impl Model<Rust> {
             AsnType::Sequence(ComponentTypeList {
                 fields,
                 extension_after,
+                unknown_extensions,
+                ..
             }) => {
-                let fields = Self::asn_fields_to_rust_fields(name, fields, ctxt);
+                let fields = Self::asn_fields_to_rust_fields(name, fields, ctxt)?;
                 ctxt.add_definition(Definition(
                     name.into(),
                     Rust::Struct {
@@ -628,6 +1311,8 @@ impl Model<Rust> {
                         fields,
                         tag,
                         extension_after: *extension_after,
+                        unknown_extensions: unknown_extensions.clone(),
+                        explicit: false,
                     },
                 ));
             }
@@ -635,8 +1320,10 @@ impl Model<Rust> {
             AsnType::Set(ComponentTypeList {
                 fields,
                 extension_after,
+                unknown_extensions,
+                ..
             }) => {
-                let fields = Self::asn_fields_to_rust_fields(name, fields, ctxt);
+                let fields = Self::asn_fields_to_rust_fields(name, fields, ctxt)?;
                 ctxt.add_definition(Definition(
                     name.into(),
                     Rust::Struct {
@@ -644,14 +1331,16 @@ impl Model<Rust> {
                         fields,
                         tag,
                         extension_after: *extension_after,
+                        unknown_extensions: unknown_extensions.clone(),
+                        explicit: false,
                     },
                 ));
             }
 
             AsnType::SequenceOf(asn, size) => {
                 let inner = RustType::Vec(
-                    Box::new(Self::definition_type_to_rust_type(name, asn, tag, ctxt)),
-                    *size,
+                    Box::new(Self::definition_type_to_rust_type(name, asn, tag, ctxt)?),
+                    Self::resolve_size(size, ctxt),
                     EncodingOrdering::Keep,
                 );
                 ctxt.add_definition(Definition(name.into(), Rust::tuple_struct_from_type(inner)));
@@ -659,8 +1348,8 @@ impl Model<Rust> {
 
             AsnType::SetOf(asn, size) => {
                 let inner = RustType::Vec(
-                    Box::new(Self::definition_type_to_rust_type(name, asn, tag, ctxt)),
-                    *size,
+                    Box::new(Self::definition_type_to_rust_type(name, asn, tag, ctxt)?),
+                    Self::resolve_size(size, ctxt),
                     EncodingOrdering::Sort,
                 );
                 ctxt.add_definition(Definition(
@@ -674,59 +1363,169 @@ impl Model<Rust> {
                     variants: Vec::with_capacity(choice.len()),
                     tag,
                     extended_after_index: choice.extension_after_index(),
+                    unknown_extensions: choice.unknown_extensions().map(ToString::to_string),
+                    explicit: false,
                 };
 
-                for ChoiceVariant { name, r#type, tag } in choice.variants() {
+                let tags = ctxt.component_tags(
+                    &choice
+                        .variants()
+                        .map(|variant| (variant.tag, &variant.r#type))
+                        .collect::<Vec<_>>(),
+                );
+                for (variant, tag) in choice.variants().zip(tags) {
+                    let ChoiceVariant {
+                        name,
+                        tag: tagged,
+                        tagging,
+                        r#type,
+                        boxed,
+                    } = variant;
                     let rust_name = format!("{}{}", name, rust_struct_or_enum_name(&name));
-                    let rust_role =
-                        Self::definition_type_to_rust_type(&rust_name, &r#type, *tag, ctxt);
+                    let explicit = ctxt.resolver().is_explicit(*tagged, *tagging);
+                    // an explicit tag belongs to the variant and not to its type
+                    let type_tag = if explicit { None } else { *tagged };
+                    let mut rust_role =
+                        Self::definition_type_to_rust_type(&rust_name, &r#type, type_tag, ctxt)?;
+                    if explicit {
+                        rust_role = rust_role.into_explicit();
+                    }
+                    if *boxed {
+                        rust_role = rust_role.into_boxed();
+                    }
                     let rust_field_name = rust_variant_name(&name);
                     enumeration.variants.push(
-                        DataVariant::from_name_type(rust_field_name, rust_role).with_tag_opt(*tag),
+                        DataVariant::from_name_type(rust_field_name, rust_role)
+                            .with_asn_name(name)
+                            .with_tag_opt(tag),
                     );
                 }
 
                 ctxt.add_definition(Definition(name.into(), Rust::DataEnum(enumeration)));
             }
 
+            AsnType::OpenType(open_type) => {
+                let mut enumeration = Enumeration {
+                    variants: Vec::with_capacity(open_type.variants.len()),
+                    tag: None,
+                    extended_after_index: None,
+                    unknown_extensions: open_type.unknown_extensions.clone(),
+                    explicit: false,
+                };
+
+                for OpenTypeVariant {
+                    key,
+                    name: variant_name,
+                    r#type,
+                } in &open_type.variants
+                {
+                    let rust_name = format!("{}{}", name, rust_struct_or_enum_name(variant_name));
+                    let rust_role =
+                        Self::definition_type_to_rust_type(&rust_name, r#type, None, ctxt)?;
+                    enumeration.variants.push(
+                        DataVariant::from_name_type(rust_variant_name(variant_name), rust_role)
+                            .with_key(*key),
+                    );
+                }
+
+                ctxt.add_definition(Definition(name.into(), Rust::OpenType(enumeration)));
+            }
+
+            AsnType::ObjectClassField(field) => {
+                let resolved = ctxt.resolve_object_class_field(field)?;
+                Self::definition_to_rust(name, &resolved, tag, ctxt)?;
+            }
+
             AsnType::Enumerated(enumerated) => {
                 let mut rust_enum = Enumeration {
                     variants: Vec::with_capacity(enumerated.len()),
                     tag,
                     extended_after_index: enumerated.extension_after_index(),
+                    unknown_extensions: enumerated.unknown_extensions().map(ToString::to_string),
+                    explicit: false,
                 };
 
                 for variant in enumerated.variants() {
-                    rust_enum.variants.push(rust_variant_name(variant.name()));
+                    rust_enum.variants.push(
+                        PlainVariant::from_name(rust_variant_name(variant.name()))
+                            .with_asn_name(variant.name())
+                            .with_number(variant.number()),
+                    );
                 }
 
                 ctxt.add_definition(Definition(name.into(), Rust::Enum(rust_enum)));
             }
         }
+        Ok(())
     }
 
     fn asn_fields_to_rust_fields(
         name: &str,
         fields: &[crate::model::Field<Asn>],
         ctxt: &mut Context<'_>,
-    ) -> Vec<Field> {
+    ) -> Result<Vec<Field>, Error> {
         let mut rust_fields = Vec::with_capacity(fields.len());
+        let tags = ctxt.component_tags(
+            &fields
+                .iter()
+                .map(|field| (field.role.tag, &field.role.r#type))
+                .collect::<Vec<_>>(),
+        );
 
-        for field in fields.iter() {
+        for ((index, field), tag) in fields.iter().enumerate().zip(tags) {
             let rust_name = format!("{}{}", name, rust_struct_or_enum_name(&field.name));
-            let tag = field.role.tag;
-            let rust_role =
-                Self::definition_type_to_rust_type(&rust_name, &field.role.r#type, tag, ctxt);
+            let explicit = ctxt
+                .resolver()
+                .is_explicit(field.role.tag, field.role.tagging);
+            // an explicit tag belongs to the component and not to its type
+            let type_tag = if explicit { None } else { field.role.tag };
+            let (rust_role, key) =
+                match ctxt.resolve_component_relation(&field.role.r#type, &fields[..index])? {
+                    // the value of an open type is always encoded with its own tag
+                    Some((open_type, key)) => (
+                        Self::definition_type_to_rust_type(
+                            &rust_name,
+                            &AsnType::OpenType(open_type),
+                            field.role.tag,
+                            ctxt,
+                        )?,
+                        Some(rust_field_name(key)),
+                    ),
+                    None => {
+                        let rust_role = Self::definition_type_to_rust_type(
+                            &rust_name,
+                            &field.role.r#type,
+                            type_tag,
+                            ctxt,
+                        )?;
+                        (
+                            if explicit {
+                                rust_role.into_explicit()
+                            } else {
+                                rust_role
+                            },
+                            field.role.key.clone(),
+                        )
+                    }
+                };
+            let rust_role = if field.role.boxed {
+                rust_role.into_boxed()
+            } else {
+                rust_role
+            };
             let rust_field_name = rust_field_name(&field.name);
             let constants = Self::asn_constants_to_rust_constants(&field.role.r#type);
             rust_fields.push(
                 RustField::from_name_type(rust_field_name, rust_role)
+                    .with_asn_name(&field.name)
                     .with_constants(constants)
+                    .with_default(field.role.default.clone())
+                    .with_key(key)
                     .with_tag_opt(tag),
             );
         }
 
-        rust_fields
+        Ok(rust_fields)
     }
 
     pub fn asn_constants_to_rust_constants(asn: &AsnType) -> Vec<(String, String)> {
@@ -745,6 +1544,11 @@ impl Model<Rust> {
             Type::Boolean
             | Type::String(..)
             | Type::OctetString(_)
+            | Type::Null
+            | Type::Real
+            | Type::ObjectIdentifier
+            | Type::RelativeOid
+            | Type::Time(_)
             | Type::Optional(_)
             | Type::Sequence(_)
             | Type::SequenceOf(..)
@@ -752,7 +1556,82 @@ impl Model<Rust> {
             | Type::SetOf(..)
             | Type::Enumerated(_)
             | Type::Choice(_)
-            | Type::TypeReference(_, _) => Vec::default(),
+            | Type::TypeReference(_, _)
+            | Type::WithComponents(..)
+            | Type::Containing(..)
+            | Type::ObjectClassField(_)
+            | Type::OpenType(_) => Vec::default(),
+        }
+    }
+
+    /// The smallest Rust type that covers the range of an `INTEGER`, of which the unconstrained
+    /// ones are of arbitrary precision if `big` is set
+    fn integer_to_rust_type(range: Range<Option<i128>>, big: bool) -> RustType {
+        let exceeds_i64 = |value: i128| i64::try_from(value).is_err();
+        match (*range.min(), *range.max()) {
+            (None, None) if big => RustType::BigInt(range.extensible()),
+            (min, max) if min.is_some_and(exceeds_i64) || max.is_some_and(exceeds_i64) => match min
+            {
+                Some(min) if min >= 0 => RustType::U128(Range(
+                    Some(min as u128),
+                    max.map(|v| v as u128),
+                    range.extensible(),
+                )),
+                _ => RustType::I128(Range(min, max, range.extensible())),
+            },
+            (min, max) => Self::integer_to_rust_type_64(Range(
+                min.map(|v| v as i64),
+                max.map(|v| v as i64),
+                range.extensible(),
+            )),
+        }
+    }
+
+    fn integer_to_rust_type_64(range: Range<Option<i64>>) -> RustType {
+        if range.extensible() {
+            match (*range.min(), *range.max()) {
+                (None, None)
+                | (Some(0), None)
+                | (Some(0), Some(i64::MAX))
+                | (None, Some(i64::MAX)) => RustType::U64(Range(None, None, true)),
+                (min, max) if min.unwrap_or_default() >= 0 && max.unwrap_or_default() >= 0 => {
+                    RustType::U64(Range(min.map(|v| v as u64), max.map(|v| v as u64), true))
+                }
+                (min, max) => RustType::I64(Range(min, max, true)),
+            }
+        } else {
+            match (*range.min(), *range.max()) {
+                (None, None)
+                | (Some(0), None)
+                | (Some(0), Some(i64::MAX))
+                | (None, Some(i64::MAX)) => RustType::U64(Range(None, None, false)),
+                // semi-constrained, the upper bound is MAX and not the largest value of the type
+                (min, None) => match min.unwrap_or_default() {
+                    min if min >= 0 => RustType::U64(Range(Some(min as u64), None, false)),
+                    min => RustType::I64(Range(Some(min), None, false)),
+                },
+                (min, Some(max)) => {
+                    let min = min.unwrap_or_default();
+                    if min >= 0 {
+                        match max as u64 {
+                            m if m <= U8_MAX => RustType::U8(Range::inclusive(min as u8, max as u8)),
+                            m if m <= U16_MAX => RustType::U16(Range::inclusive(min as u16, max as u16)),
+                            m if m <= U32_MAX => RustType::U32(Range::inclusive(min as u32, max as u32)),
+                            _/*m if m <= U64_MAX*/ => RustType::U64(Range::inclusive(Some(min as u64), Some(max as u64))),
+                            //_ => panic!("This should never happen, since max (as u64 frm i64) cannot be greater than U64_MAX")
+                        }
+                    } else {
+                        let max_amplitude = (min - 1).abs().max(max);
+                        match max_amplitude {
+                            _ if max_amplitude <= I8_MAX => RustType::I8(Range::inclusive(min as i8, max as i8)),
+                            _ if max_amplitude <= I16_MAX => RustType::I16(Range::inclusive(min as i16, max as i16)),
+                            _ if max_amplitude <= I32_MAX => RustType::I32(Range::inclusive(min as i32, max as i32)),
+                            _/*if max_amplitude <= I64_MAX*/ => RustType::I64(Range::inclusive(Some(min), Some(max))),
+                            //_ => panic!("This should never happen, since max (being i64) cannot be greater than I64_MAX")
+                        }
+                    }
+                }
+            }
         }
     }
 
@@ -761,66 +1640,38 @@ impl Model<Rust> {
         asn: &AsnType,
         tag: Option<Tag>,
         ctxt: &mut Context<'_>,
-    ) -> RustType {
-        match asn {
+    ) -> Result<RustType, Error> {
+        Ok(match asn {
             AsnType::Boolean => RustType::Bool,
-            AsnType::Integer(int) if int.range.extensible() => {
-                match (int.range.min(), int.range.max()) {
-                    (None, None)
-                    | (Some(0), None)
-                    | (Some(0), Some(i64::MAX))
-                    | (None, Some(i64::MAX)) => RustType::U64(Range(None, None, true)),
-                    (min, max) if min.unwrap_or_default() >= 0 && max.unwrap_or_default() >= 0 => {
-                        RustType::U64(Range(min.map(|v| v as u64), max.map(|v| v as u64), true))
-                    }
-                    (min, max) => RustType::I64(Range(
-                        min.unwrap_or_else(i64::min_value),
-                        max.unwrap_or_else(i64::max_value),
-                        true,
-                    )),
+            AsnType::Null => RustType::Null,
+            AsnType::Real => RustType::F64,
+            AsnType::ObjectIdentifier => RustType::ObjectIdentifier,
+            AsnType::RelativeOid => RustType::RelativeOid,
+            AsnType::Time(time) => RustType::Time(*time),
+            AsnType::Integer(int) => match &int.constraint {
+                Some(constraint) => {
+                    let set = constraint.map(&|value| Self::resolve_integer(value.clone(), ctxt));
+                    let (min, max) = set.effective_range();
+                    let range = Range(min, max, int.range.extensible());
+                    RustType::Constrained(Box::new(Self::integer_to_rust_type(range, int.big)), set)
                 }
+                None => Self::integer_to_rust_type(Self::resolve_range(&int.range, ctxt), int.big),
+            },
+
+            AsnType::String(size, charset, alphabet) => {
+                RustType::String(Self::resolve_size(size, ctxt), *charset, alphabet.clone())
             }
-            AsnType::Integer(int) => {
-                match (int.range.min(), int.range.max()) {
-                    (None, None)
-                    | (Some(0), None)
-                    | (Some(0), Some(i64::MAX))
-                    | (None, Some(i64::MAX)) => RustType::U64(Range(None, None, false)),
-                    (min, max) => {
-                        let min = min.unwrap_or_default();
-                        let max = max.unwrap_or(i64::MAX);
-                        if min >= 0 {
-                            match max as u64 {
-                                m if m <= U8_MAX => RustType::U8(Range::inclusive(min as u8, max as u8)),
-                                m if m <= U16_MAX => RustType::U16(Range::inclusive(min as u16, max as u16)),
-                                m if m <= U32_MAX => RustType::U32(Range::inclusive(min as u32, max as u32)),
-                                _/*m if m <= U64_MAX*/ => RustType::U64(Range::inclusive(Some(min as u64), Some(max as u64))),
-                                //_ => panic!("This should never happen, since max (as u64 frm i64) cannot be greater than U64_MAX")
-                            }
-                        } else {
-                            let max_amplitude = (min - 1).abs().max(max);
-                            match max_amplitude {
-                                _ if max_amplitude <= I8_MAX => RustType::I8(Range::inclusive(min as i8, max as i8)),
-                                _ if max_amplitude <= I16_MAX => RustType::I16(Range::inclusive(min as i16, max as i16)),
-                                _ if max_amplitude <= I32_MAX => RustType::I32(Range::inclusive(min as i32, max as i32)),
-                                _/*if max_amplitude <= I64_MAX*/ => RustType::I64(Range::inclusive(min as i64, max as i64)),
-                                //_ => panic!("This should never happen, since max (being i64) cannot be greater than I64_MAX")
-                            }
-                        }
-                    }
-                }
+            AsnType::OctetString(size) => RustType::VecU8(Self::resolve_size(size, ctxt)),
+            AsnType::BitString(bitstring) => {
+                RustType::BitVec(Self::resolve_size(&bitstring.size, ctxt))
             }
-
-            AsnType::String(size, charset) => RustType::String(*size, *charset),
-            AsnType::OctetString(size) => RustType::VecU8(*size),
-            AsnType::BitString(bitstring) => RustType::BitVec(bitstring.size),
             Type::Optional(inner) => {
                 RustType::Option(Box::new(Self::definition_type_to_rust_type(
                     name,
                     inner,
                     tag.or_else(|| ctxt.resolver().resolve_no_default(&**inner)),
                     ctxt,
-                )))
+                )?))
             }
             AsnType::SequenceOf(asn, size) => RustType::Vec(
                 Box::new(Self::definition_type_to_rust_type(
@@ -828,8 +1679,8 @@ impl Model<Rust> {
                     asn,
                     tag.or_else(|| ctxt.resolver().resolve_no_default(&**asn)),
                     ctxt,
-                )),
-                *size,
+                )?),
+                Self::resolve_size(size, ctxt),
                 EncodingOrdering::Keep,
             ),
             AsnType::SetOf(asn, size) => RustType::Vec(
@@ -838,8 +1689,8 @@ impl Model<Rust> {
                     asn,
                     tag.or_else(|| ctxt.resolver().resolve_no_default(&**asn)),
                     ctxt,
-                )),
-                *size,
+                )?),
+                Self::resolve_size(size, ctxt),
                 EncodingOrdering::Sort,
             ),
             ty @ AsnType::Sequence(_)
@@ -847,14 +1698,33 @@ impl Model<Rust> {
             | ty @ AsnType::Enumerated(_)
             | ty @ AsnType::Choice(_) => {
                 let name = rust_struct_or_enum_name(name);
-                Self::definition_to_rust(&name, asn, tag, ctxt);
+                Self::definition_to_rust(&name, asn, tag, ctxt)?;
                 RustType::Complex(name, tag.or_else(|| ctxt.resolver().resolve_type_tag(ty)))
             }
             AsnType::TypeReference(name, tag) => RustType::Complex(
-                name.clone(),
+                rust_struct_or_enum_name(name),
                 tag.clone().or_else(|| ctxt.resolver().resolve_tag(name)),
             ),
-        }
+            AsnType::WithComponents(inner, constraints) => RustType::WithComponents(
+                Box::new(Self::definition_type_to_rust_type(name, inner, tag, ctxt)?),
+                constraints.map(&|value| Self::resolve_integer(value.clone(), ctxt)),
+            ),
+            // the tag belongs to the string and not to the contained value
+            AsnType::Containing(inner, container) => RustType::Containing(
+                Box::new(Self::definition_type_to_rust_type(name, inner, None, ctxt)?),
+                *container,
+            ),
+            AsnType::ObjectClassField(field) => {
+                let resolved = ctxt.resolve_object_class_field(field)?;
+                Self::definition_type_to_rust_type(name, &resolved, tag, ctxt)?
+            }
+            AsnType::OpenType(_) => {
+                let name = rust_struct_or_enum_name(name);
+                Self::definition_to_rust(&name, asn, tag, ctxt)?;
+                // ITU-T X.680 | ISO/IEC 8824-1, 8.6: the tag of an open type is indeterminate
+                RustType::Complex(name, None)
+            }
+        })
     }
 }
 
@@ -880,6 +1750,11 @@ pub fn rust_variant_name(name: &str) -> String {
     out
 }
 
+#[allow(clippy::module_name_repetitions)]
+pub fn rust_constant_name(name: &str) -> String {
+    rust_module_name(name).to_uppercase()
+}
+
 #[allow(clippy::module_name_repetitions)]
 pub fn rust_struct_or_enum_name(name: &str) -> String {
     rust_variant_name(name)
@@ -926,9 +1801,13 @@ mod tests {
 
     #[test]
     fn test_simple_asn_sequence_represented_correctly_as_rust_model() {
-        let model_rust = Model::try_from(Tokenizer::default().parse(SIMPLE_INTEGER_STRUCT_ASN))
-            .unwrap()
-            .to_rust();
+        let model_rust = Model::try_from(
+            Tokenizer::default()
+                .parse(SIMPLE_INTEGER_STRUCT_ASN)
+                .unwrap(),
+        )
+        .unwrap()
+        .to_rust();
 
         assert_eq!("simple_schema", model_rust.name);
         assert_eq!(true, model_rust.imports.is_empty());
@@ -950,9 +1829,375 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_integers_exceeding_64_bits() {
+        let model_rust = Model::try_from(
+            Tokenizer
+                .parse(
+                    r"WideSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+            Wide ::= SEQUENCE {
+                unsigned INTEGER (0..18446744073709551616),
+                signed INTEGER (-170141183460469231731687303715884105728..0),
+                semi INTEGER (18446744073709551616..MAX),
+                narrow INTEGER (-9223372036854775808..9223372036854775807, ...)
+            }
+            END",
+                )
+                .unwrap(),
+        )
+        .unwrap()
+        .to_rust();
+
+        assert_eq!(
+            Definition(
+                "Wide".into(),
+                Rust::struct_from_fields(vec![
+                    RustField::from_name_type(
+                        "unsigned",
+                        RustType::U128(Range::inclusive(Some(0), Some(1 << 64)))
+                    ),
+                    RustField::from_name_type(
+                        "signed",
+                        RustType::I128(Range::inclusive(Some(i128::MIN), Some(0)))
+                    ),
+                    RustField::from_name_type(
+                        "semi",
+                        RustType::U128(Range::inclusive(Some(1 << 64), None))
+                    ),
+                    RustField::from_name_type(
+                        "narrow",
+                        RustType::I64(
+                            Range::inclusive(Some(i64::MIN), Some(i64::MAX)).with_extensible(true)
+                        )
+                    ),
+                ]),
+            ),
+            model_rust.definitions[0]
+        );
+    }
+
+    #[test]
+    fn test_semi_constrained_integers_keep_max_unbounded() {
+        let model_rust = Model::try_from(
+            Tokenizer
+                .parse(
+                    r"SemiSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+            Semi ::= SEQUENCE {
+                positive INTEGER (1..MAX),
+                offset INTEGER (-5..MAX)
+            }
+            END",
+                )
+                .unwrap(),
+        )
+        .unwrap()
+        .to_rust();
+
+        assert_eq!(
+            Definition(
+                "Semi".into(),
+                Rust::struct_from_fields(vec![
+                    RustField::from_name_type(
+                        "positive",
+                        RustType::U64(Range::inclusive(Some(1), None))
+                    ),
+                    RustField::from_name_type(
+                        "offset",
+                        RustType::I64(Range::inclusive(Some(-5), None))
+                    ),
+                ]),
+            ),
+            model_rust.definitions[0]
+        );
+    }
+
+    #[test]
+    fn test_explicit_tags_of_type_assignments() {
+        let model_rust = Model::try_from(
+            Tokenizer
+                .parse(
+                    r"ExplicitSchema DEFINITIONS IMPLICIT TAGS ::= BEGIN
+            Implicit ::= [APPLICATION 1] SEQUENCE { a INTEGER }
+            Explicit ::= [APPLICATION 2] EXPLICIT ENUMERATED { a, b }
+            Tagged ::= [APPLICATION 3] CHOICE { a INTEGER, b BOOLEAN }
+            Untagged ::= CHOICE { a INTEGER, b BOOLEAN }
+            END",
+                )
+                .unwrap(),
+        )
+        .unwrap()
+        .to_rust();
+
+        assert!(matches!(
+            model_rust.definitions[0].value(),
+            Rust::Struct {
+                explicit: false,
+                ..
+            }
+        ));
+        assert!(matches!(
+            model_rust.definitions[1].value(),
+            Rust::Enum(plain) if plain.is_explicit()
+        ));
+        // a tagged CHOICE is explicitly tagged regardless of the module
+        assert!(matches!(
+            model_rust.definitions[2].value(),
+            Rust::DataEnum(data) if data.is_explicit()
+        ));
+        assert!(matches!(
+            model_rust.definitions[3].value(),
+            Rust::DataEnum(data) if !data.is_explicit()
+        ));
+    }
+
+    #[test]
+    fn test_integers_with_set_arithmetic() {
+        let model_rust = Model::try_from(
+            Tokenizer
+                .parse(
+                    r"SetSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+            Sets ::= SEQUENCE {
+                sparse INTEGER (1 | 3 | 5..10),
+                except INTEGER (-5..5 EXCEPT 0)
+            }
+            END",
+                )
+                .unwrap(),
+        )
+        .unwrap()
+        .to_rust();
+
+        assert_eq!(
+            Definition(
+                "Sets".into(),
+                Rust::struct_from_fields(vec![
+                    RustField::from_name_type(
+                        "sparse",
+                        RustType::Constrained(
+                            Box::new(RustType::U8(Range::inclusive(1, 10))),
+                            ElementSet::Union(vec![
+                                ElementSet::Value(1),
+                                ElementSet::Value(3),
+                                ElementSet::Range(Some(5), Some(10)),
+                            ])
+                        )
+                    ),
+                    RustField::from_name_type(
+                        "except",
+                        RustType::Constrained(
+                            Box::new(RustType::I8(Range::inclusive(-5, 5))),
+                            ElementSet::Except(
+                                Box::new(ElementSet::Range(Some(-5), Some(5))),
+                                Box::new(ElementSet::Value(0)),
+                            )
+                        )
+                    ),
+                ]),
+            ),
+            model_rust.definitions[0]
+        );
+    }
+
+    #[test]
+    fn test_explicit_tags() {
+        let model_rust = Model::try_from(
+            Tokenizer
+                .parse(
+                    r"TagSchema DEFINITIONS EXPLICIT TAGS ::= BEGIN
+            Tags ::= SEQUENCE {
+                explicit [0] INTEGER (0..255),
+                implicit [1] IMPLICIT BOOLEAN,
+                untagged BOOLEAN OPTIONAL
+            }
+            Tagged ::= [APPLICATION 3] INTEGER (0..255)
+            END",
+                )
+                .unwrap(),
+        )
+        .unwrap()
+        .to_rust();
+
+        assert_eq!(
+            &[
+                Definition(
+                    "Tags".into(),
+                    Rust::struct_from_fields(vec![
+                        RustField::from_name_type(
+                            "explicit",
+                            RustType::U8(Range::inclusive(0, 255)).into_explicit()
+                        )
+                        .with_tag(Tag::ContextSpecific(0)),
+                        RustField::from_name_type("implicit", RustType::Bool)
+                            .with_tag(Tag::ContextSpecific(1)),
+                        RustField::from_name_type(
+                            "untagged",
+                            RustType::Option(Box::new(RustType::Bool))
+                        )
+                        .with_tag(Tag::DEFAULT_BOOLEAN),
+                    ]),
+                ),
+                Definition(
+                    "Tagged".into(),
+                    Rust::tuple_struct_from_type(
+                        RustType::U8(Range::inclusive(0, 255)).into_explicit()
+                    )
+                    .with_tag_opt(Some(Tag::Application(3))),
+                ),
+            ],
+            &model_rust.definitions[..]
+        );
+    }
+
+    #[test]
+    fn test_recursive_types() {
+        let model_rust = Model::try_from(
+            Tokenizer
+                .parse(
+                    r"RecursiveSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+            Node ::= SEQUENCE {
+                value INTEGER (0..255),
+                children SEQUENCE OF Node,
+                next Node OPTIONAL
+            }
+            Expression ::= CHOICE {
+                number INTEGER (0..255),
+                negation Expression,
+                sum Sum
+            }
+            Sum ::= SEQUENCE {
+                left Expression,
+                right Expression
+            }
+            END",
+                )
+                .unwrap(),
+        )
+        .unwrap()
+        .to_rust();
+
+        let boxed = |mut rust: Rust| {
+            rust.boxable_types_mut()
+                .into_iter()
+                .map(|r#type| r#type.is_boxed())
+                .collect::<Vec<_>>()
+        };
+        let [node, expression, sum] = [0, 1, 2].map(|i| model_rust.definitions[i].1.clone());
+        assert_eq!(vec![false, false, true], boxed(node));
+        assert_eq!(vec![false, true, false], boxed(expression));
+        assert_eq!(vec![true, true], boxed(sum));
+        assert_eq!(
+            RustType::Option(Box::new(RustType::Boxed(Box::new(RustType::Complex(
+                "Node".into(),
+                Some(Tag::DEFAULT_SEQUENCE)
+            ))))),
+            model_rust.definitions[0].1.clone().boxable_types_mut()[2].clone()
+        );
+    }
+
+    #[test]
+    fn test_inner_type_constraints() {
+        use crate::model::constraint::ValueConstraint;
+        use crate::model::constraint::{ComponentConstraint, InnerTypeConstraints, Presence};
+        let model_rust = Model::try_from(
+            Tokenizer
+                .parse(
+                    r"ComponentsSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+            Report ::= SEQUENCE {
+                id INTEGER (0..255),
+                speed INTEGER OPTIONAL
+            }
+            Known ::= Report (WITH COMPONENTS { ..., id (1 | 3), speed ABSENT })
+            END",
+                )
+                .unwrap(),
+        )
+        .unwrap()
+        .to_rust();
+
+        assert_eq!(
+            Definition(
+                "Known".into(),
+                Rust::TupleStruct {
+                    r#type: RustType::WithComponents(
+                        Box::new(RustType::Complex(
+                            "Report".into(),
+                            Some(Tag::DEFAULT_SEQUENCE)
+                        )),
+                        InnerTypeConstraints {
+                            partial: true,
+                            components: vec![
+                                ComponentConstraint {
+                                    name: "id".into(),
+                                    value: Some(ValueConstraint::Integer(ElementSet::Union(vec![
+                                        ElementSet::Value(1),
+                                        ElementSet::Value(3)
+                                    ]))),
+                                    presence: None,
+                                },
+                                ComponentConstraint {
+                                    name: "speed".into(),
+                                    value: None,
+                                    presence: Some(Presence::Absent),
+                                },
+                            ],
+                            element: None,
+                        }
+                    ),
+                    tag: Some(Tag::DEFAULT_SEQUENCE),
+                    constants: Vec::new(),
+                },
+            ),
+            model_rust.definitions[1]
+        );
+    }
+
+    #[test]
+    fn test_contents_constraints() {
+        let model_rust = Model::try_from(
+            Tokenizer
+                .parse(
+                    r"ContainingSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+            Report ::= SEQUENCE {
+                id INTEGER (0..255)
+            }
+            Envelope ::= SEQUENCE {
+                report [5] OCTET STRING (CONTAINING Report),
+                counter BIT STRING (CONTAINING INTEGER (0..255))
+            }
+            END",
+                )
+                .unwrap(),
+        )
+        .unwrap()
+        .to_rust();
+
+        let fields = match &model_rust.definitions[1] {
+            Definition(name, Rust::Struct { fields, .. }) if name == "Envelope" => fields,
+            definition => panic!("Unexpected definition: {:?}", definition),
+        };
+        assert_eq!(
+            vec![
+                &RustType::Containing(
+                    Box::new(RustType::Complex(
+                        "Report".into(),
+                        Some(Tag::DEFAULT_SEQUENCE)
+                    )),
+                    Container::OctetString
+                ),
+                &RustType::Containing(
+                    Box::new(RustType::U8(Range::inclusive(0, 255))),
+                    Container::BitString
+                ),
+            ],
+            fields.iter().map(RustField::r#type).collect::<Vec<_>>()
+        );
+        // the tag belongs to the string and not to the contained value
+        assert_eq!(Some(Tag::ContextSpecific(5)), fields[0].tag());
+    }
+
     #[test]
     fn test_inline_asn_enumerated_represented_correctly_as_rust_model() {
-        let modle_rust = Model::try_from(Tokenizer::default().parse(INLINE_ASN_WITH_ENUM))
+        let modle_rust = Model::try_from(Tokenizer::default().parse(INLINE_ASN_WITH_ENUM).unwrap())
             .unwrap()
             .to_rust();
 
@@ -992,9 +2237,13 @@ mod tests {
 
     #[test]
     fn test_inline_asn_sequence_of_represented_correctly_as_rust_model() {
-        let model_rust = Model::try_from(Tokenizer::default().parse(INLINE_ASN_WITH_SEQUENCE_OF))
-            .unwrap()
-            .to_rust();
+        let model_rust = Model::try_from(
+            Tokenizer::default()
+                .parse(INLINE_ASN_WITH_SEQUENCE_OF)
+                .unwrap(),
+        )
+        .unwrap()
+        .to_rust();
 
         assert_eq!("simple_schema", model_rust.name);
         assert_eq!(true, model_rust.imports.is_empty());
@@ -1036,7 +2285,8 @@ mod tests {
                             Size::Any,
                             EncodingOrdering::Keep
                         ),
-                    ),
+                    )
+                    .with_asn_name("also-ones"),
                     RustField::from_name_type(
                         "nesteds",
                         RustType::Vec(
@@ -1069,9 +2319,10 @@ mod tests {
 
     #[test]
     fn test_inline_asn_choice_represented_correctly_as_rust_model() {
-        let model_rust = Model::try_from(Tokenizer::default().parse(INLINE_ASN_WITH_CHOICE))
-            .unwrap()
-            .to_rust();
+        let model_rust =
+            Model::try_from(Tokenizer::default().parse(INLINE_ASN_WITH_CHOICE).unwrap())
+                .unwrap()
+                .to_rust();
 
         assert_eq!("simple_schema", model_rust.name);
         assert_eq!(true, model_rust.imports.is_empty());
@@ -1117,15 +2368,18 @@ mod tests {
                         DataVariant::from_name_type(
                             "This",
                             RustType::Complex("This".into(), Some(Tag::DEFAULT_SEQUENCE_OF))
-                        ),
+                        )
+                        .with_asn_name("this"),
                         DataVariant::from_name_type(
                             "That",
                             RustType::Complex("That".into(), Some(Tag::DEFAULT_SEQUENCE_OF))
-                        ),
+                        )
+                        .with_asn_name("that"),
                         DataVariant::from_name_type(
                             "Neither",
                             RustType::Complex("Neither".into(), Some(Tag::DEFAULT_ENUMERATED))
-                        ),
+                        )
+                        .with_asn_name("neither"),
                     ]
                     .into()
                 )
@@ -1146,9 +2400,13 @@ mod tests {
 
     #[test]
     fn test_inline_asn_sequence_represented_correctly_as_rust_model() {
-        let model_rust = Model::try_from(Tokenizer::default().parse(INLINE_ASN_WITH_SEQUENCE))
-            .unwrap()
-            .to_rust();
+        let model_rust = Model::try_from(
+            Tokenizer::default()
+                .parse(INLINE_ASN_WITH_SEQUENCE)
+                .unwrap(),
+        )
+        .unwrap()
+        .to_rust();
 
         assert_eq!("simple_schema", model_rust.name);
         assert_eq!(true, model_rust.imports.is_empty());
@@ -1165,7 +2423,8 @@ mod tests {
                             Size::Any,
                             EncodingOrdering::Keep
                         ),
-                    ),
+                    )
+                    .with_asn_name("list-ones"),
                     RustField::from_name_type(
                         "optional_ones",
                         RustType::Option(Box::new(RustType::Vec(
@@ -1173,7 +2432,8 @@ mod tests {
                             Size::Any,
                             EncodingOrdering::Keep
                         ))),
-                    ),
+                    )
+                    .with_asn_name("optional-ones"),
                 ]),
             ),
             model_rust.definitions[0]
@@ -1210,7 +2470,14 @@ mod tests {
         assert_eq!(
             Definition(
                 "SimpleEnumTest".into(),
-                Rust::Enum(vec!["Bernd".into(), "DasVerdammte".into(), "Brooot".into(),].into()),
+                Rust::Enum(
+                    vec![
+                        "Bernd".into(),
+                        PlainVariant::from_name("DasVerdammte").with_asn_name("Das-Verdammte"),
+                        "Brooot".into(),
+                    ]
+                    .into()
+                ),
             ),
             model_rust.definitions[0]
         );
@@ -1238,9 +2505,11 @@ mod tests {
                     vec![
                         DataVariant::from_name_type(
                             "BerndDasBrot",
-                            RustType::String(Size::Any, Charset::Utf8),
-                        ),
-                        DataVariant::from_name_type("NochSoEinBrot", RustType::VecU8(Size::Any)),
+                            RustType::String(Size::Any, Charset::Utf8, None),
+                        )
+                        .with_asn_name("bernd-das-brot"),
+                        DataVariant::from_name_type("NochSoEinBrot", RustType::VecU8(Size::Any))
+                            .with_asn_name("nochSoEinBrot"),
                     ]
                     .into()
                 ),
@@ -1284,11 +2553,12 @@ mod tests {
                         DataVariant::from_name_type(
                             "NormalList",
                             RustType::Vec(
-                                Box::new(RustType::String(Size::Any, Charset::Utf8)),
+                                Box::new(RustType::String(Size::Any, Charset::Utf8, None)),
                                 Size::Any,
                                 EncodingOrdering::Keep
                             ),
-                        ),
+                        )
+                        .with_asn_name("normal-List"),
                         DataVariant::from_name_type(
                             "NESTEDList",
                             RustType::Vec(
@@ -1326,7 +2596,7 @@ mod tests {
             Definition(
                 "TupleTest".into(),
                 Rust::tuple_struct_from_type(RustType::Vec(
-                    Box::new(RustType::String(Size::Any, Charset::Utf8)),
+                    Box::new(RustType::String(Size::Any, Charset::Utf8, None)),
                     Size::Any,
                     EncodingOrdering::Keep
                 )),
@@ -1359,7 +2629,7 @@ mod tests {
                 "NestedTupleTest".into(),
                 Rust::tuple_struct_from_type(RustType::Vec(
                     Box::new(RustType::Vec(
-                        Box::new(RustType::String(Size::Any, Charset::Utf8)),
+                        Box::new(RustType::String(Size::Any, Charset::Utf8, None)),
                         Size::Any,
                         EncodingOrdering::Keep
                     )),
@@ -1395,7 +2665,7 @@ mod tests {
                 Rust::struct_from_fields(vec![RustField::from_name_type(
                     "strings",
                     RustType::Option(Box::new(RustType::Vec(
-                        Box::new(RustType::String(Size::Any, Charset::Utf8)),
+                        Box::new(RustType::String(Size::Any, Charset::Utf8, None)),
                         Size::Any,
                         EncodingOrdering::Keep
                     ))),
@@ -1428,7 +2698,7 @@ mod tests {
                 Rust::struct_from_fields(vec![RustField::from_name_type(
                     "strings",
                     RustType::Vec(
-                        Box::new(RustType::String(Size::Any, Charset::Utf8)),
+                        Box::new(RustType::String(Size::Any, Charset::Utf8, None)),
                         Size::Any,
                         EncodingOrdering::Keep
                     ),
@@ -1468,7 +2738,7 @@ mod tests {
                     "strings",
                     RustType::Vec(
                         Box::new(RustType::Vec(
-                            Box::new(RustType::String(Size::Any, Charset::Utf8)),
+                            Box::new(RustType::String(Size::Any, Charset::Utf8, None)),
                             Size::Any,
                             EncodingOrdering::Keep
                         )),
@@ -1494,6 +2764,7 @@ mod tests {
                     EnumeratedVariant::from_name_number("ghi", 42),
                 ],
                 extension_after: Some(2),
+                unknown_extensions: None,
             })
             .untagged(),
         ));
@@ -1504,14 +2775,35 @@ mod tests {
             &[Definition(
                 "Extensible".into(),
                 Rust::Enum(
-                    PlainEnum::from_names(["Abc", "Def", "Ghi"].iter())
-                        .with_extension_after(Some(2))
+                    PlainEnum::from(vec![
+                        PlainVariant::from_name("Abc").with_asn_name("abc"),
+                        PlainVariant::from_name("Def").with_asn_name("def"),
+                        PlainVariant::from_name("Ghi")
+                            .with_asn_name("ghi")
+                            .with_number(Some(42)),
+                    ])
+                    .with_extension_after(Some(2))
                 ),
             )],
             &model_rust.definitions[..]
         );
     }
 
+    #[test]
+    pub fn test_enum_variant_values() {
+        let plain = PlainEnum::from(vec![
+            PlainVariant::from_name("A"),
+            PlainVariant::from_name("B").with_number(Some(0)),
+            PlainVariant::from_name("C"),
+            PlainVariant::from_name("D").with_number(Some(7)),
+            PlainVariant::from_name("E"),
+            PlainVariant::from_name("F").with_number(Some(9)),
+            PlainVariant::from_name("G"),
+        ])
+        .with_extension_after(Some(3));
+        assert_eq!(vec![1, 0, 2, 7, 3, 9, 10], plain.variant_values());
+    }
+
     #[test]
     pub fn test_extensible_choice() {
         let mut model_asn = Model::default();
@@ -1525,10 +2817,13 @@ mod tests {
                     ChoiceVariant {
                         name: "ghi".to_string(),
                         tag: Some(Tag::Universal(4)),
+                        tagging: None,
                         r#type: Type::Boolean,
+                        boxed: false,
                     },
                 ],
                 extension_after: Some(2),
+                unknown_extensions: None,
             })
             .untagged(),
         ));
@@ -1541,12 +2836,18 @@ mod tests {
                 "Extensible".into(),
                 Rust::DataEnum(
                     DataEnum::from(vec![
-                        DataVariant::from_name_type("Abc".to_string(), RustType::VecU8(Size::Any)),
+                        // not tagged automatically, because another variant is tagged
+                        DataVariant::from_name_type("Abc".to_string(), RustType::VecU8(Size::Any))
+                            .with_asn_name("abc")
+                            .with_tag(Tag::DEFAULT_OCTET_STRING),
                         DataVariant::from_name_type(
                             "Def".to_string(),
                             RustType::U64(Range::none()),
-                        ),
+                        )
+                        .with_asn_name("def")
+                        .with_tag(Tag::DEFAULT_INTEGER),
                         DataVariant::from_name_type("Ghi".to_string(), RustType::Bool)
+                            .with_asn_name("ghi")
                             .with_tag(Tag::Universal(4)),
                     ])
                     .with_extension_after(Some(2))
@@ -1555,4 +2856,168 @@ mod tests {
             &model_rust.definitions[..]
         );
     }
+
+    #[test]
+    pub fn test_value_references_are_resolved_across_imports() {
+        let constants = Model::try_from(
+            Tokenizer
+                .parse(
+                    r"Constants DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+            maxItems INTEGER ::= 23
+            fixedSize INTEGER ::= 4
+            END",
+                )
+                .unwrap(),
+        )
+        .unwrap();
+        let model = Model::try_from(
+            Tokenizer
+                .parse(
+                    r"Items DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+            IMPORTS maxItems, fixedSize FROM Constants;
+            minValue INTEGER ::= -1
+            Items ::= SEQUENCE (SIZE(1..maxItems)) OF INTEGER (minValue..maxItems)
+            Fixed ::= OCTET STRING (SIZE(fixedSize..fixedSize))
+            END",
+                )
+                .unwrap(),
+        )
+        .unwrap();
+
+        let model_rust = model.to_rust_with_scope(&[&constants, &model]);
+        assert_eq!(
+            vec!["MAX_ITEMS".to_string(), "FIXED_SIZE".to_string()],
+            model_rust.imports[0].what
+        );
+        assert_eq!(
+            vec![ValueReference {
+                name: "MIN_VALUE".to_string(),
+                role: RustType::I64(Range::inclusive(Some(i64::MIN), Some(i64::MAX))),
+                value: LiteralValue::Integer(-1),
+            }],
+            model_rust.value_references
+        );
+        assert_eq!(
+            vec![
+                Definition(
+                    "Items".to_string(),
+                    Rust::tuple_struct_from_type(RustType::Vec(
+                        Box::new(RustType::I8(Range::inclusive(-1, 23))),
+                        Size::Range(1, 23, false),
+                        EncodingOrdering::Keep
+                    ))
+                ),
+                Definition(
+                    "Fixed".to_string(),
+                    Rust::tuple_struct_from_type(RustType::VecU8(Size::Fix(4, false)))
+                ),
+            ],
+            model_rust.definitions
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Unable to resolve the value reference maxItems")]
+    pub fn test_value_reference_of_module_not_in_scope() {
+        Model::try_from(
+            Tokenizer
+                .parse(
+                    r"Items DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+            IMPORTS maxItems FROM Constants;
+            Items ::= SEQUENCE (SIZE(1..maxItems)) OF INTEGER
+            END",
+                )
+                .unwrap(),
+        )
+        .unwrap()
+        .to_rust();
+    }
+
+    #[test]
+    pub fn test_components_of_are_included_across_imports() {
+        let common = Model::try_from(
+            Tokenizer
+                .parse(
+                    r"Common DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+            StationId ::= INTEGER (0..4294967295)
+            Header ::= SEQUENCE {
+                version INTEGER (0..255),
+                station StationId,
+                ...,
+                extended BOOLEAN
+            }
+            END",
+                )
+                .unwrap(),
+        )
+        .unwrap();
+        let model = Model::try_from(
+            Tokenizer
+                .parse(
+                    r"Messages DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+            IMPORTS Header FROM Common;
+            Message ::= SEQUENCE {
+                COMPONENTS OF Header,
+                payload OCTET STRING,
+                ...
+            }
+            END",
+                )
+                .unwrap(),
+        )
+        .unwrap();
+
+        let model_rust = model.to_rust_with_scope(&[&common, &model]);
+        // the type of the included component is imported as well
+        assert_eq!(
+            vec!["Header".to_string(), "StationId".to_string()],
+            model_rust.imports[0].what
+        );
+        assert_eq!(
+            vec![Definition(
+                "Message".to_string(),
+                Rust::Struct {
+                    ordering: EncodingOrdering::Keep,
+                    fields: vec![
+                        RustField::from_name_type(
+                            "version",
+                            RustType::U8(Range::inclusive(0, 255))
+                        ),
+                        RustField::from_name_type(
+                            "station",
+                            RustType::Complex("StationId".into(), Some(Tag::DEFAULT_INTEGER))
+                        ),
+                        RustField::from_name_type("payload", RustType::VecU8(Size::Any)),
+                    ],
+                    tag: None,
+                    extension_after: Some(2),
+                    unknown_extensions: None,
+                    explicit: false,
+                }
+            )],
+            model_rust.definitions
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "The type Header of COMPONENTS OF is neither assigned in Messages nor imported from a module in the scope"
+    )]
+    pub fn test_components_of_module_not_in_scope() {
+        Model::try_from(
+            Tokenizer
+                .parse(
+                    r"Messages DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+            IMPORTS Header FROM Common;
+            Message ::= SEQUENCE {
+                COMPONENTS OF Header,
+                payload OCTET STRING
+            }
+            END",
+                )
+                .unwrap(),
+        )
+        .unwrap()
+        .to_rust();
+    }
 }
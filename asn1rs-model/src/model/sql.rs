@@ -4,7 +4,7 @@ use crate::model::rust::{Field, PlainEnum};
 use crate::model::Range;
 use crate::model::Rust;
 use crate::model::RustType;
-use crate::model::{Charset, Model};
+use crate::model::{Charset, Model, Target};
 use crate::model::{Definition, Size};
 
 const FOREIGN_KEY_DEFAULT_COLUMN: &str = "id";
@@ -18,6 +18,7 @@ pub enum SqlType {
     Integer,  // 4byte
     BigInt,   // 8byte
     Serial,   // 4byte
+    Double,   // 8byte
     Boolean,
     Text,
     Array(Box<SqlType>),
@@ -60,10 +61,11 @@ impl SqlType {
         RustType::Option(Box::new(match self {
             SqlType::SmallInt => RustType::I16(Range::inclusive(0, i16::max_value())),
             SqlType::Integer => RustType::I32(Range::inclusive(0, i32::max_value())),
-            SqlType::BigInt => RustType::I64(Range::inclusive(0, i64::max_value())),
+            SqlType::BigInt => RustType::I64(Range::inclusive(Some(0), Some(i64::max_value()))),
             SqlType::Serial => RustType::I32(Range::inclusive(0, i32::max_value())),
+            SqlType::Double => RustType::F64,
             SqlType::Boolean => RustType::Bool,
-            SqlType::Text => RustType::String(Size::Any, Charset::Utf8),
+            SqlType::Text => RustType::String(Size::Any, Charset::Utf8, None),
             SqlType::Array(inner) => {
                 RustType::Vec(Box::new(inner.to_rust()), Size::Any, EncodingOrdering::Keep)
             }
@@ -82,6 +84,7 @@ impl ToString for SqlType {
             SqlType::Integer => "INTEGER".into(),
             SqlType::BigInt => "BIGINT".into(),
             SqlType::Serial => "SERIAL".into(),
+            SqlType::Double => "DOUBLE PRECISION".into(),
             SqlType::Boolean => "BOOLEAN".into(),
             SqlType::Text => "TEXT".into(),
             SqlType::Array(inner) => format!("{}[]", inner.to_string()),
@@ -129,16 +132,27 @@ pub enum Sql {
     SilentlyPreventAnyDelete(String),
 }
 
+impl Target for Sql {
+    type ValueReferenceType = SqlType;
+}
+
 impl Model<Sql> {
     pub fn convert_rust_to_sql(rust_model: &Model<Rust>) -> Model<Sql> {
         let mut model = Model {
             name: rust_model.name.clone(),
             oid: rust_model.oid.clone(),
+            tag_default: rust_model.tag_default,
+            extensibility_implied: rust_model.extensibility_implied,
             imports: Default::default(), // ignored in SQL
             definitions: Vec::with_capacity(rust_model.definitions.len()),
+            value_references: Default::default(), // ignored in SQL
+            classes: Vec::default(),
+            objects: Vec::default(),
+            object_sets: Vec::default(),
         };
-        for Definition(name, rust) in &rust_model.definitions {
-            Self::definition_to_sql(name, rust, &mut model.definitions);
+        for definition in &rust_model.definitions {
+            let Definition(name, rust) = definition.with_object_identifiers_as_vec();
+            Self::definition_to_sql(&name, &rust, &mut model.definitions);
         }
         model
     }
@@ -149,10 +163,12 @@ impl Model<Sql> {
                 fields,
                 tag: _,
                 extension_after: _,
+                unknown_extensions: _,
                 ordering: _,
+                explicit: _,
             } => Self::rust_struct_to_sql_table(name, fields, definitions),
             Rust::Enum(rust_enum) => Self::rust_enum_to_sql_enum(name, rust_enum, definitions),
-            Rust::DataEnum(enumeration) => {
+            Rust::DataEnum(enumeration) | Rust::OpenType(enumeration) => {
                 Self::rust_data_enum_to_sql_table(name, enumeration, definitions)
             }
             Rust::TupleStruct { r#type: rust, .. } => {
@@ -265,7 +281,10 @@ impl Model<Sql> {
         enumeration: &PlainEnum,
         definitions: &mut Vec<Definition<Sql>>,
     ) {
-        let variants = enumeration.variants().map(String::clone).collect();
+        let variants = enumeration
+            .variants()
+            .map(|v| v.name().to_string())
+            .collect();
         definitions.push(Definition(name.into(), Sql::Enum(variants)));
         Self::add_silently_prevent_any_delete(name, definitions);
     }
@@ -410,6 +429,7 @@ impl Model<Sql> {
         #[allow(clippy::match_same_arms)] // to have the same order as the original enum
         match rust.clone().into_inner_type() {
             RustType::String(..) => true,
+            RustType::Time(_) => true,
             RustType::VecU8(_) => true,
             RustType::BitVec(_) => true,
             r => r.is_primitive(),
@@ -454,9 +474,23 @@ impl ToSql for RustType {
             }
             RustType::U16(_) | RustType::I32(_) => SqlType::Integer,
             RustType::U32(_) | RustType::U64(_) | RustType::I64(_) => SqlType::BigInt,
-            RustType::String(_size, _charset) => SqlType::Text,
+            // in their decimal notation
+            RustType::I128(_) | RustType::U128(_) | RustType::BigInt(_) => SqlType::Text,
+            RustType::Constrained(inner, _)
+            | RustType::WithComponents(inner, _)
+            | RustType::Containing(inner, _)
+            | RustType::Explicit(inner)
+            | RustType::Boxed(inner) => return inner.to_sql(),
+            RustType::String(_size, _charset, _alphabet) => SqlType::Text,
+            RustType::Time(_) => SqlType::Text,
             RustType::VecU8(_) => SqlType::ByteArray,
             RustType::BitVec(_) => SqlType::BitsReprByByteArrayAndBitsLen,
+            // the presence of the value
+            RustType::Null => SqlType::Boolean,
+            RustType::F64 => SqlType::Double,
+            RustType::ObjectIdentifier | RustType::RelativeOid => {
+                return self.clone().with_object_identifiers_as_vec().to_sql()
+            }
             RustType::Vec(inner, _size, _ordering) => SqlType::Array(inner.to_sql().into()),
             RustType::Option(inner) => return inner.to_sql().nullable(),
             RustType::Complex(name, _tag) => SqlType::References(
@@ -473,7 +507,7 @@ impl ToSql for RustType {
 mod tests {
     use super::*;
     use crate::model::rust::{EncodingOrdering, Field};
-    use crate::model::{Charset, Model, Tag};
+    use crate::model::{Charset, Model, Tag, TagDefault};
     use crate::model::{Import, Size};
 
     #[test]
@@ -481,15 +515,21 @@ mod tests {
         let model = Model {
             name: "Manfred".into(),
             oid: None,
+            tag_default: TagDefault::Automatic,
+            extensibility_implied: false,
             imports: vec![Import {
                 what: vec!["a".into(), "b".into()],
                 from: "to_be_ignored".into(),
                 from_oid: None,
             }],
+            value_references: Vec::default(),
+            classes: Vec::default(),
+            objects: Vec::default(),
+            object_sets: Vec::default(),
             definitions: vec![Definition(
                 "Person".into(),
                 Rust::struct_from_fields(vec![
-                    Field::from_name_type("name", RustType::String(Size::Any, Charset::Utf8)),
+                    Field::from_name_type("name", RustType::String(Size::Any, Charset::Utf8, None)),
                     Field::from_name_type("birth", RustType::Complex("City".into(), None)),
                 ]),
             )],
@@ -551,18 +591,24 @@ mod tests {
         let model = Model {
             name: "Hurray".into(),
             oid: None,
+            tag_default: TagDefault::Automatic,
+            extensibility_implied: false,
             imports: vec![Import {
                 what: vec!["a".into(), "b".into()],
                 from: "to_be_ignored".into(),
                 from_oid: None,
             }],
+            value_references: Vec::default(),
+            classes: Vec::default(),
+            objects: Vec::default(),
+            object_sets: Vec::default(),
             definitions: vec![Definition(
                 "PersonState".into(),
                 Rust::DataEnum(
                     vec![
                         DataVariant::from_name_type(
                             "DeadSince",
-                            RustType::String(Size::Any, Charset::Utf8),
+                            RustType::String(Size::Any, Charset::Utf8, None),
                         ),
                         DataVariant::from_name_type(
                             "Alive",
@@ -630,11 +676,17 @@ mod tests {
         let model = Model {
             name: "Alfred".into(),
             oid: None,
+            tag_default: TagDefault::Automatic,
+            extensibility_implied: false,
             imports: vec![Import {
                 what: vec!["a".into(), "b".into()],
                 from: "to_be_ignored".into(),
                 from_oid: None,
             }],
+            value_references: Vec::default(),
+            classes: Vec::default(),
+            objects: Vec::default(),
+            object_sets: Vec::default(),
             definitions: vec![Definition(
                 "City".into(),
                 Rust::Enum(vec!["Esslingen".into(), "Stuttgart".into()].into()),
@@ -663,14 +715,20 @@ mod tests {
         let model = Model {
             name: "Bernhard".into(),
             oid: None,
+            tag_default: TagDefault::Automatic,
+            extensibility_implied: false,
             imports: vec![],
+            value_references: Vec::default(),
+            classes: Vec::default(),
+            objects: Vec::default(),
+            object_sets: Vec::default(),
             definitions: vec![Definition(
                 "SomeStruct".into(),
                 Rust::struct_from_fields(vec![
                     Field::from_name_type(
                         "list_of_primitive",
                         RustType::Vec(
-                            Box::new(RustType::String(Size::Any, Charset::Utf8)),
+                            Box::new(RustType::String(Size::Any, Charset::Utf8, None)),
                             Size::Any,
                             EncodingOrdering::Keep,
                         ),
@@ -798,15 +856,21 @@ mod tests {
         let model = Model {
             name: "Hurray".into(),
             oid: None,
+            tag_default: TagDefault::Automatic,
+            extensibility_implied: false,
             imports: vec![Import {
                 what: vec!["a".into(), "b".into()],
                 from: "to_be_ignored".into(),
                 from_oid: None,
             }],
+            value_references: Vec::default(),
+            classes: Vec::default(),
+            objects: Vec::default(),
+            object_sets: Vec::default(),
             definitions: vec![
                 Definition(
                     "Whatever".into(),
-                    Rust::tuple_struct_from_type(RustType::String(Size::Any, Charset::Utf8)),
+                    Rust::tuple_struct_from_type(RustType::String(Size::Any, Charset::Utf8, None)),
                 ),
                 Definition(
                     "Whatelse".into(),
@@ -959,16 +1023,22 @@ mod tests {
         let model = Model {
             name: "Alfred".into(),
             oid: None,
+            tag_default: TagDefault::Automatic,
+            extensibility_implied: false,
             imports: vec![Import {
                 what: vec!["a".into(), "b".into()],
                 from: "to_be_ignored".into(),
                 from_oid: None,
             }],
+            value_references: Vec::default(),
+            classes: Vec::default(),
+            objects: Vec::default(),
+            object_sets: Vec::default(),
             definitions: vec![Definition(
                 "City".into(),
                 Rust::struct_from_fields(vec![Field::from_name_type(
                     "id",
-                    RustType::String(Size::Any, Charset::Utf8),
+                    RustType::String(Size::Any, Charset::Utf8, None),
                 )]),
             )],
         }
@@ -1047,30 +1117,30 @@ mod tests {
             RustType::U32(Range::inclusive(0, u32::max_value()))
                 .to_sql()
                 .to_rust(),
-            RustType::I64(Range::inclusive(0, i64::max_value()))
+            RustType::I64(Range::inclusive(Some(0), Some(i64::max_value())))
         );
         assert_eq!(
-            RustType::I64(Range::inclusive(0, i64::max_value()))
+            RustType::I64(Range::inclusive(Some(0), Some(i64::max_value())))
                 .to_sql()
                 .to_rust(),
-            RustType::I64(Range::inclusive(0, i64::max_value()))
+            RustType::I64(Range::inclusive(Some(0), Some(i64::max_value())))
         );
         assert_eq!(
             RustType::U64(Range::none()).to_sql().to_rust(),
-            RustType::I64(Range::inclusive(0, i64::max_value()))
+            RustType::I64(Range::inclusive(Some(0), Some(i64::max_value())))
         );
         assert_eq!(
             RustType::U64(Range::inclusive(Some(0), Some(u64::max_value())))
                 .to_sql()
                 .to_rust(),
-            RustType::I64(Range::inclusive(0, i64::max_value()))
+            RustType::I64(Range::inclusive(Some(0), Some(i64::max_value())))
         );
 
         assert_eq!(
-            RustType::String(Size::Any, Charset::Utf8)
+            RustType::String(Size::Any, Charset::Utf8, None)
                 .to_sql()
                 .to_rust(),
-            RustType::String(Size::Any, Charset::Utf8),
+            RustType::String(Size::Any, Charset::Utf8, None),
         );
         assert_eq!(
             RustType::VecU8(Size::Any).to_sql().to_rust(),
@@ -1078,14 +1148,14 @@ mod tests {
         );
         assert_eq!(
             RustType::Vec(
-                Box::new(RustType::String(Size::Any, Charset::Utf8)),
+                Box::new(RustType::String(Size::Any, Charset::Utf8, None)),
                 Size::Any,
                 EncodingOrdering::Keep
             )
             .to_sql()
             .to_rust(),
             RustType::Vec(
-                Box::new(RustType::String(Size::Any, Charset::Utf8)),
+                Box::new(RustType::String(Size::Any, Charset::Utf8, None)),
                 Size::Any,
                 EncodingOrdering::Keep
             ),
@@ -1113,6 +1183,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_null_real_and_object_identifier_to_sql() {
+        assert_eq!(
+            SqlType::NotNull(SqlType::Boolean.into()),
+            RustType::Null.to_sql()
+        );
+        assert_eq!(
+            SqlType::NotNull(SqlType::Double.into()),
+            RustType::F64.to_sql()
+        );
+        assert_eq!(
+            RustType::Vec(
+                Box::new(RustType::U64(Range::none())),
+                Size::Any,
+                EncodingOrdering::Keep
+            )
+            .to_sql(),
+            RustType::ObjectIdentifier.to_sql()
+        );
+        assert_eq!("DOUBLE PRECISION", &SqlType::Double.to_string());
+    }
+
     #[test]
     fn test_nullable() {
         assert_eq!(
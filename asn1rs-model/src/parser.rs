@@ -1,5 +1,109 @@
+use crate::model::Error;
 use std::fmt::{Display, Formatter};
 
+/// The reserved words, see ITU-T X.680 | ISO/IEC 8824-1:2015, 12.38
+const KEYWORDS: &[&str] = &[
+    "ABSENT",
+    "ABSTRACT-SYNTAX",
+    "ALL",
+    "APPLICATION",
+    "AUTOMATIC",
+    "BEGIN",
+    "BIT",
+    "BMPString",
+    "BOOLEAN",
+    "BY",
+    "CHARACTER",
+    "CHOICE",
+    "CLASS",
+    "COMPONENT",
+    "COMPONENTS",
+    "CONSTRAINED",
+    "CONTAINING",
+    "DATE",
+    "DATE-TIME",
+    "DEFAULT",
+    "DEFINITIONS",
+    "DURATION",
+    "EMBEDDED",
+    "ENCODED",
+    "ENCODING-CONTROL",
+    "END",
+    "ENUMERATED",
+    "EXCEPT",
+    "EXPLICIT",
+    "EXPORTS",
+    "EXTENSIBILITY",
+    "EXTERNAL",
+    "FALSE",
+    "FROM",
+    "GeneralizedTime",
+    "GeneralString",
+    "GraphicString",
+    "IA5String",
+    "IDENTIFIER",
+    "IMPLICIT",
+    "IMPLIED",
+    "IMPORTS",
+    "INCLUDES",
+    "INSTANCE",
+    "INSTRUCTIONS",
+    "INTEGER",
+    "INTERSECTION",
+    "ISO646String",
+    "MAX",
+    "MIN",
+    "MINUS-INFINITY",
+    "NOT-A-NUMBER",
+    "NULL",
+    "NumericString",
+    "OBJECT",
+    "ObjectDescriptor",
+    "OCTET",
+    "OF",
+    "OID-IRI",
+    "OPTIONAL",
+    "PATTERN",
+    "PDV",
+    "PLUS-INFINITY",
+    "PRESENT",
+    "PrintableString",
+    "PRIVATE",
+    "REAL",
+    "RELATIVE-OID",
+    "RELATIVE-OID-IRI",
+    "SEQUENCE",
+    "SET",
+    "SETTINGS",
+    "SIZE",
+    "STRING",
+    "SYNTAX",
+    "T61String",
+    "TAGS",
+    "TeletexString",
+    "TIME",
+    "TIME-OF-DAY",
+    "TRUE",
+    "TYPE-IDENTIFIER",
+    "UNION",
+    "UNIQUE",
+    "UNIVERSAL",
+    "UniversalString",
+    "UTCTime",
+    "UTF8String",
+    "VideotexString",
+    "VisibleString",
+    "WITH",
+];
+
+/// The lexical items consisting of more than one character, longest first
+const SYMBOLS: &[&str] = &["::=", "...", "..", "[[", "]]"];
+
+/// The lexical items consisting of a single character
+const SEPARATORS: &[char] = &[
+    ':', ';', '=', '(', ')', '{', '}', '.', ',', '[', ']', '|', '^', '@', '!', '<', '>',
+];
+
 #[derive(Debug, Default, Copy, Clone, PartialOrd, PartialEq)]
 pub struct Location {
     line: usize,
@@ -20,10 +124,25 @@ impl Location {
     }
 }
 
-#[derive(Debug, PartialOrd, PartialEq)]
+/// A lexical item, see ITU-T X.680 | ISO/IEC 8824-1:2015, chapter 12
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
 pub enum Token {
-    Text(Location, String),
+    /// A (type or value) reference or an identifier, including field references like `&id`
+    Identifier(Location, String),
+    /// A reserved word, see [`KEYWORDS`]
+    Keyword(Location, String),
+    /// A number or real number, possibly negative
+    Number(Location, String),
+    /// The content of a character string, with its quotes removed and its escapes resolved
+    CString(Location, String),
+    /// The binary digits of a binary string, without its quotes and white-space
+    BString(Location, String),
+    /// The hexadecimal digits of a hexadecimal string, without its quotes and white-space
+    HString(Location, String),
+    /// A lexical item consisting of a single character, like `{`, `,` or `|`
     Separator(Location, char),
+    /// A lexical item consisting of more than one character, like `::=`, `..` or `[[`
+    Symbol(Location, &'static str),
 }
 
 impl From<char> for Token {
@@ -34,37 +153,36 @@ impl From<char> for Token {
 
 impl From<String> for Token {
     fn from(text: String) -> Self {
-        Token::Text(Location::default(), text)
+        Token::Identifier(Location::default(), text)
     }
 }
 
 impl Display for Token {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         match self {
-            Token::Text(_, text) => write!(f, "\"{}\"", text),
+            Token::Identifier(_, text) | Token::Keyword(_, text) | Token::Number(_, text) => {
+                write!(f, "\"{}\"", text)
+            }
+            Token::CString(_, text) => write!(f, "\"{}\"", text.replace('"', "\"\"")),
+            Token::BString(_, digits) => write!(f, "'{}'B", digits),
+            Token::HString(_, digits) => write!(f, "'{}'H", digits),
             Token::Separator(_, separator) => write!(f, "\'{}\'", separator),
+            Token::Symbol(_, symbol) => write!(f, "\'{}\'", symbol),
         }
     }
 }
 
 impl Token {
-    fn append(self, other: Token) -> (Token, Option<Token>) {
-        match (self, other) {
-            (Token::Text(location, mut text), Token::Text(_, other)) => (
-                Token::Text(location, {
-                    text.push_str(&other);
-                    text
-                }),
-                None,
-            ),
-            (a, b) => (a, Some(b)),
-        }
-    }
-
     pub fn location(&self) -> Location {
         match self {
-            Token::Text(location, _) => *location,
+            Token::Identifier(location, _) => *location,
+            Token::Keyword(location, _) => *location,
+            Token::Number(location, _) => *location,
+            Token::CString(location, _) => *location,
+            Token::BString(location, _) => *location,
+            Token::HString(location, _) => *location,
             Token::Separator(location, _) => *location,
+            Token::Symbol(location, _) => *location,
         }
     }
 
@@ -90,9 +208,16 @@ impl Token {
         self.separator().map(|s| s == separator).unwrap_or(false)
     }
 
+    pub fn eq_symbol(&self, symbol: &str) -> bool {
+        self.symbol().map(|s| s == symbol).unwrap_or(false)
+    }
+
+    /// The text of an identifier, keyword or number
     pub fn text(&self) -> Option<&str> {
         match self {
-            Token::Text(_, text) => Some(text),
+            Token::Identifier(_, text) | Token::Keyword(_, text) | Token::Number(_, text) => {
+                Some(text)
+            }
             _ => None,
         }
     }
@@ -104,25 +229,43 @@ impl Token {
         }
     }
 
+    pub fn symbol(&self) -> Option<&'static str> {
+        match self {
+            Token::Symbol(_, symbol) => Some(*symbol),
+            _ => None,
+        }
+    }
+
     pub fn is_text(&self) -> bool {
         self.text().is_some()
     }
 
+    pub fn is_keyword(&self) -> bool {
+        matches!(self, Token::Keyword(..))
+    }
+
     pub fn is_separator(&self) -> bool {
         self.separator().is_some()
     }
 
+    pub fn is_symbol(&self) -> bool {
+        self.symbol().is_some()
+    }
+
     pub fn into_text(self) -> Option<String> {
-        if let Token::Text(_, text) = self {
-            Some(text)
-        } else {
-            None
+        match self {
+            Token::Identifier(_, text) | Token::Keyword(_, text) | Token::Number(_, text) => {
+                Some(text)
+            }
+            _ => None,
         }
     }
 
     pub fn into_text_or_else<E, F: Fn(Token) -> E>(self, f: F) -> Result<String, E> {
         match self {
-            Token::Text(_, text) => Ok(text),
+            Token::Identifier(_, text) | Token::Keyword(_, text) | Token::Number(_, text) => {
+                Ok(text)
+            }
             token => Err(f(token)),
         }
     }
@@ -139,69 +282,269 @@ impl Token {
 pub struct Tokenizer;
 
 impl Tokenizer {
-    pub fn parse(&self, asn: &str) -> Vec<Token> {
-        let mut previous = None;
+    /// Splits the given ASN.1 definitions into their lexical items. White-space as well as line
+    /// (`-- ... --`) and nested block (`/* ... */`) comments are skipped, unexpected characters
+    /// are reported and ignored. Unterminated character strings and block comments as well as
+    /// invalid digits in binary and hexadecimal strings are errors.
+    pub fn parse(&self, asn: &str) -> Result<Vec<Token>, Error> {
+        let mut lexer = Lexer::new(asn);
         let mut tokens = Vec::new();
+        while let Some(token) = lexer.next_token()? {
+            tokens.push(token);
+        }
+        Ok(tokens)
+    }
+}
 
-        for (line_0, line) in asn.lines().enumerate() {
-            let mut token = None;
-            let content = line.split("--").next(); // get rid of one-line comments
-
-            for (column_0, char) in content.iter().map(|c| c.chars()).flatten().enumerate() {
-                match char {
-                    // asn syntax
-                    ':' | ';' | '=' | '(' | ')' | '{' | '}' | '.' | ',' | '[' | ']' => {
-                        token = Some(Token::Separator(
-                            Location::at(line_0 + 1, column_0 + 1),
-                            char,
-                        ))
-                    }
-                    // text
-                    c if !c.is_control() && c != ' ' => {
-                        token = Some(Token::Text(
-                            Location::at(line_0 + 1, column_0 + 1),
-                            format!("{}", c),
-                        ));
+fn report_unexpected_character(location: Location, c: char) {
+    eprintln!(
+        "Ignoring unexpected character at line {}, column {}: {}-0x{:02x}-{:03}",
+        location.line(),
+        location.column(),
+        c,
+        c as u32,
+        c as u32
+    );
+}
+
+/// The characters of the ASN.1 definitions with their [`Location`], without the control
+/// characters that are not white-space
+struct Lexer {
+    chars: Vec<(Location, char)>,
+    position: usize,
+}
+
+impl Lexer {
+    fn new(asn: &str) -> Self {
+        let mut chars = Vec::with_capacity(asn.len());
+        for (line_0, line) in asn.split('\n').enumerate() {
+            let mut column_0 = 0;
+            for c in line.chars() {
+                let location = Location::at(line_0 + 1, column_0 + 1);
+                if c.is_control() && !c.is_whitespace() {
+                    report_unexpected_character(location, c);
+                } else {
+                    chars.push((location, c));
+                }
+                column_0 += 1;
+            }
+            chars.push((Location::at(line_0 + 1, column_0 + 1), '\n'));
+        }
+        Self { chars, position: 0 }
+    }
+
+    fn peek(&self, offset: usize) -> Option<char> {
+        self.chars.get(self.position + offset).map(|(_, c)| *c)
+    }
+
+    fn peek_matches<F: Fn(char) -> bool>(&self, offset: usize, f: F) -> bool {
+        matches!(self.peek(offset), Some(c) if f(c))
+    }
+
+    fn lookahead(&self, text: &str) -> bool {
+        text.chars()
+            .enumerate()
+            .all(|(offset, c)| self.peek(offset) == Some(c))
+    }
+
+    fn next_token(&mut self) -> Result<Option<Token>, Error> {
+        loop {
+            let (location, c) = match self.chars.get(self.position) {
+                Some(next) => *next,
+                None => return Ok(None),
+            };
+            match c {
+                c if c.is_whitespace() => self.position += 1,
+                '-' if self.lookahead("--") => self.skip_line_comment(),
+                '/' if self.lookahead("/*") => self.skip_block_comment(location)?,
+                '"' => return self.cstring(location).map(Some),
+                '\'' => match self.bstring_or_hstring(location)? {
+                    Some(token) => return Ok(Some(token)),
+                    None => {
+                        report_unexpected_character(location, c);
+                        self.position += 1;
                     }
-                    // text separator
-                    ' ' | '\r' | '\n' | '\t' => {
-                        if let Some(token) = previous.take() {
-                            tokens.push(token);
-                        }
+                },
+                '-' if self.peek_matches(1, |c| c.is_ascii_digit()) => {
+                    return Ok(Some(self.number(location)))
+                }
+                c if c.is_ascii_digit() => return Ok(Some(self.number(location))),
+                '&' if self.peek_matches(1, char::is_alphabetic) => {
+                    return Ok(Some(self.word(location)))
+                }
+                c if c.is_alphabetic() => return Ok(Some(self.word(location))),
+                c => {
+                    if let Some(symbol) = SYMBOLS.iter().find(|s| self.lookahead(s)) {
+                        self.position += symbol.len();
+                        return Ok(Some(Token::Symbol(location, symbol)));
+                    } else if SEPARATORS.contains(&c) {
+                        self.position += 1;
+                        return Ok(Some(Token::Separator(location, c)));
+                    } else {
+                        report_unexpected_character(location, c);
+                        self.position += 1;
                     }
-                    c => eprintln!(
-                        "Ignoring unexpected character: {}-0x{:02x}-{:03}",
-                        c, c as u8, c as u8
-                    ),
                 }
+            }
+        }
+    }
 
-                if let Some(token) = token.take() {
-                    previous = match previous {
-                        None => Some(token),
-                        Some(current) => {
-                            let (token, second) = current.append(token);
-                            match second {
-                                None => Some(token),
-                                Some(next) => {
-                                    tokens.push(token);
-                                    Some(next)
-                                }
-                            }
-                        }
-                    }
+    fn skip_hyphens(&mut self) {
+        while self.peek(0) == Some('-') {
+            self.position += 1;
+        }
+    }
+
+    /// A line comment ends with the line or with the next pair of hyphens. To not trip over
+    /// comments like `--- Header ---`, a whole sequence of hyphens opens or closes a comment.
+    fn skip_line_comment(&mut self) {
+        self.skip_hyphens();
+        while let Some(c) = self.peek(0) {
+            if c == '\n' {
+                break;
+            } else if self.lookahead("--") {
+                self.skip_hyphens();
+                break;
+            } else {
+                self.position += 1;
+            }
+        }
+    }
+
+    /// Block comments can be nested, see ITU-T X.680 | ISO/IEC 8824-1:2015, 12.6.4
+    fn skip_block_comment(&mut self, location: Location) -> Result<(), Error> {
+        let mut depth = 0_usize;
+        while self.peek(0).is_some() {
+            if self.lookahead("/*") {
+                depth += 1;
+                self.position += 2;
+            } else if self.lookahead("*/") {
+                depth -= 1;
+                self.position += 2;
+                if depth == 0 {
+                    return Ok(());
                 }
+            } else {
+                self.position += 1;
+            }
+        }
+        Err(Error::unterminated_comment(location))
+    }
+
+    fn take_while<F: Fn(char) -> bool>(&mut self, text: &mut String, f: F) {
+        while let Some(c) = self.peek(0).filter(|c| f(*c)) {
+            text.push(c);
+            self.position += 1;
+        }
+    }
+
+    /// Identifiers and references consist of letters, digits and single hyphens that are
+    /// neither trailing nor followed by another hyphen
+    fn word(&mut self, location: Location) -> Token {
+        let mut text = String::new();
+        if let Some(c) = self.peek(0) {
+            text.push(c);
+            self.position += 1;
+        }
+        loop {
+            self.take_while(&mut text, |c| c.is_alphanumeric() || c == '_');
+            if self.peek(0) == Some('-') && self.peek_matches(1, char::is_alphanumeric) {
+                text.push('-');
+                self.position += 1;
+            } else {
+                break;
             }
+        }
+        if KEYWORDS.contains(&text.as_str()) {
+            Token::Keyword(location, text)
+        } else {
+            Token::Identifier(location, text)
+        }
+    }
 
-            if let Some(token) = previous.take() {
-                tokens.push(token);
+    /// Numbers, optionally negative, with an optional fraction and exponent
+    fn number(&mut self, location: Location) -> Token {
+        let mut text = String::new();
+        if self.peek(0) == Some('-') {
+            text.push('-');
+            self.position += 1;
+        }
+        self.take_while(&mut text, |c| c.is_ascii_digit());
+        if self.peek(0) == Some('.') && self.peek_matches(1, |c| c.is_ascii_digit()) {
+            text.push('.');
+            self.position += 1;
+            self.take_while(&mut text, |c| c.is_ascii_digit());
+        }
+        if self.peek_matches(0, |c| c == 'e' || c == 'E')
+            && (self.peek_matches(1, |c| c.is_ascii_digit())
+                || (self.peek(1) == Some('-') && self.peek_matches(2, |c| c.is_ascii_digit())))
+        {
+            text.push('e');
+            self.position += 1;
+            if self.peek(0) == Some('-') {
+                text.push('-');
+                self.position += 1;
             }
+            self.take_while(&mut text, |c| c.is_ascii_digit());
         }
+        Token::Number(location, text)
+    }
 
-        if let Some(token) = previous {
-            tokens.push(token);
+    /// Pairs of quotes within a character string represent a single quote and white-space
+    /// around line breaks is removed, see ITU-T X.680 | ISO/IEC 8824-1:2015, 12.14
+    fn cstring(&mut self, location: Location) -> Result<Token, Error> {
+        let mut text = String::new();
+        self.position += 1;
+        while let Some(c) = self.peek(0) {
+            self.position += 1;
+            if c == '"' {
+                if self.peek(0) == Some('"') {
+                    text.push('"');
+                    self.position += 1;
+                } else {
+                    return Ok(Token::CString(location, text));
+                }
+            } else if c == '\n' {
+                text.truncate(text.trim_end().len());
+                while self.peek_matches(0, char::is_whitespace) {
+                    self.position += 1;
+                }
+            } else {
+                text.push(c);
+            }
         }
+        Err(Error::unterminated_string(location))
+    }
 
-        tokens
+    /// Binary (`'0101'B`) and hexadecimal (`'1F'H`) strings, or [`None`] if the quote does not
+    /// start one. Their digits are restricted to `0` and `1` respectively `0` to `9` and `A` to
+    /// `F`, see ITU-T X.680 | ISO/IEC 8824-1:2015, 12.10 and 12.12
+    fn bstring_or_hstring(&mut self, location: Location) -> Result<Option<Token>, Error> {
+        let end = match (self.position + 1..self.chars.len()).find(|i| self.chars[*i].1 == '\'') {
+            Some(end) => end,
+            None => return Ok(None),
+        };
+        let hexadecimal = match self.chars.get(end + 1).map(|(_, c)| *c) {
+            Some('B') => false,
+            Some('H') => true,
+            _ => return Ok(None),
+        };
+        let mut digits = String::new();
+        for (digit_location, c) in &self.chars[self.position + 1..end] {
+            match c {
+                '0' | '1' => digits.push(*c),
+                '2'..='9' | 'A'..='F' if hexadecimal => digits.push(*c),
+                c if c.is_whitespace() => {}
+                c => return Err(Error::invalid_digit(*digit_location, *c)),
+            }
+        }
+        let token = if hexadecimal {
+            Token::HString(location, digits)
+        } else {
+            Token::BString(location, digits)
+        };
+        self.position = end + 2;
+        Ok(Some(token))
     }
 }
 
@@ -209,9 +552,26 @@ impl Tokenizer {
 mod tests {
     use super::*;
 
+    #[test]
+    pub fn test_quoted_string_is_single_token() {
+        let result = Tokenizer.parse(r#"abc DEFAULT "x, y"" {z}" ,"#).unwrap();
+        let mut iter = result.into_iter();
+        assert!(iter.next().unwrap().eq_text("abc"));
+        assert!(iter.next().unwrap().eq_text("DEFAULT"));
+        assert_eq!(
+            iter.next(),
+            Some(Token::CString(
+                Location::at(1, 13),
+                r#"x, y" {z}"#.to_string()
+            ))
+        );
+        assert!(iter.next().unwrap().eq_separator(','));
+        assert!(iter.next().is_none());
+    }
+
     #[test]
     pub fn test_separator_tokens_not_merged() {
-        let result = Tokenizer.parse(":;=(){}.,[]");
+        let result = Tokenizer.parse(":;=(){}.,[]|^@!<>").unwrap();
         let mut iter = result.into_iter();
         assert!(iter.next().unwrap().eq_separator(':'));
         assert!(iter.next().unwrap().eq_separator(';'));
@@ -224,24 +584,63 @@ mod tests {
         assert!(iter.next().unwrap().eq_separator(','));
         assert!(iter.next().unwrap().eq_separator('['));
         assert!(iter.next().unwrap().eq_separator(']'));
+        assert!(iter.next().unwrap().eq_separator('|'));
+        assert!(iter.next().unwrap().eq_separator('^'));
+        assert!(iter.next().unwrap().eq_separator('@'));
+        assert!(iter.next().unwrap().eq_separator('!'));
+        assert!(iter.next().unwrap().eq_separator('<'));
+        assert!(iter.next().unwrap().eq_separator('>'));
         assert!(iter.next().is_none());
     }
 
     #[test]
     pub fn test_text_between_seapators_is_represented_as_one_text_token() {
-        let result = Tokenizer.parse("::=ASN{");
+        let result = Tokenizer.parse("::=ASN{").unwrap();
         let mut iter = result.into_iter();
-        assert!(iter.next().unwrap().eq_separator(':'));
-        assert!(iter.next().unwrap().eq_separator(':'));
-        assert!(iter.next().unwrap().eq_separator('='));
+        assert!(iter.next().unwrap().eq_symbol("::="));
         assert!(iter.next().unwrap().eq_text("ASN"));
         assert!(iter.next().unwrap().eq_separator('{'));
         assert!(iter.next().is_none());
     }
 
+    #[test]
+    pub fn test_symbols() {
+        let result = Tokenizer.parse("::= ... .. [[ ]] : . [ ] ]]]").unwrap();
+        let mut iter = result.into_iter();
+        assert_eq!(iter.next(), Some(Token::Symbol(Location::at(1, 1), "::=")));
+        assert_eq!(iter.next(), Some(Token::Symbol(Location::at(1, 5), "...")));
+        assert_eq!(iter.next(), Some(Token::Symbol(Location::at(1, 9), "..")));
+        assert_eq!(iter.next(), Some(Token::Symbol(Location::at(1, 12), "[[")));
+        assert_eq!(iter.next(), Some(Token::Symbol(Location::at(1, 15), "]]")));
+        assert_eq!(
+            iter.next(),
+            Some(Token::Separator(Location::at(1, 18), ':'))
+        );
+        assert_eq!(
+            iter.next(),
+            Some(Token::Separator(Location::at(1, 20), '.'))
+        );
+        assert_eq!(
+            iter.next(),
+            Some(Token::Separator(Location::at(1, 22), '['))
+        );
+        assert_eq!(
+            iter.next(),
+            Some(Token::Separator(Location::at(1, 24), ']'))
+        );
+        assert_eq!(iter.next(), Some(Token::Symbol(Location::at(1, 26), "]]")));
+        assert_eq!(
+            iter.next(),
+            Some(Token::Separator(Location::at(1, 28), ']'))
+        );
+        assert!(iter.next().is_none());
+    }
+
     #[test]
     pub fn test_invisible_separator_characters() {
-        let result = Tokenizer.parse("a b\rc\nd\te AB\rCD\nEF\tGH aa  bb\r\rcc\n\ndd\t\tee");
+        let result = Tokenizer
+            .parse("a b\rc\nd\te AB\rCD\nEF\tGH aa  bb\r\rcc\n\ndd\t\tee")
+            .unwrap();
         let mut iter = result.into_iter();
         assert!(iter.next().unwrap().eq_text("a"));
         assert!(iter.next().unwrap().eq_text("b"));
@@ -269,7 +668,7 @@ mod tests {
 
     #[test]
     pub fn test_token_separator() {
-        let result = Tokenizer.parse("AS\x00N");
+        let result = Tokenizer.parse("AS\x00N").unwrap();
         let mut iter = result.into_iter();
         assert!(iter.next().unwrap().eq_text("ASN"));
         assert!(iter.next().is_none());
@@ -284,18 +683,216 @@ mod tests {
 
     #[test]
     pub fn test_ignores_line_comments() {
-        let result = Tokenizer::default().parse(
-            r"
+        let result = Tokenizer::default()
+            .parse(
+                r"
                 Some ::= None -- very clever
                         -- ignore true ::= false
         ",
-        );
+            )
+            .unwrap();
         let mut iter = result.into_iter();
         assert!(iter.next().unwrap().eq_text("Some"));
-        assert!(iter.next().unwrap().eq_separator(':'));
-        assert!(iter.next().unwrap().eq_separator(':'));
-        assert!(iter.next().unwrap().eq_separator('='));
+        assert!(iter.next().unwrap().eq_symbol("::="));
         assert!(iter.next().unwrap().eq_text("None"));
         assert!(iter.next().is_none());
     }
+
+    #[test]
+    pub fn test_line_comments_end_with_hyphens() {
+        let result = Tokenizer
+            .parse("a -- b -- c --- d --- e\n---- f ----\ng--h")
+            .unwrap();
+        let mut iter = result.into_iter();
+        assert!(iter.next().unwrap().eq_text("a"));
+        assert!(iter.next().unwrap().eq_text("c"));
+        assert!(iter.next().unwrap().eq_text("e"));
+        assert!(iter.next().unwrap().eq_text("g"));
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    pub fn test_ignores_nested_block_comments() {
+        let result = Tokenizer
+            .parse(
+                r"a /* b /* c */ d
+            -- e */ f /* g -- */ h",
+            )
+            .unwrap();
+        let mut iter = result.into_iter();
+        assert!(iter.next().unwrap().eq_text("a"));
+        assert_eq!(
+            iter.next(),
+            Some(Token::Identifier(Location::at(2, 21), "f".to_string()))
+        );
+        assert_eq!(
+            iter.next(),
+            Some(Token::Identifier(Location::at(2, 34), "h".to_string()))
+        );
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    pub fn test_keywords_identifiers_and_references() {
+        let result = Tokenizer
+            .parse("SEQUENCE Sequence TIME-OF-DAY my-value &Type &id-x UTF8String")
+            .unwrap();
+        let mut iter = result.into_iter();
+        assert!(iter.next().unwrap().is_keyword());
+        assert_eq!(
+            iter.next(),
+            Some(Token::Identifier(
+                Location::at(1, 10),
+                "Sequence".to_string()
+            ))
+        );
+        assert_eq!(
+            iter.next(),
+            Some(Token::Keyword(
+                Location::at(1, 19),
+                "TIME-OF-DAY".to_string()
+            ))
+        );
+        assert_eq!(
+            iter.next(),
+            Some(Token::Identifier(
+                Location::at(1, 31),
+                "my-value".to_string()
+            ))
+        );
+        assert_eq!(
+            iter.next(),
+            Some(Token::Identifier(Location::at(1, 40), "&Type".to_string()))
+        );
+        assert_eq!(
+            iter.next(),
+            Some(Token::Identifier(Location::at(1, 46), "&id-x".to_string()))
+        );
+        assert!(iter.next().unwrap().is_keyword());
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    pub fn test_numbers() {
+        let result = Tokenizer.parse("(-5..10) 1.5 2e-3 -7.25E4 x-1").unwrap();
+        let mut iter = result.into_iter();
+        assert!(iter.next().unwrap().eq_separator('('));
+        assert_eq!(
+            iter.next(),
+            Some(Token::Number(Location::at(1, 2), "-5".to_string()))
+        );
+        assert!(iter.next().unwrap().eq_symbol(".."));
+        assert_eq!(
+            iter.next(),
+            Some(Token::Number(Location::at(1, 6), "10".to_string()))
+        );
+        assert!(iter.next().unwrap().eq_separator(')'));
+        assert!(iter.next().unwrap().eq_text("1.5"));
+        assert!(iter.next().unwrap().eq_text("2e-3"));
+        assert!(iter.next().unwrap().eq_text("-7.25e4"));
+        assert!(iter.next().unwrap().eq_text("x-1"));
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    pub fn test_cstrings() {
+        let result = Tokenizer
+            .parse("\"a -- b /* c */ ::= \" \"\" \"first  \n    second\"\r\n\"\"\"quoted\"\"\"")
+            .unwrap();
+        let mut iter = result.into_iter();
+        assert_eq!(
+            iter.next(),
+            Some(Token::CString(
+                Location::at(1, 1),
+                "a -- b /* c */ ::= ".to_string()
+            ))
+        );
+        assert_eq!(
+            iter.next(),
+            Some(Token::CString(Location::at(1, 23), String::default()))
+        );
+        assert_eq!(
+            iter.next(),
+            Some(Token::CString(
+                Location::at(1, 26),
+                "firstsecond".to_string()
+            ))
+        );
+        assert_eq!(
+            iter.next(),
+            Some(Token::CString(Location::at(3, 1), "\"quoted\"".to_string()))
+        );
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    pub fn test_bstrings_and_hstrings() {
+        let result = Tokenizer
+            .parse("'0101'B '1F 2E'H\n''B '01\n 10'B 'x' y")
+            .unwrap();
+        let mut iter = result.into_iter();
+        assert_eq!(
+            iter.next(),
+            Some(Token::BString(Location::at(1, 1), "0101".to_string()))
+        );
+        assert_eq!(
+            iter.next(),
+            Some(Token::HString(Location::at(1, 9), "1F2E".to_string()))
+        );
+        assert_eq!(
+            iter.next(),
+            Some(Token::BString(Location::at(2, 1), String::default()))
+        );
+        assert_eq!(
+            iter.next(),
+            Some(Token::BString(Location::at(2, 5), "0110".to_string()))
+        );
+        // not a bstring or hstring, the quotes are ignored
+        assert!(iter.next().unwrap().eq_text("x"));
+        assert!(iter.next().unwrap().eq_text("y"));
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    pub fn test_invalid_digits_of_bstrings_and_hstrings() {
+        assert_eq!(
+            Err(Error::invalid_digit(Location::at(1, 5), '2')),
+            Tokenizer.parse("'01 2'B")
+        );
+        assert_eq!(
+            Err(Error::invalid_digit(Location::at(2, 4), 'e')),
+            Tokenizer.parse("a\n'1Fe'H")
+        );
+    }
+
+    #[test]
+    pub fn test_unterminated_cstring_and_block_comment() {
+        assert_eq!(
+            Err(Error::unterminated_string(Location::at(1, 3))),
+            Tokenizer.parse("a \"b\"\" c")
+        );
+        assert_eq!(
+            Err(Error::unterminated_comment(Location::at(2, 1))),
+            Tokenizer.parse("a\n/* b /* c */ d")
+        );
+    }
+
+    #[test]
+    pub fn test_locations_across_lines() {
+        let result = Tokenizer.parse("Äbc ::=\n  INTEGER\r\n\t(0..7)").unwrap();
+        let locations = result.iter().map(Token::location).collect::<Vec<_>>();
+        assert_eq!(
+            locations,
+            vec![
+                Location::at(1, 1),
+                Location::at(1, 5),
+                Location::at(2, 3),
+                Location::at(3, 2),
+                Location::at(3, 3),
+                Location::at(3, 4),
+                Location::at(3, 6),
+                Location::at(3, 7),
+            ]
+        );
+    }
 }
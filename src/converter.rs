@@ -55,7 +55,7 @@ pub struct Converter {
 impl Converter {
     pub fn load_file<F: AsRef<Path>>(&mut self, file: F) -> Result<(), Error> {
         let input = ::std::fs::read_to_string(file)?;
-        let tokens = Tokenizer::default().parse(&input);
+        let tokens = Tokenizer::default().parse(&input)?;
         let model = Model::try_from(tokens)?;
         self.models.push(model);
         Ok(())
@@ -71,7 +71,7 @@ impl Converter {
 
         for model in &self.models {
             let mut generator = RustGenerator::default();
-            generator.add_model(model.to_rust_with_scope(&scope[..]));
+            generator.add_model(model.try_to_rust_with_scope(&scope[..])?);
 
             custom_adjustments(&mut generator);
 
@@ -101,7 +101,7 @@ impl Converter {
 
         for model in &self.models {
             let mut generator = ProtobufGenerator::default();
-            generator.add_model(model.to_rust_with_scope(&scope[..]).to_protobuf());
+            generator.add_model(model.try_to_rust_with_scope(&scope[..])?.to_protobuf());
 
             files.insert(
                 model.name.clone(),
@@ -136,7 +136,7 @@ impl Converter {
 
         for model in &self.models {
             generator.reset();
-            generator.add_model(model.to_rust_with_scope(&scope[..]).to_sql());
+            generator.add_model(model.try_to_rust_with_scope(&scope[..])?.to_sql());
 
             files.insert(
                 model.name.clone(),
@@ -162,10 +162,10 @@ pub fn convert_to_rust<F: AsRef<Path>, D: AsRef<Path>, A: Fn(&mut RustGenerator)
     custom_adjustments: A,
 ) -> Result<Vec<String>, Error> {
     let input = ::std::fs::read_to_string(file)?;
-    let tokens = Tokenizer::default().parse(&input);
+    let tokens = Tokenizer::default().parse(&input)?;
     let model = Model::try_from(tokens)?;
     let mut generator = RustGenerator::default();
-    generator.add_model(model.to_rust());
+    generator.add_model(model.try_to_rust()?);
 
     custom_adjustments(&mut generator);
 
@@ -185,10 +185,10 @@ pub fn convert_to_proto<F: AsRef<Path>, D: AsRef<Path>>(
     dir: D,
 ) -> Result<Vec<String>, Error> {
     let input = ::std::fs::read_to_string(file)?;
-    let tokens = Tokenizer::default().parse(&input);
+    let tokens = Tokenizer::default().parse(&input)?;
     let model = Model::try_from(tokens)?;
     let mut generator = ProtobufGenerator::default();
-    generator.add_model(model.to_rust().to_protobuf());
+    generator.add_model(model.try_to_rust()?.to_protobuf());
     let output = generator.to_string()?;
 
     let mut files = Vec::new();
@@ -215,10 +215,10 @@ pub fn convert_to_sql_with<F: AsRef<Path>, D: AsRef<Path>>(
     mut generator: SqlGenerator,
 ) -> Result<Vec<String>, Error> {
     let input = ::std::fs::read_to_string(file)?;
-    let tokens = Tokenizer::default().parse(&input);
+    let tokens = Tokenizer::default().parse(&input)?;
     let model = Model::try_from(tokens)?;
 
-    generator.add_model(model.to_rust().to_sql());
+    generator.add_model(model.try_to_rust()?.to_sql());
     let output = generator.to_string()?;
 
     let mut files = Vec::new();
@@ -0,0 +1,183 @@
+use crate::model::{Charset, Tag, TimeType};
+use crate::syn::components::Violation;
+
+#[derive(Debug, PartialOrd, PartialEq)]
+pub enum Error {
+    InvalidUtf8String,
+    InvalidIa5String,
+    InvalidCharacterString(Charset),
+    InvalidReal,
+    InvalidObjectIdentifier,
+    InvalidTime(TimeType),
+    UnexpectedTag(Tag, Tag),
+    UnexpectedEncodingForm(Tag, bool),
+    IndefiniteLengthNotAllowed(Tag),
+    InvalidContentLength(Tag, usize),
+    InvalidUnusedBitsCount(u8),
+    NoMatchingChoiceVariant(Tag),
+    InvalidEnumeratedValue(i64),
+    ValueNotInRange(i64, i64, i64),
+    WideValueNotInRange(String, Option<i128>, Option<i128>),
+    ValueNotPermitted(String),
+    InvalidComponents(Violation),
+    ValueExceedsMaxInt,
+    SizeNotInRange(u64, u64, u64),
+    UnexpectedTrailingContent(usize),
+    MissingEndOfContents,
+    NonMinimalIdentifier(Tag),
+    NonMinimalLength(usize),
+    NonMinimalInteger,
+    InvalidBooleanValue(u8),
+    NonZeroUnusedBits,
+    UnsortedSetOf,
+    UnknownOpenTypeKey(i64),
+    MismatchedOpenTypeKey(i64, i64),
+    EndOfStream,
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::InvalidUtf8String => {
+                write!(f, "The underlying dataset is not a valid UTF8-String")
+            }
+            Error::InvalidIa5String => {
+                write!(f, "The underlying dataset is not a valid IA5-String")
+            }
+            Error::InvalidCharacterString(charset) => {
+                write!(
+                    f,
+                    "The underlying dataset is not a valid {:?}String",
+                    charset
+                )
+            }
+            Error::InvalidReal => write!(f, "The underlying dataset is not a valid REAL"),
+            Error::InvalidObjectIdentifier => write!(
+                f,
+                "The underlying dataset is not a valid OBJECT IDENTIFIER or RELATIVE-OID"
+            ),
+            Error::InvalidTime(time) => {
+                write!(f, "The underlying dataset is not a valid {}", time.name())
+            }
+            Error::UnexpectedTag(expected, found) => write!(
+                f,
+                "Expected an element with the tag {:?}, but found {:?}",
+                expected, found
+            ),
+            Error::UnexpectedEncodingForm(tag, constructed) => write!(
+                f,
+                "The element with the tag {:?} is not expected to be encoded in the {} form",
+                tag,
+                if *constructed {
+                    "constructed"
+                } else {
+                    "primitive"
+                }
+            ),
+            Error::IndefiniteLengthNotAllowed(tag) => write!(
+                f,
+                "The element with the tag {:?} must not use the indefinite length form",
+                tag
+            ),
+            Error::InvalidContentLength(tag, length) => write!(
+                f,
+                "The element with the tag {:?} has the invalid content length of {} bytes",
+                tag, length
+            ),
+            Error::InvalidUnusedBitsCount(count) => write!(
+                f,
+                "The amount of unused bits {} of the bitstring is invalid",
+                count
+            ),
+            Error::NoMatchingChoiceVariant(tag) => write!(
+                f,
+                "There is no choice variant for an element with the tag {:?}",
+                tag
+            ),
+            Error::InvalidEnumeratedValue(value) => {
+                write!(f, "The enumerated value {} is unknown", value)
+            }
+            Error::ValueNotInRange(value, min, max) => write!(
+                f,
+                "The value {} is not within the inclusive range of {} and {}",
+                value, min, max
+            ),
+            Error::WideValueNotInRange(value, min, max) => write!(
+                f,
+                "The value {} is not within the inclusive range of {} and {}",
+                value,
+                min.map_or_else(|| "MIN".to_string(), |min| min.to_string()),
+                max.map_or_else(|| "MAX".to_string(), |max| max.to_string()),
+            ),
+            Error::ValueNotPermitted(value) => {
+                write!(f, "The value {} is not permitted by the constraint", value)
+            }
+            Error::InvalidComponents(violation) => {
+                write!(f, "The inner subtype constraint is violated: {}", violation)
+            }
+            Error::ValueExceedsMaxInt => {
+                write!(f, "The value exceeds the maximum supported integer size",)
+            }
+            Error::SizeNotInRange(size, min, max) => write!(
+                f,
+                "The size {} is not within the inclusive range of {} and {}",
+                size, min, max
+            ),
+            Error::UnexpectedTrailingContent(length) => write!(
+                f,
+                "There are {} unexpected bytes left at the end of the contents",
+                length
+            ),
+            Error::MissingEndOfContents => write!(
+                f,
+                "The end-of-contents octets of an indefinite length element are missing"
+            ),
+            Error::NonMinimalIdentifier(tag) => write!(
+                f,
+                "The identifier of the element with the tag {:?} is not encoded minimally",
+                tag
+            ),
+            Error::NonMinimalLength(length) => write!(
+                f,
+                "The length {} is not encoded in the least amount of octets",
+                length
+            ),
+            Error::NonMinimalInteger => write!(
+                f,
+                "The integer is not encoded in the least amount of octets"
+            ),
+            Error::InvalidBooleanValue(value) => write!(
+                f,
+                "The boolean value {:#04x} is neither 0x00 nor 0xff",
+                value
+            ),
+            Error::NonZeroUnusedBits => {
+                write!(f, "The unused bits of the bitstring are not all zero")
+            }
+            Error::UnsortedSetOf => write!(
+                f,
+                "The elements of the SET OF are not sorted by their encoding"
+            ),
+            Error::UnknownOpenTypeKey(key) => write!(
+                f,
+                "The key {} of the open type is unknown to its object set",
+                key
+            ),
+            Error::MismatchedOpenTypeKey(key, value_key) => write!(
+                f,
+                "The key {} does not match the key {} of the value of the open type",
+                key, value_key
+            ),
+            Error::EndOfStream => write!(
+                f,
+                "Can no longer read or write any bytes from the underlying dataset"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn description(&self) -> &str {
+        "encoding or decoding BER failed"
+    }
+}
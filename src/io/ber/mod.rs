@@ -0,0 +1,315 @@
+//! This module contains the basic building blocks of the Basic Encoding Rules as specified in
+//! ITU-T X.690 | ISO/IEC 8825-1:2015. Each value is encoded as a TLV-triplet of the identifier
+//! octets (tag), the length octets and the contents octets.
+
+pub mod err;
+
+pub use err::Error;
+
+use crate::model::Tag;
+use core::cmp::Ordering;
+
+/// ITU-T X.690 | ISO/IEC 8825-1:2015, chapter 8.1.3.6.1, the single octet indicating the
+/// indefinite length form
+pub const INDEFINITE_LENGTH: u8 = 0x80;
+
+/// ITU-T X.690 | ISO/IEC 8825-1:2015, chapter 8.1.5, the end-of-contents octets terminating
+/// the contents of an element with an indefinite length
+pub const END_OF_CONTENTS: [u8; 2] = [0x00, 0x00];
+
+const CLASS_UNIVERSAL: u8 = 0b0000_0000;
+const CLASS_APPLICATION: u8 = 0b0100_0000;
+const CLASS_CONTEXT_SPECIFIC: u8 = 0b1000_0000;
+const CLASS_PRIVATE: u8 = 0b1100_0000;
+const CONSTRUCTED: u8 = 0b0010_0000;
+const HIGH_TAG_NUMBER: u8 = 0b0001_1111;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Length {
+    Definite(usize),
+    Indefinite,
+}
+
+/// ITU-T X.690 | ISO/IEC 8825-1:2015, chapter 8.1.2, writes the identifier octets for the given
+/// tag. Tag numbers greater than 30 are encoded in the high-tag-number form.
+pub fn write_identifier(buffer: &mut Vec<u8>, tag: Tag, constructed: bool) {
+    let (class, number) = match tag {
+        Tag::Universal(number) => (CLASS_UNIVERSAL, number),
+        Tag::Application(number) => (CLASS_APPLICATION, number),
+        Tag::ContextSpecific(number) => (CLASS_CONTEXT_SPECIFIC, number),
+        Tag::Private(number) => (CLASS_PRIVATE, number),
+    };
+    let leading = class | if constructed { CONSTRUCTED } else { 0 };
+    if number < usize::from(HIGH_TAG_NUMBER) {
+        buffer.push(leading | number as u8);
+    } else {
+        buffer.push(leading | HIGH_TAG_NUMBER);
+        write_base128(buffer, number);
+    }
+}
+
+/// ITU-T X.690 | ISO/IEC 8825-1:2015, chapter 8.1.3, writes the length octets in the definite
+/// form, using the least amount of octets possible (as required by chapter 10.1)
+pub fn write_length(buffer: &mut Vec<u8>, length: usize) {
+    if length < 0x80 {
+        buffer.push(length as u8);
+    } else {
+        let bytes = length.to_be_bytes();
+        let skip = bytes.iter().take_while(|b| **b == 0).count();
+        buffer.push(0x80 | (bytes.len() - skip) as u8);
+        buffer.extend_from_slice(&bytes[skip..]);
+    }
+}
+
+/// ITU-T X.690 | ISO/IEC 8825-1:2015, chapter 8.3, writes the contents octets of the given
+/// integer in the two's complement form using the least amount of octets possible
+pub fn write_integer_content(buffer: &mut Vec<u8>, value: i64) {
+    let bytes = value.to_be_bytes();
+    let mut skip = 0;
+    while skip + 1 < bytes.len() {
+        let redundant = (bytes[skip] == 0x00 && bytes[skip + 1] & 0x80 == 0)
+            || (bytes[skip] == 0xFF && bytes[skip + 1] & 0x80 != 0);
+        if redundant {
+            skip += 1;
+        } else {
+            break;
+        }
+    }
+    buffer.extend_from_slice(&bytes[skip..]);
+}
+
+/// ITU-T X.690 | ISO/IEC 8825-1:2015, chapter 11.6, compares the encodings of two `SET OF`
+/// elements as octet strings, where the shorter encoding is padded with trailing zero octets
+pub fn cmp_set_of_encodings(a: &[u8], b: &[u8]) -> Ordering {
+    let len = a.len().max(b.len());
+    let padded = |bytes: &[u8]| {
+        let mut padded = bytes.to_vec();
+        padded.resize(len, 0x00);
+        padded
+    };
+    padded(a).cmp(&padded(b))
+}
+
+/// ITU-T X.690 | ISO/IEC 8825-1:2015, chapter 8.3.2, whether the first nine bits of the given
+/// contents octets of an integer are neither all ones nor all zeros
+pub fn is_minimal_integer_content(bytes: &[u8]) -> bool {
+    match bytes {
+        [0x00, second, ..] => second & 0x80 != 0,
+        [0xFF, second, ..] => second & 0x80 == 0,
+        _ => true,
+    }
+}
+
+fn write_base128(buffer: &mut Vec<u8>, value: usize) {
+    let mut shift = (usize::BITS - value.leading_zeros()).max(1).div_ceil(7) * 7;
+    while shift > 7 {
+        shift -= 7;
+        buffer.push(0x80 | ((value >> shift) & 0x7F) as u8);
+    }
+    buffer.push((value & 0x7F) as u8);
+}
+
+/// ITU-T X.690 | ISO/IEC 8825-1:2015, chapter 8.1.2, reads the identifier octets and returns
+/// the tag, whether the element is constructed and the amount of octets consumed
+pub fn read_identifier(bytes: &[u8]) -> Result<(Tag, bool, usize), Error> {
+    let leading = *bytes.first().ok_or(Error::EndOfStream)?;
+    let constructed = leading & CONSTRUCTED != 0;
+    let (number, consumed) = if leading & HIGH_TAG_NUMBER == HIGH_TAG_NUMBER {
+        let (number, consumed) = read_base128(&bytes[1..])?;
+        (number, consumed + 1)
+    } else {
+        (usize::from(leading & HIGH_TAG_NUMBER), 1)
+    };
+    let tag = match leading & CLASS_PRIVATE {
+        CLASS_UNIVERSAL => Tag::Universal(number),
+        CLASS_APPLICATION => Tag::Application(number),
+        CLASS_CONTEXT_SPECIFIC => Tag::ContextSpecific(number),
+        _ => Tag::Private(number),
+    };
+    Ok((tag, constructed, consumed))
+}
+
+/// ITU-T X.690 | ISO/IEC 8825-1:2015, chapter 8.1.3, reads the length octets and returns the
+/// length as well as the amount of octets consumed
+pub fn read_length(bytes: &[u8]) -> Result<(Length, usize), Error> {
+    let leading = *bytes.first().ok_or(Error::EndOfStream)?;
+    if leading == INDEFINITE_LENGTH {
+        Ok((Length::Indefinite, 1))
+    } else if leading & 0x80 == 0 {
+        Ok((Length::Definite(usize::from(leading)), 1))
+    } else {
+        let octets = usize::from(leading & 0x7F);
+        let bytes = bytes.get(1..=octets).ok_or(Error::EndOfStream)?;
+        let mut length = 0_usize;
+        for byte in bytes {
+            length = length
+                .checked_mul(256)
+                .ok_or(Error::ValueExceedsMaxInt)?
+                .checked_add(usize::from(*byte))
+                .ok_or(Error::ValueExceedsMaxInt)?;
+        }
+        Ok((Length::Definite(length), octets + 1))
+    }
+}
+
+/// ITU-T X.690 | ISO/IEC 8825-1:2015, chapter 8.3, reads the given contents octets as integer
+/// in the two's complement form
+pub fn read_integer_content(bytes: &[u8]) -> Result<i64, Error> {
+    if bytes.is_empty() {
+        Err(Error::InvalidContentLength(Tag::DEFAULT_INTEGER, 0))
+    } else if bytes.len() > std::mem::size_of::<i64>() {
+        Err(Error::ValueExceedsMaxInt)
+    } else {
+        let fill = if bytes[0] & 0x80 != 0 { 0xFF } else { 0x00 };
+        let mut buffer = [fill; std::mem::size_of::<i64>()];
+        buffer[std::mem::size_of::<i64>() - bytes.len()..].copy_from_slice(bytes);
+        Ok(i64::from_be_bytes(buffer))
+    }
+}
+
+fn read_base128(bytes: &[u8]) -> Result<(usize, usize), Error> {
+    let mut value = 0_usize;
+    for (index, byte) in bytes.iter().enumerate() {
+        value = value
+            .checked_mul(128)
+            .ok_or(Error::ValueExceedsMaxInt)?
+            .checked_add(usize::from(byte & 0x7F))
+            .ok_or(Error::ValueExceedsMaxInt)?;
+        if byte & 0x80 == 0 {
+            return Ok((value, index + 1));
+        }
+    }
+    Err(Error::EndOfStream)
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    #[test]
+    fn test_identifier_low_tag_number() {
+        let mut buffer = Vec::new();
+        write_identifier(&mut buffer, Tag::DEFAULT_INTEGER, false);
+        write_identifier(&mut buffer, Tag::DEFAULT_SEQUENCE, true);
+        write_identifier(&mut buffer, Tag::Application(3), false);
+        write_identifier(&mut buffer, Tag::ContextSpecific(30), true);
+        write_identifier(&mut buffer, Tag::Private(0), false);
+        assert_eq!(&[0x02, 0x30, 0x43, 0xBE, 0xC0], &buffer[..]);
+
+        assert_eq!(
+            (Tag::DEFAULT_INTEGER, false, 1),
+            read_identifier(&buffer[0..]).unwrap()
+        );
+        assert_eq!(
+            (Tag::DEFAULT_SEQUENCE, true, 1),
+            read_identifier(&buffer[1..]).unwrap()
+        );
+        assert_eq!(
+            (Tag::Application(3), false, 1),
+            read_identifier(&buffer[2..]).unwrap()
+        );
+        assert_eq!(
+            (Tag::ContextSpecific(30), true, 1),
+            read_identifier(&buffer[3..]).unwrap()
+        );
+        assert_eq!(
+            (Tag::Private(0), false, 1),
+            read_identifier(&buffer[4..]).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_identifier_high_tag_number() {
+        for (tag, bytes) in [
+            (Tag::ContextSpecific(31), &[0x9F, 0x1F][..]),
+            (Tag::ContextSpecific(127), &[0x9F, 0x7F][..]),
+            (Tag::ContextSpecific(128), &[0x9F, 0x81, 0x00][..]),
+            (Tag::Application(16383), &[0x5F, 0xFF, 0x7F][..]),
+            (Tag::Application(16384), &[0x5F, 0x81, 0x80, 0x00][..]),
+        ] {
+            let mut buffer = Vec::new();
+            write_identifier(&mut buffer, tag, false);
+            assert_eq!(bytes, &buffer[..]);
+            assert_eq!((tag, false, bytes.len()), read_identifier(bytes).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_length() {
+        for (length, bytes) in [
+            (0, &[0x00][..]),
+            (127, &[0x7F][..]),
+            (128, &[0x81, 0x80][..]),
+            (255, &[0x81, 0xFF][..]),
+            (256, &[0x82, 0x01, 0x00][..]),
+            (65536, &[0x83, 0x01, 0x00, 0x00][..]),
+        ] {
+            let mut buffer = Vec::new();
+            write_length(&mut buffer, length);
+            assert_eq!(bytes, &buffer[..]);
+            assert_eq!(
+                (Length::Definite(length), bytes.len()),
+                read_length(bytes).unwrap()
+            );
+        }
+        assert_eq!((Length::Indefinite, 1), read_length(&[0x80]).unwrap());
+        assert_eq!(Err(Error::EndOfStream), read_length(&[0x82, 0x01]));
+    }
+
+    #[test]
+    fn test_integer_content() {
+        for (value, bytes) in [
+            (0, &[0x00][..]),
+            (127, &[0x7F][..]),
+            (128, &[0x00, 0x80][..]),
+            (256, &[0x01, 0x00][..]),
+            (-1, &[0xFF][..]),
+            (-128, &[0x80][..]),
+            (-129, &[0xFF, 0x7F][..]),
+            (
+                i64::MIN,
+                &[0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00][..],
+            ),
+            (
+                i64::MAX,
+                &[0x7F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF][..],
+            ),
+        ] {
+            let mut buffer = Vec::new();
+            write_integer_content(&mut buffer, value);
+            assert_eq!(bytes, &buffer[..]);
+            assert_eq!(value, read_integer_content(bytes).unwrap());
+        }
+        assert_eq!(
+            Err(Error::ValueExceedsMaxInt),
+            read_integer_content(&[0x01, 0, 0, 0, 0, 0, 0, 0, 0])
+        );
+    }
+
+    #[test]
+    fn test_cmp_set_of_encodings() {
+        assert_eq!(
+            Ordering::Less,
+            cmp_set_of_encodings(&[0x02, 0x01], &[0x02, 0x02])
+        );
+        assert_eq!(
+            Ordering::Greater,
+            cmp_set_of_encodings(&[0x02, 0x01, 0x01], &[0x02, 0x01])
+        );
+        assert_eq!(
+            Ordering::Equal,
+            cmp_set_of_encodings(&[0x02, 0x01, 0x00], &[0x02, 0x01])
+        );
+        assert_eq!(Ordering::Less, cmp_set_of_encodings(&[], &[0x01]));
+    }
+
+    #[test]
+    fn test_is_minimal_integer_content() {
+        assert!(is_minimal_integer_content(&[0x00]));
+        assert!(is_minimal_integer_content(&[0xFF]));
+        assert!(is_minimal_integer_content(&[0x00, 0x80]));
+        assert!(is_minimal_integer_content(&[0xFF, 0x7F]));
+        assert!(!is_minimal_integer_content(&[0x00, 0x7F]));
+        assert!(!is_minimal_integer_content(&[0xFF, 0x80]));
+    }
+}
@@ -0,0 +1,52 @@
+//! Octet representation of the restricted character string types for the octet-based encodings,
+//! see ITU-T X.690 | ISO/IEC 8825-1:2015, chapter 8.23 and ITU-T X.696 | ISO/IEC 8825-7:2015,
+//! chapter 27
+
+use asn1rs_model::model::Charset;
+
+/// The number of octets used for each character, `BMPString` uses UCS-2 and `UniversalString`
+/// UCS-4 - both big-endian - all others a single octet. `UTF8String` is not a
+/// known-multiplier character string type and therefore not supported.
+#[inline]
+pub const fn octets_per_char(charset: Charset) -> usize {
+    match charset {
+        Charset::Bmp => 2,
+        Charset::Universal => 4,
+        Charset::Utf8 | Charset::Ia5 | Charset::Numeric | Charset::Printable | Charset::Visible => {
+            1
+        }
+    }
+}
+
+/// Encodes the given value or returns `None` if it contains characters that are not part of the
+/// charset
+pub fn to_octets(charset: Charset, value: &str) -> Option<Vec<u8>> {
+    let width = octets_per_char(charset);
+    let mut octets = Vec::with_capacity(value.len() * width);
+    for character in value.chars() {
+        if !charset.contains(character) {
+            return None;
+        }
+        let bytes = u32::from(character).to_be_bytes();
+        octets.extend_from_slice(&bytes[bytes.len() - width..]);
+    }
+    Some(octets)
+}
+
+/// Decodes the given octets or returns `None` if they do not represent valid characters of the
+/// charset
+pub fn from_octets(charset: Charset, octets: &[u8]) -> Option<String> {
+    let width = octets_per_char(charset);
+    let chunks = octets.chunks_exact(width);
+    if !chunks.remainder().is_empty() {
+        return None;
+    }
+    chunks
+        .map(|chunk| {
+            let value = chunk
+                .iter()
+                .fold(0_u32, |value, byte| (value << 8) | u32::from(*byte));
+            char::from_u32(value).filter(|c| charset.contains(*c))
+        })
+        .collect()
+}
@@ -0,0 +1,129 @@
+use crate::model::{Charset, TimeType};
+use crate::syn::components::Violation;
+
+#[derive(Debug, PartialOrd, PartialEq)]
+pub enum Error {
+    InvalidJson(usize),
+    UnexpectedTrailingContent(usize),
+    UnexpectedValue(&'static str),
+    InvalidNumber(String),
+    InvalidHexString,
+    InvalidIa5String,
+    InvalidCharacterString(Charset),
+    InvalidReal,
+    InvalidObjectIdentifier,
+    InvalidTime(TimeType),
+    MissingMember(String),
+    UnknownMember(String),
+    UnknownVariant(String),
+    MissingIdentifier(usize),
+    BitStringLengthMismatch(u64, usize),
+    ValueNotInRange(i64, i64, i64),
+    WideValueNotInRange(String, Option<i128>, Option<i128>),
+    ValueNotPermitted(String),
+    InvalidComponents(Violation),
+    SizeNotInRange(u64, u64, u64),
+    UnknownOpenTypeKey(i64),
+    MismatchedOpenTypeKey(i64, i64),
+    EndOfStream,
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::InvalidJson(position) => {
+                write!(f, "The JSON text is malformed at position {}", position)
+            }
+            Error::UnexpectedTrailingContent(position) => write!(
+                f,
+                "There is unexpected content after the JSON value at position {}",
+                position
+            ),
+            Error::UnexpectedValue(expected) => {
+                write!(f, "Expected a JSON {}, but found another value", expected)
+            }
+            Error::InvalidNumber(number) => {
+                write!(f, "The number {} is not a valid integer", number)
+            }
+            Error::InvalidHexString => write!(
+                f,
+                "The string does not consist of an even amount of hex digits"
+            ),
+            Error::InvalidIa5String => {
+                write!(f, "The underlying dataset is not a valid IA5-String")
+            }
+            Error::InvalidCharacterString(charset) => {
+                write!(
+                    f,
+                    "The underlying dataset is not a valid {:?}String",
+                    charset
+                )
+            }
+            Error::InvalidReal => write!(f, "The underlying dataset is not a valid REAL"),
+            Error::InvalidObjectIdentifier => write!(
+                f,
+                "The underlying dataset is not a valid OBJECT IDENTIFIER or RELATIVE-OID"
+            ),
+            Error::InvalidTime(time) => {
+                write!(f, "The underlying dataset is not a valid {}", time.name())
+            }
+            Error::MissingMember(name) => {
+                write!(f, "The object is missing the member {:?}", name)
+            }
+            Error::UnknownMember(name) => {
+                write!(f, "The object contains the unknown member {:?}", name)
+            }
+            Error::UnknownVariant(name) => write!(f, "The variant {:?} is unknown", name),
+            Error::MissingIdentifier(index) => write!(
+                f,
+                "There is no identifier for the field or variant with the index {}",
+                index
+            ),
+            Error::BitStringLengthMismatch(length, octets) => write!(
+                f,
+                "The bit string length {} does not match the {} octets of its value",
+                length, octets
+            ),
+            Error::ValueNotInRange(value, min, max) => write!(
+                f,
+                "The value {} is not within the inclusive range of {} and {}",
+                value, min, max
+            ),
+            Error::WideValueNotInRange(value, min, max) => write!(
+                f,
+                "The value {} is not within the inclusive range of {} and {}",
+                value,
+                min.map_or_else(|| "MIN".to_string(), |min| min.to_string()),
+                max.map_or_else(|| "MAX".to_string(), |max| max.to_string()),
+            ),
+            Error::ValueNotPermitted(value) => {
+                write!(f, "The value {} is not permitted by the constraint", value)
+            }
+            Error::InvalidComponents(violation) => {
+                write!(f, "The inner subtype constraint is violated: {}", violation)
+            }
+            Error::SizeNotInRange(size, min, max) => write!(
+                f,
+                "The size {} is not within the inclusive range of {} and {}",
+                size, min, max
+            ),
+            Error::UnknownOpenTypeKey(key) => write!(
+                f,
+                "The key {} of the open type is unknown to its object set",
+                key
+            ),
+            Error::MismatchedOpenTypeKey(key, value_key) => write!(
+                f,
+                "The key {} does not match the key {} of the value of the open type",
+                key, value_key
+            ),
+            Error::EndOfStream => write!(f, "There are no further values to read"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn description(&self) -> &str {
+        "encoding or decoding JER failed"
+    }
+}
@@ -0,0 +1,367 @@
+//! This module contains the basic building blocks of the JSON Encoding Rules as specified in
+//! ITU-T X.697 | ISO/IEC 8825-8:2018. Values are represented by a minimal JSON document model
+//! which can be written to and parsed from a JSON text.
+
+pub mod err;
+
+pub use err::Error;
+
+use std::fmt::Write;
+
+/// A JSON value. Numbers are kept in their textual representation so that no precision is lost
+/// until the number is interpreted according to the ASN.1 type.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    Number(String),
+    String(String),
+    Array(Vec<Value>),
+    /// The members in the order of their appearance
+    Object(Vec<(String, Value)>),
+}
+
+impl Value {
+    pub fn parse(text: &str) -> Result<Value, Error> {
+        let mut parser = Parser {
+            bytes: text.as_bytes(),
+            pos: 0,
+        };
+        let value = parser.parse_value()?;
+        parser.skip_whitespace();
+        if parser.pos < parser.bytes.len() {
+            Err(Error::UnexpectedTrailingContent(parser.pos))
+        } else {
+            Ok(value)
+        }
+    }
+}
+
+impl std::fmt::Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::Null => f.write_str("null"),
+            Value::Bool(value) => write!(f, "{}", value),
+            Value::Number(number) => f.write_str(number),
+            Value::String(string) => write_string(f, string),
+            Value::Array(values) => {
+                f.write_char('[')?;
+                for (index, value) in values.iter().enumerate() {
+                    if index > 0 {
+                        f.write_char(',')?;
+                    }
+                    write!(f, "{}", value)?;
+                }
+                f.write_char(']')
+            }
+            Value::Object(members) => {
+                f.write_char('{')?;
+                for (index, (name, value)) in members.iter().enumerate() {
+                    if index > 0 {
+                        f.write_char(',')?;
+                    }
+                    write_string(f, name)?;
+                    write!(f, ":{}", value)?;
+                }
+                f.write_char('}')
+            }
+        }
+    }
+}
+
+fn write_string(f: &mut std::fmt::Formatter<'_>, string: &str) -> std::fmt::Result {
+    f.write_char('"')?;
+    for c in string.chars() {
+        match c {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            '\r' => f.write_str("\\r")?,
+            '\t' => f.write_str("\\t")?,
+            c if (c as u32) < 0x20 => write!(f, "\\u{:04x}", c as u32)?,
+            c => f.write_char(c)?,
+        }
+    }
+    f.write_char('"')
+}
+
+/// Recursive descent parser for JSON texts according to RFC 8259
+struct Parser<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn skip_whitespace(&mut self) {
+        while let Some(b' ' | b'\t' | b'\n' | b'\r') = self.bytes.get(self.pos) {
+            self.pos += 1;
+        }
+    }
+
+    fn peek(&mut self) -> Option<u8> {
+        self.skip_whitespace();
+        self.bytes.get(self.pos).copied()
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<(), Error> {
+        if self.peek() == Some(byte) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(Error::InvalidJson(self.pos))
+        }
+    }
+
+    fn expect_literal(&mut self, literal: &str) -> Result<(), Error> {
+        if self.bytes[self.pos..].starts_with(literal.as_bytes()) {
+            self.pos += literal.len();
+            Ok(())
+        } else {
+            Err(Error::InvalidJson(self.pos))
+        }
+    }
+
+    fn parse_value(&mut self) -> Result<Value, Error> {
+        match self.peek() {
+            Some(b'n') => self.expect_literal("null").map(|_| Value::Null),
+            Some(b't') => self.expect_literal("true").map(|_| Value::Bool(true)),
+            Some(b'f') => self.expect_literal("false").map(|_| Value::Bool(false)),
+            Some(b'"') => self.parse_string().map(Value::String),
+            Some(b'[') => self.parse_array(),
+            Some(b'{') => self.parse_object(),
+            Some(b'-' | b'0'..=b'9') => self.parse_number(),
+            _ => Err(Error::InvalidJson(self.pos)),
+        }
+    }
+
+    fn parse_array(&mut self) -> Result<Value, Error> {
+        self.expect(b'[')?;
+        let mut values = Vec::new();
+        if self.peek() == Some(b']') {
+            self.pos += 1;
+            return Ok(Value::Array(values));
+        }
+        loop {
+            values.push(self.parse_value()?);
+            match self.peek() {
+                Some(b',') => self.pos += 1,
+                Some(b']') => {
+                    self.pos += 1;
+                    return Ok(Value::Array(values));
+                }
+                _ => return Err(Error::InvalidJson(self.pos)),
+            }
+        }
+    }
+
+    fn parse_object(&mut self) -> Result<Value, Error> {
+        self.expect(b'{')?;
+        let mut members = Vec::new();
+        if self.peek() == Some(b'}') {
+            self.pos += 1;
+            return Ok(Value::Object(members));
+        }
+        loop {
+            if self.peek() != Some(b'"') {
+                return Err(Error::InvalidJson(self.pos));
+            }
+            let name = self.parse_string()?;
+            self.expect(b':')?;
+            members.push((name, self.parse_value()?));
+            match self.peek() {
+                Some(b',') => self.pos += 1,
+                Some(b'}') => {
+                    self.pos += 1;
+                    return Ok(Value::Object(members));
+                }
+                _ => return Err(Error::InvalidJson(self.pos)),
+            }
+        }
+    }
+
+    fn parse_number(&mut self) -> Result<Value, Error> {
+        let start = self.pos;
+        let digits = |parser: &mut Self| {
+            let start = parser.pos;
+            while parser.bytes.get(parser.pos).is_some_and(u8::is_ascii_digit) {
+                parser.pos += 1;
+            }
+            if parser.pos > start {
+                Ok(())
+            } else {
+                Err(Error::InvalidJson(parser.pos))
+            }
+        };
+        if self.bytes.get(self.pos) == Some(&b'-') {
+            self.pos += 1;
+        }
+        digits(self)?;
+        if self.bytes.get(self.pos) == Some(&b'.') {
+            self.pos += 1;
+            digits(self)?;
+        }
+        if let Some(b'e' | b'E') = self.bytes.get(self.pos) {
+            self.pos += 1;
+            if let Some(b'+' | b'-') = self.bytes.get(self.pos) {
+                self.pos += 1;
+            }
+            digits(self)?;
+        }
+        let number = core::str::from_utf8(&self.bytes[start..self.pos])
+            .map_err(|_| Error::InvalidJson(start))?;
+        Ok(Value::Number(number.to_string()))
+    }
+
+    fn parse_string(&mut self) -> Result<String, Error> {
+        self.expect(b'"')?;
+        let mut string = Vec::new();
+        loop {
+            let byte = *self
+                .bytes
+                .get(self.pos)
+                .ok_or(Error::InvalidJson(self.pos))?;
+            self.pos += 1;
+            match byte {
+                b'"' => break,
+                b'\\' => {
+                    let escaped = *self
+                        .bytes
+                        .get(self.pos)
+                        .ok_or(Error::InvalidJson(self.pos))?;
+                    self.pos += 1;
+                    let c = match escaped {
+                        b'"' => '"',
+                        b'\\' => '\\',
+                        b'/' => '/',
+                        b'b' => '\u{8}',
+                        b'f' => '\u{c}',
+                        b'n' => '\n',
+                        b'r' => '\r',
+                        b't' => '\t',
+                        b'u' => self.parse_unicode_escape()?,
+                        _ => return Err(Error::InvalidJson(self.pos - 1)),
+                    };
+                    let mut buffer = [0u8; 4];
+                    string.extend_from_slice(c.encode_utf8(&mut buffer).as_bytes());
+                }
+                0x00..=0x1F => return Err(Error::InvalidJson(self.pos - 1)),
+                byte => string.push(byte),
+            }
+        }
+        String::from_utf8(string).map_err(|_| Error::InvalidJson(self.pos))
+    }
+
+    fn parse_hex4(&mut self) -> Result<u32, Error> {
+        let hex = self
+            .bytes
+            .get(self.pos..self.pos + 4)
+            .and_then(|hex| core::str::from_utf8(hex).ok())
+            .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+            .ok_or(Error::InvalidJson(self.pos))?;
+        self.pos += 4;
+        Ok(hex)
+    }
+
+    fn parse_unicode_escape(&mut self) -> Result<char, Error> {
+        let position = self.pos;
+        let high = self.parse_hex4()?;
+        let code = if (0xD800..0xDC00).contains(&high) {
+            // a surrogate pair is required for characters outside of the basic multilingual plane
+            self.expect_literal("\\u")?;
+            let low = self.parse_hex4()?;
+            if !(0xDC00..0xE000).contains(&low) {
+                return Err(Error::InvalidJson(position));
+            }
+            0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00)
+        } else {
+            high
+        };
+        char::from_u32(code).ok_or(Error::InvalidJson(position))
+    }
+}
+
+/// ITU-T X.697 | ISO/IEC 8825-8:2018, octets are written as pairs of
+/// uppercase hex digits
+pub fn to_hex(bytes: &[u8]) -> String {
+    let mut hex = String::with_capacity(bytes.len() * 2);
+    for byte in bytes {
+        let _ = write!(hex, "{:02X}", byte);
+    }
+    hex
+}
+
+/// Parses pairs of hex digits, both, uppercase and lowercase digits are accepted
+pub fn from_hex(hex: &str) -> Result<Vec<u8>, Error> {
+    if hex.len() & 1 != 0 || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Err(Error::InvalidHexString);
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).map_err(|_| Error::InvalidHexString))
+        .collect()
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    #[test]
+    fn test_value_display() {
+        let value = Value::Object(vec![
+            ("a".to_string(), Value::Number("-12".to_string())),
+            (
+                "b".to_string(),
+                Value::Array(vec![Value::Bool(true), Value::Null]),
+            ),
+            ("c\"".to_string(), Value::String("x\n\u{1}y".to_string())),
+        ]);
+        assert_eq!(
+            r#"{"a":-12,"b":[true,null],"c\"":"x\n\u0001y"}"#,
+            value.to_string()
+        );
+    }
+
+    #[test]
+    fn test_value_parse() {
+        assert_eq!(
+            Ok(Value::Object(vec![
+                ("a".to_string(), Value::Number("-1.5e3".to_string())),
+                (
+                    "b".to_string(),
+                    Value::Array(vec![Value::Bool(false), Value::Null])
+                ),
+                ("c".to_string(), Value::String("\"\u{e9}\u{1F600}".to_string())),
+                ("d".to_string(), Value::Object(Vec::new())),
+            ])),
+            Value::parse(
+                " { \"a\" : -1.5e3, \"b\": [false, null], \"c\": \"\\\"\\u00e9\\ud83d\\ude00\", \"d\": {} } "
+            )
+        );
+    }
+
+    #[test]
+    fn test_value_parse_invalid() {
+        assert_eq!(Err(Error::InvalidJson(5)), Value::parse("[1, 2"));
+        assert_eq!(Err(Error::InvalidJson(1)), Value::parse("-"));
+        assert_eq!(Err(Error::InvalidJson(1)), Value::parse("{1: 2}"));
+        assert_eq!(Err(Error::InvalidJson(0)), Value::parse("nul"));
+        assert_eq!(
+            Err(Error::UnexpectedTrailingContent(3)),
+            Value::parse("{} 1")
+        );
+    }
+
+    #[test]
+    fn test_value_round_trip() {
+        let text = r#"{"name":"\\\t","list":[1,-2,{"x":[]}],"flag":true}"#;
+        assert_eq!(text, Value::parse(text).unwrap().to_string());
+    }
+
+    #[test]
+    fn test_hex() {
+        assert_eq!("00A0FF", to_hex(&[0x00, 0xA0, 0xFF]));
+        assert_eq!(Ok(vec![0x00, 0xA0, 0xFF]), from_hex("00a0FF"));
+        assert_eq!(Err(Error::InvalidHexString), from_hex("0A0"));
+        assert_eq!(Err(Error::InvalidHexString), from_hex("0G"));
+    }
+}
@@ -1,9 +1,17 @@
 //! ```text
 //! crate::io                       Utils, common io-root
+//!      ::io::ber                  Basic Encoding Rules (TLV) utils
+//!      ::io::charset              Octets of the restricted character string types
+//!      ::io::jer                  JSON Encoding Rules utils
+//!      ::io::oer                  Octet Encoding Rules utils
+//!      ::io::oid                  Octets of object identifiers
 //!      ::io::per                  Generic Packed Encoding impls and traits
 //!      ::io::per::unaligned       UNALIGNED PER specialization
 //!      ::io::per::aligned         ALIGNED PER specialization
-//!      ::io::...                  Other ASN.1 representations (e.g xer, ber, ...)
+//!      ::io::real                 Octets of real values
+//!      ::io::time                 Values of the time types
+//!      ::io::xer                  XML Encoding Rules utils
+//!      ::io::...                  Other ASN.1 representations
 //!
 //!      ::io::async_psql           Async PSQL io-utils
 //!      ::io::protobuf             Protocol Buffer io-utils
@@ -12,8 +20,16 @@
 //!      ::io::uper                 Deprecated UNALIGNED PER decoder/encoder
 //! ```
 
+pub mod ber;
+pub mod charset;
+pub mod jer;
+pub mod oer;
+pub mod oid;
 pub mod per;
 pub mod protobuf;
+pub mod real;
+pub mod time;
+pub mod xer;
 
 #[cfg(feature = "psql")]
 pub mod psql;
@@ -0,0 +1,322 @@
+//! This module contains the basic building blocks of the Octet Encoding Rules as specified in
+//! ITU-T X.696 | ISO/IEC 8825-7:2015. All values are encoded in whole octets and most values of
+//! constrained types have a fixed size.
+
+pub mod err;
+
+pub use err::Error;
+
+use crate::model::Tag;
+use std::convert::TryFrom;
+
+const CLASS_UNIVERSAL: u8 = 0b0000_0000;
+const CLASS_APPLICATION: u8 = 0b0100_0000;
+const CLASS_CONTEXT_SPECIFIC: u8 = 0b1000_0000;
+const CLASS_PRIVATE: u8 = 0b1100_0000;
+const HIGH_TAG_NUMBER: u8 = 0b0011_1111;
+
+/// ITU-T X.696 | ISO/IEC 8825-7:2015, chapter 10, the encoding of an integer depends on the
+/// effective value constraint. An extensible constraint is treated as if there were no
+/// constraint at all.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum IntegerEncoding {
+    /// Chapter 10.3, the lower bound is not negative, the upper bound fits into the given amount
+    /// of octets (1, 2, 4 or 8)
+    FixedUnsigned(usize),
+    /// Chapter 10.4, the lower bound is negative, both bounds fit into the given amount of
+    /// octets (1, 2, 4 or 8)
+    FixedSigned(usize),
+    /// Chapter 10.6, the lower bound is not negative, prefixed by a length determinant
+    VariableUnsigned,
+    /// Chapter 10.7 and 10.8, prefixed by a length determinant
+    VariableSigned,
+}
+
+impl IntegerEncoding {
+    pub const fn from_constraint(min: Option<i64>, max: Option<i64>, extensible: bool) -> Self {
+        match (min, max) {
+            _ if extensible => IntegerEncoding::VariableSigned,
+            (Some(min), Some(max)) if min >= 0 => IntegerEncoding::FixedUnsigned(if max <= 0xFF {
+                1
+            } else if max <= 0xFF_FF {
+                2
+            } else if max <= 0xFF_FF_FF_FF {
+                4
+            } else {
+                8
+            }),
+            (Some(min), Some(max)) => {
+                IntegerEncoding::FixedSigned(if min >= i8::MIN as i64 && max <= i8::MAX as i64 {
+                    1
+                } else if min >= i16::MIN as i64 && max <= i16::MAX as i64 {
+                    2
+                } else if min >= i32::MIN as i64 && max <= i32::MAX as i64 {
+                    4
+                } else {
+                    8
+                })
+            }
+            (Some(min), None) if min >= 0 => IntegerEncoding::VariableUnsigned,
+            _ => IntegerEncoding::VariableSigned,
+        }
+    }
+
+    /// Like [`IntegerEncoding::from_constraint`], but for bounds that might exceed 64 bits and
+    /// therefore require a length determinant
+    pub fn from_wide_constraint(min: Option<i128>, max: Option<i128>, extensible: bool) -> Self {
+        let narrow = |bound: Option<i128>| bound.map(i64::try_from).transpose();
+        match (narrow(min), narrow(max)) {
+            (Ok(min), Ok(max)) => Self::from_constraint(min, max, extensible),
+            _ if !extensible && matches!(min, Some(min) if min >= 0) => {
+                IntegerEncoding::VariableUnsigned
+            }
+            _ => IntegerEncoding::VariableSigned,
+        }
+    }
+}
+
+/// ITU-T X.696 | ISO/IEC 8825-7:2015, chapter 8.6, writes the length determinant in the short
+/// form for lengths less than 128 and otherwise in the long form with the least amount of octets
+pub fn write_length_determinant(buffer: &mut Vec<u8>, length: usize) {
+    if length < 0x80 {
+        buffer.push(length as u8);
+    } else {
+        let octets = unsigned_octets(length as u64);
+        buffer.push(0x80 | octets as u8);
+        write_unsigned(buffer, length as u64, octets);
+    }
+}
+
+/// The amount of octets [`write_length_determinant`] writes for the given length
+pub const fn length_determinant_len(length: usize) -> usize {
+    if length < 0x80 {
+        1
+    } else {
+        1 + unsigned_octets(length as u64)
+    }
+}
+
+/// ITU-T X.696 | ISO/IEC 8825-7:2015, chapter 8.6, reads the length determinant and returns the
+/// length and the amount of octets consumed
+pub fn read_length_determinant(bytes: &[u8]) -> Result<(usize, usize), Error> {
+    let leading = *bytes.first().ok_or(Error::EndOfStream)?;
+    if leading & 0x80 == 0 {
+        Ok((usize::from(leading), 1))
+    } else {
+        let octets = usize::from(leading & 0x7F);
+        let bytes = bytes.get(1..=octets).ok_or(Error::EndOfStream)?;
+        let length = read_unsigned(bytes)?;
+        if length > usize::MAX as u64 {
+            Err(Error::ValueExceedsMaxInt)
+        } else {
+            Ok((length as usize, octets + 1))
+        }
+    }
+}
+
+/// The least amount of octets needed to represent the given value as unsigned integer
+pub const fn unsigned_octets(value: u64) -> usize {
+    let bits = 64 - value.leading_zeros() as usize;
+    if bits == 0 {
+        1
+    } else {
+        bits.div_ceil(8)
+    }
+}
+
+/// The least amount of octets needed to represent the given value in the two's complement form
+pub const fn signed_octets(value: i64) -> usize {
+    let redundant = if value < 0 {
+        value.leading_ones()
+    } else {
+        value.leading_zeros()
+    } as usize;
+    (64 - redundant + 1).div_ceil(8)
+}
+
+/// Writes the given value as unsigned integer in the given amount of octets
+pub fn write_unsigned(buffer: &mut Vec<u8>, value: u64, octets: usize) {
+    buffer.extend_from_slice(&value.to_be_bytes()[8 - octets..]);
+}
+
+/// Writes the given value in the two's complement form in the given amount of octets
+pub fn write_signed(buffer: &mut Vec<u8>, value: i64, octets: usize) {
+    buffer.extend_from_slice(&value.to_be_bytes()[8 - octets..]);
+}
+
+/// Reads all of the given octets as unsigned integer
+pub fn read_unsigned(bytes: &[u8]) -> Result<u64, Error> {
+    let skip = bytes.iter().take_while(|b| **b == 0).count();
+    if bytes.len() - skip > 8 {
+        Err(Error::ValueExceedsMaxInt)
+    } else {
+        Ok(bytes[skip..]
+            .iter()
+            .fold(0_u64, |value, byte| (value << 8) | u64::from(*byte)))
+    }
+}
+
+/// Reads all of the given octets as integer in the two's complement form
+pub fn read_signed(bytes: &[u8]) -> Result<i64, Error> {
+    if bytes.len() > 8 {
+        Err(Error::ValueExceedsMaxInt)
+    } else {
+        let fill = if bytes.first().is_some_and(|b| b & 0x80 != 0) {
+            0xFF
+        } else {
+            0x00
+        };
+        let mut buffer = [fill; 8];
+        buffer[8 - bytes.len()..].copy_from_slice(bytes);
+        Ok(i64::from_be_bytes(buffer))
+    }
+}
+
+/// ITU-T X.696 | ISO/IEC 8825-7:2015, chapter 8.7, writes the tag of a choice alternative. Tag
+/// numbers greater than 62 are written in the subsequent octets, seven bits at a time.
+pub fn write_tag(buffer: &mut Vec<u8>, tag: Tag) {
+    let (class, number) = match tag {
+        Tag::Universal(number) => (CLASS_UNIVERSAL, number),
+        Tag::Application(number) => (CLASS_APPLICATION, number),
+        Tag::ContextSpecific(number) => (CLASS_CONTEXT_SPECIFIC, number),
+        Tag::Private(number) => (CLASS_PRIVATE, number),
+    };
+    if number < usize::from(HIGH_TAG_NUMBER) {
+        buffer.push(class | number as u8);
+    } else {
+        buffer.push(class | HIGH_TAG_NUMBER);
+        let mut shift = (usize::BITS - number.leading_zeros()).div_ceil(7) * 7;
+        while shift > 7 {
+            shift -= 7;
+            buffer.push(0x80 | ((number >> shift) & 0x7F) as u8);
+        }
+        buffer.push((number & 0x7F) as u8);
+    }
+}
+
+/// ITU-T X.696 | ISO/IEC 8825-7:2015, chapter 8.7, reads the tag of a choice alternative and
+/// returns it with the amount of octets consumed
+pub fn read_tag(bytes: &[u8]) -> Result<(Tag, usize), Error> {
+    let leading = *bytes.first().ok_or(Error::EndOfStream)?;
+    let (number, consumed) = if leading & HIGH_TAG_NUMBER == HIGH_TAG_NUMBER {
+        let mut number = 0_usize;
+        let mut consumed = None;
+        for (index, byte) in bytes[1..].iter().enumerate() {
+            number = number
+                .checked_mul(128)
+                .ok_or(Error::ValueExceedsMaxInt)?
+                .checked_add(usize::from(byte & 0x7F))
+                .ok_or(Error::ValueExceedsMaxInt)?;
+            if byte & 0x80 == 0 {
+                consumed = Some(index + 2);
+                break;
+            }
+        }
+        (number, consumed.ok_or(Error::EndOfStream)?)
+    } else {
+        (usize::from(leading & HIGH_TAG_NUMBER), 1)
+    };
+    let tag = match leading & CLASS_PRIVATE {
+        CLASS_UNIVERSAL => Tag::Universal(number),
+        CLASS_APPLICATION => Tag::Application(number),
+        CLASS_CONTEXT_SPECIFIC => Tag::ContextSpecific(number),
+        _ => Tag::Private(number),
+    };
+    Ok((tag, consumed))
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    #[test]
+    fn test_integer_encoding_from_constraint() {
+        use IntegerEncoding::*;
+        for (min, max, extensible, encoding) in [
+            (Some(0), Some(255), false, FixedUnsigned(1)),
+            (Some(0), Some(256), false, FixedUnsigned(2)),
+            (Some(10), Some(65535), false, FixedUnsigned(2)),
+            (Some(0), Some(4294967295), false, FixedUnsigned(4)),
+            (Some(0), Some(4294967296), false, FixedUnsigned(8)),
+            (Some(-128), Some(127), false, FixedSigned(1)),
+            (Some(-129), Some(127), false, FixedSigned(2)),
+            (Some(-1), Some(32768), false, FixedSigned(4)),
+            (Some(i64::MIN), Some(0), false, FixedSigned(8)),
+            (Some(0), None, false, VariableUnsigned),
+            (Some(-1), None, false, VariableSigned),
+            (None, Some(5), false, VariableSigned),
+            (None, None, false, VariableSigned),
+            (Some(0), Some(255), true, VariableSigned),
+        ] {
+            assert_eq!(
+                encoding,
+                IntegerEncoding::from_constraint(min, max, extensible)
+            );
+        }
+    }
+
+    #[test]
+    fn test_length_determinant() {
+        for (length, bytes) in [
+            (0, &[0x00][..]),
+            (127, &[0x7F][..]),
+            (128, &[0x81, 0x80][..]),
+            (256, &[0x82, 0x01, 0x00][..]),
+        ] {
+            let mut buffer = Vec::new();
+            write_length_determinant(&mut buffer, length);
+            assert_eq!(bytes, &buffer[..]);
+            assert_eq!(bytes.len(), length_determinant_len(length));
+            assert_eq!(
+                (length, bytes.len()),
+                read_length_determinant(bytes).unwrap()
+            );
+        }
+        assert_eq!(
+            (5, 3),
+            read_length_determinant(&[0x82, 0x00, 0x05]).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_octets() {
+        assert_eq!(1, unsigned_octets(0));
+        assert_eq!(1, unsigned_octets(255));
+        assert_eq!(2, unsigned_octets(256));
+        assert_eq!(8, unsigned_octets(u64::MAX));
+        assert_eq!(1, signed_octets(0));
+        assert_eq!(1, signed_octets(127));
+        assert_eq!(2, signed_octets(128));
+        assert_eq!(1, signed_octets(-128));
+        assert_eq!(2, signed_octets(-129));
+        assert_eq!(8, signed_octets(i64::MIN));
+        assert_eq!(8, signed_octets(i64::MAX));
+    }
+
+    #[test]
+    fn test_signed_and_unsigned() {
+        let mut buffer = Vec::new();
+        write_unsigned(&mut buffer, 1000, 4);
+        write_signed(&mut buffer, -2, 2);
+        assert_eq!(&[0x00, 0x00, 0x03, 0xE8, 0xFF, 0xFE], &buffer[..]);
+        assert_eq!(1000, read_unsigned(&buffer[..4]).unwrap());
+        assert_eq!(-2, read_signed(&buffer[4..]).unwrap());
+        assert_eq!(0xFFFE, read_unsigned(&buffer[4..]).unwrap());
+    }
+
+    #[test]
+    fn test_tag() {
+        for (tag, bytes) in [
+            (Tag::ContextSpecific(0), &[0x80][..]),
+            (Tag::Universal(2), &[0x02][..]),
+            (Tag::Application(62), &[0x7E][..]),
+            (Tag::Private(63), &[0xFF, 0x3F][..]),
+            (Tag::ContextSpecific(128), &[0xBF, 0x81, 0x00][..]),
+        ] {
+            let mut buffer = Vec::new();
+            write_tag(&mut buffer, tag);
+            assert_eq!(bytes, &buffer[..]);
+            assert_eq!((tag, bytes.len()), read_tag(bytes).unwrap());
+        }
+    }
+}
@@ -0,0 +1,121 @@
+//! Content octets of `OBJECT IDENTIFIER` and `RELATIVE-OID` values as used by the octet-based
+//! encodings and PER, see ITU-T X.690 | ISO/IEC 8825-1:2015, chapter 8.19 and 8.20
+
+/// Encodes the arcs of an object identifier or returns `None` if there are less than two arcs
+/// or the first two arcs cannot be combined
+pub fn object_identifier_to_octets(arcs: &[u64]) -> Option<Vec<u8>> {
+    match arcs {
+        [first @ 0..=1, second @ 0..=39, rest @ ..] | [first @ 2, second, rest @ ..] => {
+            let mut octets = Vec::with_capacity(arcs.len());
+            write_subidentifier(&mut octets, (first * 40).checked_add(*second)?);
+            rest.iter()
+                .for_each(|arc| write_subidentifier(&mut octets, *arc));
+            Some(octets)
+        }
+        _ => None,
+    }
+}
+
+/// Decodes the arcs of an object identifier or returns `None` if the octets are not a valid
+/// encoding
+pub fn object_identifier_from_octets(octets: &[u8]) -> Option<Vec<u64>> {
+    let mut arcs = relative_oid_from_octets(octets)?;
+    let first = *arcs.first()?;
+    let (first, second) = match first {
+        0..=39 => (0, first),
+        40..=79 => (1, first - 40),
+        _ => (2, first - 80),
+    };
+    arcs[0] = second;
+    arcs.insert(0, first);
+    Some(arcs)
+}
+
+/// Encodes the arcs of a relative object identifier
+pub fn relative_oid_to_octets(arcs: &[u64]) -> Vec<u8> {
+    let mut octets = Vec::with_capacity(arcs.len());
+    arcs.iter()
+        .for_each(|arc| write_subidentifier(&mut octets, *arc));
+    octets
+}
+
+/// Decodes the arcs of a relative object identifier or returns `None` if the octets are not a
+/// valid encoding
+pub fn relative_oid_from_octets(octets: &[u8]) -> Option<Vec<u64>> {
+    let mut arcs = Vec::new();
+    let mut arc = 0_u64;
+    let mut first_of_arc = true;
+    for octet in octets {
+        if first_of_arc && *octet == 0x80 {
+            // the leading octet shall not be 0x80
+            return None;
+        }
+        arc = arc.checked_mul(128)? | u64::from(octet & 0x7F);
+        first_of_arc = octet & 0x80 == 0;
+        if first_of_arc {
+            arcs.push(arc);
+            arc = 0;
+        }
+    }
+    if first_of_arc {
+        Some(arcs)
+    } else {
+        None
+    }
+}
+
+fn write_subidentifier(octets: &mut Vec<u8>, arc: u64) {
+    let groups = (64 - arc.leading_zeros() as usize).div_ceil(7);
+    for group in (1..groups.max(1)).rev() {
+        octets.push(0x80 | (arc >> (7 * group)) as u8 & 0x7F);
+    }
+    octets.push(arc as u8 & 0x7F);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_object_identifier() {
+        // ITU-T X.690, 8.19.5
+        let arcs = vec![2, 100, 3];
+        assert_eq!(
+            Some(vec![0x81, 0x34, 0x03]),
+            object_identifier_to_octets(&arcs)
+        );
+        assert_eq!(
+            Some(arcs),
+            object_identifier_from_octets(&[0x81, 0x34, 0x03])
+        );
+        assert_eq!(
+            Some(vec![1, 2, 840, 113_549]),
+            object_identifier_from_octets(&[0x2A, 0x86, 0x48, 0x86, 0xF7, 0x0D])
+        );
+    }
+
+    #[test]
+    fn test_invalid_object_identifier() {
+        assert_eq!(None, object_identifier_to_octets(&[1]));
+        assert_eq!(None, object_identifier_to_octets(&[1, 40]));
+        assert_eq!(None, object_identifier_to_octets(&[3, 1]));
+        assert_eq!(None, object_identifier_from_octets(&[]));
+        assert_eq!(None, object_identifier_from_octets(&[0x2A, 0x86]));
+        assert_eq!(None, object_identifier_from_octets(&[0x80, 0x01]));
+    }
+
+    #[test]
+    fn test_relative_oid() {
+        let arcs = vec![8571, 3, 2];
+        assert_eq!(vec![0xC2, 0x7B, 0x03, 0x02], relative_oid_to_octets(&arcs));
+        assert_eq!(
+            Some(arcs),
+            relative_oid_from_octets(&[0xC2, 0x7B, 0x03, 0x02])
+        );
+        assert_eq!(Some(vec![0]), relative_oid_from_octets(&[0x00]));
+        assert_eq!(
+            Some(vec![u64::MAX]),
+            relative_oid_from_octets(&relative_oid_to_octets(&[u64::MAX]))
+        );
+    }
+}
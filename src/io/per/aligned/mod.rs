@@ -0,0 +1,974 @@
+use crate::io::per::unaligned::buffer::BitBuffer;
+use crate::io::per::unaligned::BitWrite;
+use crate::io::per::unaligned::ScopedBitRead;
+use crate::io::per::unaligned::BYTE_LEN;
+use crate::io::per::Error;
+use crate::io::per::{PackedRead, PackedWrite};
+use std::convert::TryFrom;
+
+const MAX_FRAGMENTS: u64 = 4  /* 11.9.3.8, NOTE */ ;
+
+const LENGTH_127: u64 = 127;
+const LENGTH_16K: u64 = 16 * 1024;
+const LENGTH_64K: u64 = 64 * 1024;
+
+const SMALL_NON_NEGATIVE_NUMBER: u64 = 64;
+
+/// Wraps a bit source ([`ScopedBitRead`]) or a bit sink ([`BitBuffer`]) and provides the
+/// ALIGNED variant of [`PackedRead`] and [`PackedWrite`] on top of it. Octet-alignment is always
+/// relative to the beginning of the wrapped value.
+///
+/// Because everything that is not octet-aligned is encoded identical to the UNALIGNED variant,
+/// the wrapped value can be accessed directly for bit-fields (like presence flags), while all
+/// other values must be encoded and decoded through this wrapper.
+#[derive(Default)]
+pub struct Aligned<T>(pub T);
+
+impl<T> Aligned<T> {
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> From<T> for Aligned<T> {
+    fn from(value: T) -> Self {
+        Aligned(value)
+    }
+}
+
+/// Number of octets required to represent the given value, but at least one
+#[inline]
+const fn octets_for(value: u64) -> u64 {
+    let bits = (u64::BITS - value.leading_zeros()) as u64;
+    if bits == 0 {
+        1
+    } else {
+        bits.div_ceil(BYTE_LEN as u64)
+    }
+}
+
+/// Number of octets required to represent the given value, but at least one
+#[inline]
+const fn octets_for_wide(value: u128) -> u64 {
+    let bits = (u128::BITS - value.leading_zeros()) as u64;
+    if bits == 0 {
+        1
+    } else {
+        bits.div_ceil(BYTE_LEN as u64)
+    }
+}
+
+/// Whether the length determinant is encoded in the form of ITU-T X.691 | ISO/IEC 8825-2:2015,
+/// chapter 11.9.3.5 - 11.9.3.8, which is the only form that allows fragmentation
+#[inline]
+const fn is_unconstrained_length(upper_bound: Option<u64>) -> bool {
+    match upper_bound {
+        Some(upper_bound) => upper_bound >= LENGTH_64K,
+        None => true,
+    }
+}
+
+impl<T: ScopedBitRead> Aligned<T> {
+    /// Skips the padding bits until the read position is on an octet boundary
+    #[inline]
+    pub fn align(&mut self) -> Result<(), Error> {
+        let padding = (BYTE_LEN - (self.0.pos() % BYTE_LEN)) % BYTE_LEN;
+        if padding > self.0.remaining() {
+            Err(Error::EndOfStream)
+        } else {
+            self.0.set_pos(self.0.pos() + padding);
+            Ok(())
+        }
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.5.7, `range` is the difference between the
+    /// upper- and the lower-bound
+    #[inline]
+    fn read_constrained_offset(&mut self, range: u64) -> Result<u64, Error> {
+        if range == 0 {
+            Ok(0)
+        } else if range < 255 {
+            // 11.5.7.1: the bit-field case
+            self.0.read_non_negative_binary_integer(None, Some(range))
+        } else if range == 255 {
+            // 11.5.7.2: the one-octet case
+            self.align()?;
+            self.0
+                .read_non_negative_binary_integer(None, Some(u64::from(u8::MAX)))
+        } else if range < LENGTH_64K {
+            // 11.5.7.3: the two-octet case
+            self.align()?;
+            self.0
+                .read_non_negative_binary_integer(None, Some(u64::from(u16::MAX)))
+        } else {
+            // 11.5.7.4: the indefinite length case
+            let octets = self.read_constrained_offset(octets_for(range) - 1)? + 1;
+            self.align()?;
+            self.read_octets_as_integer(octets)
+        }
+    }
+
+    #[inline]
+    fn read_octets_as_integer(&mut self, octets: u64) -> Result<u64, Error> {
+        let mut bytes = [0u8; std::mem::size_of::<u64>()];
+        if octets as usize > bytes.len() {
+            return Err(Error::ValueExceedsMaxInt);
+        }
+        let offset = bytes.len() - octets as usize;
+        self.0.read_bits(&mut bytes[offset..])?;
+        Ok(u64::from_be_bytes(bytes))
+    }
+
+    /// Reads the fragments following a length determinant that announced a fragment of
+    /// `length` units, ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.9.3.8
+    #[inline]
+    fn read_fragmented<F: FnMut(&mut Self, u64) -> Result<(), Error>>(
+        &mut self,
+        mut length: u64,
+        mut read: F,
+    ) -> Result<(), Error> {
+        loop {
+            read(self, length)?;
+            if length < LENGTH_16K {
+                return Ok(());
+            }
+            length = self.read_length_determinant(None, None)?;
+        }
+    }
+}
+
+impl<T: ScopedBitRead> PackedRead for Aligned<T> {
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 12
+    #[inline]
+    fn read_boolean(&mut self) -> Result<bool, Error> {
+        self.0.read_bit()
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.4
+    #[inline]
+    fn read_2s_compliment_binary_integer(&mut self, bit_len: u64) -> Result<i64, Error> {
+        self.0.read_2s_compliment_binary_integer(bit_len)
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.5
+    #[inline]
+    fn read_constrained_whole_number(
+        &mut self,
+        lower_bound: i64,
+        upper_bound: i64,
+    ) -> Result<i64, Error> {
+        let range = upper_bound - lower_bound;
+        if range > 0 {
+            Ok(lower_bound + self.read_constrained_offset(range as u64)? as i64)
+        } else {
+            Ok(lower_bound)
+        }
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.5
+    #[inline]
+    fn read_constrained_wide_offset(&mut self, range: u128) -> Result<u128, Error> {
+        if let Ok(range) = u64::try_from(range) {
+            Ok(u128::from(self.read_constrained_offset(range)?))
+        } else {
+            // 11.5.7.4: the indefinite length case
+            let octets = self.read_constrained_offset(octets_for_wide(range) - 1)? + 1;
+            self.align()?;
+            let mut bytes = [0u8; std::mem::size_of::<u128>()];
+            if octets as usize > bytes.len() {
+                return Err(Error::ValueExceedsMaxInt);
+            }
+            let offset = bytes.len() - octets as usize;
+            self.0.read_bits(&mut bytes[offset..])?;
+            Ok(u128::from_be_bytes(bytes))
+        }
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.9
+    #[inline]
+    #[allow(clippy::redundant_pattern_matching)] // allow for const_*!
+    fn read_length_determinant(
+        &mut self,
+        lower_bound: Option<u64>,
+        upper_bound: Option<u64>,
+    ) -> Result<u64, Error> {
+        if is_unconstrained_length(upper_bound) {
+            // 11.9.3.5: octet-aligned in the ALIGNED variant
+            self.align()?;
+            if !self.0.read_bit()? {
+                // 11.9.3.6: less than or equal to 127
+                self.0
+                    .read_non_negative_binary_integer(None, Some(LENGTH_127))
+            } else if !self.0.read_bit()? {
+                // 11.9.3.7: greater than 127 and less than 16K
+                self.0
+                    .read_non_negative_binary_integer(None, Some(LENGTH_16K - 1))
+            } else {
+                // 11.9.3.8: chunks of 16k multiples
+                let multiple = self
+                    .0
+                    .read_non_negative_binary_integer(None, Some(SMALL_NON_NEGATIVE_NUMBER - 1))?;
+                if multiple == 0 || multiple > MAX_FRAGMENTS {
+                    Err(Error::ValueNotInRange(
+                        multiple as i64,
+                        1,
+                        MAX_FRAGMENTS as i64,
+                    ))
+                } else {
+                    Ok(LENGTH_16K * multiple)
+                }
+            }
+        } else {
+            // 11.9.3.3: ub less than 64K, encoded as constrained whole number
+            let lower_bound = const_unwrap_or!(lower_bound, 0);
+            let upper_bound = const_unwrap_or!(upper_bound, 0);
+            Ok(lower_bound
+                + self.read_constrained_offset(upper_bound.saturating_sub(lower_bound))?)
+        }
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.9.3.4
+    #[inline]
+    fn read_normally_small_length(&mut self) -> Result<u64, Error> {
+        self.read_normally_small_non_negative_whole_number()
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.6
+    #[inline]
+    fn read_normally_small_non_negative_whole_number(&mut self) -> Result<u64, Error> {
+        let greater_or_equal_to_64 = self.0.read_bit()?;
+        if greater_or_equal_to_64 {
+            // 11.6.2
+            self.read_non_negative_binary_integer(None, None)
+        } else {
+            // 11.6.1
+            self.0
+                .read_non_negative_binary_integer(None, Some(SMALL_NON_NEGATIVE_NUMBER - 1))
+        }
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.3, the field size is determined according to
+    /// chapter 11.5.7 for a constrained value or according to chapter 11.7 otherwise.
+    #[inline]
+    #[allow(clippy::redundant_pattern_matching)] // allow for const_*!
+    fn read_non_negative_binary_integer(
+        &mut self,
+        lower_bound: Option<u64>,
+        upper_bound: Option<u64>,
+    ) -> Result<u64, Error> {
+        if const_is_none!(lower_bound) && const_is_none!(upper_bound) {
+            let octets = self.read_length_determinant(None, None)?;
+            self.read_octets_as_integer(octets)
+        } else {
+            let lower = const_unwrap_or!(lower_bound, 0);
+            let upper = const_unwrap_or!(upper_bound, i64::MAX as u64);
+            Ok(lower + self.read_constrained_offset(upper - lower)?)
+        }
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.7
+    #[inline]
+    fn read_semi_constrained_whole_number(&mut self, lower_bound: i64) -> Result<i64, Error> {
+        let n = self.read_non_negative_binary_integer(None, None)?;
+        Ok((n as i64) + lower_bound)
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.8
+    #[inline]
+    fn read_unconstrained_whole_number(&mut self) -> Result<i64, Error> {
+        let octet_len = self.read_length_determinant(None, None)?;
+        if octet_len as usize > std::mem::size_of::<i64>() {
+            return Err(Error::ValueExceedsMaxInt);
+        }
+        self.0
+            .read_2s_compliment_binary_integer(octet_len * BYTE_LEN as u64)
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 16
+    #[inline]
+    #[allow(clippy::redundant_pattern_matching)] // allow for const_*!
+    fn read_bitstring(
+        &mut self,
+        lower_bound_size: Option<u64>,
+        upper_bound_size: Option<u64>,
+        extensible: bool,
+    ) -> Result<(Vec<u8>, u64), Error> {
+        let upper_bound = const_unwrap_or!(upper_bound_size, i64::MAX as u64);
+        let fixed_size = const_is_some!(lower_bound_size) && lower_bound_size == upper_bound_size;
+
+        let (bit_len, aligned, constrained) = if extensible && self.0.read_bit()? {
+            // 16.6
+            (self.read_length_determinant(None, None)?, true, None)
+        } else if upper_bound == 0 {
+            // 16.8
+            return Ok((Vec::default(), 0));
+        } else if fixed_size && upper_bound <= 16 {
+            // 16.9
+            (upper_bound, false, Some(upper_bound))
+        } else if fixed_size && upper_bound < LENGTH_64K {
+            // 16.10
+            (upper_bound, true, Some(upper_bound))
+        } else {
+            // 16.11
+            let length = self.read_length_determinant(lower_bound_size, upper_bound_size)?;
+            (length, true, upper_bound_size)
+        };
+
+        let mut buffer = Vec::new();
+        let mut total = 0_u64;
+        let mut read = |reader: &mut Self, length: u64| {
+            if aligned && length > 0 {
+                reader.align()?;
+            }
+            buffer.resize((total + length).div_ceil(BYTE_LEN as u64) as usize, 0u8);
+            reader
+                .0
+                .read_bits_with_offset_len(&mut buffer[..], total as usize, length as usize)?;
+            total += length;
+            Ok(())
+        };
+
+        if is_unconstrained_length(constrained) {
+            self.read_fragmented(bit_len, read)?;
+        } else {
+            read(self, bit_len)?;
+        }
+
+        Ok((buffer, total))
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 17
+    #[inline]
+    #[allow(clippy::redundant_pattern_matching)] // allow for const_*!
+    fn read_octetstring(
+        &mut self,
+        lower_bound_size: Option<u64>,
+        upper_bound_size: Option<u64>,
+        extensible: bool,
+    ) -> Result<Vec<u8>, Error> {
+        let upper_bound = const_unwrap_or!(upper_bound_size, i64::MAX as u64);
+        let fixed_size = const_is_some!(lower_bound_size) && lower_bound_size == upper_bound_size;
+
+        let (byte_len, aligned, constrained) = if extensible && self.0.read_bit()? {
+            // 17.3
+            (self.read_length_determinant(None, None)?, true, None)
+        } else if upper_bound == 0 {
+            // 17.5
+            return Ok(Vec::default());
+        } else if fixed_size && upper_bound <= 2 {
+            // 17.6
+            (upper_bound, false, Some(upper_bound))
+        } else if fixed_size && upper_bound < LENGTH_64K {
+            // 17.7
+            (upper_bound, true, Some(upper_bound))
+        } else {
+            // 17.8
+            let length = self.read_length_determinant(lower_bound_size, upper_bound_size)?;
+            (length, true, upper_bound_size)
+        };
+
+        let mut buffer = Vec::new();
+        let mut read = |reader: &mut Self, length: u64| {
+            if aligned && length > 0 {
+                reader.align()?;
+            }
+            let offset = buffer.len();
+            buffer.resize(offset + length as usize, 0u8);
+            reader.0.read_bits(&mut buffer[offset..])
+        };
+
+        if is_unconstrained_length(constrained) {
+            self.read_fragmented(byte_len, read)?;
+        } else {
+            read(self, byte_len)?;
+        }
+
+        Ok(buffer)
+    }
+
+    #[inline]
+    fn read_choice_index(&mut self, std_variants: u64, extensible: bool) -> Result<u64, Error> {
+        self.read_enumeration_index(std_variants, extensible)
+    }
+
+    #[inline]
+    fn read_enumeration_index(
+        &mut self,
+        std_variants: u64,
+        extensible: bool,
+    ) -> Result<u64, Error> {
+        if extensible && self.0.read_bit()? {
+            Ok(self.read_normally_small_length()? + std_variants)
+        } else {
+            self.read_constrained_offset(std_variants.saturating_sub(1))
+        }
+    }
+}
+
+impl Aligned<BitBuffer> {
+    /// Writes zero-bits as padding until the write position is on an octet boundary
+    #[inline]
+    pub fn align(&mut self) -> Result<(), Error> {
+        let padding = (BYTE_LEN - (self.0.write_position % BYTE_LEN)) % BYTE_LEN;
+        if padding > 0 {
+            self.0.write_bits_with_offset_len(&[0u8], 0, padding)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.5.7, `range` is the difference between the
+    /// upper- and the lower-bound
+    #[inline]
+    fn write_constrained_offset(&mut self, range: u64, value: u64) -> Result<(), Error> {
+        if range == 0 {
+            Ok(())
+        } else if range < 255 {
+            // 11.5.7.1: the bit-field case
+            self.0
+                .write_non_negative_binary_integer(None, Some(range), value)
+        } else if range == 255 {
+            // 11.5.7.2: the one-octet case
+            self.align()?;
+            self.0.write_bits(&[value as u8])
+        } else if range < LENGTH_64K {
+            // 11.5.7.3: the two-octet case
+            self.align()?;
+            self.0.write_bits(&(value as u16).to_be_bytes())
+        } else {
+            // 11.5.7.4: the indefinite length case
+            let octets = octets_for(value);
+            self.write_constrained_offset(octets_for(range) - 1, octets - 1)?;
+            self.align()?;
+            self.write_integer_as_octets(octets, value)
+        }
+    }
+
+    #[inline]
+    fn write_integer_as_octets(&mut self, octets: u64, value: u64) -> Result<(), Error> {
+        let bytes = value.to_be_bytes();
+        self.0.write_bits(&bytes[bytes.len() - octets as usize..])
+    }
+
+    /// Writes `length` units with the given closure, splits them into fragments if necessary,
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.9.3.8
+    #[inline]
+    fn write_fragmented<F: FnMut(&mut Self, u64, u64) -> Result<(), Error>>(
+        &mut self,
+        length: u64,
+        mut write: F,
+    ) -> Result<(), Error> {
+        let mut written = 0;
+        loop {
+            let fragment = (length - written).min(LENGTH_16K * MAX_FRAGMENTS);
+            let fragment = if fragment >= LENGTH_16K {
+                fragment - (fragment % LENGTH_16K)
+            } else {
+                fragment
+            };
+            self.write_length_determinant(None, None, fragment)?;
+            write(self, written, fragment)?;
+            written += fragment;
+
+            if fragment < LENGTH_16K {
+                return Ok(());
+            }
+        }
+    }
+}
+
+impl PackedWrite for Aligned<BitBuffer> {
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 12
+    #[inline]
+    fn write_boolean(&mut self, boolean: bool) -> Result<(), Error> {
+        self.0.write_bit(boolean)
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.4
+    #[inline]
+    fn write_2s_compliment_binary_integer(
+        &mut self,
+        bit_len: u64,
+        value: i64,
+    ) -> Result<(), Error> {
+        self.0.write_2s_compliment_binary_integer(bit_len, value)
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.5
+    #[inline]
+    fn write_constrained_whole_number(
+        &mut self,
+        lower_bound: i64,
+        upper_bound: i64,
+        value: i64,
+    ) -> Result<(), Error> {
+        let range = upper_bound - lower_bound;
+        if range > 0 {
+            if value < lower_bound || value > upper_bound {
+                Err(Error::ValueNotInRange(value, lower_bound, upper_bound))
+            } else {
+                self.write_constrained_offset(range as u64, (value - lower_bound) as u64)
+            }
+        } else {
+            Ok(())
+        }
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.5
+    #[inline]
+    fn write_constrained_wide_offset(&mut self, range: u128, offset: u128) -> Result<(), Error> {
+        if let (Ok(range), Ok(offset)) = (u64::try_from(range), u64::try_from(offset)) {
+            self.write_constrained_offset(range, offset)
+        } else {
+            // 11.5.7.4: the indefinite length case
+            let octets = octets_for_wide(offset);
+            self.write_constrained_offset(octets_for_wide(range) - 1, octets - 1)?;
+            self.align()?;
+            let bytes = offset.to_be_bytes();
+            self.0.write_bits(&bytes[bytes.len() - octets as usize..])
+        }
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.9
+    #[inline]
+    #[allow(clippy::redundant_pattern_matching)] // allow for const_*!
+    fn write_length_determinant(
+        &mut self,
+        lower_bound: Option<u64>,
+        upper_bound: Option<u64>,
+        length: u64,
+    ) -> Result<(), Error> {
+        if is_unconstrained_length(upper_bound) {
+            // 11.9.3.5: octet-aligned in the ALIGNED variant
+            self.align()?;
+            if length <= LENGTH_127 {
+                // 11.9.3.6: less than or equal to 127
+                self.0.write_bit(false)?;
+                self.0
+                    .write_non_negative_binary_integer(None, Some(LENGTH_127), length)
+            } else if length < LENGTH_16K {
+                // 11.9.3.7: greater than 127 and less than 16K
+                self.0.write_bit(true)?;
+                self.0.write_bit(false)?;
+                self.0
+                    .write_non_negative_binary_integer(None, Some(LENGTH_16K - 1), length)
+            } else {
+                // 11.9.3.8: chunks of 16k multiples
+                self.0.write_bit(true)?;
+                self.0.write_bit(true)?;
+                self.0.write_non_negative_binary_integer(
+                    None,
+                    Some(SMALL_NON_NEGATIVE_NUMBER - 1),
+                    (length / LENGTH_16K).min(MAX_FRAGMENTS),
+                )
+            }
+        } else {
+            // 11.9.3.3: ub less than 64K, encoded as constrained whole number
+            let lower_bound = const_unwrap_or!(lower_bound, 0);
+            let upper_bound = const_unwrap_or!(upper_bound, 0);
+            if length < lower_bound || length > upper_bound {
+                Err(Error::SizeNotInRange(length, lower_bound, upper_bound))
+            } else {
+                self.write_constrained_offset(
+                    upper_bound.saturating_sub(lower_bound),
+                    length - lower_bound,
+                )
+            }
+        }
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.9.3.4
+    #[inline]
+    fn write_normally_small_length(&mut self, value: u64) -> Result<(), Error> {
+        self.write_normally_small_non_negative_whole_number(value)
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.6
+    #[inline]
+    fn write_normally_small_non_negative_whole_number(&mut self, value: u64) -> Result<(), Error> {
+        let greater_or_equal_to_64 = value >= SMALL_NON_NEGATIVE_NUMBER;
+        self.0.write_bit(greater_or_equal_to_64)?;
+        if greater_or_equal_to_64 {
+            // 11.6.2
+            self.write_non_negative_binary_integer(None, None, value)
+        } else {
+            // 11.6.1
+            self.0.write_non_negative_binary_integer(
+                None,
+                Some(SMALL_NON_NEGATIVE_NUMBER - 1),
+                value,
+            )
+        }
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.3, the field size is determined according to
+    /// chapter 11.5.7 for a constrained value or according to chapter 11.7 otherwise.
+    #[inline]
+    #[allow(clippy::redundant_pattern_matching)] // allow for const_*!
+    fn write_non_negative_binary_integer(
+        &mut self,
+        lower_bound: Option<u64>,
+        upper_bound: Option<u64>,
+        value: u64,
+    ) -> Result<(), Error> {
+        if const_is_none!(lower_bound) && const_is_none!(upper_bound) {
+            let octets = octets_for(value);
+            self.write_length_determinant(None, None, octets)?;
+            self.write_integer_as_octets(octets, value)
+        } else {
+            let lower = const_unwrap_or!(lower_bound, 0);
+            let upper = const_unwrap_or!(upper_bound, i64::MAX as u64);
+            self.write_constrained_offset(upper - lower, value - lower)
+        }
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.7
+    #[inline]
+    fn write_semi_constrained_whole_number(
+        &mut self,
+        lower_bound: i64,
+        value: i64,
+    ) -> Result<(), Error> {
+        if value < lower_bound {
+            Err(Error::ValueNotInRange(value, lower_bound, i64::MAX))
+        } else {
+            self.write_non_negative_binary_integer(None, None, (value - lower_bound) as u64)
+        }
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.8
+    #[inline]
+    fn write_unconstrained_whole_number(&mut self, value: i64) -> Result<(), Error> {
+        let prefix_len = if value.is_negative() {
+            value.leading_ones().saturating_sub(1)
+        } else {
+            value.leading_zeros().saturating_sub(1)
+        } as u64
+            / 8;
+        let octet_len = core::mem::size_of::<i64>() as u64 - prefix_len;
+        self.write_length_determinant(None, None, octet_len)?;
+        self.0
+            .write_2s_compliment_binary_integer(octet_len * BYTE_LEN as u64, value)
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 16
+    #[inline]
+    #[allow(clippy::redundant_pattern_matching)] // allow for const_*!
+    fn write_bitstring(
+        &mut self,
+        lower_bound_size: Option<u64>,
+        upper_bound_size: Option<u64>,
+        extensible: bool,
+        src: &[u8],
+        offset: u64,
+        len: u64,
+    ) -> Result<(), Error> {
+        let lower_bound = const_unwrap_or!(lower_bound_size, 0);
+        let upper_bound = const_unwrap_or!(upper_bound_size, i64::MAX as u64);
+        let fixed_size = const_is_some!(lower_bound_size) && lower_bound_size == upper_bound_size;
+        let out_of_range = len < lower_bound || len > upper_bound;
+
+        if extensible {
+            self.0.write_bit(out_of_range)?;
+        }
+
+        let write = |writer: &mut Self, start: u64, length: u64| {
+            if length > 0 {
+                writer.align()?;
+            }
+            writer
+                .0
+                .write_bits_with_offset_len(src, (offset + start) as usize, length as usize)
+        };
+
+        if out_of_range {
+            if extensible {
+                // 16.6
+                self.write_fragmented(len, write)
+            } else {
+                Err(Error::SizeNotInRange(len, lower_bound, upper_bound))
+            }
+        } else if upper_bound == 0 {
+            // 16.8
+            Ok(())
+        } else if fixed_size && upper_bound <= 16 {
+            // 16.9
+            self.0
+                .write_bits_with_offset_len(src, offset as usize, len as usize)
+        } else if fixed_size && upper_bound < LENGTH_64K {
+            // 16.10
+            write(self, 0, len)
+        } else if is_unconstrained_length(upper_bound_size) {
+            // 16.11
+            self.write_fragmented(len, write)
+        } else {
+            // 16.11
+            self.write_length_determinant(lower_bound_size, upper_bound_size, len)?;
+            write(self, 0, len)
+        }
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 17
+    #[inline]
+    #[allow(clippy::redundant_pattern_matching)] // allow for const_*!
+    fn write_octetstring(
+        &mut self,
+        lower_bound_size: Option<u64>,
+        upper_bound_size: Option<u64>,
+        extensible: bool,
+        src: &[u8],
+    ) -> Result<(), Error> {
+        let lower_bound = const_unwrap_or!(lower_bound_size, 0);
+        let upper_bound = const_unwrap_or!(upper_bound_size, i64::MAX as u64);
+        let fixed_size = const_is_some!(lower_bound_size) && lower_bound_size == upper_bound_size;
+        let len = src.len() as u64;
+        let out_of_range = len < lower_bound || len > upper_bound;
+
+        if extensible {
+            self.0.write_bit(out_of_range)?;
+        }
+
+        let write = |writer: &mut Self, start: u64, length: u64| {
+            if length > 0 {
+                writer.align()?;
+            }
+            writer
+                .0
+                .write_bits(&src[start as usize..(start + length) as usize])
+        };
+
+        if out_of_range {
+            if extensible {
+                // 17.3
+                self.write_fragmented(len, write)
+            } else {
+                Err(Error::SizeNotInRange(len, lower_bound, upper_bound))
+            }
+        } else if upper_bound == 0 {
+            // 17.5
+            Ok(())
+        } else if fixed_size && upper_bound <= 2 {
+            // 17.6
+            self.0.write_bits(src)
+        } else if fixed_size && upper_bound < LENGTH_64K {
+            // 17.7
+            write(self, 0, len)
+        } else if is_unconstrained_length(upper_bound_size) {
+            // 17.8
+            self.write_fragmented(len, write)
+        } else {
+            // 17.8
+            self.write_length_determinant(lower_bound_size, upper_bound_size, len)?;
+            write(self, 0, len)
+        }
+    }
+
+    #[inline]
+    fn write_choice_index(
+        &mut self,
+        std_variants: u64,
+        extensible: bool,
+        index: u64,
+    ) -> Result<(), Error> {
+        self.write_enumeration_index(std_variants, extensible, index)
+    }
+
+    #[inline]
+    fn write_enumeration_index(
+        &mut self,
+        std_variants: u64,
+        extensible: bool,
+        index: u64,
+    ) -> Result<(), Error> {
+        let out_of_range = index >= std_variants;
+        if extensible {
+            self.0.write_bit(out_of_range)?;
+        }
+
+        if out_of_range {
+            if extensible {
+                self.write_normally_small_length(index - std_variants)
+            } else {
+                Err(Error::InvalidChoiceIndex(index, std_variants))
+            }
+        } else {
+            self.write_constrained_offset(std_variants.saturating_sub(1), index)
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use crate::io::per::unaligned::buffer::Bits;
+
+    fn written<F: FnOnce(&mut Aligned<BitBuffer>) -> Result<(), Error>>(f: F) -> Vec<u8> {
+        let mut buffer = Aligned(BitBuffer::default());
+        f(&mut buffer).unwrap();
+        buffer.0.into()
+    }
+
+    #[test]
+    fn constrained_whole_number_bit_field_case() {
+        // 11.5.7.1: range of 255 values or less, no alignment
+        let bytes = written(|b| {
+            b.0.write_bit(true)?;
+            b.write_constrained_whole_number(0, 254, 3)
+        });
+        assert_eq!(&[0b1000_0001, 0b1000_0000], &bytes[..]);
+        let mut reader = Aligned(Bits::from(&bytes[..]));
+        assert!(reader.read_boolean().unwrap());
+        assert_eq!(3, reader.read_constrained_whole_number(0, 254).unwrap());
+    }
+
+    #[test]
+    fn constrained_whole_number_one_octet_case() {
+        // 11.5.7.2: range of 256 values, octet-aligned
+        let bytes = written(|b| {
+            b.0.write_bit(true)?;
+            b.write_constrained_whole_number(0, 255, 3)
+        });
+        assert_eq!(&[0b1000_0000, 0x03], &bytes[..]);
+        let mut reader = Aligned(Bits::from(&bytes[..]));
+        assert!(reader.read_boolean().unwrap());
+        assert_eq!(3, reader.read_constrained_whole_number(0, 255).unwrap());
+    }
+
+    #[test]
+    fn constrained_whole_number_two_octet_case() {
+        // 11.5.7.3: range of 257 to 64K values, octet-aligned
+        let bytes = written(|b| b.write_constrained_whole_number(-10, 1000, 0));
+        assert_eq!(&[0x00, 0x0A], &bytes[..]);
+        let mut reader = Aligned(Bits::from(&bytes[..]));
+        assert_eq!(0, reader.read_constrained_whole_number(-10, 1000).unwrap());
+    }
+
+    #[test]
+    fn constrained_whole_number_indefinite_length_case() {
+        // 11.5.7.4: range greater than 64K, length as 2 bit (1..4 octets) bit-field, then
+        // octet-aligned minimal octets of the value
+        let bytes = written(|b| b.write_constrained_whole_number(0, 4_294_967_295, 256));
+        assert_eq!(&[0b0100_0000, 0x01, 0x00], &bytes[..]);
+        let mut reader = Aligned(Bits::from(&bytes[..]));
+        assert_eq!(
+            256,
+            reader
+                .read_constrained_whole_number(0, 4_294_967_295)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn unconstrained_whole_number_is_octet_aligned() {
+        let bytes = written(|b| {
+            b.0.write_bit(true)?;
+            b.write_unconstrained_whole_number(-1)
+        });
+        assert_eq!(&[0b1000_0000, 0x01, 0xFF], &bytes[..]);
+        let mut reader = Aligned(Bits::from(&bytes[..]));
+        assert!(reader.read_boolean().unwrap());
+        assert_eq!(-1, reader.read_unconstrained_whole_number().unwrap());
+    }
+
+    #[test]
+    fn semi_constrained_whole_number() {
+        let bytes = written(|b| b.write_semi_constrained_whole_number(-1, 300));
+        assert_eq!(&[0x02, 0x01, 0x2D], &bytes[..]);
+        let mut reader = Aligned(Bits::from(&bytes[..]));
+        assert_eq!(300, reader.read_semi_constrained_whole_number(-1).unwrap());
+    }
+
+    #[test]
+    fn octetstring_fixed_size_of_two_is_not_aligned() {
+        let bytes = written(|b| {
+            b.0.write_bit(true)?;
+            b.write_octetstring(Some(2), Some(2), false, &[0xAB, 0xCD])
+        });
+        assert_eq!(&[0b1101_0101, 0b1110_0110, 0b1000_0000], &bytes[..]);
+        let mut reader = Aligned(Bits::from(&bytes[..]));
+        assert!(reader.read_boolean().unwrap());
+        assert_eq!(
+            vec![0xAB, 0xCD],
+            reader.read_octetstring(Some(2), Some(2), false).unwrap()
+        );
+    }
+
+    #[test]
+    fn octetstring_constrained_length_then_aligned_content() {
+        let bytes = written(|b| {
+            b.0.write_bit(true)?;
+            b.write_octetstring(Some(1), Some(4), false, &[0xAB, 0xCD])
+        });
+        assert_eq!(&[0b1010_0000, 0xAB, 0xCD], &bytes[..]);
+        let mut reader = Aligned(Bits::from(&bytes[..]));
+        assert!(reader.read_boolean().unwrap());
+        assert_eq!(
+            vec![0xAB, 0xCD],
+            reader.read_octetstring(Some(1), Some(4), false).unwrap()
+        );
+    }
+
+    #[test]
+    fn octetstring_fragmented() {
+        let src = (0..LENGTH_64K + 500).map(|v| v as u8).collect::<Vec<u8>>();
+        let bytes = written(|b| b.write_octetstring(None, None, false, &src[..]));
+        // 4x 16K fragment, then the 500 bytes remaining
+        assert_eq!(0b1100_0100, bytes[0]);
+        assert_eq!(
+            &[0b1000_0001, 0b1111_0100],
+            &bytes[1 + 65536..1 + 65536 + 2]
+        );
+        assert_eq!(1 + 65536 + 2 + 500, bytes.len());
+        let mut reader = Aligned(Bits::from(&bytes[..]));
+        assert_eq!(src, reader.read_octetstring(None, None, false).unwrap());
+    }
+
+    #[test]
+    fn octetstring_fragmented_with_trailing_empty_fragment() {
+        let src = vec![0x42_u8; LENGTH_16K as usize];
+        let bytes = written(|b| b.write_octetstring(None, None, false, &src[..]));
+        assert_eq!(0b1100_0001, bytes[0]);
+        assert_eq!(0x00, bytes[bytes.len() - 1]);
+        assert_eq!(1 + LENGTH_16K as usize + 1, bytes.len());
+        let mut reader = Aligned(Bits::from(&bytes[..]));
+        assert_eq!(src, reader.read_octetstring(None, None, false).unwrap());
+    }
+
+    #[test]
+    fn bitstring_fixed_size_small_is_not_aligned() {
+        let bytes = written(|b| {
+            b.0.write_bit(true)?;
+            b.write_bitstring(Some(12), Some(12), false, &[0xFF, 0xF0], 0, 12)
+        });
+        assert_eq!(&[0b1111_1111, 0b1111_1000], &bytes[..]);
+        let mut reader = Aligned(Bits::from(&bytes[..]));
+        assert!(reader.read_boolean().unwrap());
+        assert_eq!(
+            (vec![0xFF, 0xF0], 12),
+            reader.read_bitstring(Some(12), Some(12), false).unwrap()
+        );
+    }
+
+    #[test]
+    fn bitstring_unconstrained_is_aligned() {
+        let bytes = written(|b| {
+            b.0.write_bit(true)?;
+            b.write_bitstring(None, None, false, &[0b1010_0000], 0, 3)
+        });
+        assert_eq!(&[0b1000_0000, 0x03, 0b1010_0000], &bytes[..]);
+        let mut reader = Aligned(Bits::from(&bytes[..]));
+        assert!(reader.read_boolean().unwrap());
+        assert_eq!(
+            (vec![0b1010_0000], 3),
+            reader.read_bitstring(None, None, false).unwrap()
+        );
+    }
+
+    #[test]
+    fn length_determinant_two_octets() {
+        let bytes = written(|b| b.write_length_determinant(None, None, 300));
+        assert_eq!(&[0b1000_0001, 0b0010_1100], &bytes[..]);
+        let mut reader = Aligned(Bits::from(&bytes[..]));
+        assert_eq!(300, reader.read_length_determinant(None, None).unwrap());
+    }
+}
@@ -0,0 +1,144 @@
+//! Permitted alphabets of known-multiplier character string types, see ITU-T X.691 |
+//! ISO/IEC 8825-2:2015, chapter 30.5
+
+use crate::io::per::err::Error;
+
+/// The effective permitted alphabet of a known-multiplier character string type, given as sorted
+/// and inclusive ranges of character values. Each character is encoded with [`Alphabet::bits`]
+/// bits, either as its value or - if the largest value does not fit - as its index within the
+/// alphabet (ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 30.5.4).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Alphabet<'a> {
+    ranges: &'a [(u32, u32)],
+    bits: usize,
+    indexed: bool,
+}
+
+impl<'a> Alphabet<'a> {
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 30.5.3: the UNALIGNED variant uses the
+    /// smallest number of bits that can represent all characters, the ALIGNED variant rounds
+    /// this up to the next power of two. An empty alphabet permits no character at all and is
+    /// rejected.
+    pub fn new(ranges: &'a [(u32, u32)], aligned: bool) -> Result<Self, Error> {
+        let size = ranges
+            .iter()
+            .map(|(min, max)| u64::from(max - min) + 1)
+            .sum::<u64>();
+        if size == 0 {
+            return Err(Error::EmptyPermittedAlphabet);
+        }
+        let mut bits = (64 - (size - 1).leading_zeros()) as usize;
+        if aligned && bits > 0 {
+            bits = bits.next_power_of_two();
+        }
+        let largest = ranges.last().map(|(_, max)| u64::from(*max)).unwrap_or(0);
+        Ok(Self {
+            ranges,
+            bits,
+            indexed: bits < 32 && largest > (1_u64 << bits) - 1,
+        })
+    }
+
+    /// The number of bits per character
+    #[inline]
+    pub const fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// The value or index to encode for the given character or `None` if the character is not
+    /// part of the alphabet
+    pub fn encode(&self, character: char) -> Option<u32> {
+        let value = u32::from(character);
+        let mut index = 0_u32;
+        for (min, max) in self.ranges {
+            if (*min..=*max).contains(&value) {
+                return Some(if self.indexed {
+                    index + (value - min)
+                } else {
+                    value
+                });
+            }
+            index = index.wrapping_add(max - min).wrapping_add(1);
+        }
+        None
+    }
+
+    /// The character for the given encoded value or index or `None` if there is no such
+    /// character in the alphabet
+    pub fn decode(&self, encoded: u32) -> Option<char> {
+        let value = if self.indexed {
+            let mut index = encoded;
+            self.ranges.iter().find_map(|(min, max)| {
+                if index <= max - min {
+                    Some(min + index)
+                } else {
+                    index -= max - min + 1;
+                    None
+                }
+            })?
+        } else if self
+            .ranges
+            .iter()
+            .any(|(min, max)| (*min..=*max).contains(&encoded))
+        {
+            encoded
+        } else {
+            return None;
+        };
+        char::from_u32(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use asn1rs_model::model::Charset;
+
+    #[test]
+    fn test_bits_per_character() {
+        let bits =
+            |charset: Charset, aligned| Alphabet::new(charset.alphabet(), aligned).unwrap().bits();
+        assert_eq!(4, bits(Charset::Numeric, false));
+        assert_eq!(4, bits(Charset::Numeric, true));
+        assert_eq!(7, bits(Charset::Printable, false));
+        assert_eq!(8, bits(Charset::Printable, true));
+        assert_eq!(7, bits(Charset::Visible, false));
+        assert_eq!(8, bits(Charset::Visible, true));
+        assert_eq!(7, bits(Charset::Ia5, false));
+        assert_eq!(8, bits(Charset::Ia5, true));
+        assert_eq!(16, bits(Charset::Bmp, false));
+        assert_eq!(16, bits(Charset::Bmp, true));
+        assert_eq!(32, bits(Charset::Universal, false));
+        assert_eq!(32, bits(Charset::Universal, true));
+    }
+
+    #[test]
+    fn test_empty_alphabet_is_rejected() {
+        assert_eq!(
+            Err(Error::EmptyPermittedAlphabet),
+            Alphabet::new(&[], false)
+        );
+        assert_eq!(Err(Error::EmptyPermittedAlphabet), Alphabet::new(&[], true));
+    }
+
+    #[test]
+    fn test_numeric_string_is_indexed() {
+        let alphabet = Alphabet::new(Charset::Numeric.alphabet(), false).unwrap();
+        assert_eq!(Some(0), alphabet.encode(' '));
+        assert_eq!(Some(1), alphabet.encode('0'));
+        assert_eq!(Some(10), alphabet.encode('9'));
+        assert_eq!(None, alphabet.encode('a'));
+        assert_eq!(Some(' '), alphabet.decode(0));
+        assert_eq!(Some('9'), alphabet.decode(10));
+        assert_eq!(None, alphabet.decode(11));
+    }
+
+    #[test]
+    fn test_printable_string_is_not_indexed() {
+        let alphabet = Alphabet::new(Charset::Printable.alphabet(), false).unwrap();
+        assert_eq!(Some(u32::from('A')), alphabet.encode('A'));
+        assert_eq!(None, alphabet.encode('*'));
+        assert_eq!(Some('z'), alphabet.decode(u32::from('z')));
+        assert_eq!(None, alphabet.decode(u32::from('!')));
+    }
+}
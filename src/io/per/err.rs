@@ -1,20 +1,45 @@
+use crate::model::{Charset, TimeType};
+use crate::syn::components::Violation;
+
 #[derive(Debug, PartialOrd, PartialEq)]
 pub enum Error {
     InvalidUtf8String,
     InvalidIa5String,
+    InvalidCharacterString(Charset),
+    InvalidReal,
+    InvalidObjectIdentifier,
+    InvalidTime(TimeType),
     UnsupportedOperation(String),
     InsufficientSpaceInDestinationBuffer,
     InsufficientDataInSourceBuffer,
     InvalidChoiceIndex(u64, u64),
     InvalidExtensionConstellation(bool, bool),
     ValueNotInRange(i64, i64, i64),
+    WideValueNotInRange(String, Option<i128>, Option<i128>),
+    ValueNotPermitted(String),
+    InvalidComponents(Violation),
     ValueExceedsMaxInt,
     ValueIsNegativeButExpectedUnsigned(i64),
     SizeNotInRange(u64, u64, u64),
+    EmptyPermittedAlphabet,
     OptFlagsExhausted,
+    MissingExtensionAddition,
+    UnknownOpenTypeKey(i64),
+    MismatchedOpenTypeKey(i64, i64),
     EndOfStream,
 }
 
+impl Error {
+    /// The error for a known-multiplier character string with characters that are not part of
+    /// its permitted alphabet, of which `IA5String` has a dedicated one
+    pub(crate) fn invalid_character_string(charset: Charset) -> Self {
+        match charset {
+            Charset::Ia5 => Error::InvalidIa5String,
+            charset => Error::InvalidCharacterString(charset),
+        }
+    }
+}
+
 impl std::fmt::Display for Error {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -24,6 +49,21 @@ impl std::fmt::Display for Error {
             Error::InvalidIa5String => {
                 write!(f, "The underlying dataset is not a valid IA5-String")
             }
+            Error::InvalidCharacterString(charset) => {
+                write!(
+                    f,
+                    "The underlying dataset is not a valid {:?}String",
+                    charset
+                )
+            }
+            Error::InvalidReal => write!(f, "The underlying dataset is not a valid REAL"),
+            Error::InvalidObjectIdentifier => write!(
+                f,
+                "The underlying dataset is not a valid OBJECT IDENTIFIER or RELATIVE-OID"
+            ),
+            Error::InvalidTime(time) => {
+                write!(f, "The underlying dataset is not a valid {}", time.name())
+            }
             Error::UnsupportedOperation(o) => write!(f, "The operation is not supported: {}", o),
             Error::InsufficientSpaceInDestinationBuffer => write!(
                 f,
@@ -48,6 +88,19 @@ impl std::fmt::Display for Error {
                 "The value {} is not within the inclusive range of {} and {}",
                 value, min, max
             ),
+            Error::WideValueNotInRange(value, min, max) => write!(
+                f,
+                "The value {} is not within the inclusive range of {} and {}",
+                value,
+                min.map_or_else(|| "MIN".to_string(), |min| min.to_string()),
+                max.map_or_else(|| "MAX".to_string(), |max| max.to_string()),
+            ),
+            Error::ValueNotPermitted(value) => {
+                write!(f, "The value {} is not permitted by the constraint", value)
+            }
+            Error::InvalidComponents(violation) => {
+                write!(f, "The inner subtype constraint is violated: {}", violation)
+            }
             Error::ValueExceedsMaxInt => {
                 write!(f, "The value exceeds the maximum supported integer size",)
             }
@@ -61,7 +114,24 @@ impl std::fmt::Display for Error {
                 "The size {} is not within the inclusive range of {} and {}",
                 size, min, max
             ),
+            Error::EmptyPermittedAlphabet => {
+                write!(f, "The permitted alphabet does not contain any character")
+            }
             Error::OptFlagsExhausted => write!(f, "All optional flags have already been exhausted"),
+            Error::MissingExtensionAddition => write!(
+                f,
+                "The extension addition is not present, but it is not optional"
+            ),
+            Error::UnknownOpenTypeKey(key) => write!(
+                f,
+                "The key {} of the open type is unknown to its object set",
+                key
+            ),
+            Error::MismatchedOpenTypeKey(key, value_key) => write!(
+                f,
+                "The key {} does not match the key {} of the value of the open type",
+                key, value_key
+            ),
             Error::EndOfStream => write!(
                 f,
                 "Can no longer read or write any bytes from the underlying dataset"
@@ -3,8 +3,12 @@
 //! The idea is to provide all building blocks to composite the more complex types on top of the
 //! traits without caring about the representation being ALIGNED or UNALIGNED.
 
+pub mod aligned;
+pub mod alphabet;
 pub mod err;
+pub mod time;
 pub mod unaligned;
+pub mod wide;
 
 pub use err::Error;
 
@@ -26,6 +30,11 @@ pub trait PackedRead {
         upper_bound: i64,
     ) -> Result<i64, Error>;
 
+    /// According to ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.5, the offset of a constrained
+    /// whole number to its lower-bound, where `range` - the difference between the upper- and the
+    /// lower-bound - might exceed 64 bits
+    fn read_constrained_wide_offset(&mut self, range: u128) -> Result<u128, Error>;
+
     /// According to ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 3.7.17, the length determinant is
     /// a number used to count bits, octets (bytes), characters or components
     fn read_length_determinant(
@@ -97,6 +106,11 @@ pub trait PackedWrite {
         value: i64,
     ) -> Result<(), Error>;
 
+    /// According to ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.5, the offset of a constrained
+    /// whole number to its lower-bound, where `range` - the difference between the upper- and the
+    /// lower-bound - might exceed 64 bits
+    fn write_constrained_wide_offset(&mut self, range: u128, offset: u128) -> Result<(), Error>;
+
     /// According to ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 3.7.17, the length determinant is
     /// a number used to count bits, octets (bytes), characters or components
     fn write_length_determinant(
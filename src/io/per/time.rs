@@ -0,0 +1,190 @@
+//! The encoding of `DATE`, `TIME-OF-DAY`, `DATE-TIME` and `DURATION` values as of ITU-T X.691 |
+//! ISO/IEC 8825-2:2015, chapter 32, where each value is encoded as a structure of integers
+//! instead of its character representation.
+
+use crate::io::per::{Error, PackedRead, PackedWrite};
+use crate::io::time::{Date, DateTime, Duration, TimeOfDay};
+use crate::model::TimeType;
+use std::convert::TryFrom;
+
+const YEAR_IMMEDIATE: (i64, i64) = (2005, 2020);
+const YEAR_NEAR_FUTURE: (i64, i64) = (2021, 2276);
+const YEAR_NEAR_PAST: (i64, i64) = (1749, 2004);
+
+/// `YEAR-ENCODING ::= CHOICE { immediate INTEGER (2005..2020), near-future INTEGER (2021..2276),
+/// near-past INTEGER (1749..2004), remainder INTEGER (MIN..1748 | 2277..MAX) }`
+fn write_year<W: PackedWrite>(writer: &mut W, year: i64) -> Result<(), Error> {
+    let ranges = [YEAR_IMMEDIATE, YEAR_NEAR_FUTURE, YEAR_NEAR_PAST];
+    match ranges
+        .iter()
+        .position(|(lower, upper)| (*lower..=*upper).contains(&year))
+    {
+        Some(index) => {
+            let (lower, upper) = ranges[index];
+            writer.write_choice_index(4, false, index as u64)?;
+            writer.write_constrained_whole_number(lower, upper, year)
+        }
+        None => {
+            writer.write_choice_index(4, false, 3)?;
+            writer.write_unconstrained_whole_number(year)
+        }
+    }
+}
+
+fn read_year<R: PackedRead>(reader: &mut R) -> Result<i64, Error> {
+    match reader.read_choice_index(4, false)? {
+        0 => reader.read_constrained_whole_number(YEAR_IMMEDIATE.0, YEAR_IMMEDIATE.1),
+        1 => reader.read_constrained_whole_number(YEAR_NEAR_FUTURE.0, YEAR_NEAR_FUTURE.1),
+        2 => reader.read_constrained_whole_number(YEAR_NEAR_PAST.0, YEAR_NEAR_PAST.1),
+        _ => reader.read_unconstrained_whole_number(),
+    }
+}
+
+/// `DATE-ENCODING ::= SEQUENCE { year YEAR-ENCODING, month INTEGER (1..12),
+/// day INTEGER (1..31) }`
+pub fn write_date<W: PackedWrite>(writer: &mut W, date: &Date) -> Result<(), Error> {
+    write_year(writer, i64::from(date.year))?;
+    writer.write_constrained_whole_number(1, 12, i64::from(date.month))?;
+    writer.write_constrained_whole_number(1, 31, i64::from(date.day))
+}
+
+pub fn read_date<R: PackedRead>(reader: &mut R) -> Result<Date, Error> {
+    let date = Date {
+        year: u16::try_from(read_year(reader)?).map_err(|_| Error::InvalidTime(TimeType::Date))?,
+        month: reader.read_constrained_whole_number(1, 12)? as u8,
+        day: reader.read_constrained_whole_number(1, 31)? as u8,
+    };
+    if date.is_valid() {
+        Ok(date)
+    } else {
+        Err(Error::InvalidTime(TimeType::Date))
+    }
+}
+
+/// `HMS-ENCODING ::= SEQUENCE { hours INTEGER (0..24), minutes INTEGER (0..59),
+/// seconds INTEGER (0..60) }`
+pub fn write_time_of_day<W: PackedWrite>(writer: &mut W, time: &TimeOfDay) -> Result<(), Error> {
+    writer.write_constrained_whole_number(0, 24, i64::from(time.hours))?;
+    writer.write_constrained_whole_number(0, 59, i64::from(time.minutes))?;
+    writer.write_constrained_whole_number(0, 60, i64::from(time.seconds))
+}
+
+pub fn read_time_of_day<R: PackedRead>(reader: &mut R) -> Result<TimeOfDay, Error> {
+    let time = TimeOfDay {
+        hours: reader.read_constrained_whole_number(0, 24)? as u8,
+        minutes: reader.read_constrained_whole_number(0, 59)? as u8,
+        seconds: reader.read_constrained_whole_number(0, 60)? as u8,
+    };
+    if time.is_valid() {
+        Ok(time)
+    } else {
+        Err(Error::InvalidTime(TimeType::TimeOfDay))
+    }
+}
+
+/// `DATE-TIME-ENCODING ::= SEQUENCE { date DATE-ENCODING, time HMS-ENCODING }`
+pub fn write_date_time<W: PackedWrite>(writer: &mut W, date_time: &DateTime) -> Result<(), Error> {
+    write_date(writer, &date_time.date)?;
+    write_time_of_day(writer, &date_time.time)
+}
+
+pub fn read_date_time<R: PackedRead>(reader: &mut R) -> Result<DateTime, Error> {
+    Ok(DateTime {
+        date: read_date(reader)?,
+        time: read_time_of_day(reader)?,
+    })
+}
+
+/// `DURATION-INTERVAL-ENCODING ::= SEQUENCE { years INTEGER (0..MAX) OPTIONAL, months ...,
+/// weeks ..., days ..., hours ..., minutes ..., seconds ..., fractional-part SEQUENCE {
+/// number-of-digits INTEGER (1..MAX), fractional-value INTEGER (0..MAX) } OPTIONAL }`
+pub fn write_duration<W: PackedWrite>(writer: &mut W, duration: &Duration) -> Result<(), Error> {
+    let components = duration_components(duration);
+    for component in components.iter() {
+        writer.write_boolean(component.is_some())?;
+    }
+    writer.write_boolean(duration.fraction.is_some())?;
+    for value in components.iter().flatten() {
+        writer.write_semi_constrained_whole_number(0, to_i64(*value)?)?;
+    }
+    if let Some((digits, value)) = duration.fraction {
+        writer.write_semi_constrained_whole_number(1, to_i64(digits)?)?;
+        writer.write_semi_constrained_whole_number(0, to_i64(value)?)?;
+    }
+    Ok(())
+}
+
+pub fn read_duration<R: PackedRead>(reader: &mut R) -> Result<Duration, Error> {
+    let mut presence = [false; 8];
+    for present in presence.iter_mut() {
+        *present = reader.read_boolean()?;
+    }
+    let mut components = [None; 7];
+    for (component, present) in components.iter_mut().zip(presence.iter()) {
+        if *present {
+            *component = Some(reader.read_semi_constrained_whole_number(0)? as u64);
+        }
+    }
+    let fraction = if presence[7] {
+        let digits = reader.read_semi_constrained_whole_number(1)? as u64;
+        Some((digits, reader.read_semi_constrained_whole_number(0)? as u64))
+    } else {
+        None
+    };
+    let [years, months, weeks, days, hours, minutes, seconds] = components;
+    let duration = Duration {
+        years,
+        months,
+        weeks,
+        days,
+        hours,
+        minutes,
+        seconds,
+        fraction,
+    };
+    // the encoding might describe values without a value notation, like `P1W1D`
+    Duration::parse(&duration.to_string())
+        .filter(|parsed| *parsed == duration)
+        .ok_or(Error::InvalidTime(TimeType::Duration))
+}
+
+fn duration_components(duration: &Duration) -> [Option<u64>; 7] {
+    [
+        duration.years,
+        duration.months,
+        duration.weeks,
+        duration.days,
+        duration.hours,
+        duration.minutes,
+        duration.seconds,
+    ]
+}
+
+fn to_i64(value: u64) -> Result<i64, Error> {
+    i64::try_from(value).map_err(|_| Error::ValueExceedsMaxInt)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::per::unaligned::buffer::BitBuffer;
+
+    #[test]
+    fn test_date_near_past_year() {
+        let mut buffer = BitBuffer::default();
+        let date = Date::parse("1999-12-31").unwrap();
+        write_date(&mut buffer, &date).unwrap();
+        // choice index 2, 1999 - 1749 in 8 bits, 11 in 4 bits, 30 in 5 bits
+        assert_eq!(2 + 8 + 4 + 5, buffer.bit_len());
+        assert_eq!(&[0b1011_1110, 0b1010_1111, 0b1100_0000], buffer.content());
+        assert_eq!(Ok(date), read_date(&mut buffer));
+    }
+
+    #[test]
+    fn test_duration_with_fraction() {
+        let mut buffer = BitBuffer::default();
+        let duration = Duration::parse("P1DT2.5H").unwrap();
+        write_duration(&mut buffer, &duration).unwrap();
+        assert_eq!(Ok(duration), read_duration(&mut buffer));
+    }
+}
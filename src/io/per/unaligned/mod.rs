@@ -146,6 +146,16 @@ impl<T: BitRead> PackedRead for T {
         }
     }
 
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.5
+    #[inline]
+    fn read_constrained_wide_offset(&mut self, range: u128) -> Result<u128, Error> {
+        let mut bytes = [0u8; std::mem::size_of::<u128>()];
+        if range > 0 {
+            self.read_bits_with_offset(&mut bytes, range.leading_zeros() as usize)?;
+        }
+        Ok(u128::from_be_bytes(bytes))
+    }
+
     /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.6
     #[inline]
     fn read_normally_small_non_negative_whole_number(&mut self) -> Result<u64, Error> {
@@ -462,6 +472,16 @@ impl<T: BitWrite> PackedWrite for T {
         }
     }
 
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.5
+    #[inline]
+    fn write_constrained_wide_offset(&mut self, range: u128, offset: u128) -> Result<(), Error> {
+        if range > 0 {
+            self.write_bits_with_offset(&offset.to_be_bytes(), range.leading_zeros() as usize)
+        } else {
+            Ok(())
+        }
+    }
+
     /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.6
     #[inline]
     fn write_normally_small_non_negative_whole_number(&mut self, value: u64) -> Result<(), Error> {
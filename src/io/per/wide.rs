@@ -0,0 +1,169 @@
+//! The encoding of `INTEGER`s whose bounds exceed the 64 bit types as of ITU-T X.691 |
+//! ISO/IEC 8825-2:2015, chapter 13. The encoding is identical to the one of the 64 bit types
+//! for all values that can also be represented by them.
+
+use crate::io::per::{Error, PackedRead, PackedWrite};
+use crate::syn::numbers::{from_unsigned_octets, unsigned_octets, WideNumber};
+
+pub fn write_wide_whole_number<W: PackedWrite, T: WideNumber>(
+    writer: &mut W,
+    lower_bound: Option<i128>,
+    upper_bound: Option<i128>,
+    extensible: bool,
+    value: &T,
+) -> Result<(), Error> {
+    let within = value.is_within(lower_bound, upper_bound);
+    let not_in_range = || Error::WideValueNotInRange(value.to_string(), lower_bound, upper_bound);
+
+    if extensible {
+        if !within {
+            return write_wide_extension_value(writer, value);
+        }
+        writer.write_boolean(false)?;
+    } else if !within {
+        return Err(not_in_range());
+    }
+
+    match (lower_bound, upper_bound) {
+        (Some(lower), Some(upper)) => {
+            // 13.2.2
+            let offset = value.offset_from(lower).ok_or_else(not_in_range)?;
+            writer.write_constrained_wide_offset(upper.wrapping_sub(lower) as u128, offset)
+        }
+        (Some(lower), None) => {
+            // 13.2.3
+            let offset = value.offset_from(lower).ok_or_else(not_in_range)?;
+            writer.write_octetstring(None, None, false, &unsigned_octets(offset))
+        }
+        (None, _) => {
+            // 13.2.4
+            writer.write_octetstring(None, None, false, &value.to_signed_octets())
+        }
+    }
+}
+
+/// Writes the value of an extensible constraint as not being part of its extension root (13.1)
+pub fn write_wide_extension_value<W: PackedWrite, T: WideNumber>(
+    writer: &mut W,
+    value: &T,
+) -> Result<(), Error> {
+    writer.write_boolean(true)?;
+    writer.write_octetstring(None, None, false, &value.to_signed_octets())
+}
+
+pub fn read_wide_whole_number<R: PackedRead, T: WideNumber>(
+    reader: &mut R,
+    lower_bound: Option<i128>,
+    upper_bound: Option<i128>,
+    extensible: bool,
+) -> Result<T, Error> {
+    if extensible && reader.read_boolean()? {
+        let octets = reader.read_octetstring(None, None, false)?;
+        return T::from_signed_octets(&octets).ok_or(Error::ValueExceedsMaxInt);
+    }
+
+    let value = match (lower_bound, upper_bound) {
+        (Some(lower), Some(upper)) => {
+            let offset = reader.read_constrained_wide_offset(upper.wrapping_sub(lower) as u128)?;
+            T::from_offset(lower, offset)
+        }
+        (Some(lower), None) => {
+            let octets = reader.read_octetstring(None, None, false)?;
+            let offset = from_unsigned_octets(&octets).ok_or(Error::ValueExceedsMaxInt)?;
+            T::from_offset(lower, offset)
+        }
+        (None, _) => T::from_signed_octets(&reader.read_octetstring(None, None, false)?),
+    }
+    .ok_or(Error::ValueExceedsMaxInt)?;
+
+    if value.is_within(lower_bound, upper_bound) {
+        Ok(value)
+    } else {
+        Err(Error::WideValueNotInRange(
+            value.to_string(),
+            lower_bound,
+            upper_bound,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::per::aligned::Aligned;
+    use crate::io::per::unaligned::buffer::BitBuffer;
+    use crate::io::per::unaligned::buffer::Bits;
+
+    #[test]
+    fn test_constrained_beyond_64_bits_unaligned() {
+        let mut buffer = BitBuffer::default();
+        let max = 1_i128 << 100;
+        write_wide_whole_number(&mut buffer, Some(0), Some(max), false, &max).unwrap();
+        // 101 bits with only the most significant bit set
+        assert_eq!(101, buffer.bit_len());
+        assert_eq!(0x80, buffer.content()[0]);
+        assert_eq!(
+            Ok(max),
+            read_wide_whole_number::<_, i128>(&mut buffer, Some(0), Some(max), false)
+        );
+    }
+
+    #[test]
+    fn test_constrained_beyond_64_bits_aligned() {
+        let mut buffer = Aligned(BitBuffer::default());
+        let max = 1_i128 << 100;
+        write_wide_whole_number(&mut buffer, Some(0), Some(max), false, &5_i128).unwrap();
+        // the number of octets (1..=13) in 4 bits, padding and the single octet
+        assert_eq!(&[0x00, 0x05], buffer.0.content());
+        let bytes: Vec<u8> = buffer.into_inner().into();
+        let mut buffer = Aligned(Bits::from(&bytes[..]));
+        assert_eq!(
+            Ok(5),
+            read_wide_whole_number::<_, i128>(&mut buffer, Some(0), Some(max), false)
+        );
+    }
+
+    #[test]
+    fn test_semi_constrained_and_unconstrained() {
+        let mut buffer = BitBuffer::default();
+        write_wide_whole_number(&mut buffer, Some(1), None, false, &u128::MAX).unwrap();
+        write_wide_whole_number(&mut buffer, None, None, false, &i128::MIN).unwrap();
+        assert_eq!(
+            Ok(u128::MAX),
+            read_wide_whole_number::<_, u128>(&mut buffer, Some(1), None, false)
+        );
+        assert_eq!(
+            Ok(i128::MIN),
+            read_wide_whole_number::<_, i128>(&mut buffer, None, None, false)
+        );
+    }
+
+    #[test]
+    fn test_values_out_of_range() {
+        let mut buffer = BitBuffer::default();
+        assert_eq!(
+            Err(Error::WideValueNotInRange("-1".to_string(), Some(0), None)),
+            write_wide_whole_number(&mut buffer, Some(0), None, false, &-1_i128)
+        );
+        write_wide_whole_number(&mut buffer, Some(0), Some(1 << 70), true, &-1_i128).unwrap();
+        assert_eq!(
+            Ok(-1),
+            read_wide_whole_number::<_, i128>(&mut buffer, Some(0), Some(1 << 70), true)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "bigint")]
+    fn test_unconstrained_big_integer() {
+        use crate::syn::numbers::BigInt;
+        let value = -(BigInt::from(u128::MAX) << 8_u32);
+        let mut buffer = BitBuffer::default();
+        write_wide_whole_number(&mut buffer, None, None, false, &value).unwrap();
+        // the length of the 18 octets and the octets
+        assert_eq!(19 * 8, buffer.bit_len());
+        assert_eq!(
+            Ok(value),
+            read_wide_whole_number::<_, BigInt>(&mut buffer, None, None, false)
+        );
+    }
+}
@@ -152,6 +152,13 @@ pub trait Writer {
 
     fn write_string(&mut self, value: &str) -> Result<(), Error>;
 
+    fn write_double(&mut self, value: f64) -> Result<(), Error>;
+
+    /// `NULL` is written as an empty message (`google.protobuf.Empty`)
+    fn write_empty(&mut self, _value: ()) -> Result<(), Error> {
+        self.write_bytes(&[])
+    }
+
     fn write_tagged_bool(&mut self, field: u32, value: bool) -> Result<(), Error> {
         self.write_tag(field, Format::VarInt)?;
         self.write_bool(value)
@@ -197,6 +204,16 @@ pub trait Writer {
         self.write_string(value)
     }
 
+    fn write_tagged_double(&mut self, field: u32, value: f64) -> Result<(), Error> {
+        self.write_tag(field, Format::Fixed64)?;
+        self.write_double(value)
+    }
+
+    fn write_tagged_empty(&mut self, field: u32, value: ()) -> Result<(), Error> {
+        self.write_tag(field, Format::LengthDelimited)?;
+        self.write_empty(value)
+    }
+
     fn write_tagged_varint(&mut self, field: u32, value: u64) -> Result<(), Error> {
         self.write_tag(field, Format::VarInt)?;
         self.write_varint(value)
@@ -232,6 +249,11 @@ impl<W: Write> Writer for W {
         self.write_bytes(value.as_bytes())?;
         Ok(())
     }
+
+    fn write_double(&mut self, value: f64) -> Result<(), Error> {
+        self.write_f64::<E>(value)?;
+        Ok(())
+    }
 }
 
 pub trait Reader {
@@ -285,6 +307,14 @@ pub trait Reader {
     }
 
     fn read_string(&mut self) -> Result<String, Error>;
+
+    fn read_double(&mut self) -> Result<f64, Error>;
+
+    fn read_empty(&mut self) -> Result<(), Error> {
+        // unknown fields of the empty message are ignored
+        let _ = self.read_bytes()?;
+        Ok(())
+    }
 }
 
 impl<R: Read> Reader for R {
@@ -313,6 +343,10 @@ impl<R: Read> Reader for R {
         Ok(self.read_i32::<E>()?)
     }
 
+    fn read_double(&mut self) -> Result<f64, Error> {
+        Ok(self.read_f64::<E>()?)
+    }
+
     fn read_string(&mut self) -> Result<String, Error> {
         let bytes = self.read_bytes()?;
         if let Ok(string) = String::from_utf8(bytes) {
@@ -357,12 +391,25 @@ impl<T: ProtobufEq> ProtobufEq<Vec<T>> for Vec<T> {
         }
     }
 }
+
+impl<T: ProtobufEq> ProtobufEq<Box<T>> for Box<T> {
+    fn protobuf_eq(&self, other: &Box<T>) -> bool {
+        T::protobuf_eq(self, other)
+    }
+}
+
 impl ProtobufEq<BitVec> for BitVec {
     fn protobuf_eq(&self, other: &BitVec) -> bool {
         self.eq(other)
     }
 }
 
+impl ProtobufEq<()> for () {
+    fn protobuf_eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
 impl ProtobufEq<bool> for bool {
     fn protobuf_eq(&self, other: &Self) -> bool {
         self == other
@@ -417,6 +464,12 @@ impl ProtobufEq<i64> for i64 {
     }
 }
 
+impl ProtobufEq<f64> for f64 {
+    fn protobuf_eq(&self, other: &Self) -> bool {
+        self == other
+    }
+}
+
 impl ProtobufEq<String> for String {
     fn protobuf_eq(&self, other: &Self) -> bool {
         self == other
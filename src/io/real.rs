@@ -0,0 +1,196 @@
+//! Content octets of `REAL` values as used by the octet-based encodings and PER, see ITU-T
+//! X.690 | ISO/IEC 8825-1:2015, chapter 8.5 and 11.3, ITU-T X.691 | ISO/IEC 8825-2:2015,
+//! chapter 15 and ITU-T X.696 | ISO/IEC 8825-7:2015, chapter 12
+
+const PLUS_INFINITY: u8 = 0x40;
+const MINUS_INFINITY: u8 = 0x41;
+const NOT_A_NUMBER: u8 = 0x42;
+const MINUS_ZERO: u8 = 0x43;
+
+/// Encodes the value in the canonical binary form with base 2, an odd mantissa and the scaling
+/// factor of zero (CER and DER)
+pub fn to_octets(value: f64) -> Vec<u8> {
+    if value == 0.0 {
+        if value.is_sign_negative() {
+            vec![MINUS_ZERO]
+        } else {
+            Vec::default()
+        }
+    } else if value.is_nan() {
+        vec![NOT_A_NUMBER]
+    } else if value.is_infinite() {
+        vec![if value.is_sign_positive() {
+            PLUS_INFINITY
+        } else {
+            MINUS_INFINITY
+        }]
+    } else {
+        let bits = value.to_bits();
+        let biased_exponent = ((bits >> 52) & 0x7FF) as i64;
+        let fraction = bits & ((1 << 52) - 1);
+        let (mantissa, exponent) = if biased_exponent == 0 {
+            // subnormal
+            (fraction, -1074)
+        } else {
+            (fraction | (1 << 52), biased_exponent - 1075)
+        };
+        let shift = mantissa.trailing_zeros();
+        let mantissa = mantissa >> shift;
+        let exponent = exponent + i64::from(shift);
+
+        let exponent = exponent.to_be_bytes();
+        let exponent = &exponent[leading_redundant_signed_octets(&exponent)..];
+        let mantissa = mantissa.to_be_bytes();
+        let mantissa = &mantissa[mantissa.iter().take_while(|b| **b == 0).count()..];
+
+        let mut octets = Vec::with_capacity(1 + exponent.len() + mantissa.len());
+        octets.push(
+            0x80 | if value.is_sign_negative() { 0x40 } else { 0x00 } | (exponent.len() as u8 - 1),
+        );
+        octets.extend_from_slice(exponent);
+        octets.extend_from_slice(mantissa);
+        octets
+    }
+}
+
+/// Decodes the binary, decimal or special real value form or returns `None` if the octets are
+/// not a valid encoding
+pub fn from_octets(octets: &[u8]) -> Option<f64> {
+    let (first, octets) = match octets.split_first() {
+        None => return Some(0.0),
+        Some(split) => split,
+    };
+    if first & 0x80 != 0 {
+        from_binary_octets(*first, octets)
+    } else if first & 0x40 != 0 {
+        if !octets.is_empty() {
+            return None;
+        }
+        match *first {
+            PLUS_INFINITY => Some(f64::INFINITY),
+            MINUS_INFINITY => Some(f64::NEG_INFINITY),
+            NOT_A_NUMBER => Some(f64::NAN),
+            MINUS_ZERO => Some(-0.0),
+            _ => None,
+        }
+    } else {
+        // ISO 6093 NR1, NR2 or NR3 form
+        match first & 0x3F {
+            0x01..=0x03 => core::str::from_utf8(octets)
+                .ok()?
+                .trim_start_matches(' ')
+                .replace(',', ".")
+                .parse::<f64>()
+                .ok(),
+            _ => None,
+        }
+    }
+}
+
+fn from_binary_octets(first: u8, octets: &[u8]) -> Option<f64> {
+    let base_bits = match (first >> 4) & 0x03 {
+        0 => 1,
+        1 => 3,
+        2 => 4,
+        _ => return None,
+    };
+    let scaling_factor = i64::from((first >> 2) & 0x03);
+    let (exponent_len, octets) = match first & 0x03 {
+        3 => {
+            let (len, octets) = octets.split_first()?;
+            (usize::from(*len), octets)
+        }
+        len => (usize::from(len) + 1, octets),
+    };
+    if exponent_len == 0 || exponent_len > 8 || exponent_len > octets.len() {
+        return None;
+    }
+    let (exponent, mantissa) = octets.split_at(exponent_len);
+    let exponent = exponent
+        .iter()
+        .skip(1)
+        .fold(i64::from(exponent[0] as i8), |value, byte| {
+            (value << 8) | i64::from(*byte)
+        });
+
+    // values beyond these bounds are zero or infinite anyway, but must not overflow
+    let exponent = exponent.clamp(-0x10_0000, 0x10_0000) * base_bits + scaling_factor;
+    let mantissa = mantissa
+        .iter()
+        .fold(0.0, |value, byte| value * 256.0 + f64::from(*byte));
+    let value = scale_by_power_of_two(mantissa, exponent);
+    Some(if first & 0x40 != 0 { -value } else { value })
+}
+
+fn scale_by_power_of_two(mut value: f64, mut exponent: i64) -> f64 {
+    while exponent > 1000 && value.is_finite() {
+        value *= 2_f64.powi(1000);
+        exponent -= 1000;
+    }
+    while exponent < -1000 && value != 0.0 {
+        value *= 2_f64.powi(-1000);
+        exponent += 1000;
+    }
+    value * 2_f64.powi(exponent as i32)
+}
+
+/// The count of leading octets of a big-endian two's complement value that can be omitted
+/// without changing its value
+fn leading_redundant_signed_octets(octets: &[u8]) -> usize {
+    octets
+        .windows(2)
+        .take_while(|pair| {
+            (pair[0] == 0x00 && pair[1] & 0x80 == 0) || (pair[0] == 0xFF && pair[1] & 0x80 != 0)
+        })
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_canonical_binary_encoding() {
+        assert_eq!(Vec::<u8>::new(), to_octets(0.0));
+        assert_eq!(vec![0x80, 0x00, 0x01], to_octets(1.0));
+        assert_eq!(vec![0xC0, 0xFF, 0x03], to_octets(-1.5));
+        assert_eq!(vec![0x80, 0x02, 0x01], to_octets(4.0));
+        assert_eq!(
+            vec![0x81, 0xFB, 0xCE, 0x01],
+            to_octets(f64::from_bits(1)) // the smallest subnormal
+        );
+    }
+
+    #[test]
+    fn test_special_values() {
+        assert_eq!(vec![MINUS_ZERO], to_octets(-0.0));
+        assert_eq!(vec![PLUS_INFINITY], to_octets(f64::INFINITY));
+        assert_eq!(vec![MINUS_INFINITY], to_octets(f64::NEG_INFINITY));
+        assert_eq!(vec![NOT_A_NUMBER], to_octets(f64::NAN));
+        assert!(from_octets(&[NOT_A_NUMBER]).unwrap().is_nan());
+        assert!(from_octets(&[MINUS_ZERO]).unwrap().is_sign_negative());
+        assert_eq!(None, from_octets(&[0x44]));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for value in &[
+            0.1,
+            -2.5e-300,
+            1.7976931348623157e308,
+            f64::MIN_POSITIVE,
+            123_456.789,
+        ] {
+            assert_eq!(Some(*value), from_octets(&to_octets(*value)));
+        }
+    }
+
+    #[test]
+    fn test_non_canonical_forms() {
+        // base 16, scaling factor 1, exponent 1 and mantissa 3: 3 * 2^1 * 16^1
+        assert_eq!(Some(96.0), from_octets(&[0xA4, 0x01, 0x03]));
+        // NR3 form
+        assert_eq!(Some(-1250.0), from_octets(b"\x03-1.25E3"));
+        assert_eq!(None, from_octets(b"\x04-1.25E3"));
+    }
+}
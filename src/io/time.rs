@@ -0,0 +1,681 @@
+//! Values of the time types, which are represented by their value notation (ITU-T X.680 |
+//! ISO/IEC 8824-1:2015, chapter 38, 46 and 47). The components of `DATE`, `TIME-OF-DAY`,
+//! `DATE-TIME` and `DURATION` values are needed for their encoding in PER, see ITU-T X.691 |
+//! ISO/IEC 8825-2:2015, chapter 32. With the feature `chrono`, the values can be converted from
+//! and to the types of the `chrono` crate.
+
+use asn1rs_model::model::TimeType;
+use std::convert::TryFrom;
+use std::fmt::{Display, Formatter};
+
+/// Whether the given text is a valid value of the time type
+pub fn is_valid(time: TimeType, value: &str) -> bool {
+    match time {
+        TimeType::UtcTime => Moment::parse_utc_time(value).is_some(),
+        TimeType::GeneralizedTime => Moment::parse_generalized_time(value).is_some(),
+        TimeType::Date => Date::parse(value).is_some(),
+        TimeType::TimeOfDay => TimeOfDay::parse(value).is_some(),
+        TimeType::DateTime => DateTime::parse(value).is_some(),
+        TimeType::Duration => Duration::parse(value).is_some(),
+    }
+}
+
+/// A `DATE` value in the form of `YYYY-MM-DD`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Date {
+    pub year: u16,
+    pub month: u8,
+    pub day: u8,
+}
+
+impl Date {
+    pub fn parse(value: &str) -> Option<Self> {
+        let (date, rest) = Self::parse_prefix(value)?;
+        if rest.is_empty() {
+            Some(date)
+        } else {
+            None
+        }
+    }
+
+    fn parse_prefix(value: &str) -> Option<(Self, &str)> {
+        let (year, rest) = number(value, 4)?;
+        let (month, rest) = number(rest.strip_prefix('-')?, 2)?;
+        let (day, rest) = number(rest.strip_prefix('-')?, 2)?;
+        let date = Date {
+            year: year as u16,
+            month: month as u8,
+            day: day as u8,
+        };
+        if date.is_valid() {
+            Some((date, rest))
+        } else {
+            None
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.year <= 9999 && is_valid_day(u32::from(self.year), self.month, self.day)
+    }
+}
+
+impl Display for Date {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// A `TIME-OF-DAY` value in the form of `hh:mm:ss`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeOfDay {
+    pub hours: u8,
+    pub minutes: u8,
+    pub seconds: u8,
+}
+
+impl TimeOfDay {
+    pub fn parse(value: &str) -> Option<Self> {
+        let (hours, rest) = number(value, 2)?;
+        let (minutes, rest) = number(rest.strip_prefix(':')?, 2)?;
+        let (seconds, rest) = number(rest.strip_prefix(':')?, 2)?;
+        let time = TimeOfDay {
+            hours: hours as u8,
+            minutes: minutes as u8,
+            seconds: seconds as u8,
+        };
+        if rest.is_empty() && time.is_valid() {
+            Some(time)
+        } else {
+            None
+        }
+    }
+
+    /// The end of the day `24:00:00` and leap seconds are valid
+    pub fn is_valid(&self) -> bool {
+        is_valid_time(self.hours, self.minutes, self.seconds)
+    }
+}
+
+impl Display for TimeOfDay {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:02}:{:02}:{:02}",
+            self.hours, self.minutes, self.seconds
+        )
+    }
+}
+
+/// A `DATE-TIME` value in the form of `YYYY-MM-DDThh:mm:ss`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DateTime {
+    pub date: Date,
+    pub time: TimeOfDay,
+}
+
+impl DateTime {
+    pub fn parse(value: &str) -> Option<Self> {
+        let (date, rest) = Date::parse_prefix(value)?;
+        Some(DateTime {
+            date,
+            time: TimeOfDay::parse(rest.strip_prefix('T')?)?,
+        })
+    }
+}
+
+impl Display for DateTime {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}T{}", self.date, self.time)
+    }
+}
+
+/// A `DURATION` value in the form of `PnYnMnDTnHnMnS` or `PnW`, of which at least one component
+/// is present and only the last one might have a fractional part
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Duration {
+    pub years: Option<u64>,
+    pub months: Option<u64>,
+    pub weeks: Option<u64>,
+    pub days: Option<u64>,
+    pub hours: Option<u64>,
+    pub minutes: Option<u64>,
+    pub seconds: Option<u64>,
+    /// The number of digits and the value of the fractional part of the last component
+    pub fraction: Option<(u64, u64)>,
+}
+
+impl Duration {
+    pub fn parse(value: &str) -> Option<Self> {
+        let mut rest = value.strip_prefix('P')?;
+        let mut duration = Duration::default();
+        let mut time = false;
+        let mut next_component = 0;
+
+        while !rest.is_empty() {
+            if let Some(remaining) = rest.strip_prefix('T').filter(|_| !time) {
+                rest = remaining;
+                time = true;
+                continue;
+            }
+            let (number, remaining) = unsigned(rest)?;
+            let (fraction, remaining) = match remaining.strip_prefix(&['.', ','][..]) {
+                Some(digits) => {
+                    let (fraction, remaining) = unsigned(digits)?;
+                    let len = (digits.len() - remaining.len()) as u64;
+                    (Some((len, fraction)), remaining)
+                }
+                None => (None, remaining),
+            };
+            let mut chars = remaining.chars();
+            let component = match (time, chars.next()?) {
+                (false, 'Y') => 0,
+                (false, 'M') => 1,
+                (false, 'W') => 2,
+                (false, 'D') => 3,
+                (true, 'H') => 4,
+                (true, 'M') => 5,
+                (true, 'S') => 6,
+                _ => return None,
+            };
+            if component < next_component {
+                return None;
+            }
+            next_component = component + 1;
+            rest = chars.as_str();
+            *duration.component_mut(component) = Some(number);
+            if fraction.is_some() {
+                if !rest.is_empty() {
+                    return None;
+                }
+                duration.fraction = fraction;
+            }
+        }
+
+        let has_date = duration.years.is_some()
+            || duration.months.is_some()
+            || duration.weeks.is_some()
+            || duration.days.is_some();
+        let has_time =
+            duration.hours.is_some() || duration.minutes.is_some() || duration.seconds.is_some();
+        let weeks_only = duration.weeks.is_none()
+            || (duration.years.is_none() && duration.months.is_none() && next_component == 3);
+        if (has_date || has_time) && has_time == time && weeks_only {
+            Some(duration)
+        } else {
+            None
+        }
+    }
+
+    fn component_mut(&mut self, index: usize) -> &mut Option<u64> {
+        match index {
+            0 => &mut self.years,
+            1 => &mut self.months,
+            2 => &mut self.weeks,
+            3 => &mut self.days,
+            4 => &mut self.hours,
+            5 => &mut self.minutes,
+            _ => &mut self.seconds,
+        }
+    }
+}
+
+impl Display for Duration {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let components = [
+            (self.years, 'Y'),
+            (self.months, 'M'),
+            (self.weeks, 'W'),
+            (self.days, 'D'),
+            (self.hours, 'H'),
+            (self.minutes, 'M'),
+            (self.seconds, 'S'),
+        ];
+        let last = components.iter().rposition(|(value, _)| value.is_some());
+        f.write_str("P")?;
+        for (index, (value, designator)) in components.iter().enumerate() {
+            if index == 4 && components[4..].iter().any(|(value, _)| value.is_some()) {
+                f.write_str("T")?;
+            }
+            if let Some(value) = value {
+                write!(f, "{}", value)?;
+                if let Some((digits, fraction)) = self.fraction.filter(|_| Some(index) == last) {
+                    write!(f, ".{:0width$}", fraction, width = digits as usize)?;
+                }
+                write!(f, "{}", designator)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The components of `UTCTime` and `GeneralizedTime` values
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Moment {
+    year: u32,
+    month: u8,
+    day: u8,
+    hours: u8,
+    minutes: u8,
+    seconds: u8,
+    nanos: u32,
+    /// The difference to UTC in minutes, unless the time is local
+    offset: Option<i32>,
+}
+
+impl Moment {
+    /// `YYMMDDhhmm[ss]` followed by `Z` or `+hhmm` or `-hhmm`, where years below 50 are in the
+    /// 21st century (as of RFC 5280, 4.1.2.5.1)
+    fn parse_utc_time(value: &str) -> Option<Self> {
+        let (year, rest) = number(value, 2)?;
+        let year = if year < 50 { 2000 + year } else { 1900 + year };
+        let (month, rest) = number(rest, 2)?;
+        let (day, rest) = number(rest, 2)?;
+        let (hours, rest) = number(rest, 2)?;
+        let (minutes, rest) = number(rest, 2)?;
+        let (seconds, rest) = number(rest, 2).unwrap_or((0, rest));
+        let (offset, rest) = match rest.strip_prefix('Z') {
+            Some(rest) => (0, rest),
+            None => time_difference(rest, true)?,
+        };
+        Self::new(
+            year,
+            month,
+            day,
+            (hours, minutes, seconds, 0),
+            Some(offset),
+            rest,
+        )
+    }
+
+    /// `YYYYMMDDhh[mm[ss]][.fff]`, optionally followed by `Z` or `+hh[mm]` or `-hh[mm]`
+    fn parse_generalized_time(value: &str) -> Option<Self> {
+        let (year, rest) = number(value, 4)?;
+        let (month, rest) = number(rest, 2)?;
+        let (day, rest) = number(rest, 2)?;
+        let (hours, rest) = number(rest, 2)?;
+        let (minutes, rest, unit) = match number(rest, 2) {
+            Some((minutes, rest)) => (Some(minutes), rest, 60),
+            None => (None, rest, 3600),
+        };
+        let (seconds, rest, unit) = match minutes.and_then(|_| number(rest, 2)) {
+            Some((seconds, rest)) => (Some(seconds), rest, 1),
+            None => (None, rest, unit),
+        };
+        let (fraction, rest) = match rest.strip_prefix(&['.', ','][..]) {
+            Some(rest) => {
+                let len = rest.bytes().take_while(u8::is_ascii_digit).count();
+                let (digits, rest) = rest.split_at(len);
+                if digits.is_empty() {
+                    return None;
+                }
+                // the fraction of the last unit in nanoseconds, beyond which digits are ignored
+                let nanos = digits
+                    .bytes()
+                    .take(18)
+                    .fold((0_u128, 1_u128), |(value, divisor), digit| {
+                        (value * 10 + u128::from(digit - b'0'), divisor * 10)
+                    });
+                (nanos.0 * unit * 1_000_000_000 / nanos.1, rest)
+            }
+            None => (0, rest),
+        };
+        let (offset, rest) = match rest.strip_prefix('Z') {
+            Some(rest) => (Some(0), rest),
+            None if rest.is_empty() => (None, rest),
+            None => {
+                let (offset, rest) = time_difference(rest, false)?;
+                (Some(offset), rest)
+            }
+        };
+        // the fraction of an hour or a minute is less than an hour or a minute respectively
+        let extra_seconds = (fraction / 1_000_000_000) as u32;
+        Self::new(
+            year,
+            month,
+            day,
+            (
+                hours,
+                minutes.unwrap_or(0) + extra_seconds / 60,
+                seconds.unwrap_or(0) + extra_seconds % 60,
+                (fraction % 1_000_000_000) as u32,
+            ),
+            offset,
+            rest,
+        )
+    }
+
+    fn new(
+        year: u32,
+        month: u32,
+        day: u32,
+        (hours, minutes, seconds, nanos): (u32, u32, u32, u32),
+        offset: Option<i32>,
+        rest: &str,
+    ) -> Option<Self> {
+        let moment = Moment {
+            year,
+            month: u8::try_from(month).ok()?,
+            day: u8::try_from(day).ok()?,
+            hours: u8::try_from(hours).ok()?,
+            minutes: u8::try_from(minutes).ok()?,
+            seconds: u8::try_from(seconds).ok()?,
+            nanos,
+            offset,
+        };
+        if rest.is_empty()
+            && is_valid_day(year, moment.month, moment.day)
+            && moment.hours < 24
+            && is_valid_time(moment.hours, moment.minutes, moment.seconds)
+        {
+            Some(moment)
+        } else {
+            None
+        }
+    }
+}
+
+/// Splits the value of the given amount of leading decimal digits from the text
+fn number(text: &str, len: usize) -> Option<(u32, &str)> {
+    let digits = text.get(..len)?;
+    if digits.bytes().all(|b| b.is_ascii_digit()) {
+        Some((digits.parse().ok()?, &text[len..]))
+    } else {
+        None
+    }
+}
+
+/// Splits the value of all leading decimal digits from the text
+fn unsigned(text: &str) -> Option<(u64, &str)> {
+    let len = text.bytes().take_while(u8::is_ascii_digit).count();
+    Some((text.get(..len)?.parse().ok()?, &text[len..]))
+}
+
+/// `+hh[mm]` or `-hh[mm]` in minutes, of which the minutes are mandatory for `UTCTime`
+fn time_difference(text: &str, with_minutes: bool) -> Option<(i32, &str)> {
+    let (sign, rest) = match text.strip_prefix('+') {
+        Some(rest) => (1, rest),
+        None => (-1, text.strip_prefix('-')?),
+    };
+    let (hours, rest) = number(rest, 2)?;
+    let (minutes, rest) = match number(rest, 2) {
+        Some(minutes) => minutes,
+        None if !with_minutes => (0, rest),
+        None => return None,
+    };
+    if hours < 24 && minutes < 60 {
+        Some((sign * (hours * 60 + minutes) as i32, rest))
+    } else {
+        None
+    }
+}
+
+fn is_valid_day(year: u32, month: u8, day: u8) -> bool {
+    let leap_year = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+    let days = match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if leap_year => 29,
+        2 => 28,
+        _ => return false,
+    };
+    (1..=days).contains(&day)
+}
+
+fn is_valid_time(hours: u8, minutes: u8, seconds: u8) -> bool {
+    (hours < 24 && minutes < 60 && seconds <= 60) || (hours == 24 && minutes == 0 && seconds == 0)
+}
+
+#[cfg(feature = "chrono")]
+mod chrono_conversions {
+    use super::*;
+    use chrono::{Datelike, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Timelike};
+
+    impl From<NaiveDate> for Date {
+        fn from(date: NaiveDate) -> Self {
+            Date {
+                year: date.year().clamp(0, 9999) as u16,
+                month: date.month() as u8,
+                day: date.day() as u8,
+            }
+        }
+    }
+
+    impl Date {
+        pub fn to_naive_date(&self) -> Option<NaiveDate> {
+            NaiveDate::from_ymd_opt(
+                i32::from(self.year),
+                u32::from(self.month),
+                u32::from(self.day),
+            )
+        }
+    }
+
+    impl From<NaiveTime> for TimeOfDay {
+        /// Fractions of seconds are truncated and a leap second is represented as second 60
+        fn from(time: NaiveTime) -> Self {
+            TimeOfDay {
+                hours: time.hour() as u8,
+                minutes: time.minute() as u8,
+                seconds: (time.second() + time.nanosecond() / 1_000_000_000) as u8,
+            }
+        }
+    }
+
+    impl TimeOfDay {
+        /// The end of the day `24:00:00` cannot be represented by [`NaiveTime`]
+        pub fn to_naive_time(&self) -> Option<NaiveTime> {
+            naive_time(self.hours, self.minutes, self.seconds, 0)
+        }
+    }
+
+    impl From<NaiveDateTime> for DateTime {
+        fn from(date_time: NaiveDateTime) -> Self {
+            DateTime {
+                date: date_time.date().into(),
+                time: date_time.time().into(),
+            }
+        }
+    }
+
+    impl DateTime {
+        pub fn to_naive_date_time(&self) -> Option<NaiveDateTime> {
+            Some(NaiveDateTime::new(
+                self.date.to_naive_date()?,
+                self.time.to_naive_time()?,
+            ))
+        }
+    }
+
+    /// The `UTCTime` value of the given time, for which the year must be within 1950 and 2049
+    pub fn utc_time_from_chrono<Tz: TimeZone>(time: &chrono::DateTime<Tz>) -> Option<String> {
+        let time = time.naive_utc();
+        if (1950..2050).contains(&time.year()) {
+            Some(format!(
+                "{:02}{:02}{:02}{:02}{:02}{:02}Z",
+                time.year() % 100,
+                time.month(),
+                time.day(),
+                time.hour(),
+                time.minute(),
+                time.second() + time.nanosecond() / 1_000_000_000
+            ))
+        } else {
+            None
+        }
+    }
+
+    pub fn utc_time_to_chrono(value: &str) -> Option<chrono::DateTime<FixedOffset>> {
+        Moment::parse_utc_time(value)?.to_chrono()
+    }
+
+    /// The `GeneralizedTime` value of the given time in UTC, with a fraction of a second
+    /// without trailing zeros (as of ITU-T X.690 | ISO/IEC 8825-1:2015, chapter 11.7)
+    pub fn generalized_time_from_chrono<Tz: TimeZone>(
+        time: &chrono::DateTime<Tz>,
+    ) -> Option<String> {
+        let time = time.naive_utc();
+        if !(0..=9999).contains(&time.year()) {
+            return None;
+        }
+        let nanos = time.nanosecond() % 1_000_000_000;
+        let fraction = if nanos == 0 {
+            String::new()
+        } else {
+            format!(".{:09}", nanos).trim_end_matches('0').to_string()
+        };
+        Some(format!(
+            "{:04}{:02}{:02}{:02}{:02}{:02}{}Z",
+            time.year(),
+            time.month(),
+            time.day(),
+            time.hour(),
+            time.minute(),
+            time.second() + time.nanosecond() / 1_000_000_000,
+            fraction
+        ))
+    }
+
+    /// Local times without a time difference to UTC cannot be converted
+    pub fn generalized_time_to_chrono(value: &str) -> Option<chrono::DateTime<FixedOffset>> {
+        Moment::parse_generalized_time(value)?.to_chrono()
+    }
+
+    impl Moment {
+        fn to_chrono(self) -> Option<chrono::DateTime<FixedOffset>> {
+            let date = NaiveDate::from_ymd_opt(
+                i32::try_from(self.year).ok()?,
+                u32::from(self.month),
+                u32::from(self.day),
+            )?;
+            let time = naive_time(self.hours, self.minutes, self.seconds, self.nanos)?;
+            FixedOffset::east_opt(self.offset? * 60)?
+                .from_local_datetime(&NaiveDateTime::new(date, time))
+                .single()
+        }
+    }
+
+    fn naive_time(hours: u8, minutes: u8, seconds: u8, nanos: u32) -> Option<NaiveTime> {
+        // chrono represents leap seconds as the second 59 with more than 10^9 nanoseconds
+        let (seconds, nanos) = if seconds == 60 {
+            (59, nanos + 1_000_000_000)
+        } else {
+            (seconds, nanos)
+        };
+        NaiveTime::from_hms_nano_opt(
+            u32::from(hours),
+            u32::from(minutes),
+            u32::from(seconds),
+            nanos,
+        )
+    }
+}
+
+#[cfg(feature = "chrono")]
+pub use chrono_conversions::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_date() {
+        assert_eq!(
+            Some(Date {
+                year: 2020,
+                month: 2,
+                day: 29
+            }),
+            Date::parse("2020-02-29")
+        );
+        assert_eq!(None, Date::parse("2021-02-29"));
+        assert_eq!(None, Date::parse("1900-02-29"));
+        assert_eq!(
+            Some(Date {
+                year: 2000,
+                month: 2,
+                day: 29
+            }),
+            Date::parse("2000-02-29")
+        );
+        assert_eq!(None, Date::parse("2021-13-01"));
+        assert_eq!(None, Date::parse("2021-1-01"));
+        assert_eq!(None, Date::parse("20210101"));
+    }
+
+    #[test]
+    fn test_time_of_day_and_date_time() {
+        assert!(is_valid(TimeType::TimeOfDay, "24:00:00"));
+        assert!(is_valid(TimeType::TimeOfDay, "23:59:60"));
+        assert!(!is_valid(TimeType::TimeOfDay, "24:00:01"));
+        assert!(!is_valid(TimeType::TimeOfDay, "12:60:00"));
+        let date_time = DateTime::parse("1999-12-31T23:59:59").unwrap();
+        assert_eq!("1999-12-31T23:59:59", date_time.to_string());
+        assert_eq!(None, DateTime::parse("1999-12-31 23:59:59"));
+    }
+
+    #[test]
+    fn test_duration() {
+        for valid in &["P1Y2M10DT2H30M", "PT0.5S", "P3W", "P1DT1,25H", "PT36H"] {
+            let duration = Duration::parse(valid).unwrap_or_else(|| panic!("{}", valid));
+            assert_eq!(valid.replace(',', "."), duration.to_string());
+        }
+        assert_eq!(
+            Some(Duration {
+                minutes: Some(1),
+                seconds: Some(2),
+                fraction: Some((3, 50)),
+                ..Duration::default()
+            }),
+            Duration::parse("PT1M2.050S")
+        );
+        for invalid in &[
+            "P", "PT", "P1DT", "1D", "P1M1Y", "P1.5Y2M", "P1W2D", "P1H", "PT1D",
+        ] {
+            assert_eq!(None, Duration::parse(invalid), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn test_utc_time() {
+        assert!(is_valid(TimeType::UtcTime, "9912312359Z"));
+        assert!(is_valid(TimeType::UtcTime, "200229235959+0130"));
+        assert!(!is_valid(TimeType::UtcTime, "210229235959Z"));
+        assert!(!is_valid(TimeType::UtcTime, "2002292359"));
+        assert!(!is_valid(TimeType::UtcTime, "200229235959+01"));
+    }
+
+    #[test]
+    fn test_generalized_time() {
+        assert!(is_valid(TimeType::GeneralizedTime, "2020022923"));
+        assert!(is_valid(TimeType::GeneralizedTime, "20200229235959.123Z"));
+        assert!(is_valid(TimeType::GeneralizedTime, "202002292359,5-05"));
+        assert!(is_valid(TimeType::GeneralizedTime, "20161231235960Z"));
+        assert!(!is_valid(TimeType::GeneralizedTime, "20200229235959."));
+        assert!(!is_valid(TimeType::GeneralizedTime, "2020022924Z"));
+        assert!(!is_valid(TimeType::GeneralizedTime, "20200229235959X"));
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn test_chrono_conversions() {
+        use chrono::{NaiveDate, TimeZone, Utc};
+        let time = Utc.with_ymd_and_hms(2020, 2, 29, 23, 59, 58).unwrap();
+        assert_eq!(
+            Some("200229235958Z".to_string()),
+            utc_time_from_chrono(&time)
+        );
+        assert_eq!(
+            Some("20200229235958Z".to_string()),
+            generalized_time_from_chrono(&time)
+        );
+        assert_eq!(
+            Some(Utc.with_ymd_and_hms(2020, 3, 1, 0, 59, 30).unwrap()),
+            generalized_time_to_chrono("202002292359,5-01").map(|t| t.with_timezone(&Utc))
+        );
+        assert_eq!(None, generalized_time_to_chrono("20200229235958"));
+        assert_eq!(
+            NaiveDate::from_ymd_opt(2020, 2, 29),
+            Date::parse("2020-02-29").and_then(|d| d.to_naive_date())
+        );
+    }
+}
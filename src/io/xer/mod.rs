@@ -0,0 +1,408 @@
+//! This module contains the basic building blocks of the XML Encoding Rules as specified in
+//! ITU-T X.693 | ISO/IEC 8825-4:2015. Documents are represented by a minimal element tree which
+//! can be parsed from an XML text, writing is done directly into a `String`.
+
+pub mod err;
+
+pub use err::Error;
+
+use std::fmt::Write;
+
+/// ITU-T X.680 | ISO/IEC 8824-1:2015, the names of the empty elements that
+/// represent the control characters 0x00 to 0x1F within a character string
+const CONTROL_CHARACTERS: [&str; 32] = [
+    "nul", "soh", "stx", "etx", "eot", "enq", "ack", "bel", "bs", "ht", "lf", "vt", "ff", "cr",
+    "so", "si", "dle", "dc1", "dc2", "dc3", "dc4", "nak", "syn", "etb", "can", "em", "sub", "esc",
+    "is4", "is3", "is2", "is1",
+];
+
+/// The content of an element in the order of its appearance
+#[derive(Debug, Clone, PartialEq)]
+pub enum Node {
+    Text(String),
+    Element(Element),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Element {
+    pub name: String,
+    pub nodes: Vec<Node>,
+}
+
+impl Element {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            nodes: Vec::new(),
+        }
+    }
+
+    pub fn with_node(mut self, node: Node) -> Self {
+        self.nodes.push(node);
+        self
+    }
+
+    pub fn parse(text: &str) -> Result<Element, Error> {
+        let mut parser = Parser { text, pos: 0 };
+        parser.skip_misc()?;
+        let element = parser.parse_element()?;
+        parser.skip_misc()?;
+        if parser.pos < text.len() {
+            Err(Error::UnexpectedTrailingContent(parser.pos))
+        } else {
+            Ok(element)
+        }
+    }
+
+    /// The child elements, the text in between is ignored
+    pub fn elements(&self) -> impl Iterator<Item = &Element> {
+        self.nodes.iter().filter_map(|node| match node {
+            Node::Element(element) => Some(element),
+            Node::Text(_) => None,
+        })
+    }
+
+    /// The child elements, the text in between is ignored
+    pub fn into_elements(self) -> Vec<Element> {
+        self.nodes
+            .into_iter()
+            .filter_map(|node| match node {
+                Node::Element(element) => Some(element),
+                Node::Text(_) => None,
+            })
+            .collect()
+    }
+
+    /// The concatenated text of this element, child elements are ignored
+    pub fn text(&self) -> String {
+        self.nodes
+            .iter()
+            .filter_map(|node| match node {
+                Node::Text(text) => Some(text.as_str()),
+                Node::Element(_) => None,
+            })
+            .collect()
+    }
+
+    /// The value of a character string, in which child elements may only represent control
+    /// characters
+    pub fn string(&self) -> Result<String, Error> {
+        let mut string = String::new();
+        for node in &self.nodes {
+            match node {
+                Node::Text(text) => string.push_str(text),
+                Node::Element(element) => {
+                    let character = CONTROL_CHARACTERS
+                        .iter()
+                        .position(|name| *name == element.name)
+                        .filter(|_| element.nodes.is_empty())
+                        .ok_or_else(|| Error::InvalidCharacter(element.name.clone()))?;
+                    string.push(char::from(character as u8));
+                }
+            }
+        }
+        Ok(string)
+    }
+}
+
+/// Writes the given text as content of an element. Markup characters are replaced by their
+/// predefined entities and control characters by their empty elements.
+pub fn write_text(buffer: &mut String, text: &str) {
+    for c in text.chars() {
+        match c {
+            '&' => buffer.push_str("&amp;"),
+            '<' => buffer.push_str("&lt;"),
+            '>' => buffer.push_str("&gt;"),
+            '\t' | '\n' => buffer.push(c),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(buffer, "<{}/>", CONTROL_CHARACTERS[c as usize]);
+            }
+            c => buffer.push(c),
+        }
+    }
+}
+
+/// ITU-T X.693 | ISO/IEC 8825-4:2015, octets are written as pairs of uppercase
+/// hex digits
+pub fn to_hex(bytes: &[u8]) -> String {
+    let mut hex = String::with_capacity(bytes.len() * 2);
+    for byte in bytes {
+        let _ = write!(hex, "{:02X}", byte);
+    }
+    hex
+}
+
+/// Parses pairs of hex digits, white-space in between is ignored
+pub fn from_hex(text: &str) -> Result<Vec<u8>, Error> {
+    let digits = text
+        .chars()
+        .filter(|c| !c.is_ascii_whitespace())
+        .map(|c| c.to_digit(16).map(|d| d as u8))
+        .collect::<Option<Vec<u8>>>()
+        .ok_or(Error::InvalidHexString)?;
+    let pairs = digits.chunks_exact(2);
+    if !pairs.remainder().is_empty() {
+        return Err(Error::InvalidHexString);
+    }
+    Ok(pairs.map(|pair| pair[0] << 4 | pair[1]).collect())
+}
+
+/// ITU-T X.693 | ISO/IEC 8825-4:2015, bits are written as the characters 0 and 1
+pub fn to_bits(bytes: &[u8], bit_len: u64) -> String {
+    (0..bit_len)
+        .map(|bit| {
+            let byte = bytes[(bit / 8) as usize];
+            if byte & (0x80 >> (bit % 8)) != 0 {
+                '1'
+            } else {
+                '0'
+            }
+        })
+        .collect()
+}
+
+/// Parses the characters 0 and 1, white-space in between is ignored
+pub fn from_bits(text: &str) -> Result<(Vec<u8>, u64), Error> {
+    let mut bytes = Vec::new();
+    let mut bit_len = 0_u64;
+    for c in text.chars().filter(|c| !c.is_ascii_whitespace()) {
+        let bit = bit_len % 8;
+        if bit == 0 {
+            bytes.push(0x00);
+        }
+        match c {
+            '0' => {}
+            '1' => *bytes.last_mut().unwrap() |= 0x80 >> bit,
+            _ => return Err(Error::InvalidBitString),
+        }
+        bit_len += 1;
+    }
+    Ok((bytes, bit_len))
+}
+
+/// Parser for the subset of XML 1.0 that is needed for XER documents. Attributes, comments,
+/// processing instructions and the document type declaration are skipped.
+struct Parser<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn rest(&self) -> &str {
+        &self.text[self.pos..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn skip_until(&mut self, end: &str) -> Result<(), Error> {
+        let offset = self.rest().find(end).ok_or(Error::InvalidXml(self.pos))?;
+        self.pos += offset + end.len();
+        Ok(())
+    }
+
+    /// Skips white-space, comments, processing instructions and the document type declaration
+    fn skip_misc(&mut self) -> Result<(), Error> {
+        loop {
+            self.skip_whitespace();
+            if self.rest().starts_with("<?") {
+                self.skip_until("?>")?;
+            } else if self.rest().starts_with("<!--") {
+                self.skip_until("-->")?;
+            } else if self.rest().starts_with("<!") {
+                self.skip_until(">")?;
+            } else {
+                return Ok(());
+            }
+        }
+    }
+
+    fn expect(&mut self, expected: &str) -> Result<(), Error> {
+        if self.rest().starts_with(expected) {
+            self.pos += expected.len();
+            Ok(())
+        } else {
+            Err(Error::InvalidXml(self.pos))
+        }
+    }
+
+    fn parse_name(&mut self) -> Result<String, Error> {
+        let len = self
+            .rest()
+            .find(|c: char| c.is_whitespace() || "<>/=\"'&".contains(c))
+            .unwrap_or(self.rest().len());
+        if len == 0 {
+            return Err(Error::InvalidXml(self.pos));
+        }
+        let name = self.rest()[..len].to_string();
+        self.pos += len;
+        Ok(name)
+    }
+
+    fn skip_attribute(&mut self) -> Result<(), Error> {
+        self.parse_name()?;
+        self.skip_whitespace();
+        self.expect("=")?;
+        self.skip_whitespace();
+        let quote = if self.rest().starts_with('"') {
+            "\""
+        } else {
+            "'"
+        };
+        self.expect(quote)?;
+        self.skip_until(quote)
+    }
+
+    fn parse_element(&mut self) -> Result<Element, Error> {
+        let start = self.pos;
+        self.expect("<")?;
+        let mut element = Element::new(self.parse_name()?);
+        loop {
+            self.skip_whitespace();
+            if self.rest().starts_with("/>") {
+                self.pos += 2;
+                return Ok(element);
+            } else if self.rest().starts_with('>') {
+                self.pos += 1;
+                break;
+            } else {
+                self.skip_attribute()?;
+            }
+        }
+        loop {
+            let rest = self.rest();
+            if rest.starts_with("</") {
+                self.pos += 2;
+                let name = self.parse_name()?;
+                self.skip_whitespace();
+                self.expect(">")?;
+                return if name == element.name {
+                    Ok(element)
+                } else {
+                    Err(Error::InvalidXml(start))
+                };
+            } else if rest.starts_with("<!--") {
+                self.skip_until("-->")?;
+            } else if rest.starts_with("<![CDATA[") {
+                self.pos += "<![CDATA[".len();
+                let len = self.rest().find("]]>").ok_or(Error::InvalidXml(self.pos))?;
+                let text = self.rest()[..len].to_string();
+                Self::push_text(&mut element, &text);
+                self.pos += len + "]]>".len();
+            } else if rest.starts_with("<?") {
+                self.skip_until("?>")?;
+            } else if rest.starts_with('<') {
+                let child = self.parse_element()?;
+                element.nodes.push(Node::Element(child));
+            } else if rest.starts_with('&') {
+                let c = self.parse_reference()?;
+                Self::push_text(&mut element, c.encode_utf8(&mut [0u8; 4]));
+            } else if rest.is_empty() {
+                return Err(Error::InvalidXml(self.pos));
+            } else {
+                let len = rest.find(['<', '&']).unwrap_or(rest.len());
+                let text = rest[..len].to_string();
+                Self::push_text(&mut element, &text);
+                self.pos += len;
+            }
+        }
+    }
+
+    fn push_text(element: &mut Element, text: &str) {
+        if let Some(Node::Text(existing)) = element.nodes.last_mut() {
+            existing.push_str(text);
+        } else {
+            element.nodes.push(Node::Text(text.to_string()));
+        }
+    }
+
+    fn parse_reference(&mut self) -> Result<char, Error> {
+        let start = self.pos;
+        self.expect("&")?;
+        let len = self.rest().find(';').ok_or(Error::InvalidXml(start))?;
+        let reference = &self.rest()[..len];
+        let c = match reference {
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "amp" => Some('&'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            _ if reference.starts_with("#x") => u32::from_str_radix(&reference[2..], 16)
+                .ok()
+                .and_then(char::from_u32),
+            _ if reference.starts_with('#') => {
+                reference[1..].parse::<u32>().ok().and_then(char::from_u32)
+            }
+            _ => None,
+        };
+        self.pos += len + 1;
+        c.ok_or(Error::InvalidXml(start))
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_document() {
+        assert_eq!(
+            Ok(Element::new("Potato")
+                .with_node(Node::Text(" ".to_string()))
+                .with_node(Node::Element(
+                    Element::new("name").with_node(Node::Text("<a&b>'\u{e9}".to_string()))
+                ))
+                .with_node(Node::Element(
+                    Element::new("flag").with_node(Node::Element(Element::new("true")))
+                ))),
+            Element::parse(
+                "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<!-- comment -->\n\
+                 <Potato xmlns:x='urn:x'> <name a=\"1\">&lt;a<![CDATA[&b>]]>&apos;&#xE9;</name>\
+                 <!-- another --><flag><true /></flag></Potato >\n"
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid_documents() {
+        assert_eq!(Err(Error::InvalidXml(3)), Element::parse("<a><b></a>"));
+        assert_eq!(Err(Error::InvalidXml(7)), Element::parse("<a>text"));
+        assert_eq!(
+            Err(Error::InvalidXml(3)),
+            Element::parse("<a>&unknown;</a>")
+        );
+        assert_eq!(Err(Error::InvalidXml(1)), Element::parse("< a/>"));
+        assert_eq!(
+            Err(Error::UnexpectedTrailingContent(5)),
+            Element::parse("<a/> <b/>")
+        );
+    }
+
+    #[test]
+    fn test_text_with_control_characters() {
+        let mut buffer = String::new();
+        write_text(&mut buffer, "a<&>\u{0}\t\r\n\u{1b}");
+        assert_eq!("a&lt;&amp;&gt;<nul/>\t<cr/>\n<esc/>", buffer);
+        let element = Element::parse(&format!("<s>{}</s>", buffer)).unwrap();
+        assert_eq!(Ok("a<&>\u{0}\t\r\n\u{1b}".to_string()), element.string());
+        assert_eq!(
+            Err(Error::InvalidCharacter("x".to_string())),
+            Element::parse("<s>a<x/></s>").unwrap().string()
+        );
+    }
+
+    #[test]
+    fn test_hex_and_bits() {
+        assert_eq!("00A0FF", to_hex(&[0x00, 0xA0, 0xFF]));
+        assert_eq!(Ok(vec![0x00, 0xA0, 0xFF]), from_hex(" 00a0\n FF "));
+        assert_eq!(Err(Error::InvalidHexString), from_hex("0A0"));
+        assert_eq!("101100001", to_bits(&[0b1011_0000, 0b1000_0000], 9));
+        assert_eq!(
+            Ok((vec![0b1011_0000, 0b1000_0000], 9)),
+            from_bits("1011 0000 1")
+        );
+        assert_eq!(Err(Error::InvalidBitString), from_bits("102"));
+    }
+}
@@ -0,0 +1,45 @@
+use crate::syn::{ReadableType, Reader, WritableType, Writer};
+use asn1rs_model::model::{Charset, Tag};
+use core::marker::PhantomData;
+
+pub struct BmpString<C: Constraint = NoConstraint>(PhantomData<C>);
+
+impl<C: Constraint> Default for BmpString<C> {
+    fn default() -> Self {
+        BmpString(Default::default())
+    }
+}
+
+pub trait Constraint: super::common::Constraint {
+    const MIN: Option<u64> = None;
+    const MAX: Option<u64> = None;
+    const EXTENSIBLE: bool = false;
+    /// The effective permitted alphabet as sorted and inclusive ranges of character values,
+    /// see [`Charset::alphabet`]
+    const ALPHABET: &'static [(u32, u32)] = Charset::Bmp.alphabet();
+}
+
+#[derive(Default)]
+pub struct NoConstraint;
+impl super::common::Constraint for NoConstraint {
+    const TAG: Tag = Tag::DEFAULT_BMP_STRING;
+}
+impl Constraint for NoConstraint {}
+
+impl<C: Constraint> WritableType for BmpString<C> {
+    type Type = String;
+
+    #[inline]
+    fn write_value<W: Writer>(writer: &mut W, value: &Self::Type) -> Result<(), W::Error> {
+        writer.write_bmpstring::<C>(value.as_str())
+    }
+}
+
+impl<C: Constraint> ReadableType for BmpString<C> {
+    type Type = String;
+
+    #[inline]
+    fn read_value<R: Reader>(reader: &mut R) -> Result<Self::Type, <R as Reader>::Error> {
+        reader.read_bmpstring::<C>()
+    }
+}
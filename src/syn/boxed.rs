@@ -0,0 +1,33 @@
+use crate::syn::{ReadableType, Reader, WritableType, Writer};
+use core::marker::PhantomData;
+
+/// A value on the heap, which breaks the cycle of a recursive type. Boxing a value does not
+/// change its encoding.
+pub struct Boxed<T>(PhantomData<T>);
+
+impl<T> Default for Boxed<T> {
+    fn default() -> Self {
+        Boxed(Default::default())
+    }
+}
+
+impl<T: WritableType> WritableType for Boxed<T> {
+    type Type = Box<T::Type>;
+
+    #[inline]
+    fn write_value<W: Writer>(
+        writer: &mut W,
+        value: &Self::Type,
+    ) -> Result<(), <W as Writer>::Error> {
+        T::write_value(writer, &**value)
+    }
+}
+
+impl<T: ReadableType> ReadableType for Boxed<T> {
+    type Type = Box<T::Type>;
+
+    #[inline]
+    fn read_value<R: Reader>(reader: &mut R) -> Result<Self::Type, <R as Reader>::Error> {
+        T::read_value(reader).map(Box::new)
+    }
+}
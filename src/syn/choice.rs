@@ -11,15 +11,35 @@ impl<C: Constraint> Default for Choice<C> {
 
 pub trait Constraint: super::common::Constraint + Sized {
     const NAME: &'static str;
+    /// The identifiers of the variants as in the ASN.1 definition
+    const VARIANT_NAMES: &'static [&'static str];
     const VARIANT_COUNT: u64;
     const STD_VARIANT_COUNT: u64;
     const EXTENSIBLE: bool = false;
+    /// Whether the `TAG` is the tag of a tagged type assignment instead of the smallest tag of
+    /// the variants, a tagged `CHOICE` is always explicitly tagged (ITU-T X.680 | ISO/IEC 8824-1,
+    /// 31.2.7)
+    const EXPLICIT: bool = false;
 
     fn to_choice_index(&self) -> u64;
 
     fn write_content<W: Writer>(&self, writer: &mut W) -> Result<(), W::Error>;
 
     fn read_content<R: Reader>(index: u64, reader: &mut R) -> Result<Option<Self>, R::Error>;
+
+    /// Creates the variant which preserves an extension that is unknown to the definition from
+    /// its index and encoded content. By default, unknown extensions are not preserved.
+    #[inline]
+    fn from_unknown_extension(_index: u64, _bytes: Vec<u8>) -> Option<Self> {
+        None
+    }
+
+    /// The encoded content, if this is the variant of an unknown extension, see
+    /// [`Constraint::from_unknown_extension`]
+    #[inline]
+    fn unknown_extension(&self) -> Option<&[u8]> {
+        None
+    }
 }
 
 impl<C: Constraint> WritableType for Choice<C> {
@@ -19,7 +19,7 @@ impl<V: Writable, C: Constraint> WritableType for Complex<V, C> {
         writer: &mut W,
         value: &Self::Type,
     ) -> Result<(), <W as Writer>::Error> {
-        value.write(writer)
+        writer.write_complex::<C, _>(|w| value.write(w))
     }
 }
 
@@ -28,6 +28,6 @@ impl<V: Readable, C: Constraint> ReadableType for Complex<V, C> {
 
     #[inline]
     fn read_value<R: Reader>(reader: &mut R) -> Result<Self::Type, <R as Reader>::Error> {
-        V::read(reader)
+        reader.read_complex::<C, V, _>(V::read)
     }
 }
@@ -0,0 +1,229 @@
+use crate::syn::{ReadableType, Reader, WritableType, Writer};
+use core::fmt::{Display, Formatter};
+use core::marker::PhantomData;
+
+/// A referenced `SEQUENCE`, `SET`, `CHOICE`, `SEQUENCE OF` or `SET OF` with an inner subtype
+/// constraint (`WITH COMPONENTS` or `WITH COMPONENT`, ITU-T X.680 | ISO/IEC 8824-1:2015,
+/// chapter 51.8). The constraint is not
+/// PER-visible and therefore does not change the encoding, but values that violate it are
+/// neither written nor read.
+pub struct WithComponents<T, C>(PhantomData<T>, PhantomData<C>);
+
+impl<T, C> Default for WithComponents<T, C> {
+    fn default() -> Self {
+        WithComponents(Default::default(), Default::default())
+    }
+}
+
+pub trait Constraint {
+    const COMPONENTS: InnerType;
+}
+
+/// The components of a `SEQUENCE`, `SET` or `CHOICE` by their ASN.1 identifiers, as required to
+/// check an [`InnerType`] constraint. This is implemented for all generated types.
+pub trait Components {
+    /// The identifiers of the components that may be absent: the `OPTIONAL` components of a
+    /// `SEQUENCE` or `SET` or all alternatives of a `CHOICE`
+    #[inline]
+    fn optional_components(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    /// The component with the given identifier or `None` if it is absent
+    #[inline]
+    fn component(&self, _name: &str) -> Option<Component<'_>> {
+        None
+    }
+
+    /// The value of a type that is defined as `INTEGER`
+    #[inline]
+    fn integer(&self) -> Option<i128> {
+        None
+    }
+
+    /// The elements of a type that is defined as `SEQUENCE OF` or `SET OF`
+    #[inline]
+    fn elements(&self) -> Vec<Component<'_>> {
+        Vec::new()
+    }
+}
+
+pub enum Component<'a> {
+    Integer(i128),
+    Components(&'a dyn Components),
+    /// The elements of a `SEQUENCE OF` or `SET OF` component
+    Elements(Vec<Component<'a>>),
+    /// A component of any other type, which can only be constrained in its presence
+    Other,
+}
+
+#[derive(Debug, Copy, Clone, PartialOrd, PartialEq)]
+pub enum Presence {
+    Present,
+    Absent,
+    Optional,
+}
+
+#[derive(Debug, Copy, Clone, PartialOrd, PartialEq)]
+pub struct InnerType {
+    /// Whether the components that are not listed are unconstrained (`...,`), otherwise they
+    /// must be absent unless they are mandatory
+    pub partial: bool,
+    pub components: &'static [ComponentConstraint],
+    /// The constraint of every element of a `SEQUENCE OF` or `SET OF` (`WITH COMPONENT`)
+    pub element: Option<&'static ValueConstraint>,
+}
+
+#[derive(Debug, Copy, Clone, PartialOrd, PartialEq)]
+pub struct ComponentConstraint {
+    pub name: &'static str,
+    pub value: Option<ValueConstraint>,
+    pub presence: Option<Presence>,
+}
+
+#[derive(Debug, Copy, Clone, PartialOrd, PartialEq)]
+pub enum ValueConstraint {
+    /// The sorted and inclusive ranges of the permitted values of an `INTEGER` component
+    Integer(&'static [(i128, i128)]),
+    Components(InnerType),
+}
+
+/// The violation of an [`InnerType`] constraint with the path to the component, like `a.b`
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub enum Violation {
+    MustBePresent(String),
+    MustBeAbsent(String),
+    ValueNotPermitted(String, i128),
+}
+
+impl Violation {
+    fn within(self, name: &str) -> Self {
+        match self {
+            Violation::MustBePresent(path) => {
+                Violation::MustBePresent(format!("{}.{}", name, path))
+            }
+            Violation::MustBeAbsent(path) => Violation::MustBeAbsent(format!("{}.{}", name, path)),
+            Violation::ValueNotPermitted(path, value) => {
+                Violation::ValueNotPermitted(format!("{}.{}", name, path), value)
+            }
+        }
+    }
+}
+
+impl Display for Violation {
+    fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+        match self {
+            Violation::MustBePresent(path) => write!(f, "The component {} must be present", path),
+            Violation::MustBeAbsent(path) => write!(f, "The component {} must be absent", path),
+            Violation::ValueNotPermitted(path, value) => write!(
+                f,
+                "The value {} of the component {} is not permitted",
+                value, path
+            ),
+        }
+    }
+}
+
+impl InnerType {
+    pub fn check(&self, value: &dyn Components) -> Result<(), Violation> {
+        for constraint in self.components {
+            let component = value.component(constraint.name);
+            match (constraint.presence, &component) {
+                (Some(Presence::Present), None) => {
+                    return Err(Violation::MustBePresent(constraint.name.to_string()))
+                }
+                (Some(Presence::Absent), Some(_)) => {
+                    return Err(Violation::MustBeAbsent(constraint.name.to_string()))
+                }
+                _ => {}
+            }
+            if let (Some(value), Some(component)) = (&constraint.value, component) {
+                value.check(constraint.name, component)?;
+            }
+        }
+        if !self.partial {
+            // ITU-T X.680 | ISO/IEC 8824-1:2015, 51.8.7: a full specification implies ABSENT
+            // for all omitted components
+            if let Some(name) = value.optional_components().iter().find(|name| {
+                !self.components.iter().any(|c| c.name == **name) && value.component(name).is_some()
+            }) {
+                return Err(Violation::MustBeAbsent(name.to_string()));
+            }
+        }
+        match self.element {
+            Some(element) => element.check_elements(value.elements()),
+            None => Ok(()),
+        }
+    }
+}
+
+impl ValueConstraint {
+    fn check(&self, name: &str, component: Component) -> Result<(), Violation> {
+        match (self, component) {
+            (ValueConstraint::Integer(permitted), component) => {
+                let value = match component {
+                    Component::Integer(value) => Some(value),
+                    Component::Components(components) => components.integer(),
+                    Component::Elements(_) | Component::Other => None,
+                };
+                match value {
+                    Some(value)
+                        if !permitted
+                            .iter()
+                            .any(|(min, max)| (*min..=*max).contains(&value)) =>
+                    {
+                        Err(Violation::ValueNotPermitted(name.to_string(), value))
+                    }
+                    _ => Ok(()),
+                }
+            }
+            (ValueConstraint::Components(inner), Component::Components(components)) => inner
+                .check(components)
+                .map_err(|violation| violation.within(name)),
+            (ValueConstraint::Components(inner), Component::Elements(elements)) => {
+                match inner.element {
+                    Some(element) => element
+                        .check_elements(elements)
+                        .map_err(|violation| violation.within(name)),
+                    None => Ok(()),
+                }
+            }
+            (ValueConstraint::Components(_), _) => Ok(()),
+        }
+    }
+
+    /// The elements are named by their index in the path of a [`Violation`]
+    fn check_elements(&self, elements: Vec<Component>) -> Result<(), Violation> {
+        for (index, component) in elements.into_iter().enumerate() {
+            self.check(&index.to_string(), component)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: WritableType, C: Constraint> WritableType for WithComponents<T, C>
+where
+    T::Type: Components,
+{
+    type Type = T::Type;
+
+    #[inline]
+    fn write_value<W: Writer>(
+        writer: &mut W,
+        value: &Self::Type,
+    ) -> Result<(), <W as Writer>::Error> {
+        writer.write_with_components::<C, T>(value)
+    }
+}
+
+impl<T: ReadableType, C: Constraint> ReadableType for WithComponents<T, C>
+where
+    T::Type: Components,
+{
+    type Type = T::Type;
+
+    #[inline]
+    fn read_value<R: Reader>(reader: &mut R) -> Result<Self::Type, <R as Reader>::Error> {
+        reader.read_with_components::<C, T>()
+    }
+}
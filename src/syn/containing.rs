@@ -0,0 +1,59 @@
+use crate::model::Container;
+use crate::syn::{ReadableType, Reader, WritableType, Writer};
+use core::marker::PhantomData;
+
+/// An `OCTET STRING` or `BIT STRING` whose contents are the complete encoding of a value of the
+/// contained type (`CONTAINING`, ITU-T X.682 | ISO/IEC 8824-3:2015, chapter 11). The contents
+/// are encoded with the same encoding rules as the enclosing value, except for JER and XER,
+/// which embed the contained value directly.
+pub struct Containing<T, C>(PhantomData<T>, PhantomData<C>);
+
+impl<T, C> Default for Containing<T, C> {
+    fn default() -> Self {
+        Containing(Default::default(), Default::default())
+    }
+}
+
+pub trait Constraint: super::octetstring::Constraint + super::bitstring::Constraint {
+    const CONTAINER: Container;
+}
+
+impl<T: WritableType, C: Constraint> WritableType for Containing<T, C> {
+    type Type = T::Type;
+
+    #[inline]
+    fn write_value<W: Writer>(
+        writer: &mut W,
+        value: &Self::Type,
+    ) -> Result<(), <W as Writer>::Error> {
+        writer.write_containing::<C, T>(value)
+    }
+}
+
+impl<T: ReadableType, C: Constraint> ReadableType for Containing<T, C> {
+    type Type = T::Type;
+
+    #[inline]
+    fn read_value<R: Reader>(reader: &mut R) -> Result<Self::Type, <R as Reader>::Error> {
+        reader.read_containing::<C, T>()
+    }
+}
+
+/// Writes the given encoding of the contained value as the `OCTET STRING` or `BIT STRING`
+pub(crate) fn write_contents<C: Constraint, W: Writer>(
+    writer: &mut W,
+    contents: &[u8],
+) -> Result<(), W::Error> {
+    match C::CONTAINER {
+        Container::OctetString => writer.write_octet_string::<C>(contents),
+        Container::BitString => writer.write_bit_string::<C>(contents, contents.len() as u64 * 8),
+    }
+}
+
+/// Reads the encoding of the contained value from the `OCTET STRING` or `BIT STRING`
+pub(crate) fn read_contents<C: Constraint, R: Reader>(reader: &mut R) -> Result<Vec<u8>, R::Error> {
+    match C::CONTAINER {
+        Container::OctetString => reader.read_octet_string::<C>(),
+        Container::BitString => reader.read_bit_string::<C>().map(|(bytes, _)| bytes),
+    }
+}
@@ -0,0 +1,39 @@
+use crate::syn::{ReadableType, Reader, WritableType, Writer};
+use asn1rs_model::model::Tag;
+use core::marker::PhantomData;
+
+/// `DATE`, whose values are written as `YYYY-MM-DD`
+pub struct Date<C: Constraint = NoConstraint>(PhantomData<C>);
+
+impl<C: Constraint> Default for Date<C> {
+    fn default() -> Self {
+        Date(Default::default())
+    }
+}
+
+pub trait Constraint: super::common::Constraint {}
+
+#[derive(Default)]
+pub struct NoConstraint;
+impl super::common::Constraint for NoConstraint {
+    const TAG: Tag = Tag::DEFAULT_DATE;
+}
+impl Constraint for NoConstraint {}
+
+impl<C: Constraint> WritableType for Date<C> {
+    type Type = String;
+
+    #[inline]
+    fn write_value<W: Writer>(writer: &mut W, value: &Self::Type) -> Result<(), W::Error> {
+        writer.write_date::<C>(value.as_str())
+    }
+}
+
+impl<C: Constraint> ReadableType for Date<C> {
+    type Type = String;
+
+    #[inline]
+    fn read_value<R: Reader>(reader: &mut R) -> Result<Self::Type, <R as Reader>::Error> {
+        reader.read_date::<C>()
+    }
+}
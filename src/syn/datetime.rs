@@ -0,0 +1,39 @@
+use crate::syn::{ReadableType, Reader, WritableType, Writer};
+use asn1rs_model::model::Tag;
+use core::marker::PhantomData;
+
+/// `DATE-TIME`, whose values are written as `YYYY-MM-DDThh:mm:ss`
+pub struct DateTime<C: Constraint = NoConstraint>(PhantomData<C>);
+
+impl<C: Constraint> Default for DateTime<C> {
+    fn default() -> Self {
+        DateTime(Default::default())
+    }
+}
+
+pub trait Constraint: super::common::Constraint {}
+
+#[derive(Default)]
+pub struct NoConstraint;
+impl super::common::Constraint for NoConstraint {
+    const TAG: Tag = Tag::DEFAULT_DATE_TIME;
+}
+impl Constraint for NoConstraint {}
+
+impl<C: Constraint> WritableType for DateTime<C> {
+    type Type = String;
+
+    #[inline]
+    fn write_value<W: Writer>(writer: &mut W, value: &Self::Type) -> Result<(), W::Error> {
+        writer.write_date_time::<C>(value.as_str())
+    }
+}
+
+impl<C: Constraint> ReadableType for DateTime<C> {
+    type Type = String;
+
+    #[inline]
+    fn read_value<R: Reader>(reader: &mut R) -> Result<Self::Type, <R as Reader>::Error> {
+        reader.read_date_time::<C>()
+    }
+}
@@ -0,0 +1,44 @@
+use crate::syn::{ReadableType, Reader, WritableType, Writer};
+use core::marker::PhantomData;
+
+/// A component of a `SEQUENCE` or `SET` that is declared with `DEFAULT`. Like an `OPTIONAL`
+/// component, it is omitted from the encoding if its value equals the default value.
+pub struct DefaultValue<T, C>(PhantomData<T>, PhantomData<C>);
+
+impl<T, C> Default for DefaultValue<T, C> {
+    fn default() -> Self {
+        DefaultValue(Default::default(), Default::default())
+    }
+}
+
+pub trait Constraint<T> {
+    fn default_value() -> T;
+}
+
+impl<T: WritableType, C: Constraint<T::Type>> WritableType for DefaultValue<T, C>
+where
+    T::Type: PartialEq,
+{
+    type Type = T::Type;
+
+    #[inline]
+    fn write_value<W: Writer>(
+        writer: &mut W,
+        value: &Self::Type,
+    ) -> Result<(), <W as Writer>::Error> {
+        if *value == C::default_value() {
+            writer.write_opt::<T>(None)
+        } else {
+            writer.write_opt::<T>(Some(value))
+        }
+    }
+}
+
+impl<T: ReadableType, C: Constraint<T::Type>> ReadableType for DefaultValue<T, C> {
+    type Type = T::Type;
+
+    #[inline]
+    fn read_value<R: Reader>(reader: &mut R) -> Result<Self::Type, <R as Reader>::Error> {
+        Ok(reader.read_opt::<T>()?.unwrap_or_else(C::default_value))
+    }
+}
@@ -0,0 +1,40 @@
+use crate::syn::{ReadableType, Reader, WritableType, Writer};
+use asn1rs_model::model::Tag;
+use core::marker::PhantomData;
+
+/// `DURATION`, whose values are written as `PnYnMnDTnHnMnS` or `PnW`, where only the last
+/// component might have a fractional part
+pub struct Duration<C: Constraint = NoConstraint>(PhantomData<C>);
+
+impl<C: Constraint> Default for Duration<C> {
+    fn default() -> Self {
+        Duration(Default::default())
+    }
+}
+
+pub trait Constraint: super::common::Constraint {}
+
+#[derive(Default)]
+pub struct NoConstraint;
+impl super::common::Constraint for NoConstraint {
+    const TAG: Tag = Tag::DEFAULT_DURATION;
+}
+impl Constraint for NoConstraint {}
+
+impl<C: Constraint> WritableType for Duration<C> {
+    type Type = String;
+
+    #[inline]
+    fn write_value<W: Writer>(writer: &mut W, value: &Self::Type) -> Result<(), W::Error> {
+        writer.write_duration::<C>(value.as_str())
+    }
+}
+
+impl<C: Constraint> ReadableType for Duration<C> {
+    type Type = String;
+
+    #[inline]
+    fn read_value<R: Reader>(reader: &mut R) -> Result<Self::Type, <R as Reader>::Error> {
+        reader.read_duration::<C>()
+    }
+}
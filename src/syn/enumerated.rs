@@ -11,13 +11,34 @@ impl<C: Constraint> Default for Enumerated<C> {
 
 pub trait Constraint: super::common::Constraint + Sized {
     const NAME: &'static str;
+    /// The identifiers of the variants as in the ASN.1 definition
+    const VARIANT_NAMES: &'static [&'static str];
+    /// The enumeration values of the variants as in the ASN.1 definition
+    const VARIANT_VALUES: &'static [i64];
     const VARIANT_COUNT: u64;
     const STD_VARIANT_COUNT: u64;
     const EXTENSIBLE: bool = false;
+    /// Whether the `TAG` is the explicit tag of a type assignment, see
+    /// [`crate::syn::sequence::Constraint::EXPLICIT`]
+    const EXPLICIT: bool = false;
 
     fn to_choice_index(&self) -> u64;
 
     fn from_choice_index(index: u64) -> Option<Self>;
+
+    /// The enumeration value of this variant, which is unknown for unknown extensions
+    fn to_enumeration_value(&self) -> Option<i64> {
+        Self::VARIANT_VALUES
+            .get(self.to_choice_index() as usize)
+            .copied()
+    }
+
+    fn from_enumeration_value(value: i64) -> Option<Self> {
+        Self::VARIANT_VALUES
+            .iter()
+            .position(|v| *v == value)
+            .and_then(|index| Self::from_choice_index(index as u64))
+    }
 }
 
 impl<C: Constraint> WritableType for Enumerated<C> {
@@ -0,0 +1,37 @@
+use crate::syn::{ReadableType, Reader, WritableType, Writer};
+use core::marker::PhantomData;
+
+/// A value with an explicit tag (`EXPLICIT`, ITU-T X.680 | ISO/IEC 8824-1:2015, chapter 31.2.7).
+/// In BER, the encoding of the value with its own tag is wrapped in a constructed element with
+/// the tag of the [`Constraint`]. The other encoding rules do not encode tags, except for the
+/// alternatives of a `CHOICE` in OER.
+pub struct Explicit<T, C>(PhantomData<T>, PhantomData<C>);
+
+impl<T, C> Default for Explicit<T, C> {
+    fn default() -> Self {
+        Explicit(Default::default(), Default::default())
+    }
+}
+
+pub trait Constraint: super::common::Constraint {}
+
+impl<T: WritableType, C: Constraint> WritableType for Explicit<T, C> {
+    type Type = T::Type;
+
+    #[inline]
+    fn write_value<W: Writer>(
+        writer: &mut W,
+        value: &Self::Type,
+    ) -> Result<(), <W as Writer>::Error> {
+        writer.write_explicit::<C, T>(value)
+    }
+}
+
+impl<T: ReadableType, C: Constraint> ReadableType for Explicit<T, C> {
+    type Type = T::Type;
+
+    #[inline]
+    fn read_value<R: Reader>(reader: &mut R) -> Result<Self::Type, <R as Reader>::Error> {
+        reader.read_explicit::<C, T>()
+    }
+}
@@ -0,0 +1,40 @@
+use crate::syn::{ReadableType, Reader, WritableType, Writer};
+use asn1rs_model::model::Tag;
+use core::marker::PhantomData;
+
+/// `GeneralizedTime`, whose values are written as `YYYYMMDDhh[mm[ss]][.fff]`, optionally
+/// followed by `Z` or a time difference `+hh[mm]` or `-hh[mm]`
+pub struct GeneralizedTime<C: Constraint = NoConstraint>(PhantomData<C>);
+
+impl<C: Constraint> Default for GeneralizedTime<C> {
+    fn default() -> Self {
+        GeneralizedTime(Default::default())
+    }
+}
+
+pub trait Constraint: super::common::Constraint {}
+
+#[derive(Default)]
+pub struct NoConstraint;
+impl super::common::Constraint for NoConstraint {
+    const TAG: Tag = Tag::DEFAULT_GENERALIZED_TIME;
+}
+impl Constraint for NoConstraint {}
+
+impl<C: Constraint> WritableType for GeneralizedTime<C> {
+    type Type = String;
+
+    #[inline]
+    fn write_value<W: Writer>(writer: &mut W, value: &Self::Type) -> Result<(), W::Error> {
+        writer.write_generalized_time::<C>(value.as_str())
+    }
+}
+
+impl<C: Constraint> ReadableType for GeneralizedTime<C> {
+    type Type = String;
+
+    #[inline]
+    fn read_value<R: Reader>(reader: &mut R) -> Result<Self::Type, <R as Reader>::Error> {
+        reader.read_generalized_time::<C>()
+    }
+}
@@ -1,5 +1,5 @@
 use crate::syn::{ReadableType, Reader, WritableType, Writer};
-use asn1rs_model::model::Tag;
+use asn1rs_model::model::{Charset, Tag};
 use core::marker::PhantomData;
 
 pub struct Ia5String<C: Constraint = NoConstraint>(PhantomData<C>);
@@ -14,6 +14,9 @@ pub trait Constraint: super::common::Constraint {
     const MIN: Option<u64> = None;
     const MAX: Option<u64> = None;
     const EXTENSIBLE: bool = false;
+    /// The effective permitted alphabet as sorted and inclusive ranges of character values,
+    /// see [`Charset::alphabet`]
+    const ALPHABET: &'static [(u32, u32)] = Charset::Ia5.alphabet();
 }
 
 #[derive(Default)]
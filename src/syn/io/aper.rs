@@ -0,0 +1,1241 @@
+use crate::io::per::aligned::Aligned;
+use crate::io::per::alphabet::Alphabet;
+use crate::io::per::err::Error;
+use crate::io::per::unaligned::buffer::BitBuffer;
+use crate::io::per::unaligned::BitWrite;
+use crate::io::per::unaligned::BYTE_LEN;
+use crate::io::per::PackedRead;
+use crate::io::per::PackedWrite;
+use crate::io::time;
+use crate::model::{Charset, TimeType};
+use crate::syn::io::uper::Scope;
+use crate::syn::*;
+use std::ops::Range;
+
+pub use crate::io::per::unaligned::buffer::Bits;
+pub use crate::io::per::unaligned::ScopedBitRead;
+
+/// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 30.5.7: the characters of a known-multiplier
+/// character string are only octet-aligned if the encoding might exceed 16 bits
+#[inline]
+const fn is_aligned_char_string(max_chars: Option<u64>, char_bits: u64) -> bool {
+    match max_chars {
+        Some(max) => max.saturating_mul(char_bits) > 16,
+        None => true,
+    }
+}
+
+/// Encodes values in the ALIGNED variant of the Packed Encoding Rules (APER). The structure
+/// and the handling of OPTIONAL flags and extensible sequences is identical to the
+/// [`UperWriter`](crate::syn::io::UperWriter), but most values are octet-aligned.
+#[derive(Default)]
+pub struct AperWriter {
+    bits: Aligned<BitBuffer>,
+    scope: Option<Scope>,
+}
+
+impl AperWriter {
+    pub fn with_capacity(capacity_bytes: usize) -> Self {
+        Self {
+            bits: Aligned(BitBuffer::with_capacity(capacity_bytes)),
+            ..Default::default()
+        }
+    }
+
+    pub fn byte_content(&self) -> &[u8] {
+        self.bits.0.content()
+    }
+
+    pub const fn bit_len(&self) -> usize {
+        self.bits.0.bit_len()
+    }
+
+    pub fn into_bytes_vec(self) -> Vec<u8> {
+        debug_assert_eq!(self.bit_len().div_ceil(BYTE_LEN), self.bits.0.buffer.len());
+        self.bits.into_inner().into()
+    }
+
+    pub fn as_reader(&self) -> AperReader<Bits<'_>> {
+        AperReader::from(Bits::from((self.byte_content(), self.bit_len())))
+    }
+
+    #[inline]
+    pub fn scope_pushed<R, F: FnOnce(&mut Self) -> R>(&mut self, scope: Scope, f: F) -> R {
+        let original = self.scope.replace(scope);
+        let result = f(self);
+        let scope = core::mem::replace(&mut self.scope, original);
+        // save because this is supposed to be the original from above
+        debug_assert!(scope.unwrap().exhausted());
+        result
+    }
+
+    #[inline]
+    pub fn scope_stashed<R, F: FnOnce(&mut Self) -> R>(&mut self, f: F) -> R {
+        let scope = self.scope.take();
+        let result = f(self);
+        self.scope = scope;
+        result
+    }
+
+    #[inline]
+    pub fn write_bit_field_entry(&mut self, is_opt: bool, is_present: bool) -> Result<(), Error> {
+        if let Some(scope) = &mut self.scope {
+            scope.write_into_field(&mut self.bits.0, is_opt, is_present)
+        } else if is_opt {
+            self.bits.0.write_bit(is_present)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Writes the given content as open type. According to ITU-T X.691 | ISO/IEC 8825-2:2015,
+    /// chapter 11.2, the content is padded to a multiple of eight bits and an empty encoding is
+    /// replaced by a single zero octet.
+    #[inline]
+    pub fn write_open_type<T, F: FnOnce(&mut Self) -> Result<T, Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<T, Error> {
+        let mut writer = AperWriter::with_capacity(512);
+        let result = f(&mut writer)?;
+        if writer.bit_len() == 0 {
+            self.bits.write_octetstring(None, None, false, &[0x00])?;
+        } else {
+            self.bits
+                .write_octetstring(None, None, false, writer.byte_content())?;
+        }
+        Ok(result)
+    }
+
+    #[inline]
+    #[allow(clippy::redundant_pattern_matching)] // allow for const_*!
+    pub fn with_buffer<T, F: FnOnce(&mut Self) -> Result<T, Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<T, Error> {
+        if const_map_or!(self.scope, Scope::encode_as_open_type_field, false) {
+            self.scope_stashed(|w| w.write_open_type(f))
+        } else {
+            f(self)
+        }
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 30.5: each character of a known-multiplier
+    /// character string is encoded with the number of bits its permitted alphabet requires,
+    /// rounded up to the next power of two
+    fn write_known_multiplier_string(
+        &mut self,
+        charset: Charset,
+        alphabet: &[(u32, u32)],
+        min: Option<u64>,
+        max: Option<u64>,
+        extensible: bool,
+        value: &str,
+    ) -> Result<(), Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| {
+            let alphabet = Alphabet::new(alphabet, true)?;
+            let encoded = value
+                .chars()
+                .map(|c| alphabet.encode(c))
+                .collect::<Option<Vec<_>>>()
+                .ok_or_else(|| Error::invalid_character_string(charset))?;
+
+            let chars = encoded.len() as u64;
+            let lower = min.unwrap_or(0);
+            let upper = max.unwrap_or(u64::MAX);
+            let out_of_range = chars < lower || chars > upper;
+
+            if extensible {
+                w.bits.0.write_bit(out_of_range)?;
+            }
+
+            let aligned = if out_of_range {
+                if !extensible {
+                    return Err(Error::SizeNotInRange(chars, lower, upper));
+                } else {
+                    w.bits.write_length_determinant(None, None, chars)?;
+                    true
+                }
+            } else {
+                w.bits.write_length_determinant(min, max, chars)?;
+                is_aligned_char_string(max, alphabet.bits() as u64)
+            };
+
+            if aligned && chars > 0 {
+                w.bits.align()?;
+            }
+
+            for value in encoded {
+                w.bits
+                    .0
+                    .write_bits_with_offset(&value.to_be_bytes(), 32 - alphabet.bits())?;
+            }
+
+            Ok(())
+        })
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 32.1: `UTCTime` and `GeneralizedTime` are
+    /// encoded as `VisibleString`
+    fn write_time_string(&mut self, time: TimeType, value: &str) -> Result<(), Error> {
+        if !time::is_valid(time, value) {
+            return Err(Error::InvalidTime(time));
+        }
+        let alphabet = Charset::Visible.alphabet();
+        self.write_known_multiplier_string(Charset::Visible, alphabet, None, None, false, value)
+    }
+}
+
+impl Writer for AperWriter {
+    type Error = Error;
+
+    #[inline]
+    fn write_sequence<C: sequence::Constraint, F: Fn(&mut Self) -> Result<(), Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| {
+            if C::EXTENDED_AFTER_FIELD.is_some() {
+                // set once an extended field is present
+                w.bits.0.write_bit(false)?;
+            }
+
+            // As in UPER, the values for all OPTIONAL flags are written before any field
+            // value is written. This remembers their position, so a later call of `write_opt`
+            // can write them to the buffer
+            let write_pos = w.bits.0.write_position;
+            let range = write_pos..write_pos + C::STD_OPTIONAL_FIELDS as usize;
+            for _ in 0..C::STD_OPTIONAL_FIELDS {
+                if let Err(e) = w.bits.0.write_bit(false) {
+                    w.bits.0.write_position = write_pos; // undo write_bits
+                    return Err(e);
+                }
+            }
+
+            if let Some(extension_after) = C::EXTENDED_AFTER_FIELD {
+                let original = w.scope.replace(Scope::ExtensibleSequence {
+                    opt_bit_field: Some(range),
+                    calls_until_ext_bitfield: (extension_after + 1) as usize,
+                    number_of_ext_fields: (C::FIELD_COUNT - (extension_after + 1)) as usize,
+                    extensions_present: false,
+                });
+                let result = f(w);
+                let scope = core::mem::replace(&mut w.scope, original);
+                result?;
+
+                // save because this is supposed to be the original from above
+                debug_assert!(scope.as_ref().unwrap().exhausted());
+                if let Some(Scope::AllBitField(_)) = scope {
+                    // part2 was written because an extended field is present
+                    w.bits
+                        .0
+                        .with_write_position_at(write_pos - 1, |b| b.write_bit(true))?;
+                }
+                Ok(())
+            } else {
+                w.scope_pushed(Scope::OptBitField(range), f)
+            }
+        })
+    }
+
+    #[inline]
+    #[allow(clippy::redundant_pattern_matching)] // allow for const_*!
+    fn write_sequence_of<C: sequenceof::Constraint, T: WritableType>(
+        &mut self,
+        slice: &[T::Type],
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| {
+            const MAX: u64 = i64::MAX as u64;
+            let min = const_unwrap_or!(C::MIN, 0);
+            let max = const_unwrap_or!(C::MAX, MAX);
+            let len = slice.len() as u64;
+            let out_of_range = len < min || len > max;
+
+            if C::EXTENSIBLE {
+                w.bits.0.write_bit(out_of_range)?;
+            }
+
+            if out_of_range {
+                if !C::EXTENSIBLE {
+                    return Err(Error::SizeNotInRange(len, min, max));
+                } else {
+                    w.bits.write_length_determinant(None, None, len)?;
+                }
+            } else {
+                w.bits.write_length_determinant(C::MIN, C::MAX, len)?;
+            }
+            w.scope_stashed(|w| {
+                for value in slice {
+                    T::write_value(w, value)?;
+                }
+                Ok(())
+            })
+        })
+    }
+
+    #[inline]
+    fn write_set<C: set::Constraint, F: Fn(&mut Self) -> Result<(), Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<(), Self::Error> {
+        self.write_sequence::<C, F>(f)
+    }
+
+    #[inline]
+    fn write_set_of<C: setof::Constraint, T: WritableType>(
+        &mut self,
+        slice: &[<T as WritableType>::Type],
+    ) -> Result<(), Self::Error> {
+        self.write_sequence_of::<C, T>(slice)
+    }
+
+    #[inline]
+    fn write_enumerated<C: enumerated::Constraint>(
+        &mut self,
+        enumerated: &C,
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| {
+            w.bits.write_enumeration_index(
+                C::STD_VARIANT_COUNT,
+                C::EXTENSIBLE,
+                enumerated.to_choice_index(),
+            )
+        })
+    }
+
+    #[inline]
+    fn write_choice<C: choice::Constraint>(&mut self, choice: &C) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| {
+            w.scope_stashed(|w| {
+                let index = choice.to_choice_index();
+
+                // this fails if the index is out of range
+                w.bits
+                    .write_choice_index(C::STD_VARIANT_COUNT, C::EXTENSIBLE, index)?;
+
+                if index >= C::STD_VARIANT_COUNT {
+                    w.write_open_type(|w| choice.write_content(w))
+                } else {
+                    choice.write_content(w)
+                }
+            })
+        })
+    }
+
+    #[inline]
+    fn write_open_type<C: opentype::Constraint>(
+        &mut self,
+        value: &C,
+        key: i64,
+    ) -> Result<(), Self::Error> {
+        if value.key() != key {
+            return Err(Error::MismatchedOpenTypeKey(key, value.key()));
+        }
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| {
+            w.scope_stashed(|w| {
+                if let Some(bytes) = value.unknown() {
+                    w.bits.write_octetstring(None, None, false, bytes)
+                } else {
+                    w.write_open_type(|w| value.write_content(w))
+                }
+            })
+        })
+    }
+
+    #[inline]
+    #[allow(clippy::redundant_pattern_matching)] // allow for const_*!
+    fn write_opt<T: WritableType>(
+        &mut self,
+        value: Option<&<T as WritableType>::Type>,
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(true, const_is_some!(value))?;
+        if let Some(value) = value {
+            self.with_buffer(|w| w.scope_stashed(|w| T::write_value(w, value)))
+        } else {
+            Ok(())
+        }
+    }
+
+    #[inline]
+    #[allow(clippy::redundant_pattern_matching)] // allow for const_*!
+    fn write_number<T: numbers::Number, C: numbers::Constraint<T>>(
+        &mut self,
+        value: T,
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        let value = value.to_i64();
+
+        if !C::EXTENSIBLE && !C::permits(value) {
+            return Err(Error::ValueNotPermitted(value.to_string()));
+        }
+
+        self.with_buffer(|w| {
+            if C::EXTENSIBLE {
+                let min = const_unwrap_or!(C::MIN, i64::MIN);
+                let max = const_unwrap_or!(C::MAX, i64::MAX);
+                let out_of_range = value < min || value > max || !C::permits(value);
+                w.bits.0.write_bit(out_of_range)?;
+                if out_of_range {
+                    return w.bits.write_unconstrained_whole_number(value);
+                }
+            }
+
+            match (C::MIN, C::MAX) {
+                (Some(min), Some(max)) => w.bits.write_constrained_whole_number(min, max, value),
+                (Some(min), None) => w.bits.write_semi_constrained_whole_number(min, value),
+                (None, _) => w.bits.write_unconstrained_whole_number(value),
+            }
+        })
+    }
+
+    #[inline]
+    fn write_wide_number<T: numbers::WideNumber, C: numbers::WideConstraint>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| {
+            if !C::permits(value) {
+                return if C::EXTENSIBLE {
+                    crate::io::per::wide::write_wide_extension_value(&mut w.bits, value)
+                } else {
+                    Err(Error::ValueNotPermitted(value.to_string()))
+                };
+            }
+            crate::io::per::wide::write_wide_whole_number(
+                &mut w.bits,
+                C::MIN,
+                C::MAX,
+                C::EXTENSIBLE,
+                value,
+            )
+        })
+    }
+
+    #[inline]
+    #[allow(clippy::redundant_pattern_matching)] // allow for const_*!
+    fn write_utf8string<C: utf8string::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| {
+            if !C::EXTENSIBLE {
+                let chars = value.chars().count() as u64;
+                let min = const_unwrap_or!(C::MIN, 0);
+                let max = const_unwrap_or!(C::MAX, u64::MAX);
+                if chars < min || chars > max {
+                    return Err(Error::SizeNotInRange(chars, min, max));
+                }
+            }
+
+            // ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 30.3
+            // For 'known-multiplier character string types' there is no min/max in the encoding
+            w.bits
+                .write_octetstring(None, None, false, value.as_bytes())
+        })
+    }
+
+    #[inline]
+    fn write_ia5string<C: ia5string::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_known_multiplier_string(
+            Charset::Ia5,
+            C::ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            value,
+        )
+    }
+
+    #[inline]
+    fn write_numericstring<C: numericstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_known_multiplier_string(
+            Charset::Numeric,
+            C::ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            value,
+        )
+    }
+
+    #[inline]
+    fn write_printablestring<C: printablestring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_known_multiplier_string(
+            Charset::Printable,
+            C::ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            value,
+        )
+    }
+
+    #[inline]
+    fn write_visiblestring<C: visiblestring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_known_multiplier_string(
+            Charset::Visible,
+            C::ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            value,
+        )
+    }
+
+    #[inline]
+    fn write_bmpstring<C: bmpstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_known_multiplier_string(
+            Charset::Bmp,
+            C::ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            value,
+        )
+    }
+
+    #[inline]
+    fn write_universalstring<C: universalstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_known_multiplier_string(
+            Charset::Universal,
+            C::ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            value,
+        )
+    }
+
+    #[inline]
+    fn write_octet_string<C: octetstring::Constraint>(
+        &mut self,
+        value: &[u8],
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| {
+            w.bits
+                .write_octetstring(C::MIN, C::MAX, C::EXTENSIBLE, value)
+        })
+    }
+
+    #[inline]
+    fn write_bit_string<C: bitstring::Constraint>(
+        &mut self,
+        value: &[u8],
+        bit_len: u64,
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| {
+            w.bits
+                .write_bitstring(C::MIN, C::MAX, C::EXTENSIBLE, value, 0, bit_len)
+        })
+    }
+
+    #[inline]
+    fn write_boolean<C: boolean::Constraint>(&mut self, value: bool) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| w.bits.write_boolean(value))
+    }
+
+    #[inline]
+    fn write_null<C: null::Constraint>(&mut self) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|_| Ok(()))
+    }
+
+    #[inline]
+    fn write_real<C: real::Constraint>(&mut self, value: f64) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| {
+            w.bits
+                .write_octetstring(None, None, false, &crate::io::real::to_octets(value))
+        })
+    }
+
+    #[inline]
+    fn write_object_identifier<C: objectidentifier::Constraint>(
+        &mut self,
+        value: &[u64],
+    ) -> Result<(), Self::Error> {
+        let octets = crate::io::oid::object_identifier_to_octets(value)
+            .ok_or(Error::InvalidObjectIdentifier)?;
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| w.bits.write_octetstring(None, None, false, &octets))
+    }
+
+    #[inline]
+    fn write_relative_oid<C: relativeoid::Constraint>(
+        &mut self,
+        value: &[u64],
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| {
+            w.bits.write_octetstring(
+                None,
+                None,
+                false,
+                &crate::io::oid::relative_oid_to_octets(value),
+            )
+        })
+    }
+
+    #[inline]
+    fn write_utc_time<C: utctime::Constraint>(&mut self, value: &str) -> Result<(), Self::Error> {
+        self.write_time_string(TimeType::UtcTime, value)
+    }
+
+    #[inline]
+    fn write_generalized_time<C: generalizedtime::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_time_string(TimeType::GeneralizedTime, value)
+    }
+
+    #[inline]
+    fn write_date<C: date::Constraint>(&mut self, value: &str) -> Result<(), Self::Error> {
+        let date = time::Date::parse(value).ok_or(Error::InvalidTime(TimeType::Date))?;
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| crate::io::per::time::write_date(&mut w.bits, &date))
+    }
+
+    #[inline]
+    fn write_time_of_day<C: timeofday::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        let time = time::TimeOfDay::parse(value).ok_or(Error::InvalidTime(TimeType::TimeOfDay))?;
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| crate::io::per::time::write_time_of_day(&mut w.bits, &time))
+    }
+
+    #[inline]
+    fn write_date_time<C: datetime::Constraint>(&mut self, value: &str) -> Result<(), Self::Error> {
+        let date_time =
+            time::DateTime::parse(value).ok_or(Error::InvalidTime(TimeType::DateTime))?;
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| crate::io::per::time::write_date_time(&mut w.bits, &date_time))
+    }
+
+    #[inline]
+    fn write_duration<C: duration::Constraint>(&mut self, value: &str) -> Result<(), Self::Error> {
+        let duration =
+            time::Duration::parse(value).ok_or(Error::InvalidTime(TimeType::Duration))?;
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| crate::io::per::time::write_duration(&mut w.bits, &duration))
+    }
+
+    #[inline]
+    fn write_with_components<C: components::Constraint, T: WritableType>(
+        &mut self,
+        value: &T::Type,
+    ) -> Result<(), Self::Error>
+    where
+        T::Type: components::Components,
+    {
+        C::COMPONENTS
+            .check(value)
+            .map_err(Error::InvalidComponents)?;
+        T::write_value(self, value)
+    }
+
+    #[inline]
+    fn write_containing<C: containing::Constraint, T: WritableType>(
+        &mut self,
+        value: &T::Type,
+    ) -> Result<(), Self::Error> {
+        let mut writer = AperWriter::default();
+        T::write_value(&mut writer, value)?;
+        if writer.bit_len() == 0 {
+            // ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.1.3: a complete encoding has at
+            // least one octet
+            containing::write_contents::<C, _>(self, &[0x00])
+        } else {
+            containing::write_contents::<C, _>(self, writer.byte_content())
+        }
+    }
+
+    #[inline]
+    fn write_explicit<C: explicit::Constraint, T: WritableType>(
+        &mut self,
+        value: &T::Type,
+    ) -> Result<(), Self::Error> {
+        // tags are not encoded
+        T::write_value(self, value)
+    }
+}
+
+/// Decodes values in the ALIGNED variant of the Packed Encoding Rules (APER), see
+/// [`AperWriter`] for further details.
+pub struct AperReader<B: ScopedBitRead> {
+    bits: Aligned<B>,
+    scope: Option<Scope>,
+}
+
+impl<'a, I: Into<Bits<'a>>> From<I> for AperReader<Bits<'a>> {
+    fn from(bits: I) -> Self {
+        AperReader {
+            bits: Aligned(bits.into()),
+            scope: None,
+        }
+    }
+}
+
+impl<B: ScopedBitRead> AperReader<B> {
+    #[inline]
+    pub fn bits_remaining(&self) -> usize {
+        self.bits.0.remaining()
+    }
+
+    #[inline]
+    pub fn scope_pushed<R, F: FnOnce(&mut Self) -> R>(&mut self, scope: Scope, f: F) -> R {
+        let original = self.scope.replace(scope);
+        let result = f(self);
+        if cfg!(debug_assertions) {
+            let scope = core::mem::replace(&mut self.scope, original);
+            // save because this is the original from above
+            debug_assert!(scope.unwrap().exhausted());
+        } else {
+            self.scope = original;
+        }
+        result
+    }
+
+    #[inline]
+    pub fn scope_stashed<R, F: FnOnce(&mut Self) -> R>(&mut self, f: F) -> R {
+        let scope = self.scope.take();
+        let result = f(self);
+        self.scope = scope;
+        result
+    }
+
+    #[inline]
+    pub fn read_whole_sub_slice<T, E, F: FnOnce(&mut Self) -> Result<T, E>>(
+        &mut self,
+        length_bytes: usize,
+        f: F,
+    ) -> Result<T, E> {
+        let write_position = self.bits.0.pos() + (length_bytes * BYTE_LEN);
+        let write_original = self.bits.0.len();
+        self.bits.0.set_len(write_position);
+        let result = f(self);
+        // extend to original position
+        let len = self.bits.0.set_len(write_original);
+        debug_assert_eq!(write_original, len);
+        if result.is_ok() {
+            // on successful read, skip the slice
+            self.bits.0.set_pos(write_position);
+        }
+        result
+    }
+
+    #[inline]
+    pub fn read_bit_field_entry(&mut self, is_opt: bool) -> Result<Option<bool>, Error> {
+        if let Some(scope) = &mut self.scope {
+            scope.read_from_field(&mut self.bits.0, is_opt)
+        } else if is_opt {
+            Some(self.bits.0.read_bit()).transpose()
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Skips the open types of all present extended fields that are unknown to the struct
+    /// being read
+    #[inline]
+    pub fn skip_extension_additions(&mut self, unknown: Range<usize>) -> Result<(), Error> {
+        for flag in unknown {
+            if self
+                .bits
+                .0
+                .with_read_position_at(flag, |buffer| buffer.read_bit())?
+            {
+                let len = self.bits.read_length_determinant(None, None)? as usize * BYTE_LEN;
+                if self.bits.0.remaining() < len {
+                    return Err(Error::EndOfStream);
+                }
+                self.bits.0.set_pos(self.bits.0.pos() + len);
+            }
+        }
+        Ok(())
+    }
+
+    /// Reads the content of an open type, see [`AperWriter::write_open_type`]
+    #[inline]
+    pub fn read_open_type<T, F: FnOnce(&mut Self) -> Result<T, Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<T, Error> {
+        let len = self.bits.read_length_determinant(None, None)?;
+        self.read_whole_sub_slice(len as usize, f)
+    }
+
+    #[inline]
+    pub fn with_buffer<T, F: FnOnce(&mut Self) -> Result<T, Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<T, Error> {
+        if self
+            .scope
+            .as_ref()
+            .map(Scope::encode_as_open_type_field)
+            .unwrap_or(false)
+        {
+            self.scope_stashed(|r| r.read_open_type(f))
+        } else {
+            f(self)
+        }
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 30.5: each character of a known-multiplier
+    /// character string is encoded with the number of bits its permitted alphabet requires,
+    /// rounded up to the next power of two
+    fn read_known_multiplier_string(
+        &mut self,
+        charset: Charset,
+        alphabet: &[(u32, u32)],
+        min: Option<u64>,
+        max: Option<u64>,
+        extensible: bool,
+    ) -> Result<String, Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|r| {
+            let alphabet = Alphabet::new(alphabet, true)?;
+            let (len, aligned) = if extensible && r.bits.0.read_bit()? {
+                (r.bits.read_length_determinant(None, None)?, true)
+            } else {
+                (
+                    r.bits.read_length_determinant(min, max)?,
+                    is_aligned_char_string(max, alphabet.bits() as u64),
+                )
+            };
+
+            if aligned && len > 0 {
+                r.bits.align()?;
+            }
+
+            let mut string = String::with_capacity(len as usize);
+            for _ in 0..len {
+                let mut buffer = [0u8; 4];
+                r.bits
+                    .0
+                    .read_bits_with_offset(&mut buffer[..], 32 - alphabet.bits())?;
+                let character = alphabet
+                    .decode(u32::from_be_bytes(buffer))
+                    .ok_or_else(|| Error::invalid_character_string(charset))?;
+                string.push(character);
+            }
+
+            Ok(string)
+        })
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 32.1: `UTCTime` and `GeneralizedTime` are
+    /// encoded as `VisibleString`
+    fn read_time_string(&mut self, time: TimeType) -> Result<String, Error> {
+        let alphabet = Charset::Visible.alphabet();
+        let value =
+            self.read_known_multiplier_string(Charset::Visible, alphabet, None, None, false)?;
+        if time::is_valid(time, &value) {
+            Ok(value)
+        } else {
+            Err(Error::InvalidTime(time))
+        }
+    }
+}
+
+impl<B: ScopedBitRead> Reader for AperReader<B> {
+    type Error = Error;
+
+    #[inline]
+    fn read_sequence<
+        C: sequence::Constraint,
+        S: Sized,
+        F: Fn(&mut Self) -> Result<S, Self::Error>,
+    >(
+        &mut self,
+        f: F,
+    ) -> Result<S, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|r| {
+            // the sender might know more or less extended fields than this struct does
+            let extensions_present = C::EXTENDED_AFTER_FIELD.is_some() && r.bits.0.read_bit()?;
+
+            // As in UPER, the values for all OPTIONAL flags are written before any field
+            // value is written. This remembers their position, so a later call of `read_opt`
+            // can retrieve them from the buffer
+            if r.bits.0.remaining() < C::STD_OPTIONAL_FIELDS as usize {
+                return Err(Error::EndOfStream);
+            }
+            let range = r.bits.0.pos()..r.bits.0.pos() + C::STD_OPTIONAL_FIELDS as usize;
+            r.bits.0.set_pos(range.end); // skip optional
+
+            if let Some(extension_after) = C::EXTENDED_AFTER_FIELD {
+                let original = r.scope.replace(Scope::ExtensibleSequence {
+                    opt_bit_field: Some(range),
+                    calls_until_ext_bitfield: (extension_after + 1) as usize,
+                    number_of_ext_fields: (C::FIELD_COUNT - (extension_after + 1)) as usize,
+                    extensions_present,
+                });
+                let result = f(r);
+                let scope = core::mem::replace(&mut r.scope, original);
+                let result = result?;
+                let unknown = match scope {
+                    Some(Scope::ExtensionAdditions { unknown, .. }) => unknown,
+                    // there are no known extended fields, which would have read part2 already
+                    Some(Scope::ExtensibleSequence {
+                        extensions_present: true,
+                        ..
+                    }) => match Scope::read_extension_additions(&mut r.bits.0, 0)? {
+                        Scope::ExtensionAdditions { unknown, .. } => unknown,
+                        _ => unreachable!(),
+                    },
+                    _ => 0..0,
+                };
+                r.skip_extension_additions(unknown)?;
+                Ok(result)
+            } else {
+                r.scope_pushed(Scope::OptBitField(range), f)
+            }
+        })
+    }
+
+    #[inline]
+    fn read_sequence_of<C: sequenceof::Constraint, T: ReadableType>(
+        &mut self,
+    ) -> Result<Vec<T::Type>, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|r| {
+            let len = if C::EXTENSIBLE && r.bits.0.read_bit()? {
+                r.bits.read_length_determinant(None, None)?
+            } else {
+                r.bits.read_length_determinant(C::MIN, C::MAX)?
+            };
+            r.scope_stashed(|r| {
+                let mut vec = Vec::with_capacity(len as usize);
+                for _ in 0..len {
+                    vec.push(T::read_value(r)?);
+                }
+                Ok(vec)
+            })
+        })
+    }
+
+    #[inline]
+    fn read_set<C: set::Constraint, S: Sized, F: Fn(&mut Self) -> Result<S, Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<S, Self::Error> {
+        self.read_sequence::<C, S, F>(f)
+    }
+
+    #[inline]
+    fn read_set_of<C: setof::Constraint, T: ReadableType>(
+        &mut self,
+    ) -> Result<Vec<<T as ReadableType>::Type>, Self::Error> {
+        self.read_sequence_of::<C, T>()
+    }
+
+    #[inline]
+    fn read_enumerated<C: enumerated::Constraint>(&mut self) -> Result<C, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|r| {
+            r.bits
+                .read_enumeration_index(C::STD_VARIANT_COUNT, C::EXTENSIBLE)
+        })
+        .and_then(|index| {
+            C::from_choice_index(index).ok_or(Error::InvalidChoiceIndex(index, C::VARIANT_COUNT))
+        })
+    }
+
+    #[inline]
+    fn read_choice<C: choice::Constraint>(&mut self) -> Result<C, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|r| {
+            r.scope_stashed(|r| {
+                let index = r
+                    .bits
+                    .read_choice_index(C::STD_VARIANT_COUNT, C::EXTENSIBLE)?;
+                if index >= C::STD_VARIANT_COUNT {
+                    r.read_open_type(|r| Ok((index, C::read_content(index, r)?)))
+                } else {
+                    Ok((index, C::read_content(index, r)?))
+                }
+                .and_then(|(index, content)| {
+                    content.ok_or(Error::InvalidChoiceIndex(index, C::VARIANT_COUNT))
+                })
+            })
+        })
+    }
+
+    #[inline]
+    fn read_open_type<C: opentype::Constraint>(&mut self, key: i64) -> Result<C, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|r| {
+            r.scope_stashed(|r| {
+                let bytes = r.bits.read_octetstring(None, None, false)?;
+                match C::read_content(key, &mut AperReader::from(&bytes[..]))? {
+                    Some(value) => Ok(Some(value)),
+                    // the value of a key that is unknown to the object set
+                    None => Ok(C::from_unknown(key, bytes)),
+                }
+                .and_then(|value| value.ok_or(Error::UnknownOpenTypeKey(key)))
+            })
+        })
+    }
+
+    #[inline]
+    fn read_opt<T: ReadableType>(
+        &mut self,
+    ) -> Result<Option<<T as ReadableType>::Type>, Self::Error> {
+        // unwrap: as opt-field this must and will return some value
+        if self.read_bit_field_entry(true)?.unwrap() {
+            self.with_buffer(|r| r.scope_stashed(T::read_value))
+                .map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    #[inline]
+    #[allow(clippy::redundant_pattern_matching)] // allow for const_*!
+    fn read_number<T: numbers::Number, C: numbers::Constraint<T>>(
+        &mut self,
+    ) -> Result<T, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|r| {
+            if C::EXTENSIBLE && r.bits.0.read_bit()? {
+                return r.bits.read_unconstrained_whole_number().map(T::from_i64);
+            }
+
+            match (C::MIN, C::MAX) {
+                (Some(min), Some(max)) => r.bits.read_constrained_whole_number(min, max),
+                (Some(min), None) => r.bits.read_semi_constrained_whole_number(min),
+                (None, _) => r.bits.read_unconstrained_whole_number(),
+            }
+            .map(T::from_i64)
+        })
+    }
+
+    #[inline]
+    fn read_wide_number<T: numbers::WideNumber, C: numbers::WideConstraint>(
+        &mut self,
+    ) -> Result<T, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|r| {
+            crate::io::per::wide::read_wide_whole_number(&mut r.bits, C::MIN, C::MAX, C::EXTENSIBLE)
+        })
+    }
+
+    #[inline]
+    fn read_utf8string<C: utf8string::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|r| {
+            // ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 30.3
+            // For 'known-multiplier character string types' there is no min/max in the encoding
+            let octets = r.bits.read_octetstring(None, None, false)?;
+            String::from_utf8(octets).map_err(|_| Self::Error::InvalidUtf8String)
+        })
+    }
+
+    #[inline]
+    fn read_ia5string<C: ia5string::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_known_multiplier_string(Charset::Ia5, C::ALPHABET, C::MIN, C::MAX, C::EXTENSIBLE)
+    }
+
+    #[inline]
+    fn read_numericstring<C: numericstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_known_multiplier_string(
+            Charset::Numeric,
+            C::ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )
+    }
+
+    #[inline]
+    fn read_printablestring<C: printablestring::Constraint>(
+        &mut self,
+    ) -> Result<String, Self::Error> {
+        self.read_known_multiplier_string(
+            Charset::Printable,
+            C::ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )
+    }
+
+    #[inline]
+    fn read_visiblestring<C: visiblestring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_known_multiplier_string(
+            Charset::Visible,
+            C::ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )
+    }
+
+    #[inline]
+    fn read_bmpstring<C: bmpstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_known_multiplier_string(Charset::Bmp, C::ALPHABET, C::MIN, C::MAX, C::EXTENSIBLE)
+    }
+
+    #[inline]
+    fn read_universalstring<C: universalstring::Constraint>(
+        &mut self,
+    ) -> Result<String, Self::Error> {
+        self.read_known_multiplier_string(
+            Charset::Universal,
+            C::ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )
+    }
+
+    #[inline]
+    fn read_octet_string<C: octetstring::Constraint>(&mut self) -> Result<Vec<u8>, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|r| r.bits.read_octetstring(C::MIN, C::MAX, C::EXTENSIBLE))
+    }
+
+    #[inline]
+    fn read_bit_string<C: bitstring::Constraint>(&mut self) -> Result<(Vec<u8>, u64), Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|r| r.bits.read_bitstring(C::MIN, C::MAX, C::EXTENSIBLE))
+    }
+
+    #[inline]
+    fn read_boolean<C: boolean::Constraint>(&mut self) -> Result<bool, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|r| r.bits.read_boolean())
+    }
+
+    #[inline]
+    fn read_null<C: null::Constraint>(&mut self) -> Result<(), Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|_| Ok(()))
+    }
+
+    #[inline]
+    fn read_real<C: real::Constraint>(&mut self) -> Result<f64, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        let octets = self.with_buffer(|r| r.bits.read_octetstring(None, None, false))?;
+        crate::io::real::from_octets(&octets).ok_or(Error::InvalidReal)
+    }
+
+    #[inline]
+    fn read_object_identifier<C: objectidentifier::Constraint>(
+        &mut self,
+    ) -> Result<Vec<u64>, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        let octets = self.with_buffer(|r| r.bits.read_octetstring(None, None, false))?;
+        crate::io::oid::object_identifier_from_octets(&octets).ok_or(Error::InvalidObjectIdentifier)
+    }
+
+    #[inline]
+    fn read_relative_oid<C: relativeoid::Constraint>(&mut self) -> Result<Vec<u64>, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        let octets = self.with_buffer(|r| r.bits.read_octetstring(None, None, false))?;
+        crate::io::oid::relative_oid_from_octets(&octets).ok_or(Error::InvalidObjectIdentifier)
+    }
+
+    #[inline]
+    fn read_utc_time<C: utctime::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_time_string(TimeType::UtcTime)
+    }
+
+    #[inline]
+    fn read_generalized_time<C: generalizedtime::Constraint>(
+        &mut self,
+    ) -> Result<String, Self::Error> {
+        self.read_time_string(TimeType::GeneralizedTime)
+    }
+
+    #[inline]
+    fn read_date<C: date::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|r| crate::io::per::time::read_date(&mut r.bits))
+            .map(|date| date.to_string())
+    }
+
+    #[inline]
+    fn read_time_of_day<C: timeofday::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|r| crate::io::per::time::read_time_of_day(&mut r.bits))
+            .map(|time| time.to_string())
+    }
+
+    #[inline]
+    fn read_date_time<C: datetime::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|r| crate::io::per::time::read_date_time(&mut r.bits))
+            .map(|date_time| date_time.to_string())
+    }
+
+    #[inline]
+    fn read_duration<C: duration::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|r| crate::io::per::time::read_duration(&mut r.bits))
+            .map(|duration| duration.to_string())
+    }
+
+    #[inline]
+    fn read_with_components<C: components::Constraint, T: ReadableType>(
+        &mut self,
+    ) -> Result<T::Type, Self::Error>
+    where
+        T::Type: components::Components,
+    {
+        let value = T::read_value(self)?;
+        C::COMPONENTS
+            .check(&value)
+            .map_err(Error::InvalidComponents)?;
+        Ok(value)
+    }
+
+    #[inline]
+    fn read_containing<C: containing::Constraint, T: ReadableType>(
+        &mut self,
+    ) -> Result<T::Type, Self::Error> {
+        let bytes = containing::read_contents::<C, _>(self)?;
+        T::read_value(&mut AperReader::from((&bytes[..], bytes.len() * BYTE_LEN)))
+    }
+
+    #[inline]
+    fn read_explicit<C: explicit::Constraint, T: ReadableType>(
+        &mut self,
+    ) -> Result<T::Type, Self::Error> {
+        // tags are not encoded
+        T::read_value(self)
+    }
+}
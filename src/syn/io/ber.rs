@@ -0,0 +1,1316 @@
+use crate::io::ber::err::Error;
+use crate::io::ber::{self, Length, END_OF_CONTENTS};
+use crate::io::charset;
+use crate::model::{Charset, Tag, TimeType};
+use crate::syn::*;
+use core::cmp::Ordering;
+use core::marker::PhantomData;
+
+#[inline]
+fn check_size(
+    min: Option<u64>,
+    max: Option<u64>,
+    extensible: bool,
+    size: u64,
+) -> Result<(), Error> {
+    let (min, max) = (min.unwrap_or(0), max.unwrap_or(u64::MAX));
+    if extensible || (min..=max).contains(&size) {
+        Ok(())
+    } else {
+        Err(Error::SizeNotInRange(size, min, max))
+    }
+}
+
+#[inline]
+fn check_range(
+    min: Option<i64>,
+    max: Option<i64>,
+    extensible: bool,
+    value: i64,
+) -> Result<(), Error> {
+    let (min, max) = (min.unwrap_or(i64::MIN), max.unwrap_or(i64::MAX));
+    if extensible || (min..=max).contains(&value) {
+        Ok(())
+    } else {
+        Err(Error::ValueNotInRange(value, min, max))
+    }
+}
+
+fn check_wide_range<T: numbers::WideNumber>(
+    min: Option<i128>,
+    max: Option<i128>,
+    extensible: bool,
+    value: &T,
+) -> Result<(), Error> {
+    if extensible || value.is_within(min, max) {
+        Ok(())
+    } else {
+        Err(Error::WideValueNotInRange(value.to_string(), min, max))
+    }
+}
+
+#[inline]
+fn check_permitted(
+    permitted: bool,
+    extensible: bool,
+    value: &impl core::fmt::Display,
+) -> Result<(), Error> {
+    if extensible || permitted {
+        Ok(())
+    } else {
+        Err(Error::ValueNotPermitted(value.to_string()))
+    }
+}
+
+/// The flavour of the TLV-based encoding rules that is applied by the [`TlvWriter`] and
+/// [`TlvReader`]
+pub trait EncodingRules: Default {
+    /// Whether the restrictions of the Distinguished Encoding Rules (DER) apply, see ITU-T X.690
+    /// | ISO/IEC 8825-1:2015, chapter 10 and 11
+    const DISTINGUISHED: bool;
+}
+
+/// The Basic Encoding Rules (BER)
+#[derive(Default)]
+pub struct Basic;
+
+impl EncodingRules for Basic {
+    const DISTINGUISHED: bool = false;
+}
+
+/// The Distinguished Encoding Rules (DER)
+#[derive(Default)]
+pub struct Distinguished;
+
+impl EncodingRules for Distinguished {
+    const DISTINGUISHED: bool = true;
+}
+
+/// Encodes values according to the Basic Encoding Rules (BER), see [`TlvWriter`]
+pub type BerWriter = TlvWriter<Basic>;
+
+/// Encodes values according to the Distinguished Encoding Rules (DER), see [`TlvWriter`]
+pub type DerWriter = TlvWriter<Distinguished>;
+
+/// Encodes values according to the Basic Encoding Rules (BER), see ITU-T X.690 | ISO/IEC
+/// 8825-1:2015. All lengths are written in the definite form and all strings in the primitive
+/// form. The tag of a field (the `C::TAG` of a [`complex::Constraint`]) replaces the tag of the
+/// referenced type (IMPLICIT tagging), except for `CHOICE` types, which are explicitly tagged.
+/// The explicit tag of a `SEQUENCE`, `SET`, `ENUMERATED` or `CHOICE` type assignment (`EXPLICIT`
+/// of their constraint) wraps the encoding of the type with its universal tag.
+///
+/// This already satisfies most of the Distinguished Encoding Rules (DER). For
+/// [`Distinguished`], the elements of a `SET OF` are additionally sorted by their encoding. The
+/// components of a `SET` are sorted by their tag regardless, see `TagResolver`.
+#[derive(Default)]
+pub struct TlvWriter<R: EncodingRules> {
+    buffer: Vec<u8>,
+    tag: Option<Tag>,
+    rules: PhantomData<R>,
+}
+
+impl<R: EncodingRules> TlvWriter<R> {
+    pub fn with_capacity(capacity_bytes: usize) -> Self {
+        Self {
+            buffer: Vec::with_capacity(capacity_bytes),
+            ..Default::default()
+        }
+    }
+
+    pub fn byte_content(&self) -> &[u8] {
+        &self.buffer
+    }
+
+    pub fn into_bytes_vec(self) -> Vec<u8> {
+        self.buffer
+    }
+
+    pub fn as_reader(&self) -> TlvReader<'_, R> {
+        TlvReader::from(self.byte_content())
+    }
+
+    /// Returns the pending tag of the field that is currently being written or the given
+    /// default tag of the type.
+    #[inline]
+    fn take_tag(&mut self, default: Tag) -> Tag {
+        self.tag.take().unwrap_or(default)
+    }
+
+    /// ITU-T X.690 | ISO/IEC 8825-1:2015, chapter 8.25 and 8.26, the time types are encoded as
+    /// the characters of their value notation
+    fn write_time(&mut self, tag: Tag, time: TimeType, value: &str) -> Result<(), Error> {
+        if !crate::io::time::is_valid(time, value) {
+            return Err(Error::InvalidTime(time));
+        }
+        let tag = self.take_tag(tag);
+        self.write_primitive(tag, value.as_bytes());
+        Ok(())
+    }
+
+    #[inline]
+    pub fn write_primitive(&mut self, tag: Tag, content: &[u8]) {
+        ber::write_identifier(&mut self.buffer, tag, false);
+        ber::write_length(&mut self.buffer, content.len());
+        self.buffer.extend_from_slice(content);
+    }
+
+    /// Writes the contents written by the given closure as constructed element. Because the
+    /// length of the contents is unknown beforehand, the contents are buffered.
+    #[inline]
+    pub fn write_constructed<F: FnOnce(&mut Self) -> Result<(), Error>>(
+        &mut self,
+        tag: Tag,
+        f: F,
+    ) -> Result<(), Error> {
+        let outer = core::mem::take(&mut self.buffer);
+        let result = f(self);
+        let content = core::mem::replace(&mut self.buffer, outer);
+        result?;
+        ber::write_identifier(&mut self.buffer, tag, true);
+        ber::write_length(&mut self.buffer, content.len());
+        self.buffer.extend_from_slice(&content);
+        Ok(())
+    }
+
+    /// Writes a `SEQUENCE` or `SET` with the given universal tag, which is wrapped by the
+    /// explicit tag of its type assignment, if any
+    fn write_sequence_or_set<C: sequence::Constraint, F: Fn(&mut Self) -> Result<(), Error>>(
+        &mut self,
+        universal: Tag,
+        f: F,
+    ) -> Result<(), Error> {
+        if C::TRANSPARENT {
+            // the pending tag belongs to the single wrapped field
+            f(self)
+        } else {
+            let tag = self.take_tag(C::TAG);
+            if C::EXPLICIT {
+                self.write_constructed(tag, |w| w.write_constructed(universal, f))
+            } else {
+                self.write_constructed(tag, f)
+            }
+        }
+    }
+
+    /// ITU-T X.690 | ISO/IEC 8825-1:2015, chapter 8.23, writes the characters of a
+    /// known-multiplier character string as octets of a fixed width
+    fn write_known_multiplier_string(
+        &mut self,
+        tag: Tag,
+        charset: Charset,
+        min: Option<u64>,
+        max: Option<u64>,
+        extensible: bool,
+        value: &str,
+    ) -> Result<(), Error> {
+        let content =
+            charset::to_octets(charset, value).ok_or(Error::InvalidCharacterString(charset))?;
+        check_size(min, max, extensible, value.chars().count() as u64)?;
+        self.write_primitive(tag, &content);
+        Ok(())
+    }
+}
+
+impl<R: EncodingRules> Writer for TlvWriter<R> {
+    type Error = Error;
+
+    #[inline]
+    fn write_sequence<C: sequence::Constraint, F: Fn(&mut Self) -> Result<(), Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<(), Self::Error> {
+        self.write_sequence_or_set::<C, F>(Tag::DEFAULT_SEQUENCE, f)
+    }
+
+    #[inline]
+    fn write_sequence_of<C: sequenceof::Constraint, T: WritableType>(
+        &mut self,
+        slice: &[<T as WritableType>::Type],
+    ) -> Result<(), Self::Error> {
+        let tag = self.take_tag(C::TAG);
+        check_size(C::MIN, C::MAX, C::EXTENSIBLE, slice.len() as u64)?;
+        self.write_constructed(tag, |w| {
+            for value in slice {
+                T::write_value(w, value)?;
+            }
+            Ok(())
+        })
+    }
+
+    #[inline]
+    fn write_set<C: set::Constraint, F: Fn(&mut Self) -> Result<(), Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<(), Self::Error> {
+        self.write_sequence_or_set::<C, F>(Tag::DEFAULT_SET, f)
+    }
+
+    #[inline]
+    fn write_set_of<C: setof::Constraint, T: WritableType>(
+        &mut self,
+        slice: &[<T as WritableType>::Type],
+    ) -> Result<(), Self::Error> {
+        if !R::DISTINGUISHED {
+            return self.write_sequence_of::<C, T>(slice);
+        }
+        let tag = self.take_tag(C::TAG);
+        check_size(C::MIN, C::MAX, C::EXTENSIBLE, slice.len() as u64)?;
+        self.write_constructed(tag, |w| {
+            // ITU-T X.690 | ISO/IEC 8825-1:2015, chapter 11.6, the elements are sorted by their
+            // encoding, therefore each element is encoded separately first
+            let mut encodings = Vec::with_capacity(slice.len());
+            for value in slice {
+                let outer = core::mem::take(&mut w.buffer);
+                let result = T::write_value(w, value);
+                encodings.push(core::mem::replace(&mut w.buffer, outer));
+                result?;
+            }
+            encodings.sort_by(|a, b| ber::cmp_set_of_encodings(a, b));
+            for encoding in encodings {
+                w.buffer.extend_from_slice(&encoding);
+            }
+            Ok(())
+        })
+    }
+
+    #[inline]
+    fn write_enumerated<C: enumerated::Constraint>(
+        &mut self,
+        enumerated: &C,
+    ) -> Result<(), Self::Error> {
+        let tag = self.take_tag(C::TAG);
+        let value = enumerated
+            .to_enumeration_value()
+            .ok_or(Error::InvalidEnumeratedValue(
+                enumerated.to_choice_index() as i64
+            ))?;
+        let mut content = Vec::with_capacity(1);
+        ber::write_integer_content(&mut content, value);
+        if C::EXPLICIT {
+            self.write_constructed(tag, |w| {
+                w.write_primitive(Tag::DEFAULT_ENUMERATED, &content);
+                Ok(())
+            })
+        } else {
+            self.write_primitive(tag, &content);
+            Ok(())
+        }
+    }
+
+    #[inline]
+    fn write_choice<C: choice::Constraint>(&mut self, choice: &C) -> Result<(), Self::Error> {
+        // ITU-T X.680 | ISO/IEC 8824-1, 31.2.7: a tagged CHOICE is always explicitly tagged
+        match self.tag.take() {
+            Some(tag) if tag != C::TAG => self.write_constructed(tag, |w| choice.write_content(w)),
+            _ if C::EXPLICIT => self.write_constructed(C::TAG, |w| choice.write_content(w)),
+            _ => choice.write_content(self),
+        }
+    }
+
+    #[inline]
+    fn write_open_type<C: opentype::Constraint>(
+        &mut self,
+        value: &C,
+        key: i64,
+    ) -> Result<(), Self::Error> {
+        if value.key() != key {
+            return Err(Error::MismatchedOpenTypeKey(key, value.key()));
+        }
+        // the value of an open type is encoded with its own tag
+        self.tag = None;
+        if let Some(encoding) = value.unknown() {
+            self.buffer.extend_from_slice(encoding);
+            Ok(())
+        } else {
+            value.write_content(self)
+        }
+    }
+
+    #[inline]
+    fn write_opt<T: WritableType>(
+        &mut self,
+        value: Option<&<T as WritableType>::Type>,
+    ) -> Result<(), Self::Error> {
+        if let Some(value) = value {
+            T::write_value(self, value)
+        } else {
+            Ok(())
+        }
+    }
+
+    #[inline]
+    fn write_number<T: numbers::Number, C: numbers::Constraint<T>>(
+        &mut self,
+        value: T,
+    ) -> Result<(), Self::Error> {
+        let tag = self.take_tag(C::TAG);
+        let value = value.to_i64();
+        check_range(C::MIN, C::MAX, C::EXTENSIBLE, value)?;
+        check_permitted(C::permits(value), C::EXTENSIBLE, &value)?;
+        let mut content = Vec::with_capacity(8);
+        ber::write_integer_content(&mut content, value);
+        self.write_primitive(tag, &content);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_wide_number<T: numbers::WideNumber, C: numbers::WideConstraint>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        let tag = self.take_tag(C::TAG);
+        check_wide_range(C::MIN, C::MAX, C::EXTENSIBLE, value)?;
+        check_permitted(C::permits(value), C::EXTENSIBLE, value)?;
+        self.write_primitive(tag, &value.to_signed_octets());
+        Ok(())
+    }
+
+    #[inline]
+    fn write_utf8string<C: utf8string::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        let tag = self.take_tag(C::TAG);
+        check_size(C::MIN, C::MAX, C::EXTENSIBLE, value.chars().count() as u64)?;
+        self.write_primitive(tag, value.as_bytes());
+        Ok(())
+    }
+
+    #[inline]
+    fn write_ia5string<C: ia5string::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        let tag = self.take_tag(C::TAG);
+        if !value.is_ascii() {
+            return Err(Error::InvalidIa5String);
+        }
+        check_size(C::MIN, C::MAX, C::EXTENSIBLE, value.len() as u64)?;
+        self.write_primitive(tag, value.as_bytes());
+        Ok(())
+    }
+
+    #[inline]
+    fn write_numericstring<C: numericstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        let tag = self.take_tag(C::TAG);
+        self.write_known_multiplier_string(
+            tag,
+            Charset::Numeric,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            value,
+        )
+    }
+
+    #[inline]
+    fn write_printablestring<C: printablestring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        let tag = self.take_tag(C::TAG);
+        self.write_known_multiplier_string(
+            tag,
+            Charset::Printable,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            value,
+        )
+    }
+
+    #[inline]
+    fn write_visiblestring<C: visiblestring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        let tag = self.take_tag(C::TAG);
+        self.write_known_multiplier_string(
+            tag,
+            Charset::Visible,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            value,
+        )
+    }
+
+    #[inline]
+    fn write_bmpstring<C: bmpstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        let tag = self.take_tag(C::TAG);
+        self.write_known_multiplier_string(tag, Charset::Bmp, C::MIN, C::MAX, C::EXTENSIBLE, value)
+    }
+
+    #[inline]
+    fn write_universalstring<C: universalstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        let tag = self.take_tag(C::TAG);
+        self.write_known_multiplier_string(
+            tag,
+            Charset::Universal,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            value,
+        )
+    }
+
+    #[inline]
+    fn write_octet_string<C: octetstring::Constraint>(
+        &mut self,
+        value: &[u8],
+    ) -> Result<(), Self::Error> {
+        let tag = self.take_tag(C::TAG);
+        check_size(C::MIN, C::MAX, C::EXTENSIBLE, value.len() as u64)?;
+        self.write_primitive(tag, value);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_bit_string<C: bitstring::Constraint>(
+        &mut self,
+        value: &[u8],
+        bit_len: u64,
+    ) -> Result<(), Self::Error> {
+        let tag = self.take_tag(C::TAG);
+        check_size(C::MIN, C::MAX, C::EXTENSIBLE, bit_len)?;
+        // ITU-T X.690 | ISO/IEC 8825-1:2015, chapter 8.6.2, the initial octet holds the amount
+        // of unused bits in the final octet, which are written as zero
+        let byte_len = bit_len.div_ceil(8) as usize;
+        let unused_bits = (byte_len * 8) as u64 - bit_len;
+        let bytes = value.get(..byte_len).ok_or(Error::EndOfStream)?;
+        let mut content = Vec::with_capacity(byte_len + 1);
+        content.push(unused_bits as u8);
+        content.extend_from_slice(bytes);
+        if let Some(last) = content.last_mut().filter(|_| byte_len > 0) {
+            *last &= 0xFF << unused_bits;
+        }
+        self.write_primitive(tag, &content);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_boolean<C: boolean::Constraint>(&mut self, value: bool) -> Result<(), Self::Error> {
+        let tag = self.take_tag(C::TAG);
+        self.write_primitive(tag, &[if value { 0xFF } else { 0x00 }]);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_null<C: null::Constraint>(&mut self) -> Result<(), Self::Error> {
+        let tag = self.take_tag(C::TAG);
+        self.write_primitive(tag, &[]);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_real<C: real::Constraint>(&mut self, value: f64) -> Result<(), Self::Error> {
+        let tag = self.take_tag(C::TAG);
+        self.write_primitive(tag, &crate::io::real::to_octets(value));
+        Ok(())
+    }
+
+    #[inline]
+    fn write_object_identifier<C: objectidentifier::Constraint>(
+        &mut self,
+        value: &[u64],
+    ) -> Result<(), Self::Error> {
+        let tag = self.take_tag(C::TAG);
+        let content = crate::io::oid::object_identifier_to_octets(value)
+            .ok_or(Error::InvalidObjectIdentifier)?;
+        self.write_primitive(tag, &content);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_relative_oid<C: relativeoid::Constraint>(
+        &mut self,
+        value: &[u64],
+    ) -> Result<(), Self::Error> {
+        let tag = self.take_tag(C::TAG);
+        self.write_primitive(tag, &crate::io::oid::relative_oid_to_octets(value));
+        Ok(())
+    }
+
+    #[inline]
+    fn write_utc_time<C: utctime::Constraint>(&mut self, value: &str) -> Result<(), Self::Error> {
+        self.write_time(C::TAG, TimeType::UtcTime, value)
+    }
+
+    #[inline]
+    fn write_generalized_time<C: generalizedtime::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_time(C::TAG, TimeType::GeneralizedTime, value)
+    }
+
+    #[inline]
+    fn write_date<C: date::Constraint>(&mut self, value: &str) -> Result<(), Self::Error> {
+        self.write_time(C::TAG, TimeType::Date, value)
+    }
+
+    #[inline]
+    fn write_time_of_day<C: timeofday::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_time(C::TAG, TimeType::TimeOfDay, value)
+    }
+
+    #[inline]
+    fn write_date_time<C: datetime::Constraint>(&mut self, value: &str) -> Result<(), Self::Error> {
+        self.write_time(C::TAG, TimeType::DateTime, value)
+    }
+
+    #[inline]
+    fn write_duration<C: duration::Constraint>(&mut self, value: &str) -> Result<(), Self::Error> {
+        self.write_time(C::TAG, TimeType::Duration, value)
+    }
+
+    #[inline]
+    fn write_with_components<C: components::Constraint, T: WritableType>(
+        &mut self,
+        value: &T::Type,
+    ) -> Result<(), Self::Error>
+    where
+        T::Type: components::Components,
+    {
+        C::COMPONENTS
+            .check(value)
+            .map_err(Error::InvalidComponents)?;
+        T::write_value(self, value)
+    }
+
+    #[inline]
+    fn write_containing<C: containing::Constraint, T: WritableType>(
+        &mut self,
+        value: &T::Type,
+    ) -> Result<(), Self::Error> {
+        let mut writer = TlvWriter::<R>::default();
+        T::write_value(&mut writer, value)?;
+        containing::write_contents::<C, _>(self, writer.byte_content())
+    }
+
+    #[inline]
+    fn write_explicit<C: explicit::Constraint, T: WritableType>(
+        &mut self,
+        value: &T::Type,
+    ) -> Result<(), Self::Error> {
+        // ITU-T X.690 | ISO/IEC 8825-1:2015, chapter 8.14.2: the encoding of the value with its
+        // own tag is the content of a constructed element
+        let tag = self.take_tag(C::TAG);
+        self.write_constructed(tag, |w| T::write_value(w, value))
+    }
+
+    #[inline]
+    fn write_complex<C: complex::Constraint, F: Fn(&mut Self) -> Result<(), Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<(), Self::Error> {
+        // the outermost tag wins, see ITU-T X.680 | ISO/IEC 8824-1, 31.2.6 (IMPLICIT)
+        if self.tag.is_none() {
+            self.tag = Some(C::TAG);
+        }
+        let result = f(self);
+        self.tag = None;
+        result
+    }
+}
+
+/// Decodes values according to the Basic Encoding Rules (BER), see [`TlvReader`]
+pub type BerReader<'a> = TlvReader<'a, Basic>;
+
+/// Decodes values according to the Distinguished Encoding Rules (DER), see [`TlvReader`]
+pub type DerReader<'a> = TlvReader<'a, Distinguished>;
+
+/// Decodes values according to the Basic Encoding Rules (BER), see [`TlvWriter`] for further
+/// details. Besides the encoding produced by the [`TlvWriter`], elements with an indefinite
+/// length and strings in the constructed form are understood as well. Unknown elements at the
+/// end of an extensible `SEQUENCE` are skipped. The components of a `SET` are expected in the
+/// canonical order of their tags.
+///
+/// For [`Distinguished`], all encodings that do not satisfy the restrictions of the
+/// Distinguished Encoding Rules (DER) are rejected.
+pub struct TlvReader<'a, R: EncodingRules> {
+    bytes: &'a [u8],
+    pos: usize,
+    end: usize,
+    indefinite: bool,
+    tag: Option<Tag>,
+    rules: PhantomData<R>,
+}
+
+impl<'a, R: EncodingRules> From<&'a [u8]> for TlvReader<'a, R> {
+    fn from(bytes: &'a [u8]) -> Self {
+        Self {
+            bytes,
+            pos: 0,
+            end: bytes.len(),
+            indefinite: false,
+            tag: None,
+            rules: PhantomData,
+        }
+    }
+}
+
+impl<'a, R: EncodingRules> TlvReader<'a, R> {
+    /// The amount of bytes left in the contents of the current element
+    #[inline]
+    pub fn bytes_remaining(&self) -> usize {
+        self.end - self.pos
+    }
+
+    #[inline]
+    fn take_tag(&mut self, default: Tag) -> Tag {
+        self.tag.take().unwrap_or(default)
+    }
+
+    /// ITU-T X.690 | ISO/IEC 8825-1:2015, chapter 8.25 and 8.26
+    fn read_time(&mut self, tag: Tag, time: TimeType) -> Result<String, Error> {
+        let tag = self.take_tag(tag);
+        String::from_utf8(self.read_string_content(tag)?)
+            .ok()
+            .filter(|value| crate::io::time::is_valid(time, value))
+            .ok_or(Error::InvalidTime(time))
+    }
+
+    /// Whether all elements of the contents of the current element have been read
+    #[inline]
+    pub fn is_at_end_of_contents(&self) -> bool {
+        if self.indefinite {
+            self.bytes[self.pos..self.end].starts_with(&END_OF_CONTENTS)
+        } else {
+            self.pos >= self.end
+        }
+    }
+
+    fn read_header(&mut self) -> Result<(Tag, bool, Length), Error> {
+        if self.is_at_end_of_contents() {
+            return Err(Error::EndOfStream);
+        }
+        let (tag, constructed, identifier_len) =
+            ber::read_identifier(&self.bytes[self.pos..self.end])?;
+        let (length, length_len) =
+            ber::read_length(&self.bytes[self.pos + identifier_len..self.end])?;
+        if length == Length::Indefinite && (!constructed || R::DISTINGUISHED) {
+            return Err(Error::IndefiniteLengthNotAllowed(tag));
+        }
+        if R::DISTINGUISHED {
+            // ITU-T X.690 | ISO/IEC 8825-1:2015, chapter 8.1.2.4.2 and 10.1
+            let mut minimal = Vec::new();
+            ber::write_identifier(&mut minimal, tag, constructed);
+            if minimal.len() != identifier_len {
+                return Err(Error::NonMinimalIdentifier(tag));
+            }
+            if let Length::Definite(length) = length {
+                minimal.clear();
+                ber::write_length(&mut minimal, length);
+                if minimal.len() != length_len {
+                    return Err(Error::NonMinimalLength(length));
+                }
+            }
+        }
+        self.pos += identifier_len + length_len;
+        Ok((tag, constructed, length))
+    }
+
+    /// Reads the identifier and length octets of the next element, which must have the given
+    /// tag. On a mismatch, the position is left unchanged.
+    fn read_header_of(&mut self, expected: Tag) -> Result<(bool, Length), Error> {
+        let pos = self.pos;
+        let (tag, constructed, length) = self.read_header()?;
+        if tag == expected {
+            Ok((constructed, length))
+        } else {
+            self.pos = pos;
+            Err(Error::UnexpectedTag(expected, tag))
+        }
+    }
+
+    fn read_definite_content(&mut self, length: usize) -> Result<&'a [u8], Error> {
+        let end = self.pos.checked_add(length).ok_or(Error::EndOfStream)?;
+        if end > self.end {
+            Err(Error::EndOfStream)
+        } else {
+            let content = &self.bytes[self.pos..end];
+            self.pos = end;
+            Ok(content)
+        }
+    }
+
+    /// Reads the contents of the next element, which must be in the primitive form
+    pub fn read_primitive(&mut self, tag: Tag) -> Result<&'a [u8], Error> {
+        match self.read_header_of(tag)? {
+            (false, Length::Definite(length)) => self.read_definite_content(length),
+            (constructed, _) => Err(Error::UnexpectedEncodingForm(tag, constructed)),
+        }
+    }
+
+    /// Reads the contents of the next element, which must be in the constructed form, through
+    /// the given closure
+    pub fn read_constructed<T, F: FnOnce(&mut Self) -> Result<T, Error>>(
+        &mut self,
+        tag: Tag,
+        f: F,
+    ) -> Result<T, Error> {
+        match self.read_header_of(tag)? {
+            (true, length) => self.with_contents(length, f),
+            (false, _) => Err(Error::UnexpectedEncodingForm(tag, false)),
+        }
+    }
+
+    fn with_contents<T, F: FnOnce(&mut Self) -> Result<T, Error>>(
+        &mut self,
+        length: Length,
+        f: F,
+    ) -> Result<T, Error> {
+        let (end, indefinite) = (self.end, self.indefinite);
+        if let Length::Definite(length) = length {
+            self.end = self
+                .pos
+                .checked_add(length)
+                .filter(|new_end| *new_end <= end)
+                .ok_or(Error::EndOfStream)?;
+            self.indefinite = false;
+        } else {
+            self.indefinite = true;
+        }
+
+        let result = f(self).and_then(|value| {
+            if self.indefinite {
+                if self.is_at_end_of_contents() {
+                    self.pos += END_OF_CONTENTS.len();
+                    Ok(value)
+                } else {
+                    Err(Error::MissingEndOfContents)
+                }
+            } else if self.pos < self.end {
+                Err(Error::UnexpectedTrailingContent(self.end - self.pos))
+            } else {
+                Ok(value)
+            }
+        });
+
+        self.end = end;
+        self.indefinite = indefinite;
+        result
+    }
+
+    /// Skips the next element, regardless of its tag
+    pub fn skip_element(&mut self) -> Result<(), Error> {
+        match self.read_header()? {
+            (_, _, Length::Definite(length)) => self.read_definite_content(length).map(drop),
+            (_, _, Length::Indefinite) => {
+                self.with_contents(Length::Indefinite, Self::skip_remaining)
+            }
+        }
+    }
+
+    /// Skips all remaining elements of the contents of the current element
+    pub fn skip_remaining(&mut self) -> Result<(), Error> {
+        while !self.is_at_end_of_contents() {
+            self.skip_element()?;
+        }
+        Ok(())
+    }
+
+    /// ITU-T X.690 | ISO/IEC 8825-1:2015, chapter 8.7.3, reads the contents of a string type
+    /// that is either encoded in the primitive or the constructed form, in which the segments
+    /// are `OCTET STRING`s
+    fn read_string_content(&mut self, tag: Tag) -> Result<Vec<u8>, Error> {
+        match self.read_header_of(tag)? {
+            (false, Length::Definite(length)) => Ok(self.read_definite_content(length)?.to_vec()),
+            (false, Length::Indefinite) => Err(Error::IndefiniteLengthNotAllowed(tag)),
+            // ITU-T X.690 | ISO/IEC 8825-1:2015, chapter 10.2
+            (true, _) if R::DISTINGUISHED => Err(Error::UnexpectedEncodingForm(tag, true)),
+            (true, length) => self.with_contents(length, |r| {
+                let mut content = Vec::new();
+                while !r.is_at_end_of_contents() {
+                    content.extend(r.read_string_content(Tag::DEFAULT_OCTET_STRING)?);
+                }
+                Ok(content)
+            }),
+        }
+    }
+
+    /// ITU-T X.690 | ISO/IEC 8825-1:2015, chapter 8.6, reads the contents of a bitstring that is
+    /// either encoded in the primitive or the constructed form, in which only the last segment
+    /// might have unused bits
+    fn read_bit_string_content(&mut self, tag: Tag) -> Result<(Vec<u8>, u64), Error> {
+        match self.read_header_of(tag)? {
+            (false, Length::Definite(length)) => {
+                let content = self.read_definite_content(length)?;
+                let (unused_bits, bytes) = content
+                    .split_first()
+                    .ok_or(Error::InvalidContentLength(tag, 0))?;
+                if *unused_bits > 7 || (bytes.is_empty() && *unused_bits > 0) {
+                    Err(Error::InvalidUnusedBitsCount(*unused_bits))
+                } else if R::DISTINGUISHED
+                    // ITU-T X.690 | ISO/IEC 8825-1:2015, chapter 11.2.1
+                    && bytes.last().is_some_and(|last| last & !(0xFF << unused_bits) != 0)
+                {
+                    Err(Error::NonZeroUnusedBits)
+                } else {
+                    Ok((
+                        bytes.to_vec(),
+                        bytes.len() as u64 * 8 - u64::from(*unused_bits),
+                    ))
+                }
+            }
+            (false, Length::Indefinite) => Err(Error::IndefiniteLengthNotAllowed(tag)),
+            // ITU-T X.690 | ISO/IEC 8825-1:2015, chapter 10.2
+            (true, _) if R::DISTINGUISHED => Err(Error::UnexpectedEncodingForm(tag, true)),
+            (true, length) => self.with_contents(length, |r| {
+                let mut content = Vec::new();
+                let mut bit_len = 0;
+                while !r.is_at_end_of_contents() {
+                    if bit_len % 8 != 0 {
+                        return Err(Error::InvalidUnusedBitsCount((8 - bit_len % 8) as u8));
+                    }
+                    let (bytes, bits) = r.read_bit_string_content(Tag::DEFAULT_BIT_STRING)?;
+                    content.extend(bytes);
+                    bit_len += bits;
+                }
+                Ok((content, bit_len))
+            }),
+        }
+    }
+
+    fn read_integer(&mut self, tag: Tag) -> Result<i64, Error> {
+        let content = self.read_primitive(tag)?;
+        if content.is_empty() {
+            Err(Error::InvalidContentLength(tag, 0))
+        } else if R::DISTINGUISHED && !ber::is_minimal_integer_content(content) {
+            // ITU-T X.690 | ISO/IEC 8825-1:2015, chapter 8.3.2, applies to BER as well, but is
+            // only enforced for DER to be lenient with other encoders
+            Err(Error::NonMinimalInteger)
+        } else {
+            ber::read_integer_content(content)
+        }
+    }
+
+    fn read_choice_content<C: choice::Constraint>(&mut self) -> Result<C, Error> {
+        let pos = self.pos;
+        for index in 0..C::VARIANT_COUNT {
+            match C::read_content(index, self) {
+                Ok(Some(choice)) => return Ok(choice),
+                Ok(None) => {}
+                Err(Error::UnexpectedTag(..)) | Err(Error::NoMatchingChoiceVariant(..))
+                    if self.pos == pos => {}
+                Err(e) => return Err(e),
+            }
+        }
+        let (tag, _, _) = self.read_header()?;
+        self.pos = pos;
+        Err(Error::NoMatchingChoiceVariant(tag))
+    }
+
+    /// Reads a `SEQUENCE` or `SET` with the given universal tag, which is wrapped by the
+    /// explicit tag of its type assignment, if any
+    fn read_sequence_or_set<
+        C: sequence::Constraint,
+        S: Sized,
+        F: Fn(&mut Self) -> Result<S, Error>,
+    >(
+        &mut self,
+        universal: Tag,
+        f: F,
+    ) -> Result<S, Error> {
+        if C::TRANSPARENT {
+            // the pending tag belongs to the single wrapped field
+            return f(self);
+        }
+        let tag = self.take_tag(C::TAG);
+        let contents = |r: &mut Self| {
+            let value = f(r)?;
+            if C::EXTENDED_AFTER_FIELD.is_some() {
+                r.skip_remaining()?;
+            }
+            Ok(value)
+        };
+        if C::EXPLICIT {
+            self.read_constructed(tag, |r| r.read_constructed(universal, contents))
+        } else {
+            self.read_constructed(tag, contents)
+        }
+    }
+
+    /// ITU-T X.690 | ISO/IEC 8825-1:2015, chapter 8.23, reads the characters of a
+    /// known-multiplier character string from octets of a fixed width
+    fn read_known_multiplier_string(
+        &mut self,
+        tag: Tag,
+        charset: Charset,
+        min: Option<u64>,
+        max: Option<u64>,
+        extensible: bool,
+    ) -> Result<String, Error> {
+        let content = self.read_string_content(tag)?;
+        let string = charset::from_octets(charset, &content)
+            .ok_or(Error::InvalidCharacterString(charset))?;
+        check_size(min, max, extensible, string.chars().count() as u64)?;
+        Ok(string)
+    }
+}
+
+impl<'a, R: EncodingRules> Reader for TlvReader<'a, R> {
+    type Error = Error;
+
+    #[inline]
+    fn read_sequence<
+        C: sequence::Constraint,
+        S: Sized,
+        F: Fn(&mut Self) -> Result<S, Self::Error>,
+    >(
+        &mut self,
+        f: F,
+    ) -> Result<S, Self::Error> {
+        self.read_sequence_or_set::<C, S, F>(Tag::DEFAULT_SEQUENCE, f)
+    }
+
+    #[inline]
+    fn read_sequence_of<C: sequenceof::Constraint, T: ReadableType>(
+        &mut self,
+    ) -> Result<Vec<<T as ReadableType>::Type>, Self::Error> {
+        let tag = self.take_tag(C::TAG);
+        let values = self.read_constructed(tag, |r| {
+            let mut values = Vec::new();
+            while !r.is_at_end_of_contents() {
+                values.push(T::read_value(r)?);
+            }
+            Ok(values)
+        })?;
+        check_size(C::MIN, C::MAX, C::EXTENSIBLE, values.len() as u64)?;
+        Ok(values)
+    }
+
+    #[inline]
+    fn read_set<C: set::Constraint, S: Sized, F: Fn(&mut Self) -> Result<S, Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<S, Self::Error> {
+        self.read_sequence_or_set::<C, S, F>(Tag::DEFAULT_SET, f)
+    }
+
+    #[inline]
+    fn read_set_of<C: setof::Constraint, T: ReadableType>(
+        &mut self,
+    ) -> Result<Vec<<T as ReadableType>::Type>, Self::Error> {
+        if !R::DISTINGUISHED {
+            return self.read_sequence_of::<C, T>();
+        }
+        let tag = self.take_tag(C::TAG);
+        let values = self.read_constructed(tag, |r| {
+            let mut values = Vec::new();
+            let mut previous: Option<&[u8]> = None;
+            while !r.is_at_end_of_contents() {
+                let start = r.pos;
+                values.push(T::read_value(r)?);
+                let encoding = &r.bytes[start..r.pos];
+                // ITU-T X.690 | ISO/IEC 8825-1:2015, chapter 11.6
+                if previous.is_some_and(|previous| {
+                    ber::cmp_set_of_encodings(previous, encoding) == Ordering::Greater
+                }) {
+                    return Err(Error::UnsortedSetOf);
+                }
+                previous = Some(encoding);
+            }
+            Ok(values)
+        })?;
+        check_size(C::MIN, C::MAX, C::EXTENSIBLE, values.len() as u64)?;
+        Ok(values)
+    }
+
+    #[inline]
+    fn read_enumerated<C: enumerated::Constraint>(&mut self) -> Result<C, Self::Error> {
+        let tag = self.take_tag(C::TAG);
+        let value = if C::EXPLICIT {
+            self.read_constructed(tag, |r| r.read_integer(Tag::DEFAULT_ENUMERATED))?
+        } else {
+            self.read_integer(tag)?
+        };
+        C::from_enumeration_value(value).ok_or(Error::InvalidEnumeratedValue(value))
+    }
+
+    #[inline]
+    fn read_choice<C: choice::Constraint>(&mut self) -> Result<C, Self::Error> {
+        match self.tag.take() {
+            Some(tag) if tag != C::TAG => {
+                self.read_constructed(tag, Self::read_choice_content::<C>)
+            }
+            _ if C::EXPLICIT => self.read_constructed(C::TAG, Self::read_choice_content::<C>),
+            _ => self.read_choice_content(),
+        }
+    }
+
+    #[inline]
+    fn read_open_type<C: opentype::Constraint>(&mut self, key: i64) -> Result<C, Self::Error> {
+        self.tag = None;
+        match C::read_content(key, self)? {
+            Some(value) => Ok(value),
+            None => {
+                // the value of a key that is unknown to the object set is kept as its encoding
+                let start = self.pos;
+                self.skip_element()?;
+                C::from_unknown(key, self.bytes[start..self.pos].to_vec())
+                    .ok_or(Error::UnknownOpenTypeKey(key))
+            }
+        }
+    }
+
+    #[inline]
+    fn read_opt<T: ReadableType>(
+        &mut self,
+    ) -> Result<Option<<T as ReadableType>::Type>, Self::Error> {
+        if self.is_at_end_of_contents() {
+            return Ok(None);
+        }
+        let pos = self.pos;
+        match T::read_value(self) {
+            Ok(value) => Ok(Some(value)),
+            // the next element belongs to another field
+            Err(Error::UnexpectedTag(..)) | Err(Error::NoMatchingChoiceVariant(..))
+                if self.pos == pos =>
+            {
+                self.tag = None;
+                Ok(None)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    #[inline]
+    fn read_number<T: numbers::Number, C: numbers::Constraint<T>>(
+        &mut self,
+    ) -> Result<T, Self::Error> {
+        let tag = self.take_tag(C::TAG);
+        let value = self.read_integer(tag)?;
+        check_range(C::MIN, C::MAX, C::EXTENSIBLE, value)?;
+        Ok(T::from_i64(value))
+    }
+
+    #[inline]
+    fn read_wide_number<T: numbers::WideNumber, C: numbers::WideConstraint>(
+        &mut self,
+    ) -> Result<T, Self::Error> {
+        let tag = self.take_tag(C::TAG);
+        let content = self.read_primitive(tag)?;
+        if content.is_empty() {
+            return Err(Error::InvalidContentLength(tag, 0));
+        } else if R::DISTINGUISHED && !ber::is_minimal_integer_content(content) {
+            return Err(Error::NonMinimalInteger);
+        }
+        let value = T::from_signed_octets(content).ok_or(Error::ValueExceedsMaxInt)?;
+        check_wide_range(C::MIN, C::MAX, C::EXTENSIBLE, &value)?;
+        Ok(value)
+    }
+
+    #[inline]
+    fn read_utf8string<C: utf8string::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let tag = self.take_tag(C::TAG);
+        let content = self.read_string_content(tag)?;
+        let string = String::from_utf8(content).map_err(|_| Error::InvalidUtf8String)?;
+        check_size(C::MIN, C::MAX, C::EXTENSIBLE, string.chars().count() as u64)?;
+        Ok(string)
+    }
+
+    #[inline]
+    fn read_ia5string<C: ia5string::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let tag = self.take_tag(C::TAG);
+        let content = self.read_string_content(tag)?;
+        if !content.is_ascii() {
+            return Err(Error::InvalidIa5String);
+        }
+        check_size(C::MIN, C::MAX, C::EXTENSIBLE, content.len() as u64)?;
+        String::from_utf8(content).map_err(|_| Error::InvalidIa5String)
+    }
+
+    #[inline]
+    fn read_numericstring<C: numericstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let tag = self.take_tag(C::TAG);
+        self.read_known_multiplier_string(tag, Charset::Numeric, C::MIN, C::MAX, C::EXTENSIBLE)
+    }
+
+    #[inline]
+    fn read_printablestring<C: printablestring::Constraint>(
+        &mut self,
+    ) -> Result<String, Self::Error> {
+        let tag = self.take_tag(C::TAG);
+        self.read_known_multiplier_string(tag, Charset::Printable, C::MIN, C::MAX, C::EXTENSIBLE)
+    }
+
+    #[inline]
+    fn read_visiblestring<C: visiblestring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let tag = self.take_tag(C::TAG);
+        self.read_known_multiplier_string(tag, Charset::Visible, C::MIN, C::MAX, C::EXTENSIBLE)
+    }
+
+    #[inline]
+    fn read_bmpstring<C: bmpstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let tag = self.take_tag(C::TAG);
+        self.read_known_multiplier_string(tag, Charset::Bmp, C::MIN, C::MAX, C::EXTENSIBLE)
+    }
+
+    #[inline]
+    fn read_universalstring<C: universalstring::Constraint>(
+        &mut self,
+    ) -> Result<String, Self::Error> {
+        let tag = self.take_tag(C::TAG);
+        self.read_known_multiplier_string(tag, Charset::Universal, C::MIN, C::MAX, C::EXTENSIBLE)
+    }
+
+    #[inline]
+    fn read_octet_string<C: octetstring::Constraint>(&mut self) -> Result<Vec<u8>, Self::Error> {
+        let tag = self.take_tag(C::TAG);
+        let content = self.read_string_content(tag)?;
+        check_size(C::MIN, C::MAX, C::EXTENSIBLE, content.len() as u64)?;
+        Ok(content)
+    }
+
+    #[inline]
+    fn read_bit_string<C: bitstring::Constraint>(&mut self) -> Result<(Vec<u8>, u64), Self::Error> {
+        let tag = self.take_tag(C::TAG);
+        let (content, bit_len) = self.read_bit_string_content(tag)?;
+        check_size(C::MIN, C::MAX, C::EXTENSIBLE, bit_len)?;
+        Ok((content, bit_len))
+    }
+
+    #[inline]
+    fn read_boolean<C: boolean::Constraint>(&mut self) -> Result<bool, Self::Error> {
+        let tag = self.take_tag(C::TAG);
+        match self.read_primitive(tag)? {
+            // ITU-T X.690 | ISO/IEC 8825-1:2015, chapter 11.1
+            [value] if R::DISTINGUISHED && *value != 0x00 && *value != 0xFF => {
+                Err(Error::InvalidBooleanValue(*value))
+            }
+            [value] => Ok(*value != 0x00),
+            content => Err(Error::InvalidContentLength(tag, content.len())),
+        }
+    }
+
+    #[inline]
+    fn read_null<C: null::Constraint>(&mut self) -> Result<(), Self::Error> {
+        let tag = self.take_tag(C::TAG);
+        match self.read_primitive(tag)? {
+            [] => Ok(()),
+            content => Err(Error::InvalidContentLength(tag, content.len())),
+        }
+    }
+
+    #[inline]
+    fn read_real<C: real::Constraint>(&mut self) -> Result<f64, Self::Error> {
+        let tag = self.take_tag(C::TAG);
+        let content = self.read_primitive(tag)?;
+        let value = crate::io::real::from_octets(content).ok_or(Error::InvalidReal)?;
+        // ITU-T X.690 | ISO/IEC 8825-1:2015, chapter 11.3, binary values are normalized
+        if R::DISTINGUISHED
+            && content.first().is_some_and(|first| first & 0x80 != 0)
+            && crate::io::real::to_octets(value) != content
+        {
+            Err(Error::InvalidReal)
+        } else {
+            Ok(value)
+        }
+    }
+
+    #[inline]
+    fn read_object_identifier<C: objectidentifier::Constraint>(
+        &mut self,
+    ) -> Result<Vec<u64>, Self::Error> {
+        let tag = self.take_tag(C::TAG);
+        crate::io::oid::object_identifier_from_octets(self.read_primitive(tag)?)
+            .ok_or(Error::InvalidObjectIdentifier)
+    }
+
+    #[inline]
+    fn read_relative_oid<C: relativeoid::Constraint>(&mut self) -> Result<Vec<u64>, Self::Error> {
+        let tag = self.take_tag(C::TAG);
+        crate::io::oid::relative_oid_from_octets(self.read_primitive(tag)?)
+            .ok_or(Error::InvalidObjectIdentifier)
+    }
+
+    #[inline]
+    fn read_utc_time<C: utctime::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_time(C::TAG, TimeType::UtcTime)
+    }
+
+    #[inline]
+    fn read_generalized_time<C: generalizedtime::Constraint>(
+        &mut self,
+    ) -> Result<String, Self::Error> {
+        self.read_time(C::TAG, TimeType::GeneralizedTime)
+    }
+
+    #[inline]
+    fn read_date<C: date::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_time(C::TAG, TimeType::Date)
+    }
+
+    #[inline]
+    fn read_time_of_day<C: timeofday::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_time(C::TAG, TimeType::TimeOfDay)
+    }
+
+    #[inline]
+    fn read_date_time<C: datetime::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_time(C::TAG, TimeType::DateTime)
+    }
+
+    #[inline]
+    fn read_duration<C: duration::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_time(C::TAG, TimeType::Duration)
+    }
+
+    #[inline]
+    fn read_with_components<C: components::Constraint, T: ReadableType>(
+        &mut self,
+    ) -> Result<T::Type, Self::Error>
+    where
+        T::Type: components::Components,
+    {
+        let value = T::read_value(self)?;
+        C::COMPONENTS
+            .check(&value)
+            .map_err(Error::InvalidComponents)?;
+        Ok(value)
+    }
+
+    #[inline]
+    fn read_containing<C: containing::Constraint, T: ReadableType>(
+        &mut self,
+    ) -> Result<T::Type, Self::Error> {
+        let bytes = containing::read_contents::<C, _>(self)?;
+        T::read_value(&mut TlvReader::<R>::from(&bytes[..]))
+    }
+
+    #[inline]
+    fn read_explicit<C: explicit::Constraint, T: ReadableType>(
+        &mut self,
+    ) -> Result<T::Type, Self::Error> {
+        let tag = self.take_tag(C::TAG);
+        self.read_constructed(tag, |r| T::read_value(r))
+    }
+
+    #[inline]
+    fn read_complex<C: complex::Constraint, T, F: Fn(&mut Self) -> Result<T, Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<T, Self::Error> {
+        // the outermost tag wins, see ITU-T X.680 | ISO/IEC 8824-1, 31.2.6 (IMPLICIT)
+        if self.tag.is_none() {
+            self.tag = Some(C::TAG);
+        }
+        let result = f(self);
+        self.tag = None;
+        result
+    }
+}
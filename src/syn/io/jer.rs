@@ -0,0 +1,978 @@
+use crate::io::jer::err::Error;
+use crate::io::jer::{from_hex, to_hex, Value};
+use crate::model::{Charset, TimeType};
+use crate::syn::*;
+use std::convert::TryFrom;
+
+#[inline]
+fn check_size(
+    min: Option<u64>,
+    max: Option<u64>,
+    extensible: bool,
+    size: u64,
+) -> Result<(), Error> {
+    let (min, max) = (min.unwrap_or(0), max.unwrap_or(u64::MAX));
+    if extensible || (min..=max).contains(&size) {
+        Ok(())
+    } else {
+        Err(Error::SizeNotInRange(size, min, max))
+    }
+}
+
+/// Checks that all characters of the given value are part of the charset, the value itself is
+/// encoded as plain JSON string (ITU-T X.697 | ISO/IEC 8825-8:2018, chapter 25)
+#[inline]
+fn check_charset(charset: Charset, value: &str) -> Result<(), Error> {
+    if value.chars().all(|c| charset.contains(c)) {
+        Ok(())
+    } else {
+        Err(Error::InvalidCharacterString(charset))
+    }
+}
+
+#[inline]
+fn check_wide_range<T: numbers::WideNumber>(
+    min: Option<i128>,
+    max: Option<i128>,
+    extensible: bool,
+    value: &T,
+) -> Result<(), Error> {
+    if extensible || value.is_within(min, max) {
+        Ok(())
+    } else {
+        Err(Error::WideValueNotInRange(value.to_string(), min, max))
+    }
+}
+
+#[inline]
+fn check_permitted(
+    permitted: bool,
+    extensible: bool,
+    value: &impl core::fmt::Display,
+) -> Result<(), Error> {
+    if extensible || permitted {
+        Ok(())
+    } else {
+        Err(Error::ValueNotPermitted(value.to_string()))
+    }
+}
+
+fn check_range(
+    min: Option<i64>,
+    max: Option<i64>,
+    extensible: bool,
+    value: i64,
+) -> Result<(), Error> {
+    let (min, max) = (min.unwrap_or(i64::MIN), max.unwrap_or(i64::MAX));
+    if extensible || (min..=max).contains(&value) {
+        Ok(())
+    } else {
+        Err(Error::ValueNotInRange(value, min, max))
+    }
+}
+
+/// ITU-T X.697 | ISO/IEC 8825-8:2018, only a `BIT STRING` with a fixed size is
+/// encoded as plain hex string, all others as an object with the members "value" and "length"
+#[inline]
+const fn fixed_size(min: Option<u64>, max: Option<u64>, extensible: bool) -> Option<u64> {
+    match (min, max) {
+        (Some(min), Some(max)) if min == max && !extensible => Some(min),
+        _ => None,
+    }
+}
+
+/// ITU-T X.697 | ISO/IEC 8825-8:2018, chapter 29 and 30: the arcs of object identifiers are
+/// written as a string of dot-separated numbers
+#[inline]
+fn dotted_arcs(arcs: &[u64]) -> String {
+    arcs.iter()
+        .map(u64::to_string)
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+#[inline]
+fn parse_dotted_arcs(value: &str) -> Result<Vec<u64>, Error> {
+    value
+        .split('.')
+        .map(|arc| {
+            if arc.bytes().all(|b| b.is_ascii_digit()) {
+                arc.parse().ok()
+            } else {
+                None
+            }
+        })
+        .collect::<Option<Vec<_>>>()
+        .ok_or(Error::InvalidObjectIdentifier)
+}
+
+#[inline]
+fn identifier(names: &'static [&'static str], index: usize) -> Result<&'static str, Error> {
+    names
+        .get(index)
+        .copied()
+        .ok_or(Error::MissingIdentifier(index))
+}
+
+/// The JSON value that is currently being written
+enum WriteFrame {
+    /// The members of a `SEQUENCE` or `SET`, named by the identifier of the field at `index`
+    Object {
+        names: &'static [&'static str],
+        index: usize,
+        members: Vec<(String, Value)>,
+    },
+    /// The elements of a `SEQUENCE OF` or `SET OF`
+    Array(Vec<Value>),
+    /// The value of a `CHOICE` variant or of a transparent `SEQUENCE`
+    Single(Option<Value>),
+}
+
+/// Encodes values according to the JSON Encoding Rules (JER), see ITU-T X.697 | ISO/IEC
+/// 8825-8:2018. Fields and variants are named by their original ASN.1 identifiers and the
+/// variants of an `ENUMERATED` are written as their identifier.
+#[derive(Default)]
+pub struct JerWriter {
+    frames: Vec<WriteFrame>,
+    value: Option<Value>,
+}
+
+impl JerWriter {
+    pub fn value(&self) -> Option<&Value> {
+        self.value.as_ref()
+    }
+
+    pub fn into_value(self) -> Option<Value> {
+        self.value
+    }
+
+    /// The JSON text of the written value, which is empty if nothing has been written yet
+    pub fn into_string(self) -> String {
+        self.value.map(|v| v.to_string()).unwrap_or_default()
+    }
+
+    fn push(&mut self, value: Value) -> Result<(), Error> {
+        match self.frames.last_mut() {
+            Some(WriteFrame::Object {
+                names,
+                index,
+                members,
+            }) => {
+                let name = identifier(names, *index)?;
+                *index += 1;
+                members.push((name.to_string(), value));
+            }
+            Some(WriteFrame::Array(values)) => values.push(value),
+            Some(WriteFrame::Single(slot)) => *slot = Some(value),
+            None => self.value = Some(value),
+        }
+        Ok(())
+    }
+
+    fn write_time(&mut self, time: TimeType, value: &str) -> Result<(), Error> {
+        if !crate::io::time::is_valid(time, value) {
+            return Err(Error::InvalidTime(time));
+        }
+        self.push(Value::String(value.to_string()))
+    }
+
+    fn nested<F: FnOnce(&mut Self) -> Result<(), Error>>(
+        &mut self,
+        frame: WriteFrame,
+        f: F,
+    ) -> Result<WriteFrame, Error> {
+        self.frames.push(frame);
+        let result = f(self);
+        let frame = self.frames.pop().ok_or(Error::EndOfStream)?;
+        result.map(|_| frame)
+    }
+
+    fn nested_single<F: FnOnce(&mut Self) -> Result<(), Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<Value, Error> {
+        match self.nested(WriteFrame::Single(None), f)? {
+            WriteFrame::Single(Some(value)) => Ok(value),
+            _ => Err(Error::EndOfStream),
+        }
+    }
+}
+
+impl Writer for JerWriter {
+    type Error = Error;
+
+    #[inline]
+    fn write_sequence<C: sequence::Constraint, F: Fn(&mut Self) -> Result<(), Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<(), Self::Error> {
+        if C::TRANSPARENT {
+            let value = self.nested_single(f)?;
+            return self.push(value);
+        }
+        let frame = WriteFrame::Object {
+            names: C::FIELD_NAMES,
+            index: 0,
+            members: Vec::new(),
+        };
+        if let WriteFrame::Object { members, .. } = self.nested(frame, f)? {
+            self.push(Value::Object(members))?;
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn write_sequence_of<C: sequenceof::Constraint, T: WritableType>(
+        &mut self,
+        slice: &[<T as WritableType>::Type],
+    ) -> Result<(), Self::Error> {
+        check_size(C::MIN, C::MAX, C::EXTENSIBLE, slice.len() as u64)?;
+        let frame = WriteFrame::Array(Vec::with_capacity(slice.len()));
+        let frame = self.nested(frame, |w| {
+            for value in slice {
+                T::write_value(w, value)?;
+            }
+            Ok(())
+        })?;
+        if let WriteFrame::Array(values) = frame {
+            self.push(Value::Array(values))?;
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn write_set<C: set::Constraint, F: Fn(&mut Self) -> Result<(), Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<(), Self::Error> {
+        self.write_sequence::<C, F>(f)
+    }
+
+    #[inline]
+    fn write_set_of<C: setof::Constraint, T: WritableType>(
+        &mut self,
+        slice: &[<T as WritableType>::Type],
+    ) -> Result<(), Self::Error> {
+        self.write_sequence_of::<C, T>(slice)
+    }
+
+    #[inline]
+    fn write_enumerated<C: enumerated::Constraint>(
+        &mut self,
+        enumerated: &C,
+    ) -> Result<(), Self::Error> {
+        let name = identifier(C::VARIANT_NAMES, enumerated.to_choice_index() as usize)?;
+        self.push(Value::String(name.to_string()))
+    }
+
+    #[inline]
+    fn write_choice<C: choice::Constraint>(&mut self, choice: &C) -> Result<(), Self::Error> {
+        // ITU-T X.697 | ISO/IEC 8825-8:2018, an object with a single member that is
+        // named by the identifier of the variant
+        let name = identifier(C::VARIANT_NAMES, choice.to_choice_index() as usize)?;
+        let value = self.nested_single(|w| choice.write_content(w))?;
+        self.push(Value::Object(vec![(name.to_string(), value)]))
+    }
+
+    #[inline]
+    fn write_open_type<C: opentype::Constraint>(
+        &mut self,
+        value: &C,
+        key: i64,
+    ) -> Result<(), Self::Error> {
+        if value.key() != key {
+            return Err(Error::MismatchedOpenTypeKey(key, value.key()));
+        }
+        if value.unknown().is_some() {
+            // the preserved binary encoding cannot be embedded in JSON
+            return Err(Error::UnknownOpenTypeKey(key));
+        }
+        value.write_content(self)
+    }
+
+    #[inline]
+    fn write_opt<T: WritableType>(
+        &mut self,
+        value: Option<&<T as WritableType>::Type>,
+    ) -> Result<(), Self::Error> {
+        match value {
+            Some(value) => T::write_value(self, value),
+            None => {
+                // absent fields are omitted
+                if let Some(WriteFrame::Object { index, .. }) = self.frames.last_mut() {
+                    *index += 1;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    #[inline]
+    fn write_number<T: numbers::Number, C: numbers::Constraint<T>>(
+        &mut self,
+        value: T,
+    ) -> Result<(), Self::Error> {
+        let value = value.to_i64();
+        check_range(C::MIN, C::MAX, C::EXTENSIBLE, value)?;
+        check_permitted(C::permits(value), C::EXTENSIBLE, &value)?;
+        self.push(Value::Number(value.to_string()))
+    }
+
+    #[inline]
+    fn write_wide_number<T: numbers::WideNumber, C: numbers::WideConstraint>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        check_wide_range(C::MIN, C::MAX, C::EXTENSIBLE, value)?;
+        check_permitted(C::permits(value), C::EXTENSIBLE, value)?;
+        self.push(Value::Number(value.to_string()))
+    }
+
+    #[inline]
+    fn write_utf8string<C: utf8string::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        check_size(C::MIN, C::MAX, C::EXTENSIBLE, value.chars().count() as u64)?;
+        self.push(Value::String(value.to_string()))
+    }
+
+    #[inline]
+    fn write_ia5string<C: ia5string::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        if !value.is_ascii() {
+            return Err(Error::InvalidIa5String);
+        }
+        check_size(C::MIN, C::MAX, C::EXTENSIBLE, value.len() as u64)?;
+        self.push(Value::String(value.to_string()))
+    }
+
+    #[inline]
+    fn write_numericstring<C: numericstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        check_charset(Charset::Numeric, value)?;
+        check_size(C::MIN, C::MAX, C::EXTENSIBLE, value.chars().count() as u64)?;
+        self.push(Value::String(value.to_string()))
+    }
+
+    #[inline]
+    fn write_printablestring<C: printablestring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        check_charset(Charset::Printable, value)?;
+        check_size(C::MIN, C::MAX, C::EXTENSIBLE, value.chars().count() as u64)?;
+        self.push(Value::String(value.to_string()))
+    }
+
+    #[inline]
+    fn write_visiblestring<C: visiblestring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        check_charset(Charset::Visible, value)?;
+        check_size(C::MIN, C::MAX, C::EXTENSIBLE, value.chars().count() as u64)?;
+        self.push(Value::String(value.to_string()))
+    }
+
+    #[inline]
+    fn write_bmpstring<C: bmpstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        check_charset(Charset::Bmp, value)?;
+        check_size(C::MIN, C::MAX, C::EXTENSIBLE, value.chars().count() as u64)?;
+        self.push(Value::String(value.to_string()))
+    }
+
+    #[inline]
+    fn write_universalstring<C: universalstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        check_charset(Charset::Universal, value)?;
+        check_size(C::MIN, C::MAX, C::EXTENSIBLE, value.chars().count() as u64)?;
+        self.push(Value::String(value.to_string()))
+    }
+
+    #[inline]
+    fn write_octet_string<C: octetstring::Constraint>(
+        &mut self,
+        value: &[u8],
+    ) -> Result<(), Self::Error> {
+        check_size(C::MIN, C::MAX, C::EXTENSIBLE, value.len() as u64)?;
+        self.push(Value::String(to_hex(value)))
+    }
+
+    #[inline]
+    fn write_bit_string<C: bitstring::Constraint>(
+        &mut self,
+        value: &[u8],
+        bit_len: u64,
+    ) -> Result<(), Self::Error> {
+        check_size(C::MIN, C::MAX, C::EXTENSIBLE, bit_len)?;
+        let byte_len = bit_len.div_ceil(8) as usize;
+        let unused_bits = (byte_len * 8) as u64 - bit_len;
+        let mut bytes = value.get(..byte_len).ok_or(Error::EndOfStream)?.to_vec();
+        if let Some(last) = bytes.last_mut() {
+            *last &= 0xFF << unused_bits;
+        }
+        let hex = Value::String(to_hex(&bytes));
+        if fixed_size(C::MIN, C::MAX, C::EXTENSIBLE).is_some() {
+            self.push(hex)
+        } else {
+            self.push(Value::Object(vec![
+                ("value".to_string(), hex),
+                ("length".to_string(), Value::Number(bit_len.to_string())),
+            ]))
+        }
+    }
+
+    #[inline]
+    fn write_boolean<C: boolean::Constraint>(&mut self, value: bool) -> Result<(), Self::Error> {
+        self.push(Value::Bool(value))
+    }
+
+    #[inline]
+    fn write_null<C: null::Constraint>(&mut self) -> Result<(), Self::Error> {
+        self.push(Value::Null)
+    }
+
+    /// ITU-T X.697 | ISO/IEC 8825-8:2018, chapter 20: special values are written as string
+    #[inline]
+    fn write_real<C: real::Constraint>(&mut self, value: f64) -> Result<(), Self::Error> {
+        self.push(if value.is_nan() {
+            Value::String("NaN".to_string())
+        } else if value.is_infinite() {
+            Value::String(if value > 0.0 { "INF" } else { "-INF" }.to_string())
+        } else if value == 0.0 && value.is_sign_negative() {
+            Value::String("-0".to_string())
+        } else {
+            Value::Number(format!("{:?}", value))
+        })
+    }
+
+    #[inline]
+    fn write_object_identifier<C: objectidentifier::Constraint>(
+        &mut self,
+        value: &[u64],
+    ) -> Result<(), Self::Error> {
+        if crate::io::oid::object_identifier_to_octets(value).is_none() {
+            return Err(Error::InvalidObjectIdentifier);
+        }
+        self.push(Value::String(dotted_arcs(value)))
+    }
+
+    #[inline]
+    fn write_relative_oid<C: relativeoid::Constraint>(
+        &mut self,
+        value: &[u64],
+    ) -> Result<(), Self::Error> {
+        self.push(Value::String(dotted_arcs(value)))
+    }
+
+    #[inline]
+    fn write_utc_time<C: utctime::Constraint>(&mut self, value: &str) -> Result<(), Self::Error> {
+        self.write_time(TimeType::UtcTime, value)
+    }
+
+    #[inline]
+    fn write_generalized_time<C: generalizedtime::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_time(TimeType::GeneralizedTime, value)
+    }
+
+    #[inline]
+    fn write_date<C: date::Constraint>(&mut self, value: &str) -> Result<(), Self::Error> {
+        self.write_time(TimeType::Date, value)
+    }
+
+    #[inline]
+    fn write_time_of_day<C: timeofday::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_time(TimeType::TimeOfDay, value)
+    }
+
+    #[inline]
+    fn write_date_time<C: datetime::Constraint>(&mut self, value: &str) -> Result<(), Self::Error> {
+        self.write_time(TimeType::DateTime, value)
+    }
+
+    #[inline]
+    fn write_duration<C: duration::Constraint>(&mut self, value: &str) -> Result<(), Self::Error> {
+        self.write_time(TimeType::Duration, value)
+    }
+
+    #[inline]
+    fn write_with_components<C: components::Constraint, T: WritableType>(
+        &mut self,
+        value: &T::Type,
+    ) -> Result<(), Self::Error>
+    where
+        T::Type: components::Components,
+    {
+        C::COMPONENTS
+            .check(value)
+            .map_err(Error::InvalidComponents)?;
+        T::write_value(self, value)
+    }
+
+    #[inline]
+    fn write_containing<C: containing::Constraint, T: WritableType>(
+        &mut self,
+        value: &T::Type,
+    ) -> Result<(), Self::Error> {
+        // the contained value is embedded instead of its encoding
+        T::write_value(self, value)
+    }
+
+    #[inline]
+    fn write_explicit<C: explicit::Constraint, T: WritableType>(
+        &mut self,
+        value: &T::Type,
+    ) -> Result<(), Self::Error> {
+        // tags are not encoded
+        T::write_value(self, value)
+    }
+}
+
+/// The JSON value that is currently being read
+enum ReadFrame {
+    /// The remaining members of a `SEQUENCE` or `SET`, the next field is named by the identifier
+    /// at `index`
+    Object {
+        names: &'static [&'static str],
+        index: usize,
+        members: Vec<(String, Value)>,
+    },
+    /// The remaining elements of a `SEQUENCE OF` or `SET OF`
+    Array(std::vec::IntoIter<Value>),
+    /// The value of a `CHOICE` variant or of a transparent `SEQUENCE`
+    Single(Option<Value>),
+}
+
+/// Decodes values according to the JSON Encoding Rules (JER), see [`JerWriter`] for further
+/// details. The JSON text is parsed once the first value is read. Unknown members are only
+/// accepted for extensible `SEQUENCE`s and are ignored.
+pub struct JerReader<'a> {
+    text: Option<&'a str>,
+    frames: Vec<ReadFrame>,
+}
+
+impl<'a> From<&'a str> for JerReader<'a> {
+    fn from(text: &'a str) -> Self {
+        Self {
+            text: Some(text),
+            frames: Vec::new(),
+        }
+    }
+}
+
+impl<'a> JerReader<'a> {
+    fn next(&mut self) -> Result<Value, Error> {
+        match self.frames.last_mut() {
+            Some(ReadFrame::Object {
+                names,
+                index,
+                members,
+            }) => {
+                let name = identifier(names, *index)?;
+                *index += 1;
+                let position = members
+                    .iter()
+                    .position(|(n, _)| n == name)
+                    .ok_or_else(|| Error::MissingMember(name.to_string()))?;
+                Ok(members.remove(position).1)
+            }
+            Some(ReadFrame::Array(values)) => values.next().ok_or(Error::EndOfStream),
+            Some(ReadFrame::Single(slot)) => slot.take().ok_or(Error::EndOfStream),
+            None => Value::parse(self.text.take().ok_or(Error::EndOfStream)?),
+        }
+    }
+
+    fn nested<T, F: FnOnce(&mut Self) -> Result<T, Error>>(
+        &mut self,
+        frame: ReadFrame,
+        f: F,
+    ) -> Result<(T, ReadFrame), Error> {
+        self.frames.push(frame);
+        let result = f(self);
+        let frame = self.frames.pop().ok_or(Error::EndOfStream)?;
+        result.map(|value| (value, frame))
+    }
+
+    fn next_string(&mut self) -> Result<String, Error> {
+        match self.next()? {
+            Value::String(string) => Ok(string),
+            _ => Err(Error::UnexpectedValue("string")),
+        }
+    }
+
+    fn read_time(&mut self, time: TimeType) -> Result<String, Error> {
+        Some(self.next_string()?)
+            .filter(|value| crate::io::time::is_valid(time, value))
+            .ok_or(Error::InvalidTime(time))
+    }
+
+    fn next_integer<T: core::str::FromStr>(&mut self) -> Result<T, Error> {
+        match self.next()? {
+            Value::Number(number) => number.parse().map_err(|_| Error::InvalidNumber(number)),
+            _ => Err(Error::UnexpectedValue("number")),
+        }
+    }
+}
+
+impl<'a> Reader for JerReader<'a> {
+    type Error = Error;
+
+    #[inline]
+    fn read_sequence<
+        C: sequence::Constraint,
+        S: Sized,
+        F: Fn(&mut Self) -> Result<S, Self::Error>,
+    >(
+        &mut self,
+        f: F,
+    ) -> Result<S, Self::Error> {
+        let value = self.next()?;
+        if C::TRANSPARENT {
+            return self.nested(ReadFrame::Single(Some(value)), f).map(|r| r.0);
+        }
+        let members = match value {
+            Value::Object(members) => members,
+            _ => return Err(Error::UnexpectedValue("object")),
+        };
+        let frame = ReadFrame::Object {
+            names: C::FIELD_NAMES,
+            index: 0,
+            members,
+        };
+        let (result, frame) = self.nested(frame, f)?;
+        match frame {
+            ReadFrame::Object { mut members, .. }
+                if !members.is_empty() && C::EXTENDED_AFTER_FIELD.is_none() =>
+            {
+                Err(Error::UnknownMember(members.remove(0).0))
+            }
+            _ => Ok(result),
+        }
+    }
+
+    #[inline]
+    fn read_sequence_of<C: sequenceof::Constraint, T: ReadableType>(
+        &mut self,
+    ) -> Result<Vec<<T as ReadableType>::Type>, Self::Error> {
+        let values = match self.next()? {
+            Value::Array(values) => values,
+            _ => return Err(Error::UnexpectedValue("array")),
+        };
+        check_size(C::MIN, C::MAX, C::EXTENSIBLE, values.len() as u64)?;
+        let len = values.len();
+        self.nested(ReadFrame::Array(values.into_iter()), |r| {
+            (0..len).map(|_| T::read_value(r)).collect()
+        })
+        .map(|r| r.0)
+    }
+
+    #[inline]
+    fn read_set<C: set::Constraint, S: Sized, F: Fn(&mut Self) -> Result<S, Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<S, Self::Error> {
+        self.read_sequence::<C, S, F>(f)
+    }
+
+    #[inline]
+    fn read_set_of<C: setof::Constraint, T: ReadableType>(
+        &mut self,
+    ) -> Result<Vec<<T as ReadableType>::Type>, Self::Error> {
+        self.read_sequence_of::<C, T>()
+    }
+
+    #[inline]
+    fn read_enumerated<C: enumerated::Constraint>(&mut self) -> Result<C, Self::Error> {
+        let name = self.next_string()?;
+        C::VARIANT_NAMES
+            .iter()
+            .position(|n| *n == name)
+            .and_then(|index| C::from_choice_index(index as u64))
+            .ok_or(Error::UnknownVariant(name))
+    }
+
+    #[inline]
+    fn read_choice<C: choice::Constraint>(&mut self) -> Result<C, Self::Error> {
+        let (name, value) = match self.next()? {
+            Value::Object(mut members) if members.len() == 1 => members.remove(0),
+            _ => return Err(Error::UnexpectedValue("object with a single member")),
+        };
+        let index = match C::VARIANT_NAMES.iter().position(|n| *n == name) {
+            Some(index) => index as u64,
+            None => return Err(Error::UnknownVariant(name)),
+        };
+        match self.nested(ReadFrame::Single(Some(value)), |r| {
+            C::read_content(index, r)
+        })? {
+            (Some(choice), _) => Ok(choice),
+            (None, _) => Err(Error::UnknownVariant(name)),
+        }
+    }
+
+    #[inline]
+    fn read_open_type<C: opentype::Constraint>(&mut self, key: i64) -> Result<C, Self::Error> {
+        C::read_content(key, self)?.ok_or(Error::UnknownOpenTypeKey(key))
+    }
+
+    #[inline]
+    fn read_opt<T: ReadableType>(
+        &mut self,
+    ) -> Result<Option<<T as ReadableType>::Type>, Self::Error> {
+        if let Some(ReadFrame::Object {
+            names,
+            index,
+            members,
+        }) = self.frames.last_mut()
+        {
+            let name = identifier(names, *index)?;
+            if !members.iter().any(|(n, _)| n == name) {
+                *index += 1;
+                return Ok(None);
+            }
+        }
+        T::read_value(self).map(Some)
+    }
+
+    #[inline]
+    fn read_number<T: numbers::Number, C: numbers::Constraint<T>>(
+        &mut self,
+    ) -> Result<T, Self::Error> {
+        let value = self.next_integer()?;
+        check_range(C::MIN, C::MAX, C::EXTENSIBLE, value)?;
+        Ok(T::from_i64(value))
+    }
+
+    #[inline]
+    fn read_wide_number<T: numbers::WideNumber, C: numbers::WideConstraint>(
+        &mut self,
+    ) -> Result<T, Self::Error> {
+        let value = self.next_integer::<T>()?;
+        check_wide_range(C::MIN, C::MAX, C::EXTENSIBLE, &value)?;
+        Ok(value)
+    }
+
+    #[inline]
+    fn read_utf8string<C: utf8string::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let string = self.next_string()?;
+        check_size(C::MIN, C::MAX, C::EXTENSIBLE, string.chars().count() as u64)?;
+        Ok(string)
+    }
+
+    #[inline]
+    fn read_ia5string<C: ia5string::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let string = self.next_string()?;
+        if !string.is_ascii() {
+            return Err(Error::InvalidIa5String);
+        }
+        check_size(C::MIN, C::MAX, C::EXTENSIBLE, string.len() as u64)?;
+        Ok(string)
+    }
+
+    #[inline]
+    fn read_numericstring<C: numericstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let string = self.next_string()?;
+        check_charset(Charset::Numeric, &string)?;
+        check_size(C::MIN, C::MAX, C::EXTENSIBLE, string.chars().count() as u64)?;
+        Ok(string)
+    }
+
+    #[inline]
+    fn read_printablestring<C: printablestring::Constraint>(
+        &mut self,
+    ) -> Result<String, Self::Error> {
+        let string = self.next_string()?;
+        check_charset(Charset::Printable, &string)?;
+        check_size(C::MIN, C::MAX, C::EXTENSIBLE, string.chars().count() as u64)?;
+        Ok(string)
+    }
+
+    #[inline]
+    fn read_visiblestring<C: visiblestring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let string = self.next_string()?;
+        check_charset(Charset::Visible, &string)?;
+        check_size(C::MIN, C::MAX, C::EXTENSIBLE, string.chars().count() as u64)?;
+        Ok(string)
+    }
+
+    #[inline]
+    fn read_bmpstring<C: bmpstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let string = self.next_string()?;
+        check_charset(Charset::Bmp, &string)?;
+        check_size(C::MIN, C::MAX, C::EXTENSIBLE, string.chars().count() as u64)?;
+        Ok(string)
+    }
+
+    #[inline]
+    fn read_universalstring<C: universalstring::Constraint>(
+        &mut self,
+    ) -> Result<String, Self::Error> {
+        let string = self.next_string()?;
+        check_charset(Charset::Universal, &string)?;
+        check_size(C::MIN, C::MAX, C::EXTENSIBLE, string.chars().count() as u64)?;
+        Ok(string)
+    }
+
+    #[inline]
+    fn read_octet_string<C: octetstring::Constraint>(&mut self) -> Result<Vec<u8>, Self::Error> {
+        let bytes = from_hex(&self.next_string()?)?;
+        check_size(C::MIN, C::MAX, C::EXTENSIBLE, bytes.len() as u64)?;
+        Ok(bytes)
+    }
+
+    #[inline]
+    fn read_bit_string<C: bitstring::Constraint>(&mut self) -> Result<(Vec<u8>, u64), Self::Error> {
+        let (bytes, bit_len) = match fixed_size(C::MIN, C::MAX, C::EXTENSIBLE) {
+            Some(size) => (from_hex(&self.next_string()?)?, size),
+            None => {
+                let members = match self.next()? {
+                    Value::Object(members) => members,
+                    _ => return Err(Error::UnexpectedValue("object")),
+                };
+                let ((bytes, bit_len), _) = self.nested(
+                    ReadFrame::Object {
+                        names: &["value", "length"],
+                        index: 0,
+                        members,
+                    },
+                    |r| {
+                        let bytes = from_hex(&r.next_string()?)?;
+                        let bit_len = r.next_integer::<i64>()?;
+                        u64::try_from(bit_len)
+                            .map(|bit_len| (bytes, bit_len))
+                            .map_err(|_| Error::InvalidNumber(bit_len.to_string()))
+                    },
+                )?;
+                (bytes, bit_len)
+            }
+        };
+        if bytes.len() as u64 != bit_len.div_ceil(8) {
+            return Err(Error::BitStringLengthMismatch(bit_len, bytes.len()));
+        }
+        check_size(C::MIN, C::MAX, C::EXTENSIBLE, bit_len)?;
+        Ok((bytes, bit_len))
+    }
+
+    #[inline]
+    fn read_boolean<C: boolean::Constraint>(&mut self) -> Result<bool, Self::Error> {
+        match self.next()? {
+            Value::Bool(value) => Ok(value),
+            _ => Err(Error::UnexpectedValue("boolean")),
+        }
+    }
+
+    #[inline]
+    fn read_null<C: null::Constraint>(&mut self) -> Result<(), Self::Error> {
+        match self.next()? {
+            Value::Null => Ok(()),
+            _ => Err(Error::UnexpectedValue("null")),
+        }
+    }
+
+    #[inline]
+    fn read_real<C: real::Constraint>(&mut self) -> Result<f64, Self::Error> {
+        match self.next()? {
+            Value::Number(number) => number.parse().map_err(|_| Error::InvalidReal),
+            Value::String(string) => match string.as_str() {
+                "NaN" => Ok(f64::NAN),
+                "INF" => Ok(f64::INFINITY),
+                "-INF" => Ok(f64::NEG_INFINITY),
+                "-0" => Ok(-0.0),
+                _ => Err(Error::InvalidReal),
+            },
+            _ => Err(Error::UnexpectedValue("number")),
+        }
+    }
+
+    #[inline]
+    fn read_object_identifier<C: objectidentifier::Constraint>(
+        &mut self,
+    ) -> Result<Vec<u64>, Self::Error> {
+        let arcs = parse_dotted_arcs(&self.next_string()?)?;
+        if crate::io::oid::object_identifier_to_octets(&arcs).is_none() {
+            return Err(Error::InvalidObjectIdentifier);
+        }
+        Ok(arcs)
+    }
+
+    #[inline]
+    fn read_relative_oid<C: relativeoid::Constraint>(&mut self) -> Result<Vec<u64>, Self::Error> {
+        parse_dotted_arcs(&self.next_string()?)
+    }
+
+    #[inline]
+    fn read_utc_time<C: utctime::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_time(TimeType::UtcTime)
+    }
+
+    #[inline]
+    fn read_generalized_time<C: generalizedtime::Constraint>(
+        &mut self,
+    ) -> Result<String, Self::Error> {
+        self.read_time(TimeType::GeneralizedTime)
+    }
+
+    #[inline]
+    fn read_date<C: date::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_time(TimeType::Date)
+    }
+
+    #[inline]
+    fn read_time_of_day<C: timeofday::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_time(TimeType::TimeOfDay)
+    }
+
+    #[inline]
+    fn read_date_time<C: datetime::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_time(TimeType::DateTime)
+    }
+
+    #[inline]
+    fn read_duration<C: duration::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_time(TimeType::Duration)
+    }
+
+    #[inline]
+    fn read_with_components<C: components::Constraint, T: ReadableType>(
+        &mut self,
+    ) -> Result<T::Type, Self::Error>
+    where
+        T::Type: components::Components,
+    {
+        let value = T::read_value(self)?;
+        C::COMPONENTS
+            .check(&value)
+            .map_err(Error::InvalidComponents)?;
+        Ok(value)
+    }
+
+    #[inline]
+    fn read_containing<C: containing::Constraint, T: ReadableType>(
+        &mut self,
+    ) -> Result<T::Type, Self::Error> {
+        T::read_value(self)
+    }
+
+    #[inline]
+    fn read_explicit<C: explicit::Constraint, T: ReadableType>(
+        &mut self,
+    ) -> Result<T::Type, Self::Error> {
+        // tags are not encoded
+        T::read_value(self)
+    }
+}
@@ -0,0 +1,1515 @@
+use crate::io::ber::cmp_set_of_encodings;
+use crate::io::charset;
+use crate::io::oer::err::Error;
+use crate::io::oer::{self, IntegerEncoding};
+use crate::model::{Charset, Tag, TimeType};
+use crate::syn::*;
+use core::cmp::Ordering;
+use std::convert::TryFrom;
+
+#[inline]
+fn check_size(
+    min: Option<u64>,
+    max: Option<u64>,
+    extensible: bool,
+    size: u64,
+) -> Result<(), Error> {
+    let (min, max) = (min.unwrap_or(0), max.unwrap_or(u64::MAX));
+    if extensible || (min..=max).contains(&size) {
+        Ok(())
+    } else {
+        Err(Error::SizeNotInRange(size, min, max))
+    }
+}
+
+#[inline]
+fn check_wide_range<T: numbers::WideNumber>(
+    min: Option<i128>,
+    max: Option<i128>,
+    extensible: bool,
+    value: &T,
+) -> Result<(), Error> {
+    if extensible || value.is_within(min, max) {
+        Ok(())
+    } else {
+        Err(Error::WideValueNotInRange(value.to_string(), min, max))
+    }
+}
+
+#[inline]
+fn check_permitted(
+    permitted: bool,
+    extensible: bool,
+    value: &impl core::fmt::Display,
+) -> Result<(), Error> {
+    if extensible || permitted {
+        Ok(())
+    } else {
+        Err(Error::ValueNotPermitted(value.to_string()))
+    }
+}
+
+fn check_range(
+    min: Option<i64>,
+    max: Option<i64>,
+    extensible: bool,
+    value: i64,
+) -> Result<(), Error> {
+    let (min, max) = (min.unwrap_or(i64::MIN), max.unwrap_or(i64::MAX));
+    if extensible || (min..=max).contains(&value) {
+        Ok(())
+    } else {
+        Err(Error::ValueNotInRange(value, min, max))
+    }
+}
+
+/// ITU-T X.696 | ISO/IEC 8825-7:2015, chapter 16.2, 17.1 and 21.2: strings with a fixed size
+/// are encoded without a length determinant
+#[inline]
+const fn fixed_size(min: Option<u64>, max: Option<u64>, extensible: bool) -> Option<u64> {
+    match (min, max) {
+        (Some(min), Some(max)) if min == max && !extensible => Some(min),
+        _ => None,
+    }
+}
+
+/// The state of the `SEQUENCE` that is currently being written
+struct WriteScope {
+    /// The position of the first octet of the preamble in the buffer
+    preamble_pos: usize,
+    /// The index of the bit in the preamble for the next OPTIONAL field
+    preamble_bit: usize,
+    /// The amount of fields until the extension additions are reached
+    root_fields: u64,
+    /// The encodings of all extension additions written so far
+    extensions: Vec<Option<Vec<u8>>>,
+}
+
+/// Encodes values according to the Octet Encoding Rules (OER), see ITU-T X.696 | ISO/IEC
+/// 8825-7:2015. In the canonical mode (COER), the elements of a `SET OF` are additionally sorted
+/// by their encoding. All other encodings are canonical regardless of the mode.
+#[derive(Default)]
+pub struct OerWriter {
+    buffer: Vec<u8>,
+    scope: Option<WriteScope>,
+    choice_tag_pending: bool,
+    choice_tag: Option<Tag>,
+    canonical: bool,
+}
+
+impl OerWriter {
+    /// Creates a writer for the Canonical Octet Encoding Rules (COER)
+    pub fn canonical() -> Self {
+        Self {
+            canonical: true,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_capacity(capacity_bytes: usize) -> Self {
+        Self {
+            buffer: Vec::with_capacity(capacity_bytes),
+            ..Default::default()
+        }
+    }
+
+    pub fn byte_content(&self) -> &[u8] {
+        &self.buffer
+    }
+
+    pub fn into_bytes_vec(self) -> Vec<u8> {
+        self.buffer
+    }
+
+    pub fn as_reader(&self) -> OerReader<'_> {
+        let mut reader = OerReader::from(self.byte_content());
+        reader.canonical = self.canonical;
+        reader
+    }
+
+    /// Remembers the tag of the first value written after [`Writer::write_choice`], which is the
+    /// tag of the choice variant.
+    #[inline]
+    fn note_choice_tag(&mut self, tag: Tag) {
+        if self.choice_tag_pending {
+            self.choice_tag_pending = false;
+            self.choice_tag = Some(tag);
+        }
+    }
+
+    /// ITU-T X.696 | ISO/IEC 8825-7:2015, the time types are encoded as the characters of their
+    /// value notation with a length determinant
+    fn write_time(&mut self, tag: Tag, time: TimeType, value: &str) -> Result<(), Error> {
+        if !crate::io::time::is_valid(time, value) {
+            return Err(Error::InvalidTime(time));
+        }
+        self.note_choice_tag(tag);
+        self.write_field(false, true, |w| {
+            w.write_octets(None, value.as_bytes());
+            Ok(())
+        })
+    }
+
+    /// Writes a value of the current `SEQUENCE` or any other value if there is no such scope.
+    /// For root fields, the OPTIONAL flag is updated in the preamble. Extension additions are
+    /// buffered until the end of the `SEQUENCE`, see [`OerWriter::write_extensions`].
+    fn write_field<F: FnOnce(&mut Self) -> Result<(), Error>>(
+        &mut self,
+        is_opt: bool,
+        is_present: bool,
+        f: F,
+    ) -> Result<(), Error> {
+        let mut scope = match self.scope.take() {
+            Some(scope) => scope,
+            None if is_present => return f(self),
+            None => return Ok(()),
+        };
+
+        let result = if scope.root_fields > 0 {
+            scope.root_fields -= 1;
+            if is_opt {
+                if is_present {
+                    self.buffer[scope.preamble_pos + scope.preamble_bit / 8] |=
+                        0x80 >> (scope.preamble_bit % 8);
+                }
+                scope.preamble_bit += 1;
+            }
+            if is_present {
+                f(self)
+            } else {
+                Ok(())
+            }
+        } else if is_present {
+            let start = self.buffer.len();
+            let result = f(self);
+            scope.extensions.push(Some(self.buffer.split_off(start)));
+            result
+        } else {
+            scope.extensions.push(None);
+            Ok(())
+        };
+
+        self.scope = Some(scope);
+        result
+    }
+
+    /// ITU-T X.696 | ISO/IEC 8825-7:2015, chapter 16.4 to 16.6, writes the presence bitmap of the
+    /// extension additions followed by each present extension addition as open type
+    fn write_extensions(&mut self, extensions: &[Option<Vec<u8>>]) {
+        let octets = extensions.len().div_ceil(8);
+        let mut bitmap = vec![0_u8; octets];
+        for (index, extension) in extensions.iter().enumerate() {
+            if extension.is_some() {
+                bitmap[index / 8] |= 0x80 >> (index % 8);
+            }
+        }
+        oer::write_length_determinant(&mut self.buffer, octets + 1);
+        self.buffer.push((octets * 8 - extensions.len()) as u8);
+        self.buffer.extend_from_slice(&bitmap);
+
+        for encoding in extensions.iter().flatten() {
+            self.write_open_type(encoding);
+        }
+    }
+
+    /// ITU-T X.696 | ISO/IEC 8825-7:2015, chapter 30, writes the given encoding prefixed by its
+    /// length
+    #[inline]
+    fn write_open_type(&mut self, encoding: &[u8]) {
+        oer::write_length_determinant(&mut self.buffer, encoding.len());
+        self.buffer.extend_from_slice(encoding);
+    }
+
+    #[inline]
+    fn write_integer(&mut self, encoding: IntegerEncoding, value: i64) {
+        match encoding {
+            IntegerEncoding::FixedUnsigned(octets) => {
+                oer::write_unsigned(&mut self.buffer, value as u64, octets)
+            }
+            IntegerEncoding::FixedSigned(octets) => {
+                oer::write_signed(&mut self.buffer, value, octets)
+            }
+            IntegerEncoding::VariableUnsigned => {
+                let octets = oer::unsigned_octets(value as u64);
+                oer::write_length_determinant(&mut self.buffer, octets);
+                oer::write_unsigned(&mut self.buffer, value as u64, octets);
+            }
+            IntegerEncoding::VariableSigned => {
+                let octets = oer::signed_octets(value);
+                oer::write_length_determinant(&mut self.buffer, octets);
+                oer::write_signed(&mut self.buffer, value, octets);
+            }
+        }
+    }
+
+    /// Like [`OerWriter::write_integer`] for values that might exceed 64 bits
+    fn write_wide_integer<T: numbers::WideNumber>(&mut self, encoding: IntegerEncoding, value: &T) {
+        let unsigned = || numbers::unsigned_octets(value.offset_from(0).unwrap_or_default());
+        match encoding {
+            IntegerEncoding::FixedUnsigned(octets) => {
+                let content = unsigned();
+                let padding = octets.saturating_sub(content.len());
+                self.buffer.resize(self.buffer.len() + padding, 0x00);
+                self.buffer.extend_from_slice(&content);
+            }
+            IntegerEncoding::FixedSigned(octets) => {
+                let content = value.to_signed_octets();
+                let fill = if content[0] >= 0x80 { 0xFF } else { 0x00 };
+                let padding = octets.saturating_sub(content.len());
+                self.buffer.resize(self.buffer.len() + padding, fill);
+                self.buffer.extend_from_slice(&content);
+            }
+            IntegerEncoding::VariableUnsigned => self.write_octets(None, &unsigned()),
+            IntegerEncoding::VariableSigned => self.write_octets(None, &value.to_signed_octets()),
+        }
+    }
+
+    /// ITU-T X.696 | ISO/IEC 8825-7:2015, chapter 17 and 21, writes the given octets with a
+    /// length determinant unless the size is fixed
+    #[inline]
+    fn write_octets(&mut self, fixed_size: Option<u64>, value: &[u8]) {
+        if fixed_size.is_none() {
+            oer::write_length_determinant(&mut self.buffer, value.len());
+        }
+        self.buffer.extend_from_slice(value);
+    }
+
+    /// ITU-T X.696 | ISO/IEC 8825-7:2015, chapter 20.6, writes the quantity field of a
+    /// `SEQUENCE OF` or `SET OF`
+    #[inline]
+    fn write_quantity(&mut self, quantity: usize) {
+        let octets = oer::unsigned_octets(quantity as u64);
+        oer::write_length_determinant(&mut self.buffer, octets);
+        oer::write_unsigned(&mut self.buffer, quantity as u64, octets);
+    }
+
+    /// ITU-T X.696 | ISO/IEC 8825-7:2015, chapter 27, writes the characters of a
+    /// known-multiplier character string as octets of a fixed width
+    fn write_known_multiplier_string(
+        &mut self,
+        tag: Tag,
+        charset: Charset,
+        min: Option<u64>,
+        max: Option<u64>,
+        extensible: bool,
+        value: &str,
+    ) -> Result<(), Error> {
+        self.note_choice_tag(tag);
+        let content =
+            charset::to_octets(charset, value).ok_or(Error::InvalidCharacterString(charset))?;
+        check_size(min, max, extensible, value.chars().count() as u64)?;
+        let fixed_octets = fixed_size(min, max, extensible)
+            .map(|size| size * charset::octets_per_char(charset) as u64);
+        self.write_field(false, true, |w| {
+            w.write_octets(fixed_octets, &content);
+            Ok(())
+        })
+    }
+}
+
+impl Writer for OerWriter {
+    type Error = Error;
+
+    #[inline]
+    fn write_sequence<C: sequence::Constraint, F: Fn(&mut Self) -> Result<(), Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<(), Self::Error> {
+        self.note_choice_tag(C::TAG);
+        self.write_field(false, true, |w| {
+            // ITU-T X.696 | ISO/IEC 8825-7:2015, chapter 16.2, the preamble consists of the
+            // extension bit and one bit per OPTIONAL root field, padded to whole octets
+            let extensible = C::EXTENDED_AFTER_FIELD.is_some();
+            let bits = usize::from(extensible) + C::STD_OPTIONAL_FIELDS as usize;
+            let preamble_pos = w.buffer.len();
+            w.buffer.resize(preamble_pos + bits.div_ceil(8), 0x00);
+
+            let original = w.scope.replace(WriteScope {
+                preamble_pos,
+                preamble_bit: usize::from(extensible),
+                root_fields: C::EXTENDED_AFTER_FIELD.map_or(C::FIELD_COUNT, |e| e + 1),
+                extensions: Vec::new(),
+            });
+            let result = f(w);
+            let scope = core::mem::replace(&mut w.scope, original);
+            result?;
+
+            if let Some(scope) = scope.filter(|s| s.extensions.iter().any(Option::is_some)) {
+                w.buffer[preamble_pos] |= 0x80;
+                w.write_extensions(&scope.extensions);
+            }
+            Ok(())
+        })
+    }
+
+    #[inline]
+    fn write_sequence_of<C: sequenceof::Constraint, T: WritableType>(
+        &mut self,
+        slice: &[<T as WritableType>::Type],
+    ) -> Result<(), Self::Error> {
+        self.note_choice_tag(C::TAG);
+        check_size(C::MIN, C::MAX, C::EXTENSIBLE, slice.len() as u64)?;
+        self.write_field(false, true, |w| {
+            w.write_quantity(slice.len());
+            for value in slice {
+                T::write_value(w, value)?;
+            }
+            Ok(())
+        })
+    }
+
+    #[inline]
+    fn write_set<C: set::Constraint, F: Fn(&mut Self) -> Result<(), Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<(), Self::Error> {
+        self.write_sequence::<C, F>(f)
+    }
+
+    #[inline]
+    fn write_set_of<C: setof::Constraint, T: WritableType>(
+        &mut self,
+        slice: &[<T as WritableType>::Type],
+    ) -> Result<(), Self::Error> {
+        if !self.canonical {
+            return self.write_sequence_of::<C, T>(slice);
+        }
+        self.note_choice_tag(C::TAG);
+        check_size(C::MIN, C::MAX, C::EXTENSIBLE, slice.len() as u64)?;
+        self.write_field(false, true, |w| {
+            // ITU-T X.696 | ISO/IEC 8825-7:2015, chapter 27.12, the elements are sorted by their
+            // encoding, therefore each element is encoded separately first
+            let mut encodings = Vec::with_capacity(slice.len());
+            for value in slice {
+                let start = w.buffer.len();
+                let result = T::write_value(w, value);
+                encodings.push(w.buffer.split_off(start));
+                result?;
+            }
+            encodings.sort_by(|a, b| cmp_set_of_encodings(a, b));
+            w.write_quantity(encodings.len());
+            for encoding in encodings {
+                w.buffer.extend_from_slice(&encoding);
+            }
+            Ok(())
+        })
+    }
+
+    #[inline]
+    fn write_enumerated<C: enumerated::Constraint>(
+        &mut self,
+        enumerated: &C,
+    ) -> Result<(), Self::Error> {
+        self.note_choice_tag(C::TAG);
+        self.write_field(false, true, |w| {
+            // ITU-T X.696 | ISO/IEC 8825-7:2015, chapter 11
+            let value = enumerated
+                .to_enumeration_value()
+                .ok_or(Error::InvalidEnumeratedValue(
+                    enumerated.to_choice_index() as i64
+                ))?;
+            if (0..0x80).contains(&value) {
+                w.buffer.push(value as u8);
+            } else {
+                let octets = oer::signed_octets(value);
+                w.buffer.push(0x80 | octets as u8);
+                oer::write_signed(&mut w.buffer, value, octets);
+            }
+            Ok(())
+        })
+    }
+
+    #[inline]
+    fn write_choice<C: choice::Constraint>(&mut self, choice: &C) -> Result<(), Self::Error> {
+        self.note_choice_tag(C::TAG);
+        self.write_field(false, true, |w| {
+            // ITU-T X.696 | ISO/IEC 8825-7:2015, chapter 20, the tag of the variant is written
+            // first, which is only known once the variant writes its value
+            let start = w.buffer.len();
+            w.choice_tag_pending = true;
+            let result = choice.write_content(w);
+            w.choice_tag_pending = false;
+            let tag = w.choice_tag.take();
+            let content = w.buffer.split_off(start);
+            result?;
+
+            oer::write_tag(&mut w.buffer, tag.unwrap_or(C::TAG));
+            if choice.to_choice_index() >= C::STD_VARIANT_COUNT {
+                w.write_open_type(&content);
+            } else {
+                w.buffer.extend_from_slice(&content);
+            }
+            Ok(())
+        })
+    }
+
+    #[inline]
+    fn write_open_type<C: opentype::Constraint>(
+        &mut self,
+        value: &C,
+        key: i64,
+    ) -> Result<(), Self::Error> {
+        if value.key() != key {
+            return Err(Error::MismatchedOpenTypeKey(key, value.key()));
+        }
+        self.write_field(false, true, |w| {
+            if let Some(encoding) = value.unknown() {
+                w.write_open_type(encoding);
+                return Ok(());
+            }
+            let start = w.buffer.len();
+            let result = value.write_content(w);
+            let content = w.buffer.split_off(start);
+            result?;
+            w.write_open_type(&content);
+            Ok(())
+        })
+    }
+
+    #[inline]
+    fn write_opt<T: WritableType>(
+        &mut self,
+        value: Option<&<T as WritableType>::Type>,
+    ) -> Result<(), Self::Error> {
+        self.write_field(true, value.is_some(), |w| match value {
+            Some(value) => T::write_value(w, value),
+            None => Ok(()),
+        })
+    }
+
+    #[inline]
+    fn write_number<T: numbers::Number, C: numbers::Constraint<T>>(
+        &mut self,
+        value: T,
+    ) -> Result<(), Self::Error> {
+        self.note_choice_tag(C::TAG);
+        let value = value.to_i64();
+        check_range(C::MIN, C::MAX, C::EXTENSIBLE, value)?;
+        check_permitted(C::permits(value), C::EXTENSIBLE, &value)?;
+        self.write_field(false, true, |w| {
+            w.write_integer(
+                IntegerEncoding::from_constraint(C::MIN, C::MAX, C::EXTENSIBLE),
+                value,
+            );
+            Ok(())
+        })
+    }
+
+    #[inline]
+    fn write_wide_number<T: numbers::WideNumber, C: numbers::WideConstraint>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        self.note_choice_tag(C::TAG);
+        check_wide_range(C::MIN, C::MAX, C::EXTENSIBLE, value)?;
+        check_permitted(C::permits(value), C::EXTENSIBLE, value)?;
+        self.write_field(false, true, |w| {
+            w.write_wide_integer(
+                IntegerEncoding::from_wide_constraint(C::MIN, C::MAX, C::EXTENSIBLE),
+                value,
+            );
+            Ok(())
+        })
+    }
+
+    #[inline]
+    fn write_utf8string<C: utf8string::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.note_choice_tag(C::TAG);
+        check_size(C::MIN, C::MAX, C::EXTENSIBLE, value.chars().count() as u64)?;
+        self.write_field(false, true, |w| {
+            // not a known-multiplier character string type, therefore always with a length
+            w.write_octets(None, value.as_bytes());
+            Ok(())
+        })
+    }
+
+    #[inline]
+    fn write_ia5string<C: ia5string::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.note_choice_tag(C::TAG);
+        if !value.is_ascii() {
+            return Err(Error::InvalidIa5String);
+        }
+        check_size(C::MIN, C::MAX, C::EXTENSIBLE, value.len() as u64)?;
+        self.write_field(false, true, |w| {
+            w.write_octets(fixed_size(C::MIN, C::MAX, C::EXTENSIBLE), value.as_bytes());
+            Ok(())
+        })
+    }
+
+    #[inline]
+    fn write_numericstring<C: numericstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_known_multiplier_string(
+            C::TAG,
+            Charset::Numeric,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            value,
+        )
+    }
+
+    #[inline]
+    fn write_printablestring<C: printablestring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_known_multiplier_string(
+            C::TAG,
+            Charset::Printable,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            value,
+        )
+    }
+
+    #[inline]
+    fn write_visiblestring<C: visiblestring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_known_multiplier_string(
+            C::TAG,
+            Charset::Visible,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            value,
+        )
+    }
+
+    #[inline]
+    fn write_bmpstring<C: bmpstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_known_multiplier_string(
+            C::TAG,
+            Charset::Bmp,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            value,
+        )
+    }
+
+    #[inline]
+    fn write_universalstring<C: universalstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_known_multiplier_string(
+            C::TAG,
+            Charset::Universal,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            value,
+        )
+    }
+
+    #[inline]
+    fn write_octet_string<C: octetstring::Constraint>(
+        &mut self,
+        value: &[u8],
+    ) -> Result<(), Self::Error> {
+        self.note_choice_tag(C::TAG);
+        check_size(C::MIN, C::MAX, C::EXTENSIBLE, value.len() as u64)?;
+        self.write_field(false, true, |w| {
+            w.write_octets(fixed_size(C::MIN, C::MAX, C::EXTENSIBLE), value);
+            Ok(())
+        })
+    }
+
+    #[inline]
+    fn write_bit_string<C: bitstring::Constraint>(
+        &mut self,
+        value: &[u8],
+        bit_len: u64,
+    ) -> Result<(), Self::Error> {
+        self.note_choice_tag(C::TAG);
+        check_size(C::MIN, C::MAX, C::EXTENSIBLE, bit_len)?;
+        let byte_len = bit_len.div_ceil(8) as usize;
+        let unused_bits = (byte_len * 8) as u64 - bit_len;
+        let mut bytes = value.get(..byte_len).ok_or(Error::EndOfStream)?.to_vec();
+        if let Some(last) = bytes.last_mut() {
+            *last &= 0xFF << unused_bits;
+        }
+        self.write_field(false, true, |w| {
+            // ITU-T X.696 | ISO/IEC 8825-7:2015, chapter 16, the initial octet holding the
+            // amount of unused bits is only present if the size is not fixed
+            if fixed_size(C::MIN, C::MAX, C::EXTENSIBLE).is_none() {
+                oer::write_length_determinant(&mut w.buffer, byte_len + 1);
+                w.buffer.push(unused_bits as u8);
+            }
+            w.buffer.extend_from_slice(&bytes);
+            Ok(())
+        })
+    }
+
+    #[inline]
+    fn write_boolean<C: boolean::Constraint>(&mut self, value: bool) -> Result<(), Self::Error> {
+        self.note_choice_tag(C::TAG);
+        self.write_field(false, true, |w| {
+            w.buffer.push(if value { 0xFF } else { 0x00 });
+            Ok(())
+        })
+    }
+
+    #[inline]
+    fn write_null<C: null::Constraint>(&mut self) -> Result<(), Self::Error> {
+        self.note_choice_tag(C::TAG);
+        self.write_field(false, true, |_| Ok(()))
+    }
+
+    #[inline]
+    fn write_real<C: real::Constraint>(&mut self, value: f64) -> Result<(), Self::Error> {
+        self.note_choice_tag(C::TAG);
+        self.write_field(false, true, |w| {
+            w.write_octets(None, &crate::io::real::to_octets(value));
+            Ok(())
+        })
+    }
+
+    #[inline]
+    fn write_object_identifier<C: objectidentifier::Constraint>(
+        &mut self,
+        value: &[u64],
+    ) -> Result<(), Self::Error> {
+        self.note_choice_tag(C::TAG);
+        let content = crate::io::oid::object_identifier_to_octets(value)
+            .ok_or(Error::InvalidObjectIdentifier)?;
+        self.write_field(false, true, |w| {
+            w.write_octets(None, &content);
+            Ok(())
+        })
+    }
+
+    #[inline]
+    fn write_relative_oid<C: relativeoid::Constraint>(
+        &mut self,
+        value: &[u64],
+    ) -> Result<(), Self::Error> {
+        self.note_choice_tag(C::TAG);
+        self.write_field(false, true, |w| {
+            w.write_octets(None, &crate::io::oid::relative_oid_to_octets(value));
+            Ok(())
+        })
+    }
+
+    #[inline]
+    fn write_utc_time<C: utctime::Constraint>(&mut self, value: &str) -> Result<(), Self::Error> {
+        self.write_time(C::TAG, TimeType::UtcTime, value)
+    }
+
+    #[inline]
+    fn write_generalized_time<C: generalizedtime::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_time(C::TAG, TimeType::GeneralizedTime, value)
+    }
+
+    #[inline]
+    fn write_date<C: date::Constraint>(&mut self, value: &str) -> Result<(), Self::Error> {
+        self.write_time(C::TAG, TimeType::Date, value)
+    }
+
+    #[inline]
+    fn write_time_of_day<C: timeofday::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_time(C::TAG, TimeType::TimeOfDay, value)
+    }
+
+    #[inline]
+    fn write_date_time<C: datetime::Constraint>(&mut self, value: &str) -> Result<(), Self::Error> {
+        self.write_time(C::TAG, TimeType::DateTime, value)
+    }
+
+    #[inline]
+    fn write_duration<C: duration::Constraint>(&mut self, value: &str) -> Result<(), Self::Error> {
+        self.write_time(C::TAG, TimeType::Duration, value)
+    }
+
+    #[inline]
+    fn write_with_components<C: components::Constraint, T: WritableType>(
+        &mut self,
+        value: &T::Type,
+    ) -> Result<(), Self::Error>
+    where
+        T::Type: components::Components,
+    {
+        C::COMPONENTS
+            .check(value)
+            .map_err(Error::InvalidComponents)?;
+        T::write_value(self, value)
+    }
+
+    #[inline]
+    fn write_containing<C: containing::Constraint, T: WritableType>(
+        &mut self,
+        value: &T::Type,
+    ) -> Result<(), Self::Error> {
+        let mut writer = OerWriter {
+            canonical: self.canonical,
+            ..Default::default()
+        };
+        T::write_value(&mut writer, value)?;
+        containing::write_contents::<C, _>(self, writer.byte_content())
+    }
+
+    #[inline]
+    fn write_explicit<C: explicit::Constraint, T: WritableType>(
+        &mut self,
+        value: &T::Type,
+    ) -> Result<(), Self::Error> {
+        // only the outer tag identifies an alternative of a CHOICE
+        self.note_choice_tag(C::TAG);
+        T::write_value(self, value)
+    }
+
+    #[inline]
+    fn write_complex<C: complex::Constraint, F: Fn(&mut Self) -> Result<(), Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<(), Self::Error> {
+        self.note_choice_tag(C::TAG);
+        f(self)
+    }
+}
+
+/// The state of the `SEQUENCE` that is currently being read
+struct ReadScope {
+    preamble_pos: usize,
+    preamble_bit: usize,
+    root_fields: u64,
+    extension_present: bool,
+    /// The position of the first octet of the presence bitmap, the amount of extension
+    /// additions in the bitmap and the index of the next extension addition
+    bitmap: Option<(usize, usize, usize)>,
+}
+
+/// Decodes values according to the Octet Encoding Rules (OER), see [`OerWriter`] for further
+/// details. Unknown extension additions of a `SEQUENCE` are skipped. In the canonical mode
+/// (COER), all encodings that are not canonical are rejected.
+pub struct OerReader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+    scope: Option<ReadScope>,
+    choice_tag: Option<Tag>,
+    canonical: bool,
+}
+
+impl<'a> From<&'a [u8]> for OerReader<'a> {
+    fn from(bytes: &'a [u8]) -> Self {
+        Self {
+            bytes,
+            pos: 0,
+            scope: None,
+            choice_tag: None,
+            canonical: false,
+        }
+    }
+}
+
+impl<'a> OerReader<'a> {
+    /// Creates a reader for the Canonical Octet Encoding Rules (COER)
+    pub fn canonical(bytes: &'a [u8]) -> Self {
+        Self {
+            canonical: true,
+            ..Self::from(bytes)
+        }
+    }
+
+    #[inline]
+    pub fn bytes_remaining(&self) -> usize {
+        self.bytes.len() - self.pos
+    }
+
+    /// Ensures that the tag of the choice variant that is currently being read matches
+    #[inline]
+    fn check_choice_tag(&mut self, tag: Tag) -> Result<(), Error> {
+        match self.choice_tag.take() {
+            Some(found) if found != tag => Err(Error::UnexpectedTag(tag, found)),
+            _ => Ok(()),
+        }
+    }
+
+    fn read_time(&mut self, tag: Tag, time: TimeType) -> Result<String, Error> {
+        self.check_choice_tag(tag)?;
+        let content = self.read_field(|r| r.read_octets_vec(None))?;
+        String::from_utf8(content)
+            .ok()
+            .filter(|value| crate::io::time::is_valid(time, value))
+            .ok_or(Error::InvalidTime(time))
+    }
+
+    #[inline]
+    fn read_octets(&mut self, len: usize) -> Result<&'a [u8], Error> {
+        let end = self.pos.checked_add(len).ok_or(Error::EndOfStream)?;
+        let bytes = self.bytes.get(self.pos..end).ok_or(Error::EndOfStream)?;
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    fn read_length_determinant(&mut self) -> Result<usize, Error> {
+        let (length, consumed) = oer::read_length_determinant(&self.bytes[self.pos..])?;
+        if self.canonical && consumed != oer::length_determinant_len(length) {
+            return Err(Error::NonMinimalLength(length));
+        }
+        self.pos += consumed;
+        Ok(length)
+    }
+
+    /// Reads a value of the current `SEQUENCE` or any other value if there is no such scope,
+    /// returns `None` for absent OPTIONAL fields
+    fn read_field_entry<T, F: FnOnce(&mut Self) -> Result<T, Error>>(
+        &mut self,
+        is_opt: bool,
+        f: F,
+    ) -> Result<Option<T>, Error> {
+        let mut scope = match self.scope.take() {
+            Some(scope) => scope,
+            None => return f(self).map(Some),
+        };
+        let result = self.read_field_in_scope(&mut scope, is_opt, f);
+        self.scope = Some(scope);
+        result
+    }
+
+    fn read_field_in_scope<T, F: FnOnce(&mut Self) -> Result<T, Error>>(
+        &mut self,
+        scope: &mut ReadScope,
+        is_opt: bool,
+        f: F,
+    ) -> Result<Option<T>, Error> {
+        if scope.root_fields > 0 {
+            scope.root_fields -= 1;
+            let present = !is_opt || {
+                let bit = self.bytes[scope.preamble_pos + scope.preamble_bit / 8]
+                    & (0x80 >> (scope.preamble_bit % 8))
+                    != 0;
+                scope.preamble_bit += 1;
+                bit
+            };
+            return if present { f(self).map(Some) } else { Ok(None) };
+        }
+
+        let present = scope.extension_present && {
+            let (start, count, next) = match scope.bitmap {
+                Some(bitmap) => bitmap,
+                None => self.read_extension_bitmap()?,
+            };
+            scope.bitmap = Some((start, count, next + 1));
+            next < count && self.bytes[start + next / 8] & (0x80 >> (next % 8)) != 0
+        };
+
+        if present {
+            self.read_open_type(f).map(Some)
+        } else if is_opt {
+            Ok(None)
+        } else {
+            Err(Error::MissingExtensionAddition)
+        }
+    }
+
+    /// ITU-T X.696 | ISO/IEC 8825-7:2015, chapter 16.4, reads the presence bitmap of the extension
+    /// additions and returns the position of its first octet and the amount of entries
+    fn read_extension_bitmap(&mut self) -> Result<(usize, usize, usize), Error> {
+        let length = self.read_length_determinant()?;
+        let content = self.read_octets(length)?;
+        let (unused_bits, bitmap) = content.split_first().ok_or(Error::EndOfStream)?;
+        if *unused_bits > 7 || (bitmap.is_empty() && *unused_bits > 0) {
+            return Err(Error::InvalidUnusedBitsCount(*unused_bits));
+        }
+        if self.canonical
+            && bitmap
+                .last()
+                .is_some_and(|b| b & !(0xFF << unused_bits) != 0)
+        {
+            return Err(Error::NonZeroPaddingBits);
+        }
+        let start = self.pos - bitmap.len();
+        Ok((start, bitmap.len() * 8 - usize::from(*unused_bits), 0))
+    }
+
+    /// ITU-T X.696 | ISO/IEC 8825-7:2015, chapter 30, reads a value that is prefixed by its length
+    fn read_open_type<T, F: FnOnce(&mut Self) -> Result<T, Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<T, Error> {
+        let length = self.read_length_determinant()?;
+        let end = self.pos.checked_add(length).ok_or(Error::EndOfStream)?;
+        if end > self.bytes.len() {
+            return Err(Error::EndOfStream);
+        }
+        let value = f(self)?;
+        match self.pos.cmp(&end) {
+            Ordering::Greater => Err(Error::EndOfStream),
+            Ordering::Less if self.canonical => {
+                Err(Error::UnexpectedTrailingContent(end - self.pos))
+            }
+            _ => {
+                self.pos = end;
+                Ok(value)
+            }
+        }
+    }
+
+    /// Reads the encoding of an open type without decoding it, see
+    /// [`OerReader::read_open_type`]
+    fn read_open_type_encoding(&mut self) -> Result<&'a [u8], Error> {
+        let length = self.read_length_determinant()?;
+        let end = self.pos.checked_add(length).ok_or(Error::EndOfStream)?;
+        let encoding = self.bytes.get(self.pos..end).ok_or(Error::EndOfStream)?;
+        self.pos = end;
+        Ok(encoding)
+    }
+
+    /// Skips all extension additions of the `SEQUENCE` that are unknown to the reader
+    fn skip_extensions(&mut self, scope: ReadScope) -> Result<(), Error> {
+        let (start, count, next) = match scope.bitmap {
+            Some(bitmap) => bitmap,
+            None => self.read_extension_bitmap()?,
+        };
+        for index in next..count {
+            if self.bytes[start + index / 8] & (0x80 >> (index % 8)) != 0 {
+                let length = self.read_length_determinant()?;
+                self.read_octets(length)?;
+            }
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn read_field<T, F: FnOnce(&mut Self) -> Result<T, Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<T, Error> {
+        self.read_field_entry(false, f)?
+            .ok_or(Error::MissingExtensionAddition)
+    }
+
+    fn read_integer(&mut self, encoding: IntegerEncoding) -> Result<i64, Error> {
+        match encoding {
+            IntegerEncoding::FixedUnsigned(octets) => {
+                let value = oer::read_unsigned(self.read_octets(octets)?)?;
+                i64::try_from(value).map_err(|_| Error::ValueExceedsMaxInt)
+            }
+            IntegerEncoding::FixedSigned(octets) => oer::read_signed(self.read_octets(octets)?),
+            IntegerEncoding::VariableUnsigned => {
+                let octets = self.read_length_determinant()?;
+                let value = oer::read_unsigned(self.read_octets(octets)?)?;
+                if self.canonical && octets != oer::unsigned_octets(value) {
+                    return Err(Error::NonMinimalInteger);
+                }
+                i64::try_from(value).map_err(|_| Error::ValueExceedsMaxInt)
+            }
+            IntegerEncoding::VariableSigned => {
+                let octets = self.read_length_determinant()?;
+                let value = oer::read_signed(self.read_octets(octets)?)?;
+                if self.canonical && octets != oer::signed_octets(value) {
+                    return Err(Error::NonMinimalInteger);
+                }
+                Ok(value)
+            }
+        }
+    }
+
+    /// Like [`OerReader::read_integer`] for values that might exceed 64 bits
+    fn read_wide_integer<T: numbers::WideNumber>(
+        &mut self,
+        encoding: IntegerEncoding,
+    ) -> Result<T, Error> {
+        let (octets, signed) = match encoding {
+            IntegerEncoding::FixedUnsigned(octets) => (octets, false),
+            IntegerEncoding::FixedSigned(octets) => (octets, true),
+            IntegerEncoding::VariableUnsigned => (self.read_length_determinant()?, false),
+            IntegerEncoding::VariableSigned => (self.read_length_determinant()?, true),
+        };
+        let content = self.read_octets(octets)?;
+        let value = if signed {
+            T::from_signed_octets(content)
+        } else {
+            numbers::from_unsigned_octets(content).and_then(|offset| T::from_offset(0, offset))
+        }
+        .ok_or(Error::ValueExceedsMaxInt)?;
+        let minimal = match encoding {
+            IntegerEncoding::VariableUnsigned => {
+                numbers::unsigned_octets(value.offset_from(0).unwrap_or_default()).len()
+            }
+            IntegerEncoding::VariableSigned => value.to_signed_octets().len(),
+            _ => octets,
+        };
+        if self.canonical && octets != minimal {
+            Err(Error::NonMinimalInteger)
+        } else {
+            Ok(value)
+        }
+    }
+
+    fn read_quantity(&mut self) -> Result<usize, Error> {
+        let octets = self.read_length_determinant()?;
+        let quantity = oer::read_unsigned(self.read_octets(octets)?)?;
+        if self.canonical && octets != oer::unsigned_octets(quantity) {
+            return Err(Error::NonMinimalInteger);
+        }
+        usize::try_from(quantity).map_err(|_| Error::ValueExceedsMaxInt)
+    }
+
+    fn read_octets_vec(&mut self, fixed_size: Option<u64>) -> Result<Vec<u8>, Error> {
+        let length = match fixed_size {
+            Some(size) => usize::try_from(size).map_err(|_| Error::ValueExceedsMaxInt)?,
+            None => self.read_length_determinant()?,
+        };
+        Ok(self.read_octets(length)?.to_vec())
+    }
+
+    /// ITU-T X.696 | ISO/IEC 8825-7:2015, chapter 27, reads the characters of a
+    /// known-multiplier character string from octets of a fixed width
+    fn read_known_multiplier_string(
+        &mut self,
+        tag: Tag,
+        charset: Charset,
+        min: Option<u64>,
+        max: Option<u64>,
+        extensible: bool,
+    ) -> Result<String, Error> {
+        self.check_choice_tag(tag)?;
+        let fixed_octets = fixed_size(min, max, extensible)
+            .map(|size| size * charset::octets_per_char(charset) as u64);
+        let content = self.read_field(|r| r.read_octets_vec(fixed_octets))?;
+        let string = charset::from_octets(charset, &content)
+            .ok_or(Error::InvalidCharacterString(charset))?;
+        check_size(min, max, extensible, string.chars().count() as u64)?;
+        Ok(string)
+    }
+}
+
+impl<'a> Reader for OerReader<'a> {
+    type Error = Error;
+
+    #[inline]
+    fn read_sequence<
+        C: sequence::Constraint,
+        S: Sized,
+        F: Fn(&mut Self) -> Result<S, Self::Error>,
+    >(
+        &mut self,
+        f: F,
+    ) -> Result<S, Self::Error> {
+        self.check_choice_tag(C::TAG)?;
+        self.read_field(|r| {
+            let extensible = C::EXTENDED_AFTER_FIELD.is_some();
+            let bits = usize::from(extensible) + C::STD_OPTIONAL_FIELDS as usize;
+            let preamble_pos = r.pos;
+            let preamble = r.read_octets(bits.div_ceil(8))?;
+            let used_bits = bits % 8;
+            if r.canonical
+                && used_bits != 0
+                && preamble
+                    .last()
+                    .is_some_and(|b| b & (0xFF >> used_bits) != 0)
+            {
+                return Err(Error::NonZeroPaddingBits);
+            }
+
+            let original = r.scope.replace(ReadScope {
+                preamble_pos,
+                preamble_bit: usize::from(extensible),
+                root_fields: C::EXTENDED_AFTER_FIELD.map_or(C::FIELD_COUNT, |e| e + 1),
+                extension_present: extensible && preamble[0] & 0x80 != 0,
+                bitmap: None,
+            });
+            let result = f(r);
+            let scope = core::mem::replace(&mut r.scope, original);
+            let value = result?;
+
+            if let Some(scope) = scope.filter(|s| s.extension_present) {
+                r.skip_extensions(scope)?;
+            }
+            Ok(value)
+        })
+    }
+
+    #[inline]
+    fn read_sequence_of<C: sequenceof::Constraint, T: ReadableType>(
+        &mut self,
+    ) -> Result<Vec<<T as ReadableType>::Type>, Self::Error> {
+        self.check_choice_tag(C::TAG)?;
+        let values = self.read_field(|r| {
+            let quantity = r.read_quantity()?;
+            // do not trust the quantity for the capacity
+            let mut values = Vec::with_capacity(quantity.min(r.bytes_remaining()));
+            for _ in 0..quantity {
+                values.push(T::read_value(r)?);
+            }
+            Ok(values)
+        })?;
+        check_size(C::MIN, C::MAX, C::EXTENSIBLE, values.len() as u64)?;
+        Ok(values)
+    }
+
+    #[inline]
+    fn read_set<C: set::Constraint, S: Sized, F: Fn(&mut Self) -> Result<S, Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<S, Self::Error> {
+        self.read_sequence::<C, S, F>(f)
+    }
+
+    #[inline]
+    fn read_set_of<C: setof::Constraint, T: ReadableType>(
+        &mut self,
+    ) -> Result<Vec<<T as ReadableType>::Type>, Self::Error> {
+        if !self.canonical {
+            return self.read_sequence_of::<C, T>();
+        }
+        self.check_choice_tag(C::TAG)?;
+        let values = self.read_field(|r| {
+            let quantity = r.read_quantity()?;
+            let mut values = Vec::with_capacity(quantity.min(r.bytes_remaining()));
+            let mut previous: Option<&[u8]> = None;
+            for _ in 0..quantity {
+                let start = r.pos;
+                values.push(T::read_value(r)?);
+                let encoding = &r.bytes[start..r.pos];
+                if previous.is_some_and(|previous| cmp_set_of_encodings(previous, encoding).is_gt())
+                {
+                    return Err(Error::UnsortedSetOf);
+                }
+                previous = Some(encoding);
+            }
+            Ok(values)
+        })?;
+        check_size(C::MIN, C::MAX, C::EXTENSIBLE, values.len() as u64)?;
+        Ok(values)
+    }
+
+    #[inline]
+    fn read_enumerated<C: enumerated::Constraint>(&mut self) -> Result<C, Self::Error> {
+        self.check_choice_tag(C::TAG)?;
+        let value = self.read_field(|r| {
+            let leading = r.read_octets(1)?[0];
+            if leading & 0x80 == 0 {
+                Ok(i64::from(leading))
+            } else {
+                let octets = usize::from(leading & 0x7F);
+                let value = oer::read_signed(r.read_octets(octets)?)?;
+                if r.canonical
+                    && ((0..0x80).contains(&value) || octets != oer::signed_octets(value))
+                {
+                    return Err(Error::NonMinimalInteger);
+                }
+                Ok(value)
+            }
+        })?;
+        C::from_enumeration_value(value).ok_or(Error::InvalidEnumeratedValue(value))
+    }
+
+    #[inline]
+    fn read_choice<C: choice::Constraint>(&mut self) -> Result<C, Self::Error> {
+        self.check_choice_tag(C::TAG)?;
+        self.read_field(|r| {
+            let (tag, consumed) = oer::read_tag(&r.bytes[r.pos..])?;
+            r.pos += consumed;
+            let start = r.pos;
+
+            for index in 0..C::VARIANT_COUNT {
+                r.pos = start;
+                r.choice_tag = Some(tag);
+                let result = if index >= C::STD_VARIANT_COUNT {
+                    r.read_open_type(|r| C::read_content(index, r))
+                } else {
+                    C::read_content(index, r)
+                };
+                r.choice_tag = None;
+                match result {
+                    Ok(Some(choice)) => return Ok(choice),
+                    Ok(None) => {}
+                    // another variant was expected, the content has not been read yet
+                    Err(Error::UnexpectedTag(_, found)) if found == tag => {}
+                    Err(e) => return Err(e),
+                }
+            }
+
+            r.pos = start;
+            Err(Error::NoMatchingChoiceVariant(tag))
+        })
+    }
+
+    #[inline]
+    fn read_open_type<C: opentype::Constraint>(&mut self, key: i64) -> Result<C, Self::Error> {
+        self.read_field(|r| {
+            let start = r.pos;
+            let encoding = r.read_open_type_encoding()?;
+            let end = r.pos;
+            r.pos = start;
+            r.read_open_type(|r| match C::read_content(key, r)? {
+                Some(value) => Ok(value),
+                None => {
+                    // the value of a key that is unknown to the object set
+                    r.pos = end;
+                    C::from_unknown(key, encoding.to_vec()).ok_or(Error::UnknownOpenTypeKey(key))
+                }
+            })
+        })
+    }
+
+    #[inline]
+    fn read_opt<T: ReadableType>(
+        &mut self,
+    ) -> Result<Option<<T as ReadableType>::Type>, Self::Error> {
+        self.read_field_entry(true, T::read_value)
+    }
+
+    #[inline]
+    fn read_number<T: numbers::Number, C: numbers::Constraint<T>>(
+        &mut self,
+    ) -> Result<T, Self::Error> {
+        self.check_choice_tag(C::TAG)?;
+        let value = self.read_field(|r| {
+            r.read_integer(IntegerEncoding::from_constraint(
+                C::MIN,
+                C::MAX,
+                C::EXTENSIBLE,
+            ))
+        })?;
+        check_range(C::MIN, C::MAX, C::EXTENSIBLE, value)?;
+        Ok(T::from_i64(value))
+    }
+
+    #[inline]
+    fn read_wide_number<T: numbers::WideNumber, C: numbers::WideConstraint>(
+        &mut self,
+    ) -> Result<T, Self::Error> {
+        self.check_choice_tag(C::TAG)?;
+        let value = self.read_field(|r| {
+            r.read_wide_integer(IntegerEncoding::from_wide_constraint(
+                C::MIN,
+                C::MAX,
+                C::EXTENSIBLE,
+            ))
+        })?;
+        check_wide_range(C::MIN, C::MAX, C::EXTENSIBLE, &value)?;
+        Ok(value)
+    }
+
+    #[inline]
+    fn read_utf8string<C: utf8string::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.check_choice_tag(C::TAG)?;
+        let content = self.read_field(|r| r.read_octets_vec(None))?;
+        let string = String::from_utf8(content).map_err(|_| Error::InvalidUtf8String)?;
+        check_size(C::MIN, C::MAX, C::EXTENSIBLE, string.chars().count() as u64)?;
+        Ok(string)
+    }
+
+    #[inline]
+    fn read_ia5string<C: ia5string::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.check_choice_tag(C::TAG)?;
+        let content =
+            self.read_field(|r| r.read_octets_vec(fixed_size(C::MIN, C::MAX, C::EXTENSIBLE)))?;
+        if !content.is_ascii() {
+            return Err(Error::InvalidIa5String);
+        }
+        check_size(C::MIN, C::MAX, C::EXTENSIBLE, content.len() as u64)?;
+        String::from_utf8(content).map_err(|_| Error::InvalidIa5String)
+    }
+
+    #[inline]
+    fn read_numericstring<C: numericstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_known_multiplier_string(C::TAG, Charset::Numeric, C::MIN, C::MAX, C::EXTENSIBLE)
+    }
+
+    #[inline]
+    fn read_printablestring<C: printablestring::Constraint>(
+        &mut self,
+    ) -> Result<String, Self::Error> {
+        self.read_known_multiplier_string(C::TAG, Charset::Printable, C::MIN, C::MAX, C::EXTENSIBLE)
+    }
+
+    #[inline]
+    fn read_visiblestring<C: visiblestring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_known_multiplier_string(C::TAG, Charset::Visible, C::MIN, C::MAX, C::EXTENSIBLE)
+    }
+
+    #[inline]
+    fn read_bmpstring<C: bmpstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_known_multiplier_string(C::TAG, Charset::Bmp, C::MIN, C::MAX, C::EXTENSIBLE)
+    }
+
+    #[inline]
+    fn read_universalstring<C: universalstring::Constraint>(
+        &mut self,
+    ) -> Result<String, Self::Error> {
+        self.read_known_multiplier_string(C::TAG, Charset::Universal, C::MIN, C::MAX, C::EXTENSIBLE)
+    }
+
+    #[inline]
+    fn read_octet_string<C: octetstring::Constraint>(&mut self) -> Result<Vec<u8>, Self::Error> {
+        self.check_choice_tag(C::TAG)?;
+        let content =
+            self.read_field(|r| r.read_octets_vec(fixed_size(C::MIN, C::MAX, C::EXTENSIBLE)))?;
+        check_size(C::MIN, C::MAX, C::EXTENSIBLE, content.len() as u64)?;
+        Ok(content)
+    }
+
+    #[inline]
+    fn read_bit_string<C: bitstring::Constraint>(&mut self) -> Result<(Vec<u8>, u64), Self::Error> {
+        self.check_choice_tag(C::TAG)?;
+        let (content, bit_len) = self.read_field(|r| {
+            let (unused_bits, bytes, bit_len) = match fixed_size(C::MIN, C::MAX, C::EXTENSIBLE) {
+                Some(bit_len) => {
+                    let bytes = r.read_octets(bit_len.div_ceil(8) as usize)?;
+                    ((bytes.len() * 8) as u64 - bit_len, bytes, bit_len)
+                }
+                None => {
+                    let length = r.read_length_determinant()?;
+                    let content = r.read_octets(length)?;
+                    let (unused_bits, bytes) = content.split_first().ok_or(Error::EndOfStream)?;
+                    if *unused_bits > 7 || (bytes.is_empty() && *unused_bits > 0) {
+                        return Err(Error::InvalidUnusedBitsCount(*unused_bits));
+                    }
+                    let unused_bits = u64::from(*unused_bits);
+                    (unused_bits, bytes, bytes.len() as u64 * 8 - unused_bits)
+                }
+            };
+            if r.canonical
+                && bytes
+                    .last()
+                    .is_some_and(|b| b & !(0xFF << unused_bits) != 0)
+            {
+                return Err(Error::NonZeroPaddingBits);
+            }
+            Ok((bytes.to_vec(), bit_len))
+        })?;
+        check_size(C::MIN, C::MAX, C::EXTENSIBLE, bit_len)?;
+        Ok((content, bit_len))
+    }
+
+    #[inline]
+    fn read_boolean<C: boolean::Constraint>(&mut self) -> Result<bool, Self::Error> {
+        self.check_choice_tag(C::TAG)?;
+        self.read_field(|r| match r.read_octets(1)?[0] {
+            // ITU-T X.696 | ISO/IEC 8825-7:2015, chapter 9
+            value if r.canonical && value != 0x00 && value != 0xFF => {
+                Err(Error::InvalidBooleanValue(value))
+            }
+            value => Ok(value != 0x00),
+        })
+    }
+
+    #[inline]
+    fn read_null<C: null::Constraint>(&mut self) -> Result<(), Self::Error> {
+        self.check_choice_tag(C::TAG)?;
+        self.read_field(|_| Ok(()))
+    }
+
+    #[inline]
+    fn read_real<C: real::Constraint>(&mut self) -> Result<f64, Self::Error> {
+        self.check_choice_tag(C::TAG)?;
+        let content = self.read_field(|r| r.read_octets_vec(None))?;
+        crate::io::real::from_octets(&content).ok_or(Error::InvalidReal)
+    }
+
+    #[inline]
+    fn read_object_identifier<C: objectidentifier::Constraint>(
+        &mut self,
+    ) -> Result<Vec<u64>, Self::Error> {
+        self.check_choice_tag(C::TAG)?;
+        let content = self.read_field(|r| r.read_octets_vec(None))?;
+        crate::io::oid::object_identifier_from_octets(&content)
+            .ok_or(Error::InvalidObjectIdentifier)
+    }
+
+    #[inline]
+    fn read_relative_oid<C: relativeoid::Constraint>(&mut self) -> Result<Vec<u64>, Self::Error> {
+        self.check_choice_tag(C::TAG)?;
+        let content = self.read_field(|r| r.read_octets_vec(None))?;
+        crate::io::oid::relative_oid_from_octets(&content).ok_or(Error::InvalidObjectIdentifier)
+    }
+
+    #[inline]
+    fn read_utc_time<C: utctime::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_time(C::TAG, TimeType::UtcTime)
+    }
+
+    #[inline]
+    fn read_generalized_time<C: generalizedtime::Constraint>(
+        &mut self,
+    ) -> Result<String, Self::Error> {
+        self.read_time(C::TAG, TimeType::GeneralizedTime)
+    }
+
+    #[inline]
+    fn read_date<C: date::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_time(C::TAG, TimeType::Date)
+    }
+
+    #[inline]
+    fn read_time_of_day<C: timeofday::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_time(C::TAG, TimeType::TimeOfDay)
+    }
+
+    #[inline]
+    fn read_date_time<C: datetime::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_time(C::TAG, TimeType::DateTime)
+    }
+
+    #[inline]
+    fn read_duration<C: duration::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_time(C::TAG, TimeType::Duration)
+    }
+
+    #[inline]
+    fn read_with_components<C: components::Constraint, T: ReadableType>(
+        &mut self,
+    ) -> Result<T::Type, Self::Error>
+    where
+        T::Type: components::Components,
+    {
+        let value = T::read_value(self)?;
+        C::COMPONENTS
+            .check(&value)
+            .map_err(Error::InvalidComponents)?;
+        Ok(value)
+    }
+
+    #[inline]
+    fn read_containing<C: containing::Constraint, T: ReadableType>(
+        &mut self,
+    ) -> Result<T::Type, Self::Error> {
+        let bytes = containing::read_contents::<C, _>(self)?;
+        let mut reader = OerReader::from(&bytes[..]);
+        reader.canonical = self.canonical;
+        T::read_value(&mut reader)
+    }
+
+    #[inline]
+    fn read_explicit<C: explicit::Constraint, T: ReadableType>(
+        &mut self,
+    ) -> Result<T::Type, Self::Error> {
+        self.check_choice_tag(C::TAG)?;
+        T::read_value(self)
+    }
+
+    #[inline]
+    fn read_complex<C: complex::Constraint, T, F: Fn(&mut Self) -> Result<T, Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<T, Self::Error> {
+        self.check_choice_tag(C::TAG)?;
+        f(self)
+    }
+}
@@ -1,3 +1,4 @@
+use crate::model::{Tag, TimeType};
 use crate::syn::*;
 
 #[derive(Default)]
@@ -14,6 +15,12 @@ impl PrintlnWriter {
         self.0 -= 1;
         r
     }
+
+    fn write_time(&mut self, tag: Tag, time: TimeType, value: &str) -> Result<(), ()> {
+        self.indented_println(format!("WRITING {:?}, tag={:?}", time, tag));
+        self.with_increased_indentation(|w| w.indented_println(value));
+        Ok(())
+    }
 }
 
 impl Writer for PrintlnWriter {
@@ -121,6 +128,15 @@ impl Writer for PrintlnWriter {
         })
     }
 
+    fn write_open_type<C: opentype::Constraint>(
+        &mut self,
+        value: &C,
+        key: i64,
+    ) -> Result<(), Self::Error> {
+        self.indented_println(format!("Write open type {}, key={}", C::NAME, key));
+        self.with_increased_indentation(|w| value.write_content(w))
+    }
+
     fn write_opt<T: WritableType>(&mut self, value: Option<&T::Type>) -> Result<(), Self::Error> {
         self.indented_println("Writing OPTIONAL");
         self.with_increased_indentation(|w| {
@@ -153,6 +169,25 @@ impl Writer for PrintlnWriter {
         Ok(())
     }
 
+    fn write_wide_number<T: numbers::WideNumber, C: numbers::WideConstraint>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        self.indented_println(format!(
+            "WRITING WideInteger({}..{}{}), tag={:?}",
+            C::MIN
+                .map(|v| v.to_string())
+                .unwrap_or_else(|| "MIN".to_string()),
+            C::MAX
+                .map(|v| v.to_string())
+                .unwrap_or_else(|| "MAX".to_string()),
+            if C::EXTENSIBLE { ",..." } else { "" },
+            C::TAG,
+        ));
+        self.with_increased_indentation(|w| w.indented_println(value.to_string()));
+        Ok(())
+    }
+
     fn write_utf8string<C: utf8string::Constraint>(
         &mut self,
         value: &str,
@@ -189,6 +224,96 @@ impl Writer for PrintlnWriter {
         Ok(())
     }
 
+    fn write_numericstring<C: numericstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.indented_println(format!(
+            "Writing NumericString({}..{}), tag={:?}",
+            C::MIN
+                .map(|v| format!("{}", v))
+                .unwrap_or_else(|| String::from("MIN")),
+            C::MAX
+                .map(|v| format!("{}", v))
+                .unwrap_or_else(|| String::from("MAX")),
+            C::TAG
+        ));
+        self.with_increased_indentation(|w| w.indented_println(format!("{:?}", value)));
+        Ok(())
+    }
+
+    fn write_printablestring<C: printablestring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.indented_println(format!(
+            "Writing PrintableString({}..{}), tag={:?}",
+            C::MIN
+                .map(|v| format!("{}", v))
+                .unwrap_or_else(|| String::from("MIN")),
+            C::MAX
+                .map(|v| format!("{}", v))
+                .unwrap_or_else(|| String::from("MAX")),
+            C::TAG
+        ));
+        self.with_increased_indentation(|w| w.indented_println(format!("{:?}", value)));
+        Ok(())
+    }
+
+    fn write_visiblestring<C: visiblestring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.indented_println(format!(
+            "Writing VisibleString({}..{}), tag={:?}",
+            C::MIN
+                .map(|v| format!("{}", v))
+                .unwrap_or_else(|| String::from("MIN")),
+            C::MAX
+                .map(|v| format!("{}", v))
+                .unwrap_or_else(|| String::from("MAX")),
+            C::TAG
+        ));
+        self.with_increased_indentation(|w| w.indented_println(format!("{:?}", value)));
+        Ok(())
+    }
+
+    fn write_bmpstring<C: bmpstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.indented_println(format!(
+            "Writing BmpString({}..{}), tag={:?}",
+            C::MIN
+                .map(|v| format!("{}", v))
+                .unwrap_or_else(|| String::from("MIN")),
+            C::MAX
+                .map(|v| format!("{}", v))
+                .unwrap_or_else(|| String::from("MAX")),
+            C::TAG
+        ));
+        self.with_increased_indentation(|w| w.indented_println(format!("{:?}", value)));
+        Ok(())
+    }
+
+    fn write_universalstring<C: universalstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.indented_println(format!(
+            "Writing UniversalString({}..{}), tag={:?}",
+            C::MIN
+                .map(|v| format!("{}", v))
+                .unwrap_or_else(|| String::from("MIN")),
+            C::MAX
+                .map(|v| format!("{}", v))
+                .unwrap_or_else(|| String::from("MAX")),
+            C::TAG
+        ));
+        self.with_increased_indentation(|w| w.indented_println(format!("{:?}", value)));
+        Ok(())
+    }
+
     fn write_octet_string<C: octetstring::Constraint>(
         &mut self,
         value: &[u8],
@@ -232,4 +357,96 @@ impl Writer for PrintlnWriter {
         self.with_increased_indentation(|w| w.indented_println(value.to_string()));
         Ok(())
     }
+
+    fn write_null<C: null::Constraint>(&mut self) -> Result<(), Self::Error> {
+        self.indented_println(format!("WRITING Null, tag={:?}", C::TAG));
+        Ok(())
+    }
+
+    fn write_real<C: real::Constraint>(&mut self, value: f64) -> Result<(), Self::Error> {
+        self.indented_println(format!("WRITING Real, tag={:?}", C::TAG));
+        self.with_increased_indentation(|w| w.indented_println(format!("{:?}", value)));
+        Ok(())
+    }
+
+    fn write_object_identifier<C: objectidentifier::Constraint>(
+        &mut self,
+        value: &[u64],
+    ) -> Result<(), Self::Error> {
+        self.indented_println(format!("WRITING ObjectIdentifier, tag={:?}", C::TAG));
+        self.with_increased_indentation(|w| w.indented_println(format!("{:?}", value)));
+        Ok(())
+    }
+
+    fn write_relative_oid<C: relativeoid::Constraint>(
+        &mut self,
+        value: &[u64],
+    ) -> Result<(), Self::Error> {
+        self.indented_println(format!("WRITING RelativeOid, tag={:?}", C::TAG));
+        self.with_increased_indentation(|w| w.indented_println(format!("{:?}", value)));
+        Ok(())
+    }
+
+    #[inline]
+    fn write_utc_time<C: utctime::Constraint>(&mut self, value: &str) -> Result<(), Self::Error> {
+        self.write_time(C::TAG, TimeType::UtcTime, value)
+    }
+
+    #[inline]
+    fn write_generalized_time<C: generalizedtime::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_time(C::TAG, TimeType::GeneralizedTime, value)
+    }
+
+    #[inline]
+    fn write_date<C: date::Constraint>(&mut self, value: &str) -> Result<(), Self::Error> {
+        self.write_time(C::TAG, TimeType::Date, value)
+    }
+
+    #[inline]
+    fn write_time_of_day<C: timeofday::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_time(C::TAG, TimeType::TimeOfDay, value)
+    }
+
+    #[inline]
+    fn write_date_time<C: datetime::Constraint>(&mut self, value: &str) -> Result<(), Self::Error> {
+        self.write_time(C::TAG, TimeType::DateTime, value)
+    }
+
+    #[inline]
+    fn write_duration<C: duration::Constraint>(&mut self, value: &str) -> Result<(), Self::Error> {
+        self.write_time(C::TAG, TimeType::Duration, value)
+    }
+
+    #[inline]
+    fn write_with_components<C: components::Constraint, T: WritableType>(
+        &mut self,
+        value: &T::Type,
+    ) -> Result<(), Self::Error>
+    where
+        T::Type: components::Components,
+    {
+        T::write_value(self, value)
+    }
+
+    #[inline]
+    fn write_containing<C: containing::Constraint, T: WritableType>(
+        &mut self,
+        value: &T::Type,
+    ) -> Result<(), Self::Error> {
+        T::write_value(self, value)
+    }
+
+    #[inline]
+    fn write_explicit<C: explicit::Constraint, T: WritableType>(
+        &mut self,
+        value: &T::Type,
+    ) -> Result<(), Self::Error> {
+        T::write_value(self, value)
+    }
 }
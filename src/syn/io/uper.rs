@@ -1,9 +1,12 @@
+use crate::io::per::alphabet::Alphabet;
 use crate::io::per::err::Error;
 use crate::io::per::unaligned::buffer::BitBuffer;
 use crate::io::per::unaligned::BitWrite;
 use crate::io::per::unaligned::BYTE_LEN;
 use crate::io::per::PackedRead;
 use crate::io::per::PackedWrite;
+use crate::io::time;
+use crate::model::{Charset, TimeType};
 use crate::syn::*;
 use std::ops::Range;
 
@@ -41,6 +44,23 @@ pub enum Scope {
         opt_bit_field: Option<Range<usize>>,
         calls_until_ext_bitfield: usize,
         number_of_ext_fields: usize,
+        /// Whether the `eo` flag is set and therefore part2 exists. While writing, part2 is
+        /// otherwise only written once an extended field is present.
+        extensions_present: bool,
+    },
+    /// The extended fields of an extensible struct that is being written, of which the first
+    /// `absent` are not present. Because the `eo` flag must only be set if an extended field is
+    /// present (ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 19.7), part2 is not written yet.
+    AbsentExtensionAdditions {
+        number_of_ext_fields: usize,
+        absent: usize,
+    },
+    /// The presence flags of part2 of an extensible struct that is being read. The flags in
+    /// `known` belong to the extended fields of the struct, the flags in `unknown` to fields of a
+    /// newer version of the struct. Extended fields without a flag are not present.
+    ExtensionAdditions {
+        known: Range<usize>,
+        unknown: Range<usize>,
     },
 }
 
@@ -50,13 +70,64 @@ impl Scope {
         match self {
             Scope::OptBitField(range) => range.start == range.end,
             Scope::AllBitField(range) => range.start == range.end,
-            Scope::ExtensibleSequence { .. } => false,
+            // without any extended fields, there is no part2 to transition to
+            Scope::ExtensibleSequence {
+                calls_until_ext_bitfield,
+                number_of_ext_fields,
+                ..
+            } => *calls_until_ext_bitfield == 0 && *number_of_ext_fields == 0,
+            Scope::ExtensionAdditions { known, .. } => known.start == known.end,
+            Scope::AbsentExtensionAdditions {
+                number_of_ext_fields,
+                absent,
+            } => *absent == *number_of_ext_fields,
         }
     }
 
     #[inline]
     pub const fn encode_as_open_type_field(&self) -> bool {
-        matches!(self, Scope::AllBitField(_))
+        matches!(
+            self,
+            Scope::AllBitField(_) | Scope::ExtensionAdditions { .. }
+        )
+    }
+
+    /// Reads the number of extended fields and their presence flags (part2, see
+    /// [`Scope::ExtensibleSequence`]), of which only the first `number_of_ext_fields` are known
+    #[inline]
+    pub fn read_extension_additions(
+        bits: &mut impl ScopedBitRead,
+        number_of_ext_fields: usize,
+    ) -> Result<Self, Error> {
+        let count = bits.read_normally_small_length()? as usize + 1;
+        if bits.remaining() < count {
+            return Err(Error::EndOfStream);
+        }
+        let start = bits.pos();
+        let known_end = start + count.min(number_of_ext_fields);
+        bits.set_pos(start + count); // skip bit-field
+        Ok(Scope::ExtensionAdditions {
+            known: start..known_end,
+            unknown: known_end..start + count,
+        })
+    }
+
+    /// Writes the number of extended fields and reserves their presence flags (part2, see
+    /// [`Scope::ExtensibleSequence`]), the range of which is returned
+    #[inline]
+    pub fn write_extension_additions(
+        buffer: &mut BitBuffer,
+        number_of_ext_fields: usize,
+    ) -> Result<Range<usize>, Error> {
+        buffer.write_normally_small_non_negative_whole_number(number_of_ext_fields as u64 - 1)?;
+        let pos = buffer.write_position;
+        for _ in 0..number_of_ext_fields {
+            if let Err(e) = buffer.write_bit(true) {
+                buffer.write_position = pos;
+                return Err(e);
+            }
+        }
+        Ok(pos..buffer.write_position)
     }
 
     #[inline]
@@ -87,22 +158,19 @@ impl Scope {
                 opt_bit_field,
                 calls_until_ext_bitfield,
                 number_of_ext_fields,
+                extensions_present,
             } => {
                 if *calls_until_ext_bitfield == 0 {
-                    // when we reach this point, there is never zero numbers of ext-fields
-                    buffer.write_normally_small_non_negative_whole_number(
-                        *number_of_ext_fields as u64 - 1,
-                    )?;
-                    let pos = buffer.write_position;
-                    for _ in 0..*number_of_ext_fields {
-                        if let Err(e) = buffer.write_bit(true) {
-                            buffer.write_position = pos;
-                            return Err(e);
+                    *self = if *extensions_present {
+                        // when we reach this point, there is never zero numbers of ext-fields
+                        let range = Self::write_extension_additions(buffer, *number_of_ext_fields)?;
+                        Scope::AllBitField(range)
+                    } else {
+                        Scope::AbsentExtensionAdditions {
+                            number_of_ext_fields: *number_of_ext_fields,
+                            absent: 0,
                         }
-                    }
-                    let range = pos..buffer.write_position;
-                    // buffer.write_int(range.len() as i64, (1, range.end as i64))?;
-                    *self = Scope::AllBitField(range);
+                    };
                     self.write_into_field(buffer, is_opt, is_present)
                 } else {
                     *calls_until_ext_bitfield = calls_until_ext_bitfield.saturating_sub(1);
@@ -120,6 +188,26 @@ impl Scope {
                     }
                 }
             }
+            Scope::AbsentExtensionAdditions {
+                number_of_ext_fields,
+                absent,
+            } => {
+                if is_present {
+                    let mut range = Self::write_extension_additions(buffer, *number_of_ext_fields)?;
+                    for _ in 0..*absent {
+                        buffer.with_write_position_at(range.start, |b| b.write_bit(false))?;
+                        range.start += 1;
+                    }
+                    *self = Scope::AllBitField(range);
+                    self.write_into_field(buffer, is_opt, is_present)
+                } else {
+                    *absent += 1;
+                    Ok(())
+                }
+            }
+            Scope::ExtensionAdditions { .. } => Err(Error::UnsupportedOperation(
+                "Writing extension additions of a struct that is being read".to_string(),
+            )),
         }
     }
 
@@ -149,18 +237,18 @@ impl Scope {
                 opt_bit_field,
                 calls_until_ext_bitfield,
                 number_of_ext_fields,
+                extensions_present,
             } => {
                 if *calls_until_ext_bitfield == 0 {
-                    let read_number_of_ext_fields = bits.read_normally_small_length()? as usize + 1;
-                    if read_number_of_ext_fields != *number_of_ext_fields {
-                        return Err(Error::UnsupportedOperation(format!(
-                            "Expected {} extended fields but got {}",
-                            number_of_ext_fields, read_number_of_ext_fields
-                        )));
-                    }
-                    let range = bits.pos()..bits.pos() + *number_of_ext_fields;
-                    bits.set_pos(range.end); // skip bit-field
-                    *self = Scope::AllBitField(range);
+                    *self = if *extensions_present {
+                        Self::read_extension_additions(bits, *number_of_ext_fields)?
+                    } else {
+                        let pos = bits.pos();
+                        Scope::ExtensionAdditions {
+                            known: pos..pos,
+                            unknown: pos..pos,
+                        }
+                    };
                     self.read_from_field(bits, is_opt)
                 } else {
                     *calls_until_ext_bitfield = calls_until_ext_bitfield.saturating_sub(1);
@@ -176,6 +264,25 @@ impl Scope {
                         .transpose()
                 }
             }
+            Scope::ExtensionAdditions { known, .. } => {
+                let is_present = if known.start < known.end {
+                    let result =
+                        bits.with_read_position_at(known.start, |buffer| buffer.read_bit());
+                    known.start += 1;
+                    result?
+                } else {
+                    // the sender is not aware of this extended field
+                    false
+                };
+                if is_opt || is_present {
+                    Ok(Some(is_present))
+                } else {
+                    Err(Error::MissingExtensionAddition)
+                }
+            }
+            Scope::AbsentExtensionAdditions { .. } => Err(Error::UnsupportedOperation(
+                "Reading extension additions of a struct that is being written".to_string(),
+            )),
         }
     }
 }
@@ -184,6 +291,9 @@ impl Scope {
 pub struct UperWriter {
     bits: BitBuffer,
     scope: Option<Scope>,
+    /// The extended fields unknown to the struct being written, see
+    /// [`Writer::write_unknown_extensions`]
+    unknown_extensions: Vec<Option<Vec<u8>>>,
 }
 
 impl UperWriter {
@@ -232,6 +342,24 @@ impl UperWriter {
         result
     }
 
+    /// Writes the presence flags and open types of the extended fields that are unknown to
+    /// the struct being written
+    #[inline]
+    pub fn write_unknown_extension_additions(
+        &mut self,
+        flags: Range<usize>,
+        extensions: &[Option<Vec<u8>>],
+    ) -> Result<(), Error> {
+        for (flag, extension) in flags.zip(extensions) {
+            self.bits
+                .with_write_position_at(flag, |b| b.write_bit(extension.is_some()))?;
+            if let Some(bytes) = extension {
+                self.bits.write_octetstring(None, None, false, bytes)?;
+            }
+        }
+        Ok(())
+    }
+
     #[inline]
     pub fn write_bit_field_entry(&mut self, is_opt: bool, is_present: bool) -> Result<(), Error> {
         if let Some(scope) = &mut self.scope {
@@ -259,6 +387,64 @@ impl UperWriter {
             f(self)
         }
     }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 30.5: each character of a known-multiplier
+    /// character string is encoded with the number of bits its permitted alphabet requires
+    fn write_known_multiplier_string(
+        &mut self,
+        charset: Charset,
+        alphabet: &[(u32, u32)],
+        min: Option<u64>,
+        max: Option<u64>,
+        extensible: bool,
+        value: &str,
+    ) -> Result<(), Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| {
+            let alphabet = Alphabet::new(alphabet, false)?;
+            let encoded = value
+                .chars()
+                .map(|c| alphabet.encode(c))
+                .collect::<Option<Vec<_>>>()
+                .ok_or_else(|| Error::invalid_character_string(charset))?;
+
+            let chars = encoded.len() as u64;
+            let lower = min.unwrap_or(0);
+            let upper = max.unwrap_or(u64::MAX);
+            let out_of_range = chars < lower || chars > upper;
+
+            if extensible {
+                w.bits.write_bit(out_of_range)?;
+            }
+
+            if out_of_range {
+                if !extensible {
+                    return Err(Error::SizeNotInRange(chars, lower, upper));
+                } else {
+                    w.bits.write_length_determinant(None, None, chars)?;
+                }
+            } else {
+                w.bits.write_length_determinant(min, max, chars)?;
+            }
+
+            for value in encoded {
+                w.bits
+                    .write_bits_with_offset(&value.to_be_bytes(), 32 - alphabet.bits())?;
+            }
+
+            Ok(())
+        })
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 32.1: `UTCTime` and `GeneralizedTime` are
+    /// encoded as `VisibleString`
+    fn write_time_string(&mut self, time: TimeType, value: &str) -> Result<(), Error> {
+        if !time::is_valid(time, value) {
+            return Err(Error::InvalidTime(time));
+        }
+        let alphabet = Charset::Visible.alphabet();
+        self.write_known_multiplier_string(Charset::Visible, alphabet, None, None, false, value)
+    }
 }
 
 impl Writer for UperWriter {
@@ -271,8 +457,9 @@ impl Writer for UperWriter {
     ) -> Result<(), Self::Error> {
         self.write_bit_field_entry(false, true)?;
         self.with_buffer(|w| {
-            if let Some(extension_after) = C::EXTENDED_AFTER_FIELD {
-                w.bits.write_bit(C::FIELD_COUNT > extension_after)?;
+            if C::EXTENDED_AFTER_FIELD.is_some() {
+                // set once an extended field is present
+                w.bits.write_bit(false)?;
             }
 
             // In UPER the values for all OPTIONAL flags are written before any field
@@ -290,14 +477,37 @@ impl Writer for UperWriter {
             }
 
             if let Some(extension_after) = C::EXTENDED_AFTER_FIELD {
-                w.scope_pushed(
-                    Scope::ExtensibleSequence {
-                        opt_bit_field: Some(range),
-                        calls_until_ext_bitfield: (extension_after + 1) as usize,
-                        number_of_ext_fields: (C::FIELD_COUNT - (extension_after + 1)) as usize,
-                    },
-                    f,
-                )
+                let original = w.scope.replace(Scope::ExtensibleSequence {
+                    opt_bit_field: Some(range),
+                    calls_until_ext_bitfield: (extension_after + 1) as usize,
+                    number_of_ext_fields: (C::FIELD_COUNT - (extension_after + 1)) as usize,
+                    extensions_present: false,
+                });
+                let outer_unknown_extensions = core::mem::take(&mut w.unknown_extensions);
+                let result = f(w);
+                let scope = core::mem::replace(&mut w.scope, original);
+                let unknown_extensions =
+                    core::mem::replace(&mut w.unknown_extensions, outer_unknown_extensions);
+                result?;
+
+                let flags = match scope {
+                    // part2 was written because an extended field is present
+                    Some(Scope::AllBitField(range)) => range,
+                    scope if unknown_extensions.iter().all(Option::is_none) => {
+                        // save because this is supposed to be the original from above
+                        debug_assert!(scope.unwrap().exhausted());
+                        return Ok(());
+                    }
+                    // there are no known extended fields, which would have written part2 already
+                    Some(Scope::ExtensibleSequence {
+                        number_of_ext_fields,
+                        ..
+                    }) => Scope::write_extension_additions(&mut w.bits, number_of_ext_fields)?,
+                    _ => 0..0,
+                };
+                w.bits
+                    .with_write_position_at(write_pos - 1, |b| b.write_bit(true))?;
+                w.write_unknown_extension_additions(flags, &unknown_extensions)
             } else {
                 w.scope_pushed(Scope::OptBitField(range), f)
             }
@@ -381,7 +591,9 @@ impl Writer for UperWriter {
             w.bits
                 .write_choice_index(C::STD_VARIANT_COUNT, C::EXTENSIBLE, index)?;
 
-            if index >= C::STD_VARIANT_COUNT {
+            if let Some(bytes) = choice.unknown_extension() {
+                w.bits.write_octetstring(None, None, false, bytes)
+            } else if index >= C::STD_VARIANT_COUNT {
                 // TODO performance
                 let mut writer = UperWriter::with_capacity(512);
                 choice.write_content(&mut writer)?;
@@ -393,6 +605,36 @@ impl Writer for UperWriter {
         })
     }
 
+    #[inline]
+    fn write_open_type<C: opentype::Constraint>(
+        &mut self,
+        value: &C,
+        key: i64,
+    ) -> Result<(), Self::Error> {
+        if value.key() != key {
+            return Err(Error::MismatchedOpenTypeKey(key, value.key()));
+        }
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| {
+            w.scope_stashed(|w| {
+                // ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.2
+                if let Some(bytes) = value.unknown() {
+                    w.bits.write_octetstring(None, None, false, bytes)
+                } else {
+                    // TODO performance
+                    let mut writer = UperWriter::with_capacity(512);
+                    value.write_content(&mut writer)?;
+                    if writer.bit_len() == 0 {
+                        w.bits.write_octetstring(None, None, false, &[0x00])
+                    } else {
+                        w.bits
+                            .write_octetstring(None, None, false, writer.byte_content())
+                    }
+                }
+            })
+        })
+    }
+
     #[inline]
     #[allow(clippy::redundant_pattern_matching)] // allow for const_*!
     fn write_opt<T: WritableType>(
@@ -401,7 +643,8 @@ impl Writer for UperWriter {
     ) -> Result<(), Self::Error> {
         self.write_bit_field_entry(true, const_is_some!(value))?;
         if let Some(value) = value {
-            self.scope_stashed(|w| T::write_value(w, value))
+            // extended fields are encoded as open type, regardless of being OPTIONAL
+            self.with_buffer(|w| w.scope_stashed(|w| T::write_value(w, value)))
         } else {
             Ok(())
         }
@@ -416,10 +659,14 @@ impl Writer for UperWriter {
         self.write_bit_field_entry(false, true)?;
         let value = value.to_i64();
 
+        if !C::EXTENSIBLE && !C::permits(value) {
+            return Err(Error::ValueNotPermitted(value.to_string()));
+        }
+
         let max_fn = if C::EXTENSIBLE {
             let min = const_unwrap_or!(C::MIN, 0);
             let max = const_unwrap_or!(C::MAX, i64::MAX);
-            value < min || value > max
+            value < min || value > max || !C::permits(value)
         } else {
             const_is_none!(C::MIN) && const_is_none!(C::MAX)
         };
@@ -445,6 +692,30 @@ impl Writer for UperWriter {
         }
     }
 
+    #[inline]
+    fn write_wide_number<T: numbers::WideNumber, C: numbers::WideConstraint>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| {
+            if !C::permits(value) {
+                return if C::EXTENSIBLE {
+                    crate::io::per::wide::write_wide_extension_value(&mut w.bits, value)
+                } else {
+                    Err(Error::ValueNotPermitted(value.to_string()))
+                };
+            }
+            crate::io::per::wide::write_wide_whole_number(
+                &mut w.bits,
+                C::MIN,
+                C::MAX,
+                C::EXTENSIBLE,
+                value,
+            )
+        })
+    }
+
     #[inline]
     #[allow(clippy::redundant_pattern_matching)] // allow for const_*!
     fn write_utf8string<C: utf8string::Constraint>(
@@ -474,38 +745,89 @@ impl Writer for UperWriter {
         &mut self,
         value: &str,
     ) -> Result<(), Self::Error> {
-        self.write_bit_field_entry(false, true)?;
-        self.with_buffer(|w| {
-            if value.chars().any(|c| c as u32 >= 128) {
-                return Err(Error::InvalidIa5String);
-            }
+        self.write_known_multiplier_string(
+            Charset::Ia5,
+            C::ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            value,
+        )
+    }
 
-            let chars = value.chars().count() as u64;
-            let min = const_unwrap_or!(C::MIN, 0);
-            let max = const_unwrap_or!(C::MAX, u64::MAX);
-            let out_of_range = chars < min || chars > max;
+    #[inline]
+    fn write_numericstring<C: numericstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_known_multiplier_string(
+            Charset::Numeric,
+            C::ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            value,
+        )
+    }
 
-            if C::EXTENSIBLE {
-                w.bits.write_bit(out_of_range)?;
-            }
+    #[inline]
+    fn write_printablestring<C: printablestring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_known_multiplier_string(
+            Charset::Printable,
+            C::ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            value,
+        )
+    }
 
-            if out_of_range {
-                if !C::EXTENSIBLE {
-                    return Err(Error::SizeNotInRange(chars, min, max));
-                } else {
-                    w.bits.write_length_determinant(None, None, chars)?;
-                }
-            } else {
-                w.bits.write_length_determinant(C::MIN, C::MAX, chars)?;
-            }
+    #[inline]
+    fn write_visiblestring<C: visiblestring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_known_multiplier_string(
+            Charset::Visible,
+            C::ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            value,
+        )
+    }
 
-            for char in value.chars().map(|c| c as u8) {
-                // 7 bits
-                w.bits.write_bits_with_offset(&[char], 1)?;
-            }
+    #[inline]
+    fn write_bmpstring<C: bmpstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_known_multiplier_string(
+            Charset::Bmp,
+            C::ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            value,
+        )
+    }
 
-            Ok(())
-        })
+    #[inline]
+    fn write_universalstring<C: universalstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_known_multiplier_string(
+            Charset::Universal,
+            C::ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            value,
+        )
     }
 
     #[inline]
@@ -538,6 +860,159 @@ impl Writer for UperWriter {
         self.write_bit_field_entry(false, true)?;
         self.with_buffer(|w| w.bits.write_bit(value))
     }
+
+    #[inline]
+    fn write_null<C: null::Constraint>(&mut self) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|_| Ok(()))
+    }
+
+    #[inline]
+    fn write_real<C: real::Constraint>(&mut self, value: f64) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| {
+            w.bits
+                .write_octetstring(None, None, false, &crate::io::real::to_octets(value))
+        })
+    }
+
+    #[inline]
+    fn write_object_identifier<C: objectidentifier::Constraint>(
+        &mut self,
+        value: &[u64],
+    ) -> Result<(), Self::Error> {
+        let octets = crate::io::oid::object_identifier_to_octets(value)
+            .ok_or(Error::InvalidObjectIdentifier)?;
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| w.bits.write_octetstring(None, None, false, &octets))
+    }
+
+    #[inline]
+    fn write_relative_oid<C: relativeoid::Constraint>(
+        &mut self,
+        value: &[u64],
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| {
+            w.bits.write_octetstring(
+                None,
+                None,
+                false,
+                &crate::io::oid::relative_oid_to_octets(value),
+            )
+        })
+    }
+
+    #[inline]
+    fn write_utc_time<C: utctime::Constraint>(&mut self, value: &str) -> Result<(), Self::Error> {
+        self.write_time_string(TimeType::UtcTime, value)
+    }
+
+    #[inline]
+    fn write_generalized_time<C: generalizedtime::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_time_string(TimeType::GeneralizedTime, value)
+    }
+
+    #[inline]
+    fn write_date<C: date::Constraint>(&mut self, value: &str) -> Result<(), Self::Error> {
+        let date = time::Date::parse(value).ok_or(Error::InvalidTime(TimeType::Date))?;
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| crate::io::per::time::write_date(&mut w.bits, &date))
+    }
+
+    #[inline]
+    fn write_time_of_day<C: timeofday::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        let time = time::TimeOfDay::parse(value).ok_or(Error::InvalidTime(TimeType::TimeOfDay))?;
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| crate::io::per::time::write_time_of_day(&mut w.bits, &time))
+    }
+
+    #[inline]
+    fn write_date_time<C: datetime::Constraint>(&mut self, value: &str) -> Result<(), Self::Error> {
+        let date_time =
+            time::DateTime::parse(value).ok_or(Error::InvalidTime(TimeType::DateTime))?;
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| crate::io::per::time::write_date_time(&mut w.bits, &date_time))
+    }
+
+    #[inline]
+    fn write_duration<C: duration::Constraint>(&mut self, value: &str) -> Result<(), Self::Error> {
+        let duration =
+            time::Duration::parse(value).ok_or(Error::InvalidTime(TimeType::Duration))?;
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| crate::io::per::time::write_duration(&mut w.bits, &duration))
+    }
+
+    #[inline]
+    fn write_with_components<C: components::Constraint, T: WritableType>(
+        &mut self,
+        value: &T::Type,
+    ) -> Result<(), Self::Error>
+    where
+        T::Type: components::Components,
+    {
+        C::COMPONENTS
+            .check(value)
+            .map_err(Error::InvalidComponents)?;
+        T::write_value(self, value)
+    }
+
+    #[inline]
+    fn write_containing<C: containing::Constraint, T: WritableType>(
+        &mut self,
+        value: &T::Type,
+    ) -> Result<(), Self::Error> {
+        let mut writer = UperWriter::default();
+        T::write_value(&mut writer, value)?;
+        if writer.bit_len() == 0 {
+            // ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.1.3: a complete encoding has at
+            // least one octet
+            containing::write_contents::<C, _>(self, &[0x00])
+        } else {
+            containing::write_contents::<C, _>(self, writer.byte_content())
+        }
+    }
+
+    #[inline]
+    fn write_explicit<C: explicit::Constraint, T: WritableType>(
+        &mut self,
+        value: &T::Type,
+    ) -> Result<(), Self::Error> {
+        // tags are not encoded
+        T::write_value(self, value)
+    }
+
+    #[inline]
+    fn write_unknown_extensions(
+        &mut self,
+        extensions: &[Option<Vec<u8>>],
+    ) -> Result<(), Self::Error> {
+        if extensions.is_empty() {
+            return Ok(());
+        }
+        match &mut self.scope {
+            Some(Scope::ExtensibleSequence {
+                number_of_ext_fields,
+                extensions_present,
+                ..
+            }) => {
+                *number_of_ext_fields += extensions.len();
+                *extensions_present = extensions.iter().any(Option::is_some);
+                self.unknown_extensions = extensions.to_vec();
+                Ok(())
+            }
+            _ => Err(Error::UnsupportedOperation(
+                "Unknown extensions can only be written before the fields of an extensible sequence"
+                    .to_string(),
+            )),
+        }
+    }
 }
 
 pub struct UperReader<B: ScopedBitRead> {
@@ -608,6 +1083,25 @@ impl<B: ScopedBitRead> UperReader<B> {
         result
     }
 
+    /// Skips the open types of all present extended fields that are unknown to the struct
+    /// being read
+    #[inline]
+    pub fn skip_extension_additions(&mut self, unknown: Range<usize>) -> Result<(), Error> {
+        for flag in unknown {
+            if self
+                .bits
+                .with_read_position_at(flag, |buffer| buffer.read_bit())?
+            {
+                let len = self.bits.read_length_determinant(None, None)? as usize * BYTE_LEN;
+                if self.bits.remaining() < len {
+                    return Err(Error::EndOfStream);
+                }
+                self.bits.set_pos(self.bits.pos() + len);
+            }
+        }
+        Ok(())
+    }
+
     #[inline]
     pub fn read_bit_field_entry(&mut self, is_opt: bool) -> Result<Option<bool>, Error> {
         if let Some(scope) = &mut self.scope {
@@ -636,6 +1130,53 @@ impl<B: ScopedBitRead> UperReader<B> {
             f(self)
         }
     }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 30.5: each character of a known-multiplier
+    /// character string is encoded with the number of bits its permitted alphabet requires
+    fn read_known_multiplier_string(
+        &mut self,
+        charset: Charset,
+        alphabet: &[(u32, u32)],
+        min: Option<u64>,
+        max: Option<u64>,
+        extensible: bool,
+    ) -> Result<String, Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|r| {
+            let alphabet = Alphabet::new(alphabet, false)?;
+            let len = if extensible && r.bits.read_bit()? {
+                r.bits.read_length_determinant(None, None)?
+            } else {
+                r.bits.read_length_determinant(min, max)?
+            };
+
+            let mut string = String::with_capacity(len as usize);
+            for _ in 0..len {
+                let mut buffer = [0u8; 4];
+                r.bits
+                    .read_bits_with_offset(&mut buffer[..], 32 - alphabet.bits())?;
+                let character = alphabet
+                    .decode(u32::from_be_bytes(buffer))
+                    .ok_or_else(|| Error::invalid_character_string(charset))?;
+                string.push(character);
+            }
+
+            Ok(string)
+        })
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 32.1: `UTCTime` and `GeneralizedTime` are
+    /// encoded as `VisibleString`
+    fn read_time_string(&mut self, time: TimeType) -> Result<String, Error> {
+        let alphabet = Charset::Visible.alphabet();
+        let value =
+            self.read_known_multiplier_string(Charset::Visible, alphabet, None, None, false)?;
+        if time::is_valid(time, &value) {
+            Ok(value)
+        } else {
+            Err(Error::InvalidTime(time))
+        }
+    }
 }
 
 impl<B: ScopedBitRead> Reader for UperReader<B> {
@@ -650,18 +1191,10 @@ impl<B: ScopedBitRead> Reader for UperReader<B> {
         &mut self,
         f: F,
     ) -> Result<S, Self::Error> {
-        let _ = self.read_bit_field_entry(false);
+        let _ = self.read_bit_field_entry(false)?;
         self.with_buffer(|r| {
-            if let Some(extension_after) = C::EXTENDED_AFTER_FIELD {
-                let has_extension = r.bits.read_bit()?;
-                let expects_extension = C::FIELD_COUNT > extension_after;
-                if has_extension != expects_extension {
-                    return Err(Error::InvalidExtensionConstellation(
-                        expects_extension,
-                        has_extension,
-                    ));
-                }
-            }
+            // the sender might know more or less extended fields than this struct does
+            let extensions_present = C::EXTENDED_AFTER_FIELD.is_some() && r.bits.read_bit()?;
 
             // In UPER the values for all OPTIONAL flags are written before any field
             // value is written. This remembers their position, so a later call of `read_opt`
@@ -673,14 +1206,29 @@ impl<B: ScopedBitRead> Reader for UperReader<B> {
             r.bits.set_pos(range.end); // skip optional
 
             if let Some(extension_after) = C::EXTENDED_AFTER_FIELD {
-                r.scope_pushed(
-                    Scope::ExtensibleSequence {
-                        opt_bit_field: Some(range),
-                        calls_until_ext_bitfield: (extension_after + 1) as usize,
-                        number_of_ext_fields: (C::FIELD_COUNT - (extension_after + 1)) as usize,
+                let original = r.scope.replace(Scope::ExtensibleSequence {
+                    opt_bit_field: Some(range),
+                    calls_until_ext_bitfield: (extension_after + 1) as usize,
+                    number_of_ext_fields: (C::FIELD_COUNT - (extension_after + 1)) as usize,
+                    extensions_present,
+                });
+                let result = f(r);
+                let scope = core::mem::replace(&mut r.scope, original);
+                let result = result?;
+                let unknown = match scope {
+                    Some(Scope::ExtensionAdditions { unknown, .. }) => unknown,
+                    // there are no known extended fields, which would have read part2 already
+                    Some(Scope::ExtensibleSequence {
+                        extensions_present: true,
+                        ..
+                    }) => match Scope::read_extension_additions(&mut r.bits, 0)? {
+                        Scope::ExtensionAdditions { unknown, .. } => unknown,
+                        _ => unreachable!(),
                     },
-                    f,
-                )
+                    _ => 0..0,
+                };
+                r.skip_extension_additions(unknown)?;
+                Ok(result)
             } else {
                 r.scope_pushed(Scope::OptBitField(range), f)
             }
@@ -742,7 +1290,11 @@ impl<B: ScopedBitRead> Reader for UperReader<B> {
             let index = r
                 .bits
                 .read_choice_index(C::STD_VARIANT_COUNT, C::EXTENSIBLE)?;
-            if index >= C::STD_VARIANT_COUNT {
+            if index >= C::VARIANT_COUNT {
+                // an extension that is unknown to this definition
+                let bytes = r.bits.read_octetstring(None, None, false)?;
+                Ok((index, C::from_unknown_extension(index, bytes)))
+            } else if index >= C::STD_VARIANT_COUNT {
                 let length = r.bits.read_length_determinant(None, None)?;
                 r.read_whole_sub_slice(length as usize, |r| Ok((index, C::read_content(index, r)?)))
             } else {
@@ -754,13 +1306,30 @@ impl<B: ScopedBitRead> Reader for UperReader<B> {
         })
     }
 
+    #[inline]
+    fn read_open_type<C: opentype::Constraint>(&mut self, key: i64) -> Result<C, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|r| {
+            r.scope_stashed(|r| {
+                let bytes = r.bits.read_octetstring(None, None, false)?;
+                match C::read_content(key, &mut UperReader::from(&bytes[..]))? {
+                    Some(value) => Ok(Some(value)),
+                    // the value of a key that is unknown to the object set
+                    None => Ok(C::from_unknown(key, bytes)),
+                }
+                .and_then(|value| value.ok_or(Error::UnknownOpenTypeKey(key)))
+            })
+        })
+    }
+
     #[inline]
     fn read_opt<T: ReadableType>(
         &mut self,
     ) -> Result<Option<<T as ReadableType>::Type>, Self::Error> {
         // unwrap: as opt-field this must and will return some value
         if self.read_bit_field_entry(true)?.unwrap() {
-            self.scope_stashed(T::read_value).map(Some)
+            self.with_buffer(|r| r.scope_stashed(T::read_value))
+                .map(Some)
         } else {
             Ok(None)
         }
@@ -792,6 +1361,16 @@ impl<B: ScopedBitRead> Reader for UperReader<B> {
         })
     }
 
+    #[inline]
+    fn read_wide_number<T: numbers::WideNumber, C: numbers::WideConstraint>(
+        &mut self,
+    ) -> Result<T, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|r| {
+            crate::io::per::wide::read_wide_whole_number(&mut r.bits, C::MIN, C::MAX, C::EXTENSIBLE)
+        })
+    }
+
     #[inline]
     fn read_utf8string<C: utf8string::Constraint>(&mut self) -> Result<String, Self::Error> {
         let _ = self.read_bit_field_entry(false)?;
@@ -805,21 +1384,60 @@ impl<B: ScopedBitRead> Reader for UperReader<B> {
 
     #[inline]
     fn read_ia5string<C: ia5string::Constraint>(&mut self) -> Result<String, Self::Error> {
-        let _ = self.read_bit_field_entry(false)?;
-        self.with_buffer(|r| {
-            let len = if C::EXTENSIBLE && r.bits.read_bit()? {
-                r.bits.read_length_determinant(None, None)?
-            } else {
-                r.bits.read_length_determinant(C::MIN, C::MAX)?
-            };
+        self.read_known_multiplier_string(Charset::Ia5, C::ALPHABET, C::MIN, C::MAX, C::EXTENSIBLE)
+    }
 
-            let mut buffer = vec![0u8; len as usize];
-            for i in 0..len as usize {
-                r.bits.read_bits_with_offset(&mut buffer[i..i + 1], 1)?;
-            }
+    #[inline]
+    fn read_numericstring<C: numericstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_known_multiplier_string(
+            Charset::Numeric,
+            C::ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )
+    }
 
-            String::from_utf8(buffer).map_err(|_| Error::InvalidIa5String)
-        })
+    #[inline]
+    fn read_printablestring<C: printablestring::Constraint>(
+        &mut self,
+    ) -> Result<String, Self::Error> {
+        self.read_known_multiplier_string(
+            Charset::Printable,
+            C::ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )
+    }
+
+    #[inline]
+    fn read_visiblestring<C: visiblestring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_known_multiplier_string(
+            Charset::Visible,
+            C::ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )
+    }
+
+    #[inline]
+    fn read_bmpstring<C: bmpstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_known_multiplier_string(Charset::Bmp, C::ALPHABET, C::MIN, C::MAX, C::EXTENSIBLE)
+    }
+
+    #[inline]
+    fn read_universalstring<C: universalstring::Constraint>(
+        &mut self,
+    ) -> Result<String, Self::Error> {
+        self.read_known_multiplier_string(
+            Charset::Universal,
+            C::ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )
     }
 
     #[inline]
@@ -839,6 +1457,136 @@ impl<B: ScopedBitRead> Reader for UperReader<B> {
         let _ = self.read_bit_field_entry(false)?;
         self.with_buffer(|r| r.bits.read_boolean())
     }
+
+    #[inline]
+    fn read_null<C: null::Constraint>(&mut self) -> Result<(), Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|_| Ok(()))
+    }
+
+    #[inline]
+    fn read_real<C: real::Constraint>(&mut self) -> Result<f64, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        let octets = self.with_buffer(|r| r.bits.read_octetstring(None, None, false))?;
+        crate::io::real::from_octets(&octets).ok_or(Error::InvalidReal)
+    }
+
+    #[inline]
+    fn read_object_identifier<C: objectidentifier::Constraint>(
+        &mut self,
+    ) -> Result<Vec<u64>, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        let octets = self.with_buffer(|r| r.bits.read_octetstring(None, None, false))?;
+        crate::io::oid::object_identifier_from_octets(&octets).ok_or(Error::InvalidObjectIdentifier)
+    }
+
+    #[inline]
+    fn read_relative_oid<C: relativeoid::Constraint>(&mut self) -> Result<Vec<u64>, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        let octets = self.with_buffer(|r| r.bits.read_octetstring(None, None, false))?;
+        crate::io::oid::relative_oid_from_octets(&octets).ok_or(Error::InvalidObjectIdentifier)
+    }
+
+    #[inline]
+    fn read_utc_time<C: utctime::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_time_string(TimeType::UtcTime)
+    }
+
+    #[inline]
+    fn read_generalized_time<C: generalizedtime::Constraint>(
+        &mut self,
+    ) -> Result<String, Self::Error> {
+        self.read_time_string(TimeType::GeneralizedTime)
+    }
+
+    #[inline]
+    fn read_date<C: date::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|r| crate::io::per::time::read_date(&mut r.bits))
+            .map(|date| date.to_string())
+    }
+
+    #[inline]
+    fn read_time_of_day<C: timeofday::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|r| crate::io::per::time::read_time_of_day(&mut r.bits))
+            .map(|time| time.to_string())
+    }
+
+    #[inline]
+    fn read_date_time<C: datetime::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|r| crate::io::per::time::read_date_time(&mut r.bits))
+            .map(|date_time| date_time.to_string())
+    }
+
+    #[inline]
+    fn read_duration<C: duration::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|r| crate::io::per::time::read_duration(&mut r.bits))
+            .map(|duration| duration.to_string())
+    }
+
+    #[inline]
+    fn read_with_components<C: components::Constraint, T: ReadableType>(
+        &mut self,
+    ) -> Result<T::Type, Self::Error>
+    where
+        T::Type: components::Components,
+    {
+        let value = T::read_value(self)?;
+        C::COMPONENTS
+            .check(&value)
+            .map_err(Error::InvalidComponents)?;
+        Ok(value)
+    }
+
+    #[inline]
+    fn read_containing<C: containing::Constraint, T: ReadableType>(
+        &mut self,
+    ) -> Result<T::Type, Self::Error> {
+        let bytes = containing::read_contents::<C, _>(self)?;
+        T::read_value(&mut UperReader::from((&bytes[..], bytes.len() * BYTE_LEN)))
+    }
+
+    #[inline]
+    fn read_explicit<C: explicit::Constraint, T: ReadableType>(
+        &mut self,
+    ) -> Result<T::Type, Self::Error> {
+        // tags are not encoded
+        T::read_value(self)
+    }
+
+    #[inline]
+    fn read_unknown_extensions(&mut self) -> Result<Vec<Option<Vec<u8>>>, Self::Error> {
+        if let Some(Scope::ExtensibleSequence {
+            number_of_ext_fields,
+            extensions_present: true,
+            ..
+        }) = self.scope
+        {
+            // there are no known extended fields, which would have read part2 already
+            self.scope = Some(Scope::read_extension_additions(
+                &mut self.bits,
+                number_of_ext_fields,
+            )?);
+        }
+        let unknown = match &mut self.scope {
+            Some(Scope::ExtensionAdditions { unknown, .. }) => {
+                core::mem::replace(unknown, unknown.end..unknown.end)
+            }
+            _ => return Ok(Vec::default()),
+        };
+        let mut extensions = Vec::with_capacity(unknown.len());
+        for flag in unknown {
+            if self.bits.with_read_position_at(flag, |b| b.read_bit())? {
+                extensions.push(Some(self.bits.read_octetstring(None, None, false)?));
+            } else {
+                extensions.push(None);
+            }
+        }
+        Ok(extensions)
+    }
 }
 
 pub trait UperDecodable<'a, I: Into<Bits<'a>> + 'a> {
@@ -0,0 +1,1043 @@
+use crate::io::xer::err::Error;
+use crate::io::xer::{self, Element};
+use crate::model::{Charset, TimeType};
+use crate::syn::*;
+
+#[inline]
+fn check_size(
+    min: Option<u64>,
+    max: Option<u64>,
+    extensible: bool,
+    size: u64,
+) -> Result<(), Error> {
+    let (min, max) = (min.unwrap_or(0), max.unwrap_or(u64::MAX));
+    if extensible || (min..=max).contains(&size) {
+        Ok(())
+    } else {
+        Err(Error::SizeNotInRange(size, min, max))
+    }
+}
+
+/// Checks that all characters of the given value are part of the charset, the value itself is
+/// encoded as escaped text (ITU-T X.693 | ISO/IEC 8825-4:2015, chapter 8.2.5 and 11.3)
+#[inline]
+fn check_charset(charset: Charset, value: &str) -> Result<(), Error> {
+    if value.chars().all(|c| charset.contains(c)) {
+        Ok(())
+    } else {
+        Err(Error::InvalidCharacterString(charset))
+    }
+}
+
+#[inline]
+fn check_wide_range<T: numbers::WideNumber>(
+    min: Option<i128>,
+    max: Option<i128>,
+    extensible: bool,
+    value: &T,
+) -> Result<(), Error> {
+    if extensible || value.is_within(min, max) {
+        Ok(())
+    } else {
+        Err(Error::WideValueNotInRange(value.to_string(), min, max))
+    }
+}
+
+#[inline]
+fn check_permitted(
+    permitted: bool,
+    extensible: bool,
+    value: &impl core::fmt::Display,
+) -> Result<(), Error> {
+    if extensible || permitted {
+        Ok(())
+    } else {
+        Err(Error::ValueNotPermitted(value.to_string()))
+    }
+}
+
+fn check_range(
+    min: Option<i64>,
+    max: Option<i64>,
+    extensible: bool,
+    value: i64,
+) -> Result<(), Error> {
+    let (min, max) = (min.unwrap_or(i64::MIN), max.unwrap_or(i64::MAX));
+    if extensible || (min..=max).contains(&value) {
+        Ok(())
+    } else {
+        Err(Error::ValueNotInRange(value, min, max))
+    }
+}
+
+/// ITU-T X.693 | ISO/IEC 8825-4:2015, chapter 11.11 and 11.12: the arcs of object identifiers
+/// are written as dot-separated numbers
+#[inline]
+fn dotted_arcs(arcs: &[u64]) -> String {
+    arcs.iter()
+        .map(u64::to_string)
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+#[inline]
+fn parse_dotted_arcs(value: &str) -> Result<Vec<u64>, Error> {
+    value
+        .trim()
+        .split('.')
+        .map(|arc| {
+            if arc.bytes().all(|b| b.is_ascii_digit()) {
+                arc.parse().ok()
+            } else {
+                None
+            }
+        })
+        .collect::<Option<Vec<_>>>()
+        .ok_or(Error::InvalidObjectIdentifier)
+}
+
+#[inline]
+fn identifier(names: &'static [&'static str], index: usize) -> Result<&'static str, Error> {
+    names
+        .get(index)
+        .copied()
+        .ok_or(Error::MissingIdentifier(index))
+}
+
+/// An encoded value and the name of its type
+struct Item {
+    /// The name of the element that encloses the value in a `SEQUENCE OF` or as outermost value
+    name: &'static str,
+    /// ITU-T X.680 | ISO/IEC 8824-1:2015, values of `BOOLEAN`,
+    /// `ENUMERATED` and `CHOICE` types are not enclosed by the name of their type within a
+    /// `SEQUENCE OF` (XMLValueList)
+    value_list: bool,
+    content: String,
+}
+
+/// The element that is currently being written
+enum WriteFrame {
+    /// The content of a `SEQUENCE` or `SET`, each field is enclosed by its identifier
+    Fields {
+        names: &'static [&'static str],
+        index: usize,
+        content: String,
+    },
+    /// The content of a `SEQUENCE OF` or `SET OF`
+    List(String),
+    /// The value of a `CHOICE` variant or of a transparent `SEQUENCE`
+    Single(Option<Item>),
+}
+
+/// Encodes values according to the BASIC-XER variant of the XML Encoding Rules, see ITU-T X.693
+/// | ISO/IEC 8825-4:2015. The outermost element is named after the Rust type, fields and
+/// variants are named by their original ASN.1 identifiers. No XML declaration, no indentation
+/// and no white-space is written.
+#[derive(Default)]
+pub struct XerWriter {
+    frames: Vec<WriteFrame>,
+    root: Option<Item>,
+}
+
+impl XerWriter {
+    /// The XML document of the written value, which is empty if nothing has been written yet
+    pub fn into_string(self) -> String {
+        self.root
+            .map(|item| format!("<{0}>{1}</{0}>", item.name, item.content))
+            .unwrap_or_default()
+    }
+
+    fn push(&mut self, item: Item) -> Result<(), Error> {
+        match self.frames.last_mut() {
+            Some(WriteFrame::Fields {
+                names,
+                index,
+                content,
+            }) => {
+                let name = identifier(names, *index)?;
+                *index += 1;
+                content.push_str(&format!("<{0}>{1}</{0}>", name, item.content));
+            }
+            Some(WriteFrame::List(content)) if item.value_list => {
+                content.push_str(&item.content);
+            }
+            Some(WriteFrame::List(content)) => {
+                content.push_str(&format!("<{0}>{1}</{0}>", item.name, item.content));
+            }
+            Some(WriteFrame::Single(slot)) => *slot = Some(item),
+            None => self.root = Some(item),
+        }
+        Ok(())
+    }
+
+    fn push_value(&mut self, name: &'static str, content: String) -> Result<(), Error> {
+        self.push(Item {
+            name,
+            value_list: false,
+            content,
+        })
+    }
+
+    fn write_time(&mut self, time: TimeType, value: &str) -> Result<(), Error> {
+        if !crate::io::time::is_valid(time, value) {
+            return Err(Error::InvalidTime(time));
+        }
+        self.push_value(time.name(), value.to_string())
+    }
+
+    fn nested<F: FnOnce(&mut Self) -> Result<(), Error>>(
+        &mut self,
+        frame: WriteFrame,
+        f: F,
+    ) -> Result<WriteFrame, Error> {
+        self.frames.push(frame);
+        let result = f(self);
+        let frame = self.frames.pop().ok_or(Error::EndOfStream)?;
+        result.map(|_| frame)
+    }
+
+    fn nested_single<F: FnOnce(&mut Self) -> Result<(), Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<Item, Error> {
+        match self.nested(WriteFrame::Single(None), f)? {
+            WriteFrame::Single(Some(item)) => Ok(item),
+            _ => Err(Error::EndOfStream),
+        }
+    }
+
+    fn write_list<T: WritableType>(
+        &mut self,
+        name: &'static str,
+        slice: &[<T as WritableType>::Type],
+    ) -> Result<(), Error> {
+        let frame = self.nested(WriteFrame::List(String::new()), |w| {
+            for value in slice {
+                T::write_value(w, value)?;
+            }
+            Ok(())
+        })?;
+        if let WriteFrame::List(content) = frame {
+            self.push_value(name, content)?;
+        }
+        Ok(())
+    }
+}
+
+impl Writer for XerWriter {
+    type Error = Error;
+
+    #[inline]
+    fn write_sequence<C: sequence::Constraint, F: Fn(&mut Self) -> Result<(), Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<(), Self::Error> {
+        if C::TRANSPARENT {
+            let item = self.nested_single(f)?;
+            return self.push(Item {
+                name: C::NAME,
+                ..item
+            });
+        }
+        let frame = WriteFrame::Fields {
+            names: C::FIELD_NAMES,
+            index: 0,
+            content: String::new(),
+        };
+        if let WriteFrame::Fields { content, .. } = self.nested(frame, f)? {
+            self.push_value(C::NAME, content)?;
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn write_sequence_of<C: sequenceof::Constraint, T: WritableType>(
+        &mut self,
+        slice: &[<T as WritableType>::Type],
+    ) -> Result<(), Self::Error> {
+        check_size(C::MIN, C::MAX, C::EXTENSIBLE, slice.len() as u64)?;
+        self.write_list::<T>("SEQUENCE_OF", slice)
+    }
+
+    #[inline]
+    fn write_set<C: set::Constraint, F: Fn(&mut Self) -> Result<(), Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<(), Self::Error> {
+        self.write_sequence::<C, F>(f)
+    }
+
+    #[inline]
+    fn write_set_of<C: setof::Constraint, T: WritableType>(
+        &mut self,
+        slice: &[<T as WritableType>::Type],
+    ) -> Result<(), Self::Error> {
+        check_size(C::MIN, C::MAX, C::EXTENSIBLE, slice.len() as u64)?;
+        self.write_list::<T>("SET_OF", slice)
+    }
+
+    #[inline]
+    fn write_enumerated<C: enumerated::Constraint>(
+        &mut self,
+        enumerated: &C,
+    ) -> Result<(), Self::Error> {
+        let name = identifier(C::VARIANT_NAMES, enumerated.to_choice_index() as usize)?;
+        self.push(Item {
+            name: C::NAME,
+            value_list: true,
+            content: format!("<{}/>", name),
+        })
+    }
+
+    #[inline]
+    fn write_choice<C: choice::Constraint>(&mut self, choice: &C) -> Result<(), Self::Error> {
+        let name = identifier(C::VARIANT_NAMES, choice.to_choice_index() as usize)?;
+        let item = self.nested_single(|w| choice.write_content(w))?;
+        self.push(Item {
+            name: C::NAME,
+            value_list: true,
+            content: format!("<{0}>{1}</{0}>", name, item.content),
+        })
+    }
+
+    #[inline]
+    fn write_open_type<C: opentype::Constraint>(
+        &mut self,
+        value: &C,
+        key: i64,
+    ) -> Result<(), Self::Error> {
+        if value.key() != key {
+            return Err(Error::MismatchedOpenTypeKey(key, value.key()));
+        }
+        if value.unknown().is_some() {
+            // the preserved binary encoding cannot be embedded in XML
+            return Err(Error::UnknownOpenTypeKey(key));
+        }
+        value.write_content(self)
+    }
+
+    #[inline]
+    fn write_opt<T: WritableType>(
+        &mut self,
+        value: Option<&<T as WritableType>::Type>,
+    ) -> Result<(), Self::Error> {
+        match value {
+            Some(value) => T::write_value(self, value),
+            None => {
+                // absent fields are omitted
+                if let Some(WriteFrame::Fields { index, .. }) = self.frames.last_mut() {
+                    *index += 1;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    #[inline]
+    fn write_number<T: numbers::Number, C: numbers::Constraint<T>>(
+        &mut self,
+        value: T,
+    ) -> Result<(), Self::Error> {
+        let value = value.to_i64();
+        check_range(C::MIN, C::MAX, C::EXTENSIBLE, value)?;
+        check_permitted(C::permits(value), C::EXTENSIBLE, &value)?;
+        self.push_value("INTEGER", value.to_string())
+    }
+
+    #[inline]
+    fn write_wide_number<T: numbers::WideNumber, C: numbers::WideConstraint>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        check_wide_range(C::MIN, C::MAX, C::EXTENSIBLE, value)?;
+        check_permitted(C::permits(value), C::EXTENSIBLE, value)?;
+        self.push_value("INTEGER", value.to_string())
+    }
+
+    #[inline]
+    fn write_utf8string<C: utf8string::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        check_size(C::MIN, C::MAX, C::EXTENSIBLE, value.chars().count() as u64)?;
+        let mut content = String::with_capacity(value.len());
+        xer::write_text(&mut content, value);
+        self.push_value("UTF8String", content)
+    }
+
+    #[inline]
+    fn write_ia5string<C: ia5string::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        if !value.is_ascii() {
+            return Err(Error::InvalidIa5String);
+        }
+        check_size(C::MIN, C::MAX, C::EXTENSIBLE, value.len() as u64)?;
+        let mut content = String::with_capacity(value.len());
+        xer::write_text(&mut content, value);
+        self.push_value("IA5String", content)
+    }
+
+    #[inline]
+    fn write_numericstring<C: numericstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        check_charset(Charset::Numeric, value)?;
+        check_size(C::MIN, C::MAX, C::EXTENSIBLE, value.chars().count() as u64)?;
+        let mut content = String::with_capacity(value.len());
+        xer::write_text(&mut content, value);
+        self.push_value("NumericString", content)
+    }
+
+    #[inline]
+    fn write_printablestring<C: printablestring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        check_charset(Charset::Printable, value)?;
+        check_size(C::MIN, C::MAX, C::EXTENSIBLE, value.chars().count() as u64)?;
+        let mut content = String::with_capacity(value.len());
+        xer::write_text(&mut content, value);
+        self.push_value("PrintableString", content)
+    }
+
+    #[inline]
+    fn write_visiblestring<C: visiblestring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        check_charset(Charset::Visible, value)?;
+        check_size(C::MIN, C::MAX, C::EXTENSIBLE, value.chars().count() as u64)?;
+        let mut content = String::with_capacity(value.len());
+        xer::write_text(&mut content, value);
+        self.push_value("VisibleString", content)
+    }
+
+    #[inline]
+    fn write_bmpstring<C: bmpstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        check_charset(Charset::Bmp, value)?;
+        check_size(C::MIN, C::MAX, C::EXTENSIBLE, value.chars().count() as u64)?;
+        let mut content = String::with_capacity(value.len());
+        xer::write_text(&mut content, value);
+        self.push_value("BMPString", content)
+    }
+
+    #[inline]
+    fn write_universalstring<C: universalstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        check_charset(Charset::Universal, value)?;
+        check_size(C::MIN, C::MAX, C::EXTENSIBLE, value.chars().count() as u64)?;
+        let mut content = String::with_capacity(value.len());
+        xer::write_text(&mut content, value);
+        self.push_value("UniversalString", content)
+    }
+
+    #[inline]
+    fn write_octet_string<C: octetstring::Constraint>(
+        &mut self,
+        value: &[u8],
+    ) -> Result<(), Self::Error> {
+        check_size(C::MIN, C::MAX, C::EXTENSIBLE, value.len() as u64)?;
+        self.push_value("OCTET_STRING", xer::to_hex(value))
+    }
+
+    #[inline]
+    fn write_bit_string<C: bitstring::Constraint>(
+        &mut self,
+        value: &[u8],
+        bit_len: u64,
+    ) -> Result<(), Self::Error> {
+        check_size(C::MIN, C::MAX, C::EXTENSIBLE, bit_len)?;
+        if (value.len() as u64) < bit_len.div_ceil(8) {
+            return Err(Error::EndOfStream);
+        }
+        self.push_value("BIT_STRING", xer::to_bits(value, bit_len))
+    }
+
+    #[inline]
+    fn write_boolean<C: boolean::Constraint>(&mut self, value: bool) -> Result<(), Self::Error> {
+        self.push(Item {
+            name: "BOOLEAN",
+            value_list: true,
+            content: if value { "<true/>" } else { "<false/>" }.to_string(),
+        })
+    }
+
+    #[inline]
+    fn write_null<C: null::Constraint>(&mut self) -> Result<(), Self::Error> {
+        self.push_value("NULL", String::new())
+    }
+
+    /// ITU-T X.693 | ISO/IEC 8825-4:2015, chapter 11.2.4: special values are written as empty
+    /// element
+    #[inline]
+    fn write_real<C: real::Constraint>(&mut self, value: f64) -> Result<(), Self::Error> {
+        let content = if value.is_nan() {
+            "<NOT-A-NUMBER/>".to_string()
+        } else if value.is_infinite() {
+            if value > 0.0 {
+                "<PLUS-INFINITY/>"
+            } else {
+                "<MINUS-INFINITY/>"
+            }
+            .to_string()
+        } else if value == 0.0 && value.is_sign_negative() {
+            "-0".to_string()
+        } else {
+            format!("{:?}", value)
+        };
+        self.push_value("REAL", content)
+    }
+
+    #[inline]
+    fn write_object_identifier<C: objectidentifier::Constraint>(
+        &mut self,
+        value: &[u64],
+    ) -> Result<(), Self::Error> {
+        if crate::io::oid::object_identifier_to_octets(value).is_none() {
+            return Err(Error::InvalidObjectIdentifier);
+        }
+        self.push_value("OBJECT_IDENTIFIER", dotted_arcs(value))
+    }
+
+    #[inline]
+    fn write_relative_oid<C: relativeoid::Constraint>(
+        &mut self,
+        value: &[u64],
+    ) -> Result<(), Self::Error> {
+        self.push_value("RELATIVE_OID", dotted_arcs(value))
+    }
+
+    #[inline]
+    fn write_utc_time<C: utctime::Constraint>(&mut self, value: &str) -> Result<(), Self::Error> {
+        self.write_time(TimeType::UtcTime, value)
+    }
+
+    #[inline]
+    fn write_generalized_time<C: generalizedtime::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_time(TimeType::GeneralizedTime, value)
+    }
+
+    #[inline]
+    fn write_date<C: date::Constraint>(&mut self, value: &str) -> Result<(), Self::Error> {
+        self.write_time(TimeType::Date, value)
+    }
+
+    #[inline]
+    fn write_time_of_day<C: timeofday::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_time(TimeType::TimeOfDay, value)
+    }
+
+    #[inline]
+    fn write_date_time<C: datetime::Constraint>(&mut self, value: &str) -> Result<(), Self::Error> {
+        self.write_time(TimeType::DateTime, value)
+    }
+
+    #[inline]
+    fn write_duration<C: duration::Constraint>(&mut self, value: &str) -> Result<(), Self::Error> {
+        self.write_time(TimeType::Duration, value)
+    }
+
+    #[inline]
+    fn write_with_components<C: components::Constraint, T: WritableType>(
+        &mut self,
+        value: &T::Type,
+    ) -> Result<(), Self::Error>
+    where
+        T::Type: components::Components,
+    {
+        C::COMPONENTS
+            .check(value)
+            .map_err(Error::InvalidComponents)?;
+        T::write_value(self, value)
+    }
+
+    #[inline]
+    fn write_containing<C: containing::Constraint, T: WritableType>(
+        &mut self,
+        value: &T::Type,
+    ) -> Result<(), Self::Error> {
+        // the contained value is embedded instead of its encoding
+        T::write_value(self, value)
+    }
+
+    #[inline]
+    fn write_explicit<C: explicit::Constraint, T: WritableType>(
+        &mut self,
+        value: &T::Type,
+    ) -> Result<(), Self::Error> {
+        // tags are not encoded
+        T::write_value(self, value)
+    }
+}
+
+/// Where the next value is read from
+enum Source {
+    /// The value is the content of the element
+    Enclosed(Element),
+    /// The element is part of a `SEQUENCE OF` and might be the value itself, see [`Item`]
+    Listed(Element),
+}
+
+/// The element that is currently being read
+enum ReadFrame {
+    /// The remaining fields of a `SEQUENCE` or `SET`, the next field is named by the identifier
+    /// at `index`
+    Fields {
+        names: &'static [&'static str],
+        index: usize,
+        elements: Vec<Element>,
+    },
+    /// The remaining elements of a `SEQUENCE OF` or `SET OF`
+    List(std::vec::IntoIter<Element>),
+    /// The value of a `CHOICE` variant or of a transparent `SEQUENCE`
+    Single(Option<Source>),
+}
+
+/// Decodes values according to the BASIC-XER variant of the XML Encoding Rules, see
+/// [`XerWriter`] for further details. The XML document is parsed once the first value is read.
+/// The name of the outermost element is not checked and unknown elements are only accepted for
+/// extensible `SEQUENCE`s and are ignored.
+pub struct XerReader<'a> {
+    text: Option<&'a str>,
+    frames: Vec<ReadFrame>,
+}
+
+impl<'a> From<&'a str> for XerReader<'a> {
+    fn from(text: &'a str) -> Self {
+        Self {
+            text: Some(text),
+            frames: Vec::new(),
+        }
+    }
+}
+
+impl<'a> XerReader<'a> {
+    fn next(&mut self) -> Result<Source, Error> {
+        match self.frames.last_mut() {
+            Some(ReadFrame::Fields {
+                names,
+                index,
+                elements,
+            }) => {
+                let name = identifier(names, *index)?;
+                *index += 1;
+                let position = elements
+                    .iter()
+                    .position(|e| e.name == name)
+                    .ok_or_else(|| Error::MissingElement(name.to_string()))?;
+                Ok(Source::Enclosed(elements.remove(position)))
+            }
+            Some(ReadFrame::List(elements)) => elements
+                .next()
+                .map(Source::Listed)
+                .ok_or(Error::EndOfStream),
+            Some(ReadFrame::Single(slot)) => slot.take().ok_or(Error::EndOfStream),
+            None => Ok(Source::Enclosed(Element::parse(
+                self.text.take().ok_or(Error::EndOfStream)?,
+            )?)),
+        }
+    }
+
+    /// The element that encloses the next value
+    fn next_enclosing(&mut self) -> Result<Element, Error> {
+        match self.next()? {
+            Source::Enclosed(element) | Source::Listed(element) => Ok(element),
+        }
+    }
+
+    fn read_time(&mut self, time: TimeType) -> Result<String, Error> {
+        Some(self.next_enclosing()?.text())
+            .filter(|value| crate::io::time::is_valid(time, value))
+            .ok_or(Error::InvalidTime(time))
+    }
+
+    /// The element that is the next value, which is the case for `BOOLEAN`, `ENUMERATED` and
+    /// `CHOICE` values
+    fn next_value_element(&mut self) -> Result<Element, Error> {
+        match self.next()? {
+            Source::Listed(element) => Ok(element),
+            Source::Enclosed(element) => {
+                if !element.text().trim().is_empty() {
+                    return Err(Error::UnexpectedContent("a single element"));
+                }
+                let mut elements = element.into_elements();
+                match elements.pop() {
+                    Some(element) if elements.is_empty() => Ok(element),
+                    _ => Err(Error::UnexpectedContent("a single element")),
+                }
+            }
+        }
+    }
+
+    fn nested<T, F: FnOnce(&mut Self) -> Result<T, Error>>(
+        &mut self,
+        frame: ReadFrame,
+        f: F,
+    ) -> Result<(T, ReadFrame), Error> {
+        self.frames.push(frame);
+        let result = f(self);
+        let frame = self.frames.pop().ok_or(Error::EndOfStream)?;
+        result.map(|value| (value, frame))
+    }
+
+    fn read_list<T: ReadableType>(
+        &mut self,
+        min: Option<u64>,
+        max: Option<u64>,
+        extensible: bool,
+    ) -> Result<Vec<<T as ReadableType>::Type>, Error> {
+        let elements = self.next_enclosing()?.into_elements();
+        check_size(min, max, extensible, elements.len() as u64)?;
+        let len = elements.len();
+        self.nested(ReadFrame::List(elements.into_iter()), |r| {
+            (0..len).map(|_| T::read_value(r)).collect()
+        })
+        .map(|r| r.0)
+    }
+}
+
+impl<'a> Reader for XerReader<'a> {
+    type Error = Error;
+
+    #[inline]
+    fn read_sequence<
+        C: sequence::Constraint,
+        S: Sized,
+        F: Fn(&mut Self) -> Result<S, Self::Error>,
+    >(
+        &mut self,
+        f: F,
+    ) -> Result<S, Self::Error> {
+        let source = self.next()?;
+        if C::TRANSPARENT {
+            return self.nested(ReadFrame::Single(Some(source)), f).map(|r| r.0);
+        }
+        let elements = match source {
+            Source::Enclosed(element) | Source::Listed(element) => element.into_elements(),
+        };
+        let frame = ReadFrame::Fields {
+            names: C::FIELD_NAMES,
+            index: 0,
+            elements,
+        };
+        let (result, frame) = self.nested(frame, f)?;
+        match frame {
+            ReadFrame::Fields { mut elements, .. }
+                if !elements.is_empty() && C::EXTENDED_AFTER_FIELD.is_none() =>
+            {
+                Err(Error::UnknownElement(elements.remove(0).name))
+            }
+            _ => Ok(result),
+        }
+    }
+
+    #[inline]
+    fn read_sequence_of<C: sequenceof::Constraint, T: ReadableType>(
+        &mut self,
+    ) -> Result<Vec<<T as ReadableType>::Type>, Self::Error> {
+        self.read_list::<T>(C::MIN, C::MAX, C::EXTENSIBLE)
+    }
+
+    #[inline]
+    fn read_set<C: set::Constraint, S: Sized, F: Fn(&mut Self) -> Result<S, Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<S, Self::Error> {
+        self.read_sequence::<C, S, F>(f)
+    }
+
+    #[inline]
+    fn read_set_of<C: setof::Constraint, T: ReadableType>(
+        &mut self,
+    ) -> Result<Vec<<T as ReadableType>::Type>, Self::Error> {
+        self.read_list::<T>(C::MIN, C::MAX, C::EXTENSIBLE)
+    }
+
+    #[inline]
+    fn read_enumerated<C: enumerated::Constraint>(&mut self) -> Result<C, Self::Error> {
+        let element = self.next_value_element()?;
+        C::VARIANT_NAMES
+            .iter()
+            .position(|name| *name == element.name)
+            .filter(|_| element.nodes.is_empty())
+            .and_then(|index| C::from_choice_index(index as u64))
+            .ok_or(Error::UnknownVariant(element.name))
+    }
+
+    #[inline]
+    fn read_choice<C: choice::Constraint>(&mut self) -> Result<C, Self::Error> {
+        let element = self.next_value_element()?;
+        let index = match C::VARIANT_NAMES.iter().position(|n| *n == element.name) {
+            Some(index) => index as u64,
+            None => return Err(Error::UnknownVariant(element.name)),
+        };
+        let name = element.name.clone();
+        let frame = ReadFrame::Single(Some(Source::Enclosed(element)));
+        match self.nested(frame, |r| C::read_content(index, r))? {
+            (Some(choice), _) => Ok(choice),
+            (None, _) => Err(Error::UnknownVariant(name)),
+        }
+    }
+
+    #[inline]
+    fn read_open_type<C: opentype::Constraint>(&mut self, key: i64) -> Result<C, Self::Error> {
+        C::read_content(key, self)?.ok_or(Error::UnknownOpenTypeKey(key))
+    }
+
+    #[inline]
+    fn read_opt<T: ReadableType>(
+        &mut self,
+    ) -> Result<Option<<T as ReadableType>::Type>, Self::Error> {
+        if let Some(ReadFrame::Fields {
+            names,
+            index,
+            elements,
+        }) = self.frames.last_mut()
+        {
+            let name = identifier(names, *index)?;
+            if !elements.iter().any(|e| e.name == name) {
+                *index += 1;
+                return Ok(None);
+            }
+        }
+        T::read_value(self).map(Some)
+    }
+
+    #[inline]
+    fn read_number<T: numbers::Number, C: numbers::Constraint<T>>(
+        &mut self,
+    ) -> Result<T, Self::Error> {
+        let text = self.next_enclosing()?.text();
+        let value = text
+            .trim()
+            .parse::<i64>()
+            .map_err(|_| Error::InvalidNumber(text.trim().to_string()))?;
+        check_range(C::MIN, C::MAX, C::EXTENSIBLE, value)?;
+        Ok(T::from_i64(value))
+    }
+
+    #[inline]
+    fn read_wide_number<T: numbers::WideNumber, C: numbers::WideConstraint>(
+        &mut self,
+    ) -> Result<T, Self::Error> {
+        let text = self.next_enclosing()?.text();
+        let value = text
+            .trim()
+            .parse::<T>()
+            .map_err(|_| Error::InvalidNumber(text.trim().to_string()))?;
+        check_wide_range(C::MIN, C::MAX, C::EXTENSIBLE, &value)?;
+        Ok(value)
+    }
+
+    #[inline]
+    fn read_utf8string<C: utf8string::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let string = self.next_enclosing()?.string()?;
+        check_size(C::MIN, C::MAX, C::EXTENSIBLE, string.chars().count() as u64)?;
+        Ok(string)
+    }
+
+    #[inline]
+    fn read_ia5string<C: ia5string::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let string = self.next_enclosing()?.string()?;
+        if !string.is_ascii() {
+            return Err(Error::InvalidIa5String);
+        }
+        check_size(C::MIN, C::MAX, C::EXTENSIBLE, string.len() as u64)?;
+        Ok(string)
+    }
+
+    #[inline]
+    fn read_numericstring<C: numericstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let string = self.next_enclosing()?.string()?;
+        check_charset(Charset::Numeric, &string)?;
+        check_size(C::MIN, C::MAX, C::EXTENSIBLE, string.chars().count() as u64)?;
+        Ok(string)
+    }
+
+    #[inline]
+    fn read_printablestring<C: printablestring::Constraint>(
+        &mut self,
+    ) -> Result<String, Self::Error> {
+        let string = self.next_enclosing()?.string()?;
+        check_charset(Charset::Printable, &string)?;
+        check_size(C::MIN, C::MAX, C::EXTENSIBLE, string.chars().count() as u64)?;
+        Ok(string)
+    }
+
+    #[inline]
+    fn read_visiblestring<C: visiblestring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let string = self.next_enclosing()?.string()?;
+        check_charset(Charset::Visible, &string)?;
+        check_size(C::MIN, C::MAX, C::EXTENSIBLE, string.chars().count() as u64)?;
+        Ok(string)
+    }
+
+    #[inline]
+    fn read_bmpstring<C: bmpstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let string = self.next_enclosing()?.string()?;
+        check_charset(Charset::Bmp, &string)?;
+        check_size(C::MIN, C::MAX, C::EXTENSIBLE, string.chars().count() as u64)?;
+        Ok(string)
+    }
+
+    #[inline]
+    fn read_universalstring<C: universalstring::Constraint>(
+        &mut self,
+    ) -> Result<String, Self::Error> {
+        let string = self.next_enclosing()?.string()?;
+        check_charset(Charset::Universal, &string)?;
+        check_size(C::MIN, C::MAX, C::EXTENSIBLE, string.chars().count() as u64)?;
+        Ok(string)
+    }
+
+    #[inline]
+    fn read_octet_string<C: octetstring::Constraint>(&mut self) -> Result<Vec<u8>, Self::Error> {
+        let bytes = xer::from_hex(&self.next_enclosing()?.text())?;
+        check_size(C::MIN, C::MAX, C::EXTENSIBLE, bytes.len() as u64)?;
+        Ok(bytes)
+    }
+
+    #[inline]
+    fn read_bit_string<C: bitstring::Constraint>(&mut self) -> Result<(Vec<u8>, u64), Self::Error> {
+        let (bytes, bit_len) = xer::from_bits(&self.next_enclosing()?.text())?;
+        check_size(C::MIN, C::MAX, C::EXTENSIBLE, bit_len)?;
+        Ok((bytes, bit_len))
+    }
+
+    #[inline]
+    fn read_boolean<C: boolean::Constraint>(&mut self) -> Result<bool, Self::Error> {
+        let element = self.next_value_element()?;
+        match element.name.as_str() {
+            "true" if element.nodes.is_empty() => Ok(true),
+            "false" if element.nodes.is_empty() => Ok(false),
+            _ => Err(Error::UnexpectedContent("<true/> or <false/>")),
+        }
+    }
+
+    #[inline]
+    fn read_null<C: null::Constraint>(&mut self) -> Result<(), Self::Error> {
+        let element = self.next_enclosing()?;
+        if element.text().trim().is_empty() && element.elements().next().is_none() {
+            Ok(())
+        } else {
+            Err(Error::UnexpectedContent("an empty element"))
+        }
+    }
+
+    #[inline]
+    fn read_real<C: real::Constraint>(&mut self) -> Result<f64, Self::Error> {
+        let element = self.next_enclosing()?;
+        let text = element.text();
+        let text = text.trim();
+        let mut elements = element.into_elements();
+        match elements.pop() {
+            Some(special) if elements.is_empty() && text.is_empty() && special.nodes.is_empty() => {
+                match special.name.as_str() {
+                    "PLUS-INFINITY" => Ok(f64::INFINITY),
+                    "MINUS-INFINITY" => Ok(f64::NEG_INFINITY),
+                    "NOT-A-NUMBER" => Ok(f64::NAN),
+                    _ => Err(Error::InvalidReal),
+                }
+            }
+            None if !text.is_empty()
+                && text
+                    .chars()
+                    .all(|c| c.is_ascii_digit() || matches!(c, '.' | 'e' | 'E' | '+' | '-')) =>
+            {
+                text.parse().map_err(|_| Error::InvalidReal)
+            }
+            _ => Err(Error::InvalidReal),
+        }
+    }
+
+    #[inline]
+    fn read_object_identifier<C: objectidentifier::Constraint>(
+        &mut self,
+    ) -> Result<Vec<u64>, Self::Error> {
+        let arcs = parse_dotted_arcs(&self.next_enclosing()?.text())?;
+        if crate::io::oid::object_identifier_to_octets(&arcs).is_none() {
+            return Err(Error::InvalidObjectIdentifier);
+        }
+        Ok(arcs)
+    }
+
+    #[inline]
+    fn read_relative_oid<C: relativeoid::Constraint>(&mut self) -> Result<Vec<u64>, Self::Error> {
+        parse_dotted_arcs(&self.next_enclosing()?.text())
+    }
+
+    #[inline]
+    fn read_utc_time<C: utctime::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_time(TimeType::UtcTime)
+    }
+
+    #[inline]
+    fn read_generalized_time<C: generalizedtime::Constraint>(
+        &mut self,
+    ) -> Result<String, Self::Error> {
+        self.read_time(TimeType::GeneralizedTime)
+    }
+
+    #[inline]
+    fn read_date<C: date::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_time(TimeType::Date)
+    }
+
+    #[inline]
+    fn read_time_of_day<C: timeofday::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_time(TimeType::TimeOfDay)
+    }
+
+    #[inline]
+    fn read_date_time<C: datetime::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_time(TimeType::DateTime)
+    }
+
+    #[inline]
+    fn read_duration<C: duration::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_time(TimeType::Duration)
+    }
+
+    #[inline]
+    fn read_with_components<C: components::Constraint, T: ReadableType>(
+        &mut self,
+    ) -> Result<T::Type, Self::Error>
+    where
+        T::Type: components::Components,
+    {
+        let value = T::read_value(self)?;
+        C::COMPONENTS
+            .check(&value)
+            .map_err(Error::InvalidComponents)?;
+        Ok(value)
+    }
+
+    #[inline]
+    fn read_containing<C: containing::Constraint, T: ReadableType>(
+        &mut self,
+    ) -> Result<T::Type, Self::Error> {
+        T::read_value(self)
+    }
+
+    #[inline]
+    fn read_explicit<C: explicit::Constraint, T: ReadableType>(
+        &mut self,
+    ) -> Result<T::Type, Self::Error> {
+        // tags are not encoded
+        T::read_value(self)
+    }
+}
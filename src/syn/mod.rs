@@ -1,37 +1,82 @@
 pub mod bitstring;
+pub mod bmpstring;
 pub mod boolean;
+pub mod boxed;
 pub mod choice;
 pub mod common;
 pub mod complex;
+pub mod components;
+pub mod containing;
+pub mod date;
+pub mod datetime;
+pub mod default;
+pub mod duration;
 pub mod enumerated;
+pub mod explicit;
+pub mod generalizedtime;
 pub mod ia5string;
 pub mod io;
+pub mod null;
 pub mod numbers;
+pub mod numericstring;
+pub mod objectidentifier;
 pub mod octetstring;
+pub mod opentype;
 pub mod optional;
+pub mod printablestring;
+pub mod real;
+pub mod relativeoid;
 pub mod sequence;
 pub mod sequenceof;
 pub mod set;
 pub mod setof;
+pub mod timeofday;
+pub mod universalstring;
+pub mod utctime;
 pub mod utf8string;
+pub mod visiblestring;
 
 pub use bitstring::BitString;
 pub use bitstring::BitVec;
+pub use bmpstring::BmpString;
 pub use boolean::Boolean;
+pub use boxed::Boxed;
 pub use choice::Choice;
 pub use complex::Complex;
+pub use components::WithComponents;
+pub use containing::Containing;
+pub use date::Date;
+pub use datetime::DateTime;
+pub use default::DefaultValue;
+pub use duration::Duration;
 pub use enumerated::Enumerated;
+pub use explicit::Explicit;
+pub use generalizedtime::GeneralizedTime;
 pub use ia5string::Ia5String;
+pub use null::Null;
 pub use numbers::Integer;
+pub use numbers::WideInteger;
+pub use numericstring::NumericString;
+pub use objectidentifier::ObjectIdentifier;
 pub use octetstring::OctetString;
+pub use opentype::OpenType;
+pub use printablestring::PrintableString;
+pub use real::Real;
+pub use relativeoid::RelativeOid;
 pub use sequence::Sequence;
 pub use sequenceof::SequenceOf;
 pub use set::Set;
 pub use setof::SetOf;
+pub use timeofday::TimeOfDay;
+pub use universalstring::UniversalString;
+pub use utctime::UtcTime;
 pub use utf8string::Utf8String;
+pub use visiblestring::VisibleString;
 
 pub mod prelude {
     pub use super::bitstring::BitVec;
+    #[cfg(feature = "bigint")]
+    pub use super::numbers::BigInt;
     pub use super::Readable;
     pub use super::ReadableType;
     pub use super::Reader;
@@ -77,21 +122,107 @@ pub trait Reader {
 
     fn read_choice<C: choice::Constraint>(&mut self) -> Result<C, Self::Error>;
 
+    /// Reads the value of an open type whose actual type is determined by the given key, see
+    /// [`opentype::OpenType`]
+    fn read_open_type<C: opentype::Constraint>(&mut self, key: i64) -> Result<C, Self::Error>;
+
     fn read_opt<T: ReadableType>(&mut self) -> Result<Option<T::Type>, Self::Error>;
 
     fn read_number<T: numbers::Number, C: numbers::Constraint<T>>(
         &mut self,
     ) -> Result<T, Self::Error>;
 
+    fn read_wide_number<T: numbers::WideNumber, C: numbers::WideConstraint>(
+        &mut self,
+    ) -> Result<T, Self::Error>;
+
     fn read_utf8string<C: utf8string::Constraint>(&mut self) -> Result<String, Self::Error>;
 
     fn read_ia5string<C: ia5string::Constraint>(&mut self) -> Result<String, Self::Error>;
 
+    fn read_numericstring<C: numericstring::Constraint>(&mut self) -> Result<String, Self::Error>;
+
+    fn read_printablestring<C: printablestring::Constraint>(
+        &mut self,
+    ) -> Result<String, Self::Error>;
+
+    fn read_visiblestring<C: visiblestring::Constraint>(&mut self) -> Result<String, Self::Error>;
+
+    fn read_bmpstring<C: bmpstring::Constraint>(&mut self) -> Result<String, Self::Error>;
+
+    fn read_universalstring<C: universalstring::Constraint>(
+        &mut self,
+    ) -> Result<String, Self::Error>;
+
     fn read_octet_string<C: octetstring::Constraint>(&mut self) -> Result<Vec<u8>, Self::Error>;
 
     fn read_bit_string<C: bitstring::Constraint>(&mut self) -> Result<(Vec<u8>, u64), Self::Error>;
 
     fn read_boolean<C: boolean::Constraint>(&mut self) -> Result<bool, Self::Error>;
+
+    fn read_null<C: null::Constraint>(&mut self) -> Result<(), Self::Error>;
+
+    fn read_real<C: real::Constraint>(&mut self) -> Result<f64, Self::Error>;
+
+    fn read_object_identifier<C: objectidentifier::Constraint>(
+        &mut self,
+    ) -> Result<Vec<u64>, Self::Error>;
+
+    fn read_relative_oid<C: relativeoid::Constraint>(&mut self) -> Result<Vec<u64>, Self::Error>;
+
+    fn read_utc_time<C: utctime::Constraint>(&mut self) -> Result<String, Self::Error>;
+
+    fn read_generalized_time<C: generalizedtime::Constraint>(
+        &mut self,
+    ) -> Result<String, Self::Error>;
+
+    fn read_date<C: date::Constraint>(&mut self) -> Result<String, Self::Error>;
+
+    fn read_time_of_day<C: timeofday::Constraint>(&mut self) -> Result<String, Self::Error>;
+
+    fn read_date_time<C: datetime::Constraint>(&mut self) -> Result<String, Self::Error>;
+
+    fn read_duration<C: duration::Constraint>(&mut self) -> Result<String, Self::Error>;
+
+    /// Reads a type that is defined elsewhere and referenced by a field or variant, see
+    /// [`complex::Complex`]. The constraint holds the tag of the field, which replaces the tag
+    /// of the referenced type for tag based encodings. All other encodings can rely on this
+    /// default implementation.
+    #[inline]
+    fn read_complex<C: complex::Constraint, T, F: Fn(&mut Self) -> Result<T, Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<T, Self::Error> {
+        f(self)
+    }
+
+    /// Reads a value whose components are constrained, see [`components::WithComponents`],
+    /// and rejects it if it violates the constraint
+    fn read_with_components<C: components::Constraint, T: ReadableType>(
+        &mut self,
+    ) -> Result<T::Type, Self::Error>
+    where
+        T::Type: components::Components;
+
+    /// Reads a value from the contents of an `OCTET STRING` or `BIT STRING`, see
+    /// [`containing::Containing`]
+    fn read_containing<C: containing::Constraint, T: ReadableType>(
+        &mut self,
+    ) -> Result<T::Type, Self::Error>;
+
+    /// Reads an explicitly tagged value, see [`explicit::Explicit`]
+    fn read_explicit<C: explicit::Constraint, T: ReadableType>(
+        &mut self,
+    ) -> Result<T::Type, Self::Error>;
+
+    /// Reads the extended fields of the current `SEQUENCE` or `SET` which are unknown to its
+    /// definition - each as the encoded value or `None` if absent - so they can be written again
+    /// by [`Writer::write_unknown_extensions`]. This is called after all known fields are read.
+    /// Encodings without support for this can rely on this default implementation.
+    #[inline]
+    fn read_unknown_extensions(&mut self) -> Result<Vec<Option<Vec<u8>>>, Self::Error> {
+        Ok(Vec::default())
+    }
 }
 
 pub trait Readable: Sized {
@@ -156,6 +287,14 @@ pub trait Writer {
 
     fn write_choice<C: choice::Constraint>(&mut self, choice: &C) -> Result<(), Self::Error>;
 
+    /// Writes the value of an open type, whose actual type must belong to the given key, see
+    /// [`opentype::OpenType`]
+    fn write_open_type<C: opentype::Constraint>(
+        &mut self,
+        value: &C,
+        key: i64,
+    ) -> Result<(), Self::Error>;
+
     fn write_opt<T: WritableType>(&mut self, value: Option<&T::Type>) -> Result<(), Self::Error>;
 
     fn write_number<T: numbers::Number, C: numbers::Constraint<T>>(
@@ -163,6 +302,11 @@ pub trait Writer {
         value: T,
     ) -> Result<(), Self::Error>;
 
+    fn write_wide_number<T: numbers::WideNumber, C: numbers::WideConstraint>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), Self::Error>;
+
     fn write_utf8string<C: utf8string::Constraint>(
         &mut self,
         value: &str,
@@ -171,6 +315,29 @@ pub trait Writer {
     fn write_ia5string<C: ia5string::Constraint>(&mut self, value: &str)
         -> Result<(), Self::Error>;
 
+    fn write_numericstring<C: numericstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error>;
+
+    fn write_printablestring<C: printablestring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error>;
+
+    fn write_visiblestring<C: visiblestring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error>;
+
+    fn write_bmpstring<C: bmpstring::Constraint>(&mut self, value: &str)
+        -> Result<(), Self::Error>;
+
+    fn write_universalstring<C: universalstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error>;
+
     fn write_octet_string<C: octetstring::Constraint>(
         &mut self,
         value: &[u8],
@@ -183,6 +350,84 @@ pub trait Writer {
     ) -> Result<(), Self::Error>;
 
     fn write_boolean<C: boolean::Constraint>(&mut self, value: bool) -> Result<(), Self::Error>;
+
+    fn write_null<C: null::Constraint>(&mut self) -> Result<(), Self::Error>;
+
+    fn write_real<C: real::Constraint>(&mut self, value: f64) -> Result<(), Self::Error>;
+
+    fn write_object_identifier<C: objectidentifier::Constraint>(
+        &mut self,
+        value: &[u64],
+    ) -> Result<(), Self::Error>;
+
+    fn write_relative_oid<C: relativeoid::Constraint>(
+        &mut self,
+        value: &[u64],
+    ) -> Result<(), Self::Error>;
+
+    fn write_utc_time<C: utctime::Constraint>(&mut self, value: &str) -> Result<(), Self::Error>;
+
+    fn write_generalized_time<C: generalizedtime::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error>;
+
+    fn write_date<C: date::Constraint>(&mut self, value: &str) -> Result<(), Self::Error>;
+
+    fn write_time_of_day<C: timeofday::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error>;
+
+    fn write_date_time<C: datetime::Constraint>(&mut self, value: &str) -> Result<(), Self::Error>;
+
+    fn write_duration<C: duration::Constraint>(&mut self, value: &str) -> Result<(), Self::Error>;
+
+    /// Writes a type that is defined elsewhere and referenced by a field or variant, see
+    /// [`complex::Complex`]. The constraint holds the tag of the field, which replaces the tag
+    /// of the referenced type for tag based encodings. All other encodings can rely on this
+    /// default implementation.
+    #[inline]
+    fn write_complex<C: complex::Constraint, F: Fn(&mut Self) -> Result<(), Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<(), Self::Error> {
+        f(self)
+    }
+
+    /// Writes a value whose components are constrained, see [`components::WithComponents`],
+    /// unless it violates the constraint
+    fn write_with_components<C: components::Constraint, T: WritableType>(
+        &mut self,
+        value: &T::Type,
+    ) -> Result<(), Self::Error>
+    where
+        T::Type: components::Components;
+
+    /// Writes a value as the contents of an `OCTET STRING` or `BIT STRING`, see
+    /// [`containing::Containing`]
+    fn write_containing<C: containing::Constraint, T: WritableType>(
+        &mut self,
+        value: &T::Type,
+    ) -> Result<(), Self::Error>;
+
+    /// Writes an explicitly tagged value, see [`explicit::Explicit`]
+    fn write_explicit<C: explicit::Constraint, T: WritableType>(
+        &mut self,
+        value: &T::Type,
+    ) -> Result<(), Self::Error>;
+
+    /// Writes the extended fields of the current `SEQUENCE` or `SET` which are unknown to its
+    /// definition, as read by [`Reader::read_unknown_extensions`]. This is called before any
+    /// field is written, because encodings like PER announce all extended fields upfront.
+    /// Encodings without support for this can rely on this default implementation.
+    #[inline]
+    fn write_unknown_extensions(
+        &mut self,
+        _extensions: &[Option<Vec<u8>>],
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
 }
 
 pub trait Writable {
@@ -227,6 +472,7 @@ mod tests {
         }
         impl sequence::Constraint for Whatever {
             const NAME: &'static str = "Whatever";
+            const FIELD_NAMES: &'static [&'static str] = &["name", "opt", "some"];
             const STD_OPTIONAL_FIELDS: u64 = 2;
             const FIELD_COUNT: u64 = 3;
             const EXTENDED_AFTER_FIELD: Option<u64> = None;
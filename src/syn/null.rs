@@ -0,0 +1,42 @@
+use crate::syn::{ReadableType, Reader, WritableType, Writer};
+use asn1rs_model::model::Tag;
+use core::marker::PhantomData;
+
+/// `NULL`, whose only value is represented by the unit type
+pub struct Null<C: Constraint = NoConstraint>(PhantomData<C>);
+
+impl<C: Constraint> Default for Null<C> {
+    fn default() -> Self {
+        Null(Default::default())
+    }
+}
+
+pub trait Constraint: super::common::Constraint {}
+
+#[derive(Default)]
+pub struct NoConstraint;
+impl super::common::Constraint for NoConstraint {
+    const TAG: Tag = Tag::DEFAULT_NULL;
+}
+impl Constraint for NoConstraint {}
+
+impl<C: Constraint> WritableType for Null<C> {
+    type Type = ();
+
+    #[inline]
+    fn write_value<W: Writer>(
+        writer: &mut W,
+        _value: &Self::Type,
+    ) -> Result<(), <W as Writer>::Error> {
+        writer.write_null::<C>()
+    }
+}
+
+impl<C: Constraint> ReadableType for Null<C> {
+    type Type = ();
+
+    #[inline]
+    fn read_value<R: Reader>(reader: &mut R) -> Result<Self::Type, <R as Reader>::Error> {
+        reader.read_null::<C>()
+    }
+}
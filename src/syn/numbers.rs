@@ -1,6 +1,11 @@
 use crate::syn::{ReadableType, Reader, WritableType, Writer};
 use asn1rs_model::model::Tag;
+use core::fmt::Display;
 use core::marker::PhantomData;
+use core::str::FromStr;
+
+#[cfg(feature = "bigint")]
+pub use num_bigint::BigInt;
 
 pub struct Integer<T: Number = u64, C: Constraint<T> = NoConstraint>(
     PhantomData<T>,
@@ -26,6 +31,19 @@ pub trait Constraint<T: Number>: super::common::Constraint {
     const MIN_T: Option<T> = None;
     const MAX_T: Option<T> = None;
     const EXTENSIBLE: bool = false;
+    /// The sorted and inclusive ranges of the full constraint, if it is not fully described by
+    /// [`Constraint::MIN`] and [`Constraint::MAX`], such as `INTEGER (1 | 3 | 5..10)`
+    const PERMITTED: Option<&'static [(i64, i64)]> = None;
+
+    /// Whether the value is part of the full constraint, ignoring its extensibility
+    fn permits(value: i64) -> bool {
+        match Self::PERMITTED {
+            Some(permitted) => permitted
+                .iter()
+                .any(|(min, max)| (*min..=*max).contains(&value)),
+            None => true,
+        }
+    }
 }
 
 #[derive(Default)]
@@ -75,6 +93,275 @@ macro_rules! impl_number {
 impl_number!(u8, u16, u32, u64);
 impl_number!(i8, i16, i32, i64);
 
+/// An `INTEGER` whose bounds exceed the 64 bit types of [`Integer`], represented by `i128`,
+/// `u128` or - with the feature `bigint` - [`BigInt`]
+pub struct WideInteger<T: WideNumber = i128, C: WideConstraint = NoConstraint>(
+    PhantomData<T>,
+    PhantomData<C>,
+);
+
+impl<T: WideNumber, C: WideConstraint> Default for WideInteger<T, C> {
+    fn default() -> Self {
+        WideInteger(Default::default(), Default::default())
+    }
+}
+
+pub trait WideNumber: Sized + Clone + Display + FromStr {
+    /// The minimal two's complement representation in big-endian order
+    fn to_signed_octets(&self) -> Vec<u8>;
+
+    fn from_signed_octets(octets: &[u8]) -> Option<Self>;
+
+    /// The (non-negative) difference to the given lower-bound or `None` if the value is less
+    /// than the lower-bound or the difference does not fit into an `u128`
+    fn offset_from(&self, min: i128) -> Option<u128>;
+
+    fn from_offset(min: i128, offset: u128) -> Option<Self>;
+
+    /// Whether the value is greater than the given upper-bound
+    fn exceeds(&self, max: i128) -> bool;
+
+    fn is_within(&self, min: Option<i128>, max: Option<i128>) -> bool {
+        !matches!(min, Some(min) if self.offset_from(min).is_none())
+            && !matches!(max, Some(max) if self.exceeds(max))
+    }
+}
+
+pub trait WideConstraint: super::common::Constraint {
+    const MIN: Option<i128> = None;
+    const MAX: Option<i128> = None;
+    const EXTENSIBLE: bool = false;
+    /// The sorted and inclusive ranges of the full constraint, if it is not fully described by
+    /// [`WideConstraint::MIN`] and [`WideConstraint::MAX`], of which `i128::MIN` and `i128::MAX`
+    /// stand for `MIN` and `MAX`
+    const PERMITTED: Option<&'static [(i128, i128)]> = None;
+
+    /// Whether the value is part of the full constraint, ignoring its extensibility
+    fn permits<T: WideNumber>(value: &T) -> bool {
+        match Self::PERMITTED {
+            Some(permitted) => permitted.iter().any(|(min, max)| {
+                value.is_within(
+                    Some(*min).filter(|min| *min != i128::MIN),
+                    Some(*max).filter(|max| *max != i128::MAX),
+                )
+            }),
+            None => true,
+        }
+    }
+}
+
+impl WideConstraint for NoConstraint {}
+
+impl<T: WideNumber, C: WideConstraint> WritableType for WideInteger<T, C> {
+    type Type = T;
+
+    #[inline]
+    fn write_value<W: Writer>(
+        writer: &mut W,
+        value: &Self::Type,
+    ) -> Result<(), <W as Writer>::Error> {
+        writer.write_wide_number::<T, C>(value)
+    }
+}
+
+impl<T: WideNumber, C: WideConstraint> ReadableType for WideInteger<T, C> {
+    type Type = T;
+
+    #[inline]
+    fn read_value<R: Reader>(reader: &mut R) -> Result<Self::Type, <R as Reader>::Error> {
+        reader.read_wide_number::<T, C>()
+    }
+}
+
+/// The minimal big-endian representation of the non-negative value, but at least one octet
+pub(crate) fn unsigned_octets(value: u128) -> Vec<u8> {
+    let bytes = value.to_be_bytes();
+    let leading = (value.leading_zeros() as usize / 8).min(bytes.len() - 1);
+    bytes[leading..].to_vec()
+}
+
+pub(crate) fn from_unsigned_octets(octets: &[u8]) -> Option<u128> {
+    let mut bytes = [0u8; core::mem::size_of::<u128>()];
+    if octets.is_empty() || octets.len() > bytes.len() {
+        None
+    } else {
+        let offset = bytes.len() - octets.len();
+        bytes[offset..].copy_from_slice(octets);
+        Some(u128::from_be_bytes(bytes))
+    }
+}
+
+/// Removes the leading octets that only repeat the sign bit
+fn minimal_signed_octets(octets: &[u8]) -> &[u8] {
+    let redundant = octets
+        .windows(2)
+        .take_while(|w| (w[0] == 0x00 && w[1] < 0x80) || (w[0] == 0xFF && w[1] >= 0x80))
+        .count();
+    &octets[redundant..]
+}
+
+/// Sign extends the octets to the given size, `None` if they do not fit
+fn sign_extended_octets<const N: usize>(octets: &[u8]) -> Option<[u8; N]> {
+    let octets = minimal_signed_octets(octets);
+    if octets.is_empty() || octets.len() > N {
+        return None;
+    }
+    let mut extended = if octets[0] >= 0x80 {
+        [0xFF; N]
+    } else {
+        [0x00; N]
+    };
+    extended[N - octets.len()..].copy_from_slice(octets);
+    Some(extended)
+}
+
+impl WideNumber for i128 {
+    fn to_signed_octets(&self) -> Vec<u8> {
+        minimal_signed_octets(&self.to_be_bytes()).to_vec()
+    }
+
+    fn from_signed_octets(octets: &[u8]) -> Option<Self> {
+        sign_extended_octets(octets).map(i128::from_be_bytes)
+    }
+
+    fn offset_from(&self, min: i128) -> Option<u128> {
+        if *self < min {
+            None
+        } else {
+            Some(self.wrapping_sub(min) as u128)
+        }
+    }
+
+    fn from_offset(min: i128, offset: u128) -> Option<Self> {
+        min.checked_add_unsigned(offset)
+    }
+
+    fn exceeds(&self, max: i128) -> bool {
+        *self > max
+    }
+}
+
+impl WideNumber for u128 {
+    fn to_signed_octets(&self) -> Vec<u8> {
+        let mut octets = vec![0u8];
+        octets.extend_from_slice(&self.to_be_bytes());
+        minimal_signed_octets(&octets).to_vec()
+    }
+
+    fn from_signed_octets(octets: &[u8]) -> Option<Self> {
+        let octets = sign_extended_octets::<17>(octets)?;
+        if octets[0] == 0x00 {
+            let mut value = [0u8; 16];
+            value.copy_from_slice(&octets[1..]);
+            Some(u128::from_be_bytes(value))
+        } else {
+            None
+        }
+    }
+
+    fn offset_from(&self, min: i128) -> Option<u128> {
+        if min.is_negative() {
+            self.checked_add(min.unsigned_abs())
+        } else {
+            self.checked_sub(min as u128)
+        }
+    }
+
+    fn from_offset(min: i128, offset: u128) -> Option<Self> {
+        if min.is_negative() {
+            offset.checked_sub(min.unsigned_abs())
+        } else {
+            offset.checked_add(min as u128)
+        }
+    }
+
+    fn exceeds(&self, max: i128) -> bool {
+        max.is_negative() || *self > max as u128
+    }
+}
+
+#[cfg(feature = "bigint")]
+impl WideNumber for BigInt {
+    fn to_signed_octets(&self) -> Vec<u8> {
+        self.to_signed_bytes_be()
+    }
+
+    fn from_signed_octets(octets: &[u8]) -> Option<Self> {
+        if octets.is_empty() {
+            None
+        } else {
+            Some(BigInt::from_signed_bytes_be(octets))
+        }
+    }
+
+    fn offset_from(&self, min: i128) -> Option<u128> {
+        core::convert::TryFrom::try_from(self - BigInt::from(min)).ok()
+    }
+
+    fn from_offset(min: i128, offset: u128) -> Option<Self> {
+        Some(BigInt::from(min) + BigInt::from(offset))
+    }
+
+    fn exceeds(&self, max: i128) -> bool {
+        *self > BigInt::from(max)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_signed_octets_are_minimal() {
+        assert_eq!(vec![0x00], 0_i128.to_signed_octets());
+        assert_eq!(vec![0x00, 0x80], 128_i128.to_signed_octets());
+        assert_eq!(vec![0x80], (-128_i128).to_signed_octets());
+        assert_eq!(vec![0xFF, 0x7F], (-129_i128).to_signed_octets());
+        assert_eq!(17, u128::MAX.to_signed_octets().len());
+        assert_eq!(
+            Some(u128::MAX),
+            u128::from_signed_octets(&u128::MAX.to_signed_octets())
+        );
+        assert_eq!(
+            Some(i128::MIN),
+            i128::from_signed_octets(&i128::MIN.to_signed_octets())
+        );
+        assert_eq!(None, u128::from_signed_octets(&[0xFF]));
+        assert_eq!(None, i128::from_signed_octets(&[0x01; 17]));
+    }
+
+    #[test]
+    fn test_offsets() {
+        assert_eq!(Some(u128::MAX), i128::MAX.offset_from(i128::MIN));
+        assert_eq!(None, (-2_i128).offset_from(-1));
+        assert_eq!(Some(i128::MAX), i128::from_offset(i128::MIN, u128::MAX));
+        assert_eq!(Some(5), 3_u128.offset_from(-2));
+        assert_eq!(Some(3), u128::from_offset(-2, 5));
+        assert_eq!(None, u128::from_offset(-2, 1));
+        assert!(u128::MAX.exceeds(i128::MAX));
+        assert!(!5_u128.is_within(Some(6), None));
+        assert!(5_u128.is_within(Some(5), Some(5)));
+    }
+
+    #[test]
+    #[cfg(feature = "bigint")]
+    fn test_big_integer() {
+        let value: BigInt = BigInt::from(u128::MAX) * 4;
+        assert_eq!(17, value.to_signed_octets().len());
+        assert_eq!(
+            Some(value.clone()),
+            BigInt::from_signed_octets(&value.to_signed_octets())
+        );
+        assert_eq!(
+            Some(-value.clone()),
+            BigInt::from_signed_octets(&(-value).to_signed_octets())
+        );
+        assert_eq!(Some(7), BigInt::from(5).offset_from(-2));
+        assert_eq!(Some(BigInt::from(5)), BigInt::from_offset(-2, 7));
+        assert!(BigInt::from(u128::MAX).exceeds(i128::MAX));
+    }
+}
+
 /*
 macro_rules! read_write {
     ( $($T:ident),+ ) => {$(
@@ -0,0 +1,42 @@
+use crate::syn::{ReadableType, Reader, WritableType, Writer};
+use asn1rs_model::model::Tag;
+use core::marker::PhantomData;
+
+/// `OBJECT IDENTIFIER`, represented by its arcs
+pub struct ObjectIdentifier<C: Constraint = NoConstraint>(PhantomData<C>);
+
+impl<C: Constraint> Default for ObjectIdentifier<C> {
+    fn default() -> Self {
+        ObjectIdentifier(Default::default())
+    }
+}
+
+pub trait Constraint: super::common::Constraint {}
+
+#[derive(Default)]
+pub struct NoConstraint;
+impl super::common::Constraint for NoConstraint {
+    const TAG: Tag = Tag::DEFAULT_OBJECT_IDENTIFIER;
+}
+impl Constraint for NoConstraint {}
+
+impl<C: Constraint> WritableType for ObjectIdentifier<C> {
+    type Type = Vec<u64>;
+
+    #[inline]
+    fn write_value<W: Writer>(
+        writer: &mut W,
+        value: &Self::Type,
+    ) -> Result<(), <W as Writer>::Error> {
+        writer.write_object_identifier::<C>(&value[..])
+    }
+}
+
+impl<C: Constraint> ReadableType for ObjectIdentifier<C> {
+    type Type = Vec<u64>;
+
+    #[inline]
+    fn read_value<R: Reader>(reader: &mut R) -> Result<Self::Type, <R as Reader>::Error> {
+        reader.read_object_identifier::<C>()
+    }
+}
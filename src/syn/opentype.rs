@@ -0,0 +1,58 @@
+use crate::syn::{Reader, Writer};
+use core::marker::PhantomData;
+
+/// An open type (`CLASS.&Type`) whose actual type is determined by a key, which is the value of
+/// another component of the same `SEQUENCE` (`{ObjectSet}{@id}`). Because the key must be known
+/// in advance, an open type cannot be read through [`crate::syn::ReadableType`] but only through
+/// [`OpenType::read_with_key`]. Likewise, it is only written through [`OpenType::write_with_key`],
+/// so that the key is checked to belong to the value.
+pub struct OpenType<C: Constraint>(PhantomData<C>);
+
+impl<C: Constraint> Default for OpenType<C> {
+    fn default() -> Self {
+        Self(Default::default())
+    }
+}
+
+pub trait Constraint: Sized {
+    const NAME: &'static str;
+
+    /// The key of the object whose type the value is of
+    fn key(&self) -> i64;
+
+    fn write_content<W: Writer>(&self, writer: &mut W) -> Result<(), W::Error>;
+
+    /// Reads the value of the type that belongs to the given key or returns `None` if the key
+    /// is unknown to the object set
+    fn read_content<R: Reader>(key: i64, reader: &mut R) -> Result<Option<Self>, R::Error>;
+
+    /// Creates the variant which preserves the encoded value of a key that is unknown to the
+    /// object set. By default, such values are not preserved.
+    #[inline]
+    fn from_unknown(_key: i64, _bytes: Vec<u8>) -> Option<Self> {
+        None
+    }
+
+    /// The encoded value, if this is the variant of an unknown key, see
+    /// [`Constraint::from_unknown`]
+    #[inline]
+    fn unknown(&self) -> Option<&[u8]> {
+        None
+    }
+}
+
+impl<C: Constraint> OpenType<C> {
+    #[inline]
+    pub fn write_with_key<W: Writer>(
+        writer: &mut W,
+        value: &C,
+        key: i64,
+    ) -> Result<(), <W as Writer>::Error> {
+        writer.write_open_type::<C>(value, key)
+    }
+
+    #[inline]
+    pub fn read_with_key<R: Reader>(reader: &mut R, key: i64) -> Result<C, <R as Reader>::Error> {
+        reader.read_open_type::<C>(key)
+    }
+}
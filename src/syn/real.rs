@@ -0,0 +1,42 @@
+use crate::syn::{ReadableType, Reader, WritableType, Writer};
+use asn1rs_model::model::Tag;
+use core::marker::PhantomData;
+
+/// `REAL`, represented by a double precision floating point number
+pub struct Real<C: Constraint = NoConstraint>(PhantomData<C>);
+
+impl<C: Constraint> Default for Real<C> {
+    fn default() -> Self {
+        Real(Default::default())
+    }
+}
+
+pub trait Constraint: super::common::Constraint {}
+
+#[derive(Default)]
+pub struct NoConstraint;
+impl super::common::Constraint for NoConstraint {
+    const TAG: Tag = Tag::DEFAULT_REAL;
+}
+impl Constraint for NoConstraint {}
+
+impl<C: Constraint> WritableType for Real<C> {
+    type Type = f64;
+
+    #[inline]
+    fn write_value<W: Writer>(
+        writer: &mut W,
+        value: &Self::Type,
+    ) -> Result<(), <W as Writer>::Error> {
+        writer.write_real::<C>(*value)
+    }
+}
+
+impl<C: Constraint> ReadableType for Real<C> {
+    type Type = f64;
+
+    #[inline]
+    fn read_value<R: Reader>(reader: &mut R) -> Result<Self::Type, <R as Reader>::Error> {
+        reader.read_real::<C>()
+    }
+}
@@ -0,0 +1,42 @@
+use crate::syn::{ReadableType, Reader, WritableType, Writer};
+use asn1rs_model::model::Tag;
+use core::marker::PhantomData;
+
+/// `RELATIVE-OID`, represented by its arcs
+pub struct RelativeOid<C: Constraint = NoConstraint>(PhantomData<C>);
+
+impl<C: Constraint> Default for RelativeOid<C> {
+    fn default() -> Self {
+        RelativeOid(Default::default())
+    }
+}
+
+pub trait Constraint: super::common::Constraint {}
+
+#[derive(Default)]
+pub struct NoConstraint;
+impl super::common::Constraint for NoConstraint {
+    const TAG: Tag = Tag::DEFAULT_RELATIVE_OID;
+}
+impl Constraint for NoConstraint {}
+
+impl<C: Constraint> WritableType for RelativeOid<C> {
+    type Type = Vec<u64>;
+
+    #[inline]
+    fn write_value<W: Writer>(
+        writer: &mut W,
+        value: &Self::Type,
+    ) -> Result<(), <W as Writer>::Error> {
+        writer.write_relative_oid::<C>(&value[..])
+    }
+}
+
+impl<C: Constraint> ReadableType for RelativeOid<C> {
+    type Type = Vec<u64>;
+
+    #[inline]
+    fn read_value<R: Reader>(reader: &mut R) -> Result<Self::Type, <R as Reader>::Error> {
+        reader.read_relative_oid::<C>()
+    }
+}
@@ -11,9 +11,18 @@ impl<T: Constraint> Default for Sequence<T> {
 
 pub trait Constraint: super::common::Constraint {
     const NAME: &'static str;
+    /// The identifiers of the fields as in the ASN.1 definition
+    const FIELD_NAMES: &'static [&'static str];
     const STD_OPTIONAL_FIELDS: u64;
     const FIELD_COUNT: u64;
     const EXTENDED_AFTER_FIELD: Option<u64>;
+    /// Whether this is not an actual `SEQUENCE` but only wraps its single field, like a type
+    /// definition in the form of `MyInt ::= INTEGER` does. Only relevant for tag based encodings.
+    const TRANSPARENT: bool = false;
+    /// Whether the `TAG` is the explicit tag of a type assignment, like the one of
+    /// `MySeq ::= [APPLICATION 1] SEQUENCE { .. }` in a module with `EXPLICIT TAGS`, which then
+    /// wraps the `SEQUENCE` with its universal tag. Only relevant for tag based encodings.
+    const EXPLICIT: bool = false;
 
     fn read_seq<R: Reader>(reader: &mut R) -> Result<Self, R::Error>
     where
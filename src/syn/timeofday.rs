@@ -0,0 +1,39 @@
+use crate::syn::{ReadableType, Reader, WritableType, Writer};
+use asn1rs_model::model::Tag;
+use core::marker::PhantomData;
+
+/// `TIME-OF-DAY`, whose values are written as `hh:mm:ss`
+pub struct TimeOfDay<C: Constraint = NoConstraint>(PhantomData<C>);
+
+impl<C: Constraint> Default for TimeOfDay<C> {
+    fn default() -> Self {
+        TimeOfDay(Default::default())
+    }
+}
+
+pub trait Constraint: super::common::Constraint {}
+
+#[derive(Default)]
+pub struct NoConstraint;
+impl super::common::Constraint for NoConstraint {
+    const TAG: Tag = Tag::DEFAULT_TIME_OF_DAY;
+}
+impl Constraint for NoConstraint {}
+
+impl<C: Constraint> WritableType for TimeOfDay<C> {
+    type Type = String;
+
+    #[inline]
+    fn write_value<W: Writer>(writer: &mut W, value: &Self::Type) -> Result<(), W::Error> {
+        writer.write_time_of_day::<C>(value.as_str())
+    }
+}
+
+impl<C: Constraint> ReadableType for TimeOfDay<C> {
+    type Type = String;
+
+    #[inline]
+    fn read_value<R: Reader>(reader: &mut R) -> Result<Self::Type, <R as Reader>::Error> {
+        reader.read_time_of_day::<C>()
+    }
+}
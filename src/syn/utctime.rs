@@ -0,0 +1,40 @@
+use crate::syn::{ReadableType, Reader, WritableType, Writer};
+use asn1rs_model::model::Tag;
+use core::marker::PhantomData;
+
+/// `UTCTime`, whose values are written as `YYMMDDhhmm[ss]` followed by `Z` or a time
+/// difference `+hhmm` or `-hhmm`
+pub struct UtcTime<C: Constraint = NoConstraint>(PhantomData<C>);
+
+impl<C: Constraint> Default for UtcTime<C> {
+    fn default() -> Self {
+        UtcTime(Default::default())
+    }
+}
+
+pub trait Constraint: super::common::Constraint {}
+
+#[derive(Default)]
+pub struct NoConstraint;
+impl super::common::Constraint for NoConstraint {
+    const TAG: Tag = Tag::DEFAULT_UTC_TIME;
+}
+impl Constraint for NoConstraint {}
+
+impl<C: Constraint> WritableType for UtcTime<C> {
+    type Type = String;
+
+    #[inline]
+    fn write_value<W: Writer>(writer: &mut W, value: &Self::Type) -> Result<(), W::Error> {
+        writer.write_utc_time::<C>(value.as_str())
+    }
+}
+
+impl<C: Constraint> ReadableType for UtcTime<C> {
+    type Type = String;
+
+    #[inline]
+    fn read_value<R: Reader>(reader: &mut R) -> Result<Self::Type, <R as Reader>::Error> {
+        reader.read_utc_time::<C>()
+    }
+}
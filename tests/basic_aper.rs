@@ -0,0 +1,197 @@
+#![recursion_limit = "512"]
+
+mod test_utils;
+
+use test_utils::*;
+
+asn_to_rust!(
+    r"BasicAper DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+      Potato ::= SEQUENCE {
+        size INTEGER (0..255),
+        id INTEGER (0..65535),
+        flag BOOLEAN,
+        name IA5String (SIZE(1..32)) OPTIONAL,
+        note UTF8String
+      }
+
+      Extensible ::= SEQUENCE {
+        abc UTF8String,
+        def INTEGER,
+        ...,
+        ghi UTF8String
+      }
+
+      Decision ::= CHOICE {
+        a INTEGER (0..255),
+        b BOOLEAN,
+        ...,
+        c UTF8String
+      }
+
+      Large ::= INTEGER (0..4294967295)
+
+      Color ::= ENUMERATED { red, green, blue }
+
+      Colors ::= SEQUENCE OF Color
+
+      Versioned ::= SEQUENCE {
+        abc UTF8String,
+        ...
+      }
+
+      VersionedOptional ::= SEQUENCE {
+        abc UTF8String,
+        ...,
+        def INTEGER (0..255) OPTIONAL
+      }
+
+      VersionedLatest ::= SEQUENCE {
+        abc UTF8String,
+        ...,
+        def INTEGER (0..255) OPTIONAL,
+        ghi UTF8String
+      }
+
+    END"
+);
+
+#[test]
+fn test_potato() {
+    serialize_and_deserialize_aper(
+        8 * 9,
+        &[
+            0b1 << 7, // present flag for name, padding
+            0x05,     // size, one-octet aligned
+            0x03,     // id, two-octets aligned, part 1
+            0xE8,     // id, two-octets aligned, part 2
+            0b1 << 7   // flag
+                | 0b00001 << 2, // length of name as constrained whole number (1..32), padding
+            b'a',
+            b'b',
+            0x01, // octet-aligned length of note
+            b'x',
+        ],
+        &Potato {
+            size: 5,
+            id: 1000,
+            flag: true,
+            name: Some("ab".to_string()),
+            note: "x".to_string(),
+        },
+    );
+}
+
+#[test]
+fn test_potato_without_name() {
+    serialize_and_deserialize_aper(
+        8 * 6,
+        &[0x00, 0x05, 0x03, 0xE8, 0b1 << 7, 0x00],
+        &Potato {
+            size: 5,
+            id: 1000,
+            flag: true,
+            name: None,
+            note: String::default(),
+        },
+    );
+}
+
+#[test]
+fn test_extensible() {
+    serialize_and_deserialize_aper(
+        8 * 30,
+        &[
+            0x80, // extension flag, padding
+            0x07, b'b', b'y', b'e', b' ', b'b', b'y', b'e', // abc
+            0x02, 0x03, 0x06, // def
+            0x01, // number of extensions as normally small length, presence flag of ghi
+            0x10, // length of the open type
+            0x0F, b'g', b'r', b'e', b'a', b't', b' ', b'e', b'x', b't', b'e', b'n', b's', b'i',
+            b'o', b'n',
+        ],
+        &Extensible {
+            abc: "bye bye".to_string(),
+            def: 774,
+            ghi: "great extension".to_string(),
+        },
+    );
+}
+
+#[test]
+fn test_absent_extension_additions_are_not_encoded() {
+    serialize_and_deserialize_aper(
+        8 * 3,
+        &[
+            0x00, // extension flag, padding
+            0x01, b'a', // abc
+        ],
+        &VersionedOptional {
+            abc: "a".to_string(),
+            def: None,
+        },
+    );
+}
+
+#[test]
+fn test_choice_root_variant() {
+    serialize_and_deserialize_aper(8 * 2, &[0x00, 0x05], &Decision::A(5));
+    serialize_and_deserialize_aper(3, &[0b011 << 5], &Decision::B(true));
+}
+
+#[test]
+fn test_choice_extended_variant() {
+    serialize_and_deserialize_aper(
+        8 * 5,
+        &[0x80, 0x03, 0x02, b'h', b'i'],
+        &Decision::C("hi".to_string()),
+    );
+}
+
+#[test]
+fn test_large_integer() {
+    // length of 1..4 octets as 2 bit bit-field, padding, octet-aligned value
+    serialize_and_deserialize_aper(8 * 3, &[0b01 << 6, 0x01, 0x00], &Large(256));
+    serialize_and_deserialize_aper(
+        8 * 5,
+        &[0b11 << 6, 0xFF, 0xFF, 0xFF, 0xFF],
+        &Large(4_294_967_295),
+    );
+}
+
+#[test]
+fn test_sequence_of_enumerated() {
+    serialize_and_deserialize_aper(
+        8 + 6,
+        &[0x03, 0b00_01_10 << 2],
+        &Colors(vec![Color::Red, Color::Green, Color::Blue]),
+    );
+}
+
+#[test]
+fn test_aper_differs_from_uper() {
+    let potato = Potato {
+        size: 5,
+        id: 1000,
+        flag: true,
+        name: None,
+        note: String::default(),
+    };
+    assert_ne!(serialize_uper(&potato), serialize_aper(&potato));
+}
+
+#[test]
+fn test_skips_unknown_extension_additions() {
+    let (bits, data) = serialize_aper(&VersionedLatest {
+        abc: "abc".to_string(),
+        def: Some(5),
+        ghi: "unknown to older versions".to_string(),
+    });
+    assert_eq!(
+        Versioned {
+            abc: "abc".to_string(),
+        },
+        deserialize_aper(&data, bits)
+    );
+}
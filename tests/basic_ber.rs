@@ -0,0 +1,234 @@
+#![recursion_limit = "512"]
+
+mod test_utils;
+
+use asn1rs::io::ber::Error;
+use test_utils::*;
+
+asn_to_rust!(
+    r"BasicBer DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+      Potato ::= SEQUENCE {
+        size INTEGER (0..255),
+        id INTEGER (0..65535),
+        flag BOOLEAN,
+        name IA5String (SIZE(1..32)) OPTIONAL,
+        note UTF8String
+      }
+
+      Extensible ::= SEQUENCE {
+        abc UTF8String,
+        def INTEGER,
+        ...,
+        ghi UTF8String
+      }
+
+      Decision ::= CHOICE {
+        a INTEGER (0..255),
+        b BOOLEAN,
+        ...,
+        c UTF8String
+      }
+
+      Large ::= INTEGER (0..4294967295)
+
+      Color ::= ENUMERATED { red, green, blue }
+
+      Colors ::= SEQUENCE OF Color
+
+      Shade ::= ENUMERATED { light(5), dark(10), ..., dim }
+
+      Wrapper ::= SEQUENCE {
+        decision Decision,
+        color Color
+      }
+
+    END"
+);
+
+#[test]
+fn test_potato() {
+    serialize_and_deserialize_ber(
+        &[
+            0x30, 0x11, // SEQUENCE
+            0x80, 0x01, 0x05, // [0] size
+            0x81, 0x02, 0x03, 0xE8, // [1] id
+            0x82, 0x01, 0xFF, // [2] flag
+            0x83, 0x02, b'a', b'b', // [3] name
+            0x84, 0x01, b'x', // [4] note
+        ],
+        &Potato {
+            size: 5,
+            id: 1000,
+            flag: true,
+            name: Some("ab".to_string()),
+            note: "x".to_string(),
+        },
+    );
+}
+
+#[test]
+fn test_potato_without_optional() {
+    serialize_and_deserialize_ber(
+        &[
+            0x30, 0x0D, // SEQUENCE
+            0x80, 0x01, 0x05, // [0] size
+            0x81, 0x02, 0x03, 0xE8, // [1] id
+            0x82, 0x01, 0x00, // [2] flag
+            0x84, 0x01, b'x', // [4] note
+        ],
+        &Potato {
+            size: 5,
+            id: 1000,
+            flag: false,
+            name: None,
+            note: "x".to_string(),
+        },
+    );
+}
+
+#[test]
+fn test_potato_indefinite_length_and_constructed_string() {
+    assert_eq!(
+        Potato {
+            size: 5,
+            id: 1000,
+            flag: true,
+            name: None,
+            note: "xy".to_string(),
+        },
+        deserialize_ber::<Potato>(&[
+            0x30, 0x80, // SEQUENCE, indefinite length
+            0x80, 0x01, 0x05, // [0] size
+            0x81, 0x02, 0x03, 0xE8, // [1] id
+            0x82, 0x01, 0x01, // [2] flag, any non-zero value is true
+            0xA4, 0x80, // [4] note, constructed, indefinite length
+            0x04, 0x01, b'x', // OCTET STRING segment
+            0x04, 0x01, b'y', // OCTET STRING segment
+            0x00, 0x00, // end-of-contents of note
+            0x00, 0x00, // end-of-contents of SEQUENCE
+        ])
+    );
+}
+
+#[test]
+fn test_potato_trailing_content() {
+    let bytes = [
+        0x30, 0x10, // SEQUENCE
+        0x80, 0x01, 0x05, // [0] size
+        0x81, 0x02, 0x03, 0xE8, // [1] id
+        0x82, 0x01, 0xFF, // [2] flag
+        0x84, 0x01, b'x', // [4] note
+        0x85, 0x01, 0x00, // [5] unknown
+    ];
+    assert_eq!(
+        Err(Error::UnexpectedTrailingContent(3)),
+        BerReader::from(&bytes[..]).read::<Potato>()
+    );
+}
+
+#[test]
+fn test_potato_value_not_in_range() {
+    let bytes = [
+        0x30, 0x0E, // SEQUENCE
+        0x80, 0x02, 0x01, 0x00, // [0] size
+        0x81, 0x02, 0x03, 0xE8, // [1] id
+        0x82, 0x01, 0xFF, // [2] flag
+        0x84, 0x01, b'x', // [4] note
+    ];
+    assert_eq!(
+        Err(Error::ValueNotInRange(256, 0, 255)),
+        BerReader::from(&bytes[..]).read::<Potato>()
+    );
+}
+
+#[test]
+fn test_extensible() {
+    serialize_and_deserialize_ber(
+        &[
+            0x30, 0x1D, // SEQUENCE
+            0x80, 0x07, b'b', b'y', b'e', b' ', b'b', b'y', b'e', // [0] abc
+            0x81, 0x01, 0x03, // [1] def
+            0x82, 0x0F, b'g', b'r', b'e', b'a', b't', b' ', b'e', b'x', b't', b'e', b'n', b's',
+            b'i', b'o', b'n', // [2] ghi
+        ],
+        &Extensible {
+            abc: "bye bye".to_string(),
+            def: 3,
+            ghi: "great extension".to_string(),
+        },
+    );
+}
+
+#[test]
+fn test_extensible_skips_unknown_extension() {
+    assert_eq!(
+        Extensible {
+            abc: "bye".to_string(),
+            def: 255,
+            ghi: "x".to_string(),
+        },
+        deserialize_ber::<Extensible>(&[
+            0x30, 0x12, // SEQUENCE
+            0x80, 0x03, b'b', b'y', b'e', // [0] abc
+            0x81, 0x02, 0x00, 0xFF, // [1] def
+            0x82, 0x01, b'x', // [2] ghi
+            0xA7, 0x80, // [7] unknown, constructed, indefinite length
+            0x04, 0x00, // OCTET STRING
+            0x00, 0x00, // end-of-contents
+        ])
+    );
+}
+
+#[test]
+fn test_decision() {
+    serialize_and_deserialize_ber(&[0x80, 0x01, 0x05], &Decision::A(5));
+    serialize_and_deserialize_ber(&[0x81, 0x01, 0xFF], &Decision::B(true));
+    serialize_and_deserialize_ber(&[0x82, 0x02, b'h', b'i'], &Decision::C("hi".to_string()));
+}
+
+#[test]
+fn test_large() {
+    serialize_and_deserialize_ber(
+        &[0x02, 0x05, 0x00, 0xFF, 0xFF, 0xFF, 0xFF],
+        &Large(u32::MAX),
+    );
+    serialize_and_deserialize_ber(&[0x02, 0x01, 0x00], &Large(0));
+}
+
+#[test]
+fn test_colors() {
+    serialize_and_deserialize_ber(
+        &[
+            0x30, 0x06, // SEQUENCE OF
+            0x0A, 0x01, 0x00, // ENUMERATED red
+            0x0A, 0x01, 0x02, // ENUMERATED blue
+        ],
+        &Colors(vec![Color::Red, Color::Blue]),
+    );
+}
+
+#[test]
+fn test_enumeration_values_are_encoded_instead_of_indices() {
+    serialize_and_deserialize_ber(&[0x0A, 0x01, 0x05], &Shade::Light);
+    serialize_and_deserialize_ber(&[0x0A, 0x01, 0x0A], &Shade::Dark);
+    // the smallest value not used by the root enumeration
+    serialize_and_deserialize_ber(&[0x0A, 0x01, 0x00], &Shade::Dim);
+}
+
+#[test]
+fn test_wrapper_explicitly_tags_choice() {
+    serialize_and_deserialize_ber(
+        &[
+            0x30, 0x08, // SEQUENCE
+            0xA0, 0x03, // [0] decision, explicit
+            0x80, 0x01, 0x05, // [0] a
+            0x81, 0x01, 0x01, // [1] color, implicit
+        ],
+        &Wrapper {
+            decision: Decision::A(5),
+            color: Color::Green,
+        },
+    );
+}
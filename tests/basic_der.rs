@@ -0,0 +1,183 @@
+#![recursion_limit = "512"]
+
+mod test_utils;
+
+use asn1rs::io::ber::Error;
+use asn1rs::model::Tag;
+use test_utils::*;
+
+asn_to_rust!(
+    r"BasicDer DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+      Record ::= SET {
+        zeta [1] INTEGER,
+        alpha [0] BOOLEAN,
+        text [APPLICATION 2] UTF8String
+      }
+
+      Numbers ::= SET OF INTEGER (-1000..1000)
+
+      Flag ::= BOOLEAN
+
+      Number ::= INTEGER
+
+      Text ::= UTF8String
+
+      Bits ::= BIT STRING
+
+    END"
+);
+
+#[test]
+fn test_record_sorted_by_tag() {
+    serialize_and_deserialize_der(
+        &[
+            0x31, 0x09, // SET
+            0x42, 0x01, b'x', // [APPLICATION 2] text
+            0x80, 0x01, 0xFF, // [0] alpha
+            0x81, 0x01, 0x05, // [1] zeta
+        ],
+        &Record {
+            zeta: 5,
+            alpha: true,
+            text: "x".to_string(),
+        },
+    );
+}
+
+#[test]
+fn test_numbers_sorted_by_encoding() {
+    assert_eq!(
+        &[
+            0x31, 0x0A, // SET OF
+            0x02, 0x01, 0x01, // 1
+            0x02, 0x01, 0xFF, // -1
+            0x02, 0x02, 0x01, 0x00, // 256
+        ][..],
+        &serialize_der(&Numbers(vec![256, 1, -1]))[..]
+    );
+    assert_eq!(
+        Numbers(vec![1, -1, 256]),
+        deserialize_der(&[
+            0x31, 0x0A, // SET OF
+            0x02, 0x01, 0x01, // 1
+            0x02, 0x01, 0xFF, // -1
+            0x02, 0x02, 0x01, 0x00, // 256
+        ])
+    );
+}
+
+#[test]
+fn test_numbers_ber_keeps_order() {
+    serialize_and_deserialize_ber(
+        &[
+            0x31, 0x0A, // SET OF
+            0x02, 0x02, 0x01, 0x00, // 256
+            0x02, 0x01, 0x01, // 1
+            0x02, 0x01, 0xFF, // -1
+        ],
+        &Numbers(vec![256, 1, -1]),
+    );
+}
+
+#[test]
+fn test_reject_unsorted_set_of() {
+    let bytes = [
+        0x31, 0x06, // SET OF
+        0x02, 0x01, 0xFF, // -1
+        0x02, 0x01, 0x01, // 1
+    ];
+    assert_eq!(
+        Err(Error::UnsortedSetOf),
+        DerReader::from(&bytes[..]).read::<Numbers>()
+    );
+    assert_eq!(
+        Ok(Numbers(vec![-1, 1])),
+        BerReader::from(&bytes[..]).read::<Numbers>()
+    );
+}
+
+#[test]
+fn test_reject_indefinite_length() {
+    let bytes = [0x31, 0x80, 0x02, 0x01, 0x01, 0x00, 0x00];
+    assert_eq!(
+        Err(Error::IndefiniteLengthNotAllowed(Tag::DEFAULT_SET_OF)),
+        DerReader::from(&bytes[..]).read::<Numbers>()
+    );
+    assert_eq!(
+        Ok(Numbers(vec![1])),
+        BerReader::from(&bytes[..]).read::<Numbers>()
+    );
+}
+
+#[test]
+fn test_reject_non_minimal_length() {
+    let bytes = [0x02, 0x81, 0x01, 0x05];
+    assert_eq!(
+        Err(Error::NonMinimalLength(1)),
+        DerReader::from(&bytes[..]).read::<Number>()
+    );
+    assert_eq!(Ok(Number(5)), BerReader::from(&bytes[..]).read::<Number>());
+}
+
+#[test]
+fn test_reject_non_minimal_identifier() {
+    let bytes = [0x1F, 0x02, 0x01, 0x05];
+    assert_eq!(
+        Err(Error::NonMinimalIdentifier(Tag::DEFAULT_INTEGER)),
+        DerReader::from(&bytes[..]).read::<Number>()
+    );
+    assert_eq!(Ok(Number(5)), BerReader::from(&bytes[..]).read::<Number>());
+}
+
+#[test]
+fn test_reject_non_minimal_integer() {
+    let bytes = [0x02, 0x02, 0x00, 0x05];
+    assert_eq!(
+        Err(Error::NonMinimalInteger),
+        DerReader::from(&bytes[..]).read::<Number>()
+    );
+    assert_eq!(Ok(Number(5)), BerReader::from(&bytes[..]).read::<Number>());
+}
+
+#[test]
+fn test_reject_non_canonical_boolean() {
+    serialize_and_deserialize_der(&[0x01, 0x01, 0xFF], &Flag(true));
+    serialize_and_deserialize_der(&[0x01, 0x01, 0x00], &Flag(false));
+    let bytes = [0x01, 0x01, 0x01];
+    assert_eq!(
+        Err(Error::InvalidBooleanValue(0x01)),
+        DerReader::from(&bytes[..]).read::<Flag>()
+    );
+    assert_eq!(Ok(Flag(true)), BerReader::from(&bytes[..]).read::<Flag>());
+}
+
+#[test]
+fn test_reject_constructed_string() {
+    let bytes = [0x2C, 0x03, 0x04, 0x01, b'x'];
+    assert_eq!(
+        Err(Error::UnexpectedEncodingForm(
+            Tag::DEFAULT_UTF8_STRING,
+            true
+        )),
+        DerReader::from(&bytes[..]).read::<Text>()
+    );
+    assert_eq!(
+        Ok(Text("x".to_string())),
+        BerReader::from(&bytes[..]).read::<Text>()
+    );
+}
+
+#[test]
+fn test_reject_non_zero_unused_bits() {
+    serialize_and_deserialize_der(
+        &[0x03, 0x02, 0x04, 0b1010_0000],
+        &Bits(BitVec::from_bytes(vec![0b1010_1111], 4)),
+    );
+    let bytes = [0x03, 0x02, 0x04, 0b1010_0001];
+    assert_eq!(
+        Err(Error::NonZeroUnusedBits),
+        DerReader::from(&bytes[..]).read::<Bits>()
+    );
+}
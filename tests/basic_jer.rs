@@ -0,0 +1,207 @@
+#![recursion_limit = "512"]
+
+mod test_utils;
+
+use asn1rs::io::jer::Error;
+use asn1rs::syn::bitstring::BitVec;
+use test_utils::*;
+
+asn_to_rust!(
+    r"BasicJer DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+      Potato ::= SEQUENCE {
+        size INTEGER (0..255),
+        flag BOOLEAN,
+        potato-name IA5String (SIZE(1..32)) OPTIONAL,
+        type UTF8String
+      }
+
+      Extensible ::= SEQUENCE {
+        abc UTF8String,
+        ...,
+        def INTEGER OPTIONAL
+      }
+
+      Decision ::= CHOICE {
+        small-number INTEGER (0..255),
+        flag BOOLEAN,
+        ...,
+        text UTF8String
+      }
+
+      Color ::= ENUMERATED { red, light-blue, dark-green }
+
+      Wrapper ::= SEQUENCE {
+        decision Decision,
+        colors SEQUENCE OF Color
+      }
+
+      Bits ::= SEQUENCE {
+        fixed BIT STRING (SIZE(12)),
+        variable BIT STRING (SIZE(0..16))
+      }
+
+      Hash ::= OCTET STRING (SIZE(4))
+
+      Numbers ::= SET OF INTEGER (-1000..1000)
+
+      Text ::= UTF8String
+
+    END"
+);
+
+#[test]
+fn test_potato_with_original_identifiers() {
+    serialize_and_deserialize_jer(
+        r#"{"size":5,"flag":true,"potato-name":"ab","type":"x\"y"}"#,
+        &Potato {
+            size: 5,
+            flag: true,
+            potato_name: Some("ab".to_string()),
+            type_: "x\"y".to_string(),
+        },
+    )
+}
+
+#[test]
+fn test_potato_absent_optional_is_omitted() {
+    serialize_and_deserialize_jer(
+        r#"{"size":0,"flag":false,"type":""}"#,
+        &Potato {
+            size: 0,
+            flag: false,
+            potato_name: None,
+            type_: String::new(),
+        },
+    )
+}
+
+#[test]
+fn test_potato_with_whitespace_and_reordered_members() {
+    assert_eq!(
+        Potato {
+            size: 255,
+            flag: false,
+            potato_name: None,
+            type_: "abc".to_string(),
+        },
+        deserialize_jer(
+            r#" { "type" : "abc" , "flag" : false ,
+                "size" : 255 } "#
+        )
+    )
+}
+
+#[test]
+fn test_choice_is_single_member_object() {
+    serialize_and_deserialize_jer(r#"{"small-number":42}"#, &Decision::SmallNumber(42));
+    serialize_and_deserialize_jer(r#"{"flag":true}"#, &Decision::Flag(true));
+    serialize_and_deserialize_jer(r#"{"text":"hi"}"#, &Decision::Text("hi".to_string()));
+}
+
+#[test]
+fn test_enumerated_as_identifier() {
+    serialize_and_deserialize_jer(r#""red""#, &Color::Red);
+    serialize_and_deserialize_jer(r#""light-blue""#, &Color::LightBlue);
+    serialize_and_deserialize_jer(r#""dark-green""#, &Color::DarkGreen);
+}
+
+#[test]
+fn test_nested_choice_and_sequence_of() {
+    serialize_and_deserialize_jer(
+        r#"{"decision":{"flag":false},"colors":["dark-green","red"]}"#,
+        &Wrapper {
+            decision: Decision::Flag(false),
+            colors: vec![Color::DarkGreen, Color::Red],
+        },
+    )
+}
+
+#[test]
+fn test_bit_strings() {
+    serialize_and_deserialize_jer(
+        r#"{"fixed":"ABC0","variable":{"value":"F8","length":5}}"#,
+        &Bits {
+            fixed: BitVec::from_bytes(vec![0xAB, 0xC0], 12),
+            variable: BitVec::from_bytes(vec![0xF8], 5),
+        },
+    )
+}
+
+#[test]
+fn test_octet_string_as_hex() {
+    serialize_and_deserialize_jer(r#""DEADBEEF""#, &Hash(vec![0xDE, 0xAD, 0xBE, 0xEF]));
+    assert_eq!(
+        Hash(vec![0xDE, 0xAD, 0xBE, 0xEF]),
+        deserialize_jer(r#""deadbeef""#)
+    );
+}
+
+#[test]
+fn test_transparent_types() {
+    serialize_and_deserialize_jer(r#"[-1000,0,1000]"#, &Numbers(vec![-1000, 0, 1000]));
+    serialize_and_deserialize_jer(r#""\n""#, &Text("\n".to_string()));
+}
+
+#[test]
+fn test_extensible_ignores_unknown_members() {
+    serialize_and_deserialize_jer(
+        r#"{"abc":"x","def":7}"#,
+        &Extensible {
+            abc: "x".to_string(),
+            def: Some(7),
+        },
+    );
+    assert_eq!(
+        Extensible {
+            abc: "x".to_string(),
+            def: None,
+        },
+        deserialize_jer(r#"{"abc":"x","ghi":[1,2]}"#)
+    );
+}
+
+#[test]
+fn test_rejects_unknown_member() {
+    assert_eq!(
+        Err(Error::UnknownMember("color".to_string())),
+        JerReader::from(r#"{"size":1,"flag":true,"type":"","color":"red"}"#).read::<Potato>()
+    );
+}
+
+#[test]
+fn test_rejects_missing_member() {
+    assert_eq!(
+        Err(Error::MissingMember("flag".to_string())),
+        JerReader::from(r#"{"size":1,"type":""}"#).read::<Potato>()
+    );
+}
+
+#[test]
+fn test_rejects_unknown_identifiers() {
+    assert_eq!(
+        Err(Error::UnknownVariant("blue".to_string())),
+        JerReader::from(r#""blue""#).read::<Color>()
+    );
+    assert_eq!(
+        Err(Error::UnknownVariant("number".to_string())),
+        JerReader::from(r#"{"number":1}"#).read::<Decision>()
+    );
+}
+
+#[test]
+fn test_rejects_value_not_in_range() {
+    assert_eq!(
+        Err(Error::ValueNotInRange(256, 0, 255)),
+        JerReader::from(r#"{"small-number":256}"#).read::<Decision>()
+    );
+}
+
+#[test]
+fn test_rejects_malformed_json() {
+    assert_eq!(
+        Err(Error::InvalidJson(10)),
+        JerReader::from(r#"{"abc":"x""#).read::<Extensible>()
+    );
+}
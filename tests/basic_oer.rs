@@ -0,0 +1,306 @@
+#![recursion_limit = "512"]
+
+mod test_utils;
+
+use asn1rs::io::oer::Error;
+use test_utils::*;
+
+asn_to_rust!(
+    r"BasicOer DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+      Potato ::= SEQUENCE {
+        size INTEGER (0..255),
+        id INTEGER (0..65535),
+        flag BOOLEAN,
+        name IA5String (SIZE(1..32)) OPTIONAL,
+        note UTF8String
+      }
+
+      Extensible ::= SEQUENCE {
+        abc UTF8String,
+        def INTEGER,
+        ...,
+        ghi UTF8String
+      }
+
+      ExtensibleRoot ::= SEQUENCE {
+        abc UTF8String,
+        def INTEGER,
+        ...
+      }
+
+      Decision ::= CHOICE {
+        a INTEGER (0..255),
+        b BOOLEAN,
+        ...,
+        c UTF8String
+      }
+
+      Large ::= INTEGER (0..4294967295)
+
+      Small ::= INTEGER (-5..5)
+
+      Positive ::= INTEGER (1..MAX)
+
+      Offset ::= INTEGER (-5..MAX)
+
+      Color ::= ENUMERATED { red, green, blue }
+
+      Colors ::= SEQUENCE OF Color
+
+      Shade ::= ENUMERATED { light(5), dark(200) }
+
+      Wrapper ::= SEQUENCE {
+        decision Decision,
+        color Color
+      }
+
+      Numbers ::= SET OF INTEGER (-1000..1000)
+
+      Hash ::= OCTET STRING (SIZE(4))
+
+      Flag ::= BOOLEAN
+
+      Text ::= UTF8String
+
+    END"
+);
+
+#[test]
+fn test_potato_with_optional_field() {
+    serialize_and_deserialize_oer(
+        &[
+            0x80, // preamble, name present
+            0x05, // size
+            0x03, 0xE8, // id
+            0xFF, // flag
+            0x02, b'a', b'b', // name
+            0x01, b'x', // note
+        ],
+        &Potato {
+            size: 5,
+            id: 1000,
+            flag: true,
+            name: Some("ab".to_string()),
+            note: "x".to_string(),
+        },
+    );
+}
+
+#[test]
+fn test_potato_without_optional_field() {
+    serialize_and_deserialize_oer(
+        &[
+            0x00, // preamble, name absent
+            0x05, // size
+            0x03, 0xE8, // id
+            0x00, // flag
+            0x01, b'x', // note
+        ],
+        &Potato {
+            size: 5,
+            id: 1000,
+            flag: false,
+            name: None,
+            note: "x".to_string(),
+        },
+    );
+}
+
+#[test]
+fn test_extensible_with_extension_addition() {
+    serialize_and_deserialize_oer(
+        &[
+            0x80, // preamble, extension present
+            0x01, b'a', // abc
+            0x01, 0x05, // def
+            0x02, 0x07, 0x80, // presence bitmap
+            0x02, 0x01, b'b', // ghi as open type
+        ],
+        &Extensible {
+            abc: "a".to_string(),
+            def: 5,
+            ghi: "b".to_string(),
+        },
+    );
+}
+
+#[test]
+fn test_extensible_skips_unknown_extension_addition() {
+    assert_eq!(
+        ExtensibleRoot {
+            abc: "a".to_string(),
+            def: 5,
+        },
+        deserialize_oer(&[
+            0x80, // preamble, extension present
+            0x01, b'a', // abc
+            0x01, 0x05, // def
+            0x02, 0x07, 0x80, // presence bitmap
+            0x02, 0x01, b'b', // unknown extension addition
+        ])
+    );
+}
+
+#[test]
+fn test_extensible_missing_extension_addition() {
+    let mut reader = OerReader::from(&[0x00, 0x01, b'a', 0x01, 0x05][..]);
+    assert_eq!(
+        Err(Error::MissingExtensionAddition),
+        reader.read::<Extensible>()
+    );
+}
+
+#[test]
+fn test_decision() {
+    serialize_and_deserialize_oer(&[0x80, 0x05], &Decision::A(5));
+    serialize_and_deserialize_oer(&[0x81, 0xFF], &Decision::B(true));
+    serialize_and_deserialize_oer(
+        &[
+            0x82, // tag
+            0x02, 0x01, b'x', // c as open type
+        ],
+        &Decision::C("x".to_string()),
+    );
+}
+
+#[test]
+fn test_fixed_size_integers() {
+    serialize_and_deserialize_oer(&[0xFF, 0xFF, 0xFF, 0xFF], &Large(4_294_967_295));
+    serialize_and_deserialize_oer(&[0xFF], &Small(-1));
+    serialize_and_deserialize_oer(&[0x05], &Small(5));
+}
+
+#[test]
+fn test_semi_constrained_integers() {
+    serialize_and_deserialize_oer(&[0x01, 0x05], &Positive(5));
+    serialize_and_deserialize_oer(&[0x02, 0x01, 0x2C], &Positive(300));
+    serialize_and_deserialize_oer(&[0x01, 0xFB], &Offset(-5));
+    serialize_and_deserialize_oer(&[0x02, 0x01, 0x2C], &Offset(300));
+    assert_eq!(&[0x01, 0x05][..], &serialize_coer(&Positive(5))[..]);
+    assert_eq!(&[0x01, 0xFB][..], &serialize_coer(&Offset(-5))[..]);
+    assert_eq!(Positive(5), deserialize_coer(&[0x01, 0x05]));
+    assert_eq!(Offset(-5), deserialize_coer(&[0x01, 0xFB]));
+}
+
+#[test]
+fn test_small_not_in_range() {
+    let mut reader = OerReader::from(&[0x06][..]);
+    assert_eq!(
+        Err(Error::ValueNotInRange(6, -5, 5)),
+        reader.read::<Small>()
+    );
+}
+
+#[test]
+fn test_colors() {
+    serialize_and_deserialize_oer(
+        &[
+            0x01, 0x02, // quantity
+            0x00, // red
+            0x02, // blue
+        ],
+        &Colors(vec![Color::Red, Color::Blue]),
+    );
+}
+
+#[test]
+fn test_enumeration_values_are_encoded_instead_of_indices() {
+    serialize_and_deserialize_oer(&[0x05], &Shade::Light);
+    serialize_and_deserialize_oer(&[0x82, 0x00, 0xC8], &Shade::Dark);
+}
+
+#[test]
+fn test_unknown_enumeration_value() {
+    let mut reader = OerReader::from(&[0x01][..]);
+    assert_eq!(
+        Err(Error::InvalidEnumeratedValue(1)),
+        reader.read::<Shade>()
+    );
+}
+
+#[test]
+fn test_wrapper() {
+    serialize_and_deserialize_oer(
+        &[
+            0x81, 0xFF, // decision
+            0x01, // color
+        ],
+        &Wrapper {
+            decision: Decision::B(true),
+            color: Color::Green,
+        },
+    );
+}
+
+#[test]
+fn test_hash_fixed_size() {
+    serialize_and_deserialize_oer(&[0x01, 0x02, 0x03, 0x04], &Hash(vec![1, 2, 3, 4]));
+}
+
+#[test]
+fn test_numbers_canonical_sorted_by_encoding() {
+    assert_eq!(
+        &[
+            0x01, 0x03, // quantity
+            0x01, 0x00, // 256
+            0x00, 0x01, // 1
+            0xFF, 0xFF, // -1
+        ][..],
+        &serialize_oer(&Numbers(vec![256, 1, -1]))[..]
+    );
+    assert_eq!(
+        &[
+            0x01, 0x03, // quantity
+            0x00, 0x01, // 1
+            0x01, 0x00, // 256
+            0xFF, 0xFF, // -1
+        ][..],
+        &serialize_coer(&Numbers(vec![256, 1, -1]))[..]
+    );
+    assert_eq!(
+        Numbers(vec![1, 256, -1]),
+        deserialize_coer(&[0x01, 0x03, 0x00, 0x01, 0x01, 0x00, 0xFF, 0xFF])
+    );
+}
+
+#[test]
+fn test_canonical_rejects_unsorted_set_of() {
+    let bytes = [0x01, 0x02, 0x01, 0x00, 0x00, 0x01];
+    assert_eq!(Numbers(vec![256, 1]), deserialize_oer(&bytes));
+    let mut reader = OerReader::canonical(&bytes[..]);
+    assert_eq!(Err(Error::UnsortedSetOf), reader.read::<Numbers>());
+}
+
+#[test]
+fn test_canonical_rejects_invalid_boolean() {
+    assert_eq!(Flag(true), deserialize_oer(&[0x01]));
+    let mut reader = OerReader::canonical(&[0x01][..]);
+    assert_eq!(Err(Error::InvalidBooleanValue(0x01)), reader.read::<Flag>());
+}
+
+#[test]
+fn test_canonical_rejects_non_minimal_length() {
+    let bytes = [0x81, 0x01, b'x'];
+    assert_eq!(Text("x".to_string()), deserialize_oer(&bytes));
+    let mut reader = OerReader::canonical(&bytes[..]);
+    assert_eq!(Err(Error::NonMinimalLength(1)), reader.read::<Text>());
+}
+
+#[test]
+fn test_canonical_rejects_non_minimal_integer() {
+    let bytes = [
+        0x80, 0x01, b'a', 0x02, 0x00, 0x05, 0x02, 0x07, 0x80, 0x02, 0x01, b'b',
+    ];
+    assert_eq!(
+        Extensible {
+            abc: "a".to_string(),
+            def: 5,
+            ghi: "b".to_string(),
+        },
+        deserialize_oer(&bytes)
+    );
+    let mut reader = OerReader::canonical(&bytes[..]);
+    assert_eq!(Err(Error::NonMinimalInteger), reader.read::<Extensible>());
+}
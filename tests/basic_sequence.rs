@@ -2,10 +2,11 @@
 
 mod test_utils;
 
+use asn1rs::syn::io::UperReader as NewUperReader;
 use test_utils::*;
 
 asn_to_rust!(
-    r"BasicSet DEFINITIONS AUTOMATIC TAGS ::=
+    r#"BasicSet DEFINITIONS AUTOMATIC TAGS ::=
     BEGIN
 
       Basic ::= [5] SEQUENCE {
@@ -19,8 +20,72 @@ asn_to_rust!(
         ...,
         ghi [APPLICATION 2] UTF8String
       }
-          
-    END"
+
+      Versioned ::= SEQUENCE {
+        abc UTF8String,
+        def INTEGER (0..255),
+        ...
+      }
+
+      VersionedOptional ::= SEQUENCE {
+        abc UTF8String,
+        def INTEGER (0..255),
+        ...,
+        jkl INTEGER (0..255) OPTIONAL
+      }
+
+      VersionedRequired ::= SEQUENCE {
+        abc UTF8String,
+        def INTEGER (0..255),
+        ...,
+        jkl INTEGER (0..255)
+      }
+
+      VersionedLatest ::= SEQUENCE {
+        abc UTF8String,
+        def INTEGER (0..255),
+        ...,
+        jkl INTEGER (0..255) OPTIONAL,
+        mno UTF8String,
+        pqr BOOLEAN OPTIONAL
+      }
+
+      Outer ::= SEQUENCE {
+        inner Versioned,
+        tail INTEGER (0..255)
+      }
+
+      OuterLatest ::= SEQUENCE {
+        inner VersionedLatest,
+        tail INTEGER (0..255)
+      }
+
+      Grouped ::= SEQUENCE {
+        abc INTEGER (0..255),
+        ...,
+        [[ 2: def INTEGER (0..255), ghi BOOLEAN OPTIONAL ]],
+        [[ jkl BOOLEAN OPTIONAL ]],
+        mno BOOLEAN OPTIONAL
+      }
+
+      GroupedRoot ::= SEQUENCE {
+        abc INTEGER (0..255),
+        ...
+      }
+
+      Shade ::= ENUMERATED { red, light-blue }
+
+      WithDefaults ::= SEQUENCE {
+        number INTEGER (0..255) DEFAULT 5,
+        flag BOOLEAN DEFAULT TRUE,
+        text UTF8String DEFAULT "a, b",
+        shade Shade DEFAULT light-blue,
+        bits BIT STRING DEFAULT '1010'B,
+        bytes OCTET STRING DEFAULT 'ABCD'H,
+        required INTEGER (0..255)
+      }
+
+    END"#
 );
 
 #[test]
@@ -56,3 +121,207 @@ fn test_extensible() {
         },
     );
 }
+
+#[test]
+fn test_optional_extension_addition_is_open_type() {
+    // extension bit, abc, def, number of extended fields, presence flag, open type for jkl
+    serialize_and_deserialize_uper(
+        41,
+        &[0x80, 0x00, 0x80, 0x80, 0x82, 0x80],
+        &VersionedOptional {
+            abc: String::new(),
+            def: 1,
+            jkl: Some(5),
+        },
+    );
+}
+
+#[test]
+fn test_absent_extension_additions_are_not_encoded() {
+    // extension bit, abc, def
+    serialize_and_deserialize_uper(
+        17,
+        &[0x00, 0x00, 0x80],
+        &VersionedOptional {
+            abc: String::new(),
+            def: 1,
+            jkl: None,
+        },
+    );
+}
+
+#[test]
+fn test_absent_extension_addition_before_present_one() {
+    // extension bit, abc, def, number of extended fields, presence flags, open type for mno
+    serialize_and_deserialize_uper(
+        51,
+        &[0x80, 0x00, 0x82, 0x40, 0x40, 0x2F, 0x00],
+        &VersionedLatest {
+            abc: String::new(),
+            def: 1,
+            jkl: None,
+            mno: "x".to_string(),
+            pqr: None,
+        },
+    );
+}
+
+fn latest() -> VersionedLatest {
+    VersionedLatest {
+        abc: "abc".to_string(),
+        def: 7,
+        jkl: Some(5),
+        mno: "unknown to older versions".to_string(),
+        pqr: Some(true),
+    }
+}
+
+#[test]
+fn test_skips_unknown_extension_additions() {
+    let (bits, data) = serialize_uper(&latest());
+    assert_eq!(
+        Versioned {
+            abc: "abc".to_string(),
+            def: 7,
+        },
+        deserialize_uper(&data, bits)
+    );
+}
+
+#[test]
+fn test_reads_known_and_skips_unknown_extension_additions() {
+    let (bits, data) = serialize_uper(&latest());
+    assert_eq!(
+        VersionedOptional {
+            abc: "abc".to_string(),
+            def: 7,
+            jkl: Some(5),
+        },
+        deserialize_uper(&data, bits)
+    );
+}
+
+#[test]
+fn test_continues_after_skipped_extension_additions() {
+    let (bits, data) = serialize_uper(&OuterLatest {
+        inner: latest(),
+        tail: 42,
+    });
+    assert_eq!(
+        Outer {
+            inner: Versioned {
+                abc: "abc".to_string(),
+                def: 7,
+            },
+            tail: 42,
+        },
+        deserialize_uper(&data, bits)
+    );
+}
+
+#[test]
+fn test_missing_optional_extension_addition_of_older_version() {
+    let (bits, data) = serialize_uper(&Versioned {
+        abc: "abc".to_string(),
+        def: 7,
+    });
+    assert_eq!(
+        VersionedOptional {
+            abc: "abc".to_string(),
+            def: 7,
+            jkl: None,
+        },
+        deserialize_uper(&data, bits)
+    );
+}
+
+#[test]
+fn test_missing_required_extension_addition_of_older_version() {
+    let (bits, data) = serialize_uper(&Versioned {
+        abc: "abc".to_string(),
+        def: 7,
+    });
+    assert_eq!(
+        Err(asn1rs::io::per::Error::MissingExtensionAddition),
+        NewUperReader::from((&data[..], bits)).read::<VersionedRequired>()
+    );
+}
+
+#[test]
+fn test_extension_addition_group() {
+    // the group is encoded as a single open type with the encoding of a SEQUENCE
+    serialize_and_deserialize_uper(
+        43,
+        &[0x80, 0x82, 0x80, 0x40, 0x20, 0x00],
+        &Grouped {
+            abc: 1,
+            ext_group_v2: GroupedExtGroupV2 { def: 2, ghi: None },
+            ext_group_2: None,
+            mno: None,
+        },
+    );
+}
+
+#[test]
+fn test_extension_addition_group_of_newer_version_is_skipped() {
+    let (bits, data) = serialize_uper(&Grouped {
+        abc: 1,
+        ext_group_v2: GroupedExtGroupV2 {
+            def: 2,
+            ghi: Some(true),
+        },
+        ext_group_2: Some(GroupedExtGroup2 { jkl: Some(false) }),
+        mno: Some(true),
+    });
+    assert_eq!(GroupedRoot { abc: 1 }, deserialize_uper(&data, bits));
+}
+
+#[test]
+fn test_missing_required_extension_addition_group_of_older_version() {
+    let (bits, data) = serialize_uper(&GroupedRoot { abc: 1 });
+    assert_eq!(
+        Err(asn1rs::io::per::Error::MissingExtensionAddition),
+        NewUperReader::from((&data[..], bits)).read::<Grouped>()
+    );
+}
+
+#[test]
+fn test_default_values() {
+    assert_eq!(
+        WithDefaults {
+            number: 5,
+            flag: true,
+            text: "a, b".to_string(),
+            shade: Shade::LightBlue,
+            bits: BitVec::from_bytes(vec![0xA0], 4),
+            bytes: vec![0xAB, 0xCD],
+            required: 0,
+        },
+        WithDefaults::default()
+    );
+}
+
+#[test]
+fn test_default_values_are_omitted() {
+    serialize_and_deserialize_uper(
+        14,
+        &[0x00, 0x1C],
+        &WithDefaults {
+            required: 7,
+            ..Default::default()
+        },
+    );
+}
+
+#[test]
+fn test_values_other_than_the_default_are_present() {
+    serialize_and_deserialize_uper(
+        22,
+        &[0x80, 0x18, 0x1C],
+        &WithDefaults {
+            number: 6,
+            required: 7,
+            ..Default::default()
+        },
+    );
+}
@@ -0,0 +1,267 @@
+#![recursion_limit = "512"]
+
+mod test_utils;
+
+use asn1rs::io::xer::Error;
+use asn1rs::syn::bitstring::BitVec;
+use test_utils::*;
+
+asn_to_rust!(
+    r"BasicXer DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+      Potato ::= SEQUENCE {
+        size INTEGER (0..255),
+        flag BOOLEAN,
+        potato-name IA5String (SIZE(1..32)) OPTIONAL,
+        type UTF8String
+      }
+
+      Extensible ::= SEQUENCE {
+        abc UTF8String,
+        ...,
+        def INTEGER OPTIONAL
+      }
+
+      Decision ::= CHOICE {
+        small-number INTEGER (0..255),
+        flag BOOLEAN,
+        ...,
+        text UTF8String
+      }
+
+      Color ::= ENUMERATED { red, light-blue, dark-green }
+
+      Wrapper ::= SEQUENCE {
+        decision Decision,
+        colors SEQUENCE OF Color,
+        decisions SEQUENCE OF Decision,
+        numbers SEQUENCE OF INTEGER
+      }
+
+      Potatoes ::= SEQUENCE OF Potato
+
+      Bits ::= SEQUENCE {
+        fixed BIT STRING (SIZE(12)),
+        variable BIT STRING (SIZE(0..16))
+      }
+
+      Hash ::= OCTET STRING (SIZE(4))
+
+      Numbers ::= SET OF INTEGER (-1000..1000)
+
+      Text ::= UTF8String
+
+      Texts ::= SEQUENCE OF Text
+
+    END"
+);
+
+#[test]
+fn test_potato_with_original_identifiers() {
+    serialize_and_deserialize_xer(
+        "<Potato><size>5</size><flag><true/></flag><potato-name>ab</potato-name>\
+         <type>x&lt;&amp;&gt;y</type></Potato>",
+        &Potato {
+            size: 5,
+            flag: true,
+            potato_name: Some("ab".to_string()),
+            type_: "x<&>y".to_string(),
+        },
+    )
+}
+
+#[test]
+fn test_potato_absent_optional_is_omitted() {
+    serialize_and_deserialize_xer(
+        "<Potato><size>0</size><flag><false/></flag><type></type></Potato>",
+        &Potato {
+            size: 0,
+            flag: false,
+            potato_name: None,
+            type_: String::new(),
+        },
+    )
+}
+
+#[test]
+fn test_potato_with_declaration_and_white_space() {
+    assert_eq!(
+        Potato {
+            size: 255,
+            flag: false,
+            potato_name: None,
+            type_: " abc ".to_string(),
+        },
+        deserialize_xer(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+            <Potato>
+                <size> 255 </size>
+                <flag> <false /> </flag>
+                <type> abc </type>
+            </Potato>
+            "#
+        )
+    )
+}
+
+#[test]
+fn test_choice() {
+    serialize_and_deserialize_xer(
+        "<Decision><small-number>42</small-number></Decision>",
+        &Decision::SmallNumber(42),
+    );
+    serialize_and_deserialize_xer(
+        "<Decision><flag><true/></flag></Decision>",
+        &Decision::Flag(true),
+    );
+    serialize_and_deserialize_xer(
+        "<Decision><text>hi</text></Decision>",
+        &Decision::Text("hi".to_string()),
+    );
+}
+
+#[test]
+fn test_enumerated_as_empty_element() {
+    serialize_and_deserialize_xer("<Color><red/></Color>", &Color::Red);
+    serialize_and_deserialize_xer("<Color><light-blue/></Color>", &Color::LightBlue);
+    serialize_and_deserialize_xer("<Color><dark-green/></Color>", &Color::DarkGreen);
+}
+
+#[test]
+fn test_sequence_of_value_lists_and_delimited_items() {
+    serialize_and_deserialize_xer(
+        "<Wrapper><decision><flag><false/></flag></decision>\
+         <colors><dark-green/><red/></colors>\
+         <decisions><small-number>1</small-number><text>a</text></decisions>\
+         <numbers><INTEGER>1</INTEGER><INTEGER>2</INTEGER></numbers></Wrapper>",
+        &Wrapper {
+            decision: Decision::Flag(false),
+            colors: vec![Color::DarkGreen, Color::Red],
+            decisions: vec![Decision::SmallNumber(1), Decision::Text("a".to_string())],
+            numbers: vec![1, 2],
+        },
+    )
+}
+
+#[test]
+fn test_sequence_of_referenced_types() {
+    serialize_and_deserialize_xer(
+        "<Potatoes><Potato><size>1</size><flag><true/></flag><type>a</type></Potato></Potatoes>",
+        &Potatoes(vec![Potato {
+            size: 1,
+            flag: true,
+            potato_name: None,
+            type_: "a".to_string(),
+        }]),
+    );
+    serialize_and_deserialize_xer(
+        "<Texts><Text>a</Text><Text></Text></Texts>",
+        &Texts(vec![Text("a".to_string()), Text(String::new())]),
+    );
+}
+
+#[test]
+fn test_bit_strings() {
+    serialize_and_deserialize_xer(
+        "<Bits><fixed>101010111100</fixed><variable>11111</variable></Bits>",
+        &Bits {
+            fixed: BitVec::from_bytes(vec![0xAB, 0xC0], 12),
+            variable: BitVec::from_bytes(vec![0xF8], 5),
+        },
+    )
+}
+
+#[test]
+fn test_octet_string_as_hex() {
+    serialize_and_deserialize_xer("<Hash>DEADBEEF</Hash>", &Hash(vec![0xDE, 0xAD, 0xBE, 0xEF]));
+    assert_eq!(
+        Hash(vec![0xDE, 0xAD, 0xBE, 0xEF]),
+        deserialize_xer("<Hash>dead beef</Hash>")
+    );
+}
+
+#[test]
+fn test_transparent_types() {
+    serialize_and_deserialize_xer(
+        "<Numbers><INTEGER>-1000</INTEGER><INTEGER>1000</INTEGER></Numbers>",
+        &Numbers(vec![-1000, 1000]),
+    );
+    serialize_and_deserialize_xer(
+        "<Text>a<bel/>\tb<cr/>\n</Text>",
+        &Text("a\u{7}\tb\r\n".to_string()),
+    );
+}
+
+#[test]
+fn test_extensible_ignores_unknown_elements() {
+    serialize_and_deserialize_xer(
+        "<Extensible><abc>x</abc><def>7</def></Extensible>",
+        &Extensible {
+            abc: "x".to_string(),
+            def: Some(7),
+        },
+    );
+    assert_eq!(
+        Extensible {
+            abc: "x".to_string(),
+            def: None,
+        },
+        deserialize_xer("<Extensible><abc>x</abc><ghi><a/></ghi></Extensible>")
+    );
+}
+
+#[test]
+fn test_rejects_unknown_element() {
+    assert_eq!(
+        Err(Error::UnknownElement("color".to_string())),
+        XerReader::from(
+            "<Potato><size>1</size><flag><true/></flag><type/><color><red/></color></Potato>"
+        )
+        .read::<Potato>()
+    );
+}
+
+#[test]
+fn test_rejects_missing_element() {
+    assert_eq!(
+        Err(Error::MissingElement("flag".to_string())),
+        XerReader::from("<Potato><size>1</size><type/></Potato>").read::<Potato>()
+    );
+}
+
+#[test]
+fn test_rejects_unknown_identifiers() {
+    assert_eq!(
+        Err(Error::UnknownVariant("blue".to_string())),
+        XerReader::from("<Color><blue/></Color>").read::<Color>()
+    );
+    assert_eq!(
+        Err(Error::UnknownVariant("number".to_string())),
+        XerReader::from("<Decision><number>1</number></Decision>").read::<Decision>()
+    );
+}
+
+#[test]
+fn test_rejects_invalid_values() {
+    assert_eq!(
+        Err(Error::ValueNotInRange(256, 0, 255)),
+        XerReader::from("<Decision><small-number>256</small-number></Decision>").read::<Decision>()
+    );
+    assert_eq!(
+        Err(Error::InvalidNumber("1.5".to_string())),
+        XerReader::from("<Decision><small-number>1.5</small-number></Decision>").read::<Decision>()
+    );
+    assert_eq!(
+        Err(Error::UnexpectedContent("<true/> or <false/>")),
+        XerReader::from("<Decision><flag><yes/></flag></Decision>").read::<Decision>()
+    );
+}
+
+#[test]
+fn test_rejects_malformed_xml() {
+    assert_eq!(
+        Err(Error::InvalidXml(12)),
+        XerReader::from("<Extensible><abc>x</abd></Extensible>").read::<Extensible>()
+    );
+}
@@ -0,0 +1,153 @@
+mod test_utils;
+
+// 128-bit INTEGERs are not supported by the legacy UPER codegen, protobuf and psql supplements
+#[cfg(not(any(
+    feature = "legacy-uper-codegen",
+    feature = "protobuf",
+    feature = "psql",
+    feature = "async-psql"
+)))]
+mod wide {
+    use crate::test_utils::*;
+
+    asn_to_rust!(
+        r"BigIntegers DEFINITIONS AUTOMATIC TAGS ::=
+        BEGIN
+
+        Unsigned ::= INTEGER (0..18446744073709551616)
+
+        Signed ::= INTEGER (-170141183460469231731687303715884105728..170141183460469231731687303715884105727)
+
+        Above ::= INTEGER (18446744073709551616..MAX)
+
+        Extensible ::= INTEGER (0..18446744073709551616, ...)
+
+        Measurement ::= SEQUENCE {
+            count INTEGER (0..18446744073709551616),
+            offset Signed OPTIONAL
+        }
+
+        END"
+    );
+
+    #[test]
+    fn test_unsigned_beyond_u64() {
+        // 65 bits for the range of 2^64
+        serialize_and_deserialize_uper(65, &[0x80, 0, 0, 0, 0, 0, 0, 0, 0], &Unsigned(1 << 64));
+        serialize_and_deserialize_uper(65, &[0, 0, 0, 0, 0, 0, 0, 0x02, 0x80], &Unsigned(5));
+        // the number of octets (1..=9) in 4 bits, followed by the octet-aligned value
+        serialize_and_deserialize_aper(16, &[0x00, 0x05], &Unsigned(5));
+        serialize_and_deserialize_ber(
+            &[0x02, 0x09, 0x01, 0, 0, 0, 0, 0, 0, 0, 0],
+            &Unsigned(1 << 64),
+        );
+        serialize_and_deserialize_oer(&[0x09, 0x01, 0, 0, 0, 0, 0, 0, 0, 0], &Unsigned(1 << 64));
+        serialize_and_deserialize_jer("18446744073709551616", &Unsigned(1 << 64));
+        serialize_and_deserialize_xer(
+            "<Unsigned>18446744073709551616</Unsigned>",
+            &Unsigned(1 << 64),
+        );
+    }
+
+    #[test]
+    fn test_signed_full_range() {
+        let mut offset = [0xFF_u8; 16];
+        offset[0] = 0x7F;
+        serialize_and_deserialize_uper(128, &offset, &Signed(-1));
+        serialize_and_deserialize_uper(128, &[0; 16], &Signed(i128::MIN));
+        serialize_and_deserialize_ber(&[0x02, 0x01, 0xFF], &Signed(-1));
+        serialize_and_deserialize_oer(&[0x01, 0xFF], &Signed(-1));
+        serialize_and_deserialize_jer(
+            "-170141183460469231731687303715884105728",
+            &Signed(i128::MIN),
+        );
+    }
+
+    #[test]
+    fn test_semi_constrained() {
+        // the offset to the lower-bound as octets with a length determinant
+        let mut expected = vec![0x10];
+        expected.extend_from_slice(&(u128::MAX - (1 << 64)).to_be_bytes());
+        serialize_and_deserialize_uper(17 * 8, &expected, &Above(u128::MAX));
+        serialize_and_deserialize_uper(16, &[0x01, 0x00], &Above(1 << 64));
+    }
+
+    #[test]
+    fn test_extensible_out_of_range() {
+        // the extension bit followed by the length and the octets of the unconstrained value
+        serialize_and_deserialize_uper(
+            81,
+            &[0x84, 0x81, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &Extensible(1 << 65),
+        );
+        serialize_and_deserialize_jer("36893488147419103232", &Extensible(1 << 65));
+    }
+
+    #[test]
+    fn test_sequence() {
+        let measurement = Measurement {
+            count: 1 << 64,
+            offset: Some(Signed(-1)),
+        };
+        let (bits, bytes) = serialize_uper(&measurement);
+        assert_eq!(1 + 65 + 128, bits);
+        assert_eq!(measurement, deserialize_uper::<Measurement>(&bytes, bits));
+        serialize_and_deserialize_jer(
+            r#"{"count":18446744073709551616,"offset":-1}"#,
+            &measurement,
+        );
+    }
+
+    #[test]
+    fn test_values_out_of_range_are_rejected() {
+        assert_eq!(
+            Err(asn1rs::io::per::Error::WideValueNotInRange(
+                "18446744073709551617".to_string(),
+                Some(0),
+                Some(1 << 64)
+            )),
+            UperWriter::default().write(&Unsigned((1 << 64) + 1))
+        );
+        assert_eq!(
+            Err(asn1rs::io::ber::Error::WideValueNotInRange(
+                "5".to_string(),
+                Some(1 << 64),
+                None
+            )),
+            BerWriter::default().write(&Above(5))
+        );
+    }
+}
+
+#[cfg(feature = "bigint")]
+mod arbitrary_precision {
+    use crate::test_utils::*;
+
+    #[asn(sequence)]
+    #[derive(Default, Debug, Clone, PartialEq)]
+    pub struct Huge {
+        #[asn(integer(big))]
+        pub value: BigInt,
+        #[asn(optional(integer(big, min..max,...)))]
+        pub extensible: Option<BigInt>,
+    }
+
+    #[test]
+    fn test_big_integer_beyond_128_bits() {
+        let huge = Huge {
+            value: -(BigInt::from(u128::MAX) << 8_u32),
+            extensible: Some(BigInt::from(5)),
+        };
+        let (bits, bytes) = serialize_uper(&huge);
+        // the presence bit, the length and the 18 octets as well as the extension bit, the
+        // length and the single octet
+        assert_eq!(1 + 8 + 18 * 8 + 1 + 8 + 8, bits);
+        assert_eq!(huge, deserialize_uper::<Huge>(&bytes, bits));
+        serialize_and_deserialize_jer(
+            r#"{"value":-87112285931760246646623899502532662132480,"extensible":5}"#,
+            &huge,
+        );
+        assert_eq!(huge, deserialize_ber::<Huge>(&serialize_ber(&huge)));
+        assert_eq!(huge, deserialize_oer::<Huge>(&serialize_oer(&huge)));
+    }
+}
@@ -0,0 +1,147 @@
+mod test_utils;
+
+use asn1rs::model::Model;
+use asn1rs::parser::Tokenizer;
+use test_utils::*;
+
+asn_to_rust!(
+    r"ComponentsOf DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    Header ::= SEQUENCE {
+        version INTEGER (0..255),
+        station INTEGER (0..65535) OPTIONAL,
+        ...,
+        extended BOOLEAN OPTIONAL
+    }
+
+    Message ::= SEQUENCE {
+        COMPONENTS OF Header,
+        payload OCTET STRING (SIZE(0..8))
+    }
+
+    Spelled ::= SEQUENCE {
+        version INTEGER (0..255),
+        station INTEGER (0..65535) OPTIONAL,
+        payload OCTET STRING (SIZE(0..8))
+    }
+
+    Trailer ::= SEQUENCE {
+        id INTEGER (0..7),
+        COMPONENTS OF Message,
+        ...
+    }
+
+    Flags ::= SET {
+        flag BOOLEAN
+    }
+
+    Unordered ::= SET {
+        COMPONENTS OF Flags,
+        id INTEGER (0..7)
+    }
+
+    END"
+);
+
+#[test]
+fn test_root_components_are_included() {
+    let message = Message {
+        version: 1,
+        station: Some(0x1234),
+        payload: vec![0xAB],
+    };
+    let spelled = Spelled {
+        version: 1,
+        station: Some(0x1234),
+        payload: vec![0xAB],
+    };
+    // the extension marker and additions of the header are not included
+    assert_eq!(serialize_uper(&spelled), serialize_uper(&message));
+    serialize_and_deserialize_uper(
+        1 + 8 + 16 + 4 + 8,
+        &[0x80, 0x89, 0x1A, 0x0D, 0x58],
+        &message,
+    );
+    serialize_and_deserialize_ber(&serialize_ber(&spelled), &message);
+}
+
+#[test]
+fn test_included_components_with_own_extension() {
+    let trailer = Trailer {
+        id: 3,
+        version: 2,
+        station: None,
+        payload: Vec::new(),
+    };
+    let (bits, bytes) = serialize_uper(&trailer);
+    // the extension bit, the presence bit, id, version and the length of the payload
+    assert_eq!(1 + 1 + 3 + 8 + 4, bits);
+    assert_eq!(trailer, deserialize_uper::<Trailer>(&bytes, bits));
+    serialize_and_deserialize_jer(r#"{"id":3,"version":2,"payload":""}"#, &trailer);
+}
+
+#[test]
+fn test_included_components_of_set() {
+    let unordered = Unordered { flag: true, id: 5 };
+    serialize_and_deserialize_uper(1 + 3, &[0xD0], &unordered);
+    serialize_and_deserialize_der(
+        &[0x31, 0x06, 0x80, 0x01, 0xFF, 0x81, 0x01, 0x05],
+        &unordered,
+    );
+}
+
+#[test]
+fn test_components_of_imported_type() {
+    let headers = Model::try_from(
+        Tokenizer
+            .parse(
+                r"Headers DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+        Header ::= SEQUENCE {
+            version INTEGER (0..255),
+            station INTEGER (0..65535) OPTIONAL,
+            ...,
+            extended BOOLEAN OPTIONAL
+        }
+        END",
+            )
+            .unwrap(),
+    )
+    .unwrap();
+    let messages = Model::try_from(
+        Tokenizer
+            .parse(
+                r"Messages DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+        IMPORTS Header FROM Headers;
+        Message ::= SEQUENCE {
+            COMPONENTS OF Header,
+            payload OCTET STRING (SIZE(0..8))
+        }
+        END",
+            )
+            .unwrap(),
+    )
+    .unwrap();
+    let spelled = Model::try_from(
+        Tokenizer
+            .parse(
+                r"Spelled DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+        Message ::= SEQUENCE {
+            version INTEGER (0..255),
+            station INTEGER (0..65535) OPTIONAL,
+            payload OCTET STRING (SIZE(0..8))
+        }
+        END",
+            )
+            .unwrap(),
+    )
+    .unwrap();
+
+    // the root components of the imported type are included as if they were spelled out
+    assert_eq!(
+        spelled.to_rust().definitions,
+        messages
+            .to_rust_with_scope(&[&headers, &messages])
+            .definitions
+    );
+}
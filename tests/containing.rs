@@ -0,0 +1,139 @@
+// CONTAINING is not supported by the legacy UPER codegen
+#![cfg(not(feature = "legacy-uper-codegen"))]
+
+mod test_utils;
+
+use test_utils::*;
+
+asn_to_rust!(
+    r"Containing DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    Report ::= SEQUENCE {
+        id INTEGER (0..255),
+        note UTF8String OPTIONAL
+    }
+
+    Wrapped ::= OCTET STRING (CONTAINING Report)
+
+    Envelope ::= SEQUENCE {
+        report OCTET STRING (CONTAINING Report),
+        counter BIT STRING (CONTAINING INTEGER (0..255)) OPTIONAL,
+        marker OCTET STRING (CONTAINING NULL)
+    }
+
+    -- the contents are encoded with other encoding rules and are therefore opaque
+    Foreign ::= OCTET STRING (CONTAINING Report ENCODED BY { joint-iso-itu-t asn1(1) ber(1) })
+
+    END"
+);
+
+fn report() -> Report {
+    Report { id: 5, note: None }
+}
+
+#[test]
+fn test_contents_are_encoded_like_the_contained_value() {
+    // the length determinant, followed by the encoding of the Report
+    let (bits, bytes) = serialize_uper(&report());
+    assert_eq!((9, vec![0x02, 0x80]), (bits, bytes.clone()));
+    serialize_and_deserialize_uper(8 + 16, &[0x02, 0x02, 0x80], &Wrapped(report()));
+    // the integer is octet-aligned within the contents
+    serialize_and_deserialize_aper(8 + 16, &[0x02, 0x00, 0x05], &Wrapped(report()));
+    serialize_and_deserialize_ber(
+        &[0x04, 0x05, 0x30, 0x03, 0x80, 0x01, 0x05],
+        &Wrapped(report()),
+    );
+    serialize_and_deserialize_oer(&[0x02, 0x00, 0x05], &Wrapped(report()));
+}
+
+#[test]
+fn test_contents_of_fields() {
+    let envelope = Envelope {
+        report: Report {
+            id: 1,
+            note: Some("late".to_string()),
+        },
+        counter: Some(200),
+        marker: (),
+    };
+    let (bits, bytes) = serialize_uper(&envelope);
+    assert_eq!(envelope, deserialize_uper::<Envelope>(&bytes, bits));
+    let (bits, bytes) = serialize_aper(&envelope);
+    assert_eq!(envelope, deserialize_aper::<Envelope>(&bytes, bits));
+    assert_eq!(
+        envelope,
+        deserialize_ber::<Envelope>(&serialize_ber(&envelope))
+    );
+    assert_eq!(
+        envelope,
+        deserialize_der::<Envelope>(&serialize_der(&envelope))
+    );
+    assert_eq!(
+        envelope,
+        deserialize_oer::<Envelope>(&serialize_oer(&envelope))
+    );
+    assert_eq!(
+        envelope,
+        deserialize_coer::<Envelope>(&serialize_coer(&envelope))
+    );
+}
+
+#[test]
+fn test_contents_within_bit_string() {
+    let envelope = Envelope {
+        report: report(),
+        counter: Some(255),
+        marker: (),
+    };
+    serialize_and_deserialize_uper(
+        // presence bit, report, counter and the single octet of the complete encoding of NULL
+        1 + (8 + 16) + (8 + 8) + (8 + 8),
+        &[0x81, 0x01, 0x40, 0x04, 0x7F, 0x80, 0x80, 0x00],
+        &envelope,
+    );
+}
+
+#[test]
+fn test_contents_are_embedded_in_jer_and_xer() {
+    serialize_and_deserialize_jer(r#"{"id":5}"#, &Wrapped(report()));
+    serialize_and_deserialize_jer(
+        r#"{"report":{"id":5},"counter":7,"marker":null}"#,
+        &Envelope {
+            report: report(),
+            counter: Some(7),
+            marker: (),
+        },
+    );
+    serialize_and_deserialize_xer("<Wrapped><id>5</id></Wrapped>", &Wrapped(report()));
+}
+
+#[test]
+fn test_encoded_by_keeps_the_octet_string() {
+    let bytes = serialize_ber(&report());
+    serialize_and_deserialize_uper(
+        8 + 8 * bytes.len(),
+        &[&[bytes.len() as u8][..], &bytes[..]].concat(),
+        &Foreign(bytes.clone()),
+    );
+}
+
+#[test]
+#[cfg(feature = "protobuf")]
+fn test_protobuf_stores_the_contained_value() {
+    let envelope = Envelope {
+        report: report(),
+        counter: Some(7),
+        marker: (),
+    };
+    let mut buffer = Vec::<u8>::default();
+    envelope
+        .write_protobuf(&mut buffer as &mut dyn ProtobufWriter)
+        .unwrap();
+    // the report is a nested message just like a field of type Report
+    assert_eq!(&[0x0A, 0x02, 0x08, 0x05, 0x10, 0x07], &buffer[..6]);
+    assert_eq!(
+        envelope,
+        Envelope::read_protobuf(&mut &buffer[..] as &mut dyn ProtobufReader).unwrap()
+    );
+}
@@ -0,0 +1,188 @@
+mod test_utils;
+
+use test_utils::*;
+
+asn_to_rust!(
+    r"ExplicitTags DEFINITIONS EXPLICIT TAGS EXTENSIBILITY IMPLIED ::=
+    BEGIN
+
+    Time ::= CHOICE {
+        number INTEGER (0..255),
+        text UTF8String
+    }
+
+    Validity ::= SEQUENCE {
+        notBefore Time,
+        notAfter Time
+    }
+
+    Header ::= SEQUENCE {
+        version [0] INTEGER (0..2),
+        serial [1] IMPLICIT INTEGER (0..255),
+        flag [2] BOOLEAN OPTIONAL,
+        validity Validity
+    }
+
+    Either ::= CHOICE {
+        number [0] INTEGER (0..255),
+        text [1] IMPLICIT UTF8String
+    }
+
+    Tagged ::= [APPLICATION 3] INTEGER (0..255)
+
+    Color ::= ENUMERATED { red, green }
+
+    Record ::= [APPLICATION 1] SEQUENCE {
+        a INTEGER
+    }
+
+    Bag ::= [APPLICATION 2] SET {
+        a INTEGER
+    }
+
+    Level ::= [APPLICATION 3] ENUMERATED { low, high }
+
+    Pick ::= [APPLICATION 4] CHOICE {
+        number INTEGER (0..255),
+        text UTF8String
+    }
+
+    ImplicitRecord ::= [APPLICATION 5] IMPLICIT SEQUENCE {
+        a INTEGER
+    }
+
+    Assignments ::= SEQUENCE {
+        record Record,
+        level Level,
+        pick Pick
+    }
+
+    END"
+);
+
+fn validity() -> Validity {
+    Validity {
+        not_before: Time::Number(1),
+        not_after: Time::Text("a".to_string()),
+    }
+}
+
+#[test]
+fn test_untagged_components_are_not_tagged_automatically() {
+    serialize_and_deserialize_ber(
+        &[
+            0x30, 0x06, // SEQUENCE
+            0x02, 0x01, 0x01, // INTEGER notBefore
+            0x0C, 0x01, b'a', // UTF8String notAfter
+        ],
+        &validity(),
+    );
+}
+
+#[test]
+fn test_tags_are_explicit_unless_implicit() {
+    serialize_and_deserialize_ber(
+        &[
+            0x30, 0x15, // SEQUENCE
+            0xA0, 0x03, 0x02, 0x01, 0x02, // [0] version, explicit
+            0x81, 0x01, 0x05, // [1] serial, implicit
+            0xA2, 0x03, 0x01, 0x01, 0xFF, // [2] flag, explicit
+            0x30, 0x06, 0x02, 0x01, 0x01, 0x0C, 0x01, b'a', // validity
+        ],
+        &Header {
+            version: 2,
+            serial: 5,
+            flag: Some(true),
+            validity: validity(),
+        },
+    );
+    serialize_and_deserialize_ber(
+        &[
+            0x30, 0x10, // SEQUENCE
+            0xA0, 0x03, 0x02, 0x01, 0x00, // [0] version, explicit
+            0x81, 0x01, 0x05, // [1] serial, implicit
+            0x30, 0x06, 0x02, 0x01, 0x01, 0x0C, 0x01, b'a', // validity
+        ],
+        &Header {
+            version: 0,
+            serial: 5,
+            flag: None,
+            validity: validity(),
+        },
+    );
+}
+
+#[test]
+fn test_explicitly_tagged_alternatives() {
+    serialize_and_deserialize_ber(&[0xA0, 0x03, 0x02, 0x01, 0x07], &Either::Number(7));
+    serialize_and_deserialize_ber(&[0x81, 0x02, b'h', b'i'], &Either::Text("hi".to_string()));
+    // OER only encodes the outer tag of an alternative
+    serialize_and_deserialize_oer(&[0x80, 0x07], &Either::Number(7));
+}
+
+#[test]
+fn test_explicitly_tagged_type() {
+    serialize_and_deserialize_ber(&[0x63, 0x03, 0x02, 0x01, 0x09], &Tagged(9));
+    // tags are not encoded in PER
+    serialize_and_deserialize_uper(8, &[0x09], &Tagged(9));
+}
+
+#[test]
+fn test_explicitly_tagged_type_assignments() {
+    serialize_and_deserialize_ber(
+        &[
+            0x61, 0x05, // [APPLICATION 1]
+            0x30, 0x03, 0x02, 0x01, 0x05, // SEQUENCE
+        ],
+        &Record { a: 5 },
+    );
+    serialize_and_deserialize_ber(
+        &[
+            0x62, 0x05, // [APPLICATION 2]
+            0x31, 0x03, 0x02, 0x01, 0x05, // SET
+        ],
+        &Bag { a: 5 },
+    );
+    serialize_and_deserialize_ber(
+        &[
+            0x63, 0x03, // [APPLICATION 3]
+            0x0A, 0x01, 0x01, // ENUMERATED
+        ],
+        &Level::High,
+    );
+    serialize_and_deserialize_ber(
+        &[
+            0x64, 0x03, // [APPLICATION 4]
+            0x02, 0x01, 0x07, // INTEGER number
+        ],
+        &Pick::Number(7),
+    );
+}
+
+#[test]
+fn test_implicitly_tagged_type_assignment() {
+    serialize_and_deserialize_ber(&[0x65, 0x03, 0x02, 0x01, 0x05], &ImplicitRecord { a: 5 });
+}
+
+#[test]
+fn test_components_of_explicitly_tagged_types() {
+    serialize_and_deserialize_ber(
+        &[
+            0x30, 0x12, // SEQUENCE
+            0x61, 0x05, 0x30, 0x03, 0x02, 0x01, 0x05, // record
+            0x63, 0x03, 0x0A, 0x01, 0x00, // level
+            0x64, 0x04, 0x0C, 0x02, b'h', b'i', // pick
+        ],
+        &Assignments {
+            record: Record { a: 5 },
+            level: Level::Low,
+            pick: Pick::Text("hi".to_string()),
+        },
+    );
+}
+
+#[test]
+fn test_extensibility_is_implied() {
+    serialize_and_deserialize_uper(2, &[0x40], &Color::Green);
+    serialize_and_deserialize_uper(10, &[0x01, 0xC0], &Either::Number(7));
+}
@@ -0,0 +1,275 @@
+mod test_utils;
+
+use asn1rs::syn::components::Violation;
+use asn1rs::syn::io::UperReader as NewUperReader;
+use asn1rs::syn::io::UperWriter as NewUperWriter;
+use test_utils::*;
+
+asn_to_rust!(
+    r"InnerTypeConstraints DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    Position ::= SEQUENCE {
+        x INTEGER (0..1000),
+        y INTEGER (0..1000),
+        altitude INTEGER (0..10000) OPTIONAL
+    }
+
+    Report ::= SEQUENCE {
+        id INTEGER (0..255),
+        position Position OPTIONAL,
+        speed INTEGER (0..500) OPTIONAL,
+        note UTF8String OPTIONAL
+    }
+
+    Measurement ::= CHOICE {
+        speed INTEGER (0..500),
+        position Position
+    }
+
+    -- the position must be given, but the speed must not
+    PositionReport ::= Report (WITH COMPONENTS { ..., position PRESENT, speed ABSENT })
+
+    -- only a few identifiers are permitted
+    KnownReport ::= Report (WITH COMPONENTS { ..., id (1 | 3 | 5..7) })
+
+    -- the position must be on the ground
+    GroundReport ::= Report (WITH COMPONENTS {
+        ...,
+        position (WITH COMPONENTS { ..., altitude ABSENT }) PRESENT
+    })
+
+    -- all components that are not listed must be absent
+    SpeedOnlyReport ::= Report (WITH COMPONENTS { id, speed PRESENT })
+
+    SpeedMeasurement ::= Measurement (WITH COMPONENTS { speed (0..100) })
+
+    Flight ::= SEQUENCE {
+        report PositionReport,
+        measurements SEQUENCE OF SpeedMeasurement
+    }
+
+    Readings ::= SEQUENCE OF INTEGER (0..1000)
+
+    -- every reading must be a percentage
+    Percentages ::= Readings (WITH COMPONENT (0..100))
+
+    Batch ::= SEQUENCE {
+        reports SEQUENCE OF Report,
+        readings Readings
+    }
+
+    -- none of the reports may carry a note and all readings must be percentages
+    QuietBatch ::= Batch (WITH COMPONENTS {
+        ...,
+        reports (WITH COMPONENT (WITH COMPONENTS { ..., note ABSENT })),
+        readings (WITH COMPONENT (0..100))
+    })
+
+    END"
+);
+
+fn position() -> Position {
+    Position {
+        x: 10,
+        y: 20,
+        altitude: None,
+    }
+}
+
+fn report() -> Report {
+    Report {
+        id: 1,
+        position: Some(position()),
+        speed: None,
+        note: None,
+    }
+}
+
+#[test]
+fn test_valid_values_are_encoded_like_the_referenced_type() {
+    let report = PositionReport(report());
+    let (bits, bytes) = serialize_uper(&report);
+    assert_eq!(serialize_uper(&report.0), (bits, bytes.clone()));
+    assert_eq!(report, deserialize_uper::<PositionReport>(&bytes, bits));
+
+    serialize_and_deserialize_ber(&serialize_ber(&report.0), &report);
+    serialize_and_deserialize_jer(
+        r#"{"id":1,"position":{"x":10,"y":20}}"#,
+        &KnownReport(report.0.clone()),
+    );
+}
+
+#[test]
+fn test_presence_is_checked() {
+    let mut value = report();
+    value.position = None;
+    assert_eq!(
+        Err(asn1rs::io::per::Error::InvalidComponents(
+            Violation::MustBePresent("position".to_string())
+        )),
+        NewUperWriter::default().write(&PositionReport(value))
+    );
+
+    let mut value = report();
+    value.speed = Some(10);
+    assert_eq!(
+        Err(asn1rs::io::ber::Error::InvalidComponents(
+            Violation::MustBeAbsent("speed".to_string())
+        )),
+        BerWriter::default().write(&PositionReport(value))
+    );
+}
+
+#[test]
+fn test_values_are_checked() {
+    serialize_and_deserialize_oer(&serialize_oer(&report()), &KnownReport(report()));
+
+    let mut value = report();
+    value.id = 4;
+    assert_eq!(
+        Err(asn1rs::io::oer::Error::InvalidComponents(
+            Violation::ValueNotPermitted("id".to_string(), 4)
+        )),
+        OerWriter::default().write(&KnownReport(value))
+    );
+    assert_eq!(
+        Err(asn1rs::io::per::Error::InvalidComponents(
+            Violation::ValueNotPermitted("speed".to_string(), 101)
+        )),
+        AperWriter::default().write(&SpeedMeasurement(Measurement::Speed(101)))
+    );
+}
+
+#[test]
+fn test_nested_constraints_report_the_path() {
+    serialize_and_deserialize_xer(
+        "<GroundReport><id>1</id><position><x>10</x><y>20</y></position></GroundReport>",
+        &GroundReport(report()),
+    );
+
+    let mut value = report();
+    value.position.as_mut().unwrap().altitude = Some(100);
+    assert_eq!(
+        Err(asn1rs::io::xer::Error::InvalidComponents(
+            Violation::MustBeAbsent("position.altitude".to_string())
+        )),
+        XerWriter::default().write(&GroundReport(value))
+    );
+}
+
+#[test]
+fn test_full_specification_requires_unlisted_components_to_be_absent() {
+    let value = Report {
+        id: 1,
+        position: None,
+        speed: Some(10),
+        note: None,
+    };
+    serialize_and_deserialize_jer(r#"{"id":1,"speed":10}"#, &SpeedOnlyReport(value.clone()));
+
+    assert_eq!(
+        Err(asn1rs::io::jer::Error::InvalidComponents(
+            Violation::MustBeAbsent("note".to_string())
+        )),
+        JerWriter::default().write(&SpeedOnlyReport(Report {
+            note: Some("late".to_string()),
+            ..value
+        }))
+    );
+    // the alternatives of a CHOICE that are not listed must not be chosen
+    serialize_and_deserialize_uper(10, &[0x02, 0x80], &SpeedMeasurement(Measurement::Speed(10)));
+    assert_eq!(
+        Err(asn1rs::io::per::Error::InvalidComponents(
+            Violation::MustBeAbsent("position".to_string())
+        )),
+        NewUperWriter::default().write(&SpeedMeasurement(Measurement::Position(position())))
+    );
+}
+
+#[test]
+fn test_components_of_fields_are_checked() {
+    let flight = Flight {
+        report: PositionReport(report()),
+        measurements: vec![SpeedMeasurement(Measurement::Speed(50))],
+    };
+    let (bits, bytes) = serialize_uper(&flight);
+    assert_eq!(flight, deserialize_uper::<Flight>(&bytes, bits));
+
+    let mut flight = flight;
+    flight
+        .measurements
+        .push(SpeedMeasurement(Measurement::Speed(200)));
+    assert_eq!(
+        Err(asn1rs::io::per::Error::InvalidComponents(
+            Violation::ValueNotPermitted("speed".to_string(), 200)
+        )),
+        NewUperWriter::default().write(&flight)
+    );
+}
+
+#[test]
+fn test_violating_values_are_not_read() {
+    let mut value = report();
+    value.speed = Some(10);
+    let (bits, bytes) = serialize_uper(&value);
+    let mut reader = NewUperReader::from((&bytes[..], bits));
+    assert_eq!(
+        Err(asn1rs::io::per::Error::InvalidComponents(
+            Violation::MustBeAbsent("speed".to_string())
+        )),
+        reader.read::<PositionReport>()
+    );
+
+    let mut value = report();
+    value.id = 2;
+    let bytes = serialize_ber(&value);
+    assert_eq!(
+        Err(asn1rs::io::ber::Error::InvalidComponents(
+            Violation::ValueNotPermitted("id".to_string(), 2)
+        )),
+        BerReader::from(&bytes[..]).read::<KnownReport>()
+    );
+}
+
+#[test]
+fn test_elements_of_lists_are_checked() {
+    let percentages = Percentages(Readings(vec![0, 50, 100]));
+    let (bits, bytes) = serialize_uper(&percentages);
+    assert_eq!(percentages, deserialize_uper::<Percentages>(&bytes, bits));
+
+    assert_eq!(
+        Err(asn1rs::io::per::Error::InvalidComponents(
+            Violation::ValueNotPermitted("1".to_string(), 101)
+        )),
+        NewUperWriter::default().write(&Percentages(Readings(vec![0, 101])))
+    );
+}
+
+#[test]
+fn test_elements_of_list_components_are_checked() {
+    let batch = QuietBatch(Batch {
+        reports: vec![report(), report()],
+        readings: Readings(vec![10, 20, 30]),
+    });
+    let (bits, bytes) = serialize_uper(&batch);
+    assert_eq!(batch, deserialize_uper::<QuietBatch>(&bytes, bits));
+
+    let mut noisy = batch.clone();
+    noisy.0.reports[1].note = Some("late".to_string());
+    assert_eq!(
+        Err(asn1rs::io::per::Error::InvalidComponents(
+            Violation::MustBeAbsent("reports.1.note".to_string())
+        )),
+        NewUperWriter::default().write(&noisy)
+    );
+
+    let mut too_high = batch;
+    too_high.0.readings.0[2] = 1000;
+    assert_eq!(
+        Err(asn1rs::io::per::Error::InvalidComponents(
+            Violation::ValueNotPermitted("readings.2".to_string(), 1000)
+        )),
+        NewUperWriter::default().write(&too_high)
+    );
+}
@@ -0,0 +1,123 @@
+// the legacy UPER codegen does not support REAL, OBJECT IDENTIFIER and RELATIVE-OID
+#![cfg(not(feature = "legacy-uper-codegen"))]
+
+mod test_utils;
+
+use test_utils::*;
+
+asn_to_rust!(
+    r"NullRealOid DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    Nothing ::= NULL
+
+    Number ::= REAL
+
+    Oid ::= OBJECT IDENTIFIER
+
+    Relative ::= RELATIVE-OID
+
+    Measurement ::= SEQUENCE {
+        marker NULL,
+        value REAL,
+        unit OBJECT IDENTIFIER OPTIONAL
+    }
+
+    END"
+);
+
+const RSA: [u64; 4] = [1, 2, 840, 113_549];
+
+#[test]
+fn test_null_has_no_content() {
+    serialize_and_deserialize_uper(0, &[], &Nothing(()));
+    serialize_and_deserialize_ber(&[0x05, 0x00], &Nothing(()));
+    serialize_and_deserialize_oer(&[], &Nothing(()));
+    serialize_and_deserialize_jer("null", &Nothing(()));
+}
+
+#[test]
+fn test_real_binary_encoding() {
+    // base 2, exponent 0 and mantissa 1
+    serialize_and_deserialize_uper(32, &[0x03, 0x80, 0x00, 0x01], &Number(1.0));
+    serialize_and_deserialize_aper(32, &[0x03, 0x80, 0x00, 0x01], &Number(1.0));
+    serialize_and_deserialize_ber(&[0x09, 0x03, 0x80, 0x00, 0x01], &Number(1.0));
+    serialize_and_deserialize_der(&[0x09, 0x03, 0xC0, 0xFF, 0x03], &Number(-1.5));
+    serialize_and_deserialize_oer(&[0x03, 0x80, 0x00, 0x01], &Number(1.0));
+    serialize_and_deserialize_jer("-1.5", &Number(-1.5));
+}
+
+#[test]
+fn test_real_special_values() {
+    serialize_and_deserialize_ber(&[0x09, 0x00], &Number(0.0));
+    serialize_and_deserialize_ber(&[0x09, 0x01, 0x40], &Number(f64::INFINITY));
+    serialize_and_deserialize_ber(&[0x09, 0x01, 0x43], &Number(-0.0));
+    serialize_and_deserialize_jer("\"-INF\"", &Number(f64::NEG_INFINITY));
+    assert!(deserialize_jer::<Number>("\"NaN\"").0.is_nan());
+    assert!(deserialize_ber::<Number>(&[0x09, 0x01, 0x42]).0.is_nan());
+}
+
+#[test]
+fn test_der_rejects_non_normalized_real() {
+    // mantissa 2 and exponent 0 instead of mantissa 1 and exponent 1
+    let bytes = [0x09, 0x03, 0x80, 0x00, 0x02];
+    assert_eq!(
+        Err(asn1rs::io::ber::Error::InvalidReal),
+        DerReader::from(&bytes[..]).read::<Number>()
+    );
+    assert_eq!(
+        Ok(Number(2.0)),
+        BerReader::from(&bytes[..]).read::<Number>()
+    );
+}
+
+#[test]
+fn test_object_identifier() {
+    let oid = Oid(RSA.to_vec());
+    let content = [0x2A, 0x86, 0x48, 0x86, 0xF7, 0x0D];
+    serialize_and_deserialize_uper(56, &[0x06, 0x2A, 0x86, 0x48, 0x86, 0xF7, 0x0D], &oid);
+    serialize_and_deserialize_ber(&[&[0x06, 0x06][..], &content[..]].concat(), &oid);
+    serialize_and_deserialize_oer(&[&[0x06][..], &content[..]].concat(), &oid);
+    serialize_and_deserialize_jer("\"1.2.840.113549\"", &oid);
+    serialize_and_deserialize_xer("<Oid>1.2.840.113549</Oid>", &oid);
+}
+
+#[test]
+fn test_object_identifier_needs_valid_root_arcs() {
+    let mut writer = BerWriter::default();
+    assert_eq!(
+        Err(asn1rs::io::ber::Error::InvalidObjectIdentifier),
+        writer.write(&Oid(vec![3, 1]))
+    );
+    assert_eq!(
+        Err(asn1rs::io::jer::Error::InvalidObjectIdentifier),
+        JerReader::from("\"1.2.x\"").read::<Oid>()
+    );
+}
+
+#[test]
+fn test_relative_oid() {
+    let relative = Relative(vec![8571, 3, 2]);
+    serialize_and_deserialize_ber(&[0x0D, 0x04, 0xC2, 0x7B, 0x03, 0x02], &relative);
+    serialize_and_deserialize_jer("\"8571.3.2\"", &relative);
+}
+
+#[test]
+fn test_sequence() {
+    let measurement = Measurement {
+        marker: (),
+        value: 0.5,
+        unit: None,
+    };
+    // the presence flag of unit, no bits for the marker and the length and content of the value
+    serialize_and_deserialize_uper(33, &[0x01, 0xC0, 0x7F, 0x80, 0x80], &measurement);
+    serialize_and_deserialize_ber(
+        &[0x30, 0x07, 0x80, 0x00, 0x81, 0x03, 0x80, 0xFF, 0x01],
+        &measurement,
+    );
+    serialize_and_deserialize_jer(r#"{"marker":null,"value":0.5}"#, &measurement);
+    serialize_and_deserialize_xer(
+        "<Measurement><marker></marker><value>0.5</value></Measurement>",
+        &measurement,
+    );
+}
@@ -0,0 +1,211 @@
+mod test_utils;
+
+use asn1rs::syn::io::UperWriter as NewUperWriter;
+use test_utils::*;
+
+asn_to_rust!(
+    r"OpenTypes DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+      PROTOCOL-IES ::= CLASS {
+        &id          INTEGER (0..65535) UNIQUE,
+        &criticality Criticality,
+        &Value
+      } WITH SYNTAX {
+        ID &id
+        CRITICALITY &criticality
+        TYPE &Value
+      }
+
+      Criticality ::= ENUMERATED { reject, ignore, notify }
+
+      AmfName ::= UTF8String
+
+      id-AmfName INTEGER ::= 1
+
+      SetupIEs PROTOCOL-IES ::= {
+        { ID id-AmfName CRITICALITY reject TYPE AmfName } |
+        { ID 2 CRITICALITY ignore TYPE INTEGER (0..255) },
+        ...
+      }
+
+      SetupIE ::= SEQUENCE {
+        id PROTOCOL-IES.&id ({SetupIEs}),
+        criticality PROTOCOL-IES.&criticality ({SetupIEs}{@id}),
+        value PROTOCOL-IES.&Value ({SetupIEs}{@id})
+      }
+
+    END"
+);
+
+/// The same definitions as above, but with an object set that does not know the second object
+mod old {
+    use asn1rs::prelude::*;
+
+    #[asn(open_type, unknown_extensions(Unknown))]
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum SetupIEValue {
+        #[asn(utf8string, key(1))]
+        AmfName(String),
+        Unknown {
+            key: i64,
+            bytes: Vec<u8>,
+        },
+    }
+
+    #[asn(sequence)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct SetupIE {
+        #[asn(integer(0..65535))]
+        pub id: u16,
+        #[asn(integer(0..2))]
+        pub criticality: u8,
+        #[asn(open_type(SetupIEValue), key(id))]
+        pub value: SetupIEValue,
+    }
+}
+
+/// Hyphenated names of the types of class fields and objects
+mod hyphenated {
+    use crate::test_utils::*;
+
+    asn_to_rust!(
+        r"HyphenatedOpenTypes DEFINITIONS AUTOMATIC TAGS ::=
+        BEGIN
+
+          ProtocolIE-ID ::= INTEGER (0..65535)
+
+          Amf-Name ::= UTF8String
+
+          PROTOCOL-IES ::= CLASS {
+            &id ProtocolIE-ID UNIQUE,
+            &Value
+          } WITH SYNTAX {
+            ID &id
+            TYPE &Value
+          }
+
+          SetupIEs PROTOCOL-IES ::= { { ID 1 TYPE Amf-Name } }
+
+          SetupIE ::= SEQUENCE {
+            id PROTOCOL-IES.&id ({SetupIEs}),
+            value PROTOCOL-IES.&Value ({SetupIEs}{@id})
+          }
+
+        END"
+    );
+}
+
+#[test]
+fn test_open_type_with_type_reference() {
+    // 16 bits for the id, 2 bits for the criticality, 8 bits for the length of the open type
+    // and 24 bits for its content: the length and the two characters of the name
+    serialize_and_deserialize_uper(
+        50,
+        &[0x00, 0x01, 0x00, 0xC0, 0x98, 0x58, 0x80],
+        &SetupIE {
+            id: ID_AMF_NAME as u16,
+            criticality: Criticality::Reject,
+            value: SetupIEValue::AmfName(AmfName("ab".to_string())),
+        },
+    );
+}
+
+#[test]
+fn test_open_type_with_inline_type() {
+    // 16 bits for the id, 2 bits for the criticality, 8 bits for the length of the open type
+    // and 8 bits for the number
+    serialize_and_deserialize_uper(
+        34,
+        &[0x00, 0x02, 0x40, 0x7F, 0xC0],
+        &SetupIE {
+            id: 2,
+            criticality: Criticality::Ignore,
+            value: SetupIEValue::Value(255),
+        },
+    );
+}
+
+#[test]
+fn test_mismatched_key_is_rejected() {
+    assert_eq!(
+        Err(asn1rs::io::per::Error::MismatchedOpenTypeKey(3, 2)),
+        NewUperWriter::default().write(&SetupIE {
+            id: 3,
+            criticality: Criticality::Notify,
+            value: SetupIEValue::Value(0),
+        })
+    );
+}
+
+#[test]
+fn test_unknown_key_of_extensible_object_set_is_kept() {
+    // 16 bits for the id, 2 bits for the criticality, 8 bits for the length of the open type
+    // and 8 bits for its content, which is unknown to the object set
+    serialize_and_deserialize_uper(
+        34,
+        &[0x00, 0x03, 0x80, 0x40, 0x00],
+        &SetupIE {
+            id: 3,
+            criticality: Criticality::Notify,
+            value: SetupIEValue::Unknown {
+                key: 3,
+                bytes: vec![0x00],
+            },
+        },
+    );
+}
+
+#[test]
+fn test_unknown_key_is_preserved() {
+    let ie = SetupIE {
+        id: 2,
+        criticality: Criticality::Ignore,
+        value: SetupIEValue::Value(42),
+    };
+    let (bits, data) = serialize_uper(&ie);
+    let old = deserialize_uper::<old::SetupIE>(&data[..], bits);
+
+    assert_eq!(
+        old::SetupIEValue::Unknown {
+            key: 2,
+            bytes: vec![42]
+        },
+        old.value
+    );
+    assert_eq!((bits, data.clone()), serialize_uper(&old));
+    assert_eq!(ie, deserialize_uper::<SetupIE>(&data[..], bits));
+}
+
+#[test]
+fn test_unknown_key_of_extensible_object_set_is_kept_in_other_encodings() {
+    let ie = SetupIE {
+        id: 3,
+        criticality: Criticality::Notify,
+        value: SetupIEValue::Unknown {
+            key: 3,
+            // the encoding of the INTEGER 5 in BER
+            bytes: vec![0x02, 0x01, 0x05],
+        },
+    };
+    let (bits, data) = serialize_aper(&ie);
+    assert_eq!(ie, deserialize_aper::<SetupIE>(&data[..], bits));
+    assert_eq!(ie, deserialize_ber::<SetupIE>(&serialize_ber(&ie)));
+    assert_eq!(ie, deserialize_der::<SetupIE>(&serialize_der(&ie)));
+    assert_eq!(ie, deserialize_oer::<SetupIE>(&serialize_oer(&ie)));
+    assert_eq!(ie, deserialize_coer::<SetupIE>(&serialize_coer(&ie)));
+}
+
+#[test]
+fn test_open_type_with_hyphenated_type_names() {
+    // 16 bits for the id, 8 bits for the length of the open type and 24 bits for its content:
+    // the length and the two characters of the name
+    serialize_and_deserialize_uper(
+        48,
+        &[0x00, 0x01, 0x03, 0x02, 0x61, 0x62],
+        &hyphenated::SetupIE {
+            id: hyphenated::ProtocolIEID(1),
+            value: hyphenated::SetupIEValue::AmfName(hyphenated::AmfName("ab".to_string())),
+        },
+    );
+}
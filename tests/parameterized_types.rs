@@ -0,0 +1,43 @@
+mod test_utils;
+
+use asn1rs::syn::io::UperWriter as NewUperWriter;
+use test_utils::*;
+
+asn_to_rust!(
+    r#"ParameterizedTypes DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+      Container{ItemType, INTEGER : maxItems} ::= SEQUENCE (SIZE(1..maxItems)) OF ItemType
+
+      Numbers ::= Container{INTEGER (0..255), 3}
+
+      Message ::= SEQUENCE {
+        numbers Container{BOOLEAN, 2}
+      }
+
+    END"#
+);
+
+#[test]
+fn test_parameterized_type_is_expanded() {
+    // 2 bits for the length (1..3), 8 bits for each number (0..255)
+    serialize_and_deserialize_uper(18, &[0x40, 0x7F, 0xC0], &Numbers(vec![1, 255]));
+}
+
+#[test]
+fn test_inline_parameterized_type_is_expanded() {
+    // 1 bit for the length (1..2), 1 bit for each boolean
+    serialize_and_deserialize_uper(
+        3,
+        &[0xA0],
+        &Message {
+            numbers: vec![false, true],
+        },
+    );
+}
+
+#[test]
+fn test_uper_rejects_too_many_numbers() {
+    let mut writer = NewUperWriter::default();
+    assert!(writer.write(&Numbers(vec![0; 4])).is_err());
+}
@@ -0,0 +1,67 @@
+mod test_utils;
+
+use asn1rs::model::Charset;
+use asn1rs::syn::io::UperWriter as NewUperWriter;
+use test_utils::*;
+
+asn_to_rust!(
+    r#"PermittedAlphabet DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    Hex ::= IA5String (FROM("0".."9" | "A".."F"))
+
+    Pin ::= NumericString (SIZE(4) ^ FROM("0".."9"))
+
+    Code ::= PrintableString (SIZE(1..3)) (FROM("ABC"))
+
+    Dots ::= IA5String (FROM("."))
+
+    Loose ::= IA5String (FROM("a".."z", ...))
+
+    END"#
+);
+
+#[test]
+fn test_reindexed_alphabet() {
+    // 16 characters in 4 bits each, indexed because 'F' does not fit into 4 bits
+    serialize_and_deserialize_uper(16, &[0x02, 0x1F], &Hex("1F".to_string()));
+    serialize_and_deserialize_aper(16, &[0x02, 0x1F], &Hex("1F".to_string()));
+}
+
+#[test]
+fn test_permitted_alphabet_with_fixed_size() {
+    serialize_and_deserialize_uper(16, &[0x12, 0x34], &Pin("1234".to_string()));
+}
+
+#[test]
+fn test_permitted_alphabet_from_string() {
+    // 2 bits for the length and 2 bits for each of the 3 characters
+    serialize_and_deserialize_uper(8, &[0xA1], &Code("CAB".to_string()));
+}
+
+#[test]
+fn test_permitted_alphabet_with_single_character() {
+    // only the length is encoded
+    serialize_and_deserialize_uper(8, &[0x03], &Dots("...".to_string()));
+    serialize_and_deserialize_aper(8, &[0x03], &Dots("...".to_string()));
+}
+
+#[test]
+fn test_extensible_permitted_alphabet_is_not_per_visible() {
+    serialize_and_deserialize_uper(15, &[0x01, 0xC2], &Loose("a".to_string()));
+}
+
+#[test]
+fn test_characters_outside_of_permitted_alphabet_are_rejected() {
+    let mut writer = NewUperWriter::default();
+    assert_eq!(
+        Err(asn1rs::io::per::Error::InvalidIa5String),
+        writer.write(&Hex("1f".to_string()))
+    );
+    assert_eq!(
+        Err(asn1rs::io::per::Error::InvalidCharacterString(
+            Charset::Numeric
+        )),
+        writer.write(&Pin("12 4".to_string()))
+    );
+}
@@ -14,7 +14,7 @@
 //!                          V                             |
 //!                      Rust-Model   <--- proc-macro  <---+                                                     
 
-use asn1rs::model::{Definition, Model, Rust};
+use asn1rs::model::{Definition, Model, Rust, TagDefault};
 use asn1rs::parser::Tokenizer;
 use asn1rs_model::gen::RustCodeGenerator;
 use codegen::Scope;
@@ -111,6 +111,39 @@ END"#,
     )
 }
 
+#[test]
+fn test_extensible_sequence_with_extension_addition_groups() {
+    parse_asn_map_to_rust_map_to_stringify_with_proc_macro_annotation_re_parse_check_equal(
+        r#"BasicSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+  MyType ::= [5] SEQUENCE {
+    abc UTF8String,
+    ...,
+    [[ 2: def INTEGER, ghi UTF8String OPTIONAL ]],
+    [[ jkl BOOLEAN OPTIONAL ]]
+  }
+  
+END"#,
+    )
+}
+
+#[test]
+fn test_sequence_with_default_values() {
+    parse_asn_map_to_rust_map_to_stringify_with_proc_macro_annotation_re_parse_check_equal(
+        r#"BasicSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+  MyType ::= [5] SEQUENCE {
+    abc INTEGER (-10..10) DEFAULT -5,
+    def BOOLEAN DEFAULT FALSE,
+    ghi UTF8String DEFAULT "a ""b""",
+    jkl BIT STRING DEFAULT '01'B,
+    mno OCTET STRING DEFAULT 'FF'H
+  }
+  
+END"#,
+    )
+}
+
 #[test]
 fn test_standard_sequence_of() {
     parse_asn_map_to_rust_map_to_stringify_with_proc_macro_annotation_re_parse_check_equal(
@@ -154,14 +187,348 @@ END"#,
     )
 }
 
+#[test]
+fn test_extensible_types_with_unknown_extensions() {
+    parse_asn_map_to_rust_with_unknown_extensions_map_to_stringify_with_proc_macro_annotation_re_parse_check_equal(
+        r#"BasicSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+  MyEnum ::= [5] ENUMERATED {
+    abc,
+    ...,
+    def
+  }
+
+  MyChoice ::= [PRIVATE 1] CHOICE {
+    abc UTF8String,
+    ...
+  }
+
+  MySequence ::= [5] SEQUENCE {
+    abc UTF8String,
+    ...,
+    def INTEGER
+  }
+
+  MyStandardSequence ::= [5] SEQUENCE {
+    abc UTF8String
+  }
+  
+END"#,
+    )
+}
+
+#[test]
+fn test_open_types() {
+    parse_asn_map_to_rust_with_unknown_extensions_map_to_stringify_with_proc_macro_annotation_re_parse_check_equal(
+        r#"BasicSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+  MY-CLASS ::= CLASS {
+    &id INTEGER (0..255) UNIQUE,
+    &Value
+  }
+
+  MyName ::= UTF8String
+
+  MyObjects MY-CLASS ::= {
+    { &id 1, &Value MyName } |
+    { &id 2, &Value INTEGER (0..255) },
+    ...
+  }
+
+  MySequence ::= SEQUENCE {
+    id MY-CLASS.&id ({MyObjects}),
+    value MY-CLASS.&Value ({MyObjects}{@id})
+  }
+  
+END"#,
+    )
+}
+
+#[test]
+fn test_restricted_character_strings() {
+    parse_asn_map_to_rust_map_to_stringify_with_proc_macro_annotation_re_parse_check_equal(
+        r#"BasicSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+  MySequence ::= SEQUENCE {
+    numeric NumericString (SIZE(4)),
+    printable PrintableString,
+    visible VisibleString (SIZE(1..8,...)),
+    bmp BMPString,
+    universal UniversalString
+  }
+  
+END"#,
+    )
+}
+
+#[test]
+fn test_permitted_alphabets() {
+    parse_asn_map_to_rust_map_to_stringify_with_proc_macro_annotation_re_parse_check_equal(
+        r#"BasicSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+  MySequence ::= SEQUENCE {
+    hex IA5String (FROM("0".."9" | "A".."F")),
+    pin NumericString (SIZE(4) ^ FROM("0".."9")),
+    code PrintableString (SIZE(1..3,...)) (FROM("ABC")),
+    quote VisibleString (FROM("""\'"))
+  }
+  
+END"#,
+    )
+}
+
+#[test]
+fn test_null_real_and_object_identifiers() {
+    parse_asn_map_to_rust_map_to_stringify_with_proc_macro_annotation_re_parse_check_equal(
+        r#"BasicSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+  MySequence ::= SEQUENCE {
+    marker NULL,
+    value REAL,
+    unit OBJECT IDENTIFIER OPTIONAL,
+    path RELATIVE-OID
+  }
+  
+END"#,
+    )
+}
+
+#[test]
+fn test_time_types() {
+    parse_asn_map_to_rust_map_to_stringify_with_proc_macro_annotation_re_parse_check_equal(
+        r#"BasicSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+  MySequence ::= SEQUENCE {
+    utc UTCTime,
+    generalized GeneralizedTime,
+    day DATE OPTIONAL,
+    clock TIME-OF-DAY,
+    moment DATE-TIME,
+    period DURATION
+  }
+
+END"#,
+    )
+}
+
+#[test]
+fn test_integers_exceeding_64_bits() {
+    parse_asn_map_to_rust_map_to_stringify_with_proc_macro_annotation_re_parse_check_equal(
+        r#"BasicSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+  MySequence ::= SEQUENCE {
+    unsigned INTEGER (0..18446744073709551616),
+    signed INTEGER (-170141183460469231731687303715884105728..0, ...),
+    semi INTEGER (18446744073709551616..MAX),
+    negative INTEGER (-5..5)
+  }
+
+END"#,
+    )
+}
+
+#[test]
+fn test_big_integers() {
+    parse_asn_map_to_rust_with_big_integers_map_to_stringify_with_proc_macro_annotation_re_parse_check_equal(
+        r#"BasicSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+  MySequence ::= SEQUENCE {
+    big INTEGER,
+    extensible INTEGER (MIN..MAX, ...),
+    optional INTEGER OPTIONAL,
+    list SEQUENCE OF INTEGER,
+    small INTEGER (0..255)
+  }
+
+  MyBig ::= INTEGER
+
+END"#,
+    )
+}
+
+#[test]
+fn test_integer_set_arithmetic() {
+    parse_asn_map_to_rust_map_to_stringify_with_proc_macro_annotation_re_parse_check_equal(
+        r#"BasicSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+  MySequence ::= SEQUENCE {
+    sparse INTEGER (1 | 3 | 5..10),
+    except INTEGER (-5..5 EXCEPT 0),
+    all INTEGER (ALL EXCEPT (1 | 2)),
+    overlap INTEGER (0..100 ^ 50..MAX, ...),
+    nested INTEGER ((1..10 EXCEPT 5) | 20),
+    wide INTEGER (0 | 18446744073709551616) OPTIONAL,
+    digest OCTET STRING (SIZE(4) | SIZE(8))
+  }
+
+  MySparse ::= INTEGER (1 | 3, ...)
+
+END"#,
+    )
+}
+
+#[test]
+fn test_inner_type_constraints() {
+    parse_asn_map_to_rust_map_to_stringify_with_proc_macro_annotation_re_parse_check_equal(
+        r#"BasicSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+  Position ::= SEQUENCE {
+    x INTEGER,
+    altitude INTEGER OPTIONAL
+  }
+
+  Report ::= SEQUENCE {
+    id INTEGER (0..255),
+    position Position OPTIONAL,
+    speed-limit INTEGER OPTIONAL
+  }
+
+  Located ::= Report (WITH COMPONENTS { ..., position PRESENT, speed-limit ABSENT })
+
+  Ground ::= Report (WITH COMPONENTS {
+    id (1 | 3..MAX),
+    position (WITH COMPONENTS { ..., altitude ABSENT }) OPTIONAL
+  })
+
+  Flight ::= SEQUENCE {
+    report Located,
+    ground Ground OPTIONAL
+  }
+
+END"#,
+    )
+}
+
+#[test]
+fn test_contents_constraints() {
+    parse_asn_map_to_rust_map_to_stringify_with_proc_macro_annotation_re_parse_check_equal(
+        r#"BasicSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+  Report ::= SEQUENCE {
+    id INTEGER (0..255)
+  }
+
+  Wrapped ::= OCTET STRING (CONTAINING Report)
+
+  Envelope ::= SEQUENCE {
+    report OCTET STRING (CONTAINING Report),
+    counter BIT STRING (CONTAINING INTEGER (0..255)) OPTIONAL,
+    reports OCTET STRING (CONTAINING SEQUENCE OF Report)
+  }
+
+END"#,
+    )
+}
+
+#[test]
+fn test_components_of() {
+    parse_asn_map_to_rust_map_to_stringify_with_proc_macro_annotation_re_parse_check_equal(
+        r#"BasicSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+  Header ::= SEQUENCE {
+    version INTEGER (0..255),
+    station INTEGER (0..4294967295) OPTIONAL
+  }
+
+  Message ::= SEQUENCE {
+    COMPONENTS OF Header,
+    payload OCTET STRING,
+    ...
+  }
+
+END"#,
+    )
+}
+
+#[test]
+fn test_explicit_tags() {
+    parse_asn_map_to_rust_map_to_stringify_with_proc_macro_annotation_re_parse_check_equal(
+        r#"BasicSchema DEFINITIONS EXPLICIT TAGS ::= BEGIN
+
+  Time ::= [PRIVATE 1] CHOICE {
+    number INTEGER (0..255),
+    text [1] IMPLICIT UTF8String
+  }
+
+  Header ::= SEQUENCE {
+    version [0] INTEGER (0..2),
+    serial [1] IMPLICIT INTEGER (0..255),
+    time [2] Time OPTIONAL,
+    flag BOOLEAN
+  }
+
+  Tagged ::= [APPLICATION 3] INTEGER (0..255)
+
+  Record ::= [APPLICATION 4] SEQUENCE {
+    a INTEGER
+  }
+
+  Level ::= [APPLICATION 5] ENUMERATED { low, high }
+
+  ImplicitRecord ::= [APPLICATION 6] IMPLICIT SET {
+    a INTEGER
+  }
+
+END"#,
+    )
+}
+
+#[test]
+fn test_recursive_types() {
+    parse_asn_map_to_rust_map_to_stringify_with_proc_macro_annotation_re_parse_check_equal(
+        r#"BasicSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+  Node ::= SEQUENCE {
+    value INTEGER (0..255),
+    children SEQUENCE OF Node,
+    next Node OPTIONAL
+  }
+
+  Expression ::= [PRIVATE 1] CHOICE {
+    number INTEGER (0..255),
+    negation Expression
+  }
+
+END"#,
+    )
+}
+
 fn parse_asn_map_to_rust_map_to_stringify_with_proc_macro_annotation_re_parse_check_equal(
     asn: &str,
 ) {
-    let tokens = Tokenizer::default().parse(asn);
+    parse_asn_map_to_rust_map_to_stringify_with_proc_macro_annotation_re_parse_check_equal_with(
+        asn,
+        Definition::clone,
+    )
+}
+
+fn parse_asn_map_to_rust_with_unknown_extensions_map_to_stringify_with_proc_macro_annotation_re_parse_check_equal(
+    asn: &str,
+) {
+    parse_asn_map_to_rust_map_to_stringify_with_proc_macro_annotation_re_parse_check_equal_with(
+        asn,
+        RustCodeGenerator::with_unknown_extensions,
+    )
+}
+
+fn parse_asn_map_to_rust_with_big_integers_map_to_stringify_with_proc_macro_annotation_re_parse_check_equal(
+    asn: &str,
+) {
+    parse_asn_map_to_rust_map_to_stringify_with_proc_macro_annotation_re_parse_check_equal_with(
+        asn,
+        Definition::with_big_integers,
+    )
+}
+
+fn parse_asn_map_to_rust_map_to_stringify_with_proc_macro_annotation_re_parse_check_equal_with(
+    asn: &str,
+    map: impl Fn(&Definition<Rust>) -> Definition<Rust>,
+) {
+    let tokens = Tokenizer::default().parse(asn).unwrap();
     let asn_model = Model::try_from(tokens).unwrap();
     let rust_model = asn_model.to_rust();
 
-    for definition in rust_model.definitions {
+    for definition in rust_model.definitions.iter().map(map) {
         let stringified = generate_rust_code_with_proc_macro_attributes(&definition);
         let mut lines = stringified.lines().map(str::trim).filter(|s| !s.is_empty());
 
@@ -186,8 +553,14 @@ fn parse_asn_map_to_rust_map_to_stringify_with_proc_macro_annotation_re_parse_ch
         let re_parsed_model = Model {
             name: rust_model.name.clone(),
             oid: None,
+            tag_default: TagDefault::Automatic,
+            extensibility_implied: false,
             imports: rust_model.imports.clone(),
             definitions: vec![re_parsed],
+            value_references: Vec::default(),
+            classes: Vec::default(),
+            objects: Vec::default(),
+            object_sets: Vec::default(),
         };
 
         assert_eq!(vec![definition], re_parsed_model.to_rust().definitions);
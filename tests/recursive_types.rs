@@ -0,0 +1,99 @@
+mod test_utils;
+
+use test_utils::*;
+
+asn_to_rust!(
+    r"RecursiveTypes DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    Node ::= SEQUENCE {
+        value INTEGER (0..255),
+        children SEQUENCE OF Node,
+        next Node OPTIONAL
+    }
+
+    Expression ::= CHOICE {
+        number INTEGER (0..255),
+        negation Expression,
+        sum Sum
+    }
+
+    Sum ::= SEQUENCE {
+        left Expression,
+        right Expression
+    }
+
+    Chain ::= SEQUENCE {
+        link Link OPTIONAL
+    }
+
+    Link ::= Chain
+
+    END"
+);
+
+fn leaf(value: u8) -> Node {
+    Node {
+        value,
+        children: Vec::default(),
+        next: None,
+    }
+}
+
+#[test]
+fn test_self_reference_is_boxed() {
+    let node = Node {
+        value: 1,
+        children: Vec::default(),
+        next: Some(Box::new(leaf(2))),
+    };
+    serialize_and_deserialize_uper(34, &[0x80, 0x80, 0x00, 0x80, 0x00], &node);
+    serialize_and_deserialize_ber(
+        &[
+            0x30, 0x0C, // SEQUENCE
+            0x80, 0x01, 0x01, // value
+            0xA1, 0x00, // children
+            0xA2, 0x05, 0x80, 0x01, 0x02, 0xA1, 0x00, // next
+        ],
+        &node,
+    );
+}
+
+#[test]
+fn test_elements_of_sequence_of_are_not_boxed() {
+    serialize_and_deserialize_uper(
+        34,
+        &[0x00, 0x80, 0x80, 0xC0, 0x00],
+        &Node {
+            value: 1,
+            children: vec![leaf(3)],
+            next: None,
+        },
+    );
+}
+
+#[test]
+fn test_indirect_recursion_is_boxed() {
+    let expression = Expression::Sum(Sum {
+        left: Box::new(Expression::Number(1)),
+        right: Box::new(Expression::Negation(Box::new(Expression::Number(2)))),
+    });
+    serialize_and_deserialize_uper(24, &[0x80, 0x14, 0x02], &expression);
+    serialize_and_deserialize_ber(
+        &[
+            0xA2, 0x0C, // sum
+            0xA0, 0x03, 0x80, 0x01, 0x01, // left
+            0xA1, 0x05, 0xA1, 0x03, 0x80, 0x01, 0x02, // right
+        ],
+        &expression,
+    );
+}
+
+#[test]
+fn test_recursion_through_alias_is_boxed() {
+    let chain = Chain {
+        link: Some(Link(Box::new(Chain { link: None }))),
+    };
+    serialize_and_deserialize_uper(2, &[0x80], &chain);
+    serialize_and_deserialize_ber(&[0x30, 0x02, 0xA0, 0x00], &chain);
+}
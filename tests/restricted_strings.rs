@@ -0,0 +1,127 @@
+mod test_utils;
+
+use asn1rs::model::Charset;
+use asn1rs::syn::io::UperReader as NewUperReader;
+use asn1rs::syn::io::UperWriter as NewUperWriter;
+use test_utils::*;
+
+asn_to_rust!(
+    r"RestrictedStrings DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    Numeric ::= NumericString (SIZE(3))
+
+    Printable ::= PrintableString
+
+    Visible ::= VisibleString (SIZE(1..4,...))
+
+    Bmp ::= BMPString
+
+    Universal ::= UniversalString
+
+    Strings ::= SEQUENCE {
+        numeric NumericString,
+        printable PrintableString (SIZE(2)),
+        visible ISO646String
+    }
+
+    END"
+);
+
+#[test]
+fn test_numeric_string_uses_4_bits_per_character() {
+    // the characters are indexed within the alphabet of space and the digits 0 to 9
+    serialize_and_deserialize_uper(12, &[0x23, 0x40], &Numeric("123".to_string()));
+    serialize_and_deserialize_aper(12, &[0x23, 0x40], &Numeric("123".to_string()));
+}
+
+#[test]
+fn test_printable_string_uses_7_bits_per_character() {
+    serialize_and_deserialize_uper(22, &[0x02, 0x91, 0xA4], &Printable("Hi".to_string()));
+    serialize_and_deserialize_aper(24, &[0x02, 0x48, 0x69], &Printable("Hi".to_string()));
+}
+
+#[test]
+fn test_visible_string_extensible_size() {
+    serialize_and_deserialize_uper(10, &[0x08, 0x40], &Visible("!".to_string()));
+    serialize_and_deserialize_uper(
+        44,
+        &[0x82, 0xC8, 0xCB, 0xB3, 0x66, 0xF0],
+        &Visible("Hello".to_string()),
+    );
+}
+
+#[test]
+fn test_bmp_string_uses_16_bits_per_character() {
+    serialize_and_deserialize_uper(24, &[0x01, 0x20, 0xAC], &Bmp("€".to_string()));
+    serialize_and_deserialize_ber(&[0x1E, 0x02, 0x20, 0xAC], &Bmp("€".to_string()));
+    serialize_and_deserialize_oer(&[0x02, 0x20, 0xAC], &Bmp("€".to_string()));
+}
+
+#[test]
+fn test_universal_string_uses_32_bits_per_character() {
+    let smiley = Universal("😀".to_string());
+    serialize_and_deserialize_uper(40, &[0x01, 0x00, 0x01, 0xF6, 0x00], &smiley);
+    serialize_and_deserialize_ber(&[0x1C, 0x04, 0x00, 0x01, 0xF6, 0x00], &smiley);
+    serialize_and_deserialize_jer("\"😀\"", &smiley);
+}
+
+#[test]
+fn test_octet_based_encodings() {
+    let numeric = Numeric("123".to_string());
+    serialize_and_deserialize_ber(&[0x12, 0x03, 0x31, 0x32, 0x33], &numeric);
+    serialize_and_deserialize_oer(&[0x31, 0x32, 0x33], &numeric);
+    serialize_and_deserialize_jer("\"123\"", &numeric);
+}
+
+#[test]
+fn test_sequence_with_restricted_strings() {
+    // 8 bits length and 4 bits for the digit, 14 bits for the fixed size printable string and 8
+    // bits length and 7 bits for the visible character
+    serialize_and_deserialize_uper(
+        41,
+        &[0x01, 0xAA, 0xDE, 0x00, 0x7F, 0x00],
+        &Strings {
+            numeric: "9".to_string(),
+            printable: "Vx".to_string(),
+            visible: "~".to_string(),
+        },
+    );
+}
+
+#[test]
+fn test_invalid_characters_are_rejected() {
+    let mut writer = NewUperWriter::default();
+    assert_eq!(
+        Err(asn1rs::io::per::Error::InvalidCharacterString(
+            Charset::Numeric
+        )),
+        writer.write(&Numeric("12a".to_string()))
+    );
+    assert_eq!(
+        Err(asn1rs::io::per::Error::InvalidCharacterString(
+            Charset::Printable
+        )),
+        writer.write(&Printable("a*b".to_string()))
+    );
+
+    let mut writer = BerWriter::default();
+    assert_eq!(
+        Err(asn1rs::io::ber::Error::InvalidCharacterString(
+            Charset::Visible
+        )),
+        writer.write(&Visible("\n".to_string()))
+    );
+}
+
+#[test]
+fn test_invalid_index_is_rejected() {
+    // the index 15 is not part of the alphabet of 11 characters
+    let mut reader = NewUperReader::from((&[0xFF, 0xF0][..], 12));
+    assert_eq!(
+        Err(asn1rs::io::per::Error::InvalidCharacterString(
+            Charset::Numeric
+        )),
+        reader.read::<Numeric>()
+    );
+}
@@ -0,0 +1,149 @@
+// the time types are not supported by the legacy UPER codegen
+#![cfg(not(feature = "legacy-uper-codegen"))]
+
+mod test_utils;
+
+use asn1rs::model::TimeType;
+use test_utils::*;
+
+asn_to_rust!(
+    r"TimeTypes DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    Utc ::= UTCTime
+
+    Generalized ::= GeneralizedTime
+
+    Day ::= DATE
+
+    Clock ::= TIME-OF-DAY
+
+    Moment ::= DATE-TIME
+
+    Period ::= DURATION
+
+    Event ::= SEQUENCE {
+        at GeneralizedTime,
+        on DATE OPTIONAL,
+        lasting DURATION
+    }
+
+    END"
+);
+
+#[test]
+fn test_utc_time_as_characters() {
+    let utc = Utc("9912312359Z".to_string());
+    serialize_and_deserialize_ber(&[&[0x17, 0x0B][..], b"9912312359Z"].concat(), &utc);
+    serialize_and_deserialize_oer(&[&[0x0B][..], b"9912312359Z"].concat(), &utc);
+    serialize_and_deserialize_jer("\"9912312359Z\"", &utc);
+    serialize_and_deserialize_xer("<Utc>9912312359Z</Utc>", &utc);
+}
+
+#[test]
+fn test_generalized_time_as_characters() {
+    let generalized = Generalized("20200229235959.5Z".to_string());
+    serialize_and_deserialize_ber(
+        &[&[0x18, 0x11][..], b"20200229235959.5Z"].concat(),
+        &generalized,
+    );
+    serialize_and_deserialize_jer("\"20200229235959.5Z\"", &generalized);
+}
+
+#[test]
+fn test_date_in_per() {
+    // near-past year 1999 - 1749 in 8 bits, month 12 - 1 in 4 bits and day 31 - 1 in 5 bits
+    let day = Day("1999-12-31".to_string());
+    serialize_and_deserialize_uper(19, &[0xBE, 0xAF, 0xC0], &day);
+    serialize_and_deserialize_ber(&[&[0x1F, 0x1F, 0x0A][..], b"1999-12-31"].concat(), &day);
+    serialize_and_deserialize_jer("\"1999-12-31\"", &day);
+}
+
+#[test]
+fn test_time_of_day_and_date_time_in_per() {
+    // hours in 5 bits, minutes and seconds in 6 bits each
+    serialize_and_deserialize_uper(17, &[0x63, 0xC0, 0x00], &Clock("12:30:00".to_string()));
+    serialize_and_deserialize_uper(
+        36,
+        &[0xBE, 0xAF, 0xD7, 0xEF, 0xB0],
+        &Moment("1999-12-31T23:59:59".to_string()),
+    );
+    serialize_and_deserialize_xer(
+        "<Moment>1999-12-31T23:59:59</Moment>",
+        &Moment("1999-12-31T23:59:59".to_string()),
+    );
+}
+
+#[test]
+fn test_duration_in_per() {
+    // presence flags of days and hours, followed by the length and value of both
+    let period = Period("P1DT12H".to_string());
+    serialize_and_deserialize_uper(40, &[0x18, 0x01, 0x01, 0x01, 0x0C], &period);
+    serialize_and_deserialize_ber(&[&[0x1F, 0x22, 0x07][..], b"P1DT12H"].concat(), &period);
+}
+
+#[test]
+fn test_sequence() {
+    let event = Event {
+        at: "20200229235959.5Z".to_string(),
+        on: None,
+        lasting: "PT1M".to_string(),
+    };
+    serialize_and_deserialize_jer(r#"{"at":"20200229235959.5Z","lasting":"PT1M"}"#, &event);
+    serialize_and_deserialize_xer(
+        "<Event><at>20200229235959.5Z</at><lasting>PT1M</lasting></Event>",
+        &event,
+    );
+    let (bits, bytes) = serialize_uper(&event);
+    assert_eq!(event, deserialize_uper::<Event>(&bytes, bits));
+}
+
+#[test]
+fn test_invalid_values_are_rejected() {
+    assert_eq!(
+        Err(asn1rs::io::per::Error::InvalidTime(TimeType::TimeOfDay)),
+        UperWriter::default().write(&Clock("25:00:00".to_string()))
+    );
+    assert_eq!(
+        Err(asn1rs::io::ber::Error::InvalidTime(TimeType::UtcTime)),
+        BerWriter::default().write(&Utc("99123123".to_string()))
+    );
+    assert_eq!(
+        Err(asn1rs::io::jer::Error::InvalidTime(TimeType::Date)),
+        JerReader::from("\"2021-02-29\"").read::<Day>()
+    );
+    assert_eq!(
+        Err(asn1rs::io::per::Error::InvalidTime(TimeType::Duration)),
+        UperWriter::default().write(&Period("P1W2D".to_string()))
+    );
+}
+
+#[test]
+#[cfg(feature = "protobuf")]
+fn test_protobuf_as_strings() {
+    let event = Event {
+        at: "20200229235959.5Z".to_string(),
+        on: Some("2020-02-29".to_string()),
+        lasting: "PT1H".to_string(),
+    };
+    let mut buffer = Vec::default();
+    event
+        .write_protobuf(&mut buffer as &mut dyn ProtobufWriter)
+        .unwrap();
+    assert_eq!(
+        &[
+            &[0x0A, 0x11][..],
+            b"20200229235959.5Z",
+            &[0x12, 0x0A][..],
+            b"2020-02-29",
+            &[0x1A, 0x04][..],
+            b"PT1H"
+        ]
+        .concat()[..],
+        &buffer[..]
+    );
+    assert_eq!(
+        event,
+        Event::read_protobuf(&mut &buffer[..] as &mut dyn ProtobufReader).unwrap()
+    );
+}
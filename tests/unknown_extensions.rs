@@ -0,0 +1,153 @@
+mod test_utils;
+
+use test_utils::*;
+
+asn_to_rust!(
+    r"UnknownExtensions DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+      Kind ::= ENUMERATED {
+        red,
+        green,
+        ...,
+        blue
+      }
+
+      Detail ::= CHOICE {
+        number INTEGER (0..255),
+        ...,
+        text UTF8String
+      }
+
+      Message ::= SEQUENCE {
+        id INTEGER (0..255),
+        ...,
+        kind Kind OPTIONAL,
+        note UTF8String OPTIONAL,
+        count INTEGER (0..255) OPTIONAL
+      }
+
+      Envelope ::= SEQUENCE {
+        message Message,
+        detail Detail
+      }
+
+    END"
+);
+
+/// The same definitions as above, but without the extension additions of a newer schema
+mod old {
+    use asn1rs::prelude::*;
+
+    #[asn(enumerated, extensible_after(Green), unknown_extensions(Unknown))]
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    pub enum Kind {
+        Red,
+        Green,
+        Unknown { index: u64 },
+    }
+
+    #[asn(choice, extensible_after(Number), unknown_extensions(Unknown))]
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum Detail {
+        #[asn(integer(0..255))]
+        Number(u8),
+        Unknown {
+            index: u64,
+            bytes: Vec<u8>,
+        },
+    }
+
+    #[asn(sequence, extensible_after(id), unknown_extensions(unknown_extensions))]
+    #[derive(Debug, Default, Clone, PartialEq)]
+    pub struct Message {
+        #[asn(integer(0..255))]
+        pub id: u8,
+        #[asn(optional(complex(Kind, tag(UNIVERSAL(10)))))]
+        pub kind: Option<Kind>,
+        pub unknown_extensions: Vec<Option<Vec<u8>>>,
+    }
+
+    #[asn(sequence, extensible_after(id), unknown_extensions(unknown_extensions))]
+    #[derive(Debug, Default, Clone, PartialEq)]
+    pub struct MessageRoot {
+        #[asn(integer(0..255))]
+        pub id: u8,
+        pub unknown_extensions: Vec<Option<Vec<u8>>>,
+    }
+
+    #[asn(sequence)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Envelope {
+        #[asn(complex(Message, tag(UNIVERSAL(16))))]
+        pub message: Message,
+        #[asn(complex(Detail, tag(UNIVERSAL(16))))]
+        pub detail: Detail,
+    }
+}
+
+fn envelope() -> Envelope {
+    Envelope {
+        message: Message {
+            id: 7,
+            kind: Some(Kind::Blue),
+            note: Some("hi".to_string()),
+            count: None,
+        },
+        detail: Detail::Text("abc".to_string()),
+    }
+}
+
+#[test]
+fn test_unknown_extensions_are_preserved() {
+    let (bits, data) = serialize_uper(&envelope());
+    let old = deserialize_uper::<old::Envelope>(&data[..], bits);
+
+    assert_eq!(7, old.message.id);
+    assert_eq!(Some(old::Kind::Unknown { index: 2 }), old.message.kind);
+    assert_eq!(2, old.message.unknown_extensions.len());
+    assert!(old.message.unknown_extensions[0].is_some());
+    assert_eq!(None, old.message.unknown_extensions[1]);
+    assert!(matches!(old.detail, old::Detail::Unknown { index: 1, .. }));
+
+    assert_eq!((bits, data.clone()), serialize_uper(&old));
+    assert_eq!(envelope(), deserialize_uper::<Envelope>(&data[..], bits));
+}
+
+#[test]
+fn test_unknown_extensions_without_known_extensions_are_preserved() {
+    let message = Message {
+        id: 42,
+        kind: Some(Kind::Green),
+        note: None,
+        count: Some(3),
+    };
+    let (bits, data) = serialize_uper(&message);
+    let old = deserialize_uper::<old::MessageRoot>(&data[..], bits);
+
+    assert_eq!(42, old.id);
+    assert_eq!(3, old.unknown_extensions.len());
+    assert_eq!((bits, data.clone()), serialize_uper(&old));
+    assert_eq!(message, deserialize_uper::<Message>(&data[..], bits));
+}
+
+#[test]
+fn test_without_unknown_extensions_nothing_changes() {
+    serialize_and_deserialize_uper(
+        33,
+        &[0x80, 0x80, 0x80, 0x80, 0x00],
+        &old::Message {
+            id: 1,
+            kind: Some(old::Kind::Red),
+            unknown_extensions: Vec::default(),
+        },
+    );
+    serialize_and_deserialize_uper(
+        9,
+        &[0x00, 0x80],
+        &old::MessageRoot {
+            id: 1,
+            unknown_extensions: Vec::default(),
+        },
+    );
+}
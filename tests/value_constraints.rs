@@ -0,0 +1,149 @@
+mod test_utils;
+
+use asn1rs::syn::io::UperWriter as NewUperWriter;
+use test_utils::*;
+
+asn_to_rust!(
+    r"ValueConstraints DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    Sparse ::= INTEGER (1 | 3 | 5..10)
+
+    NonZero ::= INTEGER (-5..5 EXCEPT 0)
+
+    AnythingButZero ::= INTEGER (ALL EXCEPT 0)
+
+    Overlap ::= INTEGER (0..100 ^ 50..MAX)
+
+    ExtensibleSparse ::= INTEGER (1 | 3, ...)
+
+    Digest ::= OCTET STRING (SIZE(4) | SIZE(8))
+
+    Reading ::= SEQUENCE {
+        channel INTEGER (1 | 2 | 4 | 8),
+        digest OCTET STRING (SIZE(4) | SIZE(8))
+    }
+
+    END"
+);
+
+#[test]
+fn test_union_uses_the_effective_constraint() {
+    // the effective constraint 1..10 requires 4 bits
+    serialize_and_deserialize_uper(4, &[0x00], &Sparse(1));
+    serialize_and_deserialize_uper(4, &[0x60], &Sparse(7));
+    serialize_and_deserialize_aper(4, &[0x90], &Sparse(10));
+    serialize_and_deserialize_ber(&[0x02, 0x01, 0x03], &Sparse(3));
+    serialize_and_deserialize_oer(&[0x05], &Sparse(5));
+    serialize_and_deserialize_jer("3", &Sparse(3));
+    serialize_and_deserialize_xer("<Sparse>3</Sparse>", &Sparse(3));
+}
+
+#[test]
+fn test_except_ignores_the_excluded_values_for_the_encoding() {
+    // the effective constraint -5..5 requires 4 bits
+    serialize_and_deserialize_uper(4, &[0x00], &NonZero(-5));
+    serialize_and_deserialize_uper(4, &[0xA0], &NonZero(5));
+    // not PER-visible at all and therefore unconstrained
+    serialize_and_deserialize_uper(24, &[0x02, 0x00, 0xFF], &AnythingButZero(255));
+}
+
+#[test]
+fn test_intersection_narrows_the_effective_constraint() {
+    // the effective constraint 50..100 requires 6 bits
+    serialize_and_deserialize_uper(6, &[0x00], &Overlap(50));
+    serialize_and_deserialize_uper(6, &[0xC8], &Overlap(100));
+}
+
+#[test]
+fn test_values_not_permitted_are_rejected() {
+    assert_eq!(
+        Err(asn1rs::io::per::Error::ValueNotPermitted("4".to_string())),
+        NewUperWriter::default().write(&Sparse(4))
+    );
+    assert_eq!(
+        Err(asn1rs::io::per::Error::ValueNotPermitted("0".to_string())),
+        AperWriter::default().write(&NonZero(0))
+    );
+    assert_eq!(
+        Err(asn1rs::io::ber::Error::ValueNotPermitted("0".to_string())),
+        BerWriter::default().write(&AnythingButZero(0))
+    );
+    assert_eq!(
+        Err(asn1rs::io::oer::Error::ValueNotPermitted("2".to_string())),
+        OerWriter::default().write(&Sparse(2))
+    );
+    assert_eq!(
+        Err(asn1rs::io::per::Error::ValueNotPermitted("5".to_string())),
+        NewUperWriter::default().write(&Reading {
+            channel: 5,
+            digest: vec![0; 4],
+        })
+    );
+    // the range is still checked before the full constraint
+    assert_eq!(
+        Err(asn1rs::io::xer::Error::ValueNotInRange(11, 1, 10)),
+        XerWriter::default().write(&Sparse(11))
+    );
+}
+
+#[test]
+fn test_extensible_values_outside_of_the_root() {
+    // the extension bit and the constrained value
+    serialize_and_deserialize_uper(3, &[0x40], &ExtensibleSparse(3));
+    // the extension bit followed by the length and the octet of the unconstrained value
+    serialize_and_deserialize_uper(17, &[0x80, 0x81, 0x00], &ExtensibleSparse(2));
+    serialize_and_deserialize_jer("2", &ExtensibleSparse(2));
+}
+
+#[test]
+fn test_size_union_uses_the_effective_constraint() {
+    // the length of 4..8 requires 3 bits
+    serialize_and_deserialize_uper(
+        3 + 4 * 8,
+        &[0x1F, 0xFF, 0xFF, 0xFF, 0xE0],
+        &Digest(vec![0xFF; 4]),
+    );
+    let reading = Reading {
+        channel: 8,
+        digest: vec![0x00; 8],
+    };
+    // the effective constraint 1..8 requires 3 bits
+    let (bits, bytes) = serialize_uper(&reading);
+    assert_eq!(3 + 3 + 8 * 8, bits);
+    assert_eq!(reading, deserialize_uper::<Reading>(&bytes, bits));
+}
+
+// the 128-bit INTEGER is not supported by the legacy UPER codegen, protobuf and psql supplements
+#[cfg(not(any(
+    feature = "legacy-uper-codegen",
+    feature = "protobuf",
+    feature = "psql",
+    feature = "async-psql"
+)))]
+mod wide {
+    use crate::test_utils::*;
+
+    asn_to_rust!(
+        r"WideValueConstraints DEFINITIONS AUTOMATIC TAGS ::=
+        BEGIN
+
+        WideSparse ::= INTEGER (0 | 18446744073709551616)
+
+        END"
+    );
+
+    #[test]
+    fn test_wide_union() {
+        serialize_and_deserialize_uper(65, &[0x80, 0, 0, 0, 0, 0, 0, 0, 0], &WideSparse(1 << 64));
+        serialize_and_deserialize_uper(65, &[0, 0, 0, 0, 0, 0, 0, 0, 0], &WideSparse(0));
+    }
+
+    #[test]
+    fn test_wide_values_not_permitted_are_rejected() {
+        assert_eq!(
+            Err(asn1rs::io::jer::Error::ValueNotPermitted("1".to_string())),
+            JerWriter::default().write(&WideSparse(1))
+        );
+    }
+}
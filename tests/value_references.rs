@@ -0,0 +1,63 @@
+mod test_utils;
+
+use asn1rs::syn::io::UperWriter as NewUperWriter;
+use test_utils::*;
+
+asn_to_rust!(
+    r#"ValueReferences DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+      maxReferencePositions INTEGER ::= 3
+      minSpeed INTEGER ::= -10
+      defaultName UTF8String ::= "unknown"
+      enabled BOOLEAN ::= TRUE
+
+      Speed ::= INTEGER (minSpeed..maxSpeed)
+
+      ReferencePositions ::= SEQUENCE (SIZE(1..maxReferencePositions)) OF Speed
+
+      maxSpeed INTEGER ::= 100
+
+    END"#
+);
+
+#[test]
+fn test_value_references_are_constants() {
+    assert_eq!(3_u64, MAX_REFERENCE_POSITIONS);
+    assert_eq!(-10_i64, MIN_SPEED);
+    assert_eq!(100_u64, MAX_SPEED);
+    assert_eq!("unknown", DEFAULT_NAME);
+    let enabled: bool = ENABLED;
+    assert!(enabled);
+}
+
+#[test]
+fn test_value_references_in_integer_constraints() {
+    assert_eq!(-10, Speed::value_min());
+    assert_eq!(100, Speed::value_max());
+}
+
+#[test]
+fn test_value_references_in_size_constraints() {
+    use asn1rs::syn::sequenceof::Constraint;
+    assert_eq!(Some(1), ___asn1rs_ReferencePositionsField0Constraint::MIN);
+    assert_eq!(Some(3), ___asn1rs_ReferencePositionsField0Constraint::MAX);
+}
+
+#[test]
+fn test_uper_uses_the_resolved_constraints() {
+    // 2 bits for the length (1..3), 7 bits for each speed (-10..100)
+    serialize_and_deserialize_uper(
+        16,
+        &[0x40, 0x6E],
+        &ReferencePositions(vec![Speed(-10), Speed(100)]),
+    );
+}
+
+#[test]
+fn test_uper_rejects_too_many_reference_positions() {
+    let mut writer = NewUperWriter::default();
+    assert!(writer
+        .write(&ReferencePositions(vec![Speed(0); 4]))
+        .is_err());
+}